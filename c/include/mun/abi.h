@@ -6,7 +6,7 @@
 /**
  * Defines the current ABI version
  */
-#define MUN_ABI_VERSION 300
+#define MUN_ABI_VERSION 500
 
 /**
  * Represents the kind of memory management a struct uses.
@@ -188,6 +188,58 @@ typedef struct MunStructDefinition {
     MunStructMemoryKind memory_kind;
 } MunStructDefinition;
 
+/**
+ * Represents a single variant of an enum declaration.
+ */
+typedef struct MunVariantDefinition {
+    /**
+     * Variant name
+     */
+    const char *name;
+    /**
+     * Variant fields' names
+     */
+    const char *const *field_names;
+    /**
+     * Variant fields' information
+     */
+    const union MunTypeId *field_types;
+    /**
+     * Variant fields' offsets, relative to the start of the enum
+     */
+    const uint16_t *field_offsets;
+    /**
+     * Number of fields
+     */
+    uint16_t num_fields;
+} MunVariantDefinition;
+
+/**
+ * Represents an enum declaration.
+ *
+ * An enum is laid out in memory as a tag that identifies the active variant, followed by a
+ * payload that is large enough to hold the fields of any of its variants. Enums are always value
+ * types.
+ */
+typedef struct MunEnumDefinition {
+    /**
+     * The unique identifier of this enum
+     */
+    struct MunGuid guid;
+    /**
+     * Enum variants' information
+     */
+    const struct MunVariantDefinition *variants;
+    /**
+     * Number of variants
+     */
+    uint16_t num_variants;
+    /**
+     * The size of the tag in bytes
+     */
+    uint8_t tag_size;
+} MunEnumDefinition;
+
 /**
  * Contains data specific to a group of types that illicit the same characteristics.
  */
@@ -200,6 +252,10 @@ enum MunTypeDefinitionData_Tag
      * Struct types (i.e. record, tuple, or unit structs)
      */
     Struct,
+    /**
+     * Enum types (i.e. tagged unions of record, tuple, or unit variants)
+     */
+    Enum,
 };
 #ifndef __cplusplus
 typedef uint8_t MunTypeDefinitionData_Tag;
@@ -211,6 +267,10 @@ typedef union MunTypeDefinitionData {
         MunTypeDefinitionData_Tag struct_tag;
         struct MunStructDefinition struct_;
     };
+    struct {
+        MunTypeDefinitionData_Tag enum_tag;
+        struct MunEnumDefinition enum_;
+    };
 } MunTypeDefinitionData;
 
 /**
@@ -246,6 +306,29 @@ typedef struct MunTypeDefinition {
     union MunTypeDefinitionData data;
 } MunTypeDefinition;
 
+/**
+ * Represents a global variable definition. A global definition contains the name and type of a
+ * `static` item and a pointer to its memory in the data section of the assembly.
+ */
+typedef struct MunGlobalDefinition {
+    /**
+     * Global name
+     */
+    const char *name;
+    /**
+     * The type of the global
+     */
+    union MunTypeId type_id;
+    /**
+     * Pointer to the memory of the global
+     */
+    void *ptr;
+    /**
+     * Whether the global can be modified
+     */
+    bool mutable_;
+} MunGlobalDefinition;
+
 /**
  * Represents a module declaration.
  */
@@ -262,6 +345,10 @@ typedef struct MunModuleInfo {
      * Module types
      */
     const struct MunTypeDefinition *types;
+    /**
+     * Module globals
+     */
+    const struct MunGlobalDefinition *globals;
     /**
      * Number of module functions
      */
@@ -270,6 +357,10 @@ typedef struct MunModuleInfo {
      * Number of module types
      */
     uint32_t num_types;
+    /**
+     * Number of module globals
+     */
+    uint32_t num_globals;
 } MunModuleInfo;
 
 /**
@@ -316,6 +407,31 @@ typedef struct MunTypeLut {
     uint32_t num_entries;
 } MunTypeLut;
 
+/**
+ * Represents a lookup table for global variables that are defined in other assemblies. This is
+ * used for runtime linking.
+ *
+ * Global names, type IDs, and pointers are stored separately for cache efficiency.
+ */
+typedef struct MunGlobalLut {
+    /**
+     * Global names
+     */
+    const char *const *global_names;
+    /**
+     * Global type IDs
+     */
+    const union MunTypeId *type_ids;
+    /**
+     * Global pointers
+     */
+    void **global_ptrs;
+    /**
+     * Number of globals
+     */
+    uint32_t num_entries;
+} MunGlobalLut;
+
 /**
  * Represents an assembly declaration.
  */
@@ -332,6 +448,10 @@ typedef struct MunAssemblyInfo {
      * Type lookup table
      */
     struct MunTypeLut type_lut;
+    /**
+     * Global lookup table
+     */
+    struct MunGlobalLut global_lut;
     /**
      * Paths to assembly dependencies
      */
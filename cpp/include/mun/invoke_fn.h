@@ -75,12 +75,26 @@ InvokeResult<Output, Args...> invoke_fn(Runtime& runtime, std::string_view fn_na
 
         auto fn = reinterpret_cast<typename Marshal<Output>::type(MUN_CALLTYPE*)(
             typename Marshal<Args>::type...)>(const_cast<void*>(fn_info->function_pointer()));
+        // The return value of a function that panicked is meaningless, so it is never marshalled
+        auto panicked = [&runtime, fn_name]() {
+            if (auto error = runtime.check_panic(); error.is_error()) {
+                std::cerr << "Function '" << fn_name
+                          << "' panicked: " << error.message().value() << std::endl;
+                return true;
+            }
+            return false;
+        };
+
         if constexpr (std::is_same_v<Output, void>) {
             fn(Marshal<Args>::to(args)...);
-            return InvokeResult<Output, Args...>(std::monostate{});
+            if (!panicked()) {
+                return InvokeResult<Output, Args...>(std::monostate{});
+            }
         } else {
-            return InvokeResult<Output, Args...>(
-                Marshal<Output>::from(fn(Marshal<Args>::to(args)...), runtime));
+            auto result = fn(Marshal<Args>::to(args)...);
+            if (!panicked()) {
+                return InvokeResult<Output, Args...>(Marshal<Output>::from(result, runtime));
+            }
         }
     }
 
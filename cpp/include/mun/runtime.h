@@ -3,12 +3,14 @@
 
 #include <cassert>
 #include <optional>
+#include <string>
 #include <string_view>
 
 #include "mun/error.h"
 #include "mun/function.h"
 #include "mun/runtime_capi.h"
 #include "mun/runtime_function.h"
+#include "mun/static_type_info.h"
 #include "mun/type.h"
 
 namespace mun {
@@ -135,6 +137,60 @@ public:
         return Type(type_handle);
     }
 
+    /** Checks whether the last call of a Mun function panicked. If so, the panic is cleared and
+     * returned as an error.
+     *
+     * \return an error that describes the panic and the Mun functions it unwound through, if the
+     * function panicked
+     */
+    Error check_panic() const noexcept { return Error(mun_function_check_panic(m_handle)); }
+
+    /** Retrieves the value of the global called `global_name`.
+     *
+     * \param global_name the name of the desired global
+     * \param out_error a pointer that will optionally return an error
+     * \return possibly, the value of the global
+     */
+    template <typename T>
+    std::optional<T> get_global(std::string_view global_name,
+                                Error* out_error = nullptr) const noexcept {
+        const std::string name(global_name);
+        T value;
+        if (auto error = Error(mun_runtime_get_global(
+                m_handle, name.c_str(), StaticTypeInfo<T>::type_info().type_handle(), &value));
+            error.is_error()) {
+            if (out_error) {
+                *out_error = std::move(error);
+            }
+            return std::nullopt;
+        }
+
+        return std::make_optional(value);
+    }
+
+    /** Sets the value of the mutable global called `global_name` to `value`.
+     *
+     * \param global_name the name of the desired global
+     * \param value the new value of the global
+     * \param out_error a pointer that will optionally return an error
+     * \return whether the value of the global was set
+     */
+    template <typename T>
+    bool set_global(std::string_view global_name, const T& value,
+                    Error* out_error = nullptr) const noexcept {
+        const std::string name(global_name);
+        if (auto error = Error(mun_runtime_set_global(
+                m_handle, name.c_str(), StaticTypeInfo<T>::type_info().type_handle(), &value));
+            error.is_error()) {
+            if (out_error) {
+                *out_error = std::move(error);
+            }
+            return false;
+        }
+
+        return true;
+    }
+
     /** Checks for updates to hot reloadable assemblies.
      *
      * \param out_error a pointer that will optionally return an error
@@ -27,6 +27,7 @@ enum MunPrimitiveType
     MUN_PRIMITIVE_TYPE_F64,
     MUN_PRIMITIVE_TYPE_EMPTY,
     MUN_PRIMITIVE_TYPE_VOID,
+    MUN_PRIMITIVE_TYPE_STRING,
 };
 #ifndef __cplusplus
 typedef uint8_t MunPrimitiveType;
@@ -192,6 +193,16 @@ typedef struct MunArrayTypeId {
     const union MunTypeId *element;
 } MunArrayTypeId;
 
+/**
+ * Represents an optional reference to another type, which can be `nil`.
+ */
+typedef struct MunOptionalTypeId {
+    /**
+     * The type that is referenced if the optional is not `nil`
+     */
+    const union MunTypeId *inner;
+} MunOptionalTypeId;
+
 /**
  * Represents a unique identifier for types. The runtime can use this to lookup the corresponding
  * [`TypeInfo`]. A [`TypeId`] is a key for a [`TypeInfo`].
@@ -215,6 +226,10 @@ enum MunTypeId_Tag
      * Represents an array of a specific type
      */
     MUN_TYPE_ID_ARRAY,
+    /**
+     * Represents an optional reference to a specific type
+     */
+    MUN_TYPE_ID_OPTIONAL,
 };
 #ifndef __cplusplus
 typedef uint8_t MunTypeId_Tag;
@@ -234,6 +249,10 @@ typedef union MunTypeId {
         MunTypeId_Tag array_tag;
         struct MunArrayTypeId array;
     };
+    struct {
+        MunTypeId_Tag optional_tag;
+        struct MunOptionalTypeId optional;
+    };
 } MunTypeId;
 
 /**
@@ -283,6 +302,28 @@ typedef struct MunArrayInfo {
     const void *_1;
 } MunArrayInfo;
 
+/**
+ * Additional information of an enum [`Type`].
+ *
+ * Ownership of this type lies with the [`Type`] that created this instance. As long as the
+ * original type is not released through [`mun_type_release`] this type stays alive.
+ */
+typedef struct MunEnumInfo {
+    const void *_0;
+    const void *_1;
+} MunEnumInfo;
+
+/**
+ * Additional information of an optional [`Type`].
+ *
+ * Ownership of this type lies with the [`Type`] that created this instance. As long as the
+ * original type is not released through [`mun_type_release`] this type stays alive.
+ */
+typedef struct MunOptionalInfo {
+    const void *_0;
+    const void *_1;
+} MunOptionalInfo;
+
 /**
  * An enum that defines the kind of type.
  */
@@ -295,6 +336,8 @@ enum MunTypeKind_Tag
     MUN_TYPE_KIND_POINTER,
     MUN_TYPE_KIND_STRUCT,
     MUN_TYPE_KIND_ARRAY,
+    MUN_TYPE_KIND_ENUM,
+    MUN_TYPE_KIND_OPTIONAL,
 };
 #ifndef __cplusplus
 typedef uint8_t MunTypeKind_Tag;
@@ -318,6 +361,14 @@ typedef union MunTypeKind {
         MunTypeKind_Tag array_tag;
         struct MunArrayInfo array;
     };
+    struct {
+        MunTypeKind_Tag enum_tag;
+        struct MunEnumInfo enum_;
+    };
+    struct {
+        MunTypeKind_Tag optional_tag;
+        struct MunOptionalInfo optional;
+    };
 } MunTypeKind;
 
 /**
@@ -342,6 +393,28 @@ typedef struct MunFields {
     uintptr_t count;
 } MunFields;
 
+/**
+ * Information of a variant of an enum [`Type`].
+ *
+ * Ownership of this type lies with the [`Type`] that created this instance. As long as the
+ * original type is not released through [`mun_type_release`] this type stays alive.
+ */
+typedef struct MunVariant {
+    const void *_0;
+    const void *_1;
+} MunVariant;
+
+/**
+ * An array of [`Variant`]s.
+ *
+ * This is backed by a dynamically allocated array. Ownership is transferred via this struct
+ * and its contents must be destroyed with [`mun_variants_destroy`].
+ */
+typedef struct MunVariants {
+    const struct MunVariant *variants;
+    uintptr_t count;
+} MunVariants;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -499,6 +572,42 @@ struct MunErrorHandle mun_runtime_get_type_info_by_id(struct MunRuntime runtime,
                                                       bool *has_type_info,
                                                       struct MunType *type_info);
 
+/**
+ * Retrieves the value of the global called `global_name` from the `runtime` and copies it to
+ * `value`. If successful, `value` is set, otherwise a non-zero error handle is returned. The
+ * `global_type` must match the type of the global.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_runtime_get_global(struct MunRuntime runtime,
+                                             const char *global_name,
+                                             struct MunType global_type,
+                                             void *value);
+
+/**
+ * Sets the value of the mutable global called `global_name` in the `runtime` to the value
+ * pointed to by `value`. The `global_type` must match the type of the global. If unsuccessful, a
+ * non-zero error handle is returned.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_runtime_set_global(struct MunRuntime runtime,
+                                             const char *global_name,
+                                             struct MunType global_type,
+                                             const void *value);
+
 /**
  * Updates the runtime corresponding to `handle`. If successful, `updated` is set, otherwise a
  * non-zero error handle is returned.
@@ -513,6 +622,54 @@ struct MunErrorHandle mun_runtime_get_type_info_by_id(struct MunRuntime runtime,
  */
 struct MunErrorHandle mun_runtime_update(struct MunRuntime runtime, bool *updated);
 
+/**
+ * Allocates a string in the runtime that contains a copy of the `len` UTF-8 encoded bytes
+ * pointed to by `value`. If successful, `obj` is set, otherwise a non-zero error handle is
+ * returned.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_string_new(struct MunRuntime runtime,
+                                     const char *value,
+                                     uintptr_t len,
+                                     MunGcPtr *obj);
+
+/**
+ * Retrieves the number of bytes in the string `obj`. If successful, `len` is set, otherwise a
+ * non-zero error handle is returned.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_string_len(struct MunRuntime runtime, MunGcPtr obj, uintptr_t *len);
+
+/**
+ * Retrieves a pointer to the UTF-8 encoded bytes of the string `obj`. If successful, `data` is
+ * set, otherwise a non-zero error handle is returned. The bytes are not null-terminated; use
+ * [`mun_string_len`] to retrieve the number of bytes. The pointer is only valid as long as the
+ * string is not collected.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_string_data(struct MunRuntime runtime, MunGcPtr obj, const char **data);
+
 /**
  * Notifies the runtime an additional references exists to the function. This ensures that the data
  * is kept alive even if [`mun_function_release`] is called for the existing references. Only
@@ -588,6 +745,22 @@ struct MunErrorHandle mun_function_argument_types(struct MunFunction function,
  */
 struct MunErrorHandle mun_function_return_type(struct MunFunction function, struct MunType *ty);
 
+/**
+ * Checks whether the last call of a Mun function - through its function pointer - panicked. If
+ * so, a non-zero error handle is returned that contains the panic message followed by the names
+ * of the Mun functions that the panic unwound through, and the panic is cleared. The return value
+ * of a function that panicked is meaningless and must not be used.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+ * an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+ */
+struct MunErrorHandle mun_function_check_panic(struct MunRuntime runtime);
+
 /**
  * Deallocates a string that was allocated by the runtime.
  *
@@ -696,6 +869,16 @@ struct MunErrorHandle mun_type_pointer_type(struct MunType ty,
  */
 struct MunErrorHandle mun_type_array_type(struct MunType ty, struct MunType *array_ty);
 
+/**
+ * Returns a new [`Type`] that is an optional reference to the specified type.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `Type`s have been deallocated in a
+ * previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_type_optional_type(struct MunType ty, struct MunType *optional_ty);
+
 /**
  * Returns information about what kind of type this is.
  *
@@ -728,6 +911,82 @@ struct MunErrorHandle mun_types_destroy(struct MunTypes types);
 struct MunErrorHandle mun_array_type_element_type(struct MunArrayInfo ty,
                                                   struct MunType *element_ty);
 
+/**
+ * Returns the globally unique identifier (GUID) of the enum.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_enum_type_guid(struct MunEnumInfo ty, struct MunGuid *guid);
+
+/**
+ * Returns the size of the tag of the enum in bytes. The tag is stored at the start of the enum and
+ * holds the index of the variant that is currently stored in the enum.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_enum_type_tag_size(struct MunEnumInfo ty, uintptr_t *tag_size);
+
+/**
+ * Destroys the contents of a [`Variants`] struct.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `Variants` has been deallocated
+ * by a previous call to [`mun_variants_destroy`].
+ */
+struct MunErrorHandle mun_variants_destroy(struct MunVariants variants);
+
+/**
+ * Retrieves all the variants of the specified enum type. The index of a variant in the returned
+ * array is equal to the value of its tag.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_enum_type_variants(struct MunEnumInfo ty, struct MunVariants *variants);
+
+/**
+ * Returns the name of the variant in the parent enum. Ownership of the name is transferred and
+ * must be destroyed with [`mun_string_destroy`]. If this function fails a nullptr is returned.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `Variant` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_variant_name(struct MunVariant variant, const char **name);
+
+/**
+ * Retrieves all the fields of the specified variant. The offsets of the fields are relative to the
+ * start of the enum.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `Variant` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_variant_fields(struct MunVariant variant, struct MunFields *fields);
+
+/**
+ * Returns the type that is referenced if the optional is not `nil`. Ownership is transferred if
+ * this function returns successfully.
+ *
+ * # Safety
+ *
+ * This function results in undefined behavior if the passed in `OptionalInfo` has been deallocated
+ * by a previous call to [`mun_type_release`].
+ */
+struct MunErrorHandle mun_optional_type_inner_type(struct MunOptionalInfo ty,
+                                                   struct MunType *inner_ty);
+
 /**
  * Returns the type that this instance points to. Ownership is transferred if this function returns
  * successfully.
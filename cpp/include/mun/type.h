@@ -84,6 +84,15 @@ public:
         return type_kind.tag == MUN_TYPE_KIND_ARRAY;
     }
 
+    /**
+     * Returns true if this TypeInfo represents an optional reference.
+     */
+    [[nodiscard]] bool is_optional() const noexcept {
+        MunTypeKind type_kind;
+        MUN_ASSERT(mun_type_kind(m_handle, &type_kind));
+        return type_kind.tag == MUN_TYPE_KIND_OPTIONAL;
+    }
+
     /**
      * Returns true if this TypeInfo represents a primitive.
      */
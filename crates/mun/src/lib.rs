@@ -4,7 +4,7 @@ use std::ffi::OsString;
 
 use clap::{Parser, Subcommand};
 
-use ops::{build, init, language_server, new, start};
+use ops::{build, check, init, language_server, new, run, start, test};
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -22,6 +22,9 @@ enum Command {
     /// Compiles a local Mun file into a module
     Build(build::Args),
 
+    /// Report the diagnostics of a local Mun package without generating code
+    Check(check::Args),
+
     /// Create a new Mun project at the specified location
     New(new::Args),
 
@@ -30,6 +33,12 @@ enum Command {
 
     /// Invoke a function from a munlib
     Start(start::Args),
+
+    /// Compile a local Mun package and invoke a function from it, optionally reloading on changes
+    Run(run::Args),
+
+    /// Compile a local Mun package and run the functions marked with `#[test]`
+    Test(test::Args),
 }
 
 #[derive(Copy, Debug, Clone, PartialEq, Eq)]
@@ -56,9 +65,12 @@ where
     let args = Args::parse_from(args);
     match args.command {
         Command::Build(args) => build::build(args),
+        Command::Check(args) => check::check(args),
         Command::LanguageServer(args) => language_server::language_server(args),
         Command::New(args) => new::new(args),
         Command::Init(args) => init::init(args),
         Command::Start(args) => start::start(args),
+        Command::Run(args) => run::run(args),
+        Command::Test(args) => test::test(args),
     }
 }
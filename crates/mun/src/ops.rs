@@ -1,5 +1,8 @@
 pub mod build;
+pub mod check;
 pub mod init;
 pub mod language_server;
 pub mod new;
+pub mod run;
 pub mod start;
+pub mod test;
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::anyhow;
 use mun_compiler::{Config, DisplayColor, Target};
-use mun_project::MANIFEST_FILENAME;
+use mun_project::{Manifest, Profile, MANIFEST_FILENAME};
 
 use crate::ExitStatus;
 
@@ -14,6 +14,23 @@ pub enum UseColor {
     Auto,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// Human readable snippets of the source code
+    Human,
+    /// A JSON object per diagnostic, each on a separate line
+    Json,
+}
+
+impl From<MessageFormat> for mun_compiler::MessageFormat {
+    fn from(format: MessageFormat) -> Self {
+        match format {
+            MessageFormat::Human => mun_compiler::MessageFormat::Human,
+            MessageFormat::Json => mun_compiler::MessageFormat::Json,
+        }
+    }
+}
+
 #[derive(clap::Args)]
 pub struct Args {
     /// Path to the manifest of the project
@@ -28,6 +45,10 @@ pub struct Args {
     #[clap(long, value_enum)]
     color: Option<UseColor>,
 
+    /// Format in which diagnostics are written to stderr
+    #[clap(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+
     /// Emits IR instead of a *.munlib
     #[clap(long)]
     emit_ir: bool,
@@ -39,8 +60,19 @@ pub struct Args {
     /// Target for machine code
     #[clap(long, value_parser=parse_target_triple)]
     target: Option<Target>,
+
+    /// Profile of the manifest whose settings are used [default: dev]
+    #[clap(long)]
+    profile: Option<String>,
+
+    /// Panic when integer arithmetic overflows, regardless of the profile
+    #[clap(long)]
+    overflow_checks: bool,
 }
 
+/// The name of the profile that is used if none is specified
+const DEFAULT_PROFILE: &str = "dev";
+
 fn parse_target_triple(target_triple: &str) -> Result<Target, String> {
     Target::search(target_triple)
         .ok_or_else(|| format!("could not find target for '{}'", target_triple))
@@ -59,8 +91,39 @@ pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
         _ => return Err(anyhow!("Only optimization levels 0-3 are supported")),
     };
 
-    let display_colors = args
-        .color
+    let display_colors = display_color(args.color);
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+    log::info!("located build manifest at: {}", manifest_path.display());
+    let profile = select_profile(&manifest_path, args.profile.as_deref())?;
+
+    let compiler_options = Config {
+        target: args
+            .target
+            .unwrap_or_else(|| Target::host_target().expect("unable to determine host target")),
+        optimization_lvl,
+        out_dir: None,
+        emit_ir: args.emit_ir,
+        overflow_checks: args.overflow_checks || profile.overflow_checks,
+        export_tests: false,
+        message_format: args.message_format.into(),
+    };
+
+    if args.watch {
+        mun_compiler_daemon::compile_and_watch_manifest(
+            &manifest_path,
+            compiler_options,
+            display_colors,
+        )
+    } else {
+        mun_compiler::compile_manifest(&manifest_path, compiler_options, display_colors)
+    }
+    .map(Into::into)
+}
+
+/// Determines whether to use colors in the output, either from the command line or from the
+/// `MUN_TERMINAL_COLOR` environment variable.
+pub(crate) fn display_color(color: Option<UseColor>) -> DisplayColor {
+    color
         .map(|clr| match clr {
             UseColor::Disable => DisplayColor::Disable,
             UseColor::Enable => DisplayColor::Enable,
@@ -75,10 +138,13 @@ pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
                 })
                 .ok()
         })
-        .unwrap_or(DisplayColor::Auto);
+        .unwrap_or(DisplayColor::Auto)
+}
 
-    // Locate the manifest
-    let manifest_path = match &args.manifest_path {
+/// Returns the canonical path of the specified manifest or, if no path is specified, locates the
+/// manifest in the current directory or one of its parents.
+pub(crate) fn locate_manifest(manifest_path: Option<&Path>) -> Result<PathBuf, anyhow::Error> {
+    match manifest_path {
         None => {
             let current_dir =
                 std::env::current_dir().expect("could not determine current working directory");
@@ -88,37 +154,37 @@ pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
                     MANIFEST_FILENAME,
                     current_dir.display()
                 )
-            })?
+            })
         }
-        Some(path) => std::fs::canonicalize(Path::new(&path)).map_err(|_| {
+        Some(path) => std::fs::canonicalize(path).map_err(|_| {
             anyhow::anyhow!(
                 "'{}' does not refer to a valid manifest path",
                 path.display()
             )
-        })?,
-    };
-
-    log::info!("located build manifest at: {}", manifest_path.display());
-
-    let compiler_options = Config {
-        target: args
-            .target
-            .unwrap_or_else(|| Target::host_target().expect("unable to determine host target")),
-        optimization_lvl,
-        out_dir: None,
-        emit_ir: args.emit_ir,
-    };
+        }),
+    }
+}
 
-    if args.watch {
-        mun_compiler_daemon::compile_and_watch_manifest(
-            &manifest_path,
-            compiler_options,
-            display_colors,
-        )
-    } else {
-        mun_compiler::compile_manifest(&manifest_path, compiler_options, display_colors)
+/// Returns the settings of the profile with the specified name, or of the default profile if no
+/// name is specified. Only the default profile can be omitted from the manifest.
+pub(crate) fn select_profile(
+    manifest_path: &Path,
+    name: Option<&str>,
+) -> Result<Profile, anyhow::Error> {
+    let manifest = Manifest::from_file(manifest_path)?;
+    match name {
+        Some(name) => manifest.profile(name).cloned().ok_or_else(|| {
+            anyhow!(
+                "profile '{}' is not defined in '{}'",
+                name,
+                manifest_path.display()
+            )
+        }),
+        None => Ok(manifest
+            .profile(DEFAULT_PROFILE)
+            .cloned()
+            .unwrap_or_default()),
     }
-    .map(Into::into)
 }
 
 /// Find a Mun manifest file in the specified directory or one of its parents.
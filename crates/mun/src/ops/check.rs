@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use mun_compiler::Config;
+
+use crate::ops::build::{display_color, locate_manifest, MessageFormat, UseColor};
+use crate::ExitStatus;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Path to the manifest of the project
+    #[clap(long)]
+    manifest_path: Option<PathBuf>,
+
+    /// Use color in output
+    #[clap(long, value_enum)]
+    color: Option<UseColor>,
+
+    /// Format in which diagnostics are written to stderr
+    #[clap(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+}
+
+/// This method is invoked when the executable is run with the `check` argument. It reports the
+/// diagnostics of a project in the current directory or one of its parent directories, without
+/// generating code or linking assemblies.
+pub fn check(args: Args) -> Result<ExitStatus, anyhow::Error> {
+    log::trace!("starting check");
+
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+    log::info!("located check manifest at: {}", manifest_path.display());
+
+    let config = Config {
+        message_format: args.message_format.into(),
+        ..Config::default()
+    };
+    mun_compiler::check_manifest(&manifest_path, config, display_color(args.color)).map(Into::into)
+}
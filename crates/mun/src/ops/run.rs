@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use mun_compiler::Config;
+use mun_compiler_daemon::Daemon;
+use mun_runtime::Runtime;
+
+use crate::ops::build::{display_color, locate_manifest, select_profile, UseColor};
+use crate::ops::start::invoke_entry;
+use crate::ExitStatus;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The function entry point to call on startup
+    #[clap(default_value_t = String::from("main"))]
+    entry: String,
+
+    /// Arguments that are passed to the entry point
+    #[clap(last = true)]
+    args: Vec<String>,
+
+    /// Path to the manifest of the project
+    #[clap(long)]
+    manifest_path: Option<PathBuf>,
+
+    /// Profile of the manifest whose settings are used [default: dev]
+    #[clap(long)]
+    profile: Option<String>,
+
+    /// Use color in output
+    #[clap(long, value_enum)]
+    color: Option<UseColor>,
+
+    /// Keep recompiling the project on changes and invoke the entry point again after every
+    /// reload
+    #[clap(long)]
+    watch: bool,
+}
+
+/// Compiles the project in the current directory or one of its parent directories, starts the
+/// runtime with the assembly of its root module and invokes function `entry`.
+pub fn run(args: Args) -> anyhow::Result<ExitStatus> {
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+    log::info!("located run manifest at: {}", manifest_path.display());
+    let profile = select_profile(&manifest_path, args.profile.as_deref())?;
+
+    let config = Config {
+        overflow_checks: profile.overflow_checks,
+        ..Config::default()
+    };
+
+    let mut daemon = Daemon::new(&manifest_path, config, display_color(args.color))?;
+    if !daemon.compile()? {
+        return Ok(ExitStatus::Error);
+    }
+
+    let assembly_path = daemon
+        .driver()
+        .root_assembly_output_path()
+        .ok_or_else(|| anyhow!("the package does not contain any modules"))?;
+
+    // Safety: the assembly was compiled from the sources of the package
+    let mut runtime = unsafe { Runtime::builder(assembly_path).finish() }?;
+
+    let result = invoke_entry(&runtime, &args.entry, &args.args);
+    if !args.watch {
+        return result.map(|_| ExitStatus::Success);
+    } else if let Err(e) = result {
+        eprintln!("error: {}", e);
+    }
+
+    println!("Watching: {}", daemon.source_directory().display());
+    daemon.watch(|_| {
+        // Safety: the assemblies were compiled from the sources of the package
+        if unsafe { runtime.update() } {
+            if let Err(e) = invoke_entry(&runtime, &args.entry, &args.args) {
+                eprintln!("error: {}", e);
+            }
+        }
+        Ok(())
+    })?;
+
+    Ok(ExitStatus::Success)
+}
@@ -3,6 +3,9 @@ use mun_runtime::Runtime;
 use std::path::PathBuf;
 
 use crate::ExitStatus;
+use value::{Argument, Value};
+
+mod value;
 
 #[derive(clap::Args)]
 pub struct Args {
@@ -12,6 +15,10 @@ pub struct Args {
     /// The function entry point to call on startup
     #[clap(default_value_t = String::from("main"))]
     entry: String,
+
+    /// Arguments that are passed to the entry point
+    #[clap(last = true)]
+    args: Vec<String>,
 }
 
 /// Starts the runtime with the specified library and invokes function `entry`.
@@ -21,45 +28,40 @@ pub fn start(args: Args) -> anyhow::Result<ExitStatus> {
     // Safety: we assume that the passed in library is safe
     let runtime = unsafe { builder.finish() }?;
 
-    let fn_definition = runtime
-        .get_function_definition(&args.entry)
-        .ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!("Failed to obtain entry point '{}'", &args.entry),
-            )
-        })?;
-
-    let return_type = &fn_definition.prototype.signature.return_type;
-    if return_type.equals::<bool>() {
-        let result: bool = runtime
-            .invoke(&args.entry, ())
-            .map_err(|e| anyhow!("{}", e))?;
-
-        println!("{}", result)
-    } else if return_type.equals::<f64>() {
-        let result: f64 = runtime
-            .invoke(&args.entry, ())
-            .map_err(|e| anyhow!("{}", e))?;
+    invoke_entry(&runtime, &args.entry, &args.args)?;
+    Ok(ExitStatus::Success)
+}
 
-        println!("{}", result)
-    } else if return_type.equals::<i64>() {
-        let result: i64 = runtime
-            .invoke(&args.entry, ())
-            .map_err(|e| anyhow!("{}", e))?;
+/// Invokes the function `entry` with the specified command line arguments and prints its return
+/// value.
+pub(crate) fn invoke_entry(runtime: &Runtime, entry: &str, args: &[String]) -> anyhow::Result<()> {
+    let fn_definition = runtime.get_function_definition(entry).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Failed to obtain entry point '{}'", entry),
+        )
+    })?;
 
-        println!("{}", result)
-    } else if return_type.equals::<()>() {
-        #[allow(clippy::unit_arg)]
-        runtime
-            .invoke(&args.entry, ())
-            .map(|_: ()| ExitStatus::Success)
-            .map_err(|e| anyhow!("{}", e))?;
-    } else {
+    let arg_types = &fn_definition.prototype.signature.arg_types;
+    if arg_types.len() != args.len() {
         return Err(anyhow!(
-            "Only native Mun return types are supported for entry points. Found: {}",
-            return_type.name()
+            "Entry point '{}' expects {} argument(s), but {} were given",
+            entry,
+            arg_types.len(),
+            args.len()
         ));
-    };
-    Ok(ExitStatus::Success)
+    }
+
+    let args = args
+        .iter()
+        .zip(arg_types.iter())
+        .map(|(arg, ty)| Argument::parse(arg, ty))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| anyhow!("{}", e))?;
+
+    match value::invoke(runtime, &fn_definition, &args).map_err(|e| anyhow!("{}", e))? {
+        Value::Unit => {}
+        result => println!("{}", result),
+    }
+    Ok(())
 }
@@ -0,0 +1,480 @@
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+
+use libffi::middle::{self, Arg, Cif, CodePtr};
+use mun_runtime::{ArrayRef, FunctionDefinition, Marshal, Runtime, StructRef, Type};
+
+/// A value of a primitive type that is passed to an entry point from the command line.
+///
+/// The arguments are passed to the entry point with `libffi`, which doesn't support 128-bit
+/// integers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Argument {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+}
+
+impl Argument {
+    /// Parses `text` as a value of type `ty`.
+    pub fn parse(text: &str, ty: &Type) -> Result<Self, String> {
+        macro_rules! parse {
+            ($($variant:ident($ty:ty)),+) => {
+                $(
+                    if ty.equals::<$ty>() {
+                        return text.parse::<$ty>().map(Argument::$variant).map_err(|e| {
+                            format!("invalid value '{}' for type `{}`: {}", text, ty.name(), e)
+                        });
+                    }
+                )+
+            };
+        }
+
+        parse!(
+            Bool(bool),
+            I8(i8),
+            I16(i16),
+            I32(i32),
+            I64(i64),
+            U8(u8),
+            U16(u16),
+            U32(u32),
+            U64(u64),
+            F32(f32),
+            F64(f64)
+        );
+
+        Err(format!(
+            "arguments of type `{}` cannot be passed from the command line",
+            ty.name()
+        ))
+    }
+
+    /// Returns the `libffi` type with which the argument is passed to a function.
+    fn ffi_type(&self) -> middle::Type {
+        match self {
+            Argument::Bool(_) | Argument::U8(_) => middle::Type::u8(),
+            Argument::I8(_) => middle::Type::i8(),
+            Argument::I16(_) => middle::Type::i16(),
+            Argument::I32(_) => middle::Type::i32(),
+            Argument::I64(_) => middle::Type::i64(),
+            Argument::U16(_) => middle::Type::u16(),
+            Argument::U32(_) => middle::Type::u32(),
+            Argument::U64(_) => middle::Type::u64(),
+            Argument::F32(_) => middle::Type::f32(),
+            Argument::F64(_) => middle::Type::f64(),
+        }
+    }
+
+    /// Returns a `libffi` reference to the value of the argument.
+    fn as_ffi_arg(&self) -> Arg {
+        match self {
+            Argument::Bool(v) => middle::arg(v),
+            Argument::I8(v) => middle::arg(v),
+            Argument::I16(v) => middle::arg(v),
+            Argument::I32(v) => middle::arg(v),
+            Argument::I64(v) => middle::arg(v),
+            Argument::U8(v) => middle::arg(v),
+            Argument::U16(v) => middle::arg(v),
+            Argument::U32(v) => middle::arg(v),
+            Argument::U64(v) => middle::arg(v),
+            Argument::F32(v) => middle::arg(v),
+            Argument::F64(v) => middle::arg(v),
+        }
+    }
+}
+
+/// A Mun value that is returned from an entry point, read recursively from the memory of the
+/// runtime.
+#[derive(Clone)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Struct {
+        name: String,
+        fields: Vec<(String, Value)>,
+    },
+    Array(Vec<Value>),
+    /// A `nil` reference to a struct or an array
+    Nil,
+    /// A value of a type that cannot be displayed, e.g. an enum
+    Opaque(String),
+}
+
+impl Value {
+    /// Reads the fields of a struct.
+    fn from_struct(value: StructRef<'_>, runtime: &Runtime) -> Self {
+        let ty = value.type_info();
+        let struct_type = ty
+            .as_struct()
+            .expect("a struct reference must have a struct type");
+
+        // Safety: the memory of the struct is not garbage collected while it is being read
+        let data = unsafe { value.into_raw().get_ptr() };
+        let fields = struct_type
+            .fields()
+            .iter()
+            .map(|field| {
+                // Safety: the offset of a field always lies within the memory of its struct
+                let ptr = unsafe { NonNull::new_unchecked(data.add(field.offset()) as *mut u8) };
+                let ty = field.ty();
+                let field_value = visit(&field.ty(), FieldVisitor { ptr, ty, runtime });
+                (field.name().to_owned(), field_value)
+            })
+            .collect();
+
+        Value::Struct {
+            name: ty.name().to_owned(),
+            fields,
+        }
+    }
+
+    /// Reads the elements of an array.
+    fn from_array<'r>(value: ArrayRef<'r, u8>, runtime: &'r Runtime) -> Self {
+        let element_type = value
+            .type_info()
+            .as_array()
+            .expect("an array reference must have an array type")
+            .element_type();
+        visit(&element_type, ArrayVisitor { value, runtime })
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(v) => fmt::Display::fmt(v, f),
+            Value::I8(v) => fmt::Display::fmt(v, f),
+            Value::I16(v) => fmt::Display::fmt(v, f),
+            Value::I32(v) => fmt::Display::fmt(v, f),
+            Value::I64(v) => fmt::Display::fmt(v, f),
+            Value::I128(v) => fmt::Display::fmt(v, f),
+            Value::U8(v) => fmt::Display::fmt(v, f),
+            Value::U16(v) => fmt::Display::fmt(v, f),
+            Value::U32(v) => fmt::Display::fmt(v, f),
+            Value::U64(v) => fmt::Display::fmt(v, f),
+            Value::U128(v) => fmt::Display::fmt(v, f),
+            Value::F32(v) => fmt::Display::fmt(v, f),
+            Value::F64(v) => fmt::Display::fmt(v, f),
+            Value::String(v) => fmt::Display::fmt(v, f),
+            _ => write!(f, "{:#?}", self),
+        }
+    }
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Unit => f.write_str("()"),
+            Value::Bool(v) => fmt::Debug::fmt(v, f),
+            Value::I8(v) => fmt::Debug::fmt(v, f),
+            Value::I16(v) => fmt::Debug::fmt(v, f),
+            Value::I32(v) => fmt::Debug::fmt(v, f),
+            Value::I64(v) => fmt::Debug::fmt(v, f),
+            Value::I128(v) => fmt::Debug::fmt(v, f),
+            Value::U8(v) => fmt::Debug::fmt(v, f),
+            Value::U16(v) => fmt::Debug::fmt(v, f),
+            Value::U32(v) => fmt::Debug::fmt(v, f),
+            Value::U64(v) => fmt::Debug::fmt(v, f),
+            Value::U128(v) => fmt::Debug::fmt(v, f),
+            Value::F32(v) => fmt::Debug::fmt(v, f),
+            Value::F64(v) => fmt::Debug::fmt(v, f),
+            Value::String(v) => fmt::Debug::fmt(v, f),
+            Value::Struct { name, fields } => {
+                let mut debug_struct = f.debug_struct(name);
+                for (name, value) in fields {
+                    debug_struct.field(name, value);
+                }
+                debug_struct.finish()
+            }
+            Value::Array(elements) => f.debug_list().entries(elements).finish(),
+            Value::Nil => f.write_str("nil"),
+            Value::Opaque(type_name) => write!(f, "<{}>", type_name),
+        }
+    }
+}
+
+/// Invokes `function` with `arguments` and reads its return value. The types of the arguments
+/// must match the signature of the function.
+pub fn invoke(
+    runtime: &Runtime,
+    function: &FunctionDefinition,
+    arguments: &[Argument],
+) -> Result<Value, String> {
+    visit(
+        &function.prototype.signature.return_type,
+        ReturnVisitor {
+            runtime,
+            function,
+            arguments,
+        },
+    )
+}
+
+/// Returns the `libffi` type with which a value of type `ty` is returned from a function, or
+/// `None` if `libffi` cannot describe the type.
+fn ffi_return_type(ty: &Type) -> Option<middle::Type> {
+    macro_rules! ffi_primitives {
+        ($($ty:ty => $ffi_type:ident),+) => {
+            $(
+                if ty.equals::<$ty>() {
+                    return Some(middle::Type::$ffi_type());
+                }
+            )+
+        };
+    }
+
+    ffi_primitives!(
+        () => void,
+        bool => u8,
+        i8 => i8,
+        i16 => i16,
+        i32 => i32,
+        i64 => i64,
+        u8 => u8,
+        u16 => u16,
+        u32 => u32,
+        u64 => u64,
+        f32 => f32,
+        f64 => f64
+    );
+
+    if ty.equals::<i128>() || ty.equals::<u128>() {
+        None
+    } else {
+        // Strings, arrays and structs are returned as references to garbage collected memory
+        Some(middle::Type::pointer())
+    }
+}
+
+/// The memory in which `libffi` stores the return value of a function. Integers that are smaller
+/// than a register are stored as a full register.
+#[repr(C)]
+union ReturnSlot<T> {
+    value: ManuallyDrop<T>,
+    _register: u64,
+}
+
+/// Obtains a Mun value as the Rust type `T` that is selected based on its Mun type, and converts
+/// it into a [`Value`].
+trait Visitor<'r> {
+    type Output;
+
+    /// Obtains the Mun value as a `T` and converts it with `convert`.
+    fn visit<T: Marshal<'r> + 'r>(self, convert: fn(T, &'r Runtime) -> Value) -> Self::Output;
+
+    /// Handles a value of type `ty` that cannot be converted into a [`Value`].
+    fn unsupported(self, ty: &Type) -> Self::Output;
+}
+
+/// Selects the Rust type that represents a Mun value of type `ty`.
+fn visit<'r, V: Visitor<'r>>(ty: &Type, visitor: V) -> V::Output {
+    macro_rules! visit_primitives {
+        ($($variant:ident($ty:ty)),+) => {
+            $(
+                if ty.equals::<$ty>() {
+                    return visitor.visit::<$ty>(|v, _| Value::$variant(v));
+                }
+            )+
+        };
+    }
+
+    if ty.equals::<()>() {
+        return visitor.visit::<()>(|_, _| Value::Unit);
+    }
+
+    visit_primitives!(
+        Bool(bool),
+        I8(i8),
+        I16(i16),
+        I32(i32),
+        I64(i64),
+        I128(i128),
+        U8(u8),
+        U16(u16),
+        U32(u32),
+        U64(u64),
+        U128(u128),
+        F32(f32),
+        F64(f64)
+    );
+
+    // Strings are stored as arrays, so they have to be handled before arrays
+    if ty.equals::<str>() {
+        visitor.visit::<String>(|v, _| Value::String(v))
+    } else if let Some(struct_type) = ty.as_struct() {
+        if struct_type.is_gc_struct() {
+            visitor.visit::<Option<StructRef<'r>>>(|v, runtime| {
+                v.map_or(Value::Nil, |v| Value::from_struct(v, runtime))
+            })
+        } else {
+            visitor.visit::<StructRef<'r>>(Value::from_struct)
+        }
+    } else if ty.is_array() {
+        visitor.visit::<Option<ArrayRef<'r, u8>>>(|v, runtime| {
+            v.map_or(Value::Nil, |v| Value::from_array(v, runtime))
+        })
+    } else {
+        visitor.unsupported(ty)
+    }
+}
+
+/// Reads a value from a pointer into the memory of a struct.
+struct FieldVisitor<'r> {
+    ptr: NonNull<u8>,
+    ty: Type,
+    runtime: &'r Runtime,
+}
+
+impl<'r> Visitor<'r> for FieldVisitor<'r> {
+    type Output = Value;
+
+    fn visit<T: Marshal<'r> + 'r>(self, convert: fn(T, &'r Runtime) -> Value) -> Value {
+        let value = T::marshal_from_ptr(self.ptr.cast(), self.runtime, &self.ty);
+        convert(value, self.runtime)
+    }
+
+    fn unsupported(self, ty: &Type) -> Value {
+        Value::Opaque(ty.name().to_owned())
+    }
+}
+
+/// Reads all elements of an array.
+struct ArrayVisitor<'r> {
+    value: ArrayRef<'r, u8>,
+    runtime: &'r Runtime,
+}
+
+impl<'r> Visitor<'r> for ArrayVisitor<'r> {
+    type Output = Value;
+
+    fn visit<T: Marshal<'r> + 'r>(self, convert: fn(T, &'r Runtime) -> Value) -> Value {
+        let runtime = self.runtime;
+        let array = ArrayRef::<T>::marshal_from(self.value.into_raw(), runtime);
+        Value::Array(array.iter().map(|v| convert(v, runtime)).collect())
+    }
+
+    fn unsupported(self, ty: &Type) -> Value {
+        Value::Opaque(format!("[{}]", ty.name()))
+    }
+}
+
+/// Invokes a function and reads its return value.
+struct ReturnVisitor<'r> {
+    runtime: &'r Runtime,
+    function: &'r FunctionDefinition,
+    arguments: &'r [Argument],
+}
+
+impl<'r> Visitor<'r> for ReturnVisitor<'r> {
+    type Output = Result<Value, String>;
+
+    fn visit<T: Marshal<'r> + 'r>(
+        self,
+        convert: fn(T, &'r Runtime) -> Value,
+    ) -> Result<Value, String> {
+        let return_type = &self.function.prototype.signature.return_type;
+        let ffi_return_type =
+            ffi_return_type(return_type).ok_or_else(|| self.unsupported_message(return_type))?;
+        let cif = Cif::new(
+            self.arguments.iter().map(Argument::ffi_type),
+            ffi_return_type,
+        );
+        let arguments: Vec<Arg> = self.arguments.iter().map(Argument::as_ffi_arg).collect();
+
+        // Safety: the arguments were parsed from the types of the signature of the function and
+        // `T` was selected based on its return type.
+        let result: ReturnSlot<T::MunType> =
+            unsafe { cif.call(CodePtr::from_ptr(self.function.fn_ptr), &arguments) };
+
+        // A panic in Mun code is reported instead of the (meaningless) return value
+        if let Some(panic) = self.runtime.take_panic() {
+            return Err(panic.to_string());
+        }
+
+        // Safety: `libffi` stored a value of type `T::MunType` at the start of the slot
+        let result = ManuallyDrop::into_inner(unsafe { result.value });
+        Ok(convert(T::marshal_from(result, self.runtime), self.runtime))
+    }
+
+    fn unsupported(self, ty: &Type) -> Result<Value, String> {
+        Err(self.unsupported_message(ty))
+    }
+}
+
+impl ReturnVisitor<'_> {
+    /// Returns the error message for a return value of type `ty` that is not supported.
+    fn unsupported_message(&self, ty: &Type) -> String {
+        format!(
+            "Return values of type `{}` are not supported for entry points",
+            ty.name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Argument, Value};
+    use mun_runtime::HasStaticType;
+
+    #[test]
+    fn test_parse_argument() {
+        assert_eq!(
+            Argument::parse("-3", <i16 as HasStaticType>::type_info()),
+            Ok(Argument::I16(-3))
+        );
+        assert_eq!(
+            Argument::parse("true", <bool as HasStaticType>::type_info()),
+            Ok(Argument::Bool(true))
+        );
+        assert_eq!(
+            Argument::parse("1.5", <f32 as HasStaticType>::type_info()),
+            Ok(Argument::F32(1.5))
+        );
+        assert!(Argument::parse("256", <u8 as HasStaticType>::type_info()).is_err());
+        assert!(Argument::parse("1", <str as HasStaticType>::type_info()).is_err());
+        assert!(Argument::parse("1", <i128 as HasStaticType>::type_info()).is_err());
+    }
+
+    #[test]
+    fn test_display_value() {
+        let value = Value::Struct {
+            name: String::from("Foo"),
+            fields: vec![
+                (String::from("a"), Value::F64(1.0)),
+                (
+                    String::from("b"),
+                    Value::Array(vec![Value::Bool(true), Value::Nil]),
+                ),
+            ],
+        };
+        assert_eq!(
+            value.to_string(),
+            "Foo {\n    a: 1.0,\n    b: [\n        true,\n        nil,\n    ],\n}"
+        );
+        assert_eq!(Value::F64(1.5).to_string(), "1.5");
+        assert_eq!(Value::String(String::from("a")).to_string(), "a");
+    }
+}
@@ -0,0 +1,325 @@
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use mun_compiler::{Config, Driver, TestFunction};
+use mun_runtime::Runtime;
+use serde_derive::Serialize;
+
+use crate::ops::build::{display_color, locate_manifest, select_profile, UseColor};
+use crate::ExitStatus;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum TestFormat {
+    /// Human readable output
+    Pretty,
+    /// A JSON document that describes the results of all tests
+    Json,
+    /// A JUnit XML report
+    Junit,
+}
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Only run the tests whose name contains this string
+    filter: Option<String>,
+
+    /// Only run the test whose name exactly matches the filter
+    #[clap(long)]
+    exact: bool,
+
+    /// Path to the manifest of the project
+    #[clap(long)]
+    manifest_path: Option<PathBuf>,
+
+    /// Profile of the manifest whose settings are used [default: dev]
+    #[clap(long)]
+    profile: Option<String>,
+
+    /// Use color in output
+    #[clap(long, value_enum)]
+    color: Option<UseColor>,
+
+    /// Format of the test report that is written to stdout
+    #[clap(long, value_enum, default_value_t = TestFormat::Pretty)]
+    format: TestFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum TestOutcome {
+    Passed,
+    Failed,
+}
+
+/// The result of running a single test
+#[derive(Debug, Serialize)]
+struct TestResult {
+    name: String,
+    outcome: TestOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(serialize_with = "serialize_secs")]
+    duration: Duration,
+}
+
+/// The results of all tests that were run
+#[derive(Debug, Serialize)]
+struct TestReport {
+    package: String,
+    passed: usize,
+    failed: usize,
+    filtered_out: usize,
+    #[serde(serialize_with = "serialize_secs")]
+    duration: Duration,
+    tests: Vec<TestResult>,
+}
+
+/// Serializes a duration as a number of seconds
+fn serialize_secs<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Compiles the package, including the functions marked with `#[test]`, and invokes every test.
+/// A test fails if it panics.
+pub fn test(args: Args) -> anyhow::Result<ExitStatus> {
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+    log::info!("located test manifest at: {}", manifest_path.display());
+    let profile = select_profile(&manifest_path, args.profile.as_deref())?;
+
+    // Test assemblies export private test functions, so they are stored separately from the
+    // assemblies created by `mun build`.
+    let out_dir = manifest_path
+        .parent()
+        .expect("a manifest path always has a parent directory")
+        .join("target")
+        .join("test");
+    let config = Config {
+        out_dir: Some(out_dir),
+        overflow_checks: profile.overflow_checks,
+        export_tests: true,
+        ..Config::default()
+    };
+
+    let (package, mut driver) = Driver::with_package_path(&manifest_path, config)?;
+    if driver.emit_diagnostics(&mut io::stderr(), display_color(args.color))? {
+        return Ok(ExitStatus::Error);
+    }
+    driver.write_all_assemblies(false)?;
+
+    let tests = driver.test_functions();
+    let num_tests = tests.len();
+    let tests: Vec<TestFunction> = tests
+        .into_iter()
+        .filter(|test| match &args.filter {
+            Some(filter) if args.exact => test.name == *filter,
+            Some(filter) => test.name.contains(filter.as_str()),
+            None => true,
+        })
+        .collect();
+
+    if args.format == TestFormat::Pretty {
+        println!("\nrunning {} test{}", tests.len(), plural(tests.len()));
+    }
+
+    let start = Instant::now();
+    let mut results = Vec::with_capacity(tests.len());
+    for test in tests {
+        let result = run_test(test);
+        if args.format == TestFormat::Pretty {
+            let outcome = match result.outcome {
+                TestOutcome::Passed => "ok",
+                TestOutcome::Failed => "FAILED",
+            };
+            println!("test {} ... {}", result.name, outcome);
+        }
+        results.push(result);
+    }
+
+    let failed = results
+        .iter()
+        .filter(|result| result.outcome == TestOutcome::Failed)
+        .count();
+    let report = TestReport {
+        package: package.name().to_owned(),
+        passed: results.len() - failed,
+        failed,
+        filtered_out: num_tests - results.len(),
+        duration: start.elapsed(),
+        tests: results,
+    };
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    match args.format {
+        TestFormat::Pretty => write_pretty(&mut stdout, &report)?,
+        TestFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &report)?;
+            writeln!(stdout)?;
+        }
+        TestFormat::Junit => write_junit(&mut stdout, &report)?,
+    }
+
+    Ok((report.failed == 0).into())
+}
+
+/// Invokes a single test from a freshly loaded assembly, so the state of one test, e.g. the
+/// values of its statics, cannot affect another test.
+fn run_test(test: TestFunction) -> TestResult {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| invoke_test(&test)))
+        .unwrap_or_else(|payload| Err(panic_message(payload)));
+    let duration = start.elapsed();
+
+    match result {
+        Ok(()) => TestResult {
+            name: test.name,
+            outcome: TestOutcome::Passed,
+            message: None,
+            duration,
+        },
+        Err(message) => TestResult {
+            name: test.name,
+            outcome: TestOutcome::Failed,
+            message: Some(message),
+            duration,
+        },
+    }
+}
+
+fn invoke_test(test: &TestFunction) -> Result<(), String> {
+    let builder = Runtime::builder(&test.assembly_path);
+
+    // Safety: the assembly was compiled from the sources of the package
+    let runtime = unsafe { builder.finish() }
+        .map_err(|e| format!("failed to load '{}': {}", test.assembly_path.display(), e))?;
+
+    let result: Result<(), _> = runtime.invoke(&test.name, ());
+    result.map_err(|e| e.to_string())
+}
+
+/// Extracts the message of a Rust panic that occurred while running a test.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("the test runner panicked")
+    }
+}
+
+fn write_pretty(writer: &mut dyn Write, report: &TestReport) -> io::Result<()> {
+    let failures: Vec<&TestResult> = report
+        .tests
+        .iter()
+        .filter(|result| result.outcome == TestOutcome::Failed)
+        .collect();
+
+    if !failures.is_empty() {
+        writeln!(writer, "\nfailures:\n")?;
+        for failure in failures.iter() {
+            writeln!(writer, "---- {} ----", failure.name)?;
+            if let Some(message) = &failure.message {
+                writeln!(writer, "{}", message)?;
+            }
+            writeln!(writer)?;
+        }
+
+        writeln!(writer, "failures:")?;
+        for failure in failures.iter() {
+            writeln!(writer, "    {}", failure.name)?;
+        }
+    }
+
+    writeln!(
+        writer,
+        "\ntest result: {}. {} passed; {} failed; {} filtered out; finished in {:.2}s\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        report.passed,
+        report.failed,
+        report.filtered_out,
+        report.duration.as_secs_f64()
+    )
+}
+
+fn write_junit(writer: &mut dyn Write, report: &TestReport) -> io::Result<()> {
+    let package = escape_xml(&report.package);
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, "<testsuites>")?;
+    writeln!(
+        writer,
+        r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="0" time="{:.6}">"#,
+        package,
+        report.tests.len(),
+        report.failed,
+        report.duration.as_secs_f64()
+    )?;
+    for result in report.tests.iter() {
+        write!(
+            writer,
+            r#"    <testcase name="{}" classname="{}" time="{:.6}""#,
+            escape_xml(&result.name),
+            package,
+            result.duration.as_secs_f64()
+        )?;
+        match &result.message {
+            Some(message) if result.outcome == TestOutcome::Failed => {
+                writeln!(writer, ">")?;
+                writeln!(
+                    writer,
+                    r#"      <failure message="{}"/>"#,
+                    escape_xml(message)
+                )?;
+                writeln!(writer, "    </testcase>")?;
+            }
+            _ => writeln!(writer, "/>")?,
+        }
+    }
+    writeln!(writer, "  </testsuite>")?;
+    writeln!(writer, "</testsuites>")
+}
+
+/// Escapes the characters that are not allowed in XML attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::escape_xml;
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(
+            escape_xml(r#"<a href="x">'b' & c</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&apos;b&apos; &amp; c&lt;/a&gt;"
+        );
+        assert_eq!(escape_xml("line\nbreak"), "line&#10;break");
+    }
+}
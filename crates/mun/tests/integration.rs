@@ -53,6 +53,279 @@ fn mun_emit_ir() {
     assert!(ir_path.is_file());
 }
 
+/// Verifies that `mun check` reports errors without writing any assemblies.
+#[test]
+fn mun_check() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+
+    let manifest_path = project_path.join("mun.toml");
+    let check = |message_format: &str| {
+        let args: Vec<OsString> = vec![
+            "mun".into(),
+            "check".into(),
+            "--manifest-path".into(),
+            manifest_path.as_path().into(),
+            "--message-format".into(),
+            message_format.into(),
+        ];
+        run_with_args(args).unwrap()
+    };
+
+    assert_eq!(check("human"), mun::ExitStatus::Success);
+    assert_eq!(check("json"), mun::ExitStatus::Success);
+    assert!(!project_path.join("target/mod.munlib").exists());
+
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        "pub fn main() -> f64 { true }",
+    )
+    .unwrap();
+    assert_eq!(check("human"), mun::ExitStatus::Error);
+    assert_eq!(check("json"), mun::ExitStatus::Error);
+    assert!(!project_path.join("target/mod.munlib").exists());
+}
+
+/// Verifies that `mun run` compiles a project and invokes its entry point.
+#[test]
+fn mun_run() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+
+    let manifest_path = project_path.join("mun.toml");
+    let run = |extra_args: &[&str]| {
+        let args: Vec<OsString> = vec![
+            OsString::from("mun"),
+            OsString::from("run"),
+            OsString::from("--manifest-path"),
+            OsString::from(&manifest_path),
+        ]
+        .into_iter()
+        .chain(extra_args.iter().map(|&arg| arg.into()))
+        .collect();
+        run_with_args(args)
+    };
+
+    assert_eq!(run(&[]).unwrap(), mun::ExitStatus::Success);
+    assert!(project_path.join("target/mod.munlib").is_file());
+    assert!(run(&["unknown"]).is_err());
+    assert!(run(&["main", "--", "1"]).is_err());
+
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        "pub fn main() -> f64 { true }",
+    )
+    .unwrap();
+    assert_eq!(run(&[]).unwrap(), mun::ExitStatus::Error);
+}
+
+/// Verifies that `mun start` passes any number of arguments to the entry point and supports
+/// struct and array return values.
+#[test]
+fn mun_start_arguments() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        r#"
+        pub struct Vec2 { x: f32, y: f32 }
+        pub struct(value) Bounds { min: i8, max: u64 }
+
+        pub fn new_vec2(x: f32, y: f32) -> Vec2 { Vec2 { x: x, y: y } }
+        pub fn bounds(min: i8, max: u64) -> [Bounds] { [Bounds { min: min, max: max }] }
+        pub fn is_positive(value: i16) -> bool { value > 0 }
+        pub fn select(a: i32, b: f64, c: bool, d: u8, e: f32, f: u32) -> f64 {
+            if c && d > 0 && e > 0.0 && f > 0 && a > 0 { b } else { 0.0 }
+        }
+        "#,
+    )
+    .unwrap();
+    build(&project_path, &[]);
+
+    let library_path = project_path.join("target/mod.munlib");
+    let start = |args: &[&str]| {
+        let args: Vec<OsString> = vec![OsString::from("mun"), OsString::from("start")]
+            .into_iter()
+            .chain(std::iter::once(OsString::from(&library_path)))
+            .chain(args.iter().map(|&arg| arg.into()))
+            .collect();
+        run_with_args(args)
+    };
+
+    assert_eq!(
+        start(&["new_vec2", "--", "1.5", "-2"]).unwrap(),
+        mun::ExitStatus::Success
+    );
+    assert_eq!(
+        start(&["bounds", "--", "-1", "10"]).unwrap(),
+        mun::ExitStatus::Success
+    );
+    assert_eq!(
+        start(&["is_positive", "--", "3"]).unwrap(),
+        mun::ExitStatus::Success
+    );
+    assert_eq!(
+        start(&["select", "--", "1", "2.5", "true", "4", "5.5", "6"]).unwrap(),
+        mun::ExitStatus::Success
+    );
+    assert!(start(&["is_positive"]).is_err());
+    assert!(start(&["is_positive", "--", "70000"]).is_err());
+    assert!(start(&["new_vec2", "--", "1.5", "true"]).is_err());
+}
+
+/// Verifies that integer overflow panics if overflow checks are enabled from the command line or
+/// in a profile of the manifest.
+#[test]
+fn mun_overflow_checks() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        "pub fn add(a: u8, b: u8) -> u8 { a + b }",
+    )
+    .unwrap();
+
+    build(&project_path, &[]);
+    assert_eq!(invoke_add(&project_path), Ok(0));
+
+    build(&project_path, &["--overflow-checks"]);
+    assert_eq!(
+        invoke_add(&project_path),
+        Err(String::from("attempt to add with overflow"))
+    );
+
+    let manifest_path = project_path.join("mun.toml");
+    let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+    std::fs::write(
+        &manifest_path,
+        format!("{manifest}\n[profile.dev]\noverflow-checks = true\n\n[profile.release]\n"),
+    )
+    .unwrap();
+
+    build(&project_path, &[]);
+    assert_eq!(
+        invoke_add(&project_path),
+        Err(String::from("attempt to add with overflow"))
+    );
+
+    build(&project_path, &["--profile", "release"]);
+    assert_eq!(invoke_add(&project_path), Ok(0));
+
+    let args: Vec<OsString> = vec![
+        "mun".into(),
+        "build".into(),
+        "--manifest-path".into(),
+        manifest_path.into(),
+        "--profile".into(),
+        "unknown".into(),
+    ];
+    assert!(run_with_args(args).is_err());
+}
+
+/// Verifies that `mun test` runs the functions marked with `#[test]` and reports failures.
+#[test]
+fn mun_test() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        r#"
+        pub fn div(a: i32, b: i32) -> i32 { a / b }
+
+        #[test]
+        fn div_passes() { div(4, 2); }
+
+        #[test]
+        pub fn div_by_zero_fails() { div(4, 0); }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(test(&project_path, &[]), mun::ExitStatus::Error);
+    assert_eq!(test(&project_path, &["passes"]), mun::ExitStatus::Success);
+    assert_eq!(
+        test(&project_path, &["div_passes", "--exact"]),
+        mun::ExitStatus::Success
+    );
+    assert_eq!(
+        test(&project_path, &["--format", "json"]),
+        mun::ExitStatus::Error
+    );
+    assert_eq!(
+        test(&project_path, &["--format", "junit"]),
+        mun::ExitStatus::Error
+    );
+
+    // Test functions are not exported by a regular build
+    build(&project_path, &[]);
+    let library_path = project_path.join("target/mod.munlib");
+    let builder = Runtime::builder(&library_path);
+    let runtime = unsafe { builder.finish() }.unwrap();
+    let result: Result<(), _> = runtime.invoke("div_passes", ());
+    assert!(result.is_err());
+}
+
+fn test(project: &Path, args: &[&str]) -> mun::ExitStatus {
+    let args: Vec<OsString> = vec![
+        OsString::from("mun"),
+        OsString::from("test"),
+        OsString::from("--manifest-path"),
+        OsString::from(project.join("mun.toml")),
+    ]
+    .into_iter()
+    .chain(args.iter().map(|&arg| arg.into()))
+    .collect();
+    run_with_args(args).unwrap()
+}
+
+/// Invokes the `add` function of a built project with arguments that overflow a `u8`, returning
+/// the panic message if the function panicked.
+fn invoke_add(project: &Path) -> Result<u8, String> {
+    let library_path = project.join("target/mod.munlib");
+
+    // Safety: since we compiled the code ourselves, loading the library should be safe
+    let builder = Runtime::builder(&library_path);
+    let runtime = unsafe { builder.finish() }.unwrap();
+    runtime
+        .invoke("add", (255u8, 1u8))
+        .map_err(|err| err.message().to_owned())
+}
+
 fn build(project: &Path, args: &[&str]) {
     let args: Vec<OsString> = vec![
         OsString::from("mun"),
@@ -1,6 +1,6 @@
 use std::{ffi::CStr, os::raw::c_char, slice, str};
 
-use crate::{DispatchTable, ModuleInfo, TypeLut};
+use crate::{DispatchTable, GlobalLut, ModuleInfo, TypeLut};
 
 /// Represents an assembly declaration.
 #[repr(C)]
@@ -11,6 +11,8 @@ pub struct AssemblyInfo<'a> {
     pub dispatch_table: DispatchTable<'a>,
     /// Type lookup table
     pub type_lut: TypeLut<'a>,
+    /// Global lookup table
+    pub global_lut: GlobalLut<'a>,
     /// Paths to assembly dependencies
     pub(crate) dependencies: *const *const c_char,
     /// Number of dependencies
@@ -44,10 +46,11 @@ impl<'a> serde::Serialize for AssemblyInfo<'a> {
         use itertools::Itertools;
         use serde::ser::SerializeStruct;
 
-        let mut s = serializer.serialize_struct("AssemblyInfo", 4)?;
+        let mut s = serializer.serialize_struct("AssemblyInfo", 5)?;
         s.serialize_field("symbols", &self.symbols)?;
         s.serialize_field("dispatch_table", &self.dispatch_table)?;
         s.serialize_field("type_lut", &self.type_lut)?;
+        s.serialize_field("global_lut", &self.global_lut)?;
         s.serialize_field("dependencies", &self.dependencies().collect_vec())?;
         s.end()
     }
@@ -58,21 +61,23 @@ mod tests {
     use std::ffi::CString;
 
     use crate::test_utils::{
-        fake_assembly_info, fake_dispatch_table, fake_module_info, fake_type_lut, FAKE_DEPENDENCY,
-        FAKE_MODULE_PATH,
+        fake_assembly_info, fake_dispatch_table, fake_global_lut, fake_module_info, fake_type_lut,
+        FAKE_DEPENDENCY, FAKE_MODULE_PATH,
     };
 
     #[test]
     fn test_assembly_info_dependencies() {
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, &[], &[]);
+        let module = fake_module_info(&module_path, &[], &[], &[]);
 
         let dispatch_table = fake_dispatch_table(&[], &mut []);
         let type_lut = fake_type_lut(&[], &mut [], &[]);
+        let global_lut = fake_global_lut(&[], &[], &mut []);
 
         let dependency = CString::new(FAKE_DEPENDENCY).expect("Invalid fake dependency.");
         let dependencies = &[dependency.as_ptr()];
-        let assembly = fake_assembly_info(module, dispatch_table, type_lut, dependencies);
+        let assembly =
+            fake_assembly_info(module, dispatch_table, type_lut, global_lut, dependencies);
 
         assert_eq!(assembly.dependencies().count(), dependencies.len());
         for (lhs, rhs) in assembly.dependencies().zip([FAKE_DEPENDENCY].iter()) {
@@ -0,0 +1,219 @@
+use std::{ffi::CStr, os::raw::c_char, slice, str};
+
+use crate::type_id::TypeId;
+use crate::Guid;
+
+/// Represents an enum declaration.
+///
+/// An enum is laid out in memory as a tag that identifies the active variant, followed by a
+/// payload that is large enough to hold the fields of any of its variants. Enums are always value
+/// types.
+#[repr(C)]
+#[derive(Debug)]
+pub struct EnumDefinition<'a> {
+    /// The unique identifier of this enum
+    pub guid: Guid,
+    /// Enum variants' information
+    pub(crate) variants: *const VariantDefinition<'a>,
+    /// Number of variants
+    pub(crate) num_variants: u16,
+    /// The size of the tag in bytes
+    pub tag_size: u8,
+}
+
+/// Represents a single variant of an enum declaration.
+#[repr(C)]
+#[derive(Debug)]
+pub struct VariantDefinition<'a> {
+    /// Variant name
+    pub(crate) name: *const c_char,
+    /// Variant fields' names
+    pub(crate) field_names: *const *const c_char,
+    /// Variant fields' information
+    pub(crate) field_types: *const TypeId<'a>,
+    /// Variant fields' offsets, relative to the start of the enum
+    pub(crate) field_offsets: *const u16,
+    /// Number of fields
+    pub(crate) num_fields: u16,
+}
+
+impl<'a> EnumDefinition<'a> {
+    /// Returns the enum's variants.
+    pub fn variants(&self) -> &[VariantDefinition<'a>] {
+        if self.num_variants == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.variants, self.num_variants as usize) }
+        }
+    }
+
+    /// Returns the number of enum variants.
+    pub fn num_variants(&self) -> usize {
+        self.num_variants.into()
+    }
+}
+
+impl<'a> VariantDefinition<'a> {
+    /// Returns the variant's name.
+    pub fn name(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(CStr::from_ptr(self.name).to_bytes()) }
+    }
+
+    /// Returns the variant's field names.
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        let field_names = if self.num_fields == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.field_names, self.num_fields as usize) }
+        };
+
+        field_names
+            .iter()
+            .map(|n| unsafe { str::from_utf8_unchecked(CStr::from_ptr(*n).to_bytes()) })
+    }
+
+    /// Returns the variant's field types.
+    pub fn field_types(&self) -> &[TypeId<'a>] {
+        if self.num_fields == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.field_types, self.num_fields as usize) }
+        }
+    }
+
+    /// Returns the variant's field offsets.
+    pub fn field_offsets(&self) -> &[u16] {
+        if self.num_fields == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.field_offsets, self.num_fields as usize) }
+        }
+    }
+
+    /// Returns the number of variant fields.
+    pub fn num_fields(&self) -> usize {
+        self.num_fields.into()
+    }
+}
+
+impl<'a> PartialEq for EnumDefinition<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.guid == other.guid
+    }
+}
+
+impl<'a> Eq for EnumDefinition<'a> {}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for EnumDefinition<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("EnumInfo", 3)?;
+        s.serialize_field("guid", &self.guid)?;
+        s.serialize_field("variants", self.variants())?;
+        s.serialize_field("tag_size", &self.tag_size)?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for VariantDefinition<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use itertools::Itertools;
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("VariantInfo", 2)?;
+
+        #[derive(serde::Serialize)]
+        struct Field<'a> {
+            name: &'a str,
+            r#type: &'a TypeId<'a>,
+            offset: &'a u16,
+        }
+
+        s.serialize_field("name", self.name())?;
+        s.serialize_field(
+            "fields",
+            &self
+                .field_names()
+                .zip(self.field_types())
+                .zip(self.field_offsets())
+                .map(|((name, ty), offset)| Field {
+                    name,
+                    r#type: ty,
+                    offset,
+                })
+                .collect_vec(),
+        )?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::type_id::HasStaticTypeId;
+    use std::ffi::CString;
+
+    use crate::test_utils::{
+        fake_enum_definition, fake_variant_definition, FAKE_ENUM_NAME, FAKE_FIELD_NAME,
+        FAKE_VARIANT_NAME,
+    };
+
+    #[test]
+    fn test_enum_info_variants_none() {
+        let enum_name = CString::new(FAKE_ENUM_NAME).expect("Invalid fake enum name.");
+        let enum_info = fake_enum_definition(&enum_name, &[], 1);
+
+        assert_eq!(enum_info.num_variants(), 0);
+        assert!(enum_info.variants().is_empty());
+        assert_eq!(enum_info.tag_size, 1);
+    }
+
+    #[test]
+    fn test_enum_info_variants_some() {
+        let enum_name = CString::new(FAKE_ENUM_NAME).expect("Invalid fake enum name.");
+        let variant_name = CString::new(FAKE_VARIANT_NAME).expect("Invalid fake variant name.");
+        let field_name = CString::new(FAKE_FIELD_NAME).expect("Invalid fake field name.");
+        let type_id = i32::type_id();
+
+        let field_names = &[field_name.as_ptr()];
+        let field_types = &[type_id.clone()];
+        let field_offsets = &[4];
+        let variants = &[fake_variant_definition(
+            &variant_name,
+            field_names,
+            field_types,
+            field_offsets,
+        )];
+        let enum_info = fake_enum_definition(&enum_name, variants, 1);
+
+        assert_eq!(enum_info.num_variants(), 1);
+        let variant = &enum_info.variants()[0];
+        assert_eq!(variant.name(), FAKE_VARIANT_NAME);
+        assert_eq!(variant.num_fields(), 1);
+        for (lhs, rhs) in variant.field_names().zip([FAKE_FIELD_NAME].iter()) {
+            assert_eq!(lhs, *rhs)
+        }
+        assert_eq!(variant.field_types(), field_types);
+        assert_eq!(variant.field_offsets(), field_offsets);
+    }
+
+    #[test]
+    fn test_variant_info_fields_none() {
+        let variant_name = CString::new(FAKE_VARIANT_NAME).expect("Invalid fake variant name.");
+        let variant = fake_variant_definition(&variant_name, &[], &[], &[]);
+
+        assert_eq!(variant.name(), FAKE_VARIANT_NAME);
+        assert_eq!(variant.num_fields(), 0);
+        assert_eq!(variant.field_names().count(), 0);
+        assert!(variant.field_types().is_empty());
+        assert!(variant.field_offsets().is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    ffi::{c_void, CStr},
+    os::raw::c_char,
+    str,
+};
+
+use crate::type_id::TypeId;
+
+/// Represents a global variable definition. A global definition contains the name and type of a
+/// `static` item and a pointer to its memory in the data section of the assembly.
+#[repr(C)]
+#[derive(Clone)]
+pub struct GlobalDefinition<'a> {
+    /// Global name
+    pub(crate) name: *const c_char,
+    /// The type of the global
+    pub type_id: TypeId<'a>,
+    /// Pointer to the memory of the global
+    pub ptr: *mut c_void,
+    /// Whether the global can be modified
+    pub mutable: bool,
+}
+
+impl<'a> GlobalDefinition<'a> {
+    /// Returns the global's name.
+    pub fn name(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(CStr::from_ptr(self.name).to_bytes()) }
+    }
+}
+
+unsafe impl<'a> Send for GlobalDefinition<'a> {}
+unsafe impl<'a> Sync for GlobalDefinition<'a> {}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for GlobalDefinition<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("GlobalDefinition", 3)?;
+        s.serialize_field("name", self.name())?;
+        s.serialize_field("type_id", &self.type_id)?;
+        s.serialize_field("mutable", &self.mutable)?;
+        s.skip_field("ptr")?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr};
+
+    use crate::test_utils::{fake_global_definition, FAKE_GLOBAL_NAME};
+    use crate::type_id::HasStaticTypeId;
+
+    #[test]
+    fn test_global_definition_name() {
+        let name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+        let global = fake_global_definition(&name, i32::type_id().clone(), ptr::null_mut(), true);
+
+        assert_eq!(global.name(), FAKE_GLOBAL_NAME);
+        assert_eq!(&global.type_id, i32::type_id());
+        assert!(global.mutable);
+    }
+}
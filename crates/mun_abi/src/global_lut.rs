@@ -0,0 +1,151 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::{ffi, slice, str};
+
+use itertools::izip;
+
+use crate::type_id::TypeId;
+
+/// Represents a lookup table for global variables that are defined in other assemblies. This is
+/// used for runtime linking.
+///
+/// Global names, type IDs, and pointers are stored separately for cache efficiency.
+#[repr(C)]
+pub struct GlobalLut<'a> {
+    /// Global names
+    pub(crate) global_names: *const *const c_char,
+    /// Global type IDs
+    pub(crate) type_ids: *const TypeId<'a>,
+    /// Global pointers
+    pub(crate) global_ptrs: *mut *mut ffi::c_void,
+    /// Number of globals
+    pub num_entries: u32,
+}
+
+impl<'a> GlobalLut<'a> {
+    /// Returns an iterator over global type IDs, pointers, and names.
+    pub fn iter(&self) -> impl Iterator<Item = (&TypeId, &*mut ffi::c_void, &str)> {
+        let (type_ids, global_ptrs, global_names) = if self.num_entries != 0 {
+            let ptrs =
+                unsafe { slice::from_raw_parts(self.global_ptrs, self.num_entries as usize) };
+            let type_ids =
+                unsafe { slice::from_raw_parts(self.type_ids, self.num_entries as usize) };
+            let global_names =
+                unsafe { slice::from_raw_parts(self.global_names, self.num_entries as usize) };
+
+            (type_ids.iter(), ptrs.iter(), global_names.iter())
+        } else {
+            (([]).iter(), ([]).iter(), ([]).iter())
+        };
+
+        izip!(type_ids, global_ptrs, global_names).map(|(id, ptr, name)| {
+            (id, ptr, unsafe {
+                std::str::from_utf8_unchecked(CStr::from_ptr(*name).to_bytes())
+            })
+        })
+    }
+
+    /// Returns an iterator over global type IDs, mutable pointers, and names.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&TypeId, &mut *mut ffi::c_void, &str)> {
+        let (type_ids, global_ptrs, global_names) = if self.num_entries != 0 {
+            let ptrs =
+                unsafe { slice::from_raw_parts_mut(self.global_ptrs, self.num_entries as usize) };
+            let type_ids =
+                unsafe { slice::from_raw_parts(self.type_ids, self.num_entries as usize) };
+            let global_names =
+                unsafe { slice::from_raw_parts(self.global_names, self.num_entries as usize) };
+
+            (type_ids.iter(), ptrs.iter_mut(), global_names.iter())
+        } else {
+            (([]).iter(), ([]).iter_mut(), ([]).iter())
+        };
+
+        izip!(type_ids, global_ptrs, global_names).map(|(id, ptr, name)| {
+            (id, ptr, unsafe {
+                std::str::from_utf8_unchecked(CStr::from_ptr(*name).to_bytes())
+            })
+        })
+    }
+
+    /// Returns global names.
+    pub fn global_names(&self) -> impl Iterator<Item = &str> {
+        let global_names = if self.num_entries == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.global_names, self.num_entries as usize) }
+        };
+
+        global_names
+            .iter()
+            .map(|n| unsafe { str::from_utf8_unchecked(CStr::from_ptr(*n).to_bytes()) })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for GlobalLut<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut s = serializer.serialize_seq(Some(self.num_entries as usize))?;
+        for (ty, _, name) in self.iter() {
+            #[derive(serde::Serialize)]
+            struct Elem<'a> {
+                name: &'a str,
+                r#type: &'a TypeId<'a>,
+            }
+            s.serialize_element(&Elem { name, r#type: ty })?;
+        }
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr};
+
+    use crate::test_utils::{fake_global_lut, FAKE_GLOBAL_NAME, FAKE_TYPE_ID};
+
+    #[test]
+    fn test_global_lut_iter_none() {
+        let global_lut = fake_global_lut(&[], &[], &mut []);
+
+        assert_eq!(global_lut.iter().count(), 0);
+        assert_eq!(global_lut.global_names().count(), 0);
+    }
+
+    #[test]
+    fn test_global_lut_iter_mut_some() {
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+
+        let global_names = &[global_name.as_ptr()];
+        let type_ids = &[FAKE_TYPE_ID];
+        let global_ptrs = &mut [ptr::null_mut()];
+        let mut global_lut = fake_global_lut(global_names, type_ids, global_ptrs);
+
+        let mut value = 0u32;
+        for (type_id, ptr, name) in global_lut.iter_mut() {
+            assert_eq!(type_id, &FAKE_TYPE_ID);
+            assert_eq!(name, FAKE_GLOBAL_NAME);
+            *ptr = (&mut value as *mut u32).cast();
+        }
+
+        assert_eq!(global_ptrs[0], (&mut value as *mut u32).cast());
+    }
+
+    #[test]
+    fn test_global_lut_global_names_some() {
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+
+        let global_names = &[global_name.as_ptr()];
+        let type_ids = &[FAKE_TYPE_ID];
+        let global_ptrs = &mut [ptr::null_mut()];
+        let global_lut = fake_global_lut(global_names, type_ids, global_ptrs);
+
+        for (lhs, rhs) in global_lut.global_names().zip([FAKE_GLOBAL_NAME].iter()) {
+            assert_eq!(lhs, *rhs)
+        }
+    }
+}
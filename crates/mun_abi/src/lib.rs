@@ -9,19 +9,25 @@ use std::fmt;
 
 pub use assembly_info::AssemblyInfo;
 pub use dispatch_table::DispatchTable;
+pub use enum_info::{EnumDefinition, VariantDefinition};
 pub use function_info::{FunctionDefinition, FunctionPrototype, FunctionSignature};
+pub use global_info::GlobalDefinition;
+pub use global_lut::GlobalLut;
 pub use module_info::ModuleInfo;
 pub use primitive::PrimitiveType;
 pub use struct_info::{StructDefinition, StructMemoryKind};
 pub use type_id::HasStaticTypeId;
-pub use type_id::{ArrayTypeId, PointerTypeId, TypeId};
+pub use type_id::{ArrayTypeId, OptionalTypeId, PointerTypeId, TypeId};
 pub use type_info::{HasStaticTypeName, TypeDefinition, TypeDefinitionData};
 pub use type_lut::TypeLut;
 
 // C bindings can be manually generated by running `cargo gen-abi`.
 mod assembly_info;
 mod dispatch_table;
+mod enum_info;
 mod function_info;
+mod global_info;
+mod global_lut;
 mod module_info;
 mod primitive;
 pub mod static_type_map;
@@ -35,13 +41,18 @@ mod test_utils;
 
 /// Defines the current ABI version
 #[allow(clippy::zero_prefixed_literal)]
-pub const ABI_VERSION: u32 = 00_03_00;
+pub const ABI_VERSION: u32 = 00_05_00;
 /// Defines the name for the `get_info` function
 pub const GET_INFO_FN_NAME: &str = "get_info";
 /// Defines the name for the `get_version` function
 pub const GET_VERSION_FN_NAME: &str = "get_version";
 /// Defines the name for the `set_allocator_handle` function
 pub const SET_ALLOCATOR_HANDLE_FN_NAME: &str = "set_allocator_handle";
+/// Defines the name for the `set_panic_handle` function
+pub const SET_PANIC_HANDLE_FN_NAME: &str = "set_panic_handle";
+/// Defines the name for the function that function values call when their function was removed
+/// by hot reloading
+pub const FN_VALUE_TRAP_FN_NAME: &str = "{fn_value_trap}";
 
 /// Represents a globally unique identifier (GUID).
 #[repr(C)]
@@ -1,6 +1,6 @@
 use std::{ffi::CStr, os::raw::c_char, slice, str};
 
-use crate::{FunctionDefinition, TypeDefinition};
+use crate::{FunctionDefinition, GlobalDefinition, TypeDefinition};
 
 /// Represents a module declaration.
 #[repr(C)]
@@ -11,10 +11,14 @@ pub struct ModuleInfo<'a> {
     pub(crate) functions: *const FunctionDefinition<'a>,
     /// Module types
     pub(crate) types: *const TypeDefinition<'a>,
+    /// Module globals
+    pub(crate) globals: *const GlobalDefinition<'a>,
     /// Number of module functions
     pub num_functions: u32,
     /// Number of module types
     pub num_types: u32,
+    /// Number of module globals
+    pub num_globals: u32,
 }
 
 impl<'a> ModuleInfo<'a> {
@@ -55,6 +59,15 @@ impl<'a> ModuleInfo<'a> {
             unsafe { slice::from_raw_parts(self.types, self.num_types as usize) }
         }
     }
+
+    /// Returns the module's globals.
+    pub fn globals(&self) -> &[GlobalDefinition<'a>] {
+        if self.num_globals == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.globals, self.num_globals as usize) }
+        }
+    }
 }
 
 unsafe impl<'a> Send for ModuleInfo<'a> {}
@@ -68,10 +81,11 @@ impl<'a> serde::Serialize for ModuleInfo<'a> {
     {
         use serde::ser::SerializeStruct;
 
-        let mut s = serializer.serialize_struct("ModuleInfo", 3)?;
+        let mut s = serializer.serialize_struct("ModuleInfo", 4)?;
         s.serialize_field("path", self.path())?;
         s.serialize_field("functions", self.functions())?;
         s.serialize_field("types", self.types())?;
+        s.serialize_field("globals", self.globals())?;
         s.end()
     }
 }
@@ -83,8 +97,9 @@ mod tests {
     use crate::type_id::HasStaticTypeId;
     use crate::{
         test_utils::{
-            fake_fn_prototype, fake_module_info, fake_struct_definition, fake_type_definition,
-            FAKE_FN_NAME, FAKE_MODULE_PATH, FAKE_STRUCT_NAME,
+            fake_fn_prototype, fake_global_definition, fake_module_info, fake_struct_definition,
+            fake_type_definition, FAKE_FN_NAME, FAKE_GLOBAL_NAME, FAKE_MODULE_PATH,
+            FAKE_STRUCT_NAME,
         },
         FunctionDefinition, TypeDefinition, TypeDefinitionData,
     };
@@ -92,7 +107,7 @@ mod tests {
     #[test]
     fn test_module_info_path() {
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, &[], &[]);
+        let module = fake_module_info(&module_path, &[], &[], &[]);
 
         assert_eq!(module.path(), FAKE_MODULE_PATH);
     }
@@ -102,7 +117,7 @@ mod tests {
         let functions = &[];
         let types = &[];
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, types);
+        let module = fake_module_info(&module_path, functions, types, &[]);
 
         assert_eq!(module.functions().len(), functions.len());
         assert_eq!(module.types().len(), types.len());
@@ -127,7 +142,7 @@ mod tests {
         let types = [type_info];
 
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, &types);
+        let module = fake_module_info(&module_path, functions, &types, &[]);
 
         let result_functions = module.functions();
         assert_eq!(result_functions.len(), functions.len());
@@ -150,9 +165,34 @@ mod tests {
             assert_eq!(lhs, rhs);
             assert_eq!(lhs.name(), rhs.name());
             assert_eq!(lhs.data.is_struct(), rhs.data.is_struct());
-            let TypeDefinitionData::Struct(lhs) = &lhs.data;
-            let TypeDefinitionData::Struct(rhs) = &rhs.data;
-            assert_eq!(lhs.field_types(), rhs.field_types());
+            if let (TypeDefinitionData::Struct(lhs), TypeDefinitionData::Struct(rhs)) =
+                (&lhs.data, &rhs.data)
+            {
+                assert_eq!(lhs.field_types(), rhs.field_types());
+            }
+        }
+    }
+
+    #[test]
+    fn test_module_info_globals_some() {
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+        let globals = &[fake_global_definition(
+            &global_name,
+            i32::type_id().clone(),
+            ptr::null_mut(),
+            false,
+        )];
+
+        let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
+        let module = fake_module_info(&module_path, &[], &[], globals);
+
+        let result_globals = module.globals();
+        assert_eq!(result_globals.len(), globals.len());
+        for (lhs, rhs) in result_globals.iter().zip(globals.iter()) {
+            assert_eq!(lhs.name(), rhs.name());
+            assert_eq!(lhs.type_id, rhs.type_id);
+            assert_eq!(lhs.ptr, rhs.ptr);
+            assert_eq!(lhs.mutable, rhs.mutable);
         }
     }
 }
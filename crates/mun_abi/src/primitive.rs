@@ -50,7 +50,8 @@ define_primitives! {
     f64 => "core::f64",
     bool => "core::bool",
     () => "core::empty",
-    std::ffi::c_void => "core::void"
+    std::ffi::c_void => "core::void",
+    str => "core::string"
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -1,8 +1,9 @@
 use crate::type_id::HasStaticTypeId;
 use crate::type_id::TypeId;
 use crate::{
-    AssemblyInfo, DispatchTable, FunctionDefinition, FunctionPrototype, FunctionSignature, Guid,
-    ModuleInfo, StructDefinition, StructMemoryKind, TypeDefinition, TypeDefinitionData, TypeLut,
+    AssemblyInfo, DispatchTable, EnumDefinition, FunctionDefinition, FunctionPrototype,
+    FunctionSignature, GlobalDefinition, GlobalLut, Guid, ModuleInfo, StructDefinition,
+    StructMemoryKind, TypeDefinition, TypeDefinitionData, TypeLut, VariantDefinition,
 };
 use std::{
     ffi::{self, CStr},
@@ -13,22 +14,27 @@ pub(crate) const FAKE_TYPE_GUID: Guid =
     Guid([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
 pub(crate) const FAKE_TYPE_ID: TypeId = TypeId::Concrete(FAKE_TYPE_GUID);
 pub(crate) const FAKE_DEPENDENCY: &str = "path/to/dependency.munlib";
+pub(crate) const FAKE_ENUM_NAME: &str = "EnumName";
 pub(crate) const FAKE_FIELD_NAME: &str = "field_name";
 pub(crate) const FAKE_FN_NAME: &str = "fn_name";
+pub(crate) const FAKE_GLOBAL_NAME: &str = "GLOBAL_NAME";
 pub(crate) const FAKE_MODULE_PATH: &str = "path::to::module";
 pub(crate) const FAKE_STRUCT_NAME: &str = "StructName";
 pub(crate) const FAKE_TYPE_NAME: &str = "TypeName";
+pub(crate) const FAKE_VARIANT_NAME: &str = "VariantName";
 
 pub(crate) fn fake_assembly_info<'a>(
     symbols: ModuleInfo<'a>,
     dispatch_table: DispatchTable<'a>,
     type_lut: TypeLut<'a>,
+    global_lut: GlobalLut<'a>,
     dependencies: &[*const c_char],
 ) -> AssemblyInfo<'a> {
     AssemblyInfo {
         symbols,
         dispatch_table,
         type_lut,
+        global_lut,
         dependencies: dependencies.as_ptr(),
         num_dependencies: dependencies.len() as u32,
     }
@@ -49,6 +55,22 @@ pub(crate) fn fake_type_lut<'a>(
     }
 }
 
+pub(crate) fn fake_global_lut<'a>(
+    global_names: &[*const c_char],
+    type_ids: &[TypeId<'a>],
+    global_ptrs: &mut [*mut ffi::c_void],
+) -> GlobalLut<'a> {
+    assert_eq!(global_names.len(), type_ids.len());
+    assert_eq!(type_ids.len(), global_ptrs.len());
+
+    GlobalLut {
+        global_names: global_names.as_ptr(),
+        type_ids: type_ids.as_ptr(),
+        global_ptrs: global_ptrs.as_mut_ptr(),
+        num_entries: type_ids.len() as u32,
+    }
+}
+
 pub(crate) fn fake_dispatch_table<'a>(
     fn_prototypes: &[FunctionPrototype<'a>],
     fn_ptrs: &mut [*const ffi::c_void],
@@ -88,6 +110,7 @@ pub(crate) fn fake_module_info<'a>(
     path: &CStr,
     functions: &[FunctionDefinition<'a>],
     types: &[TypeDefinition<'a>],
+    globals: &[GlobalDefinition<'a>],
 ) -> ModuleInfo<'a> {
     ModuleInfo {
         path: path.as_ptr(),
@@ -95,6 +118,22 @@ pub(crate) fn fake_module_info<'a>(
         num_functions: functions.len() as u32,
         types: types.as_ptr(),
         num_types: types.len() as u32,
+        globals: globals.as_ptr(),
+        num_globals: globals.len() as u32,
+    }
+}
+
+pub(crate) fn fake_global_definition<'a>(
+    name: &CStr,
+    type_id: TypeId<'a>,
+    ptr: *mut ffi::c_void,
+    mutable: bool,
+) -> GlobalDefinition<'a> {
+    GlobalDefinition {
+        name: name.as_ptr(),
+        type_id,
+        ptr,
+        mutable,
     }
 }
 
@@ -118,6 +157,37 @@ pub(crate) fn fake_struct_definition<'a>(
     }
 }
 
+pub(crate) fn fake_enum_definition<'a>(
+    name: &CStr,
+    variants: &[VariantDefinition<'a>],
+    tag_size: u8,
+) -> EnumDefinition<'a> {
+    EnumDefinition {
+        guid: Guid::from_cstr(name),
+        variants: variants.as_ptr(),
+        num_variants: variants.len() as u16,
+        tag_size,
+    }
+}
+
+pub(crate) fn fake_variant_definition<'a>(
+    name: &CStr,
+    field_names: &[*const c_char],
+    field_types: &[TypeId<'a>],
+    field_offsets: &[u16],
+) -> VariantDefinition<'a> {
+    assert!(field_names.len() == field_types.len());
+    assert!(field_types.len() == field_offsets.len());
+
+    VariantDefinition {
+        name: name.as_ptr(),
+        field_names: field_names.as_ptr(),
+        field_types: field_types.as_ptr(),
+        field_offsets: field_offsets.as_ptr(),
+        num_fields: field_names.len() as u16,
+    }
+}
+
 pub(crate) fn fake_type_definition<'a>(
     name: &CStr,
     size: u32,
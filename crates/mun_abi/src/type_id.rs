@@ -19,6 +19,9 @@ pub enum TypeId<'a> {
 
     /// Represents an array of a specific type
     Array(ArrayTypeId<'a>),
+
+    /// Represents an optional reference to a specific type
+    Optional(OptionalTypeId<'a>),
 }
 
 /// Represents a pointer to another type.
@@ -42,6 +45,15 @@ pub struct ArrayTypeId<'a> {
     pub element: &'a TypeId<'a>,
 }
 
+/// Represents an optional reference to another type, which can be `nil`.
+#[repr(C)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OptionalTypeId<'a> {
+    /// The type that is referenced if the optional is not `nil`
+    pub inner: &'a TypeId<'a>,
+}
+
 unsafe impl<'a> Send for TypeId<'a> {}
 
 unsafe impl<'a> Sync for TypeId<'a> {}
@@ -64,6 +76,7 @@ impl<'a> fmt::Display for TypeId<'a> {
             TypeId::Concrete(guid) => guid.fmt(f),
             TypeId::Pointer(pointer) => pointer.fmt(f),
             TypeId::Array(array) => array.fmt(f),
+            TypeId::Optional(optional) => optional.fmt(f),
         }
     }
 }
@@ -85,6 +98,12 @@ impl<'a> fmt::Display for ArrayTypeId<'a> {
     }
 }
 
+impl<'a> fmt::Display for OptionalTypeId<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "?{}", &self.inner)
+    }
+}
+
 /// A trait that defines that for a type we can statically return a `TypeId`.
 pub trait HasStaticTypeId {
     /// Returns a reference to the TypeInfo for the type
@@ -121,7 +140,9 @@ impl<T: HasStaticTypeId + 'static> HasStaticTypeId for *mut T {
 
 #[cfg(test)]
 mod test {
-    use crate::{ArrayTypeId, HasStaticTypeId, PointerTypeId, PrimitiveType, TypeId};
+    use crate::{
+        ArrayTypeId, HasStaticTypeId, OptionalTypeId, PointerTypeId, PrimitiveType, TypeId,
+    };
 
     #[test]
     fn display() {
@@ -157,5 +178,10 @@ mod test {
             .to_string(),
             format!("[{}]", i32::guid())
         );
+
+        assert_eq!(
+            TypeId::Optional(OptionalTypeId { inner: i32_type_id }).to_string(),
+            format!("?{}", i32::guid())
+        );
     }
 }
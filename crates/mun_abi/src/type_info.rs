@@ -7,7 +7,7 @@ use std::{
     str,
 };
 
-use crate::{type_id::TypeId, Guid, StructDefinition};
+use crate::{type_id::TypeId, EnumDefinition, Guid, StructDefinition};
 
 /// Represents the type declaration for a type that is exported by an assembly.
 ///
@@ -19,8 +19,8 @@ use crate::{type_id::TypeId, Guid, StructDefinition};
 /// to ensure only one definition exists. When linking all assemblies together the type definitions
 /// from all assemblies are loaded and the information is shared to modules that reference the type.
 ///
-/// TODO: add support for polymorphism, enumerations, type parameters, generic type definitions, and
-///   constructed generic types.
+/// TODO: add support for polymorphism, type parameters, generic type definitions, and constructed
+///   generic types.
 #[repr(C)]
 pub struct TypeDefinition<'a> {
     /// Type name
@@ -68,6 +68,8 @@ impl<'a> serde::Serialize for TypeDefinition<'a> {
 pub enum TypeDefinitionData<'a> {
     /// Struct types (i.e. record, tuple, or unit structs)
     Struct(StructDefinition<'a>),
+    /// Enum types (i.e. tagged unions of record, tuple, or unit variants)
+    Enum(EnumDefinition<'a>),
 }
 
 impl<'a> TypeDefinition<'a> {
@@ -75,6 +77,7 @@ impl<'a> TypeDefinition<'a> {
     pub fn is_instance_of(&self, type_id: &TypeId<'a>) -> bool {
         match (&self.data, type_id) {
             (TypeDefinitionData::Struct(s), TypeId::Concrete(guid)) => &s.guid == guid,
+            (TypeDefinitionData::Enum(e), TypeId::Concrete(guid)) => &e.guid == guid,
             _ => false,
         }
     }
@@ -88,13 +91,26 @@ impl<'a> TypeDefinition<'a> {
     pub fn as_concrete(&self) -> &Guid {
         match &self.data {
             TypeDefinitionData::Struct(s) => &s.guid,
+            TypeDefinitionData::Enum(e) => &e.guid,
         }
     }
 
     /// Retrieves the type's struct information, if available.
     pub fn as_struct(&self) -> Option<&StructDefinition> {
-        let TypeDefinitionData::Struct(s) = &self.data;
-        Some(s)
+        if let TypeDefinitionData::Struct(s) = &self.data {
+            Some(s)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the type's enum information, if available.
+    pub fn as_enum(&self) -> Option<&EnumDefinition> {
+        if let TypeDefinitionData::Enum(e) = &self.data {
+            Some(e)
+        } else {
+            None
+        }
     }
 
     /// Returns the size of the type in bits
@@ -143,6 +159,11 @@ impl<'a> TypeDefinitionData<'a> {
     pub fn is_struct(&self) -> bool {
         matches!(self, TypeDefinitionData::Struct(_))
     }
+
+    /// Returns whether this is an enum type.
+    pub fn is_enum(&self) -> bool {
+        matches!(self, TypeDefinitionData::Enum(_))
+    }
 }
 
 /// A trait that defines that for a type we can statically return a type name.
@@ -155,7 +176,9 @@ pub trait HasStaticTypeName {
 mod tests {
     use std::ffi::CString;
 
-    use crate::test_utils::{fake_struct_definition, fake_type_definition, FAKE_TYPE_NAME};
+    use crate::test_utils::{
+        fake_enum_definition, fake_struct_definition, fake_type_definition, FAKE_TYPE_NAME,
+    };
 
     use super::TypeDefinitionData;
 
@@ -217,6 +240,19 @@ mod tests {
         let type_definition =
             fake_type_definition(&type_name, 1, 1, TypeDefinitionData::Struct(struct_info));
         assert!(type_definition.data.is_struct());
+        assert!(type_definition.as_enum().is_none());
+    }
+
+    #[test]
+    fn test_type_definition_group_enum() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let enum_info = fake_enum_definition(&type_name, &[], 1);
+
+        let type_definition =
+            fake_type_definition(&type_name, 8, 1, TypeDefinitionData::Enum(enum_info));
+        assert!(type_definition.data.is_enum());
+        assert!(type_definition.as_struct().is_none());
+        assert!(type_definition.as_enum().is_some());
     }
 
     #[test]
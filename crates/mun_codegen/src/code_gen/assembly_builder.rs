@@ -97,8 +97,11 @@ impl<'db, 'ink, 'ctx, 't> AssemblyBuilder<'db, 'ink, 'ctx, 't> {
             &value_context,
             &file.function_definitions,
             &file.type_definitions,
+            &file.global_definitions,
+            &file.closure_definitions,
             &group_ir.dispatch_table,
             &group_ir.type_table,
+            &group_ir.global_table,
             &self.code_gen.hir_types,
             self.code_gen.optimization_level,
             dependencies,
@@ -18,6 +18,9 @@ pub struct CodeGenContext<'db, 'ink> {
     /// The optimization level
     pub optimization_level: inkwell::OptimizationLevel,
 
+    /// Whether integer arithmetic is checked for overflow
+    pub overflow_checks: bool,
+
     /// The target to generate code for
     pub target_machine: Arc<TargetMachine>,
 }
@@ -31,6 +34,7 @@ impl<'db, 'ink> CodeGenContext<'db, 'ink> {
             rust_types: RefCell::new(HashMap::default()),
             hir_types: HirTypeCache::new(context, db.upcast(), target_machine.get_target_data()),
             optimization_level: db.optimization_level(),
+            overflow_checks: db.overflow_checks(),
             target_machine,
             db: db.upcast(),
         }
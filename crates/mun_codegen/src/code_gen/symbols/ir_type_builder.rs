@@ -43,6 +43,10 @@ impl<'ink, 'a, 'b, 'c> TypeIdBuilder<'ink, 'a, 'b, 'c> {
                 let element = self.get_global_type_id(arr);
                 ir::TypeId::Array(ir::ArrayTypeId { element })
             }
+            TypeIdData::Optional(inner) => {
+                let inner = self.get_global_type_id(inner);
+                ir::TypeId::Optional(ir::OptionalTypeId { inner })
+            }
         }
     }
 
@@ -1,20 +1,26 @@
 use std::convert::TryFrom;
 use std::{collections::HashSet, ffi::CString};
 
-use inkwell::{attributes::Attribute, module::Linkage, types::AnyType};
+use inkwell::{
+    attributes::Attribute,
+    module::Linkage,
+    types::{AnyType, StructType},
+};
 use itertools::Itertools;
 
 use ir_type_builder::TypeIdBuilder;
-use mun_hir::{HirDatabase, TyKind};
+use mun_hir::{HirDatabase, Substitution, Ty, TyKind};
 
-use crate::ir::ty::guid_from_struct;
-use crate::type_info::HasStaticTypeId;
+use crate::ir::ty::{guid_from_enum, guid_from_struct};
+use crate::type_info::{HasStaticTypeId, TypeId, TypeIdData};
 use crate::{
     ir::ty::HirTypeCache,
     ir::types as ir,
     ir::{
-        dispatch_table::{DispatchTable, DispatchableFunction},
+        closure::Closure,
+        dispatch_table::{DispatchTable, DispatchableFunction, FunctionPrototype},
         function,
+        global_table::GlobalTable,
         type_table::TypeTable,
     },
     value::{
@@ -22,6 +28,7 @@ use crate::{
     },
 };
 use mun_abi as abi;
+use std::sync::Arc;
 
 mod ir_type_builder;
 
@@ -65,6 +72,51 @@ fn gen_prototype_from_function<'ink>(
     }
 }
 
+/// Construct a `MunFunctionPrototype` struct for the lifted function of the specified closure. The
+/// first argument of the function is the environment of the closure.
+fn gen_prototype_from_closure<'ink>(
+    db: &dyn HirDatabase,
+    context: &IrValueContext<'ink, '_, '_>,
+    closure: &Closure,
+    hir_types: &HirTypeCache,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> ir::FunctionPrototype<'ink> {
+    let name = closure.name(db);
+
+    // Internalize the name of the function prototype
+    let name_str = CString::new(name.clone())
+        .expect("function prototype name is not a valid CString")
+        .intern(format!("fn_sig::<{}>::name", &name), context);
+
+    // Get the `ir::TypeInfo` pointer for the return type of the function
+    let fn_sig = closure.sig(db);
+    let return_type = if fn_sig.ret().is_empty() {
+        ir_type_builder.construct_from_type_id(<() as HasStaticTypeId>::type_id())
+    } else {
+        ir_type_builder.construct_from_type_id(&hir_types.type_id(fn_sig.ret()))
+    };
+
+    // Construct an array of pointers to `ir::TypeInfo`s for the arguments of the prototype
+    let env_type = closure.env_type_id(db, hir_types);
+    let arg_types = std::iter::once(ir_type_builder.construct_from_type_id(&env_type))
+        .chain(
+            fn_sig
+                .params()
+                .iter()
+                .map(|ty| ir_type_builder.construct_from_type_id(&hir_types.type_id(ty))),
+        )
+        .into_const_private_pointer_or_null(format!("fn_sig::<{}>::arg_types", &name), context);
+
+    ir::FunctionPrototype {
+        name: name_str.as_value(context),
+        signature: ir::FunctionSignature {
+            arg_types,
+            return_type,
+            num_arg_types: (fn_sig.params().len() + 1) as u16,
+        },
+    }
+}
+
 /// Construct a `MunFunctionPrototype` struct for the specified dispatch table function.
 fn gen_prototype_from_dispatch_entry<'ink>(
     context: &IrValueContext<'ink, '_, '_>,
@@ -109,58 +161,171 @@ fn get_type_definition_array<'ink>(
     db: &dyn HirDatabase,
     context: &IrValueContext<'ink, '_, '_>,
     types: impl Iterator<Item = mun_hir::Ty>,
+    closures: &[Closure],
     hir_types: &HirTypeCache,
     ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
 ) -> Value<'ink, *const ir::TypeDefinition<'ink>> {
+    // The environments of closures are garbage collected structs
+    let closure_types = closures
+        .iter()
+        .sorted_by_cached_key(|closure| closure.name(db))
+        .map(|closure| {
+            let type_id = closure.env_type_id(db, hir_types);
+            let capture_types: Vec<Ty> =
+                closure.captures(db).into_iter().map(|(_, ty)| ty).collect();
+            let inkwell_type = hir_types.get_closure_env_type(&capture_types);
+            let data = ir::TypeDefinitionData::Struct(gen_struct_info_from_fields(
+                context,
+                &type_id,
+                &closure.env_fields(db, hir_types),
+                inkwell_type,
+                abi::StructMemoryKind::Gc,
+                ir_type_builder,
+            ));
+            (type_id.name.clone(), inkwell_type, data)
+        });
+
+    // The objects that are referenced by trait objects are described as garbage collected structs
+    // without fields
+    let types: Vec<Ty> = types.collect();
+    let dyn_trait_object_types = types
+        .iter()
+        .filter_map(|ty| match ty.interned() {
+            TyKind::DynTrait(t) => Some(*t),
+            _ => None,
+        })
+        .sorted_by_cached_key(|t| t.full_name(db))
+        .map(|t| {
+            let type_id = hir_types.dyn_trait_object_type_id(t);
+            let inkwell_type = context.context.struct_type(&[], false);
+            let data = ir::TypeDefinitionData::Struct(gen_struct_info_from_fields(
+                context,
+                &type_id,
+                &[],
+                inkwell_type,
+                abi::StructMemoryKind::Gc,
+                ir_type_builder,
+            ));
+            (type_id.name.clone(), inkwell_type, data)
+        })
+        .collect::<Vec<_>>();
+
     types
+        .into_iter()
         .sorted_by_cached_key(|type_info| match type_info.interned() {
-            TyKind::Struct(s) => s.full_name(db),
+            TyKind::Struct(..) | TyKind::FnPtr(_) | TyKind::DynTrait(_) | TyKind::Tuple(..) => {
+                hir_types.type_id(type_info).name.clone()
+            }
+            TyKind::Enum(e) => e.full_name(db),
             _ => unreachable!("unsupported export type"),
         })
         .map(|type_info| match type_info.interned() {
-            TyKind::Struct(s) => {
-                let inkwell_type = hir_types.get_struct_type(*s);
-                let struct_name = s.full_name(db);
-                ir::TypeDefinition {
-                    name: CString::new(struct_name.clone())
-                        .expect("typename is not a valid CString")
-                        .intern(format!("type_info::<{}>::name", struct_name), context)
-                        .as_value(context),
-                    size_in_bits: context
-                        .type_context
-                        .target_data
-                        .get_bit_size(&inkwell_type)
-                        .try_into()
-                        .expect("could not convert size in bits to smaller size"),
-                    alignment: context
-                        .type_context
-                        .target_data
-                        .get_abi_alignment(&inkwell_type)
-                        .try_into()
-                        .expect("could not convert alignment to smaller size"),
-                    data: ir::TypeDefinitionData::Struct(gen_struct_info(
-                        db,
-                        *s,
-                        context,
-                        hir_types,
-                        ir_type_builder,
-                    )),
-                }
+            TyKind::Struct(s, substs) => (
+                hir_types.type_id(&type_info).name.clone(),
+                hir_types.get_struct_type(*s, substs),
+                ir::TypeDefinitionData::Struct(gen_struct_info(
+                    db,
+                    *s,
+                    substs,
+                    context,
+                    hir_types,
+                    ir_type_builder,
+                )),
+            ),
+            TyKind::Enum(e) => (
+                e.full_name(db),
+                hir_types.get_enum_type(*e),
+                ir::TypeDefinitionData::Enum(gen_enum_info(
+                    db,
+                    *e,
+                    context,
+                    hir_types,
+                    ir_type_builder,
+                )),
+            ),
+            TyKind::FnPtr(_) => {
+                // A function value is a reference to the environment of a closure. The
+                // function pointer type itself has no fields.
+                let type_id = hir_types.type_id(&type_info);
+                let inkwell_type = context.context.struct_type(&[], false);
+                let data = ir::TypeDefinitionData::Struct(gen_struct_info_from_fields(
+                    context,
+                    &type_id,
+                    &[],
+                    inkwell_type,
+                    abi::StructMemoryKind::Gc,
+                    ir_type_builder,
+                ));
+                (type_id.name.clone(), inkwell_type, data)
+            }
+            TyKind::DynTrait(t) => {
+                // A trait object is a value struct that holds a reference to the object and the
+                // key that identifies the type of the object.
+                let type_id = hir_types.type_id(&type_info);
+                let inkwell_type = hir_types.get_dyn_trait_type(*t);
+                let data = ir::TypeDefinitionData::Struct(gen_struct_info_from_fields(
+                    context,
+                    &type_id,
+                    &hir_types.dyn_trait_fields(*t),
+                    inkwell_type,
+                    abi::StructMemoryKind::Value,
+                    ir_type_builder,
+                ));
+                (type_id.name.clone(), inkwell_type, data)
+            }
+            TyKind::Tuple(_, substs) => {
+                // A tuple is a value struct whose fields are named after their index
+                let type_id = hir_types.type_id(&type_info);
+                let inkwell_type = hir_types.get_tuple_type(substs);
+                let data = ir::TypeDefinitionData::Struct(gen_struct_info_from_fields(
+                    context,
+                    &type_id,
+                    &hir_types.tuple_fields(substs),
+                    inkwell_type,
+                    abi::StructMemoryKind::Value,
+                    ir_type_builder,
+                ));
+                (type_id.name.clone(), inkwell_type, data)
             }
             _ => unreachable!("unsupported export type"),
         })
+        .chain(closure_types)
+        .chain(dyn_trait_object_types)
+        .map(|(type_name, inkwell_type, data)| ir::TypeDefinition {
+            name: CString::new(type_name.clone())
+                .expect("typename is not a valid CString")
+                .intern(format!("type_info::<{}>::name", type_name), context)
+                .as_value(context),
+            size_in_bits: context
+                .type_context
+                .target_data
+                .get_bit_size(&inkwell_type)
+                .try_into()
+                .expect("could not convert size in bits to smaller size"),
+            alignment: context
+                .type_context
+                .target_data
+                .get_abi_alignment(&inkwell_type)
+                .try_into()
+                .expect("could not convert alignment to smaller size"),
+            data,
+        })
         .into_const_private_pointer_or_null("fn.get_info.types", context)
 }
 
 fn gen_struct_info<'ink>(
     db: &dyn HirDatabase,
     hir_struct: mun_hir::Struct,
+    substs: &Substitution,
     context: &IrValueContext<'ink, '_, '_>,
     hir_types: &HirTypeCache,
     ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
 ) -> ir::StructDefinition<'ink> {
-    let struct_ir = hir_types.get_struct_type(hir_struct);
-    let name = hir_struct.full_name(db);
+    let struct_ir = hir_types.get_struct_type(hir_struct, substs);
+    let name = hir_types
+        .type_id(&Ty::struct_ty(hir_struct, substs.clone()))
+        .name
+        .clone();
     let fields = hir_struct.fields(db);
 
     // Construct an array of field names (or null if there are no fields)
@@ -185,7 +350,7 @@ fn gen_struct_info<'ink>(
     let field_types = fields
         .iter()
         .map(|field| {
-            let field_type_info = hir_types.type_id(&field.ty(db));
+            let field_type_info = hir_types.type_id(&field.ty(db).subst(substs));
             ir_type_builder.construct_from_type_id(&field_type_info)
         })
         .into_const_private_pointer_or_null(
@@ -210,7 +375,7 @@ fn gen_struct_info<'ink>(
         );
 
     ir::StructDefinition {
-        guid: guid_from_struct(db, hir_struct),
+        guid: guid_from_struct(db, hir_struct, substs),
         field_names,
         field_types,
         field_offsets,
@@ -222,20 +387,255 @@ fn gen_struct_info<'ink>(
     }
 }
 
+/// Constructs the `StructDefinition` of a struct that is not defined by a HIR struct, e.g. the
+/// environment of a closure or a trait object.
+fn gen_struct_info_from_fields<'ink>(
+    context: &IrValueContext<'ink, '_, '_>,
+    type_id: &TypeId,
+    fields: &[(String, Arc<TypeId>)],
+    struct_ir: StructType<'ink>,
+    memory_kind: abi::StructMemoryKind,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> ir::StructDefinition<'ink> {
+    let name = &type_id.name;
+    let guid = match &type_id.data {
+        TypeIdData::Concrete(guid) => *guid,
+        _ => unreachable!("expected a concrete type"),
+    };
+
+    // Construct an array of field names (or null if there are no fields)
+    let field_names = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, (field_name, _))| {
+            CString::new(field_name.as_str())
+                .expect("field name is not a valid CString")
+                .intern(
+                    format!("struct_info::<{}>::field_names.{}", name, idx),
+                    context,
+                )
+                .as_value(context)
+        })
+        .into_const_private_pointer_or_null(
+            format!("struct_info::<{}>::field_names", name),
+            context,
+        );
+
+    // Construct an array of field types (or null if there are no fields)
+    let field_types = fields
+        .iter()
+        .map(|(_, field_type_id)| ir_type_builder.construct_from_type_id(field_type_id))
+        .into_const_private_pointer_or_null(
+            format!("struct_info::<{}>::field_types", name),
+            context,
+        );
+
+    // Construct an array of field offsets (or null if there are no fields)
+    let field_offsets = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, _)| {
+            context
+                .type_context
+                .target_data
+                .offset_of_element(&struct_ir, idx as u32)
+                .unwrap() as u16
+        })
+        .into_const_private_pointer_or_null(
+            format!("struct_info::<{}>::field_offsets", name),
+            context,
+        );
+
+    ir::StructDefinition {
+        guid,
+        field_names,
+        field_types,
+        field_offsets,
+        num_fields: fields
+            .len()
+            .try_into()
+            .expect("could not convert num_fields to smaller bit size"),
+        memory_kind,
+    }
+}
+
+fn gen_enum_info<'ink>(
+    db: &dyn HirDatabase,
+    hir_enum: mun_hir::Enum,
+    context: &IrValueContext<'ink, '_, '_>,
+    hir_types: &HirTypeCache,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> ir::EnumDefinition<'ink> {
+    let name = hir_enum.full_name(db);
+    let variants = hir_enum.variants(db);
+
+    // Construct an array of variants (or null if there are no variants)
+    let variant_infos = variants
+        .iter()
+        .map(|variant| gen_variant_info(db, &name, *variant, context, hir_types, ir_type_builder))
+        .into_const_private_pointer_or_null(format!("enum_info::<{}>::variants", name), context);
+
+    let tag_ir = hir_types.get_enum_tag_type(hir_enum);
+
+    ir::EnumDefinition {
+        guid: guid_from_enum(db, hir_enum),
+        variants: variant_infos,
+        num_variants: variants
+            .len()
+            .try_into()
+            .expect("could not convert num_variants to smaller bit size"),
+        tag_size: context
+            .type_context
+            .target_data
+            .get_abi_size(&tag_ir)
+            .try_into()
+            .expect("could not convert tag size to smaller bit size"),
+    }
+}
+
+fn gen_variant_info<'ink>(
+    db: &dyn HirDatabase,
+    enum_name: &str,
+    variant: mun_hir::EnumVariant,
+    context: &IrValueContext<'ink, '_, '_>,
+    hir_types: &HirTypeCache,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> ir::VariantDefinition<'ink> {
+    let variant_ir = hir_types.get_enum_variant_type(variant);
+    let fields_ir = variant_ir
+        .get_field_type_at_index(2)
+        .expect("variant type must contain its fields")
+        .into_struct_type();
+    let name = format!("{}::{}", enum_name, variant.name(db));
+    let fields = variant.fields(db);
+
+    // Construct an array of field names (or null if there are no fields)
+    let field_names = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            CString::new(field.name(db).to_string())
+                .expect("field name is not a valid CString")
+                .intern(
+                    format!("variant_info::<{}>::field_names.{}", name, idx),
+                    context,
+                )
+                .as_value(context)
+        })
+        .into_const_private_pointer_or_null(
+            format!("variant_info::<{}>::field_names", name),
+            context,
+        );
+
+    // Construct an array of field types (or null if there are no fields)
+    let field_types = fields
+        .iter()
+        .map(|field| {
+            let field_type_info = hir_types.type_id(&field.ty(db));
+            ir_type_builder.construct_from_type_id(&field_type_info)
+        })
+        .into_const_private_pointer_or_null(
+            format!("variant_info::<{}>::field_types", name),
+            context,
+        );
+
+    // Construct an array of field offsets (or null if there are no fields). The offsets are
+    // relative to the start of the enum.
+    let target_data = context.type_context.target_data;
+    let payload_offset = target_data.offset_of_element(&variant_ir, 2).unwrap();
+    let field_offsets = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, _)| {
+            (payload_offset
+                + target_data
+                    .offset_of_element(&fields_ir, idx as u32)
+                    .unwrap()) as u16
+        })
+        .into_const_private_pointer_or_null(
+            format!("variant_info::<{}>::field_offsets", name),
+            context,
+        );
+
+    ir::VariantDefinition {
+        name: CString::new(variant.name(db).to_string())
+            .expect("variant name is not a valid CString")
+            .intern(format!("variant_info::<{}>::name", name), context)
+            .as_value(context),
+        field_names,
+        field_types,
+        field_offsets,
+        num_fields: fields
+            .len()
+            .try_into()
+            .expect("could not convert num_fields to smaller bit size"),
+    }
+}
+
 /// Construct a global that holds a reference to all functions. e.g.:
 /// MunFunctionDefinition[] definitions = { ... }
 fn get_function_definition_array<'ink, 'a>(
     db: &dyn HirDatabase,
     context: &IrValueContext<'ink, '_, '_>,
     functions: impl Iterator<Item = &'a mun_hir::Function>,
+    closures: &[Closure],
     hir_types: &HirTypeCache,
     ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
 ) -> Global<'ink, [ir::FunctionDefinition<'ink>]> {
     let module = context.module;
+
+    // The lifted functions of closures are exported, so that the runtime can update existing
+    // function values after hot reloading.
+    let closure_functions = closures
+        .iter()
+        .sorted_by_cached_key(|closure| closure.name(db))
+        .map(|closure| {
+            let value = module.get_function(&closure.name(db)).unwrap();
+            value.set_linkage(Linkage::Private);
+
+            let prototype =
+                gen_prototype_from_closure(db, context, closure, hir_types, ir_type_builder);
+            ir::FunctionDefinition {
+                prototype,
+                fn_ptr: Value::<*const fn()>::with_cast(
+                    value.as_global_value().as_pointer_value(),
+                    context,
+                ),
+            }
+        });
+
+    // The trap that is called by function values whose function was removed by hot reloading is
+    // exported, so that the runtime can point those function values to it.
+    let fn_value_trap = module
+        .get_function(abi::FN_VALUE_TRAP_FN_NAME)
+        .map(|value| {
+            value.set_linkage(Linkage::Private);
+
+            let prototype = gen_prototype_from_dispatch_entry(
+                context,
+                &DispatchableFunction {
+                    prototype: FunctionPrototype {
+                        name: abi::FN_VALUE_TRAP_FN_NAME.to_owned(),
+                        arg_types: Vec::new(),
+                        ret_type: <()>::type_id().clone(),
+                    },
+                    mun_hir: None,
+                },
+                ir_type_builder,
+            );
+            ir::FunctionDefinition {
+                prototype,
+                fn_ptr: Value::<*const fn()>::with_cast(
+                    value.as_global_value().as_pointer_value(),
+                    context,
+                ),
+            }
+        });
+
     functions
         .sorted_by_cached_key(|f| f.full_name(db))
         .map(|f| {
-            let name = f.name(db).to_string();
+            let name = f.name_in_module(db);
 
             // Get the function from the cloned module and modify the linkage of the function.
             let value = module
@@ -257,10 +657,96 @@ fn get_function_definition_array<'ink, 'a>(
                 ),
             }
         })
+        .chain(closure_functions)
+        .chain(fn_value_trap)
         .into_value(context)
         .into_const_private_global("fn.get_info.functions", context)
 }
 
+/// Construct a global that holds a reference to all statics. e.g.:
+/// MunGlobalDefinition[] definitions = { ... }
+fn get_global_definition_array<'ink, 'a>(
+    db: &dyn HirDatabase,
+    context: &IrValueContext<'ink, '_, '_>,
+    statics: impl Iterator<Item = &'a mun_hir::Static>,
+    hir_types: &HirTypeCache,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> Value<'ink, *const ir::GlobalDefinition<'ink>> {
+    let module = context.module;
+
+    statics
+        .sorted_by_cached_key(|s| s.full_name(db))
+        .map(|s| {
+            let name = s.full_name(db);
+
+            // Get the static from the cloned module and modify the linkage of the global.
+            let value = module.get_global(&name).unwrap();
+            value.set_linkage(Linkage::Private);
+
+            ir::GlobalDefinition {
+                name: CString::new(name.clone())
+                    .expect("global name is not a valid CString")
+                    .intern(format!("global_info::<{}>::name", name), context)
+                    .as_value(context),
+                type_id: ir_type_builder.construct_from_type_id(&hir_types.type_id(&s.ty(db))),
+                ptr: Value::<*const std::ffi::c_void>::with_cast(value.as_pointer_value(), context),
+                mutable: s.is_mut(db),
+            }
+        })
+        .into_const_private_pointer_or_null("fn.get_info.globals", context)
+}
+
+/// Generate the global lookup table information. e.g.:
+/// ```c
+/// MunGlobalLut globalLut = { ... }
+/// ```
+fn gen_global_lut<'ink>(
+    db: &dyn HirDatabase,
+    context: &IrValueContext<'ink, '_, '_>,
+    global_table: &GlobalTable,
+    hir_types: &HirTypeCache,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> ir::GlobalLut<'ink> {
+    let module = context.module;
+
+    let global_names = global_table
+        .entries()
+        .iter()
+        .map(|s| {
+            let name = s.full_name(db);
+            CString::new(name.as_str())
+                .expect("unable to create CString from global name")
+                .intern(format!("global_lut::<{}>::name", name), context)
+                .as_value(context)
+        })
+        .into_const_private_pointer_or_null("fn.get_info.globalLut.globalNames", context);
+
+    let type_ids = global_table
+        .entries()
+        .iter()
+        .map(|s| ir_type_builder.construct_from_type_id(&hir_types.type_id(&s.ty(db))))
+        .into_const_private_pointer_or_null("fn.get_info.globalLut.typeIds", context);
+
+    let global_ptrs = GlobalTable::find_global(module)
+        .map(|global_table| {
+            Value::<*mut *const std::ffi::c_void>::with_cast(
+                global_table.as_value(context).value,
+                context,
+            )
+        })
+        .unwrap_or_else(|| Value::null(context));
+
+    ir::GlobalLut {
+        global_names,
+        type_ids,
+        global_ptrs,
+        num_entries: global_table
+            .num_globals()
+            .try_into()
+            .expect("too many globals"),
+    }
+}
+
 /// Generate the type lookup table information. e.g.:
 /// ```c
 /// MunTypeLut typeLut = { ... }
@@ -352,29 +838,56 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
     context: &IrValueContext<'ink, '_, '_>,
     function_definitions: &HashSet<mun_hir::Function>,
     type_definitions: &HashSet<mun_hir::Ty>,
+    global_definitions: &HashSet<mun_hir::Static>,
+    closure_definitions: &[Closure],
     dispatch_table: &DispatchTable<'ink>,
     type_table: &TypeTable<'ink>,
+    global_table: &GlobalTable<'ink>,
     hir_types: &HirTypeCache<'db, 'ink>,
     optimization_level: inkwell::OptimizationLevel,
     dependencies: Vec<String>,
 ) {
     let ir_type_builder = TypeIdBuilder::new(context);
 
-    let num_functions = function_definitions.len() as u32;
+    let num_fn_value_traps = usize::from(
+        context
+            .module
+            .get_function(abi::FN_VALUE_TRAP_FN_NAME)
+            .is_some(),
+    );
+    let num_functions =
+        (function_definitions.len() + closure_definitions.len() + num_fn_value_traps) as u32;
     let functions = get_function_definition_array(
         db,
         context,
         function_definitions.iter(),
+        closure_definitions,
         hir_types,
         &ir_type_builder,
     );
 
-    // Get the TypeTable global
-    let num_types = type_definitions.len() as u32;
+    // Get the TypeTable global. Every trait object type also defines the type of the object it
+    // references.
+    let num_dyn_trait_types = type_definitions
+        .iter()
+        .filter(|ty| matches!(ty.interned(), TyKind::DynTrait(_)))
+        .count();
+    let num_types =
+        (type_definitions.len() + num_dyn_trait_types + closure_definitions.len()) as u32;
     let types = get_type_definition_array(
         db,
         context,
         type_definitions.iter().cloned(),
+        closure_definitions,
+        hir_types,
+        &ir_type_builder,
+    );
+
+    let num_globals = global_definitions.len() as u32;
+    let globals = get_global_definition_array(
+        db,
+        context,
+        global_definitions.iter(),
         hir_types,
         &ir_type_builder,
     );
@@ -389,6 +902,8 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
         num_functions,
         types,
         num_types,
+        globals,
+        num_globals,
     };
 
     // Construct the dispatch table struct
@@ -396,6 +911,8 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
 
     let type_lut = gen_type_lut(context, type_table, &ir_type_builder);
 
+    let global_lut = gen_global_lut(db, context, global_table, hir_types, &ir_type_builder);
+
     // Construct the actual `get_info` function
     gen_get_info_fn(
         db,
@@ -403,10 +920,12 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
         module_info,
         dispatch_table,
         type_lut,
+        global_lut,
         optimization_level,
         dependencies,
     );
     gen_set_allocator_handle_fn(context);
+    gen_set_panic_handle_fn(context);
     gen_get_version_fn(context);
 }
 
@@ -417,6 +936,7 @@ fn gen_get_info_fn<'ink>(
     module_info: ir::ModuleInfo<'ink>,
     dispatch_table: ir::DispatchTable<'ink>,
     type_lut: ir::TypeLut<'ink>,
+    global_lut: ir::GlobalLut<'ink>,
     optimization_level: inkwell::OptimizationLevel,
     dependencies: Vec<String>,
 ) {
@@ -481,17 +1001,21 @@ fn gen_get_info_fn<'ink>(
     let type_lut_addr = builder
         .build_struct_gep(result_ptr, 5, "type_lut")
         .expect("could not retrieve `type_lut` from result struct");
+    let global_lut_addr = builder
+        .build_struct_gep(result_ptr, 7, "global_lut")
+        .expect("could not retrieve `global_lut` from result struct");
     let dependencies_addr = builder
-        .build_struct_gep(result_ptr, 7, "dependencies")
+        .build_struct_gep(result_ptr, 9, "dependencies")
         .expect("could not retrieve `dependencies` from result struct");
     let num_dependencies_addr = builder
-        .build_struct_gep(result_ptr, 9, "num_dependencies")
+        .build_struct_gep(result_ptr, 11, "num_dependencies")
         .expect("could not retrieve `num_dependencies` from result struct");
 
     // Assign the struct values one by one.
     builder.build_store(symbols_addr, module_info.as_value(context).value);
     builder.build_store(dispatch_table_addr, dispatch_table.as_value(context).value);
     builder.build_store(type_lut_addr, type_lut.as_value(context).value);
+    builder.build_store(global_lut_addr, global_lut.as_value(context).value);
     builder.build_store(
         dependencies_addr,
         dependencies
@@ -551,6 +1075,31 @@ fn gen_set_allocator_handle_fn(context: &IrValueContext) {
     builder.build_return(None);
 }
 
+/// Generates a method `void set_panic_handle(void*)` that stores the argument into the global
+/// `panicHandle`. This global is used internally to signal and detect panics of Mun code.
+fn gen_set_panic_handle_fn(context: &IrValueContext) {
+    let set_panic_handle_fn = context.module.add_function(
+        abi::SET_PANIC_HANDLE_FN_NAME,
+        Value::<fn(*const u8)>::get_ir_type(context.type_context),
+        Some(Linkage::DLLExport),
+    );
+
+    let builder = context.context.create_builder();
+    let body_ir = context
+        .context
+        .append_basic_block(set_panic_handle_fn, "body");
+    builder.position_at_end(body_ir);
+
+    if let Some(panic_handle_global) = context.module.get_global("panicHandle") {
+        builder.build_store(
+            panic_handle_global.as_pointer_value(),
+            set_panic_handle_fn.get_nth_param(0).unwrap(),
+        );
+    }
+
+    builder.build_return(None);
+}
+
 /// Generates a `get_version` method that returns the current abi version.
 /// Specifically, it returns the abi version the function was generated in.
 fn gen_get_version_fn(context: &IrValueContext) {
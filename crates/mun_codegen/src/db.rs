@@ -17,6 +17,15 @@ pub trait CodeGenDatabase:
     #[salsa::input]
     fn optimization_level(&self) -> inkwell::OptimizationLevel;
 
+    /// Set whether integer arithmetic is checked for overflow, in which case an overflow panics
+    #[salsa::input]
+    fn overflow_checks(&self) -> bool;
+
+    /// Set whether functions marked with `#[test]` are exported from assemblies, regardless of
+    /// their visibility, so they can be invoked by a test runner
+    #[salsa::input]
+    fn export_tests(&self) -> bool;
+
     /// Returns the current module partition
     #[salsa::invoke(crate::module_partition::build_partition)]
     fn module_partition(&self) -> Arc<ModulePartition>;
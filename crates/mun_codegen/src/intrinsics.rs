@@ -24,4 +24,41 @@ intrinsics! {
     ///
     /// Note that the elements in the array are left uninitialized.
     pub fn new_array(type_handle: *const ffi::c_void, length: usize, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Appends the element stored at `element` to the end of `array`, growing the capacity of the
+    /// array in the allocator referred to by `alloc_handle` if required.
+    pub fn array_push(array: *const *mut ffi::c_void, element: *const u8, alloc_handle: *mut ffi::c_void) -> ();
+
+    /// Inserts the element stored at `element` at position `index` of `array`, shifting all
+    /// elements after it to the right. The capacity of the array grows in the allocator referred
+    /// to by `alloc_handle` if required.
+    pub fn array_insert(array: *const *mut ffi::c_void, index: usize, element: *const u8, alloc_handle: *mut ffi::c_void) -> ();
+
+    /// Removes the element at position `index` of `array` and stores it at `element`, shifting all
+    /// elements after it to the left.
+    pub fn array_remove(array: *const *mut ffi::c_void, index: usize, element: *mut u8, alloc_handle: *mut ffi::c_void) -> ();
+
+    /// Allocates a new string in the allocator referred to by `alloc_handle` and copies `length`
+    /// UTF-8 encoded `bytes` into it.
+    pub fn new_string(bytes: *const u8, length: usize, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Allocates a new string in the allocator referred to by `alloc_handle` that contains the
+    /// contents of `lhs` followed by the contents of `rhs`.
+    pub fn string_concat(lhs: *const *mut ffi::c_void, rhs: *const *mut ffi::c_void, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Lexicographically compares the bytes of the strings `lhs` and `rhs`. Returns a negative
+    /// value if `lhs` is less than `rhs`, zero if they are equal, and a positive value otherwise.
+    pub fn string_compare(lhs: *const *mut ffi::c_void, rhs: *const *mut ffi::c_void, alloc_handle: *mut ffi::c_void) -> i32;
+
+    /// Raises a panic in the runtime referred to by `panic_handle` because `index` is out of
+    /// bounds for an array of `length` elements.
+    pub fn panic_bounds_check(index: usize, length: usize, panic_handle: *mut ffi::c_void) -> ();
+
+    /// Raises a panic in the runtime referred to by `panic_handle` with a message that consists of
+    /// `length` UTF-8 encoded `bytes`.
+    pub fn panic(bytes: *const u8, length: usize, panic_handle: *mut ffi::c_void) -> ();
+
+    /// Records in the runtime referred to by `panic_handle` that the current panic unwinds through
+    /// the function whose name consists of `length` UTF-8 encoded `bytes`.
+    pub fn panic_unwind(bytes: *const u8, length: usize, panic_handle: *mut ffi::c_void) -> ();
 }
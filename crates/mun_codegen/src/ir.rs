@@ -10,11 +10,15 @@ use inkwell::{
 
 mod array;
 pub mod body;
+pub(crate) mod closure;
+mod constant;
 #[macro_use]
 pub(crate) mod dispatch_table;
 pub mod file;
 pub(crate) mod file_group;
 pub mod function;
+pub(crate) mod global_table;
+pub(crate) mod instance;
 mod intrinsics;
 mod reference;
 pub mod ty;
@@ -1,40 +1,77 @@
 use crate::{
     intrinsics,
-    ir::{dispatch_table::DispatchTable, ty::HirTypeCache, type_table::TypeTable},
+    ir::{
+        closure::{self, Closure},
+        constant,
+        dispatch_table::DispatchTable,
+        global_table::{self, GlobalTable},
+        instance::FunctionInstance,
+        intrinsics::{get_float_to_int_sat_intrinsic, get_math_intrinsic, get_overflow_intrinsic},
+        ty::{trait_object_key, HirTypeCache},
+        type_table::TypeTable,
+    },
     ir::{RuntimeArrayValue, RuntimeReferenceValue},
     module_group::ModuleGroup,
+    type_info::TypeId,
     value::Global,
 };
 use inkwell::{
     basic_block::BasicBlock,
     builder::Builder,
     context::Context,
+    module::Module,
+    types::{BasicTypeEnum, StructType},
     values::{
-        AggregateValueEnum, BasicMetadataValueEnum, BasicValueEnum, CallSiteValue, FloatValue,
-        FunctionValue, GlobalValue, IntValue, PointerValue, StructValue,
+        AggregateValueEnum, BasicMetadataValueEnum, BasicValueEnum, CallSiteValue, CallableValue,
+        FloatValue, FunctionValue, GlobalValue, IntValue, PointerValue, StructValue,
     },
     AddressSpace, FloatPredicate, IntPredicate,
 };
 use mun_abi as abi;
 use mun_hir::{
-    ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, HirDatabase, HirDisplay, InferenceResult,
+    ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, FnSig, HirDatabase, HirDisplay, InferenceResult,
     Literal, LogicOp, Name, Ordering, Pat, PatId, Path, ResolveBitness, Resolver, Statement,
-    TyKind, UnaryOp, ValueNs,
+    Substitution, Ty, TyKind, UnaryOp, ValueNs,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
 };
-use std::{collections::HashMap, sync::Arc};
 
 type BreakSources<'ink> = Vec<Option<(BasicValueEnum<'ink>, BasicBlock<'ink>)>>;
 
+/// The comparison between a value and a literal that is used to test a pattern.
+#[derive(Clone, Copy)]
+enum ComparisonKind {
+    Eq,
+    Ge,
+    Le,
+}
+
+/// The sequence of values a `for` loop iterates over.
+enum ForIterable<'ink> {
+    Range {
+        end: IntValue<'ink>,
+        inclusive: bool,
+        signedness: mun_hir::Signedness,
+    },
+    Array(RuntimeArrayValue<'ink>),
+}
+
 struct LoopInfo<'ink> {
+    label: Option<Name>,
     break_values: BreakSources<'ink>,
     exit_block: BasicBlock<'ink>,
+    continue_block: BasicBlock<'ink>,
 }
 
 #[derive(Clone)]
 pub(crate) struct ExternalGlobals<'ink> {
     pub alloc_handle: Option<GlobalValue<'ink>>,
+    pub panic_handle: Option<GlobalValue<'ink>>,
     pub dispatch_table: Option<GlobalValue<'ink>>,
     pub type_table: Option<Global<'ink, [*const std::ffi::c_void]>>,
+    pub global_table: Option<Global<'ink, [*const std::ffi::c_void]>>,
 }
 
 pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
@@ -48,13 +85,23 @@ pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
     pat_to_local: HashMap<PatId, inkwell::values::PointerValue<'ink>>,
     pat_to_name: HashMap<PatId, String>,
     function_map: &'t HashMap<mun_hir::Function, FunctionValue<'ink>>,
+    instance_map: &'t HashMap<FunctionInstance, FunctionValue<'ink>>,
+    closure_map: &'t HashMap<Closure, FunctionValue<'ink>>,
+    static_map: &'t HashMap<mun_hir::Static, GlobalValue<'ink>>,
     dispatch_table: &'t DispatchTable<'ink>,
     type_table: &'t TypeTable<'ink>,
+    global_table: &'t GlobalTable<'ink>,
     hir_types: &'t HirTypeCache<'db, 'ink>,
-    active_loop: Option<LoopInfo<'ink>>,
+    active_loops: Vec<LoopInfo<'ink>>,
     hir_function: mun_hir::Function,
+    substs: Substitution,
     external_globals: ExternalGlobals<'ink>,
     module_group: &'t ModuleGroup,
+    module: &'t Module<'ink>,
+    overflow_checks: bool,
+    function_name: Option<PointerValue<'ink>>,
+    function_value_exprs: Vec<ExprId>,
+    closure: Option<Closure>,
 }
 
 impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
@@ -63,18 +110,31 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         context: &'ink Context,
         db: &'db dyn HirDatabase,
         function: (mun_hir::Function, FunctionValue<'ink>),
+        substs: Substitution,
         function_map: &'t HashMap<mun_hir::Function, FunctionValue<'ink>>,
+        instance_map: &'t HashMap<FunctionInstance, FunctionValue<'ink>>,
+        closure_map: &'t HashMap<Closure, FunctionValue<'ink>>,
+        static_map: &'t HashMap<mun_hir::Static, GlobalValue<'ink>>,
         dispatch_table: &'t DispatchTable<'ink>,
         type_table: &'t TypeTable<'ink>,
+        global_table: &'t GlobalTable<'ink>,
         external_globals: ExternalGlobals<'ink>,
         hir_types: &'t HirTypeCache<'db, 'ink>,
         module_group: &'t ModuleGroup,
+        module: &'t Module<'ink>,
+        overflow_checks: bool,
     ) -> Self {
         let (hir_function, ir_function) = function;
 
-        // Get the type information from the `mun_hir::Function`
+        // Get the type information from the `mun_hir::Function`. For an instance of a generic
+        // function, the type arguments are substituted for its type parameters.
         let body = hir_function.body(db);
-        let infer = hir_function.infer(db);
+        let infer = if substs.is_empty() {
+            hir_function.infer(db)
+        } else {
+            Arc::new(hir_function.infer(db).subst(db, &substs))
+        };
+        let function_value_exprs = closure::function_value_exprs(&body, &infer);
 
         // Construct a builder for the IR function
         let builder = context.create_builder();
@@ -92,43 +152,42 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             pat_to_local: HashMap::default(),
             pat_to_name: HashMap::default(),
             function_map,
+            instance_map,
+            closure_map,
+            static_map,
             dispatch_table,
             type_table,
-            active_loop: None,
+            global_table,
+            active_loops: Vec::new(),
             hir_function,
+            substs,
             external_globals,
             hir_types,
             module_group,
+            module,
+            overflow_checks,
+            function_name: None,
+            function_value_exprs,
+            closure: None,
         }
     }
 
+    /// Returns the signature of the function for which IR is generated, with the type arguments
+    /// substituted for its type parameters.
+    fn fn_sig(&self) -> FnSig {
+        self.db
+            .callable_sig(self.hir_function.into())
+            .subst(&self.substs)
+    }
+
     /// Generates IR for the body of the function.
     pub fn gen_fn_body(&mut self) {
         // Iterate over all parameters and their type and store them so we can reference them
         // later in code.
-        for (i, (pat, _ty)) in self.body.params().iter().enumerate() {
-            let body = self.body.clone(); // Avoid borrow issues
-
-            match &body[*pat] {
-                Pat::Bind { name } => {
-                    let name = name.to_string();
-                    let param = self.fn_value.get_nth_param(i as u32).unwrap();
-                    let builder = self.new_alloca_builder();
-                    let param_ptr = builder.build_alloca(param.get_type(), &name);
-                    builder.build_store(param_ptr, param);
-                    self.pat_to_local.insert(*pat, param_ptr);
-                    self.pat_to_name.insert(*pat, name);
-                }
-                Pat::Wild => {
-                    // Wildcard patterns cannot be referenced from code. So nothing to do.
-                }
-                Pat::Path(_) => unreachable!(
-                    "Path patterns are not supported as parameters, are we missing a diagnostic?"
-                ),
-                Pat::Missing => unreachable!(
-                    "found missing Pattern, should not be generating IR for incomplete code"
-                ),
-            }
+        let body = self.body.clone(); // Avoid borrow issues
+        for (i, (pat, _ty)) in body.params().iter().enumerate() {
+            let param = self.fn_value.get_nth_param(i as u32).unwrap();
+            self.gen_param(*pat, param);
         }
 
         // Generate code for the body of the function
@@ -138,13 +197,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         // in the first place. If the return type of the body is `never` there is no need to
         // generate a return statement.
         let block_ret_type = &self.infer[self.body.body_expr()];
-        let fn_ret_type = self
-            .hir_function
-            .ty(self.db)
-            .callable_sig(self.db)
-            .unwrap()
-            .ret()
-            .clone();
+        let fn_ret_type = self.fn_sig().ret().clone();
         if !block_ret_type.is_never() {
             if fn_ret_type.is_empty() {
                 self.builder.build_return(None);
@@ -154,6 +207,101 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR for the body of the lifted function of a function value. The values that are
+    /// captured by the function value are loaded from its environment, which is passed as the
+    /// first argument.
+    pub fn gen_closure_body(&mut self, closure: &Closure) {
+        self.closure = Some(closure.clone());
+
+        // Copy the captured values from the environment into local variables
+        let captures = closure.captures(self.db);
+        let capture_types: Vec<Ty> = captures.iter().map(|(_, ty)| ty.clone()).collect();
+        let env_ir_ty = self.hir_types.get_closure_env_type(&capture_types);
+        let env_ptr = self.builder.build_bitcast(
+            self.fn_value.get_nth_param(0).unwrap(),
+            env_ir_ty
+                .ptr_type(AddressSpace::Generic)
+                .ptr_type(AddressSpace::Generic),
+            "env",
+        );
+        let env = RuntimeReferenceValue::from_ptr(env_ptr.into_pointer_value(), env_ir_ty)
+            .expect("unable to construct mun reference type");
+        let env_data_ptr = env.get_data_ptr(&self.builder);
+        let body = self.body.clone(); // Avoid borrow issues
+        for (i, (pat, _ty)) in captures.iter().enumerate() {
+            let name = match &body[*pat] {
+                Pat::Bind { name } => name.to_string(),
+                _ => unreachable!("only bindings can be captured"),
+            };
+            let capture_ptr = self
+                .builder
+                .build_struct_gep(env_data_ptr, (i + 1) as u32, &format!("{}_ptr", name))
+                .expect("could not retrieve captured value from environment");
+            let value = self.builder.build_load(capture_ptr, &name);
+            let local_ptr = self
+                .new_alloca_builder()
+                .build_alloca(value.get_type(), &name);
+            self.builder.build_store(local_ptr, value);
+            self.pat_to_local.insert(*pat, local_ptr);
+            self.pat_to_name.insert(*pat, name);
+        }
+
+        let ret_value = match &body[closure.expr] {
+            Expr::Closure {
+                args,
+                body: closure_body,
+                ..
+            } => {
+                for (i, pat) in args.iter().enumerate() {
+                    let param = self.fn_value.get_nth_param((i + 1) as u32).unwrap();
+                    self.gen_param(*pat, param);
+                }
+
+                let ret_value = self.gen_expr(*closure_body);
+                if self.infer[*closure_body].is_never() {
+                    return;
+                }
+                ret_value
+            }
+            _ => {
+                // The lifted function of a named function forwards its arguments
+                let (function, substs) = closure
+                    .target(self.db)
+                    .expect("expected a closure or a path to a function");
+                let args: Vec<BasicMetadataValueEnum> = self
+                    .fn_value
+                    .get_param_iter()
+                    .skip(1)
+                    .map(Into::into)
+                    .collect();
+                self.gen_fn_call(closure.expr, function, &substs, &args)
+            }
+        };
+
+        if closure.sig(self.db).ret().is_empty() {
+            self.builder.build_return(None);
+        } else if let Some(value) = ret_value {
+            self.builder.build_return(Some(&value));
+        }
+    }
+
+    /// Stores the value of a function parameter in the bindings introduced by the specified
+    /// pattern.
+    fn gen_param(&mut self, pat: PatId, param: BasicValueEnum<'ink>) {
+        let body = self.body.clone(); // Avoid borrow issues
+        match &body[pat] {
+            Pat::Bind { name } => {
+                let name = name.to_string();
+                let builder = self.new_alloca_builder();
+                let param_ptr = builder.build_alloca(param.get_type(), &name);
+                builder.build_store(param_ptr, param);
+                self.pat_to_local.insert(pat, param_ptr);
+                self.pat_to_name.insert(pat, name);
+            }
+            _ => self.gen_pat(pat, param, None),
+        }
+    }
+
     pub fn gen_fn_wrapper(&mut self) {
         let fn_sig = self.hir_function.ty(self.db).callable_sig(self.db).unwrap();
         let args: Vec<BasicMetadataValueEnum> = fn_sig
@@ -168,6 +316,8 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     } else {
                         param
                     }
+                } else if ty.as_enum().is_some() || is_non_empty_tuple(ty) {
+                    deref_heap_value(&self.builder, param)
                 } else {
                     param
                 }
@@ -176,7 +326,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .collect();
 
         let ret_value = self
-            .gen_call(self.hir_function, &args)
+            .gen_call(self.hir_function, &Substitution::empty(), &args)
             .try_as_basic_value()
             .left();
 
@@ -197,10 +347,19 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     if hir_struct.data(self.db.upcast()).memory_kind
                         == mun_hir::StructMemoryKind::Value
                     {
-                        self.gen_struct_alloc_on_heap(hir_struct, value.into_struct_value())
+                        self.gen_struct_alloc_on_heap(&fn_ret_type, value.into_struct_value())
                     } else {
                         value
                     }
+                } else if let Some(hir_enum) = fn_ret_type.as_enum() {
+                    self.gen_enum_alloc_on_heap(hir_enum, value.into_struct_value())
+                } else if let Some(element_tys) = fn_ret_type.as_tuple() {
+                    self.gen_alloc_on_heap(
+                        &self.hir_types.type_id(&fn_ret_type),
+                        self.hir_types.get_tuple_type(element_tys),
+                        value.into_struct_value(),
+                        &fn_ret_type.display(self.db).to_string(),
+                    )
                 } else {
                     value
                 };
@@ -213,18 +372,28 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// value is returned.
     fn gen_expr(&mut self, expr: ExprId) -> Option<inkwell::values::BasicValueEnum<'ink>> {
         let body = self.body.clone();
-        match &body[expr] {
+        let value = match &body[expr] {
             Expr::Block {
                 ref statements,
                 tail,
             } => self.gen_block(expr, statements, *tail),
+            Expr::Path(_) if self.function_value_exprs.contains(&expr) => {
+                Some(self.gen_fn_value(expr))
+            }
             Expr::Path(ref p) => {
                 let resolver =
                     mun_hir::resolver_for_expr(self.db.upcast(), self.body.owner(), expr);
                 Some(self.gen_path_expr(p, expr, &resolver))
             }
+            Expr::Closure { .. } => Some(self.gen_fn_value(expr)),
             Expr::Literal(lit) => Some(self.gen_literal(lit, expr)),
-            Expr::RecordLit { fields, .. } => Some(self.gen_record_lit(expr, fields)),
+            Expr::RecordLit { fields, .. } => {
+                if let Some(variant) = self.infer.variant_resolution_for_expr(expr) {
+                    Some(self.gen_variant_record_lit(variant, fields))
+                } else {
+                    Some(self.gen_record_lit(expr, fields))
+                }
+            }
             Expr::BinaryOp { lhs, rhs, op } => {
                 self.gen_binary_op(expr, *lhs, *rhs, op.expect("missing op"))
             }
@@ -233,8 +402,13 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 ref callee,
                 ref args,
             } => {
+                if let Some(function) = self.infer.intrinsic_resolution(expr) {
+                    return self.gen_intrinsic_function_call(function, args);
+                }
+
                 // Get the callable definition from the map
-                match self.infer[*callee].as_callable_def() {
+                let callee_ty = self.infer[*callee].clone();
+                match callee_ty.as_callable_def() {
                     Some(mun_hir::CallableDef::Function(def)) => {
                         // Get all the arguments
                         let args: Vec<BasicMetadataValueEnum> = args
@@ -242,25 +416,19 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                             .map(|expr| self.gen_expr(*expr).expect("expected a value").into())
                             .collect();
 
-                        self.gen_call(def, &args)
-                            .try_as_basic_value()
-                            .left()
-                            // If the called function is a void function it doesn't return anything.
-                            // If this method (`gen_expr`) returns None we assume the return value
-                            // is `never`. We return a const unit struct here to ensure that at
-                            // least something is returned. This matches with the mun_hir where a
-                            // `nothing` is returned instead of a `never`.
-                            //
-                            // This unit value will also be optimized out.
-                            .or_else(|| match self.infer[expr].interned() {
-                                TyKind::Never => None,
-                                _ => Some(self.context.const_struct(&[], false).into()),
-                            })
+                        let substs = callee_ty
+                            .type_parameters()
+                            .cloned()
+                            .unwrap_or_else(Substitution::empty);
+                        self.gen_fn_call(expr, def, &substs, &args)
                     }
                     Some(mun_hir::CallableDef::Struct(_)) => {
                         Some(self.gen_named_tuple_lit(expr, args))
                     }
-                    None => panic!("expected a callable expression"),
+                    Some(mun_hir::CallableDef::EnumVariant(variant)) => {
+                        Some(self.gen_variant_tuple_lit(variant, args))
+                    }
+                    None => self.gen_fn_value_call(expr, *callee, args),
                 }
             }
             Expr::If {
@@ -269,19 +437,89 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 else_branch,
             } => self.gen_if(expr, *condition, *then_branch, *else_branch),
             Expr::Return { expr: ret_expr } => self.gen_return(expr, *ret_expr),
-            Expr::Loop { body } => self.gen_loop(expr, *body),
-            Expr::While { condition, body } => self.gen_while(expr, *condition, *body),
-            Expr::Break { expr: break_expr } => self.gen_break(expr, *break_expr),
+            Expr::Loop { body, label } => self.gen_loop(expr, *body, label.clone()),
+            Expr::While {
+                condition,
+                body,
+                label,
+            } => self.gen_while(expr, *condition, *body, label.clone()),
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            } => self.gen_for(expr, *pat, *iterable, *body, label.clone()),
+            Expr::Break {
+                expr: break_expr,
+                label,
+            } => self.gen_break(expr, *break_expr, label.as_ref()),
+            Expr::Continue { label } => self.gen_continue(expr, label.as_ref()),
             Expr::Field {
                 expr: receiver_expr,
                 name,
             } => self.gen_field(expr, *receiver_expr, name),
             Expr::Array(exprs) => self.gen_array(expr, exprs).map(Into::into),
+            Expr::Tuple { exprs } => self.gen_tuple(expr, exprs),
             Expr::Index { base, index } => self.gen_index(expr, *base, *index),
+            Expr::MethodCall {
+                receiver,
+                method_name,
+                args,
+            } => self.gen_method_call(expr, *receiver, method_name, args),
+            Expr::Cast {
+                expr: value_expr, ..
+            } => self.gen_cast(expr, *value_expr),
+            Expr::Match {
+                expr: scrutinee,
+                arms,
+            } => self.gen_match(expr, *scrutinee, arms),
             _ => unimplemented!("unimplemented expr type {:?}", &body[expr]),
+        };
+
+        // A struct that is used as a trait object is converted to a trait object
+        match self.infer.trait_object_coercion(expr) {
+            Some(t) => value.map(|value| self.gen_trait_object(expr, t, value)),
+            None => value,
         }
     }
 
+    /// Generates a trait object of the trait `t` that references the struct `value` of the
+    /// expression `expr`.
+    fn gen_trait_object(
+        &mut self,
+        expr: ExprId,
+        t: mun_hir::Trait,
+        value: BasicValueEnum<'ink>,
+    ) -> BasicValueEnum<'ink> {
+        let hir_struct = self.infer[expr]
+            .as_struct()
+            .expect("only structs can be used as a trait object");
+        let object = self.builder.build_bitcast(
+            value,
+            self.context
+                .i8_type()
+                .ptr_type(AddressSpace::Generic)
+                .ptr_type(AddressSpace::Generic),
+            "object",
+        );
+        let key = self
+            .context
+            .i64_type()
+            .const_int(trait_object_key(self.db, hir_struct), false);
+
+        let trait_object: AggregateValueEnum =
+            self.hir_types.get_dyn_trait_type(t).get_undef().into();
+        let trait_object = self
+            .builder
+            .build_insert_value(trait_object, object, 0, "trait_object")
+            .expect("Failed to initialize trait object.");
+        self.builder
+            .build_insert_value(trait_object, key, 1, "trait_object")
+            .expect("Failed to initialize trait object.")
+            .into_struct_value()
+            .into()
+    }
+
     /// Generates an IR value that represents the given `Literal`.
     fn gen_literal(&mut self, lit: &Literal, expr: ExprId) -> BasicValueEnum<'ink> {
         match lit {
@@ -335,10 +573,66 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 }
             }
 
-            Literal::String(_) => unimplemented!("string literals are not implemented yet"),
+            Literal::String(value) => self.gen_string_literal(value).into(),
+
+            // `nil` is represented by a null reference
+            Literal::Nil => self
+                .hir_types
+                .get_basic_type(&self.infer[expr])
+                .expect("an optional type must have a basic type")
+                .into_pointer_type()
+                .const_null()
+                .into(),
         }
     }
 
+    /// Generates code to allocate a new string at runtime that contains the bytes of the string
+    /// literal `value`.
+    fn gen_string_literal(&mut self, value: &str) -> PointerValue<'ink> {
+        let new_string_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::new_string,
+        );
+
+        // The bytes of the literal are stored in a constant global. The runtime copies them into
+        // a newly allocated string.
+        let bytes_ptr = self
+            .builder
+            .build_global_string_ptr(value, "str")
+            .as_pointer_value();
+
+        let length_value = self
+            .hir_types
+            .get_usize_type()
+            .const_int(value.len() as u64, false);
+
+        let allocator_handle = self.get_allocator_handle_ptr();
+
+        let untyped_string_ptr = self
+            .builder
+            .build_call(
+                new_string_fn_ptr,
+                &[
+                    bytes_ptr.into(),
+                    length_value.into(),
+                    allocator_handle.into(),
+                ],
+                "ref",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        self.builder
+            .build_bitcast(
+                untyped_string_ptr,
+                self.hir_types.get_string_reference_type(),
+                "ref<string>",
+            )
+            .into_pointer_value()
+    }
+
     /// Constructs an empty struct value e.g. `{}`
     fn gen_empty(&mut self) -> BasicValueEnum<'ink> {
         self.context.const_struct(&[], false).into()
@@ -347,12 +641,17 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Allocate a struct literal either on the stack or the heap based on the type of the struct.
     fn gen_struct_alloc(
         &mut self,
-        hir_struct: mun_hir::Struct,
+        struct_ty: &Ty,
         args: Vec<BasicValueEnum<'ink>>,
     ) -> BasicValueEnum<'ink> {
+        let (hir_struct, substs) = match struct_ty.interned() {
+            TyKind::Struct(hir_struct, substs) => (*hir_struct, substs),
+            _ => unreachable!("expected a struct type"),
+        };
+
         // Construct the struct literal
-        let struct_ty = self.hir_types.get_struct_type(hir_struct);
-        let mut value: AggregateValueEnum = struct_ty.get_undef().into();
+        let struct_ir_ty = self.hir_types.get_struct_type(hir_struct, substs);
+        let mut value: AggregateValueEnum = struct_ir_ty.get_undef().into();
         for (i, arg) in args.into_iter().enumerate() {
             value = self
                 .builder
@@ -365,17 +664,52 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             mun_hir::StructMemoryKind::Value => struct_lit.into(),
             mun_hir::StructMemoryKind::Gc => {
                 // TODO: Root memory in GC
-                self.gen_struct_alloc_on_heap(hir_struct, struct_lit)
+                self.gen_struct_alloc_on_heap(struct_ty, struct_lit)
             }
         }
     }
 
     fn gen_struct_alloc_on_heap(
         &mut self,
-        hir_struct: mun_hir::Struct,
-        struct_lit: StructValue,
+        struct_ty: &Ty,
+        struct_lit: StructValue<'ink>,
+    ) -> BasicValueEnum<'ink> {
+        let (hir_struct, substs) = match struct_ty.interned() {
+            TyKind::Struct(hir_struct, substs) => (*hir_struct, substs),
+            _ => unreachable!("expected a struct type"),
+        };
+        let struct_ir_ty = self.hir_types.get_struct_type(hir_struct, substs);
+        self.gen_alloc_on_heap(
+            &self.hir_types.type_id(struct_ty),
+            struct_ir_ty,
+            struct_lit,
+            &struct_ty.display(self.db).to_string(),
+        )
+    }
+
+    fn gen_enum_alloc_on_heap(
+        &mut self,
+        hir_enum: mun_hir::Enum,
+        enum_value: StructValue<'ink>,
+    ) -> BasicValueEnum<'ink> {
+        let enum_ir_ty = self.hir_types.get_enum_type(hir_enum);
+        self.gen_alloc_on_heap(
+            &self.hir_types.type_id(&hir_enum.ty(self.db)),
+            enum_ir_ty,
+            enum_value,
+            &hir_enum.name(self.db).to_string(),
+        )
+    }
+
+    /// Allocates an object of the type identified by `type_id` on the heap and stores `value` in
+    /// it. Returns a reference to the allocated object.
+    fn gen_alloc_on_heap(
+        &mut self,
+        type_id: &Arc<TypeId>,
+        ir_ty: StructType<'ink>,
+        value: StructValue<'ink>,
+        name: &str,
     ) -> BasicValueEnum<'ink> {
-        let struct_ir_ty = self.hir_types.get_struct_type(hir_struct);
         let new_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
             self.external_globals.dispatch_table,
             &self.builder,
@@ -385,7 +719,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let type_info_ptr = self.type_table.gen_type_info_lookup(
             self.context,
             &self.builder,
-            &self.hir_types.type_id(&hir_struct.ty(self.db)),
+            type_id,
             self.external_globals.type_table,
         );
 
@@ -411,29 +745,123 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .unwrap()
             .into_pointer_value();
 
-        // Cast the object pointer to the struct type
+        // Cast the object pointer to the specified type
         let typed_reference = self
             .builder
             .build_bitcast(
                 untyped_reference,
-                struct_ir_ty
+                ir_ty
                     .ptr_type(AddressSpace::Generic)
                     .ptr_type(AddressSpace::Generic),
-                &format!("ref<{}>", hir_struct.name(self.db)),
+                &format!("ref<{}>", name),
             )
             .into_pointer_value();
 
         // Construct a reference of the object
-        let reference = RuntimeReferenceValue::from_ptr(typed_reference, struct_ir_ty)
+        let reference = RuntimeReferenceValue::from_ptr(typed_reference, ir_ty)
             .expect("unable to construct mun reference type");
 
-        // Store the struct value
-        let struct_ptr = reference.get_data_ptr(&self.builder);
-        self.builder.build_store(struct_ptr, struct_lit);
+        // Store the value
+        let data_ptr = reference.get_data_ptr(&self.builder);
+        self.builder.build_store(data_ptr, value);
 
         reference.into()
     }
 
+    /// Constructs a value of the specified enum variant, e.g. `Foo::A(1.23, 4)`. The `args` must be
+    /// in the same order as the fields of the variant.
+    fn gen_enum_variant_alloc(
+        &mut self,
+        variant: mun_hir::EnumVariant,
+        args: Vec<BasicValueEnum<'ink>>,
+    ) -> BasicValueEnum<'ink> {
+        let hir_enum = variant.parent_enum();
+        let enum_ir_ty = self.hir_types.get_enum_type(hir_enum);
+        let variant_ir_ty = self.hir_types.get_enum_variant_type(variant);
+        let variant_name = format!("{}::{}", hir_enum.name(self.db), variant.name(self.db));
+
+        // Construct the enum on the stack so the variant can be written through a pointer of the
+        // variant type
+        let enum_ptr = self
+            .new_alloca_builder()
+            .build_alloca(enum_ir_ty, &variant_name);
+        let variant_ptr = self
+            .builder
+            .build_bitcast(
+                enum_ptr,
+                variant_ir_ty.ptr_type(AddressSpace::Generic),
+                &format!("{}_ptr", variant_name),
+            )
+            .into_pointer_value();
+
+        // Store the tag
+        let tag_ptr = self
+            .builder
+            .build_struct_gep(variant_ptr, 0, &format!("{}.tag", variant_name))
+            .expect("could not get pointer to enum tag");
+        let tag_value = self
+            .hir_types
+            .get_enum_tag_type(hir_enum)
+            .const_int(variant.index().into(), false);
+        self.builder.build_store(tag_ptr, tag_value);
+
+        // Store the fields
+        let fields_ptr = self
+            .builder
+            .build_struct_gep(variant_ptr, 2, &format!("{}.fields", variant_name))
+            .expect("could not get pointer to variant fields");
+        for (i, arg) in args.into_iter().enumerate() {
+            let field_ptr = self
+                .builder
+                .build_struct_gep(fields_ptr, i as u32, &format!("{}.{}", variant_name, i))
+                .expect("could not get pointer to variant field");
+            self.builder.build_store(field_ptr, arg);
+        }
+
+        self.builder.build_load(enum_ptr, &variant_name)
+    }
+
+    /// Generates IR for an enum variant record literal, e.g. `Foo::A { a: 1.23, b: 4 }`
+    fn gen_variant_record_lit(
+        &mut self,
+        variant: mun_hir::EnumVariant,
+        fields: &[mun_hir::RecordLitField],
+    ) -> BasicValueEnum<'ink> {
+        let mut values: Vec<(u32, BasicValueEnum)> = fields
+            .iter()
+            .map(|field| {
+                let index = variant
+                    .field(self.db, &field.name)
+                    .expect("expected a variant field")
+                    .index(self.db);
+                (
+                    index,
+                    self.gen_expr(field.expr).expect("expected a field value"),
+                )
+            })
+            .collect();
+        values.sort_by_key(|(index, _)| *index);
+
+        self.gen_enum_variant_alloc(
+            variant,
+            values.into_iter().map(|(_, value)| value).collect(),
+        )
+    }
+
+    /// Generates IR for an enum variant tuple literal, e.g. `Foo::A(1.23, 4)`
+    fn gen_variant_tuple_lit(
+        &mut self,
+        variant: mun_hir::EnumVariant,
+        args: &[ExprId],
+    ) -> BasicValueEnum<'ink> {
+        let args: Vec<BasicValueEnum> = args
+            .iter()
+            .map(|expr| self.gen_expr(*expr).expect("expected a field value"))
+            .collect();
+
+        self.gen_enum_variant_alloc(variant, args)
+    }
+
     /// Generates IR for a record literal, e.g. `Foo { a: 1.23, b: 4 }`
     fn gen_record_lit(
         &mut self,
@@ -441,32 +869,29 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         fields: &[mun_hir::RecordLitField],
     ) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
         let fields: Vec<BasicValueEnum> = fields
             .iter()
             .map(|field| self.gen_expr(field.expr).expect("expected a field value"))
             .collect();
 
-        self.gen_struct_alloc(hir_struct, fields)
+        self.gen_struct_alloc(&struct_ty, fields)
     }
 
     /// Generates IR for a named tuple literal, e.g. `Foo(1.23, 4)`
     fn gen_named_tuple_lit(&mut self, type_expr: ExprId, args: &[ExprId]) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
         let args: Vec<BasicValueEnum> = args
             .iter()
             .map(|expr| self.gen_expr(*expr).expect("expected a field value"))
             .collect();
 
-        self.gen_struct_alloc(hir_struct, args)
+        self.gen_struct_alloc(&struct_ty, args)
     }
 
     /// Generates IR for a unit struct literal, e.g `Foo`
     fn gen_unit_struct_lit(&mut self, type_expr: ExprId) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
-        self.gen_struct_alloc(hir_struct, Vec::new())
+        self.gen_struct_alloc(&struct_ty, Vec::new())
     }
 
     /// Generates IR for the specified block expression.
@@ -530,116 +955,642 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             None => None,
         };
 
-        match &self.body[pat] {
-            Pat::Bind { name } => {
-                let builder = self.new_alloca_builder();
-                let pat_ty = self.infer[pat].clone();
-                let ty = self
-                    .hir_types
-                    .get_basic_type(&pat_ty)
-                    .expect("expected basic type");
-                let ptr = builder.build_alloca(ty, &name.to_string());
-                self.pat_to_local.insert(pat, ptr);
-                self.pat_to_name.insert(pat, name.to_string());
-                if !(pat_ty.is_empty() || pat_ty.is_never()) {
-                    if let Some(value) = initializer {
-                        self.builder.build_store(ptr, value);
-                    };
-                }
-            }
-            Pat::Wild => {}
-            Pat::Missing | Pat::Path(_) => unreachable!(),
+        match initializer {
+            Some(value) => self.gen_pat(pat, value, None),
+            None => self.gen_pat_allocas(pat),
         }
         true
     }
 
-    /// Generates IR for looking up a certain path expression.
-    fn gen_path_expr(
-        &mut self,
-        path: &Path,
-        expr: ExprId,
-        resolver: &Resolver,
-    ) -> inkwell::values::BasicValueEnum<'ink> {
-        match resolver
-            .resolve_path_as_value_fully(self.db.upcast(), path)
-            .expect("unknown path")
-            .0
-        {
-            ValueNs::LocalBinding(pat) => {
-                if let Some(param) = self.pat_to_param.get(&pat) {
-                    *param
-                } else if let Some(ptr) = self.pat_to_local.get(&pat) {
-                    let name = self.pat_to_name.get(&pat).expect("could not find pat name");
-                    self.builder.build_load(*ptr, name)
-                } else {
-                    unreachable!("could not find the pattern..");
-                }
+    /// Allocates stack space for all bindings introduced by the specified pattern.
+    fn gen_pat_allocas(&mut self, pat: PatId) {
+        let body = self.body.clone();
+        match &body[pat] {
+            Pat::Bind { name } => {
+                self.gen_binding_alloca(pat, name);
             }
-            ValueNs::StructId(_) => self.gen_unit_struct_lit(expr),
-            ValueNs::FunctionId(_) => panic!("unable to generate path expression from a function"),
+            pat => pat.walk_child_pats(|child| self.gen_pat_allocas(child)),
         }
     }
 
-    /// Given an expression and its value optionally dereference the value to get to the actual
-    /// value. This is useful if we need to do an indirection to get to the actual value.
-    fn opt_deref_value(
+    /// Allocates stack space for the binding introduced by the specified pattern.
+    fn gen_binding_alloca(&mut self, pat: PatId, name: &Name) -> PointerValue<'ink> {
+        let builder = self.new_alloca_builder();
+        let ty = self
+            .hir_types
+            .get_basic_type(&self.infer[pat])
+            .expect("expected basic type");
+        let ptr = builder.build_alloca(ty, &name.to_string());
+        self.pat_to_local.insert(pat, ptr);
+        self.pat_to_name.insert(pat, name.to_string());
+        ptr
+    }
+
+    /// Generates IR that matches `value` against the specified pattern and stores the values of
+    /// all bindings introduced by the pattern. If `value` doesn't match the pattern, control flow
+    /// continues at the `no_match` block. Irrefutable patterns, like the patterns of let statements
+    /// and function parameters, don't require a `no_match` block.
+    fn gen_pat(
         &mut self,
-        expr: ExprId,
+        pat: PatId,
         value: BasicValueEnum<'ink>,
-    ) -> BasicValueEnum<'ink> {
-        let ty = &self.infer[expr];
-        if let Some(s) = ty.as_struct() {
-            if s.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Gc {
-                return deref_heap_value(&self.builder, value);
+        no_match: Option<BasicBlock<'ink>>,
+    ) {
+        let body = self.body.clone();
+        match &body[pat] {
+            Pat::Bind { name } => {
+                let ptr = self.gen_binding_alloca(pat, name);
+                let pat_ty = &self.infer[pat];
+                if !(pat_ty.is_empty() || pat_ty.is_never()) {
+                    self.builder.build_store(ptr, value);
+                }
+            }
+            Pat::Wild => {}
+            Pat::Lit { .. } => {
+                let literal = self.gen_lit_pat_value(pat);
+                let condition = self.gen_pat_compare(pat, value, literal, ComparisonKind::Eq);
+                self.gen_pat_branch(condition, no_match);
             }
+            Pat::Range { start, end } => {
+                let start = self.gen_lit_pat_value(*start);
+                let end = self.gen_lit_pat_value(*end);
+                let lower = self.gen_pat_compare(pat, value, start, ComparisonKind::Ge);
+                let upper = self.gen_pat_compare(pat, value, end, ComparisonKind::Le);
+                let condition = self.builder.build_and(lower, upper, "in_range");
+                self.gen_pat_branch(condition, no_match);
+            }
+            Pat::Path(_) => {
+                if let Some(variant) = self.infer.variant_resolution_for_pat(pat) {
+                    self.gen_variant_tag_check(variant, value, no_match);
+                }
+            }
+            Pat::TupleStruct { args, .. } => {
+                let fields = self.gen_pat_fields(pat, value, no_match);
+                for (arg, field) in args.iter().zip(fields) {
+                    self.gen_pat(*arg, field, no_match);
+                }
+            }
+            Pat::Tuple { args } => {
+                let tuple_value = value.into_struct_value();
+                for (idx, arg) in args.iter().enumerate() {
+                    let element = self
+                        .builder
+                        .build_extract_value(tuple_value, idx as u32, &format!("tuple.{}", idx))
+                        .expect("could not extract tuple element");
+                    self.gen_pat(*arg, element, no_match);
+                }
+            }
+            Pat::Record { args, .. } => {
+                let fields = self.gen_pat_fields(pat, value, no_match);
+                for arg in args {
+                    let index = match self.infer.variant_resolution_for_pat(pat) {
+                        Some(variant) => variant
+                            .field(self.db, &arg.name)
+                            .expect("expected a variant field")
+                            .index(self.db),
+                        None => self.infer[pat]
+                            .as_struct()
+                            .expect("expected a struct")
+                            .field(self.db, &arg.name)
+                            .expect("expected a struct field")
+                            .index(self.db),
+                    };
+                    self.gen_pat(arg.pat, fields[index as usize], no_match);
+                }
+            }
+            Pat::Missing => unreachable!(
+                "found missing Pattern, should not be generating IR for incomplete code"
+            ),
         }
-        value
     }
 
-    /// Generates IR for looking up a certain path expression.
-    fn gen_path_place_expr(
-        &self,
-        path: &Path,
-        _expr: ExprId,
-        resolver: &Resolver,
-    ) -> inkwell::values::PointerValue<'ink> {
-        match resolver
-            .resolve_path_as_value_fully(self.db.upcast(), path)
-            .expect("unknown path")
-            .0
-        {
-            ValueNs::LocalBinding(pat) => *self
-                .pat_to_local
-                .get(&pat)
-                .expect("unresolved local binding"),
-            ValueNs::FunctionId(_) | ValueNs::StructId(_) => {
-                panic!("no support for module definitions")
-            }
+    /// Continues code generation in a new block if `condition` holds; otherwise branches to the
+    /// `no_match` block.
+    fn gen_pat_branch(&mut self, condition: IntValue<'ink>, no_match: Option<BasicBlock<'ink>>) {
+        let no_match = no_match.expect("refutable pattern in irrefutable position");
+        let match_block = self.context.append_basic_block(self.fn_value, "pat_match");
+        self.builder
+            .build_conditional_branch(condition, match_block, no_match);
+        self.builder.position_at_end(match_block);
+    }
+
+    /// Generates the value of a literal pattern, e.g. `-5`.
+    fn gen_lit_pat_value(&mut self, pat: PatId) -> BasicValueEnum<'ink> {
+        let body = self.body.clone();
+        let (expr, negated) = match &body[pat] {
+            Pat::Lit { expr, negated } => (*expr, *negated),
+            _ => unreachable!("expected a literal pattern"),
+        };
+        let lit = match &body[expr] {
+            Expr::Literal(lit) => lit,
+            _ => unreachable!("expected a literal expression"),
+        };
+        let value = self.gen_literal(lit, expr);
+        if !negated {
+            value
+        } else if value.is_float_value() {
+            self.builder
+                .build_float_neg(value.into_float_value(), "neg")
+                .into()
+        } else {
+            self.builder
+                .build_int_neg(value.into_int_value(), "neg")
+                .into()
         }
     }
 
-    /// Generates IR to calculate a binary operation between two expressions.
-    fn gen_binary_op(
+    /// Compares the value that is matched against a pattern with a literal value.
+    fn gen_pat_compare(
         &mut self,
-        _tgt_expr: ExprId,
-        lhs: ExprId,
-        rhs: ExprId,
-        op: BinaryOp,
-    ) -> Option<BasicValueEnum<'ink>> {
-        let lhs_type = self.infer[lhs].clone();
-        match lhs_type.interned() {
-            TyKind::Bool => self.gen_binary_op_bool(lhs, rhs, op),
-            TyKind::Float(_) => self.gen_binary_op_float(lhs, rhs, op),
-            TyKind::Int(ty) => self.gen_binary_op_int(lhs, rhs, op, ty.signedness),
-            TyKind::Struct(s) => {
-                if s.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Value {
-                    self.gen_binary_op_value_struct(lhs, rhs, op)
-                } else {
-                    self.gen_binary_op_heap_struct(lhs, rhs, op)
-                }
+        pat: PatId,
+        value: BasicValueEnum<'ink>,
+        literal: BasicValueEnum<'ink>,
+        kind: ComparisonKind,
+    ) -> IntValue<'ink> {
+        match self.infer[pat].interned() {
+            TyKind::Float(_) => {
+                let predicate = match kind {
+                    ComparisonKind::Eq => FloatPredicate::OEQ,
+                    ComparisonKind::Ge => FloatPredicate::OGE,
+                    ComparisonKind::Le => FloatPredicate::OLE,
+                };
+                self.builder.build_float_compare(
+                    predicate,
+                    value.into_float_value(),
+                    literal.into_float_value(),
+                    "pat_cmp",
+                )
             }
-            _ => {
-                let rhs_type = self.infer[rhs].clone();
+            TyKind::String => {
+                let predicate = match kind {
+                    ComparisonKind::Eq => IntPredicate::EQ,
+                    ComparisonKind::Ge => IntPredicate::SGE,
+                    ComparisonKind::Le => IntPredicate::SLE,
+                };
+                let ordering = self
+                    .gen_string_compare(value.into_pointer_value(), literal.into_pointer_value());
+                let zero = ordering.get_type().const_zero();
+                self.builder
+                    .build_int_compare(predicate, ordering, zero, "pat_cmp")
+            }
+            // The only literal of an optional type is `nil`
+            TyKind::Option(_) => self
+                .builder
+                .build_is_null(value.into_pointer_value(), "pat_cmp"),
+            ty => {
+                let signed = match ty {
+                    TyKind::Int(int_ty) => int_ty
+                        .resolve(&self.db.target_data_layout())
+                        .signedness
+                        .is_signed(),
+                    _ => false,
+                };
+                let predicate = match (kind, signed) {
+                    (ComparisonKind::Eq, _) => IntPredicate::EQ,
+                    (ComparisonKind::Ge, true) => IntPredicate::SGE,
+                    (ComparisonKind::Ge, false) => IntPredicate::UGE,
+                    (ComparisonKind::Le, true) => IntPredicate::SLE,
+                    (ComparisonKind::Le, false) => IntPredicate::ULE,
+                };
+                self.builder.build_int_compare(
+                    predicate,
+                    value.into_int_value(),
+                    literal.into_int_value(),
+                    "pat_cmp",
+                )
+            }
+        }
+    }
+
+    /// Branches to `no_match` if the enum `value` does not hold the specified variant.
+    fn gen_variant_tag_check(
+        &mut self,
+        variant: mun_hir::EnumVariant,
+        value: BasicValueEnum<'ink>,
+        no_match: Option<BasicBlock<'ink>>,
+    ) {
+        let hir_enum = variant.parent_enum();
+        let tag = self
+            .builder
+            .build_extract_value(value.into_struct_value(), 0, "tag")
+            .expect("could not extract enum tag")
+            .into_int_value();
+        let expected_tag = self
+            .hir_types
+            .get_enum_tag_type(hir_enum)
+            .const_int(variant.index().into(), false);
+        let condition =
+            self.builder
+                .build_int_compare(IntPredicate::EQ, tag, expected_tag, "is_variant");
+        self.gen_pat_branch(condition, no_match);
+    }
+
+    /// Returns the values of the fields of `value` that is matched by a tuple struct or record
+    /// pattern. If the pattern refers to an enum variant, the variant of `value` is checked first.
+    fn gen_pat_fields(
+        &mut self,
+        pat: PatId,
+        value: BasicValueEnum<'ink>,
+        no_match: Option<BasicBlock<'ink>>,
+    ) -> Vec<BasicValueEnum<'ink>> {
+        if let Some(variant) = self.infer.variant_resolution_for_pat(pat) {
+            self.gen_variant_tag_check(variant, value, no_match);
+
+            // The fields can only be accessed through a pointer of the variant type
+            let hir_enum = variant.parent_enum();
+            let variant_name = format!("{}::{}", hir_enum.name(self.db), variant.name(self.db));
+            let enum_ptr = self
+                .new_alloca_builder()
+                .build_alloca(self.hir_types.get_enum_type(hir_enum), &variant_name);
+            self.builder.build_store(enum_ptr, value);
+            let variant_ptr = self
+                .builder
+                .build_bitcast(
+                    enum_ptr,
+                    self.hir_types
+                        .get_enum_variant_type(variant)
+                        .ptr_type(AddressSpace::Generic),
+                    &format!("{}_ptr", variant_name),
+                )
+                .into_pointer_value();
+            let fields_ptr = self
+                .builder
+                .build_struct_gep(variant_ptr, 2, &format!("{}.fields", variant_name))
+                .expect("could not get pointer to variant fields");
+            (0..variant.fields(self.db).len())
+                .map(|i| {
+                    let field_ptr = self
+                        .builder
+                        .build_struct_gep(fields_ptr, i as u32, &format!("{}.{}", variant_name, i))
+                        .expect("could not get pointer to variant field");
+                    self.builder
+                        .build_load(field_ptr, &format!("{}.{}", variant_name, i))
+                })
+                .collect()
+        } else {
+            let hir_struct = self.infer[pat].as_struct().expect("expected a struct");
+            let value =
+                if hir_struct.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Gc {
+                    deref_heap_value(&self.builder, value)
+                } else {
+                    value
+                };
+            let hir_struct_name = hir_struct.name(self.db);
+            hir_struct
+                .fields(self.db)
+                .into_iter()
+                .map(|field| {
+                    self.builder
+                        .build_extract_value(
+                            value.into_struct_value(),
+                            field.index(self.db),
+                            &format!("{}.{}", hir_struct_name, field.name(self.db)),
+                        )
+                        .expect("could not extract struct field")
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the cases of a `switch` instruction that jumps to the first arm that can match the
+    /// value of a scrutinee, together with the block to jump to for all other values. Returns
+    /// `None` if the top-level pattern of an arm cannot be expressed as a switch case.
+    fn gen_switch_cases(
+        &mut self,
+        arms: &[mun_hir::MatchArm],
+        arm_blocks: &[BasicBlock<'ink>],
+    ) -> Option<(
+        Vec<(IntValue<'ink>, BasicBlock<'ink>)>,
+        Option<BasicBlock<'ink>>,
+    )> {
+        let body = self.body.clone();
+        let mut cases = Vec::new();
+        let mut case_values = HashSet::new();
+        let mut default_block = None;
+        for (arm, block) in arms.iter().zip(arm_blocks) {
+            let case = match &body[arm.pat] {
+                Pat::Wild | Pat::Bind { .. } => {
+                    default_block.get_or_insert(*block);
+                    continue;
+                }
+                Pat::Lit { .. }
+                    if !matches!(
+                        self.infer[arm.pat].interned(),
+                        TyKind::Float(_) | TyKind::String | TyKind::Option(_)
+                    ) =>
+                {
+                    self.gen_lit_pat_value(arm.pat).into_int_value()
+                }
+                Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Record { .. } => {
+                    let variant = self.infer.variant_resolution_for_pat(arm.pat)?;
+                    self.hir_types
+                        .get_enum_tag_type(variant.parent_enum())
+                        .const_int(variant.index().into(), false)
+                }
+                _ => return None,
+            };
+
+            // Values that are not matched by an earlier arm are handled by the default block
+            if default_block.is_none() && case_values.insert(case.get_zero_extended_constant()?) {
+                cases.push((case, *block));
+            }
+        }
+
+        if cases.is_empty() {
+            None
+        } else {
+            Some((cases, default_block))
+        }
+    }
+
+    /// Generates IR for a match expression. A `switch` on the integer value or enum tag of the
+    /// scrutinee jumps to the first arm that can match; from there the arms are tested in order
+    /// and the first arm whose pattern matches the value of the scrutinee is evaluated.
+    fn gen_match(
+        &mut self,
+        expr: ExprId,
+        scrutinee: ExprId,
+        arms: &[mun_hir::MatchArm],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let value = self.gen_expr(scrutinee)?;
+
+        let arm_blocks: Vec<BasicBlock> = arms
+            .iter()
+            .map(|_| self.context.append_basic_block(self.fn_value, "match_arm"))
+            .collect();
+        let no_match_block = self
+            .context
+            .append_basic_block(self.fn_value, "match_no_match");
+        let merge_block = self
+            .context
+            .append_basic_block(self.fn_value, "match_merge");
+
+        match self.gen_switch_cases(arms, &arm_blocks) {
+            Some((cases, default_block)) => {
+                let discriminant = match self.infer[scrutinee].interned() {
+                    TyKind::Enum(_) => self
+                        .builder
+                        .build_extract_value(value.into_struct_value(), 0, "tag")
+                        .expect("could not extract enum tag")
+                        .into_int_value(),
+                    _ => value.into_int_value(),
+                };
+                self.builder.build_switch(
+                    discriminant,
+                    default_block.unwrap_or(no_match_block),
+                    &cases,
+                );
+            }
+            None => {
+                self.builder.build_unconditional_branch(
+                    arm_blocks.first().copied().unwrap_or(no_match_block),
+                );
+            }
+        }
+
+        let mut incoming = Vec::new();
+        for (i, arm) in arms.iter().enumerate() {
+            arm_blocks[i]
+                .move_after(self.builder.get_insert_block().unwrap())
+                .expect("programmer error, insert block is invalid");
+            self.builder.position_at_end(arm_blocks[i]);
+
+            let next_arm_block = arm_blocks.get(i + 1).copied().unwrap_or(no_match_block);
+            self.gen_pat(arm.pat, value, Some(next_arm_block));
+            if let Some(arm_value) = self.gen_expr(arm.expr) {
+                if !self.infer[arm.expr].is_never() {
+                    incoming.push((arm_value, self.builder.get_insert_block().unwrap()));
+                    self.builder.build_unconditional_branch(merge_block);
+                }
+            }
+        }
+
+        no_match_block
+            .move_after(self.builder.get_insert_block().unwrap())
+            .expect("programmer error, insert block is invalid");
+        self.builder.position_at_end(no_match_block);
+
+        // Match expressions are exhaustive, so the value always matches one of the arms
+        self.builder.build_unreachable();
+
+        if incoming.is_empty() {
+            // None of the arms return, so neither does the match expression
+            merge_block
+                .remove_from_function()
+                .expect("merge block must have a parent");
+            return None;
+        }
+
+        let current_block = self.builder.get_insert_block().unwrap();
+        merge_block.move_after(current_block).unwrap();
+        self.builder.position_at_end(merge_block);
+
+        if self.infer[expr].is_empty() {
+            return Some(self.gen_empty());
+        }
+
+        let phi = self.builder.build_phi(incoming[0].0.get_type(), "matchtmp");
+        for (value, block) in incoming.iter() {
+            phi.add_incoming(&[(value, *block)]);
+        }
+        Some(phi.as_basic_value())
+    }
+
+    /// Generates IR for looking up a certain path expression.
+    fn gen_path_expr(
+        &mut self,
+        path: &Path,
+        expr: ExprId,
+        resolver: &Resolver,
+    ) -> inkwell::values::BasicValueEnum<'ink> {
+        match resolver
+            .resolve_path_as_value_fully(self.db.upcast(), path)
+            .expect("unknown path")
+            .0
+        {
+            ValueNs::LocalBinding(pat) => self.gen_local_binding(pat),
+            ValueNs::StructId(_) => self.gen_unit_struct_lit(expr),
+            ValueNs::EnumVariantId(variant) => {
+                self.gen_enum_variant_alloc(variant.into(), Vec::new())
+            }
+            ValueNs::FunctionId(_) => panic!("unable to generate path expression from a function"),
+            ValueNs::IntrinsicFunction(_) => {
+                panic!("unable to generate path expression from an intrinsic function")
+            }
+            ValueNs::ConstId(id) => {
+                let value = mun_hir::Const::from(id)
+                    .eval(self.db)
+                    .expect("the value of a constant must be evaluable");
+                constant::gen_const_value(self.hir_types, &value, &self.infer[expr])
+            }
+            ValueNs::StaticId(id) => {
+                let hir_static = mun_hir::Static::from(id);
+                let ptr = self.gen_static_ptr(hir_static);
+                self.builder
+                    .build_load(ptr, &hir_static.name(self.db).to_string())
+            }
+        }
+    }
+
+    /// Generates IR to retrieve a pointer to the memory of the specified static. Statics of other
+    /// module groups are looked up through the global table.
+    fn gen_static_ptr(&self, hir_static: mun_hir::Static) -> PointerValue<'ink> {
+        if let Some(global) = self.static_map.get(&hir_static) {
+            global.as_pointer_value()
+        } else {
+            self.global_table.gen_global_lookup(
+                self.db,
+                self.context,
+                &self.builder,
+                hir_static,
+                global_table::static_ptr_type(self.db, self.hir_types, hir_static),
+                self.external_globals.global_table,
+            )
+        }
+    }
+
+    /// Generates IR to load the value of the specified local binding.
+    fn gen_local_binding(&mut self, pat: PatId) -> BasicValueEnum<'ink> {
+        if let Some(param) = self.pat_to_param.get(&pat) {
+            *param
+        } else if let Some(ptr) = self.pat_to_local.get(&pat) {
+            let name = self.pat_to_name.get(&pat).expect("could not find pat name");
+            self.builder.build_load(*ptr, name)
+        } else {
+            unreachable!("could not find the pattern..");
+        }
+    }
+
+    /// Generates IR that creates a function value for the closure expression or path to a named
+    /// function `expr`. The environment of the function value is allocated on the heap and holds
+    /// a pointer to the lifted function, followed by the captured values.
+    fn gen_fn_value(&mut self, expr: ExprId) -> BasicValueEnum<'ink> {
+        let index = self
+            .function_value_exprs
+            .iter()
+            .position(|e| *e == expr)
+            .expect("expected a function value expression");
+        let closure = Closure {
+            owner: FunctionInstance {
+                function: self.hir_function,
+                substs: self.substs.clone(),
+            },
+            expr,
+            index,
+        };
+        let function = *self.closure_map.get(&closure).unwrap_or_else(|| {
+            panic!(
+                "missing function value for closure: '{}'",
+                closure.name(self.db)
+            )
+        });
+
+        let captures = closure.captures(self.db);
+        let capture_types: Vec<Ty> = captures.iter().map(|(_, ty)| ty.clone()).collect();
+        let env_ir_ty = self.hir_types.get_closure_env_type(&capture_types);
+
+        // Construct the environment
+        let fn_ptr = self.builder.build_bitcast(
+            function.as_global_value().as_pointer_value(),
+            self.context.i8_type().ptr_type(AddressSpace::Generic),
+            "fn_ptr",
+        );
+        let mut value: AggregateValueEnum = env_ir_ty.get_undef().into();
+        value = self
+            .builder
+            .build_insert_value(value, fn_ptr, 0, "init")
+            .expect("Failed to initialize function pointer.");
+        for (i, (pat, _ty)) in captures.iter().enumerate() {
+            let capture = self.gen_local_binding(*pat);
+            value = self
+                .builder
+                .build_insert_value(value, capture, (i + 1) as u32, "init")
+                .expect("Failed to initialize captured value.");
+        }
+
+        let env = self.gen_alloc_on_heap(
+            &closure.env_type_id(self.db, self.hir_types),
+            env_ir_ty,
+            value.into_struct_value(),
+            &closure.name(self.db),
+        );
+        self.builder
+            .build_bitcast(env, self.hir_types.get_fn_value_type(), "fn_value")
+    }
+
+    /// Given an expression and its value optionally dereference the value to get to the actual
+    /// value. This is useful if we need to do an indirection to get to the actual value.
+    fn opt_deref_value(
+        &mut self,
+        expr: ExprId,
+        value: BasicValueEnum<'ink>,
+    ) -> BasicValueEnum<'ink> {
+        let ty = &self.infer[expr];
+        if let Some(s) = ty.as_struct() {
+            if s.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Gc {
+                return deref_heap_value(&self.builder, value);
+            }
+        }
+        value
+    }
+
+    /// Generates IR for looking up a certain path expression.
+    fn gen_path_place_expr(
+        &self,
+        path: &Path,
+        _expr: ExprId,
+        resolver: &Resolver,
+    ) -> inkwell::values::PointerValue<'ink> {
+        match resolver
+            .resolve_path_as_value_fully(self.db.upcast(), path)
+            .expect("unknown path")
+            .0
+        {
+            ValueNs::LocalBinding(pat) => *self
+                .pat_to_local
+                .get(&pat)
+                .expect("unresolved local binding"),
+            ValueNs::StaticId(id) => self.gen_static_ptr(mun_hir::Static::from(id)),
+            ValueNs::FunctionId(_)
+            | ValueNs::StructId(_)
+            | ValueNs::EnumVariantId(_)
+            | ValueNs::ConstId(_)
+            | ValueNs::IntrinsicFunction(_) => {
+                panic!("no support for module definitions")
+            }
+        }
+    }
+
+    /// Generates IR to calculate a binary operation between two expressions.
+    fn gen_binary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        lhs: ExprId,
+        rhs: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        if let Some((method, substs)) = self.infer.method_resolution(tgt_expr) {
+            return self.gen_overloaded_binary_op(tgt_expr, method, &substs, lhs, rhs, op);
+        }
+
+        let lhs_type = self.infer[lhs].clone();
+        match lhs_type.interned() {
+            TyKind::Bool => self.gen_binary_op_bool(lhs, rhs, op),
+            TyKind::Float(_) => self.gen_binary_op_float(lhs, rhs, op),
+            TyKind::Int(ty) => self.gen_binary_op_int(lhs, rhs, op, ty.signedness),
+            TyKind::Struct(s, _) => {
+                if s.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Value {
+                    self.gen_binary_op_value_struct(lhs, rhs, op)
+                } else {
+                    self.gen_binary_op_heap_struct(lhs, rhs, op)
+                }
+            }
+            // Enums are value types, so they are treated like value structs
+            TyKind::Enum(_) => self.gen_binary_op_value_struct(lhs, rhs, op),
+            TyKind::Option(_) => self.gen_binary_op_optional(lhs, rhs, op),
+            TyKind::String => self.gen_binary_op_string(lhs, rhs, op),
+            _ => {
+                let rhs_type = self.infer[rhs].clone();
                 unimplemented!(
                     "unimplemented operation {0}op{1}",
                     lhs_type.display(self.db),
@@ -649,6 +1600,104 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR for a binary operation that is overloaded by the `method` of a user-defined
+    /// type, e.g. `a + b` calls `a.add(b)`.
+    fn gen_overloaded_binary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        method: mun_hir::Function,
+        substs: &Substitution,
+        lhs: ExprId,
+        rhs: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let value = self.gen_resolved_method_call(tgt_expr, method, substs, lhs, &[rhs])?;
+        match op {
+            // `a != b` is the negation of `a.eq(b)`
+            BinaryOp::CmpOp(CmpOp::Eq { negated: true }) => {
+                Some(self.builder.build_not(value.into_int_value(), "neq").into())
+            }
+            // The result of a compound assignment is stored in the left hand side, e.g. `a += b`
+            // assigns `a.add(b)` to `a`
+            BinaryOp::Assignment { .. } => {
+                let place = self.gen_place_expr(lhs)?;
+                self.builder.build_store(place, value);
+                Some(self.gen_empty())
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Generates IR to cast the value of `value_expr` to the type of the cast expression `expr`.
+    fn gen_cast(&mut self, expr: ExprId, value_expr: ExprId) -> Option<BasicValueEnum<'ink>> {
+        let value = self
+            .gen_expr(value_expr)
+            .map(|value| self.opt_deref_value(value_expr, value))?;
+        let from_ty = self.infer[value_expr].clone();
+        let to_ty = self.infer[expr].clone();
+        let value = match (from_ty.interned(), to_ty.interned()) {
+            (TyKind::Int(from), TyKind::Int(to)) => {
+                let value = value.into_int_value();
+                let ir_ty = self.hir_types.get_int_type(*to);
+                let from_width = value.get_type().get_bit_width();
+                let to_width = ir_ty.get_bit_width();
+                if from_width > to_width {
+                    self.builder.build_int_truncate(value, ir_ty, "cast").into()
+                } else if from_width == to_width {
+                    value.into()
+                } else if from.signedness == mun_hir::Signedness::Signed {
+                    self.builder.build_int_s_extend(value, ir_ty, "cast").into()
+                } else {
+                    self.builder.build_int_z_extend(value, ir_ty, "cast").into()
+                }
+            }
+            (TyKind::Bool, TyKind::Int(to)) => {
+                let ir_ty = self.hir_types.get_int_type(*to);
+                self.builder
+                    .build_int_z_extend(value.into_int_value(), ir_ty, "cast")
+                    .into()
+            }
+            (TyKind::Int(from), TyKind::Float(to)) => {
+                let ir_ty = self.hir_types.get_float_type(*to);
+                if from.signedness == mun_hir::Signedness::Signed {
+                    self.builder
+                        .build_signed_int_to_float(value.into_int_value(), ir_ty, "cast")
+                        .into()
+                } else {
+                    self.builder
+                        .build_unsigned_int_to_float(value.into_int_value(), ir_ty, "cast")
+                        .into()
+                }
+            }
+            (TyKind::Float(from), TyKind::Int(to)) => {
+                // Use the saturating conversions, `fptosi` and `fptoui` return poison for NaN and
+                // out-of-range values. This also matches the constant evaluation of casts.
+                let value = value.into_float_value();
+                let intrinsic = get_float_to_int_sat_intrinsic(
+                    self.module,
+                    to.signedness,
+                    self.hir_types.get_int_type(*to),
+                    from.bitness,
+                    value.get_type(),
+                );
+                self.builder
+                    .build_call(intrinsic, &[value.into()], "cast")
+                    .try_as_basic_value()
+                    .left()
+                    .expect("a float to integer conversion intrinsic must return a value")
+            }
+            (TyKind::Float(_), TyKind::Float(to)) => {
+                let ir_ty = self.hir_types.get_float_type(*to);
+                self.builder
+                    .build_float_cast(value.into_float_value(), ir_ty, "cast")
+                    .into()
+            }
+            // All other valid casts are casts to the same type
+            _ => value,
+        };
+        Some(value)
+    }
+
     /// Generates IR to calculate a unary operation on an expression.
     fn gen_unary_op(&mut self, expr: ExprId, op: UnaryOp) -> Option<BasicValueEnum<'ink>> {
         let ty = &self.infer[expr];
@@ -688,6 +1737,20 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         match op {
             UnaryOp::Neg => {
                 if signedness == mun_hir::Signedness::Signed {
+                    if self.overflow_checks {
+                        // Negating the minimum value of a signed integer overflows
+                        let zero = value.get_type().const_zero();
+                        return Some(
+                            self.gen_checked_arith_op_int(
+                                zero,
+                                value,
+                                ArithOp::Subtract,
+                                signedness,
+                                "attempt to negate with overflow",
+                            )
+                            .into(),
+                        );
+                    }
                     Some(self.builder.build_int_neg(value, "neg").into())
                 } else {
                     unimplemented!("Operator {:?} is not implemented for unsigned integer", op)
@@ -840,6 +1903,119 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to calculate a binary operation between two string values.
+    fn gen_binary_op_string(
+        &mut self,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let lhs = self.gen_expr(lhs_expr)?.into_pointer_value();
+        let rhs = self.gen_expr(rhs_expr)?.into_pointer_value();
+        match op {
+            BinaryOp::ArithOp(ArithOp::Add) => Some(self.gen_string_concat(lhs, rhs).into()),
+            BinaryOp::CmpOp(op) => {
+                let ordering = self.gen_string_compare(lhs, rhs);
+                let zero = ordering.get_type().const_zero();
+                Some(
+                    self.gen_cmp_bin_op_int(ordering, zero, op, mun_hir::Signedness::Signed)
+                        .into(),
+                )
+            }
+            BinaryOp::Assignment { op } => {
+                let rhs = match op {
+                    Some(ArithOp::Add) => self.gen_string_concat(lhs, rhs),
+                    Some(op) => unimplemented!(
+                        "Assignment with {:?} operator is not implemented for string",
+                        op
+                    ),
+                    None => rhs,
+                };
+                let place = self.gen_place_expr(lhs_expr)?;
+                self.builder.build_store(place, rhs);
+                Some(self.gen_empty())
+            }
+            _ => unimplemented!("Operator {:?} is not implemented for string", op),
+        }
+    }
+
+    /// Generates a call to the runtime to allocate a new string that contains the concatenation of
+    /// `lhs` and `rhs`.
+    fn gen_string_concat(
+        &mut self,
+        lhs: PointerValue<'ink>,
+        rhs: PointerValue<'ink>,
+    ) -> PointerValue<'ink> {
+        let string_concat_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::string_concat,
+        );
+
+        let untyped_ptr_ty = self
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic);
+        let lhs = self.builder.build_bitcast(lhs, untyped_ptr_ty, "lhs");
+        let rhs = self.builder.build_bitcast(rhs, untyped_ptr_ty, "rhs");
+        let allocator_handle = self.get_allocator_handle_ptr();
+
+        let untyped_string_ptr = self
+            .builder
+            .build_call(
+                string_concat_fn_ptr,
+                &[lhs.into(), rhs.into(), allocator_handle.into()],
+                "ref",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        self.builder
+            .build_bitcast(
+                untyped_string_ptr,
+                self.hir_types.get_string_reference_type(),
+                "ref<string>",
+            )
+            .into_pointer_value()
+    }
+
+    /// Generates a call to the runtime to lexicographically compare the strings `lhs` and `rhs`.
+    /// The resulting value is negative, zero or positive if `lhs` is respectively less than, equal
+    /// to or greater than `rhs`.
+    fn gen_string_compare(
+        &mut self,
+        lhs: PointerValue<'ink>,
+        rhs: PointerValue<'ink>,
+    ) -> IntValue<'ink> {
+        let string_compare_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::string_compare,
+        );
+
+        let untyped_ptr_ty = self
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic);
+        let lhs = self.builder.build_bitcast(lhs, untyped_ptr_ty, "lhs");
+        let rhs = self.builder.build_bitcast(rhs, untyped_ptr_ty, "rhs");
+        let allocator_handle = self.get_allocator_handle_ptr();
+
+        self.builder
+            .build_call(
+                string_compare_fn_ptr,
+                &[lhs.into(), rhs.into(), allocator_handle.into()],
+                "ordering",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+    }
+
     /// Generates IR to calculate a binary operation between two heap struct values (e.g. a Mun
     /// `struct(gc)`).
     fn gen_binary_op_heap_struct(
@@ -869,6 +2045,44 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to calculate a binary operation between two optional references, e.g.
+    /// `a == nil`.
+    fn gen_binary_op_optional(
+        &mut self,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let rhs = self
+            .gen_expr(rhs_expr)
+            .expect("no rhs value")
+            .into_pointer_value();
+        match op {
+            BinaryOp::Assignment { op: None } => {
+                let place = self.gen_place_expr(lhs_expr)?;
+                self.builder.build_store(place, rhs);
+                Some(self.gen_empty())
+            }
+            BinaryOp::CmpOp(CmpOp::Eq { negated }) => {
+                let lhs = self.gen_expr(lhs_expr)?.into_pointer_value();
+                let usize_type = self.hir_types.get_usize_type();
+                let lhs = self.builder.build_ptr_to_int(lhs, usize_type, "lhs_addr");
+                let rhs = self.builder.build_ptr_to_int(rhs, usize_type, "rhs_addr");
+                let (name, predicate) = if negated {
+                    ("neq", IntPredicate::NE)
+                } else {
+                    ("eq", IntPredicate::EQ)
+                };
+                Some(
+                    self.builder
+                        .build_int_compare(predicate, lhs, rhs, name)
+                        .into(),
+                )
+            }
+            _ => unimplemented!("Operator {:?} is not implemented for optionals", op),
+        }
+    }
+
     /// Generates IR to calculate a binary operation between two value struct values, denoted in
     /// Mun as `struct(value)`.
     fn gen_binary_op_value_struct(
@@ -977,6 +2191,25 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         op: ArithOp,
         signedness: mun_hir::Signedness,
     ) -> IntValue<'ink> {
+        if let ArithOp::Divide | ArithOp::Remainder = op {
+            self.gen_division_check(lhs, rhs, op, signedness);
+        }
+
+        if self.overflow_checks {
+            let message = match op {
+                ArithOp::Add => Some("attempt to add with overflow"),
+                ArithOp::Subtract => Some("attempt to subtract with overflow"),
+                ArithOp::Multiply => Some("attempt to multiply with overflow"),
+                _ => None,
+            };
+            if let Some(message) = message {
+                return self.gen_checked_arith_op_int(lhs, rhs, op, signedness, message);
+            }
+            if let ArithOp::LeftShift | ArithOp::RightShift = op {
+                self.gen_shift_check(rhs, op);
+            }
+        }
+
         match op {
             ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
             ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
@@ -1004,6 +2237,104 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to add, subtract or multiply two integers with an LLVM intrinsic that also
+    /// returns whether the operation overflowed, e.g. `llvm.sadd.with.overflow.i32`. If it did, a
+    /// panic with the specified `message` is raised.
+    fn gen_checked_arith_op_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+        message: &str,
+    ) -> IntValue<'ink> {
+        let intrinsic =
+            get_overflow_intrinsic(self.context, self.module, op, signedness, lhs.get_type());
+        let result = self
+            .builder
+            .build_call(intrinsic, &[lhs.into(), rhs.into()], "checked")
+            .try_as_basic_value()
+            .left()
+            .expect("an overflow intrinsic must return a value")
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(result, 0, "result")
+            .expect("could not extract the result of a checked operation")
+            .into_int_value();
+        let overflowed = self
+            .builder
+            .build_extract_value(result, 1, "overflowed")
+            .expect("could not extract the overflow flag of a checked operation")
+            .into_int_value();
+        self.gen_panic_if(overflowed, message);
+        value
+    }
+
+    /// Generates a check that panics if the shift amount `rhs` is not smaller than the number of
+    /// bits of the shifted integer, in which case the result of the shift would be undefined.
+    fn gen_shift_check(&mut self, rhs: IntValue<'ink>, op: ArithOp) {
+        let message = match op {
+            ArithOp::LeftShift => "attempt to shift left with overflow",
+            _ => "attempt to shift right with overflow",
+        };
+
+        // A negative shift amount is larger than the bit width when it's compared as unsigned
+        let ty = rhs.get_type();
+        let bit_width = ty.const_int(ty.get_bit_width() as u64, false);
+        let overflowed =
+            self.builder
+                .build_int_compare(IntPredicate::UGE, rhs, bit_width, "shift_overflowed");
+        self.gen_panic_if(overflowed, message);
+    }
+
+    /// Generates checks that panic if `lhs` cannot be divided by `rhs`. This is the case if `rhs`
+    /// is zero or, for signed integers, if the division overflows.
+    fn gen_division_check(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+    ) {
+        let (zero_message, overflow_message) = match op {
+            ArithOp::Divide => (
+                "attempt to divide by zero",
+                "attempt to divide with overflow",
+            ),
+            _ => (
+                "attempt to calculate the remainder with a divisor of zero",
+                "attempt to calculate the remainder with overflow",
+            ),
+        };
+
+        let ty = rhs.get_type();
+        let is_zero =
+            self.builder
+                .build_int_compare(IntPredicate::EQ, rhs, ty.const_zero(), "is_zero");
+        self.gen_panic_if(is_zero, zero_message);
+
+        // Dividing the minimum value of a signed integer by `-1` overflows
+        if signedness.is_signed() {
+            let min_value = ty
+                .const_int(1, false)
+                .const_shl(ty.const_int(u64::from(ty.get_bit_width() - 1), false));
+            let is_min_value =
+                self.builder
+                    .build_int_compare(IntPredicate::EQ, lhs, min_value, "is_min_value");
+            let is_minus_one = self.builder.build_int_compare(
+                IntPredicate::EQ,
+                rhs,
+                ty.const_all_ones(),
+                "is_minus_one",
+            );
+            let overflows = self
+                .builder
+                .build_and(is_min_value, is_minus_one, "overflows");
+            self.gen_panic_if(overflows, overflow_message);
+        }
+    }
+
     fn gen_arith_bin_op_float(
         &mut self,
         lhs: FloatValue<'ink>,
@@ -1075,13 +2406,28 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         self.module_group.should_runtime_link_fn(self.db, function)
     }
 
-    /// Generates IR for a function call.
+    /// Generates IR for a function call. For a generic function, the instance with the type
+    /// arguments `substs` is called.
     fn gen_call(
         &mut self,
         function: mun_hir::Function,
+        substs: &Substitution,
         args: &[BasicMetadataValueEnum<'ink>],
     ) -> CallSiteValue<'ink> {
-        if self.should_use_dispatch_table(function) {
+        if function.is_generic(self.db) {
+            let instance = FunctionInstance {
+                function,
+                substs: substs.clone(),
+            };
+            let llvm_function = self.instance_map.get(&instance).unwrap_or_else(|| {
+                panic!(
+                    "missing function value for instance: '{}'",
+                    instance.name_in_module(self.db),
+                )
+            });
+            self.builder
+                .build_call(*llvm_function, args, &instance.name_in_module(self.db))
+        } else if self.should_use_dispatch_table(function) {
             let ptr_value = self.dispatch_table.gen_function_lookup(
                 self.db,
                 self.external_globals.dispatch_table,
@@ -1089,19 +2435,106 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 function,
             );
             self.builder
-                .build_call(ptr_value, args, &function.name(self.db).to_string())
+                .build_call(ptr_value, args, &function.name_in_module(self.db))
         } else {
             let llvm_function = self.function_map.get(&function).unwrap_or_else(|| {
                 panic!(
                     "missing function value for mun_hir function: '{}'",
-                    function.name(self.db),
+                    function.name_in_module(self.db),
                 )
             });
             self.builder
-                .build_call(*llvm_function, args, &function.name(self.db).to_string())
+                .build_call(*llvm_function, args, &function.name_in_module(self.db))
         }
     }
 
+    /// Generates IR for a call to a Mun function, returning the value of the call expression
+    /// `expr`.
+    fn gen_fn_call(
+        &mut self,
+        expr: ExprId,
+        function: mun_hir::Function,
+        substs: &Substitution,
+        args: &[BasicMetadataValueEnum<'ink>],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let call = self.gen_call(function, substs, args);
+
+        // Propagate a panic that occurred in the called Mun function
+        if !function.is_extern(self.db) {
+            self.gen_panic_check();
+        }
+
+        self.gen_call_result(expr, call)
+    }
+
+    /// Generates IR for a call through the function value `callee`, returning the value of the
+    /// call expression `expr`. The function value is passed as the first argument, to provide the
+    /// called function access to its environment.
+    fn gen_fn_value_call(
+        &mut self,
+        expr: ExprId,
+        callee: ExprId,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let fn_value = self.gen_expr(callee)?.into_pointer_value();
+        let args: Vec<BasicMetadataValueEnum> = std::iter::once(fn_value.into())
+            .chain(
+                args.iter()
+                    .map(|expr| self.gen_expr(*expr).expect("expected a value").into()),
+            )
+            .collect();
+
+        // Load the function pointer from the first field of the environment
+        let i8_ptr_type = self.context.i8_type().ptr_type(AddressSpace::Generic);
+        let env_ptr = self.builder.build_load(fn_value, "env");
+        let fn_ptr_ptr = self
+            .builder
+            .build_bitcast(
+                env_ptr,
+                i8_ptr_type.ptr_type(AddressSpace::Generic),
+                "fn_ptr_ptr",
+            )
+            .into_pointer_value();
+        let fn_ptr = self.builder.build_load(fn_ptr_ptr, "fn_ptr");
+
+        let fn_sig = self.infer[callee]
+            .callable_sig(self.db)
+            .expect("expected a callable expression");
+        let fn_type = self.hir_types.get_closure_function_type(&fn_sig);
+        let function: CallableValue = self
+            .builder
+            .build_bitcast(fn_ptr, fn_type.ptr_type(AddressSpace::Generic), "fn")
+            .into_pointer_value()
+            .try_into()
+            .expect("Pointer value is not a valid function pointer.");
+        let call = self.builder.build_call(function, &args, "call");
+
+        // Propagate a panic that occurred in the called Mun function
+        self.gen_panic_check();
+
+        self.gen_call_result(expr, call)
+    }
+
+    /// Returns the value of the call expression `expr` from the IR value of the call.
+    fn gen_call_result(
+        &self,
+        expr: ExprId,
+        call: CallSiteValue<'ink>,
+    ) -> Option<BasicValueEnum<'ink>> {
+        call.try_as_basic_value()
+            .left()
+            // If the called function is a void function it doesn't return anything. If this method
+            // (`gen_expr`) returns None we assume the return value is `never`. We return a const
+            // unit struct here to ensure that at least something is returned. This matches with
+            // the mun_hir where a `nothing` is returned instead of a `never`.
+            //
+            // This unit value will also be optimized out.
+            .or_else(|| match self.infer[expr].interned() {
+                TyKind::Never => None,
+                _ => Some(self.context.const_struct(&[], false).into()),
+            })
+    }
+
     /// Generates IR for an if statement.
     fn gen_if(
         &mut self,
@@ -1111,10 +2544,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         else_branch: Option<ExprId>,
     ) -> Option<inkwell::values::BasicValueEnum<'ink>> {
         // Generate IR for the condition
-        let condition_ir = self
-            .gen_expr(condition)
-            .map(|value| self.opt_deref_value(condition, value))?
-            .into_int_value();
+        let condition_ir = self.gen_condition(condition)?;
 
         // Generate the code blocks to branch to
         let mut then_block = self.context.append_basic_block(self.fn_value, "then");
@@ -1129,8 +2559,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         // Build the actual branching IR for the if statement
         let else_block = else_block_and_expr.map(|e| e.0).unwrap_or(merge_block);
-        self.builder
-            .build_conditional_branch(condition_ir, then_block, else_block);
+        self.gen_condition_branch(condition, condition_ir, then_block, else_block);
 
         // Fill the then block
         self.builder.position_at_end(then_block);
@@ -1183,6 +2612,51 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR for the condition of an `if` or `while` expression. For a `let` condition, e.g.
+    /// `let a = b`, this is the value of the optional `b`.
+    fn gen_condition(&mut self, condition: ExprId) -> Option<BasicValueEnum<'ink>> {
+        let condition = match &self.body[condition] {
+            Expr::Let { expr, .. } => *expr,
+            _ => condition,
+        };
+        self.gen_expr(condition)
+            .map(|value| self.opt_deref_value(condition, value))
+    }
+
+    /// Generates IR that branches to `then_block` if the condition holds, and to `else_block`
+    /// otherwise. A `let` condition holds if its value is not `nil`, in which case the value is
+    /// bound to its pattern before entering `then_block`.
+    fn gen_condition_branch(
+        &mut self,
+        condition: ExprId,
+        condition_ir: BasicValueEnum<'ink>,
+        then_block: BasicBlock<'ink>,
+        else_block: BasicBlock<'ink>,
+    ) {
+        let body = self.body.clone();
+        match &body[condition] {
+            Expr::Let { pat, .. } => {
+                let is_some = self
+                    .builder
+                    .build_is_not_null(condition_ir.into_pointer_value(), "is_some");
+                let bind_block = self.context.append_basic_block(self.fn_value, "let_some");
+                self.builder
+                    .build_conditional_branch(is_some, bind_block, else_block);
+
+                self.builder.position_at_end(bind_block);
+                self.gen_pat(*pat, condition_ir, Some(else_block));
+                self.builder.build_unconditional_branch(then_block);
+            }
+            _ => {
+                self.builder.build_conditional_branch(
+                    condition_ir.into_int_value(),
+                    then_block,
+                    else_block,
+                );
+            }
+        }
+    }
+
     fn gen_return(
         &mut self,
         _expr: ExprId,
@@ -1200,10 +2674,25 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         None
     }
 
+    /// Returns the loop targeted by a `break` or `continue` expression. This is the loop with the
+    /// specified label, or the innermost loop if no label is specified.
+    fn target_loop(&mut self, label: Option<&Name>) -> &mut LoopInfo<'ink> {
+        match label {
+            Some(label) => self
+                .active_loops
+                .iter_mut()
+                .rev()
+                .find(|loop_info| loop_info.label.as_ref() == Some(label)),
+            None => self.active_loops.last_mut(),
+        }
+        .expect("break or continue must be inside of a loop")
+    }
+
     fn gen_break(
         &mut self,
         _expr: ExprId,
         break_expr: Option<ExprId>,
+        label: Option<&Name>,
     ) -> Option<BasicValueEnum<'ink>> {
         match break_expr {
             Some(expr) => {
@@ -1214,44 +2703,55 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
                 // If the expression never returns, we can stop what we're doing.
                 if let Some(break_value) = break_value {
-                    let loop_info = self.active_loop.as_mut().unwrap();
-                    loop_info.break_values.push(Some((
-                        break_value,
-                        self.builder.get_insert_block().unwrap(),
-                    )));
-                    self.builder
-                        .build_unconditional_branch(loop_info.exit_block);
+                    let break_block = self.builder.get_insert_block().unwrap();
+                    let loop_info = self.target_loop(label);
+                    loop_info
+                        .break_values
+                        .push(Some((break_value, break_block)));
+                    let exit_block = loop_info.exit_block;
+                    self.builder.build_unconditional_branch(exit_block);
                 }
             }
             None => {
                 // If the break expression doesnt contain a break statement. Add a none to the
                 // break values.
-                let loop_info = self.active_loop.as_mut().unwrap();
+                let loop_info = self.target_loop(label);
                 loop_info.break_values.push(None);
-                self.builder
-                    .build_unconditional_branch(loop_info.exit_block);
+                let exit_block = loop_info.exit_block;
+                self.builder.build_unconditional_branch(exit_block);
             }
         };
         None
     }
 
+    fn gen_continue(
+        &mut self,
+        _expr: ExprId,
+        label: Option<&Name>,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let continue_block = self.target_loop(label).continue_block;
+        self.builder.build_unconditional_branch(continue_block);
+        None
+    }
+
     fn gen_loop_block_expr(
         &mut self,
         block: ExprId,
+        label: Option<Name>,
         exit_block: BasicBlock<'ink>,
+        continue_block: BasicBlock<'ink>,
     ) -> (
         BasicBlock<'ink>,
         BreakSources<'ink>,
         Option<BasicValueEnum<'ink>>,
     ) {
-        // Build a new loop info struct
-        let loop_info = LoopInfo {
+        // Build a new loop info struct and make it the innermost loop
+        self.active_loops.push(LoopInfo {
+            label,
             exit_block,
             break_values: Vec::new(),
-        };
-
-        // Replace previous loop info
-        let prev_loop = std::mem::replace(&mut self.active_loop, Some(loop_info));
+            continue_block,
+        });
 
         // Start generating code inside the loop
         let value = self.gen_expr(block);
@@ -1259,7 +2759,11 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let LoopInfo {
             exit_block,
             break_values,
-        } = std::mem::replace(&mut self.active_loop, prev_loop).unwrap();
+            ..
+        } = self
+            .active_loops
+            .pop()
+            .expect("the active loop must still be present");
 
         (exit_block, break_values, value)
     }
@@ -1269,6 +2773,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         _expr: ExprId,
         condition_expr: ExprId,
         body_expr: ExprId,
+        label: Option<Name>,
     ) -> Option<BasicValueEnum<'ink>> {
         let context = self.context;
         let cond_block = context.append_basic_block(self.fn_value, "whilecond");
@@ -1280,35 +2785,208 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         // Generate condition block
         self.builder.position_at_end(cond_block);
-        let condition_ir = self
-            .gen_expr(condition_expr)
-            .map(|value| self.opt_deref_value(condition_expr, value));
-        if let Some(condition_ir) = condition_ir {
-            self.builder.build_conditional_branch(
-                condition_ir.into_int_value(),
-                loop_block,
-                exit_block,
-            );
+        if let Some(condition_ir) = self.gen_condition(condition_expr) {
+            self.gen_condition_branch(condition_expr, condition_ir, loop_block, exit_block);
         } else {
             // If the condition doesn't return a value, we also immediately return without a value.
             // This can happen if the expression is a `never` expression.
             return None;
         }
 
-        // Generate loop block
+        // Generate loop block
+        self.builder.position_at_end(loop_block);
+        let (exit_block, _, value) =
+            self.gen_loop_block_expr(body_expr, label, exit_block, cond_block);
+        if value.is_some() {
+            self.builder.build_unconditional_branch(cond_block);
+        }
+
+        // Generate exit block
+        self.builder.position_at_end(exit_block);
+
+        Some(self.gen_empty())
+    }
+
+    /// Generates a `for` loop over either an integer range or the elements of an array. A counter
+    /// is stored on the stack and incremented after every iteration, until it reaches the end of
+    /// the range or the length of the array. A loop that breaks with a value evaluates to `nil` if
+    /// it finishes without breaking.
+    fn gen_for(
+        &mut self,
+        expr: ExprId,
+        pat: PatId,
+        iterable_expr: ExprId,
+        body_expr: ExprId,
+        label: Option<Name>,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let body = self.body.clone();
+
+        // Determine the start of the iteration, and what to iterate over
+        let (start, iterable) = match &body[iterable_expr] {
+            Expr::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let signedness = match self.infer[*start].interned() {
+                    TyKind::Int(int_ty) => int_ty.signedness,
+                    _ => unreachable!("the bounds of a range must be integers"),
+                };
+                let start = self.gen_expr(*start)?.into_int_value();
+                let end = self.gen_expr(*end)?.into_int_value();
+                (
+                    start,
+                    ForIterable::Range {
+                        end,
+                        inclusive: *inclusive,
+                        signedness,
+                    },
+                )
+            }
+            _ => {
+                // Safety: type inference guarantees that the iterable is an array.
+                let array = unsafe {
+                    RuntimeArrayValue::from_ptr_unchecked(
+                        self.gen_expr(iterable_expr)?.into_pointer_value(),
+                    )
+                };
+                (
+                    self.hir_types.get_usize_type().const_zero(),
+                    ForIterable::Array(array),
+                )
+            }
+        };
+
+        let counter = self
+            .new_alloca_builder()
+            .build_alloca(start.get_type(), "for_counter");
+        self.builder.build_store(counter, start);
+
+        let context = self.context;
+        let cond_block = context.append_basic_block(self.fn_value, "forcond");
+        let loop_block = context.append_basic_block(self.fn_value, "for");
+        let step_block = context.append_basic_block(self.fn_value, "forstep");
+        let exit_block = context.append_basic_block(self.fn_value, "afterfor");
+
+        // Insert an explicit fall through from the current block to the condition check
+        self.builder.build_unconditional_branch(cond_block);
+
+        // Generate condition block
+        self.builder.position_at_end(cond_block);
+        let index = self.builder.build_load(counter, "index").into_int_value();
+        let condition = match &iterable {
+            ForIterable::Range {
+                end,
+                inclusive,
+                signedness,
+            } => self.gen_cmp_bin_op_int(
+                index,
+                *end,
+                CmpOp::Ord {
+                    ordering: Ordering::Less,
+                    strict: !inclusive,
+                },
+                *signedness,
+            ),
+            ForIterable::Array(array) => {
+                // The length is loaded every iteration because the array may be modified by the
+                // body of the loop.
+                let length = self
+                    .builder
+                    .build_load(array.get_length_ptr(&self.builder), "length")
+                    .into_int_value();
+                self.gen_cmp_bin_op_int(
+                    index,
+                    length,
+                    CmpOp::Ord {
+                        ordering: Ordering::Less,
+                        strict: true,
+                    },
+                    mun_hir::Signedness::Unsigned,
+                )
+            }
+        };
+        self.builder
+            .build_conditional_branch(condition, loop_block, exit_block);
+        let mut finished_blocks = vec![self.builder.get_insert_block().unwrap()];
+
+        // Generate loop block, which binds the current item to the pattern
         self.builder.position_at_end(loop_block);
-        let (exit_block, _, value) = self.gen_loop_block_expr(body_expr, exit_block);
+        let item = match &iterable {
+            ForIterable::Range { .. } => index.into(),
+            ForIterable::Array(array) => {
+                let elements = array.get_elements(&self.builder);
+                let element_ptr = unsafe {
+                    self.builder.build_gep(
+                        elements,
+                        &[index],
+                        &format!("{}+index", elements.get_name().to_string_lossy()),
+                    )
+                };
+                self.builder.build_load(element_ptr, "item")
+            }
+        };
+        self.gen_pat(pat, item, None);
+        let (exit_block, break_values, value) =
+            self.gen_loop_block_expr(body_expr, label, exit_block, step_block);
         if value.is_some() {
-            self.builder.build_unconditional_branch(cond_block);
+            self.builder.build_unconditional_branch(step_block);
+        }
+
+        // Generate step block, which increments the counter
+        self.builder.position_at_end(step_block);
+        let index = self.builder.build_load(counter, "index").into_int_value();
+        if let ForIterable::Range {
+            end,
+            inclusive: true,
+            ..
+        } = &iterable
+        {
+            // An inclusive range must stop before incrementing past its end, as the end might be
+            // the maximum value of its type.
+            let increment_block = context.append_basic_block(self.fn_value, "forincrement");
+            let is_last = self
+                .builder
+                .build_int_compare(IntPredicate::EQ, index, *end, "is_last");
+            self.builder
+                .build_conditional_branch(is_last, exit_block, increment_block);
+            finished_blocks.push(self.builder.get_insert_block().unwrap());
+            self.builder.position_at_end(increment_block);
         }
+        let next = self
+            .builder
+            .build_int_add(index, index.get_type().const_int(1, false), "next");
+        self.builder.build_store(counter, next);
+        self.builder.build_unconditional_branch(cond_block);
 
         // Generate exit block
         self.builder.position_at_end(exit_block);
 
-        Some(self.gen_empty())
+        // A loop that breaks with values evaluates to an optional value
+        if !matches!(self.infer[expr].interned(), TyKind::Option(_)) {
+            return Some(self.gen_empty());
+        }
+        let value_type = self
+            .hir_types
+            .get_basic_type(&self.infer[expr])
+            .expect("an optional type must have a basic type");
+        let phi = self.builder.build_phi(value_type, "for_value");
+        let nil = value_type.into_pointer_type().const_null();
+        for block in finished_blocks {
+            phi.add_incoming(&[(&nil, block)]);
+        }
+        for (value, block) in break_values.into_iter().flatten() {
+            phi.add_incoming(&[(&value, block)]);
+        }
+        Some(phi.as_basic_value())
     }
 
-    fn gen_loop(&mut self, _expr: ExprId, body_expr: ExprId) -> Option<BasicValueEnum<'ink>> {
+    fn gen_loop(
+        &mut self,
+        _expr: ExprId,
+        body_expr: ExprId,
+        label: Option<Name>,
+    ) -> Option<BasicValueEnum<'ink>> {
         let context = self.context;
         let loop_block = context.append_basic_block(self.fn_value, "loop");
         let exit_block = context.append_basic_block(self.fn_value, "exit");
@@ -1318,7 +2996,8 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         // Generate the body of the loop
         self.builder.position_at_end(loop_block);
-        let (exit_block, break_values, value) = self.gen_loop_block_expr(body_expr, exit_block);
+        let (exit_block, break_values, value) =
+            self.gen_loop_block_expr(body_expr, label, exit_block, loop_block);
         if value.is_some() {
             self.builder.build_unconditional_branch(loop_block);
         }
@@ -1350,22 +3029,30 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Returns the name of the type of `receiver_expr` and the index of its field called `name`.
+    /// The receiver is either a struct or a tuple.
+    fn field_index(&self, receiver_expr: ExprId, name: &Name) -> (String, u32) {
+        let receiver_ty = &self.infer[receiver_expr];
+        if receiver_ty.as_tuple().is_some() {
+            let field_idx = name.as_tuple_index().expect("expected a tuple field");
+            return (receiver_ty.display(self.db).to_string(), field_idx as u32);
+        }
+
+        let hir_struct = receiver_ty.as_struct().expect("expected a struct");
+        let field_idx = hir_struct
+            .field(self.db, name)
+            .expect("expected a struct field")
+            .index(self.db);
+        (hir_struct.name(self.db).to_string(), field_idx)
+    }
+
     fn gen_field(
         &mut self,
         _expr: ExprId,
         receiver_expr: ExprId,
         name: &Name,
     ) -> Option<BasicValueEnum<'ink>> {
-        let hir_struct = self.infer[receiver_expr]
-            .as_struct()
-            .expect("expected a struct");
-
-        let hir_struct_name = hir_struct.name(self.db);
-
-        let field_idx = hir_struct
-            .field(self.db, name)
-            .expect("expected a struct field")
-            .index(self.db);
+        let (hir_struct_name, field_idx) = self.field_index(receiver_expr, name);
 
         let field_ir_name = &format!("{}.{}", hir_struct_name, name);
         if self.is_place_expr(receiver_expr) {
@@ -1411,16 +3098,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         receiver_expr: ExprId,
         name: &Name,
     ) -> Option<PointerValue<'ink>> {
-        let hir_struct = self.infer[receiver_expr]
-            .as_struct()
-            .expect("expected a struct");
-
-        let hir_struct_name = hir_struct.name(self.db);
-
-        let field_idx = hir_struct
-            .field(self.db, name)
-            .expect("expected a struct field")
-            .index(self.db);
+        let (hir_struct_name, field_idx) = self.field_index(receiver_expr, name);
 
         let receiver_ptr = self.gen_place_expr(receiver_expr)?;
         let receiver_ptr = self
@@ -1442,6 +3120,29 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         )
     }
 
+    /// Generates code to construct a tuple, e.g. `(1, 2.0)`. Returns `None` if the code generation
+    /// for one of the elements never returns.
+    fn gen_tuple(&mut self, expr: ExprId, exprs: &[ExprId]) -> Option<BasicValueEnum<'ink>> {
+        let element_tys = self.infer[expr]
+            .as_tuple()
+            .expect("the type of a tuple expression must be a Tuple")
+            .clone();
+
+        let mut value: AggregateValueEnum = self
+            .hir_types
+            .get_tuple_type(&element_tys)
+            .get_undef()
+            .into();
+        for (idx, expr) in exprs.iter().enumerate() {
+            let element = self.gen_expr(*expr)?;
+            value = self
+                .builder
+                .build_insert_value(value, element, idx as u32, "init")
+                .expect("Failed to initialize tuple element.");
+        }
+        Some(value.into_struct_value().into())
+    }
+
     /// Generates code to construct an array literal at runtime. Returns `None` if the code
     /// generation for the array literal never returns.
     fn gen_array(&mut self, expr: ExprId, exprs: &[ExprId]) -> Option<RuntimeArrayValue<'ink>> {
@@ -1554,8 +3255,11 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let base = unsafe {
             RuntimeArrayValue::from_ptr_unchecked(self.gen_expr(base)?.into_pointer_value())
         };
+        let index_ty = self.infer[index].clone();
         let index = self.gen_expr(index)?.into_int_value();
 
+        self.gen_bounds_check(base, index, &index_ty, false);
+
         let elements = base.get_elements(&self.builder);
         Some(unsafe {
             self.builder.build_gep(
@@ -1566,6 +3270,595 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         })
     }
 
+    /// Generates a check whether `index` lies within the bounds of the `array`. If `inclusive` is
+    /// true, an index equal to the length of the array is also considered in bounds. If it doesn't,
+    /// the runtime is notified and the function returns.
+    fn gen_bounds_check(
+        &mut self,
+        array: RuntimeArrayValue<'ink>,
+        index: IntValue<'ink>,
+        index_ty: &mun_hir::Ty,
+        inclusive: bool,
+    ) {
+        let length = self
+            .builder
+            .build_load(array.get_length_ptr(&self.builder), "length")
+            .into_int_value();
+
+        // Compare both values in the widest of their types. A negative index is sign-extended,
+        // which makes it larger than any valid length.
+        let is_signed = matches!(
+            index_ty.interned(),
+            TyKind::Int(int_ty) if int_ty.signedness == mun_hir::Signedness::Signed
+        );
+        let (index, length) =
+            if index.get_type().get_bit_width() > length.get_type().get_bit_width() {
+                let length = self
+                    .builder
+                    .build_int_z_extend(length, index.get_type(), "length");
+                (index, length)
+            } else {
+                let index = if is_signed {
+                    self.builder
+                        .build_int_s_extend_or_bit_cast(index, length.get_type(), "index")
+                } else {
+                    self.builder
+                        .build_int_z_extend_or_bit_cast(index, length.get_type(), "index")
+                };
+                (index, length)
+            };
+
+        let predicate = if inclusive {
+            IntPredicate::UGT
+        } else {
+            IntPredicate::UGE
+        };
+        let out_of_bounds =
+            self.builder
+                .build_int_compare(predicate, index, length, "out_of_bounds");
+
+        let panic_block = self
+            .context
+            .append_basic_block(self.fn_value, "index_out_of_bounds");
+        let in_bounds_block = self.context.append_basic_block(self.fn_value, "in_bounds");
+        self.builder
+            .build_conditional_branch(out_of_bounds, panic_block, in_bounds_block);
+
+        self.builder.position_at_end(panic_block);
+        let usize_ty = self.hir_types.get_usize_type();
+        let index = self.builder.build_int_cast(index, usize_ty, "index");
+        let length = self.builder.build_int_cast(length, usize_ty, "length");
+        let panic_bounds_check_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panic_bounds_check,
+        );
+        let panic_handle = self.get_panic_handle_ptr();
+        self.builder.build_call(
+            panic_bounds_check_fn_ptr,
+            &[index.into(), length.into(), panic_handle.into()],
+            "",
+        );
+        self.gen_panic_return();
+
+        self.builder.position_at_end(in_bounds_block);
+    }
+
+    /// Generates a check whether the runtime is panicking. If so, the function returns
+    /// immediately to propagate the panic to the caller.
+    fn gen_panic_check(&mut self) {
+        let panic_handle = self.get_panic_handle_ptr();
+        let panicking = self
+            .builder
+            .build_load(panic_handle, "panicking")
+            .into_int_value();
+        let is_panicking = self.builder.build_int_compare(
+            IntPredicate::NE,
+            panicking,
+            panicking.get_type().const_zero(),
+            "is_panicking",
+        );
+
+        let unwind_block = self.context.append_basic_block(self.fn_value, "unwind");
+        let continue_block = self.context.append_basic_block(self.fn_value, "no_panic");
+        self.builder
+            .build_conditional_branch(is_panicking, unwind_block, continue_block);
+
+        self.builder.position_at_end(unwind_block);
+        self.gen_panic_return();
+
+        self.builder.position_at_end(continue_block);
+    }
+
+    /// Generates a panic with the specified `message` if `condition` is true.
+    fn gen_panic_if(&mut self, condition: IntValue<'ink>, message: &str) {
+        let panic_block = self.context.append_basic_block(self.fn_value, "panic");
+        let continue_block = self.context.append_basic_block(self.fn_value, "no_panic");
+        self.builder
+            .build_conditional_branch(condition, panic_block, continue_block);
+
+        self.builder.position_at_end(panic_block);
+        self.gen_panic_with_message(message);
+
+        self.builder.position_at_end(continue_block);
+    }
+
+    /// Generates a panic with the specified `message`, and returns from the current function.
+    fn gen_panic_with_message(&mut self, message: &str) {
+        let message_ptr = self
+            .builder
+            .build_global_string_ptr(message, "panic_message")
+            .as_pointer_value();
+        let length_value = self
+            .hir_types
+            .get_usize_type()
+            .const_int(message.len() as u64, false);
+        self.gen_panic(message_ptr, length_value);
+    }
+
+    /// Generates a panic with the message that consists of `length` UTF-8 encoded bytes stored at
+    /// `message_ptr`, and returns from the current function.
+    fn gen_panic(&mut self, message_ptr: PointerValue<'ink>, length: IntValue<'ink>) {
+        let panic_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panic,
+        );
+        let panic_handle = self.get_panic_handle_ptr();
+        self.builder.build_call(
+            panic_fn_ptr,
+            &[message_ptr.into(), length.into(), panic_handle.into()],
+            "",
+        );
+        self.gen_panic_return();
+    }
+
+    /// Generates a return from the current function while panicking. The name of the function is
+    /// added to the stack trace of the panic. The returned value is zero-initialized; it is never
+    /// observed because the panic is reported instead.
+    fn gen_panic_return(&mut self) {
+        let function_name = match &self.closure {
+            Some(closure) => closure.name(self.db),
+            None => self.hir_function.full_name(self.db),
+        };
+        let function_name_ptr = match self.function_name {
+            Some(ptr) => ptr,
+            None => {
+                let ptr = self
+                    .builder
+                    .build_global_string_ptr(&function_name, "fn_name")
+                    .as_pointer_value();
+                self.function_name = Some(ptr);
+                ptr
+            }
+        };
+        let length_value = self
+            .hir_types
+            .get_usize_type()
+            .const_int(function_name.len() as u64, false);
+        let panic_unwind_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panic_unwind,
+        );
+        let panic_handle = self.get_panic_handle_ptr();
+        self.builder.build_call(
+            panic_unwind_fn_ptr,
+            &[
+                function_name_ptr.into(),
+                length_value.into(),
+                panic_handle.into(),
+            ],
+            "",
+        );
+
+        match self.fn_value.get_type().get_return_type() {
+            Some(ty) => {
+                let value = const_zero(ty);
+                self.builder.build_return(Some(&value));
+            }
+            None => {
+                self.builder.build_return(None);
+            }
+        }
+    }
+
+    /// Generates a call to a method, which is either defined in an `impl` block or built-in.
+    fn gen_method_call(
+        &mut self,
+        expr: ExprId,
+        receiver: ExprId,
+        method_name: &Name,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        if let Some((method, substs)) = self.infer.method_resolution(expr) {
+            return self.gen_resolved_method_call(expr, method, &substs, receiver, args);
+        }
+
+        let receiver_ty = self.infer[receiver].clone();
+        match (
+            receiver_ty.interned(),
+            method_name.to_string().as_str(),
+            args,
+        ) {
+            (TyKind::String | TyKind::Array(_), "len", []) => {
+                // Safety: the type of the receiver is an array or a string, which is stored as an
+                // array
+                let array = unsafe {
+                    RuntimeArrayValue::from_ptr_unchecked(
+                        self.gen_expr(receiver)?.into_pointer_value(),
+                    )
+                };
+                let length_ptr = array.get_length_ptr(&self.builder);
+                Some(self.builder.build_load(length_ptr, "len"))
+            }
+            (TyKind::Array(_), "push", [element]) => {
+                let array = self.gen_array_receiver(receiver)?;
+                let element = self.gen_expr(*element)?;
+                self.gen_array_insert(array, None, element);
+                Some(self.gen_empty())
+            }
+            (TyKind::Array(_), "insert", [index, element]) => {
+                let array = self.gen_array_receiver(receiver)?;
+                let index_ty = self.infer[*index].clone();
+                let index = self.gen_expr(*index)?.into_int_value();
+                let element = self.gen_expr(*element)?;
+                self.gen_bounds_check(array, index, &index_ty, true);
+                self.gen_array_insert(array, Some(index), element);
+                Some(self.gen_empty())
+            }
+            (TyKind::Array(element_ty), "remove", [index]) => {
+                let array = self.gen_array_receiver(receiver)?;
+                let index_ty = self.infer[*index].clone();
+                let index = self.gen_expr(*index)?.into_int_value();
+                self.gen_bounds_check(array, index, &index_ty, false);
+                Some(self.gen_array_remove(array, index, element_ty))
+            }
+            (TyKind::Array(element_ty), "pop", []) => {
+                let array = self.gen_array_receiver(receiver)?;
+                let length = self
+                    .builder
+                    .build_load(array.get_length_ptr(&self.builder), "length")
+                    .into_int_value();
+                let is_empty = self.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    length,
+                    length.get_type().const_zero(),
+                    "is_empty",
+                );
+                self.gen_panic_if(is_empty, "cannot pop from an empty array");
+                let index = self.builder.build_int_sub(
+                    length,
+                    length.get_type().const_int(1, false),
+                    "index",
+                );
+                Some(self.gen_array_remove(array, index, element_ty))
+            }
+            _ => unimplemented!(
+                "unimplemented method `{}` for type `{}`",
+                method_name,
+                receiver_ty.display(self.db)
+            ),
+        }
+    }
+
+    /// Generates a call to a `method` that is defined in an `impl` block or trait, passing the
+    /// `receiver` as the `self` argument.
+    fn gen_resolved_method_call(
+        &mut self,
+        expr: ExprId,
+        method: mun_hir::Function,
+        substs: &Substitution,
+        receiver: ExprId,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        if let Some(t) = method.parent_trait(self.db) {
+            return self.gen_trait_object_call(expr, t, method, receiver, args);
+        }
+
+        // The receiver is passed as the `self` argument
+        let args: Vec<BasicMetadataValueEnum> = std::iter::once(receiver)
+            .chain(args.iter().copied())
+            .map(|expr| self.gen_expr(expr).expect("expected a value").into())
+            .collect();
+
+        self.gen_fn_call(expr, method, substs, &args)
+    }
+
+    /// Generates a call to a built-in function. The functions of the `core::math` module are
+    /// lowered to an LLVM intrinsic for the type of their arguments.
+    fn gen_intrinsic_function_call(
+        &mut self,
+        function: mun_hir::IntrinsicFunction,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        if function == mun_hir::IntrinsicFunction::Panic {
+            // Safety: the message of a panic is a string, which is stored as an array of bytes
+            let message = unsafe {
+                RuntimeArrayValue::from_ptr_unchecked(self.gen_expr(args[0])?.into_pointer_value())
+            };
+            let message_ptr = self
+                .builder
+                .build_bitcast(
+                    message.get_elements(&self.builder),
+                    self.context.i8_type().ptr_type(AddressSpace::Generic),
+                    "message",
+                )
+                .into_pointer_value();
+            let length_ptr = message.get_length_ptr(&self.builder);
+            let length_value = self
+                .builder
+                .build_load(length_ptr, "length")
+                .into_int_value();
+            self.gen_panic(message_ptr, length_value);
+
+            // A panic never returns
+            return None;
+        }
+
+        let ty = self.infer[args[0]].clone();
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            values.push(self.gen_expr(*arg)?);
+        }
+
+        let ir_ty = self
+            .hir_types
+            .get_basic_type(&ty)
+            .expect("the arguments of an intrinsic function must have a basic type");
+        let intrinsic = match get_math_intrinsic(self.context, self.module, function, &ty, ir_ty) {
+            Some(intrinsic) => intrinsic,
+            // The absolute value of an unsigned integer is the integer itself
+            None => return Some(values[0]),
+        };
+
+        let mut arg_values: Vec<BasicMetadataValueEnum> =
+            values.into_iter().map(Into::into).collect();
+        if intrinsic.count_params() as usize > arg_values.len() {
+            // The absolute value of the minimum value of a signed integer is not poison
+            arg_values.push(self.context.bool_type().const_zero().into());
+        }
+
+        self.builder
+            .build_call(intrinsic, &arg_values, &function.to_string())
+            .try_as_basic_value()
+            .left()
+    }
+
+    /// Generates the receiver of a built-in array method.
+    fn gen_array_receiver(&mut self, receiver: ExprId) -> Option<RuntimeArrayValue<'ink>> {
+        // Safety: built-in array methods are only resolved for receivers of an array type
+        Some(unsafe {
+            RuntimeArrayValue::from_ptr_unchecked(self.gen_expr(receiver)?.into_pointer_value())
+        })
+    }
+
+    /// Generates a call to the runtime to insert `element` into `array` at position `index`, or to
+    /// append it if no `index` is specified. The runtime grows the capacity of the array if
+    /// required, which moves its elements. Pointers into the array must not be reused afterwards.
+    fn gen_array_insert(
+        &mut self,
+        array: RuntimeArrayValue<'ink>,
+        index: Option<IntValue<'ink>>,
+        element: BasicValueEnum<'ink>,
+    ) {
+        // The element is passed to the runtime through a pointer to a copy on the stack
+        let element_ptr = self
+            .new_alloca_builder()
+            .build_alloca(element.get_type(), "element");
+        self.builder.build_store(element_ptr, element);
+
+        let byte_ptr_ty = self.context.i8_type().ptr_type(AddressSpace::Generic);
+        let element_ptr = self
+            .builder
+            .build_bitcast(element_ptr, byte_ptr_ty, "element_ptr");
+        let array_ptr = self.builder.build_bitcast(
+            PointerValue::from(array),
+            byte_ptr_ty.ptr_type(AddressSpace::Generic),
+            "array",
+        );
+        let allocator_handle = self.get_allocator_handle_ptr();
+
+        match index {
+            Some(index) => {
+                let array_insert_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+                    self.external_globals.dispatch_table,
+                    &self.builder,
+                    &intrinsics::array_insert,
+                );
+                let index =
+                    self.builder
+                        .build_int_cast(index, self.hir_types.get_usize_type(), "index");
+                self.builder.build_call(
+                    array_insert_fn_ptr,
+                    &[
+                        array_ptr.into(),
+                        index.into(),
+                        element_ptr.into(),
+                        allocator_handle.into(),
+                    ],
+                    "",
+                );
+            }
+            None => {
+                let array_push_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+                    self.external_globals.dispatch_table,
+                    &self.builder,
+                    &intrinsics::array_push,
+                );
+                self.builder.build_call(
+                    array_push_fn_ptr,
+                    &[
+                        array_ptr.into(),
+                        element_ptr.into(),
+                        allocator_handle.into(),
+                    ],
+                    "",
+                );
+            }
+        }
+    }
+
+    /// Generates a call to the runtime to remove the element at position `index` from `array`.
+    /// Returns the removed element.
+    fn gen_array_remove(
+        &mut self,
+        array: RuntimeArrayValue<'ink>,
+        index: IntValue<'ink>,
+        element_ty: &Ty,
+    ) -> BasicValueEnum<'ink> {
+        let element_ir_ty = self
+            .hir_types
+            .get_basic_type(element_ty)
+            .expect("an array element must have a basic type");
+
+        // The runtime moves the removed element to a slot on the stack
+        let element_ptr = self
+            .new_alloca_builder()
+            .build_alloca(element_ir_ty, "element");
+
+        let byte_ptr_ty = self.context.i8_type().ptr_type(AddressSpace::Generic);
+        let untyped_element_ptr =
+            self.builder
+                .build_bitcast(element_ptr, byte_ptr_ty, "element_ptr");
+        let array_ptr = self.builder.build_bitcast(
+            PointerValue::from(array),
+            byte_ptr_ty.ptr_type(AddressSpace::Generic),
+            "array",
+        );
+        let index = self
+            .builder
+            .build_int_cast(index, self.hir_types.get_usize_type(), "index");
+        let allocator_handle = self.get_allocator_handle_ptr();
+
+        let array_remove_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::array_remove,
+        );
+        self.builder.build_call(
+            array_remove_fn_ptr,
+            &[
+                array_ptr.into(),
+                index.into(),
+                untyped_element_ptr.into(),
+                allocator_handle.into(),
+            ],
+            "",
+        );
+
+        self.builder.build_load(element_ptr, "removed")
+    }
+
+    /// Generates a call to the function `method` of the trait `t` through the trait object
+    /// `receiver`. The implementation that is called is selected from the vtable of the trait with
+    /// the key stored in the trait object.
+    fn gen_trait_object_call(
+        &mut self,
+        expr: ExprId,
+        t: mun_hir::Trait,
+        method: mun_hir::Function,
+        receiver: ExprId,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let trait_object = self.gen_expr(receiver)?.into_struct_value();
+        let args: Vec<BasicMetadataValueEnum> = args
+            .iter()
+            .map(|expr| self.gen_expr(*expr).expect("expected a value").into())
+            .collect();
+
+        let object = self
+            .builder
+            .build_extract_value(trait_object, 0, "object")
+            .expect("could not extract object from trait object");
+        let key = self
+            .builder
+            .build_extract_value(trait_object, 1, "key")
+            .expect("could not extract key from trait object")
+            .into_int_value();
+
+        let function_index = t
+            .functions(self.db)
+            .iter()
+            .position(|function| *function == method)
+            .expect("method must be a function of the trait");
+        let dispatch_table = self.dispatch_table;
+        let vtable = dispatch_table.vtable(t);
+
+        let cases: Vec<(IntValue, BasicBlock)> = vtable
+            .entries
+            .iter()
+            .map(|(key, _)| {
+                (
+                    self.context.i64_type().const_int(*key, false),
+                    self.context.append_basic_block(self.fn_value, "dyn_call"),
+                )
+            })
+            .collect();
+        let no_match_block = self
+            .context
+            .append_basic_block(self.fn_value, "dyn_call_no_match");
+        let merge_block = self
+            .context
+            .append_basic_block(self.fn_value, "dyn_call_merge");
+        self.builder.build_switch(key, no_match_block, &cases);
+
+        let mut incoming = Vec::new();
+        for ((_, functions), (_, block)) in vtable.entries.iter().zip(cases.iter()) {
+            self.builder.position_at_end(*block);
+
+            // The object is passed as the `self` argument of the implementation
+            let function = functions[function_index];
+            let self_ty = function
+                .parent_impl(self.db)
+                .expect("an implementation of a trait function must be defined in an impl")
+                .self_ty(self.db);
+            let self_value = self.builder.build_bitcast(
+                object,
+                self.hir_types
+                    .get_basic_type(&self_ty)
+                    .expect("could not convert self type to basic type"),
+                "self",
+            );
+            let call_args: Vec<BasicMetadataValueEnum> = std::iter::once(self_value.into())
+                .chain(args.iter().copied())
+                .collect();
+            let call = self.gen_call(function, &Substitution::empty(), &call_args);
+
+            // Propagate a panic that occurred in the called Mun function
+            self.gen_panic_check();
+
+            if let Some(value) = call.try_as_basic_value().left() {
+                incoming.push((value, self.builder.get_insert_block().unwrap()));
+            }
+            self.builder.build_unconditional_branch(merge_block);
+        }
+
+        // The type of the object no longer implements the trait, e.g. because its `impl` block was
+        // removed by a hot reload
+        self.builder.position_at_end(no_match_block);
+        self.gen_panic_with_message(&format!(
+            "the type of the trait object no longer implements `{}`",
+            t.name(self.db)
+        ));
+
+        self.builder.position_at_end(merge_block);
+        if self.infer[expr].is_never() {
+            self.builder.build_unreachable();
+            return None;
+        } else if incoming.is_empty() {
+            return Some(self.gen_empty());
+        }
+
+        let phi = self
+            .builder
+            .build_phi(incoming[0].0.get_type(), "dyn_call_result");
+        for (value, block) in incoming.iter() {
+            phi.add_incoming(&[(value, *block)]);
+        }
+        Some(phi.as_basic_value())
+    }
+
     /// Returns a pointer to the allocator handle
     fn get_allocator_handle_ptr(&self) -> PointerValue<'ink> {
         self.builder
@@ -1578,6 +3871,37 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             )
             .into_pointer_value()
     }
+
+    /// Returns a pointer to the panic handle
+    fn get_panic_handle_ptr(&self) -> PointerValue<'ink> {
+        self.builder
+            .build_load(
+                self.external_globals
+                    .panic_handle
+                    .expect("no panic handle was specified, this is required for panics")
+                    .as_pointer_value(),
+                "panic_handle",
+            )
+            .into_pointer_value()
+    }
+}
+
+/// Returns the zero-initialized value of the specified type.
+fn const_zero(ty: BasicTypeEnum) -> BasicValueEnum {
+    match ty {
+        BasicTypeEnum::ArrayType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::FloatType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::IntType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::PointerType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::StructType(ty) => ty.const_zero().into(),
+        BasicTypeEnum::VectorType(ty) => ty.const_zero().into(),
+    }
+}
+
+/// Returns true if `ty` is a tuple with at least one element. Like value structs, such tuples are
+/// passed by reference in the public API.
+fn is_non_empty_tuple(ty: &Ty) -> bool {
+    ty.as_tuple().map_or(false, |elements| !elements.is_empty())
 }
 
 /// Derefs a heap-allocated value. As we introduce a layer of indirection for hot
@@ -0,0 +1,416 @@
+use crate::{
+    ir::{
+        instance::{FunctionInstance, Instances},
+        ty::HirTypeCache,
+    },
+    module_group::ModuleGroup,
+    type_info::{HasStaticTypeId, TypeId, TypeIdData},
+};
+use mun_abi::Guid;
+use mun_hir::{
+    Body, CallableDef, Expr, ExprId, FnSig, HirDatabase, HirDisplay, InferenceResult, ModuleDef,
+    Pat, PatId, Substitution, Ty, TyKind,
+};
+use rustc_hash::FxHashSet;
+use std::sync::Arc;
+
+/// A function that is called through a function value. Every closure expression, and every path
+/// to a named function that is used as a value, is lifted into a separate function that receives
+/// the environment of the function value as its first argument.
+///
+/// A function value is a reference to a garbage collected environment. The first field of the
+/// environment holds a pointer to the lifted function, followed by the values of the bindings
+/// that are captured by the closure:
+///
+/// ```c
+/// struct Env {
+///     void* fn_ptr;
+///     ...captures
+/// }
+/// ```
+///
+/// The type of the environment has the same name as the lifted function. The name is derived from
+/// the shape of the function value rather than its position in the body of the owner, so it
+/// survives adding or removing other function values. After hot reloading, this allows the runtime
+/// to point existing function values to the reloaded functions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Closure {
+    /// The function in whose body the function value is created
+    pub owner: FunctionInstance,
+    /// The closure expression or path expression that creates the function value
+    pub expr: ExprId,
+    /// The index of the function value among all function values created by the owner
+    pub index: usize,
+}
+
+impl Closure {
+    /// Returns the full name of the lifted function, e.g. `foo::main::{closure#1a2b3c4d}`. The
+    /// suffix is taken from the `Guid` of the closure.
+    pub fn name(&self, db: &dyn HirDatabase) -> String {
+        let guid = self.guid(db);
+        let suffix: String = guid.0[..4]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("{}::{{closure#{}}}", self.owner_name(db), suffix)
+    }
+
+    /// Returns the `Guid` that identifies the closure across compilations. It is computed from
+    /// the owner, the signature, the captures and, for a path to a named function, the target of
+    /// the function value. Function values with the same shape in the same owner are told apart
+    /// by their order.
+    pub fn guid(&self, db: &dyn HirDatabase) -> Guid {
+        let shape = self.shape(db);
+        let body = self.owner.function.body(db);
+        let infer = self.infer(db);
+        let occurrence = function_value_exprs(&body, &infer)
+            .into_iter()
+            .take(self.index)
+            .enumerate()
+            .filter(|(index, expr)| {
+                Closure {
+                    owner: self.owner.clone(),
+                    expr: *expr,
+                    index: *index,
+                }
+                .shape(db)
+                    == shape
+            })
+            .count();
+
+        Guid::from_str(&format!(
+            "closure {owner}::{shape}#{occurrence}",
+            owner = self.owner_name(db),
+            shape = shape,
+            occurrence = occurrence
+        ))
+    }
+
+    /// Returns the name of the owner, including the type arguments of a generic owner.
+    fn owner_name(&self, db: &dyn HirDatabase) -> String {
+        let owner = self.owner.function.full_name(db);
+        if self.owner.substs.is_empty() {
+            return owner;
+        }
+
+        let type_args: Vec<String> = self
+            .owner
+            .substs
+            .iter()
+            .map(|ty| ty.display(db).to_string())
+            .collect();
+        format!("{}<{}>", owner, type_args.join(", "))
+    }
+
+    /// Returns a description of the signature, the captures and the target of the function
+    /// value, e.g. `fn(core::i32) -> core::i32{offset: core::i32}`.
+    fn shape(&self, db: &dyn HirDatabase) -> String {
+        let sig = Ty::fn_ptr(self.sig(db))
+            .guid_string(db)
+            .expect("type should be convertible to a string");
+        let body = self.owner.function.body(db);
+        let captures: Vec<String> = self
+            .captures(db)
+            .into_iter()
+            .map(|(pat, ty)| {
+                let ty_string = ty
+                    .guid_string(db)
+                    .expect("type should be convertible to a string");
+                match &body[pat] {
+                    Pat::Bind { name } => format!("{}: {}", name, ty_string),
+                    _ => unreachable!("only bindings can be captured"),
+                }
+            })
+            .collect();
+
+        // Paths to different named functions with the same signature must not be swapped
+        let target = match self.target(db) {
+            Some((function, substs)) => {
+                let type_args: Vec<String> =
+                    substs.iter().map(|ty| ty.display(db).to_string()).collect();
+                format!(" = {}<{}>", function.full_name(db), type_args.join(", "))
+            }
+            None => String::new(),
+        };
+
+        format!("{}{{{}}}{}", sig, captures.join(","), target)
+    }
+
+    /// Returns the types of the body of the owner.
+    pub fn infer(&self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        if self.owner.substs.is_empty() {
+            self.owner.function.infer(db)
+        } else {
+            self.owner.infer(db)
+        }
+    }
+
+    /// Returns the signature of the function value, excluding the environment.
+    pub fn sig(&self, db: &dyn HirDatabase) -> FnSig {
+        self.infer(db)[self.expr]
+            .callable_sig(db)
+            .expect("a function value must be callable")
+    }
+
+    /// Returns the named function that is called by the function value, if the function value
+    /// was created from a path to a named function.
+    pub fn target(&self, db: &dyn HirDatabase) -> Option<(mun_hir::Function, Substitution)> {
+        match self.infer(db)[self.expr].interned() {
+            TyKind::FnDef(CallableDef::Function(function), substs) => {
+                Some((*function, substs.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the bindings that are captured by the function value and their types, in the
+    /// order in which they are stored in the environment.
+    pub fn captures(&self, db: &dyn HirDatabase) -> Vec<(PatId, Ty)> {
+        let infer = self.infer(db);
+        infer
+            .closure_captures(self.expr)
+            .iter()
+            .map(|pat| (*pat, stored_ty(db, &infer[*pat])))
+            .collect()
+    }
+
+    /// Returns the names and types of the fields of the environment.
+    pub fn env_fields(
+        &self,
+        db: &dyn HirDatabase,
+        hir_types: &HirTypeCache,
+    ) -> Vec<(String, Arc<TypeId>)> {
+        let body = self.owner.function.body(db);
+        std::iter::once((
+            String::from("fn_ptr"),
+            <*const std::ffi::c_void>::type_id().clone(),
+        ))
+        .chain(self.captures(db).into_iter().map(|(pat, ty)| {
+            let name = match &body[pat] {
+                Pat::Bind { name } => name.to_string(),
+                _ => unreachable!("only bindings can be captured"),
+            };
+            (name, hir_types.type_id(&ty))
+        }))
+        .collect()
+    }
+
+    /// Returns the `TypeId` of the environment. The `Guid` of the environment is stable across
+    /// compilations as long as the closure and the types of its captures do not change.
+    pub fn env_type_id(&self, db: &dyn HirDatabase, hir_types: &HirTypeCache) -> Arc<TypeId> {
+        let name = self.name(db);
+        let body = self.owner.function.body(db);
+        let fields: Vec<String> = std::iter::once(String::from("fn_ptr: *const core::void"))
+            .chain(self.captures(db).into_iter().map(|(pat, ty)| {
+                let ty_string = ty
+                    .guid_string(db)
+                    .expect("type should be convertible to a string");
+                match &body[pat] {
+                    Pat::Bind { name } => format!("{}: {}", name, ty_string),
+                    _ => unreachable!("only bindings can be captured"),
+                }
+            }))
+            .collect();
+
+        Arc::new(TypeId {
+            data: TypeIdData::Concrete(Guid::from_str(&format!(
+                "closure {name}{{{fields}}}",
+                name = &name,
+                fields = fields.join(",")
+            ))),
+            name,
+        })
+    }
+}
+
+/// The function values that are created and used by a module group.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FunctionValues {
+    /// The functions that are called through function values, in the order in which they were
+    /// found.
+    pub closures: Vec<Closure>,
+    /// The function pointer types that are used by the module group. Function pointer types don't
+    /// belong to a module, so every module group defines the types it uses.
+    pub fn_ptr_types: FxHashSet<Ty>,
+    /// The trait object types that are used by the module group. Like function pointer types,
+    /// trait object types are defined by every module group that uses them.
+    pub dyn_trait_types: FxHashSet<Ty>,
+    /// The tuple types that are used by the module group. Like function pointer types, tuple
+    /// types are defined by every module group that uses them.
+    pub tuple_types: FxHashSet<Ty>,
+}
+
+impl FunctionValues {
+    /// Returns true if the module group creates or calls function values.
+    pub fn has_function_values(&self) -> bool {
+        !self.closures.is_empty() || !self.fn_ptr_types.is_empty()
+    }
+}
+
+/// Returns the type with which a value of type `ty` is stored. A named function that is used as
+/// a value is stored as a function value.
+pub(crate) fn stored_ty(db: &dyn HirDatabase, ty: &Ty) -> Ty {
+    match ty.interned() {
+        TyKind::FnDef(CallableDef::Function(_), _) => {
+            Ty::fn_ptr(ty.callable_sig(db).expect("a function must be callable"))
+        }
+        _ => ty.clone(),
+    }
+}
+
+/// Returns the closure expressions, and the paths to named functions that are used as values,
+/// in the specified body, in the order in which they appear.
+pub(crate) fn function_value_exprs(body: &Body, infer: &InferenceResult) -> Vec<ExprId> {
+    fn collect_expr(
+        expr_id: ExprId,
+        body: &Body,
+        infer: &InferenceResult,
+        exprs: &mut Vec<ExprId>,
+    ) {
+        let expr = &body[expr_id];
+        match expr {
+            Expr::Closure { .. } => exprs.push(expr_id),
+            Expr::Path(_)
+                if matches!(
+                    infer[expr_id].interned(),
+                    TyKind::FnDef(CallableDef::Function(_), _)
+                ) =>
+            {
+                exprs.push(expr_id)
+            }
+            Expr::Call { callee, args } if infer[*callee].as_callable_def().is_some() => {
+                // A function that is called directly is not used as a value
+                for arg in args.iter() {
+                    collect_expr(*arg, body, infer, exprs);
+                }
+                return;
+            }
+            _ => (),
+        }
+
+        // Recurse further
+        expr.walk_child_exprs(|expr_id| collect_expr(expr_id, body, infer, exprs));
+    }
+
+    let mut exprs = Vec::new();
+    collect_expr(body.body_expr(), body, infer, &mut exprs);
+    exprs
+}
+
+/// Collects all function values that are created by the functions of the specified module group
+/// and its instances of generic functions, and the function pointer types used by the group.
+pub(crate) fn collect_function_values(
+    db: &dyn HirDatabase,
+    module_group: &ModuleGroup,
+    instances: &Instances,
+) -> FunctionValues {
+    let mut collector = FunctionValueCollector {
+        db,
+        function_values: FunctionValues::default(),
+        visited_types: FxHashSet::default(),
+    };
+
+    for def in module_group
+        .iter()
+        .flat_map(|module| module.declarations(db))
+    {
+        match def {
+            ModuleDef::Function(f) if !f.is_extern(db) && !f.is_generic(db) => {
+                let sig = db.callable_sig(f.into());
+                for ty in sig.params().iter().chain(std::iter::once(sig.ret())) {
+                    collector.collect_ty(ty);
+                }
+                collector.collect_owner(FunctionInstance {
+                    function: f,
+                    substs: Substitution::empty(),
+                });
+            }
+            ModuleDef::Struct(s) if !s.is_generic(db) => collector.collect_ty(&s.ty(db)),
+            ModuleDef::Enum(e) => {
+                for field in e.variants(db).into_iter().flat_map(|v| v.fields(db)) {
+                    collector.collect_ty(&field.ty(db));
+                }
+            }
+            _ => (),
+        }
+    }
+    for struct_ty in instances.struct_types.iter() {
+        collector.collect_ty(struct_ty);
+    }
+    for instance in instances.functions.iter() {
+        collector.collect_owner(instance.clone());
+    }
+
+    collector.function_values
+}
+
+struct FunctionValueCollector<'db> {
+    db: &'db dyn HirDatabase,
+    function_values: FunctionValues,
+    visited_types: FxHashSet<Ty>,
+}
+
+impl<'db> FunctionValueCollector<'db> {
+    /// Collects the function values that are created by the body of the specified function.
+    fn collect_owner(&mut self, owner: FunctionInstance) {
+        let body = owner.function.body(self.db);
+        let infer = if owner.substs.is_empty() {
+            owner.function.infer(self.db)
+        } else {
+            owner.infer(self.db)
+        };
+
+        for ty in infer.types() {
+            self.collect_ty(ty);
+        }
+
+        let closures = function_value_exprs(&body, &infer)
+            .into_iter()
+            .enumerate()
+            .map(|(index, expr)| Closure {
+                owner: owner.clone(),
+                expr,
+                index,
+            });
+        self.function_values.closures.extend(closures);
+    }
+
+    /// Collects the function pointer, trait object and tuple types that are contained in the
+    /// specified type.
+    fn collect_ty(&mut self, ty: &Ty) {
+        let ty = stored_ty(self.db, ty);
+        if !self.visited_types.insert(ty.clone()) {
+            return;
+        }
+
+        match ty.interned() {
+            TyKind::FnPtr(params_and_return) => {
+                for ty in params_and_return.iter() {
+                    self.collect_ty(ty);
+                }
+                self.function_values.fn_ptr_types.insert(ty.clone());
+            }
+            TyKind::Struct(..) => {
+                for ty in ty
+                    .struct_field_types(self.db)
+                    .expect("expected a struct type")
+                {
+                    self.collect_ty(&ty);
+                }
+            }
+            TyKind::Array(element_ty) | TyKind::Option(element_ty) => self.collect_ty(element_ty),
+            TyKind::DynTrait(_) => {
+                self.function_values.dyn_trait_types.insert(ty.clone());
+            }
+            TyKind::Tuple(_, substs) => {
+                for ty in substs.iter() {
+                    self.collect_ty(ty);
+                }
+                if !substs.is_empty() {
+                    self.function_values.tuple_types.insert(ty.clone());
+                }
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::ir::ty::HirTypeCache;
+use inkwell::values::BasicValueEnum;
+use mun_hir::{ConstValue, Ty};
+
+/// Constructs the IR constant that represents the compile-time evaluated `value` of type `ty`.
+pub(crate) fn gen_const_value<'ink>(
+    hir_types: &HirTypeCache<'_, 'ink>,
+    value: &ConstValue,
+    ty: &Ty,
+) -> BasicValueEnum<'ink> {
+    let ir_type = hir_types
+        .get_basic_type(ty)
+        .expect("a constant must have a basic type");
+
+    match value {
+        ConstValue::Bool(value) => ir_type
+            .into_int_type()
+            .const_int(*value as u64, false)
+            .into(),
+        ConstValue::Int(value) => {
+            let int_type = ir_type.into_int_type();
+            if int_type.get_bit_width() > 64 {
+                int_type
+                    .const_int_arbitrary_precision(&unsafe {
+                        std::mem::transmute::<i128, [u64; 2]>(*value)
+                    })
+                    .into()
+            } else {
+                int_type.const_int(*value as u64, *value < 0).into()
+            }
+        }
+        ConstValue::Float(value) => ir_type.into_float_type().const_float(*value).into(),
+    }
+}
@@ -1,6 +1,11 @@
 use crate::module_group::ModuleGroup;
 use crate::type_info::{HasStaticTypeId, TypeId};
-use crate::{intrinsics::Intrinsic, ir::function, ir::ty::HirTypeCache};
+use crate::{
+    intrinsics::Intrinsic,
+    ir::closure::Closure,
+    ir::function,
+    ir::ty::{trait_object_key, HirTypeCache},
+};
 use inkwell::values::CallableValue;
 use inkwell::{
     context::Context,
@@ -44,6 +49,23 @@ pub struct DispatchTable<'ink> {
     table_ref: Option<inkwell::values::GlobalValue<'ink>>,
     //
     table_type: Option<inkwell::types::StructType<'ink>>,
+    // The vtables of the traits whose functions are called through a trait object
+    vtables: HashMap<mun_hir::Trait, VTable>,
+}
+
+/// A `VTable` contains the implementations of the functions of a trait for every struct that can
+/// be used as a trait object of the trait. A call through a trait object selects the
+/// implementation with the key stored in the trait object.
+///
+/// The implementations are called like any other function: either directly or, if the function
+/// must be linked by the runtime, through the `DispatchTable`. This ensures that an
+/// implementation that is changed during a hot reload takes effect immediately.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VTable {
+    /// For every implementing struct, the key that identifies the struct in a trait object and the
+    /// functions that implement the functions of the trait, in the order in which the trait
+    /// declares them.
+    pub entries: Vec<(u64, Vec<mun_hir::Function>)>,
 }
 
 /// A `FunctionPrototype` defines a unique signature that can be added to the dispatch table.
@@ -73,6 +95,11 @@ impl<'ink> DispatchTable<'ink> {
         &self.entries
     }
 
+    /// Returns the vtable of the specified trait.
+    pub fn vtable(&self, t: mun_hir::Trait) -> &VTable {
+        self.vtables.get(&t).expect("unknown trait")
+    }
+
     /// Generate a function lookup through the DispatchTable, equivalent to something along the
     /// lines of: `dispatchTable[i]`, where i is the index of the function and `dispatchTable` is a
     /// struct
@@ -83,7 +110,7 @@ impl<'ink> DispatchTable<'ink> {
         builder: &inkwell::builder::Builder<'ink>,
         function: mun_hir::Function,
     ) -> CallableValue<'ink> {
-        let function_name = function.name(db).to_string();
+        let function_name = function.name_in_module(db);
 
         // Get the index of the function
         let index = *self
@@ -184,6 +211,8 @@ pub(crate) struct DispatchTableBuilder<'db, 'ink, 't> {
     module_group: &'t ModuleGroup,
     // The set of modules that is referenced
     referenced_modules: FxHashSet<mun_hir::Module>,
+    // The vtables of the traits whose functions are called through a trait object
+    vtables: HashMap<mun_hir::Trait, VTable>,
 }
 
 struct TypedDispatchableFunction<'ink> {
@@ -215,6 +244,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
             hir_types,
             module_group,
             referenced_modules: FxHashSet::default(),
+            vtables: HashMap::default(),
         };
 
         if !intrinsics.is_empty() {
@@ -252,19 +282,25 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         let expr = &body[expr_id];
 
         // If this expression is a call, store it in the dispatch table
-        if let Expr::Call { callee, .. } = expr {
-            match infer[*callee].as_callable_def() {
-                Some(mun_hir::CallableDef::Function(def)) => {
-                    if self.module_group.should_runtime_link_fn(self.db, def) {
-                        let fn_module = def.module(self.db);
-                        if !def.is_extern(self.db) && !self.module_group.contains(fn_module) {
-                            self.referenced_modules.insert(fn_module);
-                        }
-                        self.collect_fn_def(def);
-                    }
-                }
-                Some(mun_hir::CallableDef::Struct(_)) => (),
-                None => panic!("expected a callable expression"),
+        let function = match expr {
+            Expr::Call { callee, .. } => match infer[*callee].as_callable_def() {
+                Some(mun_hir::CallableDef::Function(def)) => Some(def),
+                Some(mun_hir::CallableDef::Struct(_))
+                | Some(mun_hir::CallableDef::EnumVariant(_)) => None,
+                // Function values are called through the pointer stored in their environment
+                None => None,
+            },
+            // Overloaded binary operations call the method that implements them
+            Expr::MethodCall { .. } | Expr::BinaryOp { .. } => {
+                infer.method_resolution(expr_id).map(|(def, _)| def)
+            }
+            _ => None,
+        };
+        if let Some(def) = function {
+            // A function of a trait is called through the vtable of the trait
+            match def.parent_trait(self.db) {
+                Some(t) => self.collect_vtable(t),
+                None => self.collect_called_fn(def),
             }
         }
 
@@ -272,6 +308,61 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         expr.walk_child_exprs(|expr_id| self.collect_expr(expr_id, body, infer));
     }
 
+    /// Collects the specified function if a call to it must be linked by the runtime.
+    fn collect_called_fn(&mut self, function: mun_hir::Function) {
+        if self.module_group.should_runtime_link_fn(self.db, function) {
+            let fn_module = function.module(self.db);
+            if !function.is_extern(self.db) && !self.module_group.contains(fn_module) {
+                self.referenced_modules.insert(fn_module);
+            }
+            self.collect_fn_def(function);
+        }
+    }
+
+    /// Collects the vtable of the specified trait and the functions that implement the trait.
+    fn collect_vtable(&mut self, t: mun_hir::Trait) {
+        if self.vtables.contains_key(&t) {
+            return;
+        }
+
+        // Only garbage collected structs can be used as a trait object
+        let trait_functions = t.functions(self.db);
+        let mut entries: Vec<(u64, Vec<mun_hir::Function>)> = t
+            .implementations(self.db)
+            .into_iter()
+            .filter(|(s, _)| s.data(self.db.upcast()).memory_kind == mun_hir::StructMemoryKind::Gc)
+            .map(|(s, imp)| {
+                let impl_functions = imp.functions(self.db);
+                let functions = trait_functions
+                    .iter()
+                    .map(|function| {
+                        let name = function.name(self.db);
+                        *impl_functions
+                            .iter()
+                            .find(|impl_function| impl_function.name(self.db) == name)
+                            .expect("an implementation must define all functions of the trait")
+                    })
+                    .collect();
+                (trait_object_key(self.db, s), functions)
+            })
+            .collect();
+
+        // Sort the entries to guarantee deterministically ordered output
+        entries.sort_by_key(|(key, _)| *key);
+
+        for function in entries.iter().flat_map(|(_, functions)| functions.iter()) {
+            self.collect_called_fn(*function);
+        }
+        self.vtables.insert(t, VTable { entries });
+    }
+
+    /// Collects the named function that is called by the specified function value, if any.
+    pub fn collect_closure(&mut self, closure: &Closure) {
+        if let Some((function, _)) = closure.target(self.db) {
+            self.collect_called_fn(function);
+        }
+    }
+
     /// Collects function call expression from the given expression.
     #[allow(clippy::map_entry)]
     pub fn collect_fn_def(&mut self, function: mun_hir::Function) {
@@ -379,6 +470,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
                 prototype_to_idx: self.prototype_to_idx,
                 table_ref: self.table_ref,
                 table_type,
+                vtables: self.vtables,
                 entries: self
                     .entries
                     .into_iter()
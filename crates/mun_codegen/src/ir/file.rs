@@ -1,14 +1,16 @@
 use super::body::ExternalGlobals;
+use super::constant;
 use crate::module_group::ModuleGroup;
 use crate::{
     code_gen::CodeGenContext,
     ir::body::BodyIrGenerator,
+    ir::closure::Closure,
     ir::file_group::FileGroupIr,
-    ir::{function, type_table::TypeTable},
+    ir::{function, global_table::GlobalTable, type_table::TypeTable},
     value::Global,
 };
 use inkwell::module::Module;
-use mun_hir::{HasVisibility, ModuleDef};
+use mun_hir::{HasVisibility, ModuleDef, Substitution};
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// The IR generated for a single source file.
@@ -20,6 +22,10 @@ pub struct FileIr<'ink> {
     pub function_definitions: HashSet<mun_hir::Function>,
     /// The types defined in this file
     pub type_definitions: HashSet<mun_hir::Ty>,
+    /// The `mun_hir::Static`s that constitute the file's API.
+    pub global_definitions: HashSet<mun_hir::Static>,
+    /// The lifted functions of function values that are created in this file
+    pub closure_definitions: Vec<Closure>,
 }
 
 /// Generates IR for the specified file.
@@ -37,12 +43,13 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
     let mut functions = HashMap::new();
     let mut type_definitions = HashSet::new();
     let mut wrapper_functions = BTreeMap::new();
+    let mut statics = HashMap::new();
     for def in module_group
         .iter()
         .flat_map(|module| module.declarations(code_gen.db))
     {
         if let ModuleDef::Function(f) = def {
-            if !f.is_extern(code_gen.db) {
+            if !f.is_extern(code_gen.db) && !f.is_generic(code_gen.db) {
                 let fun = function::gen_prototype(code_gen.db, hir_types, f, &llvm_module);
                 functions.insert(f, fun);
 
@@ -60,15 +67,62 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
                 }
             }
         }
-        if let ModuleDef::Struct(s) = def {
-            type_definitions.insert(s.ty(code_gen.db));
+        match def {
+            ModuleDef::Struct(s) if !s.is_generic(code_gen.db) => {
+                type_definitions.insert(s.ty(code_gen.db));
+            }
+            ModuleDef::Enum(e) => {
+                type_definitions.insert(e.ty(code_gen.db));
+            }
+            ModuleDef::Static(s) => {
+                // Statics are stored in the data section and initialized with their compile-time
+                // evaluated initial value.
+                let ty = s.ty(code_gen.db);
+                let initial_value = s
+                    .initial_value(code_gen.db)
+                    .expect("the initial value of a static must be constant");
+                let global = llvm_module.add_global(
+                    hir_types
+                        .get_basic_type(&ty)
+                        .expect("a static must have a basic type"),
+                    None,
+                    &s.full_name(code_gen.db),
+                );
+                global.set_initializer(&constant::gen_const_value(hir_types, &initial_value, &ty));
+                global.set_constant(!s.is_mut(code_gen.db));
+                statics.insert(s, global);
+            }
+            _ => (),
         }
     }
 
+    // Generate the signatures of all instances of generic functions and define the instances of
+    // generic structs that are used by the module group.
+    let mut instance_functions = HashMap::new();
+    for instance in group_ir.instances.functions.iter() {
+        let fun = function::gen_instance_prototype(code_gen.db, hir_types, instance, &llvm_module);
+        instance_functions.insert(instance.clone(), fun);
+    }
+    type_definitions.extend(group_ir.instances.struct_types.iter().cloned());
+
+    // Generate the signatures of the lifted functions of all function values and define the
+    // function pointer, trait object and tuple types that are used by the module group.
+    let mut closure_functions = HashMap::new();
+    for closure in group_ir.function_values.closures.iter() {
+        let fun = function::gen_closure_prototype(code_gen.db, hir_types, closure, &llvm_module);
+        closure_functions.insert(closure.clone(), fun);
+    }
+    type_definitions.extend(group_ir.function_values.fn_ptr_types.iter().cloned());
+    type_definitions.extend(group_ir.function_values.dyn_trait_types.iter().cloned());
+    type_definitions.extend(group_ir.function_values.tuple_types.iter().cloned());
+
     let external_globals = {
         let alloc_handle = group_ir
             .allocator_handle_type
             .map(|ty| llvm_module.add_global(ty, None, "allocatorHandle"));
+        let panic_handle = group_ir
+            .panic_handle_type
+            .map(|ty| llvm_module.add_global(ty, None, "panicHandle"));
         let dispatch_table = group_ir
             .dispatch_table
             .ty()
@@ -78,10 +132,17 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
         } else {
             Some(llvm_module.add_global(group_ir.type_table.ty(), None, TypeTable::NAME))
         };
+        let global_table = if group_ir.global_table.is_empty() {
+            None
+        } else {
+            Some(llvm_module.add_global(group_ir.global_table.ty(), None, GlobalTable::NAME))
+        };
         ExternalGlobals {
             alloc_handle,
+            panic_handle,
             dispatch_table,
             type_table: type_table.map(|g| unsafe { Global::from_raw(g) }),
+            global_table: global_table.map(|g| unsafe { Global::from_raw(g) }),
         }
     };
 
@@ -94,12 +155,43 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
             code_gen.context,
             code_gen.db,
             (*hir_function, *llvm_function),
+            Substitution::empty(),
+            &functions,
+            &instance_functions,
+            &closure_functions,
+            &statics,
+            &group_ir.dispatch_table,
+            &group_ir.type_table,
+            &group_ir.global_table,
+            external_globals.clone(),
+            &code_gen.hir_types,
+            module_group,
+            &llvm_module,
+            code_gen.overflow_checks,
+        );
+
+        code_gen.gen_fn_body();
+        fn_pass_manager.run_on(llvm_function);
+    }
+
+    for (instance, llvm_function) in instance_functions.iter() {
+        let mut code_gen = BodyIrGenerator::new(
+            code_gen.context,
+            code_gen.db,
+            (instance.function, *llvm_function),
+            instance.substs.clone(),
             &functions,
+            &instance_functions,
+            &closure_functions,
+            &statics,
             &group_ir.dispatch_table,
             &group_ir.type_table,
+            &group_ir.global_table,
             external_globals.clone(),
             &code_gen.hir_types,
             module_group,
+            &llvm_module,
+            code_gen.overflow_checks,
         );
 
         code_gen.gen_fn_body();
@@ -111,18 +203,61 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
             code_gen.context,
             code_gen.db,
             (*hir_function, *llvm_function),
+            Substitution::empty(),
             &functions,
+            &instance_functions,
+            &closure_functions,
+            &statics,
             &group_ir.dispatch_table,
             &group_ir.type_table,
+            &group_ir.global_table,
             external_globals.clone(),
             &code_gen.hir_types,
             module_group,
+            &llvm_module,
+            code_gen.overflow_checks,
         );
 
         code_gen.gen_fn_wrapper();
         fn_pass_manager.run_on(llvm_function);
     }
 
+    for (closure, llvm_function) in closure_functions.iter() {
+        let mut code_gen = BodyIrGenerator::new(
+            code_gen.context,
+            code_gen.db,
+            (closure.owner.function, *llvm_function),
+            closure.owner.substs.clone(),
+            &functions,
+            &instance_functions,
+            &closure_functions,
+            &statics,
+            &group_ir.dispatch_table,
+            &group_ir.type_table,
+            &group_ir.global_table,
+            external_globals.clone(),
+            &code_gen.hir_types,
+            module_group,
+            &llvm_module,
+            code_gen.overflow_checks,
+        );
+
+        code_gen.gen_closure_body(closure);
+        fn_pass_manager.run_on(llvm_function);
+    }
+
+    // Function values whose function was removed by hot reloading are pointed to a trap
+    if group_ir.function_values.has_function_values() {
+        let trap = function::gen_fn_value_trap(
+            code_gen.context,
+            hir_types,
+            &group_ir.dispatch_table,
+            &external_globals,
+            &llvm_module,
+        );
+        fn_pass_manager.run_on(&trap);
+    }
+
     // Filter private methods
     let function_definitions: HashSet<mun_hir::Function> = functions
         .keys()
@@ -130,9 +265,18 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
         .filter(|&f| module_group.should_export_fn(code_gen.db, f))
         .collect();
 
+    // Filter private statics
+    let global_definitions: HashSet<mun_hir::Static> = statics
+        .keys()
+        .copied()
+        .filter(|&s| module_group.should_export_static(code_gen.db, s))
+        .collect();
+
     FileIr {
         llvm_module,
         function_definitions,
         type_definitions,
+        global_definitions,
+        closure_definitions: group_ir.function_values.closures.clone(),
     }
 }
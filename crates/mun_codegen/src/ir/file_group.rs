@@ -1,5 +1,8 @@
 use super::{
+    closure::{self, FunctionValues},
     dispatch_table::{DispatchTable, DispatchTableBuilder},
+    global_table::{GlobalTable, GlobalTableBuilder},
+    instance::{self, Instances},
     intrinsics,
     type_table::{TypeTable, TypeTableBuilder},
 };
@@ -23,10 +26,18 @@ pub struct FileGroupIr<'ink> {
     pub(crate) dispatch_table: DispatchTable<'ink>,
     /// The type table
     pub(crate) type_table: TypeTable<'ink>,
+    /// The global table
+    pub(crate) global_table: GlobalTable<'ink>,
     /// The allocator handle, if it exists
     pub(crate) allocator_handle_type: Option<PointerType<'ink>>,
+    /// The panic handle, if it exists
+    pub(crate) panic_handle_type: Option<PointerType<'ink>>,
     /// The modules that contain code that was referenced from this group of modules
     pub(crate) referenced_modules: FxHashSet<mun_hir::Module>,
+    /// The instances of generic functions and structs that are used by this group of modules
+    pub(crate) instances: Instances,
+    /// The function values that are created by this group of modules
+    pub(crate) function_values: FunctionValues,
 }
 
 /// Generates IR that is shared among the group's files.
@@ -39,6 +50,10 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
     // Use a `BTreeMap` to guarantee deterministically ordered output.
     let mut intrinsics_map = BTreeMap::new();
     let mut needs_alloc = false;
+    let mut needs_panic_handle = false;
+
+    let instances = instance::collect_instances(code_gen.db, module_group);
+    let function_values = closure::collect_function_values(code_gen.db, module_group, &instances);
 
     // Collect all intrinsic functions, wrapper function, and generate struct declarations.
     for def in module_group
@@ -46,13 +61,15 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
         .flat_map(|module| module.declarations(code_gen.db))
     {
         match def {
-            ModuleDef::Function(f) if !f.is_extern(code_gen.db) => {
+            ModuleDef::Function(f) if !f.is_extern(code_gen.db) && !f.is_generic(code_gen.db) => {
                 intrinsics::collect_fn_body(
                     code_gen.context,
                     code_gen.target_machine.get_target_data(),
                     code_gen.db,
+                    code_gen.overflow_checks,
                     &mut intrinsics_map,
                     &mut needs_alloc,
+                    &mut needs_panic_handle,
                     &f.body(code_gen.db),
                     &f.infer(code_gen.db),
                 );
@@ -72,10 +89,47 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
             ModuleDef::Module(_) => (),
             ModuleDef::Function(_) => (), // TODO: Extern types?
             ModuleDef::Struct(_) => (),
+            ModuleDef::Enum(_) => (),
+            ModuleDef::EnumVariant(_) => (),
             ModuleDef::PrimitiveType(_) => (),
             ModuleDef::TypeAlias(_) => (),
+            ModuleDef::Trait(_) => (),
+            ModuleDef::Const(_) => (),
+            ModuleDef::Static(_) => (),
+            ModuleDef::BuiltinModule(_) => (),
+            ModuleDef::IntrinsicFunction(_) => (),
         }
     }
+    for instance in instances.functions.iter() {
+        intrinsics::collect_fn_body(
+            code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            code_gen.db,
+            code_gen.overflow_checks,
+            &mut intrinsics_map,
+            &mut needs_alloc,
+            &mut needs_panic_handle,
+            &instance.function.body(code_gen.db),
+            &instance.infer(code_gen.db),
+        );
+    }
+    if !function_values.closures.is_empty() {
+        intrinsics::collect_closures(
+            code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            &mut intrinsics_map,
+            &mut needs_alloc,
+            &mut needs_panic_handle,
+        );
+    }
+    if function_values.has_function_values() {
+        intrinsics::collect_fn_value_trap(
+            code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            &mut intrinsics_map,
+            &mut needs_panic_handle,
+        );
+    }
 
     // Collect all exposed functions' bodies.
     let mut dispatch_table_builder = DispatchTableBuilder::new(
@@ -93,13 +147,21 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
     {
         if let ModuleDef::Function(f) = def {
             // Find all functions that must be present in the dispatch table
-            if !f.is_extern(code_gen.db) {
+            if !f.is_extern(code_gen.db) && !f.is_generic(code_gen.db) {
                 let body = f.body(code_gen.db);
                 let infer = f.infer(code_gen.db);
                 dispatch_table_builder.collect_body(&body, &infer);
             }
         }
     }
+    for instance in instances.functions.iter() {
+        let body = instance.function.body(code_gen.db);
+        let infer = instance.infer(code_gen.db);
+        dispatch_table_builder.collect_body(&body, &infer);
+    }
+    for closure in function_values.closures.iter() {
+        dispatch_table_builder.collect_closure(closure);
+    }
 
     let (dispatch_table, referenced_modules) = dispatch_table_builder.build();
 
@@ -129,18 +191,63 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
         .flat_map(|module| module.declarations(code_gen.db))
     {
         match def {
-            ModuleDef::Struct(s) => {
+            ModuleDef::Struct(s) if !s.is_generic(code_gen.db) => {
                 type_table_builder.collect_struct(s);
             }
-            ModuleDef::Function(f) => {
+            ModuleDef::Enum(e) => {
+                type_table_builder.collect_enum(e);
+            }
+            ModuleDef::Function(f) if !f.is_generic(code_gen.db) => {
                 type_table_builder.collect_fn(f);
             }
-            ModuleDef::PrimitiveType(_) | ModuleDef::TypeAlias(_) | ModuleDef::Module(_) => (),
+            ModuleDef::Struct(_)
+            | ModuleDef::Function(_)
+            | ModuleDef::EnumVariant(_)
+            | ModuleDef::PrimitiveType(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::Trait(_)
+            | ModuleDef::Const(_)
+            | ModuleDef::Static(_)
+            | ModuleDef::Module(_)
+            | ModuleDef::BuiltinModule(_)
+            | ModuleDef::IntrinsicFunction(_) => (),
         }
     }
+    for struct_ty in instances.struct_types.iter() {
+        type_table_builder.collect_struct_ty(struct_ty);
+    }
+    for instance in instances.functions.iter() {
+        type_table_builder.collect_instance(instance);
+    }
+    for closure in function_values.closures.iter() {
+        type_table_builder.collect_closure(closure);
+    }
 
     let type_table = type_table_builder.build();
 
+    // Collect all statics of other module groups that are used
+    let mut global_table_builder =
+        GlobalTableBuilder::new(code_gen.db, &value_context, module_group);
+    for def in module_group
+        .iter()
+        .flat_map(|module| module.declarations(code_gen.db))
+    {
+        if let ModuleDef::Function(f) = def {
+            if !f.is_extern(code_gen.db) && !f.is_generic(code_gen.db) {
+                global_table_builder.collect_body(&f.body(code_gen.db));
+            }
+        }
+    }
+    for instance in instances.functions.iter() {
+        global_table_builder.collect_body(&instance.function.body(code_gen.db));
+    }
+
+    let (global_table, global_referenced_modules) = global_table_builder.build();
+    let referenced_modules = referenced_modules
+        .union(&global_referenced_modules)
+        .copied()
+        .collect();
+
     // Create the allocator handle global value
     let allocator_handle_type = if needs_alloc {
         let allocator_handle_type = code_gen.context.i8_type().ptr_type(AddressSpace::Generic);
@@ -152,11 +259,26 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
         None
     };
 
+    // Create the panic handle global value
+    let panic_handle_type = if needs_panic_handle {
+        let panic_handle_type = code_gen.context.i8_type().ptr_type(AddressSpace::Generic);
+        let global = llvm_module.add_global(panic_handle_type, None, "panicHandle");
+        global.set_initializer(&panic_handle_type.const_null());
+        global.set_unnamed_address(UnnamedAddress::Global);
+        Some(panic_handle_type)
+    } else {
+        None
+    };
+
     FileGroupIr {
         llvm_module,
         dispatch_table,
         type_table,
+        global_table,
         allocator_handle_type,
+        panic_handle_type,
         referenced_modules,
+        instances,
+        function_values,
     }
 }
@@ -1,9 +1,19 @@
-use crate::{ir::ty::HirTypeCache, Module, OptimizationLevel};
+use crate::{
+    intrinsics,
+    ir::{
+        body::ExternalGlobals, closure::Closure, dispatch_table::DispatchTable,
+        instance::FunctionInstance, ty::HirTypeCache,
+    },
+    Module, OptimizationLevel,
+};
 use inkwell::{
+    context::Context,
+    module::Linkage,
     passes::{PassManager, PassManagerBuilder},
     values::FunctionValue,
 };
 
+use mun_abi as abi;
 use mun_hir::HirDatabase;
 
 /// Constructs a PassManager to optimize functions for the given optimization level.
@@ -31,11 +41,38 @@ pub(crate) fn gen_prototype<'db, 'ink>(
     func: mun_hir::Function,
     module: &Module<'ink>,
 ) -> FunctionValue<'ink> {
-    let name = func.name(db).to_string();
+    let name = func.name_in_module(db);
     let ir_ty = types.get_function_type(func);
     module.add_function(&name, ir_ty, None)
 }
 
+/// Generates a `FunctionValue` for an instance of a generic `mun_hir::Function`. Instances are
+/// private to the module in which they are generated, as they are only called from within the
+/// module group.
+pub(crate) fn gen_instance_prototype<'db, 'ink>(
+    db: &'db dyn HirDatabase,
+    types: &HirTypeCache<'db, 'ink>,
+    instance: &FunctionInstance,
+    module: &Module<'ink>,
+) -> FunctionValue<'ink> {
+    let name = instance.name_in_module(db);
+    let ir_ty = types.get_fn_sig_type(&instance.sig(db));
+    module.add_function(&name, ir_ty, Some(Linkage::Private))
+}
+
+/// Generates a `FunctionValue` for the lifted function of a function value. The environment of
+/// the function value is passed as the first argument.
+pub(crate) fn gen_closure_prototype<'db, 'ink>(
+    db: &'db dyn HirDatabase,
+    types: &HirTypeCache<'db, 'ink>,
+    closure: &Closure,
+    module: &Module<'ink>,
+) -> FunctionValue<'ink> {
+    let name = closure.name(db);
+    let ir_ty = types.get_closure_function_type(&closure.sig(db));
+    module.add_function(&name, ir_ty, None)
+}
+
 /// Generates a `FunctionValue` for a `mun_hir::Function` that is usable from the public API. This
 /// function does not generate a body for the `mun_hir::Function`. That task is left to the `gen_body`
 /// function. The reason this is split between two functions is that first all signatures are
@@ -47,7 +84,57 @@ pub(crate) fn gen_public_prototype<'db, 'ink>(
     func: mun_hir::Function,
     module: &Module<'ink>,
 ) -> FunctionValue<'ink> {
-    let name = format!("{}_wrapper", func.name(db));
+    let name = format!("{}_wrapper", func.name_in_module(db));
     let ir_ty = types.get_public_function_type(func);
     module.add_function(&name, ir_ty, None)
 }
+
+/// Generates the trap that function values call when their function was removed by hot reloading.
+/// The trap raises a panic, which the caller of the function value propagates. The trap ignores
+/// its arguments and doesn't return a value; the caller never observes the returned value because
+/// it checks for a panic first.
+pub(crate) fn gen_fn_value_trap<'ink>(
+    context: &'ink Context,
+    types: &HirTypeCache<'_, 'ink>,
+    dispatch_table: &DispatchTable<'ink>,
+    external_globals: &ExternalGlobals<'ink>,
+    module: &Module<'ink>,
+) -> FunctionValue<'ink> {
+    let function = module.add_function(
+        abi::FN_VALUE_TRAP_FN_NAME,
+        context.void_type().fn_type(&[], false),
+        None,
+    );
+
+    let builder = context.create_builder();
+    let body_ir = context.append_basic_block(function, "body");
+    builder.position_at_end(body_ir);
+
+    let message = "the function of the function value was removed by hot reloading";
+    let message_ptr = builder
+        .build_global_string_ptr(message, "panic_message")
+        .as_pointer_value();
+    let length = types
+        .get_usize_type()
+        .const_int(message.len() as u64, false);
+    let panic_fn_ptr = dispatch_table.gen_intrinsic_lookup(
+        external_globals.dispatch_table,
+        &builder,
+        &intrinsics::panic,
+    );
+    let panic_handle = builder.build_load(
+        external_globals
+            .panic_handle
+            .expect("no panic handle was specified, this is required for panics")
+            .as_pointer_value(),
+        "panic_handle",
+    );
+    builder.build_call(
+        panic_fn_ptr,
+        &[message_ptr.into(), length.into(), panic_handle.into()],
+        "",
+    );
+    builder.build_return(None);
+
+    function
+}
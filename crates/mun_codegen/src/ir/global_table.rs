@@ -0,0 +1,207 @@
+use std::{collections::HashMap, collections::HashSet, convert::TryInto, sync::Arc};
+
+use inkwell::{
+    context::Context,
+    module::Linkage,
+    module::Module,
+    types::{ArrayType, BasicType, PointerType},
+    values::PointerValue,
+    AddressSpace,
+};
+
+use mun_hir::{Body, Expr, ExprId, HirDatabase, ValueNs};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    ir::ty::HirTypeCache,
+    value::{Global, IrValueContext, IterAsIrValue, Value},
+    ModuleGroup,
+};
+
+/// A global table in IR is a list of pointers to the memory of statics that are defined in other
+/// module groups. The runtime fills the table when linking assemblies, similar to how the dispatch
+/// table is filled with function pointers.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GlobalTable<'ink> {
+    entries: Vec<mun_hir::Static>,
+    static_to_index: HashMap<mun_hir::Static, usize>,
+    table_type: ArrayType<'ink>,
+}
+
+impl<'ink> GlobalTable<'ink> {
+    /// The name of the GlobalTable's LLVM `GlobalValue`.
+    pub(crate) const NAME: &'static str = "global_variable_lookup_table";
+
+    /// Returns a slice containing all statics
+    pub fn entries(&self) -> &[mun_hir::Static] {
+        &self.entries
+    }
+
+    /// Looks for a global symbol with the name of the GlobalTable global in the specified
+    /// `module`. Returns the global value if it could be found, `None` otherwise.
+    pub fn find_global(module: &Module<'ink>) -> Option<Global<'ink, [*const std::ffi::c_void]>> {
+        module
+            .get_global(Self::NAME)
+            .map(|g| unsafe { Global::from_raw(g) })
+    }
+
+    /// Generates a pointer lookup through the `GlobalTable`, equivalent to something along the
+    /// lines of: `(T*)global_table[i]`, where `i` is the index of the static and `T` is its type.
+    pub fn gen_global_lookup(
+        &self,
+        db: &dyn HirDatabase,
+        context: &'ink Context,
+        builder: &inkwell::builder::Builder<'ink>,
+        hir_static: mun_hir::Static,
+        ptr_type: PointerType<'ink>,
+        table_ref: Option<Global<'ink, [*const std::ffi::c_void]>>,
+    ) -> PointerValue<'ink> {
+        let table_ref = table_ref.expect("no global table defined");
+
+        let index: u64 = (*self
+            .static_to_index
+            .get(&hir_static)
+            .expect("unknown static"))
+        .try_into()
+        .expect("too many statics");
+
+        let global_index = context.i64_type().const_zero();
+        let array_index = context.i64_type().const_int(index, false);
+
+        let name = hir_static.name(db);
+        let ptr_to_global_ptr = unsafe {
+            builder.build_gep(
+                table_ref.into(),
+                &[global_index, array_index],
+                &format!("{}_ptr_ptr", name),
+            )
+        };
+
+        let global_ptr = builder
+            .build_load(ptr_to_global_ptr, &format!("{}_untyped_ptr", name))
+            .into_pointer_value();
+        builder
+            .build_bitcast(global_ptr, ptr_type, &format!("{}_ptr", name))
+            .into_pointer_value()
+    }
+
+    /// Returns the number of statics in the `GlobalTable`.
+    pub fn num_globals(&self) -> usize {
+        self.table_type.len() as usize
+    }
+
+    /// Returns whether the global table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.table_type.len() == 0
+    }
+
+    /// Returns the IR type of the global table's global value, if it exists.
+    pub fn ty(&self) -> ArrayType<'ink> {
+        self.table_type
+    }
+}
+
+/// Used to build a `GlobalTable` from HIR.
+pub(crate) struct GlobalTableBuilder<'db, 'ink, 't> {
+    db: &'db dyn HirDatabase,
+    value_context: &'t IrValueContext<'ink, 't, 't>,
+    entries: HashSet<mun_hir::Static>,
+    module_group: &'t ModuleGroup,
+    referenced_modules: FxHashSet<mun_hir::Module>,
+}
+
+impl<'db, 'ink, 't> GlobalTableBuilder<'db, 'ink, 't> {
+    /// Creates a new `GlobalTableBuilder`.
+    pub(crate) fn new(
+        db: &'db dyn HirDatabase,
+        value_context: &'t IrValueContext<'ink, '_, '_>,
+        module_group: &'t ModuleGroup,
+    ) -> Self {
+        Self {
+            db,
+            value_context,
+            entries: Default::default(),
+            module_group,
+            referenced_modules: Default::default(),
+        }
+    }
+
+    /// Collects the statics that are defined in other module groups from the specified expression
+    /// and its sub-expressions.
+    fn collect_expr(&mut self, expr_id: ExprId, body: &Arc<Body>) {
+        let expr = &body[expr_id];
+
+        if let Expr::Path(path) = expr {
+            let resolver = mun_hir::resolver_for_expr(self.db.upcast(), body.owner(), expr_id);
+            if let Some((ValueNs::StaticId(id), _)) =
+                resolver.resolve_path_as_value_fully(self.db.upcast(), path)
+            {
+                let hir_static = mun_hir::Static::from(id);
+                let module = hir_static.module(self.db);
+                if !self.module_group.contains(module) {
+                    self.referenced_modules.insert(module);
+                    self.entries.insert(hir_static);
+                }
+            }
+        }
+
+        // Recurse further
+        expr.walk_child_exprs(|expr_id| self.collect_expr(expr_id, body))
+    }
+
+    /// Collects the statics that are defined in other module groups from the specified body.
+    pub fn collect_body(&mut self, body: &Arc<Body>) {
+        self.collect_expr(body.body_expr(), body);
+    }
+
+    /// Constructs a `GlobalTable` from all *used* statics of other module groups. Returns the
+    /// `GlobalTable` and the modules that define the statics.
+    pub fn build(self) -> (GlobalTable<'ink>, FxHashSet<mun_hir::Module>) {
+        let mut entries = Vec::from_iter(self.entries.into_iter());
+        entries.sort_by_cached_key(|s| s.full_name(self.db));
+
+        let static_to_index = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, hir_static)| (*hir_static, idx))
+            .collect();
+
+        // Construct a list of pointers that are filled in by the runtime
+        let global_ptrs: Value<'ink, [*const std::ffi::c_void]> = entries
+            .iter()
+            .map(|_| Value::null(self.value_context))
+            .into_value(self.value_context);
+
+        // If there are statics, introduce a special global that contains all the pointers
+        if !global_ptrs.is_empty() {
+            let _: Global<'ink, [*const std::ffi::c_void]> = global_ptrs.into_global(
+                GlobalTable::NAME,
+                self.value_context,
+                false,
+                Linkage::External,
+                None,
+            );
+        };
+
+        (
+            GlobalTable {
+                entries,
+                static_to_index,
+                table_type: global_ptrs.get_type(),
+            },
+            self.referenced_modules,
+        )
+    }
+}
+
+/// Returns the IR type of a pointer to the memory of the specified static.
+pub(crate) fn static_ptr_type<'ink>(
+    db: &dyn HirDatabase,
+    hir_types: &HirTypeCache<'_, 'ink>,
+    hir_static: mun_hir::Static,
+) -> PointerType<'ink> {
+    hir_types
+        .get_basic_type(&hir_static.ty(db))
+        .expect("a static must have a basic type")
+        .ptr_type(AddressSpace::Generic)
+}
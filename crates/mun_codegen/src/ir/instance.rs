@@ -0,0 +1,178 @@
+use crate::module_group::ModuleGroup;
+use mun_hir::{
+    Body, Expr, ExprId, FnSig, HirDatabase, HirDisplay, InferenceResult, ModuleDef, Substitution,
+    Ty, TyKind,
+};
+use rustc_hash::FxHashSet;
+use std::sync::Arc;
+
+/// An instantiation of a generic function with concrete type arguments, e.g. `max<i32>`. Code is
+/// generated for every instance that is used by a module group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionInstance {
+    pub function: mun_hir::Function,
+    pub substs: Substitution,
+}
+
+impl FunctionInstance {
+    /// Returns the name of the instance within its module, including its type arguments (e.g:
+    /// `max<i32>`).
+    pub fn name_in_module(&self, db: &dyn HirDatabase) -> String {
+        let type_args: Vec<String> = self
+            .substs
+            .iter()
+            .map(|ty| ty.display(db).to_string())
+            .collect();
+        format!(
+            "{}<{}>",
+            self.function.name_in_module(db),
+            type_args.join(", ")
+        )
+    }
+
+    /// Returns the signature of the instance.
+    pub fn sig(&self, db: &dyn HirDatabase) -> FnSig {
+        db.callable_sig(self.function.into()).subst(&self.substs)
+    }
+
+    /// Returns the types of the body of the instance.
+    pub fn infer(&self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        Arc::new(self.function.infer(db).subst(db, &self.substs))
+    }
+}
+
+/// The instantiations of generic functions and structs that are used by a module group.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Instances {
+    /// The generic functions that are called, in the order in which they were found.
+    pub functions: Vec<FunctionInstance>,
+    /// The instantiated struct types, in the order in which they were found.
+    pub struct_types: Vec<Ty>,
+}
+
+/// Collects all instances of generic functions and structs that are used by the functions of the
+/// specified module group, including those that are only used by other instances.
+pub(crate) fn collect_instances(db: &dyn HirDatabase, module_group: &ModuleGroup) -> Instances {
+    let mut collector = InstanceCollector {
+        db,
+        instances: Instances::default(),
+        visited_functions: FxHashSet::default(),
+        visited_struct_types: FxHashSet::default(),
+    };
+
+    for def in module_group
+        .iter()
+        .flat_map(|module| module.declarations(db))
+    {
+        match def {
+            ModuleDef::Function(f) if !f.is_extern(db) && !f.is_generic(db) => {
+                collector.collect_body(&f.body(db), &f.infer(db));
+            }
+            ModuleDef::Struct(s) if !s.is_generic(db) => collector.collect_ty(&s.ty(db)),
+            ModuleDef::Enum(e) => {
+                for field in e.variants(db).into_iter().flat_map(|v| v.fields(db)) {
+                    collector.collect_ty(&field.ty(db));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // The body of an instance can use other instances
+    let mut idx = 0;
+    while idx < collector.instances.functions.len() {
+        let instance = collector.instances.functions[idx].clone();
+        collector.collect_body(&instance.function.body(db), &instance.infer(db));
+        idx += 1;
+    }
+
+    collector.instances
+}
+
+struct InstanceCollector<'db> {
+    db: &'db dyn HirDatabase,
+    instances: Instances,
+    visited_functions: FxHashSet<FunctionInstance>,
+    // Both generic and non-generic struct types, as either can have fields of generic struct types
+    visited_struct_types: FxHashSet<Ty>,
+}
+
+impl<'db> InstanceCollector<'db> {
+    /// Collects the instances that are used by the specified body.
+    fn collect_body(&mut self, body: &Arc<Body>, infer: &InferenceResult) {
+        for ty in infer.types() {
+            self.collect_ty(ty);
+        }
+        self.collect_expr(body.body_expr(), body, infer);
+    }
+
+    /// Collects the instances of generic functions that are called, or used as a value, by the
+    /// specified expression and its sub-expressions.
+    fn collect_expr(&mut self, expr_id: ExprId, body: &Arc<Body>, infer: &InferenceResult) {
+        let expr = &body[expr_id];
+
+        let instance = match expr {
+            Expr::Call { callee, .. } => match infer[*callee].interned() {
+                TyKind::FnDef(mun_hir::CallableDef::Function(function), substs) => {
+                    Some(FunctionInstance {
+                        function: *function,
+                        substs: substs.clone(),
+                    })
+                }
+                _ => None,
+            },
+            // A generic function that is used as a value is called by the lifted function of
+            // the function value
+            Expr::Path(_) => match infer[expr_id].interned() {
+                TyKind::FnDef(mun_hir::CallableDef::Function(function), substs) => {
+                    Some(FunctionInstance {
+                        function: *function,
+                        substs: substs.clone(),
+                    })
+                }
+                _ => None,
+            },
+            Expr::MethodCall { .. } | Expr::BinaryOp { .. } => infer
+                .method_resolution(expr_id)
+                .map(|(function, substs)| FunctionInstance { function, substs }),
+            _ => None,
+        };
+        if let Some(instance) = instance {
+            if instance.function.is_generic(self.db)
+                && self.visited_functions.insert(instance.clone())
+            {
+                self.instances.functions.push(instance);
+            }
+        }
+
+        // Recurse further
+        expr.walk_child_exprs(|expr_id| self.collect_expr(expr_id, body, infer));
+    }
+
+    /// Collects the instances of generic structs that are contained in the specified type.
+    fn collect_ty(&mut self, ty: &Ty) {
+        match ty.interned() {
+            TyKind::Struct(_, substs) => {
+                if !self.visited_struct_types.insert(ty.clone()) {
+                    return;
+                }
+                if !substs.is_empty() {
+                    self.instances.struct_types.push(ty.clone());
+                }
+                for ty in ty
+                    .struct_field_types(self.db)
+                    .expect("expected a struct type")
+                {
+                    self.collect_ty(&ty);
+                }
+            }
+            TyKind::Array(element_ty) | TyKind::Option(element_ty) => self.collect_ty(element_ty),
+            TyKind::Tuple(_, substs) => {
+                for ty in substs.iter() {
+                    self.collect_ty(ty);
+                }
+            }
+            _ => (),
+        }
+    }
+}
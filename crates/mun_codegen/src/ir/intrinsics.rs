@@ -2,8 +2,17 @@ use crate::{
     intrinsics::{self, Intrinsic},
     ir::dispatch_table::FunctionPrototype,
 };
-use inkwell::{context::Context, targets::TargetData, types::FunctionType};
-use mun_hir::{Body, Expr, ExprId, HirDatabase, InferenceResult, ValueNs};
+use inkwell::{
+    context::Context,
+    module::Module,
+    targets::TargetData,
+    types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum, FloatType, FunctionType, IntType},
+    values::FunctionValue,
+};
+use mun_hir::{
+    ArithOp, BinaryOp, Body, Expr, ExprId, FloatBitness, HirDatabase, InferenceResult,
+    IntrinsicFunction, Literal, Ty, TyKind, UnaryOp, ValueNs,
+};
 use std::{collections::BTreeMap, sync::Arc};
 
 // Use a `BTreeMap` to guarantee deterministically ordered output
@@ -22,14 +31,27 @@ fn collect_intrinsic<'ink>(
         .or_insert_with(|| intrinsic.ir_type(context, target));
 }
 
+/// Stores the information required to propagate a panic out of a function in `entries`.
+fn collect_panic_unwind<'ink>(
+    context: &'ink Context,
+    target: &TargetData,
+    entries: &mut IntrinsicsMap<'ink>,
+    needs_panic_handle: &mut bool,
+) {
+    collect_intrinsic(context, target, &intrinsics::panic_unwind, entries);
+    *needs_panic_handle = true;
+}
+
 /// Iterates over all expressions and stores information on which intrinsics they use in `entries`.
 #[allow(clippy::too_many_arguments)]
 fn collect_expr<'db, 'ink>(
     context: &'ink Context,
     target: &TargetData,
     db: &'db dyn HirDatabase,
+    overflow_checks: bool,
     intrinsics: &mut IntrinsicsMap<'ink>,
     needs_alloc: &mut bool,
+    needs_panic_handle: &mut bool,
     expr_id: ExprId,
     body: &Arc<Body>,
     infer: &InferenceResult,
@@ -44,8 +66,66 @@ fn collect_expr<'db, 'ink>(
                 // self.collect_intrinsic(module, entries, &intrinsics::drop);
                 *needs_alloc = true;
             }
-            Some(mun_hir::CallableDef::Function(_)) => (),
-            None => panic!("expected a callable expression"),
+            Some(mun_hir::CallableDef::Function(f)) => {
+                // A call to another Mun function is followed by a check whether it panicked
+                if !f.is_extern(db) {
+                    collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+                }
+            }
+            Some(mun_hir::CallableDef::EnumVariant(_)) => (),
+            // A call to `panic` raises a panic in the runtime
+            None if infer.intrinsic_resolution(expr_id) == Some(IntrinsicFunction::Panic) => {
+                collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+                collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+            }
+            // A call to a function of `core::math` is lowered to an LLVM intrinsic
+            None if infer.intrinsic_resolution(expr_id).is_some() => (),
+            // A call through a function value calls a Mun function, which can panic
+            None => collect_panic_unwind(context, target, intrinsics, needs_panic_handle),
+        }
+    }
+
+    if let Expr::MethodCall {
+        receiver,
+        method_name,
+        ..
+    } = expr
+    {
+        // Methods defined in `impl` blocks are Mun functions that can panic
+        if let Some((method, _)) = infer.method_resolution(expr_id) {
+            // A call through a trait object panics if the type of the object no longer implements
+            // the trait after a hot reload
+            if method.parent_trait(db).is_some() {
+                collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+            }
+            collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+        } else if matches!(infer[*receiver].interned(), TyKind::Array(_)) {
+            // Built-in array methods that modify the array are implemented by the runtime
+            match method_name.to_string().as_str() {
+                "push" => {
+                    collect_intrinsic(context, target, &intrinsics::array_push, intrinsics);
+                    *needs_alloc = true;
+                }
+                "insert" => {
+                    collect_intrinsic(context, target, &intrinsics::array_insert, intrinsics);
+                    collect_intrinsic(context, target, &intrinsics::panic_bounds_check, intrinsics);
+                    collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+                    *needs_alloc = true;
+                }
+                "remove" => {
+                    collect_intrinsic(context, target, &intrinsics::array_remove, intrinsics);
+                    collect_intrinsic(context, target, &intrinsics::panic_bounds_check, intrinsics);
+                    collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+                    *needs_alloc = true;
+                }
+                "pop" => {
+                    collect_intrinsic(context, target, &intrinsics::array_remove, intrinsics);
+                    collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+                    collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+                    *needs_alloc = true;
+                }
+                _ => (),
+            }
         }
     }
 
@@ -71,14 +151,128 @@ fn collect_expr<'db, 'ink>(
         *needs_alloc = true;
     }
 
+    // Binary operations that are overloaded by a user-defined type call a Mun function, which can
+    // panic
+    if let Expr::BinaryOp { .. } = expr {
+        if infer.method_resolution(expr_id).is_some() {
+            collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+        }
+    }
+
+    if let Expr::Index { .. } = expr {
+        collect_intrinsic(context, target, &intrinsics::panic_bounds_check, intrinsics);
+        collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+    }
+
+    // Integer divisions panic when dividing by zero or when the division overflows
+    if let Expr::BinaryOp {
+        lhs,
+        op:
+            Some(
+                BinaryOp::ArithOp(ArithOp::Divide | ArithOp::Remainder)
+                | BinaryOp::Assignment {
+                    op: Some(ArithOp::Divide | ArithOp::Remainder),
+                },
+            ),
+        ..
+    } = expr
+    {
+        if matches!(infer[*lhs].interned(), TyKind::Int(_)) {
+            collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+            collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+        }
+    }
+
+    // With overflow checks enabled, integer arithmetic that overflows panics
+    if overflow_checks {
+        let checked_expr = match expr {
+            Expr::BinaryOp {
+                lhs,
+                op:
+                    Some(
+                        BinaryOp::ArithOp(
+                            ArithOp::Add
+                            | ArithOp::Subtract
+                            | ArithOp::Multiply
+                            | ArithOp::LeftShift
+                            | ArithOp::RightShift,
+                        )
+                        | BinaryOp::Assignment {
+                            op:
+                                Some(
+                                    ArithOp::Add
+                                    | ArithOp::Subtract
+                                    | ArithOp::Multiply
+                                    | ArithOp::LeftShift
+                                    | ArithOp::RightShift,
+                                ),
+                        },
+                    ),
+                ..
+            } => Some(*lhs),
+            Expr::UnaryOp {
+                expr,
+                op: UnaryOp::Neg,
+            } => Some(*expr),
+            _ => None,
+        };
+        if let Some(checked_expr) = checked_expr {
+            if matches!(infer[checked_expr].interned(), TyKind::Int(_)) {
+                collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+                collect_panic_unwind(context, target, intrinsics, needs_panic_handle);
+            }
+        }
+    }
+
+    if let Expr::Literal(Literal::String(_)) = expr {
+        collect_intrinsic(context, target, &intrinsics::new_string, intrinsics);
+        *needs_alloc = true;
+    }
+
+    if let Expr::BinaryOp {
+        lhs, op: Some(op), ..
+    } = expr
+    {
+        if matches!(infer[*lhs].interned(), TyKind::String) {
+            match op {
+                BinaryOp::ArithOp(ArithOp::Add)
+                | BinaryOp::Assignment {
+                    op: Some(ArithOp::Add),
+                } => {
+                    collect_intrinsic(context, target, &intrinsics::string_concat, intrinsics);
+                    *needs_alloc = true;
+                }
+                BinaryOp::CmpOp(_) => {
+                    collect_intrinsic(context, target, &intrinsics::string_compare, intrinsics);
+                    *needs_alloc = true;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    // Literal patterns that match strings allocate the literal and compare it to the scrutinee
+    if let Expr::Match {
+        expr: scrutinee, ..
+    } = expr
+    {
+        if matches!(infer[*scrutinee].interned(), TyKind::String) {
+            collect_intrinsic(context, target, &intrinsics::new_string, intrinsics);
+            collect_intrinsic(context, target, &intrinsics::string_compare, intrinsics);
+            *needs_alloc = true;
+        }
+    }
+
     // Recurse further
     expr.walk_child_exprs(|expr_id| {
         collect_expr(
             context,
             target,
             db,
+            overflow_checks,
             intrinsics,
             needs_alloc,
+            needs_panic_handle,
             expr_id,
             body,
             infer,
@@ -87,12 +281,15 @@ fn collect_expr<'db, 'ink>(
 }
 
 /// Collects all intrinsics from the specified `body`.
+#[allow(clippy::too_many_arguments)]
 pub fn collect_fn_body<'db, 'ink>(
     context: &'ink Context,
     target: TargetData,
     db: &'db dyn HirDatabase,
+    overflow_checks: bool,
     intrinsics: &mut IntrinsicsMap<'ink>,
     needs_alloc: &mut bool,
+    needs_panic_handle: &mut bool,
     body: &Arc<Body>,
     infer: &InferenceResult,
 ) {
@@ -100,8 +297,10 @@ pub fn collect_fn_body<'db, 'ink>(
         context,
         &target,
         db,
+        overflow_checks,
         intrinsics,
         needs_alloc,
+        needs_panic_handle,
         body.body_expr(),
         body,
         infer,
@@ -119,3 +318,165 @@ pub fn collect_wrapper_body<'ink>(
     // self.collect_intrinsic(entries, &intrinsics::drop, module);
     *needs_alloc = true;
 }
+
+/// Collects all intrinsics that are required to create and call function values. Creating a
+/// function value allocates its environment and the lifted function of a function value
+/// propagates panics.
+pub fn collect_closures<'ink>(
+    context: &'ink Context,
+    target: TargetData,
+    intrinsics: &mut IntrinsicsMap<'ink>,
+    needs_alloc: &mut bool,
+    needs_panic_handle: &mut bool,
+) {
+    collect_intrinsic(context, &target, &intrinsics::new, intrinsics);
+    *needs_alloc = true;
+    collect_panic_unwind(context, &target, intrinsics, needs_panic_handle);
+}
+
+/// Collects all intrinsics that are required by the trap that is called by function values whose
+/// function was removed by hot reloading. The trap raises a panic.
+pub fn collect_fn_value_trap<'ink>(
+    context: &'ink Context,
+    target: TargetData,
+    intrinsics: &mut IntrinsicsMap<'ink>,
+    needs_panic_handle: &mut bool,
+) {
+    collect_intrinsic(context, &target, &intrinsics::panic, intrinsics);
+    *needs_panic_handle = true;
+}
+
+/// Returns the declaration of the LLVM intrinsic that implements the `function` of the
+/// `core::math` module for arguments of type `ty`, whose IR type is `ir_ty`. The intrinsic is
+/// declared in the `module` if it wasn't already. Returns `None` if the function doesn't need an
+/// intrinsic for the type, which is the case for the absolute value of an unsigned integer.
+pub(crate) fn get_math_intrinsic<'ink>(
+    context: &'ink Context,
+    module: &Module<'ink>,
+    function: IntrinsicFunction,
+    ty: &Ty,
+    ir_ty: BasicTypeEnum<'ink>,
+) -> Option<FunctionValue<'ink>> {
+    let mut param_tys: Vec<BasicMetadataTypeEnum> = vec![ir_ty.into(); function.param_count()];
+    let (name, type_suffix) = match ty.interned() {
+        TyKind::Float(float_ty) => {
+            let name = match function {
+                IntrinsicFunction::Sqrt => "sqrt",
+                IntrinsicFunction::Sin => "sin",
+                IntrinsicFunction::Cos => "cos",
+                IntrinsicFunction::Abs => "fabs",
+                IntrinsicFunction::Min => "minnum",
+                IntrinsicFunction::Max => "maxnum",
+                IntrinsicFunction::Floor => "floor",
+                IntrinsicFunction::Pow => "pow",
+                IntrinsicFunction::Panic => {
+                    unreachable!("`panic` is not lowered to an LLVM intrinsic")
+                }
+            };
+            let type_suffix = match float_ty.bitness {
+                FloatBitness::X32 => "f32",
+                FloatBitness::X64 => "f64",
+            };
+            (name, type_suffix.to_owned())
+        }
+        TyKind::Int(int_ty) => {
+            let is_signed = int_ty.signedness == mun_hir::Signedness::Signed;
+            let name = match (function, is_signed) {
+                (IntrinsicFunction::Abs, true) => {
+                    // Whether the result is poison if the argument is the minimum value
+                    param_tys.push(context.bool_type().into());
+                    "abs"
+                }
+                (IntrinsicFunction::Abs, false) => return None,
+                (IntrinsicFunction::Min, true) => "smin",
+                (IntrinsicFunction::Min, false) => "umin",
+                (IntrinsicFunction::Max, true) => "smax",
+                (IntrinsicFunction::Max, false) => "umax",
+                _ => unreachable!("`core::math::{}` cannot be applied to integers", function),
+            };
+            let type_suffix = format!("i{}", ir_ty.into_int_type().get_bit_width());
+            (name, type_suffix)
+        }
+        _ => unreachable!(
+            "`core::math::{}` cannot be applied to a value of this type",
+            function
+        ),
+    };
+
+    let intrinsic_name = format!("llvm.{}.{}", name, type_suffix);
+    let intrinsic = module.get_function(&intrinsic_name).unwrap_or_else(|| {
+        module.add_function(&intrinsic_name, ir_ty.fn_type(&param_tys, false), None)
+    });
+    Some(intrinsic)
+}
+
+/// Returns the declaration of the LLVM intrinsic that applies the arithmetic operation `op` to two
+/// integers of type `int_ty` and also returns whether the operation overflowed, e.g.
+/// `llvm.sadd.with.overflow.i32`. The intrinsic is declared in the `module` if it wasn't already.
+pub(crate) fn get_overflow_intrinsic<'ink>(
+    context: &'ink Context,
+    module: &Module<'ink>,
+    op: ArithOp,
+    signedness: mun_hir::Signedness,
+    int_ty: IntType<'ink>,
+) -> FunctionValue<'ink> {
+    let prefix = match signedness {
+        mun_hir::Signedness::Signed => "s",
+        mun_hir::Signedness::Unsigned => "u",
+    };
+    let name = match op {
+        ArithOp::Add => "add",
+        ArithOp::Subtract => "sub",
+        ArithOp::Multiply => "mul",
+        _ => unreachable!("there is no overflow intrinsic for {:?}", op),
+    };
+
+    let intrinsic_name = format!(
+        "llvm.{}{}.with.overflow.i{}",
+        prefix,
+        name,
+        int_ty.get_bit_width()
+    );
+    module.get_function(&intrinsic_name).unwrap_or_else(|| {
+        let return_ty = context.struct_type(&[int_ty.into(), context.bool_type().into()], false);
+        module.add_function(
+            &intrinsic_name,
+            return_ty.fn_type(&[int_ty.into(), int_ty.into()], false),
+            None,
+        )
+    })
+}
+
+/// Returns the declaration of the LLVM intrinsic that converts a float of type `float_ty` to an
+/// integer of type `int_ty`, saturating out-of-range values and converting NaN to zero, e.g.
+/// `llvm.fptosi.sat.i32.f64`. The intrinsic is declared in the `module` if it wasn't already.
+pub(crate) fn get_float_to_int_sat_intrinsic<'ink>(
+    module: &Module<'ink>,
+    signedness: mun_hir::Signedness,
+    int_ty: IntType<'ink>,
+    float_bitness: FloatBitness,
+    float_ty: FloatType<'ink>,
+) -> FunctionValue<'ink> {
+    let name = match signedness {
+        mun_hir::Signedness::Signed => "fptosi",
+        mun_hir::Signedness::Unsigned => "fptoui",
+    };
+    let float_suffix = match float_bitness {
+        FloatBitness::X32 => "f32",
+        FloatBitness::X64 => "f64",
+    };
+
+    let intrinsic_name = format!(
+        "llvm.{}.sat.i{}.{}",
+        name,
+        int_ty.get_bit_width(),
+        float_suffix
+    );
+    module.get_function(&intrinsic_name).unwrap_or_else(|| {
+        module.add_function(
+            &intrinsic_name,
+            int_ty.fn_type(&[float_ty.into()], false),
+            None,
+        )
+    })
+}
@@ -1,5 +1,6 @@
 use mun_hir::{
-    FloatBitness, HirDatabase, HirDisplay, IntBitness, ResolveBitness, Signedness, Ty, TyKind,
+    FloatBitness, FnSig, HirDatabase, HirDisplay, IntBitness, IntTy, ResolveBitness, Signedness,
+    Substitution, Ty, TyKind,
 };
 
 use crate::{
@@ -27,7 +28,22 @@ pub struct HirTypeCache<'db, 'ink> {
     target_data: TargetData,
     types: RefCell<HashMap<mun_hir::TyKind, StructType<'ink>>>,
     array_ty_to_type_id: RefCell<HashMap<mun_hir::TyKind, Arc<TypeId>>>,
-    struct_to_type_id: RefCell<HashMap<mun_hir::Struct, Arc<TypeId>>>,
+    struct_to_type_id: RefCell<HashMap<Ty, Arc<TypeId>>>,
+    enum_to_type_id: RefCell<HashMap<mun_hir::Enum, Arc<TypeId>>>,
+    variant_types: RefCell<HashMap<mun_hir::EnumVariant, StructType<'ink>>>,
+}
+
+/// Describes how an enum is laid out in memory.
+///
+/// An enum is represented by a tag that holds the index of the active variant, followed by a
+/// payload. The payload starts at the first offset after the tag that satisfies the alignment of
+/// all fields of all variants. The fields of each variant are laid out from the start of the
+/// payload.
+struct EnumLayout<'ink> {
+    tag_type: IntType<'ink>,
+    payload_offset: u64,
+    payload_align: u32,
+    payload_size: u64,
 }
 
 impl<'db, 'ink> HirTypeCache<'db, 'ink> {
@@ -39,7 +55,9 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             target_data,
             types: RefCell::new(HashMap::default()),
             struct_to_type_id: Default::default(),
+            enum_to_type_id: Default::default(),
             array_ty_to_type_id: Default::default(),
+            variant_types: Default::default(),
         }
     }
 
@@ -73,30 +91,34 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         usize::ir_type(self.context, &self.target_data)
     }
 
-    /// Returns the type of the specified integer type
-    pub fn get_struct_type(&self, struct_ty: mun_hir::Struct) -> StructType<'ink> {
+    /// Returns the type of the specified struct instantiated with the type arguments `substs`
+    pub fn get_struct_type(
+        &self,
+        struct_ty: mun_hir::Struct,
+        substs: &Substitution,
+    ) -> StructType<'ink> {
         // TODO: This assumes the contents of the mun_hir::Struct does not change. It definitely does
         //  between compilations. We have to have a way to uniquely identify the `mun_hir::Struct` and
         //  its contents.
 
-        let ty = TyKind::Struct(struct_ty);
+        let ty = Ty::struct_ty(struct_ty, substs.clone());
 
         // Get the type from the cache
-        if let Some(ir_ty) = self.types.borrow().get(&ty) {
+        if let Some(ir_ty) = self.types.borrow().get(ty.interned()) {
             return *ir_ty;
         };
 
         // Opaquely construct the struct type and store it in the cache
         let ir_ty = self
             .context
-            .opaque_struct_type(&struct_ty.name(self.db).to_string());
-        self.types.borrow_mut().insert(ty, ir_ty);
+            .opaque_struct_type(&ty.display(self.db).to_string());
+        self.types.borrow_mut().insert(ty.interned().clone(), ir_ty);
 
         // Fill the struct members
-        let field_types: Vec<_> = struct_ty
-            .fields(self.db)
+        let field_types: Vec<_> = ty
+            .struct_field_types(self.db)
+            .expect("expected a struct type")
             .into_iter()
-            .map(|field| field.ty(self.db))
             .map(|ty| {
                 self.get_basic_type(&ty)
                     .expect("could not convert struct field to basic type")
@@ -107,6 +129,162 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         ir_ty
     }
 
+    /// Returns the type of the tag of the specified enum. The tag holds the index of the active
+    /// variant.
+    pub fn get_enum_tag_type(&self, enum_ty: mun_hir::Enum) -> IntType<'ink> {
+        if enum_ty.variants(self.db).len() <= (u8::MAX as usize + 1) {
+            self.context.i8_type()
+        } else {
+            self.context.i16_type()
+        }
+    }
+
+    /// Returns the IR type that holds the fields of the specified enum variant.
+    fn get_variant_fields_type(&self, variant: mun_hir::EnumVariant) -> StructType<'ink> {
+        let field_types: Vec<_> = variant
+            .fields(self.db)
+            .into_iter()
+            .map(|field| field.ty(self.db))
+            .map(|ty| {
+                self.get_basic_type(&ty)
+                    .expect("could not convert variant field to basic type")
+            })
+            .collect();
+        self.context.struct_type(&field_types, false)
+    }
+
+    /// Computes the memory layout of the specified enum.
+    fn enum_layout(&self, enum_ty: mun_hir::Enum) -> EnumLayout<'ink> {
+        let tag_type = self.get_enum_tag_type(enum_ty);
+        let tag_size = self.target_data.get_abi_size(&tag_type);
+
+        let fields_types: Vec<_> = enum_ty
+            .variants(self.db)
+            .into_iter()
+            .map(|variant| self.get_variant_fields_type(variant))
+            .collect();
+
+        let payload_align = fields_types
+            .iter()
+            .map(|ty| self.target_data.get_abi_alignment(ty))
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let payload_size = fields_types
+            .iter()
+            .map(|ty| self.target_data.get_abi_size(ty))
+            .max()
+            .unwrap_or(0);
+        let payload_offset = (tag_size + u64::from(payload_align) - 1) / u64::from(payload_align)
+            * u64::from(payload_align);
+
+        EnumLayout {
+            tag_type,
+            payload_offset,
+            payload_align,
+            payload_size,
+        }
+    }
+
+    /// Returns the IR type of the specified enum.
+    ///
+    /// The enum is represented as its tag followed by an array of integers that is large enough to
+    /// hold the fields of every variant. The array is typed with an integer with the alignment of
+    /// the payload to ensure that the payload is correctly aligned.
+    pub fn get_enum_type(&self, enum_ty: mun_hir::Enum) -> StructType<'ink> {
+        let ty = TyKind::Enum(enum_ty);
+
+        // Get the type from the cache
+        if let Some(ir_ty) = self.types.borrow().get(&ty) {
+            return *ir_ty;
+        };
+
+        // Opaquely construct the enum type and store it in the cache
+        let ir_ty = self
+            .context
+            .opaque_struct_type(&enum_ty.name(self.db).to_string());
+        self.types.borrow_mut().insert(ty, ir_ty);
+
+        let layout = self.enum_layout(enum_ty);
+        let payload_element_type = self.context.custom_width_int_type(layout.payload_align * 8);
+        let payload_len = (layout.payload_size + u64::from(layout.payload_align) - 1)
+            / u64::from(layout.payload_align);
+        ir_ty.set_body(
+            &[
+                layout.tag_type.into(),
+                payload_element_type
+                    .array_type(
+                        payload_len
+                            .try_into()
+                            .expect("could not convert payload length to smaller size"),
+                    )
+                    .into(),
+            ],
+            false,
+        );
+
+        ir_ty
+    }
+
+    /// Returns the IR type that is used to access the fields of the specified enum variant. The
+    /// type is represented as:
+    ///
+    /// ```c
+    /// struct Variant {
+    ///     Tag tag;
+    ///     uint8_t padding[payload_offset - sizeof(Tag)];
+    ///     struct {
+    ///         ...fields
+    ///     } fields;
+    /// }
+    /// ```
+    ///
+    /// A pointer to an enum can be cast to a pointer of this type to read or write the fields of the
+    /// variant.
+    pub fn get_enum_variant_type(&self, variant: mun_hir::EnumVariant) -> StructType<'ink> {
+        if let Some(ir_ty) = self.variant_types.borrow().get(&variant) {
+            return *ir_ty;
+        }
+
+        let enum_ty = variant.parent_enum();
+        let layout = self.enum_layout(enum_ty);
+        let tag_size = self.target_data.get_abi_size(&layout.tag_type);
+
+        let ir_ty = self.context.opaque_struct_type(&format!(
+            "{}::{}",
+            enum_ty.name(self.db),
+            variant.name(self.db)
+        ));
+        ir_ty.set_body(
+            &[
+                layout.tag_type.into(),
+                self.context
+                    .i8_type()
+                    .array_type(
+                        (layout.payload_offset - tag_size)
+                            .try_into()
+                            .expect("could not convert padding to smaller size"),
+                    )
+                    .into(),
+                self.get_variant_fields_type(variant).into(),
+            ],
+            false,
+        );
+        self.variant_types.borrow_mut().insert(variant, ir_ty);
+
+        ir_ty
+    }
+
+    /// Returns the type of an enum that should be used in the public API. In the public API we
+    /// don't deal with value types, only with pointers.
+    pub fn get_public_enum_reference_type(&self, enum_ty: mun_hir::Enum) -> BasicTypeEnum<'ink> {
+        // Enums are converted to GC types in the public API.
+        self.get_enum_type(enum_ty)
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
     /// Returns the IR type of the specified array type.
     pub fn get_array_type(&self, element_ty: &Ty) -> StructType<'ink> {
         // Get the type from the cache
@@ -164,12 +342,22 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             .ptr_type(AddressSpace::Generic)
     }
 
+    /// Returns the type of a string that should be used for variables. Strings are stored on the
+    /// heap as an array of UTF-8 encoded bytes, so this will always be a pointer to an Array<u8>.
+    pub fn get_string_reference_type(&self) -> PointerType<'ink> {
+        self.get_array_reference_type(&TyKind::Int(IntTy::u8()).intern())
+    }
+
     /// Returns the type of the struct that should be used for variables. Depending on the memory
     /// type of the struct this is either a pointer to a GCHandle which holds a pointer to a struct,
     /// or, in case of a value struct, the struct type itself.
     /// Returns the type of the struct that should be used for variables.
-    pub fn get_struct_reference_type(&self, struct_ty: mun_hir::Struct) -> BasicTypeEnum<'ink> {
-        let ir_ty = self.get_struct_type(struct_ty);
+    pub fn get_struct_reference_type(
+        &self,
+        struct_ty: mun_hir::Struct,
+        substs: &Substitution,
+    ) -> BasicTypeEnum<'ink> {
+        let ir_ty = self.get_struct_type(struct_ty, substs);
         match struct_ty.data(self.db.upcast()).memory_kind {
             mun_hir::StructMemoryKind::Gc => {
                 // GC values are pointers to pointers
@@ -192,8 +380,9 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
     pub fn get_public_struct_reference_type(
         &self,
         struct_ty: mun_hir::Struct,
+        substs: &Substitution,
     ) -> BasicTypeEnum<'ink> {
-        let ir_ty = self.get_struct_type(struct_ty);
+        let ir_ty = self.get_struct_type(struct_ty, substs);
 
         // GC values are pointers to pointers
         // struct Foo {}
@@ -208,7 +397,11 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
 
     /// Returns the type of the specified function definition
     pub fn get_function_type(&self, ty: mun_hir::Function) -> FunctionType<'ink> {
-        let ty = self.db.callable_sig(ty.into());
+        self.get_fn_sig_type(&self.db.callable_sig(ty.into()))
+    }
+
+    /// Returns the type of a function with the specified signature
+    pub fn get_fn_sig_type(&self, ty: &FnSig) -> FunctionType<'ink> {
         let param_tys: Vec<_> = ty
             .params()
             .iter()
@@ -229,6 +422,114 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         }
     }
 
+    /// Returns the type of a function value. A function value is a reference to a garbage
+    /// collected environment, whose first field holds a pointer to the function that is called.
+    pub fn get_fn_value_type(&self) -> PointerType<'ink> {
+        self.context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+    }
+
+    /// Returns the type of the environment of a function value that captures values of the
+    /// specified types.
+    pub fn get_closure_env_type(&self, capture_types: &[Ty]) -> StructType<'ink> {
+        let field_types: Vec<BasicTypeEnum> = std::iter::once(
+            self.context
+                .i8_type()
+                .ptr_type(AddressSpace::Generic)
+                .into(),
+        )
+        .chain(capture_types.iter().map(|ty| {
+            self.get_basic_type(ty)
+                .expect("could not convert captured value to basic type")
+        }))
+        .collect();
+        self.context.struct_type(&field_types, false)
+    }
+
+    /// Returns the type of a function that is called through a function value with the specified
+    /// signature. The environment of the function value is passed as the first argument.
+    pub fn get_closure_function_type(&self, ty: &FnSig) -> FunctionType<'ink> {
+        let param_tys: Vec<_> = std::iter::once(self.get_fn_value_type().into())
+            .chain(ty.params().iter().map(|p| {
+                self.get_basic_type(p)
+                    .expect("could not convert function argument to basic type")
+                    .into()
+            }))
+            .collect();
+
+        let return_type = ty.ret();
+        match return_type.interned() {
+            TyKind::Tuple(0, _) => self.context.void_type().fn_type(&param_tys, false),
+            _ => self
+                .get_basic_type(return_type)
+                .expect("could not convert return value")
+                .fn_type(&param_tys, false),
+        }
+    }
+
+    /// Returns the IR type of a trait object of the specified trait. A trait object holds a
+    /// reference to a garbage collected struct together with a key that identifies the type of
+    /// the struct:
+    ///
+    /// ```c
+    /// struct DynTrait {
+    ///     void** object;
+    ///     uint64_t key;
+    /// }
+    /// ```
+    ///
+    /// The key is used to select the implementation of a trait function from the vtable of the
+    /// trait.
+    pub fn get_dyn_trait_type(&self, t: mun_hir::Trait) -> StructType<'ink> {
+        let ty = TyKind::DynTrait(t);
+
+        // Get the type from the cache
+        if let Some(ir_ty) = self.types.borrow().get(&ty) {
+            return *ir_ty;
+        };
+
+        let ir_ty = self
+            .context
+            .opaque_struct_type(&format!("dyn {}", t.name(self.db)));
+        self.types.borrow_mut().insert(ty, ir_ty);
+
+        ir_ty.set_body(
+            &[
+                self.context
+                    .i8_type()
+                    .ptr_type(AddressSpace::Generic)
+                    .ptr_type(AddressSpace::Generic)
+                    .into(),
+                self.context.i64_type().into(),
+            ],
+            false,
+        );
+
+        ir_ty
+    }
+
+    /// Returns the names and types of the fields of a trait object of the specified trait. The
+    /// object is described as a garbage collected struct without fields; the garbage collector
+    /// traces the object using its actual type.
+    pub fn dyn_trait_fields(&self, t: mun_hir::Trait) -> Vec<(String, Arc<TypeId>)> {
+        vec![
+            (String::from("object"), self.dyn_trait_object_type_id(t)),
+            (String::from("key"), u64::type_id().clone()),
+        ]
+    }
+
+    /// Returns the `TypeId` of the object that is referenced by a trait object of the specified
+    /// trait.
+    pub fn dyn_trait_object_type_id(&self, t: mun_hir::Trait) -> Arc<TypeId> {
+        let name = format!("dyn {}::object", t.full_name(self.db));
+        Arc::new(TypeId {
+            data: TypeIdData::Concrete(Guid::from_str(&name)),
+            name,
+        })
+    }
+
     /// Returns the type of a specified function definition that is callable from the outside of the
     /// Mun code. This function should be C ABI compatible.
     pub fn get_public_function_type(&self, ty: mun_hir::Function) -> FunctionType<'ink> {
@@ -260,9 +561,19 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
-            TyKind::Struct(struct_ty) => Some(self.get_struct_reference_type(*struct_ty)),
+            TyKind::Struct(struct_ty, substs) => {
+                Some(self.get_struct_reference_type(*struct_ty, substs))
+            }
+            TyKind::Enum(enum_ty) => Some(self.get_enum_type(*enum_ty).into()),
             TyKind::Bool => Some(self.get_bool_type().into()),
             TyKind::Array(element_ty) => Some(self.get_array_reference_type(element_ty).into()),
+            // An optional reference is a reference that might be null
+            TyKind::Option(inner_ty) => self.get_basic_type(inner_ty),
+            TyKind::String => Some(self.get_string_reference_type().into()),
+            TyKind::FnPtr(_) | TyKind::FnDef(mun_hir::CallableDef::Function(_), _) => {
+                Some(self.get_fn_value_type().into())
+            }
+            TyKind::DynTrait(t) => Some(self.get_dyn_trait_type(*t).into()),
             _ => None,
         }
     }
@@ -272,12 +583,22 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
     /// be represented as a basic type enum, `None` is returned.
     pub fn get_public_basic_type(&self, ty: &mun_hir::Ty) -> Option<BasicTypeEnum<'ink>> {
         match ty.interned() {
-            TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
+            TyKind::Tuple(0, substs) => Some(self.get_tuple_type(substs).into()),
+            TyKind::Tuple(_, substs) => Some(self.get_public_tuple_reference_type(substs)),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
-            TyKind::Struct(struct_ty) => Some(self.get_public_struct_reference_type(*struct_ty)),
+            TyKind::Struct(struct_ty, substs) => {
+                Some(self.get_public_struct_reference_type(*struct_ty, substs))
+            }
+            TyKind::Enum(enum_ty) => Some(self.get_public_enum_reference_type(*enum_ty)),
             TyKind::Bool => Some(self.get_bool_type().into()),
             TyKind::Array(element_ty) => Some(self.get_array_reference_type(element_ty).into()),
+            TyKind::Option(inner_ty) => self.get_public_basic_type(inner_ty),
+            TyKind::String => Some(self.get_string_reference_type().into()),
+            TyKind::FnPtr(_) | TyKind::FnDef(mun_hir::CallableDef::Function(_), _) => {
+                Some(self.get_fn_value_type().into())
+            }
+            TyKind::DynTrait(t) => Some(self.get_dyn_trait_type(*t).into()),
             _ => None,
         }
     }
@@ -289,15 +610,19 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
-            TyKind::Struct(struct_ty) => Some(self.get_struct_type(*struct_ty).into()),
-            TyKind::FnDef(mun_hir::CallableDef::Function(fn_ty), type_params) => {
-                if !type_params.is_empty() {
-                    unimplemented!("cannot yet deal with type parameters in functions");
-                }
-                Some(self.get_function_type(*fn_ty).into())
+            TyKind::Struct(struct_ty, substs) => {
+                Some(self.get_struct_type(*struct_ty, substs).into())
             }
+            TyKind::Enum(enum_ty) => Some(self.get_enum_type(*enum_ty).into()),
+            TyKind::FnDef(mun_hir::CallableDef::Function(fn_ty), type_params) => Some(
+                self.get_fn_sig_type(&self.db.callable_sig((*fn_ty).into()).subst(type_params))
+                    .into(),
+            ),
             TyKind::Bool => Some(self.get_bool_type().into()),
             TyKind::Array(element_ty) => Some(self.get_array_reference_type(element_ty).into()),
+            TyKind::Option(inner_ty) => self.get_any_type(inner_ty),
+            TyKind::String => Some(self.get_string_reference_type().into()),
+            TyKind::DynTrait(t) => Some(self.get_dyn_trait_type(*t).into()),
             _ => None,
         }
     }
@@ -320,6 +645,25 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         self.context.struct_type(&tuple_ir_types, false)
     }
 
+    /// Returns the type of a tuple that should be used in the public API. Like value structs,
+    /// tuples are converted to GC types in the public API.
+    pub fn get_public_tuple_reference_type(&self, type_params: &[Ty]) -> BasicTypeEnum<'ink> {
+        self.get_tuple_type(type_params)
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the names and types of the fields of a tuple with the specified element types. The
+    /// fields of a tuple are named after their index.
+    pub fn tuple_fields(&self, type_params: &[Ty]) -> Vec<(String, Arc<TypeId>)> {
+        type_params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| (idx.to_string(), self.type_id(ty)))
+            .collect()
+    }
+
     /// Returns a `TypeInfo` for the specified `ty`
     pub fn type_id(&self, ty: &Ty) -> Arc<TypeId> {
         match ty.interned() {
@@ -349,14 +693,40 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
                 }
             }
             TyKind::Bool => bool::type_id().clone(),
-            &TyKind::Struct(s) => self
-                .struct_to_type_id
+            TyKind::String => str::type_id().clone(),
+            TyKind::Struct(s, substs) => {
+                if let Some(type_id) = self.struct_to_type_id.borrow().get(ty) {
+                    return type_id.clone();
+                }
+
+                // The name of an instantiation of a generic struct includes its type arguments,
+                // e.g. `foo::Pair<core::i32, core::f32>`.
+                let mut name = s.full_name(self.db);
+                if !substs.is_empty() {
+                    let type_args: Vec<String> = substs
+                        .iter()
+                        .map(|ty| self.type_id(ty).name.clone())
+                        .collect();
+                    name = format!("{}<{}>", name, type_args.join(", "));
+                }
+
+                let type_id = Arc::new(TypeId {
+                    name,
+                    data: TypeIdData::Concrete(guid_from_struct(self.db, *s, substs)),
+                });
+                self.struct_to_type_id
+                    .borrow_mut()
+                    .insert(ty.clone(), type_id.clone());
+                type_id
+            }
+            &TyKind::Enum(e) => self
+                .enum_to_type_id
                 .borrow_mut()
-                .entry(s)
+                .entry(e)
                 .or_insert_with(|| {
                     Arc::new(TypeId {
-                        name: s.full_name(self.db),
-                        data: TypeIdData::Concrete(guid_from_struct(self.db, s)),
+                        name: e.full_name(self.db),
+                        data: TypeIdData::Concrete(guid_from_enum(self.db, e)),
                     })
                 })
                 .clone(),
@@ -384,19 +754,102 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
 
                 array_type_id
             }
+            TyKind::FnPtr(_) | TyKind::FnDef(mun_hir::CallableDef::Function(_), _) => {
+                let sig = ty
+                    .callable_sig(self.db)
+                    .expect("a function must be callable");
+                let param_names: Vec<String> = sig
+                    .params()
+                    .iter()
+                    .map(|ty| self.type_id(ty).name.clone())
+                    .collect();
+                let mut name = format!("fn({})", param_names.join(", "));
+                if !sig.ret().is_empty() {
+                    name = format!("{} -> {}", name, self.type_id(sig.ret()).name);
+                }
+
+                let guid_string = Ty::fn_ptr(sig)
+                    .guid_string(self.db)
+                    .expect("type should be convertible to a string");
+                Arc::new(TypeId {
+                    name,
+                    data: TypeIdData::Concrete(Guid::from_str(&guid_string)),
+                })
+            }
+            TyKind::Tuple(_, substs) if !substs.is_empty() => {
+                // A tuple is described as a value struct whose fields are named after their index,
+                // e.g. `(core::i32, core::f32)`.
+                let element_names: Vec<String> = substs
+                    .iter()
+                    .map(|ty| self.type_id(ty).name.clone())
+                    .collect();
+                let name = if element_names.len() == 1 {
+                    format!("({},)", element_names[0])
+                } else {
+                    format!("({})", element_names.join(", "))
+                };
+
+                let fields: Vec<String> = substs
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, ty)| {
+                        let ty_string = ty
+                            .guid_string(self.db)
+                            .expect("type should be convertible to a string");
+                        format!("{}: {}", idx, ty_string)
+                    })
+                    .collect();
+                Arc::new(TypeId {
+                    data: TypeIdData::Concrete(Guid::from_str(&format!(
+                        "struct {name}{{{fields}}}",
+                        name = &name,
+                        fields = fields.join(",")
+                    ))),
+                    name,
+                })
+            }
+            TyKind::Option(inner_ty) => {
+                let inner_type_id = self.type_id(inner_ty);
+                Arc::new(TypeId {
+                    name: format!("?{}", &inner_type_id.name),
+                    data: TypeIdData::Optional(inner_type_id),
+                })
+            }
+            TyKind::DynTrait(t) => Arc::new(TypeId {
+                name: format!("dyn {}", t.full_name(self.db)),
+                data: TypeIdData::Concrete(Guid::from_str(
+                    &ty.guid_string(self.db)
+                        .expect("type should be convertible to a string"),
+                )),
+            }),
             _ => unimplemented!("{} unhandled", ty.display(self.db)),
         }
     }
 }
 
-pub fn guid_from_struct(db: &dyn HirDatabase, s: mun_hir::Struct) -> Guid {
-    let name = s.full_name(db);
+/// Returns the `Guid` of the specified struct instantiated with the type arguments `substs`. The
+/// `Guid` of every instantiation of a generic struct is stable across compilations as long as the
+/// struct and its type arguments do not change.
+pub fn guid_from_struct(db: &dyn HirDatabase, s: mun_hir::Struct, substs: &Substitution) -> Guid {
+    let mut name = s.full_name(db);
+    if !substs.is_empty() {
+        let type_args: Vec<String> = substs
+            .iter()
+            .map(|ty| {
+                ty.guid_string(db)
+                    .expect("type should be convertible to a string")
+            })
+            .collect();
+        name = format!("{}<{}>", name, type_args.join(","));
+    }
+
     let fields: Vec<String> = s
         .fields(db)
         .into_iter()
         .map(|f| {
             let ty_string = f
                 .ty(db)
+                .subst(substs)
                 .guid_string(db)
                 .expect("type should be convertible to a string");
             format!("{}: {}", f.name(db), ty_string)
@@ -409,3 +862,41 @@ pub fn guid_from_struct(db: &dyn HirDatabase, s: mun_hir::Struct) -> Guid {
         fields = fields.join(",")
     ))
 }
+
+/// Returns the key that identifies the struct `s` in a trait object. The key is derived from the
+/// name of the struct, so it remains stable when the fields of the struct change during a hot
+/// reload.
+pub fn trait_object_key(db: &dyn HirDatabase, s: mun_hir::Struct) -> u64 {
+    let guid = Guid::from_str(&s.full_name(db));
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&guid.0[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+pub fn guid_from_enum(db: &dyn HirDatabase, e: mun_hir::Enum) -> Guid {
+    let name = e.full_name(db);
+    let variants: Vec<String> = e
+        .variants(db)
+        .into_iter()
+        .map(|v| {
+            let fields: Vec<String> = v
+                .fields(db)
+                .into_iter()
+                .map(|f| {
+                    let ty_string = f
+                        .ty(db)
+                        .guid_string(db)
+                        .expect("type should be convertible to a string");
+                    format!("{}: {}", f.name(db), ty_string)
+                })
+                .collect();
+            format!("{}{{{}}}", v.name(db), fields.join(","))
+        })
+        .collect();
+
+    Guid::from_str(&format!(
+        "enum {name}{{{variants}}}",
+        name = &name,
+        variants = variants.join(",")
+    ))
+}
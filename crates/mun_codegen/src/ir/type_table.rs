@@ -4,10 +4,12 @@ use inkwell::{
     context::Context, module::Linkage, module::Module, types::ArrayType, values::PointerValue,
 };
 
-use mun_hir::{Body, ExprId, HirDatabase, InferenceResult};
+use mun_hir::{Body, ExprId, HirDatabase, InferenceResult, Ty};
 
 use crate::{
+    ir::closure::Closure,
     ir::dispatch_table::{DispatchTable, FunctionPrototype},
+    ir::instance::FunctionInstance,
     ir::ty::HirTypeCache,
     type_info::TypeId,
     value::{Global, IrValueContext, IterAsIrValue, Value},
@@ -142,8 +144,10 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
                 Some(mun_hir::CallableDef::Function(hir_fn)) => {
                     self.maybe_collect_fn_signature(hir_fn);
                 }
-                Some(mun_hir::CallableDef::Struct(_)) => (),
-                None => panic!("expected a callable expression"),
+                Some(mun_hir::CallableDef::Struct(_))
+                | Some(mun_hir::CallableDef::EnumVariant(_)) => (),
+                // Function values are called through the pointer stored in their environment
+                None => (),
             }
         } else if let mun_hir::Expr::Array(..) = expr {
             self.collect_type(self.hir_types.type_id(&infer[expr_id]))
@@ -190,14 +194,59 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
         self.collect_expr(body.body_expr(), &body, &infer);
     }
 
+    /// Collects unique `TypeInfo` from the environment and the signature of the lifted function of
+    /// the specified function value.
+    pub fn collect_closure(&mut self, closure: &Closure) {
+        self.collect_type(closure.env_type_id(self.db, self.hir_types));
+        for (_, field_type_info) in closure.env_fields(self.db, self.hir_types) {
+            self.collect_type(field_type_info);
+        }
+
+        let fn_sig = closure.sig(self.db);
+        for ty in fn_sig.params().iter() {
+            self.collect_type(self.hir_types.type_id(ty));
+        }
+        let ret_ty = fn_sig.ret();
+        if !ret_ty.is_empty() {
+            self.collect_type(self.hir_types.type_id(ret_ty));
+        }
+    }
+
+    /// Collects unique `TypeInfo` from the body of the specified instance of a generic function.
+    pub fn collect_instance(&mut self, instance: &FunctionInstance) {
+        let body = instance.function.body(self.db);
+        let infer = instance.infer(self.db);
+        self.collect_expr(body.body_expr(), &body, &infer);
+    }
+
     /// Collects unique `TypeInfo` from the specified struct type.
     pub fn collect_struct(&mut self, hir_struct: mun_hir::Struct) {
-        let type_info = self.hir_types.type_id(&hir_struct.ty(self.db));
+        self.collect_struct_ty(&hir_struct.ty(self.db));
+    }
+
+    /// Collects unique `TypeInfo` from the specified struct type, which can be an instance of a
+    /// generic struct.
+    pub fn collect_struct_ty(&mut self, struct_ty: &Ty) {
+        let type_info = self.hir_types.type_id(struct_ty);
         self.collect_type(type_info);
 
-        let fields = hir_struct.fields(self.db);
-        for field in fields.into_iter() {
-            self.collect_type(self.hir_types.type_id(&field.ty(self.db)));
+        let field_types = struct_ty
+            .struct_field_types(self.db)
+            .expect("expected a struct type");
+        for field_ty in field_types.iter() {
+            self.collect_type(self.hir_types.type_id(field_ty));
+        }
+    }
+
+    /// Collects unique `TypeInfo` from the specified enum type.
+    pub fn collect_enum(&mut self, hir_enum: mun_hir::Enum) {
+        let type_info = self.hir_types.type_id(&hir_enum.ty(self.db));
+        self.collect_type(type_info);
+
+        for variant in hir_enum.variants(self.db) {
+            for field in variant.fields(self.db) {
+                self.collect_type(self.hir_types.type_id(&field.ty(self.db)));
+            }
         }
     }
 
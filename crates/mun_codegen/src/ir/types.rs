@@ -51,6 +51,7 @@ pub enum TypeId<'ink> {
     Concrete(abi::Guid),
     Pointer(PointerTypeId<'ink>),
     Array(ArrayTypeId<'ink>),
+    Optional(OptionalTypeId<'ink>),
 }
 
 #[derive(AsValue)]
@@ -64,6 +65,11 @@ pub struct ArrayTypeId<'ink> {
     pub element: Global<'ink, TypeId<'ink>>,
 }
 
+#[derive(AsValue)]
+pub struct OptionalTypeId<'ink> {
+    pub inner: Global<'ink, TypeId<'ink>>,
+}
+
 #[derive(AsValue)]
 pub struct TypeDefinition<'ink> {
     pub name: Value<'ink, *const u8>,
@@ -76,6 +82,7 @@ pub struct TypeDefinition<'ink> {
 #[repr(u8)]
 pub enum TypeDefinitionData<'ink> {
     Struct(StructDefinition<'ink>),
+    Enum(EnumDefinition<'ink>),
 }
 
 #[derive(AsValue)]
@@ -107,13 +114,40 @@ pub struct StructDefinition<'ink> {
     pub memory_kind: abi::StructMemoryKind,
 }
 
+#[derive(AsValue)]
+pub struct EnumDefinition<'ink> {
+    pub guid: abi::Guid,
+    pub variants: Value<'ink, *const VariantDefinition<'ink>>,
+    pub num_variants: u16,
+    pub tag_size: u8,
+}
+
+#[derive(AsValue)]
+pub struct VariantDefinition<'ink> {
+    pub name: Value<'ink, *const u8>,
+    pub field_names: Value<'ink, *const *const u8>,
+    pub field_types: Value<'ink, *const TypeId<'ink>>,
+    pub field_offsets: Value<'ink, *const u16>,
+    pub num_fields: u16,
+}
+
+#[derive(AsValue)]
+pub struct GlobalDefinition<'ink> {
+    pub name: Value<'ink, *const u8>,
+    pub type_id: TypeId<'ink>,
+    pub ptr: Value<'ink, *const std::ffi::c_void>,
+    pub mutable: bool,
+}
+
 #[derive(AsValue)]
 pub struct ModuleInfo<'ink> {
     pub path: Value<'ink, *const u8>,
     pub functions: Value<'ink, *const FunctionDefinition<'ink>>,
     pub types: Value<'ink, *const TypeDefinition<'ink>>,
+    pub globals: Value<'ink, *const GlobalDefinition<'ink>>,
     pub num_functions: u32,
     pub num_types: u32,
+    pub num_globals: u32,
 }
 
 #[derive(AsValue)]
@@ -131,11 +165,20 @@ pub struct TypeLut<'ink> {
     pub num_entries: u32,
 }
 
+#[derive(AsValue)]
+pub struct GlobalLut<'ink> {
+    pub global_names: Value<'ink, *const *const u8>,
+    pub type_ids: Value<'ink, *const TypeId<'ink>>,
+    pub global_ptrs: Value<'ink, *mut *const std::ffi::c_void>,
+    pub num_entries: u32,
+}
+
 #[derive(AsValue)]
 pub struct AssemblyInfo<'ink> {
     pub symbols: ModuleInfo<'ink>,
     pub dispatch_table: DispatchTable<'ink>,
     pub type_lut: TypeLut<'ink>,
+    pub global_lut: GlobalLut<'ink>,
     pub dependencies: Value<'ink, *const *const u8>,
     pub num_dependencies: u32,
 }
@@ -33,13 +33,18 @@ fn abi_struct_sizes() {
     test_type_size::<abi::TypeId, ir::TypeId>(&type_context);
     test_type_size::<abi::PointerTypeId, ir::PointerTypeId>(&type_context);
     test_type_size::<abi::ArrayTypeId, ir::ArrayTypeId>(&type_context);
+    test_type_size::<abi::OptionalTypeId, ir::OptionalTypeId>(&type_context);
     test_type_size::<abi::TypeDefinitionData, ir::TypeDefinitionData>(&type_context);
     test_type_size::<abi::StructDefinition, ir::StructDefinition>(&type_context);
+    test_type_size::<abi::EnumDefinition, ir::EnumDefinition>(&type_context);
+    test_type_size::<abi::VariantDefinition, ir::VariantDefinition>(&type_context);
     test_type_size::<abi::TypeDefinition, ir::TypeDefinition>(&type_context);
     test_type_size::<abi::FunctionSignature, ir::FunctionSignature>(&type_context);
     test_type_size::<abi::FunctionPrototype, ir::FunctionPrototype>(&type_context);
+    test_type_size::<abi::GlobalDefinition, ir::GlobalDefinition>(&type_context);
     test_type_size::<abi::ModuleInfo, ir::ModuleInfo>(&type_context);
     test_type_size::<abi::DispatchTable, ir::DispatchTable>(&type_context);
     test_type_size::<abi::TypeLut, ir::TypeLut>(&type_context);
+    test_type_size::<abi::GlobalLut, ir::GlobalLut>(&type_context);
     test_type_size::<abi::AssemblyInfo, ir::AssemblyInfo>(&type_context);
 }
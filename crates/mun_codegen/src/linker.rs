@@ -246,6 +246,8 @@ impl Linker for MsvcLinker {
             .push(format!("/EXPORT:{}", abi::GET_VERSION_FN_NAME));
         self.args
             .push(format!("/EXPORT:{}", abi::SET_ALLOCATOR_HANDLE_FN_NAME));
+        self.args
+            .push(format!("/EXPORT:{}", abi::SET_PANIC_HANDLE_FN_NAME));
         self.args.push(format!("/IMPLIB:{}", dll_lib_path_str));
         self.args.push(format!("/OUT:{}", dll_path_str));
         Ok(())
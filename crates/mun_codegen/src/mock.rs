@@ -68,6 +68,8 @@ impl Default for MockDatabase {
             events: Default::default(),
         };
         db.set_optimization_level(OptimizationLevel::Default);
+        db.set_overflow_checks(false);
+        db.set_export_tests(false);
         db.set_target(Target::host_target().unwrap());
         db
     }
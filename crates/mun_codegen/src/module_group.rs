@@ -11,19 +11,23 @@ pub struct ModuleGroup {
     ordered_modules: Vec<mun_hir::Module>,
     modules: FxHashSet<mun_hir::Module>,
     includes_entire_subtree: FxHashMap<mun_hir::Module, bool>,
+    export_tests: bool,
     pub name: String,
 }
 
 impl Hash for ModuleGroup {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.ordered_modules.hash(state);
+        self.export_tests.hash(state);
         self.name.hash(state);
     }
 }
 
 impl PartialEq for ModuleGroup {
     fn eq(&self, other: &Self) -> bool {
-        self.ordered_modules == other.ordered_modules && self.name == other.name
+        self.ordered_modules == other.ordered_modules
+            && self.export_tests == other.export_tests
+            && self.name == other.name
     }
 }
 
@@ -55,10 +59,18 @@ impl ModuleGroup {
             ordered_modules,
             modules,
             includes_entire_subtree,
+            export_tests: false,
             name,
         }
     }
 
+    /// Sets whether functions marked with `#[test]` are exported from the module group
+    /// regardless of their visibility.
+    pub fn with_exported_tests(mut self, export_tests: bool) -> Self {
+        self.export_tests = export_tests;
+        self
+    }
+
     /// Constructs a new module group from a single module
     pub fn from_single_module(db: &dyn HirDatabase, module: mun_hir::Module) -> Self {
         Self::new(db, module.full_name(db), vec![module])
@@ -82,19 +94,45 @@ impl ModuleGroup {
             return false;
         }
 
-        let vis = function.visibility(db);
+        // Generic functions are instantiated by the module groups that use them
+        if function.is_generic(db) {
+            return false;
+        }
+
+        // Tests are invoked by the test runner, so they must be exported even if they are private
+        if self.export_tests && function.is_test(db) {
+            return true;
+        }
+
+        self.should_export_visibility(function.visibility(db))
+    }
+
+    /// Returns true if the specified static should be exported from the module group. This
+    /// indicates that when queried the resulting assembly will expose this static.
+    pub fn should_export_static(&self, db: &dyn HirDatabase, hir_static: mun_hir::Static) -> bool {
+        // If the static is not defined in the module group we should definitely not export it.
+        if !self.modules.contains(&hir_static.module(db)) {
+            return false;
+        }
+
+        self.should_export_visibility(hir_static.visibility(db))
+    }
+
+    /// Returns true if an item with the specified visibility must be exported from the module
+    /// group.
+    fn should_export_visibility(&self, vis: mun_hir::Visibility) -> bool {
         match vis {
-            // If the function is publicly accessible it must always be exported
+            // If the item is publicly accessible it must always be exported
             mun_hir::Visibility::Public => true,
 
-            // The function is visible from the specified module and all child modules.
+            // The item is visible from the specified module and all child modules.
             mun_hir::Visibility::Module(visible_mod) => {
                 // If the modules is contained within `includes_entire_subtree` it is included in
                 // the module group.
                 self.includes_entire_subtree
                     .get(&visible_mod.into())
                     // If all its children are also part of the module group we can keep the
-                    // function internal, so there is no need to export it.
+                    // item internal, so there is no need to export it.
                     .map(|&includes_subtree| !includes_subtree)
                     // Otherwise, the module is not part of the group and we have to export it.
                     .unwrap_or(true)
@@ -109,7 +147,9 @@ impl ModuleGroup {
         db: &dyn HirDatabase,
         function: mun_hir::Function,
     ) -> bool {
-        function.is_extern(db) || !self.modules.contains(&function.module(db))
+        // Instances of generic functions are always generated in the module group that uses them
+        !function.is_generic(db)
+            && (function.is_extern(db) || !self.modules.contains(&function.module(db)))
     }
 
     /// Returns the `mun_hir::FileId`s that are included in this module group.
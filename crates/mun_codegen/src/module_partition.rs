@@ -81,7 +81,8 @@ pub(crate) fn build_partition(db: &dyn CodeGenDatabase) -> Arc<ModulePartition>
 
         partition.add_group(
             db.upcast(),
-            ModuleGroup::new(db.upcast(), name, vec![module]),
+            ModuleGroup::new(db.upcast(), name, vec![module])
+                .with_exported_tests(db.export_tests()),
         );
     }
     Arc::new(partition)
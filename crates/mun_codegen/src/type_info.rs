@@ -19,6 +19,7 @@ pub enum TypeIdData {
     Concrete(Guid),
     Pointer(PointerTypeId),
     Array(Arc<TypeId>),
+    Optional(Arc<TypeId>),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -67,6 +68,7 @@ impl_primitive_type_id! {
     f64,
     bool,
     (),
+    str,
     std::ffi::c_void
 }
 
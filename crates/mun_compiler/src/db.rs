@@ -62,6 +62,8 @@ impl CompilerDatabase {
     pub fn set_config(&mut self, config: &Config) {
         self.set_target(config.target.clone());
         self.set_optimization_level(config.optimization_lvl);
+        self.set_overflow_checks(config.overflow_checks);
+        self.set_export_tests(config.export_tests);
     }
 }
 
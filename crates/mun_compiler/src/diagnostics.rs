@@ -1,11 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use crate::{Config, DisplayColor, Driver, PathOrInline, RelativePathBuf};
+    use crate::{Config, DisplayColor, Driver, MessageFormat, PathOrInline, RelativePathBuf};
     use std::io::Cursor;
 
     /// Compile passed source code and return all compilation errors
     fn compilation_errors(source_code: &str) -> String {
-        let config = Config::default();
+        compilation_errors_with_format(source_code, MessageFormat::Human)
+    }
+
+    /// Compile passed source code and return all compilation errors in the specified format
+    fn compilation_errors_with_format(source_code: &str, message_format: MessageFormat) -> String {
+        let config = Config {
+            message_format,
+            ..Config::default()
+        };
 
         let input = PathOrInline::Inline {
             rel_path: RelativePathBuf::from("main.mun"),
@@ -106,4 +114,68 @@ mod tests {
     fn test_expected_function() {
         insta::assert_display_snapshot!(compilation_errors("\n\nfn foo() { let a = 3; a(); }"));
     }
+
+    #[test]
+    fn test_json_diagnostics() {
+        let errors = compilation_errors_with_format(
+            "#[foo]\nfn main() {\nlet a: f64 = false;\n}",
+            MessageFormat::Json,
+        );
+        let diagnostics: Vec<serde_json::Value> = errors
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                serde_json::json!({
+                    "severity": "error",
+                    "message": "cannot find attribute `foo`",
+                    "spans": [{
+                        "file": "main.mun",
+                        "byte_start": 0,
+                        "byte_end": 6,
+                        "line_start": 1,
+                        "column_start": 1,
+                        "line_end": 1,
+                        "column_end": 7,
+                        "is_primary": true,
+                        "label": "unknown attribute",
+                    }],
+                    "notes": [],
+                    "fixes": [{
+                        "message": "remove the `foo` attribute",
+                        "edits": [{
+                            "file": "main.mun",
+                            "byte_start": 0,
+                            "byte_end": 7,
+                            "line_start": 1,
+                            "column_start": 1,
+                            "line_end": 2,
+                            "column_end": 1,
+                            "replacement": "",
+                        }],
+                    }],
+                }),
+                serde_json::json!({
+                    "severity": "error",
+                    "message": "expected `f64`, found `bool`",
+                    "spans": [{
+                        "file": "main.mun",
+                        "byte_start": 32,
+                        "byte_end": 37,
+                        "line_start": 3,
+                        "column_start": 14,
+                        "line_end": 3,
+                        "column_end": 19,
+                        "is_primary": true,
+                        "label": null,
+                    }],
+                    "notes": [],
+                    "fixes": [],
+                }),
+            ]
+        );
+    }
 }
@@ -0,0 +1,173 @@
+use mun_diagnostics::DiagnosticForWith;
+use mun_hir::{FileId, HirDatabase, InFile};
+use mun_syntax::{Location, SyntaxError, TextRange};
+use serde_derive::Serialize;
+
+/// The severity of a diagnostic
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Error,
+}
+
+/// A machine-readable representation of a diagnostic
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic {
+    severity: Severity,
+    message: String,
+    spans: Vec<JsonSpan>,
+    notes: Vec<String>,
+    fixes: Vec<JsonFix>,
+}
+
+/// A range within a source file. Lines and columns are one-based and columns are counted in UTF-16
+/// code units.
+#[derive(Debug, Serialize)]
+struct JsonRange {
+    file: String,
+    byte_start: u32,
+    byte_end: u32,
+    line_start: u32,
+    column_start: u32,
+    line_end: u32,
+    column_end: u32,
+}
+
+/// A range within a source file that is annotated by a diagnostic
+#[derive(Debug, Serialize)]
+struct JsonSpan {
+    #[serde(flatten)]
+    range: JsonRange,
+    is_primary: bool,
+    label: Option<String>,
+}
+
+/// A change to the source code that is suggested to resolve a diagnostic
+#[derive(Debug, Serialize)]
+struct JsonFix {
+    message: String,
+    edits: Vec<JsonEdit>,
+}
+
+/// Replaces the text within a range of a source file
+#[derive(Debug, Serialize)]
+struct JsonEdit {
+    #[serde(flatten)]
+    range: JsonRange,
+    replacement: String,
+}
+
+impl JsonRange {
+    fn new(db: &impl HirDatabase, range: InFile<TextRange>) -> Self {
+        let line_index = db.line_index(range.file_id);
+        let start = line_index.line_col(range.value.start());
+        let end = line_index.line_col(range.value.end());
+        JsonRange {
+            file: db.file_relative_path(range.file_id).to_string(),
+            byte_start: range.value.start().into(),
+            byte_end: range.value.end().into(),
+            line_start: start.line + 1,
+            column_start: start.col_utf16 + 1,
+            line_end: end.line + 1,
+            column_end: end.col_utf16 + 1,
+        }
+    }
+}
+
+/// Writes the specified syntax error to the output stream as a single line of JSON.
+pub(crate) fn emit_syntax_error_json(
+    syntax_error: &SyntaxError,
+    db: &impl HirDatabase,
+    file_id: FileId,
+    writer: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    let range = match syntax_error.location() {
+        Location::Offset(offset) => TextRange::empty(offset),
+        Location::Range(range) => range,
+    };
+
+    write_json(
+        writer,
+        &JsonDiagnostic {
+            severity: Severity::Error,
+            message: syntax_error.to_string(),
+            spans: vec![JsonSpan {
+                range: JsonRange::new(db, InFile::new(file_id, range)),
+                is_primary: true,
+                label: None,
+            }],
+            notes: Vec::new(),
+            fixes: Vec::new(),
+        },
+    )
+}
+
+/// Writes a diagnostic that is a result of HIR validation to the output stream as a single line of
+/// JSON.
+pub(crate) fn emit_hir_diagnostic_json(
+    diagnostic: &dyn mun_hir::Diagnostic,
+    db: &impl HirDatabase,
+    file_id: FileId,
+    writer: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    diagnostic.with_diagnostic(db, |diagnostic| {
+        let primary_annotation = diagnostic.primary_annotation();
+        let primary_span = JsonSpan {
+            range: JsonRange::new(
+                db,
+                InFile::new(
+                    file_id,
+                    primary_annotation
+                        .as_ref()
+                        .map_or_else(|| diagnostic.range(), |annotation| annotation.range),
+                ),
+            ),
+            is_primary: true,
+            label: primary_annotation.map(|annotation| annotation.message),
+        };
+
+        let secondary_spans = diagnostic
+            .secondary_annotations()
+            .into_iter()
+            .map(|annotation| JsonSpan {
+                range: JsonRange::new(db, annotation.range),
+                is_primary: false,
+                label: Some(annotation.message),
+            });
+
+        let fixes = diagnostic
+            .fixes()
+            .into_iter()
+            .map(|fix| JsonFix {
+                message: fix.message,
+                edits: fix
+                    .edits
+                    .into_iter()
+                    .map(|edit| JsonEdit {
+                        range: JsonRange::new(db, edit.range),
+                        replacement: edit.replacement,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        write_json(
+            writer,
+            &JsonDiagnostic {
+                severity: Severity::Error,
+                message: diagnostic.title(),
+                spans: std::iter::once(primary_span)
+                    .chain(secondary_spans)
+                    .collect(),
+                notes: diagnostic.footer(),
+                fixes,
+            },
+        )
+    })
+}
+
+/// Writes `diagnostic` followed by a newline.
+fn write_json(writer: &mut dyn std::io::Write, diagnostic: &JsonDiagnostic) -> std::io::Result<()> {
+    serde_json::to_writer(&mut *writer, diagnostic)?;
+    writeln!(writer)
+}
@@ -7,17 +7,20 @@ use crate::{
 };
 use mun_codegen::{AssemblyIr, CodeGenDatabase, ModuleGroup, TargetAssembly};
 use mun_hir::{
-    AstDatabase, DiagnosticSink, FileId, Module, PackageSet, SourceDatabase, SourceRoot,
+    AstDatabase, DiagnosticSink, FileId, Module, ModuleDef, PackageSet, SourceDatabase, SourceRoot,
     SourceRootId, Upcast,
 };
 use mun_paths::RelativePathBuf;
 
 mod config;
 mod display_color;
+mod message_format;
 
 pub use self::config::Config;
 pub use self::display_color::DisplayColor;
+pub use self::message_format::MessageFormat;
 
+use crate::diagnostics_json::{emit_hir_diagnostic_json, emit_syntax_error_json};
 use crate::diagnostics_snippets::{emit_hir_diagnostic, emit_syntax_error};
 use mun_project::{Package, LOCKFILE_NAME};
 use std::{
@@ -28,6 +31,16 @@ use walkdir::WalkDir;
 
 pub const WORKSPACE: SourceRootId = SourceRootId(0);
 
+/// A function marked with `#[test]` and the assembly from which it can be invoked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestFunction {
+    /// The full name of the function (e.g. `foo::bar`)
+    pub name: String,
+
+    /// The path of the assembly that contains the function
+    pub assembly_path: PathBuf,
+}
+
 pub struct Driver {
     db: CompilerDatabase,
     out_dir: PathBuf,
@@ -40,6 +53,7 @@ pub struct Driver {
     module_to_temp_assembly_path: HashMap<Module, PathBuf>,
 
     emit_ir: bool,
+    message_format: MessageFormat,
 }
 
 impl Driver {
@@ -54,6 +68,7 @@ impl Driver {
             next_file_id: 0,
             module_to_temp_assembly_path: Default::default(),
             emit_ir: config.emit_ir,
+            message_format: config.message_format,
         }
     }
 
@@ -220,14 +235,19 @@ impl Driver {
 
                     // Emit all syntax diagnostics
                     for syntax_error in parse.errors().iter() {
-                        emit_syntax_error(
-                            syntax_error,
-                            relative_file_path.as_str(),
-                            &source_code,
-                            &line_index,
-                            emit_colors,
-                            writer,
-                        )?;
+                        match self.message_format {
+                            MessageFormat::Human => emit_syntax_error(
+                                syntax_error,
+                                relative_file_path.as_str(),
+                                &source_code,
+                                &line_index,
+                                emit_colors,
+                                writer,
+                            )?,
+                            MessageFormat::Json => {
+                                emit_syntax_error_json(syntax_error, &self.db, file_id, writer)?
+                            }
+                        }
                         has_error = true;
                     }
 
@@ -237,9 +257,15 @@ impl Driver {
                         self.db.upcast(),
                         &mut DiagnosticSink::new(|d| {
                             has_error = true;
-                            if let Err(e) =
-                                emit_hir_diagnostic(d, &self.db, file_id, emit_colors, writer)
-                            {
+                            let result = match self.message_format {
+                                MessageFormat::Human => {
+                                    emit_hir_diagnostic(d, &self.db, file_id, emit_colors, writer)
+                                }
+                                MessageFormat::Json => {
+                                    emit_hir_diagnostic_json(d, &self.db, file_id, writer)
+                                }
+                            };
+                            if let Err(e) = result {
                                 error = Some(e)
                             };
                         }),
@@ -276,6 +302,29 @@ impl Driver {
 }
 
 impl Driver {
+    /// Returns all functions marked with `#[test]`, sorted by name. The test functions can only be
+    /// invoked from the assemblies if the driver was configured with [`Config::export_tests`].
+    pub fn test_functions(&self) -> Vec<TestFunction> {
+        let mut tests = Vec::new();
+        for package in mun_hir::Package::all(self.db.upcast()) {
+            for module in package.modules(self.db.upcast()) {
+                for def in module.declarations(self.db.upcast()) {
+                    match def {
+                        ModuleDef::Function(function) if function.is_test(self.db.upcast()) => {
+                            tests.push(TestFunction {
+                                name: function.full_name(self.db.upcast()),
+                                assembly_path: self.assembly_output_path(module),
+                            })
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+        tests.sort_by(|a, b| a.name.cmp(&b.name));
+        tests
+    }
+
     /// Get the path where the driver will write the assembly for the specified file.
     pub fn assembly_output_path_from_file(&self, file_id: FileId) -> PathBuf {
         let module_partition = self.db.module_partition();
@@ -306,6 +355,14 @@ impl Driver {
             .with_extension(TargetAssembly::EXTENSION)
     }
 
+    /// Get the path where the driver will write the assembly for the root module of the package.
+    pub fn root_assembly_output_path(&self) -> Option<PathBuf> {
+        mun_hir::Package::all(self.db.upcast())
+            .into_iter()
+            .next()
+            .map(|package| self.assembly_output_path(package.root_module(self.db.upcast())))
+    }
+
     /// Get the path where the driver will write the IR for the specified module.
     pub fn ir_output_path(&self, module: Module) -> PathBuf {
         let module_partition = self.db.module_partition();
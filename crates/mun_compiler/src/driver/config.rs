@@ -1,3 +1,4 @@
+pub use super::message_format::MessageFormat;
 pub use mun_codegen::OptimizationLevel;
 use mun_target::spec::Target;
 use std::path::PathBuf;
@@ -17,6 +18,17 @@ pub struct Config {
 
     /// Whether or not to emit an IR file instead of a munlib.
     pub emit_ir: bool,
+
+    /// Whether integer arithmetic is checked for overflow. If enabled, an overflowing operation
+    /// panics instead of wrapping around.
+    pub overflow_checks: bool,
+
+    /// Whether functions marked with `#[test]` are exported from the generated assemblies so
+    /// they can be invoked by a test runner.
+    pub export_tests: bool,
+
+    /// The format in which diagnostics are emitted.
+    pub message_format: MessageFormat,
 }
 
 impl Default for Config {
@@ -29,6 +41,9 @@ impl Default for Config {
             optimization_lvl: OptimizationLevel::Default,
             out_dir: None,
             emit_ir: false,
+            overflow_checks: false,
+            export_tests: false,
+            message_format: MessageFormat::Human,
         }
     }
 }
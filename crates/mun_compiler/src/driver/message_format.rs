@@ -0,0 +1,10 @@
+/// The format in which the driver emits diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// Human-readable snippets of the source code
+    #[default]
+    Human,
+
+    /// A JSON object per diagnostic, each on a separate line
+    Json,
+}
@@ -3,6 +3,7 @@
 mod db;
 ///! This library contains the code required to go from source code to binaries.
 pub mod diagnostics;
+mod diagnostics_json;
 mod diagnostics_snippets;
 mod driver;
 
@@ -11,8 +12,8 @@ pub use mun_paths::{RelativePath, RelativePathBuf};
 pub use mun_target::spec::Target;
 use std::path::{Path, PathBuf};
 
-pub use crate::driver::DisplayColor;
-pub use crate::driver::{Config, Driver};
+pub use crate::driver::{Config, Driver, TestFunction};
+pub use crate::driver::{DisplayColor, MessageFormat};
 pub use mun_codegen::OptimizationLevel;
 
 pub use crate::db::CompilerDatabase;
@@ -102,6 +103,17 @@ pub fn compile_manifest(
     Ok(true)
 }
 
+/// Emits the diagnostics of all modules of the package without generating any code. Returns true
+/// if no errors were emitted.
+pub fn check_manifest(
+    manifest_path: &Path,
+    config: Config,
+    emit_colors: DisplayColor,
+) -> Result<bool, anyhow::Error> {
+    let (_package, driver) = Driver::with_package_path(manifest_path, config)?;
+    Ok(!driver.emit_diagnostics(&mut stderr(), emit_colors)?)
+}
+
 /// Determines the relative path of a file to the source directory.
 pub fn compute_source_relative_path(
     source_dir: &Path,
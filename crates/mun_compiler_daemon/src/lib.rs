@@ -1,11 +1,11 @@
-use std::sync::mpsc::channel;
+use std::sync::mpsc::{channel, Receiver};
 use std::time::Duration;
 
 use mun_compiler::{compute_source_relative_path, is_source_file, Config, DisplayColor, Driver};
-use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
 
 use std::io::stderr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Compiles and watches the package at the specified path. Recompiles changes that occur.
@@ -14,82 +14,153 @@ pub fn compile_and_watch_manifest(
     config: Config,
     display_color: DisplayColor,
 ) -> Result<bool, anyhow::Error> {
-    // Create the compiler driver
-    let (package, mut driver) = Driver::with_package_path(manifest_path, config)?;
+    let mut daemon = Daemon::new(manifest_path, config, display_color)?;
+    println!("Watching: {}", daemon.source_directory().display());
 
-    // Start watching the source directory
-    let (watcher_tx, watcher_rx) = channel();
-    let mut watcher: RecommendedWatcher = Watcher::new(watcher_tx, Duration::from_millis(10))?;
-    let source_directory = package.source_directory();
+    // Emit all current errors, and write the assemblies if no errors occured
+    daemon.compile()?;
 
-    watcher.watch(&source_directory, RecursiveMode::Recursive)?;
-    println!("Watching: {}", source_directory.display());
+    daemon.watch(|_| Ok(()))?;
 
-    // Emit all current errors, and write the assemblies if no errors occured
-    if !driver.emit_diagnostics(&mut stderr(), display_color)? {
-        driver.write_all_assemblies(false)?
+    Ok(true)
+}
+
+/// Watches the source directory of a package and recompiles the package whenever one of its
+/// source files changes.
+pub struct Daemon {
+    driver: Driver,
+    display_color: DisplayColor,
+    source_directory: PathBuf,
+
+    // The watcher has to be kept alive for as long as we receive events
+    _watcher: RecommendedWatcher,
+    watcher_rx: Receiver<DebouncedEvent>,
+}
+
+impl Daemon {
+    /// Loads the package at the specified path and starts watching its source directory. The
+    /// package is not compiled until [`Daemon::compile`] or [`Daemon::poll`] is called.
+    pub fn new(
+        manifest_path: &Path,
+        config: Config,
+        display_color: DisplayColor,
+    ) -> Result<Self, anyhow::Error> {
+        // Create the compiler driver
+        let (package, driver) = Driver::with_package_path(manifest_path, config)?;
+
+        // Start watching the source directory
+        let (watcher_tx, watcher_rx) = channel();
+        let mut watcher: RecommendedWatcher = Watcher::new(watcher_tx, Duration::from_millis(10))?;
+        let source_directory = package.source_directory();
+        watcher.watch(&source_directory, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            driver,
+            display_color,
+            source_directory,
+            _watcher: watcher,
+            watcher_rx,
+        })
+    }
+
+    /// Returns the compiler driver that contains the state of the package
+    pub fn driver(&self) -> &Driver {
+        &self.driver
+    }
+
+    /// Returns the directory that is watched for changes
+    pub fn source_directory(&self) -> &Path {
+        &self.source_directory
     }
 
-    // Insert Ctrl+C handler so we can gracefully quit
-    let should_quit = Arc::new(std::sync::atomic::AtomicBool::new(false));
-    let r = should_quit.clone();
-    ctrlc::set_handler(move || {
-        r.store(true, std::sync::atomic::Ordering::SeqCst);
-    })
-    .expect("error setting ctrl-c handler");
-
-    // Start watching filesystem events.
-    while !should_quit.load(std::sync::atomic::Ordering::SeqCst) {
-        if let Ok(event) = watcher_rx.recv_timeout(Duration::from_millis(1)) {
-            use notify::DebouncedEvent::*;
-            match event {
-                Write(ref path) if is_source_file(path) => {
-                    let relative_path = compute_source_relative_path(&source_directory, path)?;
-                    let file_contents = std::fs::read_to_string(path)?;
-                    log::info!("Modifying {}", relative_path);
-                    driver.update_file(relative_path, file_contents);
-                    if !driver.emit_diagnostics(&mut stderr(), display_color)? {
-                        driver.write_all_assemblies(false)?;
-                    }
-                }
-                Create(ref path) if is_source_file(path) => {
-                    let relative_path = compute_source_relative_path(&source_directory, path)?;
-                    let file_contents = std::fs::read_to_string(path)?;
-                    log::info!("Creating {}", relative_path);
-                    driver.add_file(relative_path, file_contents);
-                    if !driver.emit_diagnostics(&mut stderr(), display_color)? {
-                        driver.write_all_assemblies(false)?;
-                    }
-                }
-                Remove(ref path) if is_source_file(path) => {
-                    // Simply remove the source file from the source root
-                    let relative_path = compute_source_relative_path(&source_directory, path)?;
-                    log::info!("Removing {}", relative_path);
-                    // TODO: Remove assembly files if there are no files referencing it.
-                    // let assembly_path = driver.assembly_output_path(driver.get_file_id_for_path(&relative_path).expect("cannot remove a file that was not part of the compilation in the first place"));
-                    // if assembly_path.is_file() {
-                    //     std::fs::remove_file(assembly_path)?;
-                    // }
-                    driver.remove_file(relative_path);
-                    driver.emit_diagnostics(&mut stderr(), display_color)?;
-                }
-                Rename(ref from, ref to) => {
-                    // Renaming is done by changing the relative path of the original source file but
-                    // not modifying any text. This ensures that most of the cache for the renamed file
-                    // stays alive. This is effectively a rename of the file_id in the database.
-                    let from_relative_path = compute_source_relative_path(&source_directory, from)?;
-                    let to_relative_path = compute_source_relative_path(&source_directory, to)?;
-
-                    log::info!("Renaming {} to {}", from_relative_path, to_relative_path,);
-                    driver.rename(from_relative_path, to_relative_path);
-                    if !driver.emit_diagnostics(&mut stderr(), display_color)? {
-                        driver.write_all_assemblies(false)?;
-                    }
-                }
-                _ => {}
+    /// Emits all current diagnostics and writes the assemblies if no errors occurred. Returns true
+    /// if the assemblies were written.
+    pub fn compile(&mut self) -> Result<bool, anyhow::Error> {
+        if self
+            .driver
+            .emit_diagnostics(&mut stderr(), self.display_color)?
+        {
+            return Ok(false);
+        }
+
+        self.driver.write_all_assemblies(false)?;
+        Ok(true)
+    }
+
+    /// Waits at most `timeout` for a change in the source directory and recompiles the package if
+    /// a source file changed. Returns true if the assemblies were written.
+    pub fn poll(&mut self, timeout: Duration) -> Result<bool, anyhow::Error> {
+        let event = match self.watcher_rx.recv_timeout(timeout) {
+            Ok(event) => event,
+            Err(_) => return Ok(false),
+        };
+
+        use notify::DebouncedEvent::*;
+        match event {
+            Write(ref path) if is_source_file(path) => {
+                let relative_path = compute_source_relative_path(&self.source_directory, path)?;
+                let file_contents = std::fs::read_to_string(path)?;
+                log::info!("Modifying {}", relative_path);
+                self.driver.update_file(relative_path, file_contents);
+                self.compile()
+            }
+            Create(ref path) if is_source_file(path) => {
+                let relative_path = compute_source_relative_path(&self.source_directory, path)?;
+                let file_contents = std::fs::read_to_string(path)?;
+                log::info!("Creating {}", relative_path);
+                self.driver.add_file(relative_path, file_contents);
+                self.compile()
+            }
+            Remove(ref path) if is_source_file(path) => {
+                // Simply remove the source file from the source root
+                let relative_path = compute_source_relative_path(&self.source_directory, path)?;
+                log::info!("Removing {}", relative_path);
+                // TODO: Remove assembly files if there are no files referencing it.
+                // let assembly_path = driver.assembly_output_path(driver.get_file_id_for_path(&relative_path).expect("cannot remove a file that was not part of the compilation in the first place"));
+                // if assembly_path.is_file() {
+                //     std::fs::remove_file(assembly_path)?;
+                // }
+                self.driver.remove_file(relative_path);
+                self.driver
+                    .emit_diagnostics(&mut stderr(), self.display_color)?;
+                Ok(false)
             }
+            Rename(ref from, ref to) => {
+                // Renaming is done by changing the relative path of the original source file but
+                // not modifying any text. This ensures that most of the cache for the renamed file
+                // stays alive. This is effectively a rename of the file_id in the database.
+                let from_relative_path =
+                    compute_source_relative_path(&self.source_directory, from)?;
+                let to_relative_path = compute_source_relative_path(&self.source_directory, to)?;
+
+                log::info!("Renaming {} to {}", from_relative_path, to_relative_path,);
+                self.driver.rename(from_relative_path, to_relative_path);
+                self.compile()
+            }
+            _ => Ok(false),
         }
     }
 
-    Ok(true)
+    /// Recompiles the package on every change until the process receives Ctrl+C. After every
+    /// poll, `on_poll` is called with whether the assemblies were written.
+    pub fn watch(
+        &mut self,
+        mut on_poll: impl FnMut(bool) -> Result<(), anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        // Insert Ctrl+C handler so we can gracefully quit
+        let should_quit = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let r = should_quit.clone();
+        ctrlc::set_handler(move || {
+            r.store(true, std::sync::atomic::Ordering::SeqCst);
+        })
+        .expect("error setting ctrl-c handler");
+
+        // Start watching filesystem events.
+        while !should_quit.load(std::sync::atomic::Ordering::SeqCst) {
+            let written = self.poll(Duration::from_millis(1))?;
+            on_poll(written)?;
+        }
+
+        Ok(())
+    }
 }
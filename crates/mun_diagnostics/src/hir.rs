@@ -3,9 +3,11 @@ mod access_unknown_field;
 mod duplicate_definition_error;
 mod expected_function;
 mod exported_private;
+mod invalid_cast;
 mod mismatched_type;
 mod missing_fields;
 mod possibly_unitialized_variable;
+mod unknown_attribute;
 mod unresolved_type;
 mod unresolved_value;
 
@@ -25,6 +27,8 @@ impl<DB: mun_hir::HirDatabase> DiagnosticForWith<DB> for dyn mun_hir::Diagnostic
             f(&expected_function::ExpectedFunction::new(with, v))
         } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::MismatchedType>() {
             f(&mismatched_type::MismatchedType::new(with, v))
+        } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::InvalidCast>() {
+            f(&invalid_cast::InvalidCast::new(with, v))
         } else if let Some(v) =
             self.downcast_ref::<mun_hir::diagnostics::PossiblyUninitializedVariable>()
         {
@@ -39,6 +43,8 @@ impl<DB: mun_hir::HirDatabase> DiagnosticForWith<DB> for dyn mun_hir::Diagnostic
             f(&missing_fields::MissingFields::new(with, v))
         } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::ExportedPrivate>() {
             f(&exported_private::ExportedPrivate::new(with, v))
+        } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::UnknownAttribute>() {
+            f(&unknown_attribute::UnknownAttribute::new(with, v))
         } else {
             f(&GenericHirDiagnostic { diagnostic: self })
         }
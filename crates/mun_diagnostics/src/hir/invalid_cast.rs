@@ -0,0 +1,41 @@
+use super::HirDiagnostic;
+use crate::{Diagnostic, SourceAnnotation};
+use mun_hir::HirDisplay;
+use mun_syntax::TextRange;
+
+/// An error that is emitted when a value is cast to a type it cannot be converted to.
+///
+/// ```mun
+/// # fn main() {
+///     let a = 3.0 as bool; // invalid cast, casting `f64` as `bool` is invalid.
+/// # }
+/// ```
+pub struct InvalidCast<'db, 'diag, DB: mun_hir::HirDatabase> {
+    db: &'db DB,
+    diag: &'diag mun_hir::diagnostics::InvalidCast,
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> Diagnostic for InvalidCast<'db, 'diag, DB> {
+    fn range(&self) -> TextRange {
+        self.diag.highlight_range()
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "casting `{}` as `{}` is invalid",
+            self.diag.expr_ty.display(self.db),
+            self.diag.cast_ty.display(self.db)
+        )
+    }
+
+    fn primary_annotation(&self) -> Option<SourceAnnotation> {
+        None
+    }
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> InvalidCast<'db, 'diag, DB> {
+    /// Constructs a new instance of `InvalidCast`
+    pub fn new(db: &'db DB, diag: &'diag mun_hir::diagnostics::InvalidCast) -> Self {
+        InvalidCast { db, diag }
+    }
+}
@@ -0,0 +1,66 @@
+use super::HirDiagnostic;
+use crate::{Diagnostic, Fix, SourceAnnotation, SourceEdit};
+use mun_hir::InFile;
+use mun_syntax::{AstNode, SyntaxKind, TextRange};
+
+/// An error that is emitted when an item is annotated with an attribute that does not exist.
+///
+/// ```mun
+/// #[unknown] // cannot find attribute `unknown`
+/// fn main() {}
+/// ```
+pub struct UnknownAttribute<'db, 'diag, DB: mun_hir::HirDatabase> {
+    _db: &'db DB,
+    diag: &'diag mun_hir::diagnostics::UnknownAttribute,
+    removal_range: TextRange,
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> Diagnostic for UnknownAttribute<'db, 'diag, DB> {
+    fn range(&self) -> TextRange {
+        self.diag.highlight_range()
+    }
+
+    fn title(&self) -> String {
+        self.diag.message()
+    }
+
+    fn primary_annotation(&self) -> Option<SourceAnnotation> {
+        Some(SourceAnnotation {
+            range: self.diag.highlight_range(),
+            message: "unknown attribute".to_owned(),
+        })
+    }
+
+    fn fixes(&self) -> Vec<Fix> {
+        vec![Fix {
+            message: format!("remove the `{}` attribute", self.diag.name),
+            edits: vec![SourceEdit {
+                range: InFile::new(self.diag.attr.file_id, self.removal_range),
+                replacement: String::new(),
+            }],
+        }]
+    }
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> UnknownAttribute<'db, 'diag, DB> {
+    /// Constructs a new instance of `UnknownAttribute`
+    pub fn new(db: &'db DB, diag: &'diag mun_hir::diagnostics::UnknownAttribute) -> Self {
+        let parse = db.parse(diag.attr.file_id);
+
+        // Removing the attribute also removes the whitespace that separates it from the item
+        let attr = diag.attr.value.to_node(&parse.syntax_node());
+        let attr_range = attr.syntax().text_range();
+        let removal_range = match attr.syntax().next_sibling_or_token() {
+            Some(next) if next.kind() == SyntaxKind::WHITESPACE => {
+                TextRange::new(attr_range.start(), next.text_range().end())
+            }
+            _ => attr_range,
+        };
+
+        UnknownAttribute {
+            _db: db,
+            diag,
+            removal_range,
+        }
+    }
+}
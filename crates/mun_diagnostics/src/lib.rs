@@ -30,6 +30,26 @@ pub struct SecondaryAnnotation {
     pub message: String,
 }
 
+/// A change to the source code that replaces the text within a range
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SourceEdit {
+    /// The location in the source that is replaced
+    pub range: InFile<TextRange>,
+
+    /// The text that replaces the text within `range`
+    pub replacement: String,
+}
+
+/// A change to the source code that is suggested to resolve a diagnostic
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fix {
+    /// A description of the change
+    pub message: String,
+
+    /// The edits that make up the change
+    pub edits: Vec<SourceEdit>,
+}
+
 /// The base trait for all diagnostics in this crate.
 pub trait Diagnostic {
     /// Returns the primary message of the diagnostic.
@@ -50,6 +70,11 @@ pub trait Diagnostic {
     fn footer(&self) -> Vec<String> {
         Vec::new()
     }
+
+    /// Returns changes to the source code that are suggested to resolve this diagnostic.
+    fn fixes(&self) -> Vec<Fix> {
+        Vec::new()
+    }
 }
 
 /// When implemented enables requesting `Diagnostic`s for the implementer.
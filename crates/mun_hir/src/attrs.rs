@@ -0,0 +1,109 @@
+//! Attributes (e.g. `#[test]`) attached to items, fields and enum variants.
+
+use crate::{name::known, Name, Path};
+use mun_syntax::{ast, AstNode, SmolStr};
+
+/// The names of the attributes known to the compiler. Any other attribute results in a
+/// diagnostic.
+const BUILTIN_ATTRIBUTES: &[Name] = &[known::test, known::inline, known::deprecated];
+
+/// A single attribute, e.g. `#[test]`, `#[inline(always)]` or
+/// `#[deprecated = "use `bar` instead"]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attr {
+    pub path: Path,
+    pub input: Option<AttrInput>,
+}
+
+/// The input of an attribute that follows its path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttrInput {
+    /// `#[attr = "value"]`; string literals are stored without quotes and escapes
+    Literal(SmolStr),
+    /// `#[attr(tokens)]`; the text of the token tree including its delimiters
+    TokenTree(SmolStr),
+}
+
+impl Attr {
+    /// Constructs an `Attr` from an AST node. Returns `None` if the attribute has no valid path.
+    pub(crate) fn from_ast(attr: &ast::Attr) -> Option<Attr> {
+        let path = Path::from_ast(attr.path()?)?;
+        let input = if let Some(literal) = attr.literal() {
+            let value = match literal.kind() {
+                ast::LiteralKind::String(string) => string.value()?,
+                _ => literal.syntax().text().to_string(),
+            };
+            Some(AttrInput::Literal(value.into()))
+        } else {
+            attr.token_tree()
+                .map(|tt| AttrInput::TokenTree(tt.syntax().text().to_string().into()))
+        };
+        Some(Attr { path, input })
+    }
+
+    /// Returns the name of this attribute if its path consists of a single identifier.
+    pub fn name(&self) -> Option<&Name> {
+        self.path.as_ident()
+    }
+
+    /// Returns the value of a `#[attr = "value"]` attribute.
+    pub fn value(&self) -> Option<&SmolStr> {
+        match &self.input {
+            Some(AttrInput::Literal(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this attribute is known to the compiler.
+    pub fn is_builtin(&self) -> bool {
+        self.name()
+            .map(|name| BUILTIN_ATTRIBUTES.contains(name))
+            .unwrap_or(false)
+    }
+}
+
+/// The attributes attached to an item, field or enum variant.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Attrs {
+    entries: Option<Box<[Attr]>>,
+}
+
+impl Attrs {
+    pub const EMPTY: Attrs = Attrs { entries: None };
+
+    /// Collects the attributes of an AST node. Malformed attributes are skipped.
+    pub(crate) fn from_ast(owner: &impl ast::AttrsOwner) -> Attrs {
+        let entries: Box<[Attr]> = owner
+            .attrs()
+            .filter_map(|attr| Attr::from_ast(&attr))
+            .collect();
+        Attrs {
+            entries: if entries.is_empty() {
+                None
+            } else {
+                Some(entries)
+            },
+        }
+    }
+
+    /// Returns an iterator over all attributes.
+    pub fn iter(&self) -> impl Iterator<Item = &Attr> {
+        self.entries.iter().flat_map(|entries| entries.iter())
+    }
+
+    /// Returns true if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_none()
+    }
+
+    /// Returns the first attribute with the specified name.
+    pub fn by_name(&self, name: &Name) -> Option<&Attr> {
+        self.iter().find(|attr| attr.name() == Some(name))
+    }
+
+    /// Returns true if an attribute with the specified name is present, e.g. `test` for
+    /// `#[test]`.
+    pub fn has(&self, name: &Name) -> bool {
+        self.by_name(name).is_some()
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    ids::ItemDefinitionId,
+    name::{name, Name},
+    PerNs, Visibility,
+};
+use std::fmt;
+
+/// A module that is built into the language and that can be used from every package without
+/// declaring it, e.g. `core::math`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinModule {
+    /// The `core` module
+    Core,
+    /// The `core::math` module
+    Math,
+}
+
+impl BuiltinModule {
+    /// Returns the name of the module
+    pub fn name(self) -> Name {
+        match self {
+            BuiltinModule::Core => name![core],
+            BuiltinModule::Math => name![math],
+        }
+    }
+
+    /// Returns all the items that are defined in the module
+    pub(crate) fn entries(self) -> Vec<(Name, PerNs<(ItemDefinitionId, Visibility)>)> {
+        match self {
+            BuiltinModule::Core => vec![(
+                name![math],
+                PerNs::types((BuiltinModule::Math.into(), Visibility::Public)),
+            )],
+            BuiltinModule::Math => IntrinsicFunction::ALL
+                .iter()
+                .filter(|(_, function)| function.module() == Some(BuiltinModule::Math))
+                .map(|(name, function)| {
+                    (
+                        name.clone(),
+                        PerNs::values(((*function).into(), Visibility::Public)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the item with the specified `name` that is defined in the module
+    pub(crate) fn get(self, name: &Name) -> PerNs<(ItemDefinitionId, Visibility)> {
+        self.entries()
+            .into_iter()
+            .find_map(|(entry_name, def)| (entry_name == *name).then_some(def))
+            .unwrap_or_else(PerNs::none)
+    }
+}
+
+impl fmt::Display for BuiltinModule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuiltinModule::Core => f.write_str("core"),
+            BuiltinModule::Math => f.write_str("core::math"),
+        }
+    }
+}
+
+/// A function that is built into the language. The functions of the `core::math` module are
+/// lowered directly to LLVM intrinsics; all their arguments and their return value have the same
+/// type. `panic` is available in every module and raises a panic with the specified message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntrinsicFunction {
+    Sqrt,
+    Sin,
+    Cos,
+    Abs,
+    Min,
+    Max,
+    Floor,
+    Pow,
+    Panic,
+}
+
+impl IntrinsicFunction {
+    pub const ALL: &'static [(Name, IntrinsicFunction)] = &[
+        (name![sqrt], IntrinsicFunction::Sqrt),
+        (name![sin], IntrinsicFunction::Sin),
+        (name![cos], IntrinsicFunction::Cos),
+        (name![abs], IntrinsicFunction::Abs),
+        (name![min], IntrinsicFunction::Min),
+        (name![max], IntrinsicFunction::Max),
+        (name![floor], IntrinsicFunction::Floor),
+        (name![pow], IntrinsicFunction::Pow),
+        (name![panic], IntrinsicFunction::Panic),
+    ];
+
+    /// Returns the name of the function
+    pub fn name(self) -> Name {
+        IntrinsicFunction::ALL
+            .iter()
+            .find_map(|(name, function)| (*function == self).then(|| name.clone()))
+            .expect("every intrinsic function must have a name")
+    }
+
+    /// Returns the built-in module in which the function is defined, or `None` if the function is
+    /// available in every module without a path.
+    pub fn module(self) -> Option<BuiltinModule> {
+        match self {
+            IntrinsicFunction::Panic => None,
+            _ => Some(BuiltinModule::Math),
+        }
+    }
+
+    /// Returns the path through which the function is referred to, e.g. `core::math::sqrt`
+    pub fn path(self) -> String {
+        match self.module() {
+            Some(module) => format!("{}::{}", module, self.name()),
+            None => self.name().to_string(),
+        }
+    }
+
+    /// Returns the number of parameters of the function
+    pub fn param_count(self) -> usize {
+        match self {
+            IntrinsicFunction::Min | IntrinsicFunction::Max | IntrinsicFunction::Pow => 2,
+            IntrinsicFunction::Sqrt
+            | IntrinsicFunction::Sin
+            | IntrinsicFunction::Cos
+            | IntrinsicFunction::Abs
+            | IntrinsicFunction::Floor
+            | IntrinsicFunction::Panic => 1,
+        }
+    }
+
+    /// Returns true if the function can be applied to integers. All intrinsic functions can be
+    /// applied to floating-point numbers.
+    pub fn supports_integers(self) -> bool {
+        matches!(
+            self,
+            IntrinsicFunction::Abs | IntrinsicFunction::Min | IntrinsicFunction::Max
+        )
+    }
+}
+
+impl fmt::Display for IntrinsicFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -1,25 +1,40 @@
+mod r#const;
+pub(crate) mod r#enum;
 mod function;
+mod r#impl;
 mod module;
 mod package;
 pub(crate) mod src;
+mod r#static;
 pub(crate) mod r#struct;
+mod r#trait;
 mod type_alias;
 
-use crate::{expr::BodySourceMap, HirDatabase, Name};
+use crate::{expr::BodySourceMap, FileId, HirDatabase, Name};
 use std::sync::Arc;
 
 pub use self::{
     function::Function,
     module::{Module, ModuleDef},
     package::Package,
+    r#const::Const,
+    r#enum::{Enum, EnumVariant, VariantField},
+    r#impl::Impl,
+    r#static::Static,
     r#struct::{Field, LocalFieldId, Struct, StructKind, StructMemoryKind},
+    r#trait::Trait,
     src::HasSource,
     type_alias::TypeAlias,
 };
 
 pub use self::{
     function::FunctionData,
+    r#const::ConstData,
+    r#enum::EnumData,
+    r#impl::ImplData,
+    r#static::StaticData,
     r#struct::{FieldData, StructData},
+    r#trait::TraitData,
     type_alias::TypeAliasData,
 };
 
@@ -27,19 +42,33 @@ pub use self::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DefWithBody {
     Function(Function),
+    Const(Const),
+    Static(Static),
 }
-impl_froms!(DefWithBody: Function);
+impl_froms!(DefWithBody: Function, Const, Static);
 
 impl DefWithBody {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
         match self {
             DefWithBody::Function(f) => f.module(db),
+            DefWithBody::Const(c) => c.module(db),
+            DefWithBody::Static(s) => s.module(db),
+        }
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        match self {
+            DefWithBody::Function(f) => f.file_id(db),
+            DefWithBody::Const(c) => c.file_id(db),
+            DefWithBody::Static(s) => s.file_id(db),
         }
     }
 
     pub fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
         match self {
             DefWithBody::Function(f) => f.body_source_map(db),
+            DefWithBody::Const(c) => c.body_source_map(db),
+            DefWithBody::Static(s) => s.body_source_map(db),
         }
     }
 }
@@ -0,0 +1,293 @@
+use super::{r#struct::FieldData, LocalFieldId, Module, StructKind};
+use crate::{
+    arena::{Arena, Idx},
+    ids::{EnumId, EnumVariantId, Lookup},
+    name::AsName,
+    name_resolution::Namespace,
+    resolve::HasResolver,
+    ty::lower::LowerTyMap,
+    type_ref::{TypeRefMap, TypeRefMapBuilder, TypeRefSourceMap},
+    visibility::RawVisibility,
+    DefDatabase, DiagnosticSink, FileId, HasVisibility, HirDatabase, Name, Ty, Visibility,
+};
+use mun_syntax::ast::{self, NameOwner, TypeAscriptionOwner};
+use std::{iter::once, sync::Arc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Enum {
+    pub(crate) id: EnumId,
+}
+
+impl From<EnumId> for Enum {
+    fn from(id: EnumId) -> Self {
+        Enum { id }
+    }
+}
+
+impl Enum {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        Module {
+            id: self.id.lookup(db.upcast()).module,
+        }
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        self.id.lookup(db.upcast()).id.file_id
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<EnumData> {
+        db.enum_data(self.id)
+    }
+
+    /// Returns the name of the enum non including any module specifiers (e.g: `Bar`).
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.data(db.upcast()).name.clone()
+    }
+
+    /// Returns the full name of the enum including all module specifiers (e.g: `foo::Bar`).
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        itertools::Itertools::intersperse(
+            self.module(db)
+                .path_to_root(db)
+                .into_iter()
+                .filter_map(|module| module.name(db))
+                .chain(once(self.name(db)))
+                .map(|name| name.to_string()),
+            String::from("::"),
+        )
+        .collect()
+    }
+
+    /// Returns all the variants of the enum in declaration order.
+    pub fn variants(self, db: &dyn HirDatabase) -> Vec<EnumVariant> {
+        self.data(db.upcast())
+            .variants
+            .iter()
+            .map(|(id, _)| EnumVariant { parent: self, id })
+            .collect()
+    }
+
+    /// Returns the variant with the specified `name`, if it exists.
+    pub fn variant(self, db: &dyn HirDatabase, name: &Name) -> Option<EnumVariant> {
+        self.data(db.upcast())
+            .variant(name)
+            .map(|id| EnumVariant { parent: self, id })
+    }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        db.type_for_def(self.into(), Namespace::Types).0
+    }
+
+    pub fn lower(self, db: &dyn HirDatabase) -> Arc<LowerTyMap> {
+        db.lower_enum(self)
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let data = self.data(db.upcast());
+        let lower = self.lower(db);
+        lower.add_diagnostics(db, self.file_id(db), data.type_ref_source_map(), sink);
+    }
+}
+
+impl HasVisibility for Enum {
+    fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
+        self.data(db.upcast())
+            .visibility
+            .resolve(db.upcast(), &self.id.resolver(db.upcast()))
+    }
+}
+
+/// A single variant of an [`Enum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    pub(crate) parent: Enum,
+    pub(crate) id: LocalEnumVariantId,
+}
+
+impl From<EnumVariantId> for EnumVariant {
+    fn from(id: EnumVariantId) -> Self {
+        EnumVariant {
+            parent: id.parent.into(),
+            id: id.local_id,
+        }
+    }
+}
+
+impl EnumVariant {
+    /// Returns the enum that contains this variant
+    pub fn parent_enum(self) -> Enum {
+        self.parent
+    }
+
+    /// Returns the name of the variant
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.parent.data(db.upcast()).variants[self.id].name.clone()
+    }
+
+    /// Returns the index of the variant in the parent enum. This is also the value of the variant's
+    /// discriminant.
+    pub fn index(self) -> u32 {
+        self.id.into_raw().into()
+    }
+
+    /// Returns the kind of fields of the variant (record, tuple, or unit)
+    pub fn kind(self, db: &dyn HirDatabase) -> StructKind {
+        self.parent.data(db.upcast()).variants[self.id].kind
+    }
+
+    pub fn fields(self, db: &dyn HirDatabase) -> Vec<VariantField> {
+        self.parent.data(db.upcast()).variants[self.id]
+            .fields
+            .iter()
+            .map(|(id, _)| VariantField { parent: self, id })
+            .collect()
+    }
+
+    pub fn field(self, db: &dyn HirDatabase, name: &Name) -> Option<VariantField> {
+        self.parent.data(db.upcast()).variants[self.id]
+            .fields
+            .iter()
+            .find(|(_, data)| data.name == *name)
+            .map(|(id, _)| VariantField { parent: self, id })
+    }
+
+    pub(crate) fn data(self, db: &dyn DefDatabase) -> Arc<EnumData> {
+        self.parent.data(db)
+    }
+}
+
+/// A field of an [`EnumVariant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantField {
+    pub(crate) parent: EnumVariant,
+    pub(crate) id: LocalFieldId,
+}
+
+impl VariantField {
+    /// Returns the type of the field
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        let data = self.parent.data(db.upcast());
+        let type_ref_id = data.variants[self.parent.id].fields[self.id].type_ref;
+        let lower = self.parent.parent.lower(db);
+        lower[type_ref_id].clone()
+    }
+
+    /// Returns the name of the field
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.parent.data(db.upcast()).variants[self.parent.id].fields[self.id]
+            .name
+            .clone()
+    }
+
+    /// Returns the index of this field in the parent variant
+    pub fn index(self, _db: &dyn HirDatabase) -> u32 {
+        self.id.into_raw().into()
+    }
+}
+
+/// An identifier for an enum's variant
+pub type LocalEnumVariantId = Idx<EnumVariantData>;
+
+/// A single variant of an enum
+/// ```mun
+/// enum Foo {
+///     A(int), // <- this
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumVariantData {
+    pub name: Name,
+    pub fields: Arena<FieldData>,
+    pub kind: StructKind,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumData {
+    pub name: Name,
+    pub visibility: RawVisibility,
+    pub variants: Arena<EnumVariantData>,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl EnumData {
+    pub(crate) fn enum_data_query(db: &dyn DefDatabase, id: EnumId) -> Arc<EnumData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let enum_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id.value);
+
+        let mut type_ref_builder = TypeRefMap::builder();
+        let variants = src
+            .variant_list()
+            .into_iter()
+            .flat_map(|list| list.variants())
+            .filter_map(|variant| {
+                let name = variant.name()?.as_name();
+                let (fields, kind) = lower_variant_fields(&variant.kind(), &mut type_ref_builder);
+                Some(EnumVariantData { name, fields, kind })
+            })
+            .collect();
+
+        let visibility = item_tree[enum_def.visibility].clone();
+
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(EnumData {
+            name: enum_def.name.clone(),
+            visibility,
+            variants,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    /// Returns the id of the variant with the specified `name`, if it exists.
+    pub fn variant(&self, name: &Name) -> Option<LocalEnumVariantId> {
+        self.variants
+            .iter()
+            .find(|(_, data)| data.name == *name)
+            .map(|(id, _)| id)
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
+/// Lowers the fields of an enum variant. Fields of enum variants are always as visible as the enum
+/// itself.
+fn lower_variant_fields(
+    kind: &ast::StructKind,
+    type_ref_builder: &mut TypeRefMapBuilder,
+) -> (Arena<FieldData>, StructKind) {
+    match kind {
+        ast::StructKind::Record(r) => {
+            let fields = r
+                .fields()
+                .map(|fd| FieldData {
+                    name: fd.name().map(|n| n.as_name()).unwrap_or_else(Name::missing),
+                    type_ref: type_ref_builder.alloc_from_node_opt(fd.ascribed_type().as_ref()),
+                    visibility: RawVisibility::Public,
+                })
+                .collect();
+            (fields, StructKind::Record)
+        }
+        ast::StructKind::Tuple(t) => {
+            let fields = t
+                .fields()
+                .enumerate()
+                .map(|(index, fd)| FieldData {
+                    name: Name::new_tuple_field(index),
+                    type_ref: type_ref_builder.alloc_from_node_opt(fd.type_ref().as_ref()),
+                    visibility: RawVisibility::Public,
+                })
+                .collect();
+            (fields, StructKind::Tuple)
+        }
+        ast::StructKind::Unit => (Arena::default(), StructKind::Unit),
+    }
+}
@@ -1,16 +1,18 @@
-use super::Module;
+use super::{Impl, Module, Trait};
+use crate::display::HirDisplay;
 use crate::expr::validator::ExprValidator;
 use crate::expr::BodySourceMap;
-use crate::ids::{FunctionId, Lookup};
+use crate::ids::{FunctionId, ItemContainerId, Lookup};
+use crate::name::{name, AsName};
 use crate::name_resolution::Namespace;
 use crate::resolve::HasResolver;
 use crate::type_ref::{LocalTypeRefId, TypeRefMap, TypeRefSourceMap};
 use crate::visibility::RawVisibility;
 use crate::{
-    Body, DefDatabase, DiagnosticSink, FileId, HasVisibility, HirDatabase, InferenceResult, Name,
-    Ty, Visibility,
+    Attrs, Body, DefDatabase, DiagnosticSink, FileId, HasVisibility, HirDatabase, InferenceResult,
+    Name, Ty, Visibility,
 };
-use mun_syntax::ast::TypeAscriptionOwner;
+use mun_syntax::ast::{self, NameOwner, TypeAscriptionOwner, TypeParamsOwner};
 use std::iter::once;
 use std::sync::Arc;
 
@@ -28,12 +30,15 @@ impl From<FunctionId> for Function {
 #[derive(Debug, PartialEq, Eq)]
 pub struct FunctionData {
     name: Name,
+    type_params: Vec<Name>,
     params: Vec<LocalTypeRefId>,
     visibility: RawVisibility,
     ret_type: LocalTypeRefId,
     type_ref_map: TypeRefMap,
     type_ref_source_map: TypeRefSourceMap,
     is_extern: bool,
+    has_self_param: bool,
+    attrs: Attrs,
 }
 
 impl FunctionData {
@@ -43,10 +48,33 @@ impl FunctionData {
         let func = &item_tree[loc.id.value];
         let src = item_tree.source(db, loc.id.value);
 
+        let type_params = src
+            .type_param_list()
+            .map(|list| {
+                list.type_params()
+                    .map(|param| {
+                        param
+                            .name()
+                            .map(|n| n.as_name())
+                            .unwrap_or_else(Name::missing)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let mut type_ref_builder = TypeRefMap::builder();
 
         let mut params = Vec::new();
+        let mut has_self_param = false;
         if let Some(param_list) = src.param_list() {
+            has_self_param = param_list
+                .params()
+                .next()
+                .and_then(|param| match param.pat()?.kind() {
+                    ast::PatKind::BindPat(pat) => pat.name(),
+                    _ => None,
+                })
+                .map_or(false, |name| name.as_name() == name![SELF_PARAM]);
             for param in param_list.params() {
                 let type_ref = type_ref_builder.alloc_from_node_opt(param.ascribed_type().as_ref());
                 params.push(type_ref);
@@ -63,12 +91,15 @@ impl FunctionData {
 
         Arc::new(FunctionData {
             name: func.name.clone(),
+            type_params,
             params,
             ret_type,
             type_ref_map,
             type_ref_source_map,
             is_extern: func.is_extern,
+            has_self_param,
             visibility: item_tree[func.visibility].clone(),
+            attrs: item_tree.attrs(loc.id.value.into()).clone(),
         })
     }
 
@@ -76,6 +107,11 @@ impl FunctionData {
         &self.name
     }
 
+    /// Returns the names of the type parameters of the function, e.g. `T` in `fn max<T>(..)`.
+    pub fn type_params(&self) -> &[Name] {
+        &self.type_params
+    }
+
     pub fn params(&self) -> &[LocalTypeRefId] {
         &self.params
     }
@@ -95,29 +131,79 @@ impl FunctionData {
     pub fn type_ref_map(&self) -> &TypeRefMap {
         &self.type_ref_map
     }
+
+    /// Returns true if the first parameter of the function is `self`.
+    pub fn has_self_param(&self) -> bool {
+        self.has_self_param
+    }
+
+    /// Returns the attributes of the function, e.g. `#[test]`.
+    pub fn attrs(&self) -> &Attrs {
+        &self.attrs
+    }
 }
 
 impl Function {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
         Module {
-            id: self.id.lookup(db.upcast()).module,
+            id: self.id.lookup(db.upcast()).container.module(db.upcast()),
+        }
+    }
+
+    /// Returns the `impl` block in which this function is defined, if any.
+    pub fn parent_impl(self, db: &dyn HirDatabase) -> Option<Impl> {
+        match self.id.lookup(db.upcast()).container {
+            ItemContainerId::ImplId(id) => Some(id.into()),
+            ItemContainerId::ModuleId(_) | ItemContainerId::TraitId(_) => None,
+        }
+    }
+
+    /// Returns the trait that declares this function, if any. This does not include the functions
+    /// of `impl` blocks that implement a trait.
+    pub fn parent_trait(self, db: &dyn HirDatabase) -> Option<Trait> {
+        match self.id.lookup(db.upcast()).container {
+            ItemContainerId::TraitId(id) => Some(id.into()),
+            ItemContainerId::ModuleId(_) | ItemContainerId::ImplId(_) => None,
         }
     }
 
-    /// Returns the full name of the function including all module specifiers (e.g: `foo::bar`).
+    /// Returns the full name of the function including all module specifiers and the type it is
+    /// associated with (e.g: `foo::Bar::baz`).
     pub fn full_name(self, db: &dyn HirDatabase) -> String {
         itertools::Itertools::intersperse(
             self.module(db)
                 .path_to_root(db)
                 .into_iter()
                 .filter_map(|module| module.name(db))
-                .chain(once(self.name(db)))
-                .map(|name| name.to_string()),
+                .map(|name| name.to_string())
+                .chain(once(self.name_in_module(db))),
             String::from("::"),
         )
         .collect()
     }
 
+    /// Returns the name of the function within its module. For a function that is defined in an
+    /// `impl` block this includes the name of the type it is associated with (e.g: `Bar::baz`) and
+    /// the trait it implements, if any (e.g: `<Bar as Damageable>::damage`).
+    pub fn name_in_module(self, db: &dyn HirDatabase) -> String {
+        if let Some(imp) = self.parent_impl(db) {
+            let self_ty = imp.self_ty(db);
+            match imp.target_trait(db) {
+                Some(target_trait) => format!(
+                    "<{} as {}>::{}",
+                    self_ty.display(db),
+                    target_trait.name(db),
+                    self.name(db)
+                ),
+                None => format!("{}::{}", self_ty.display(db), self.name(db)),
+            }
+        } else if let Some(parent_trait) = self.parent_trait(db) {
+            format!("{}::{}", parent_trait.name(db), self.name(db))
+        } else {
+            self.name(db).to_string()
+        }
+    }
+
     pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
         self.id.lookup(db.upcast()).id.file_id
     }
@@ -153,6 +239,24 @@ impl Function {
         db.fn_data(self.id).is_extern
     }
 
+    /// Returns true if this function declares type parameters, e.g. `fn max<T>(a: T, b: T) -> T`.
+    /// Code is only generated for instantiations of a generic function.
+    pub fn is_generic(self, db: &dyn HirDatabase) -> bool {
+        !db.fn_data(self.id).type_params.is_empty()
+    }
+
+    /// Returns true if this function is a method, i.e. it is defined in an `impl` block or trait
+    /// and its first parameter is `self`.
+    pub fn is_method(self, db: &dyn HirDatabase) -> bool {
+        (self.parent_impl(db).is_some() || self.parent_trait(db).is_some())
+            && db.fn_data(self.id).has_self_param
+    }
+
+    /// Returns true if this function is marked with the `#[test]` attribute.
+    pub fn is_test(self, db: &dyn HirDatabase) -> bool {
+        db.fn_data(self.id).attrs.has(&name![test])
+    }
+
     pub(crate) fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
         db.body_with_source_map(self.id.into()).1
     }
@@ -161,7 +265,7 @@ impl Function {
         let body = self.body(db);
         body.add_diagnostics(db, self.into(), sink);
         let infer = self.infer(db);
-        infer.add_diagnostics(db, self, sink);
+        infer.add_diagnostics(db, self.into(), sink);
         let validator = ExprValidator::new(self, db);
         validator.validate_body(sink);
     }
@@ -169,8 +273,16 @@ impl Function {
 
 impl HasVisibility for Function {
     fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
-        self.data(db.upcast())
-            .visibility
-            .resolve(db.upcast(), &self.id.resolver(db.upcast()))
+        // The functions of a trait, and of its implementations, are as visible as the trait itself
+        let parent_trait = self
+            .parent_trait(db)
+            .or_else(|| self.parent_impl(db)?.target_trait(db));
+        match parent_trait {
+            Some(parent_trait) => parent_trait.visibility(db),
+            None => self
+                .data(db.upcast())
+                .visibility
+                .resolve(db.upcast(), &self.id.resolver(db.upcast())),
+        }
     }
 }
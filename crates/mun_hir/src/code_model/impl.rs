@@ -0,0 +1,278 @@
+use super::{Function, Module, Trait};
+use crate::{
+    diagnostics::{
+        ConflictingTraitImpl, InvalidSelfTyImpl, InvalidSelfTyTraitImpl, MissingTraitFunctions,
+        NotATraitMember, PrivateAccess, TraitFunctionSignatureMismatch, UnresolvedTrait,
+    },
+    ids::{FunctionId, FunctionLoc, ImplId, Intern, ItemContainerId, Lookup},
+    item_tree::ItemTreeId,
+    resolve::{HasResolver, TypeNs},
+    ty::lower::LowerTyMap,
+    type_ref::{LocalTypeRefId, TypeRef, TypeRefMap, TypeRefSourceMap},
+    DefDatabase, DiagnosticSink, FileId, HasSource, HasVisibility, HirDatabase, HirDisplay, InFile,
+    Ty,
+};
+use mun_syntax::AstPtr;
+use std::sync::Arc;
+
+/// An `impl` block that associates functions with a type, e.g.:
+/// ```mun
+/// impl Foo {
+///     fn bar(self: Foo) {}
+/// }
+/// ```
+/// An `impl` block can also implement a trait for a type, e.g.:
+/// ```mun
+/// impl Damageable for Foo {
+///     fn damage(self: Self, amount: f32) {}
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Impl {
+    pub(crate) id: ImplId,
+}
+
+impl From<ImplId> for Impl {
+    fn from(id: ImplId) -> Self {
+        Impl { id }
+    }
+}
+
+impl Impl {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        Module {
+            id: self.id.lookup(db.upcast()).module,
+        }
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        self.id.lookup(db.upcast()).id.file_id
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<ImplData> {
+        db.impl_data(self.id)
+    }
+
+    /// Returns the type for which the functions of this `impl` block are defined.
+    pub fn self_ty(self, db: &dyn HirDatabase) -> Ty {
+        let data = self.data(db.upcast());
+        self.lower(db)[data.self_ty].clone()
+    }
+
+    /// Returns the trait that is implemented by this `impl` block, if any.
+    pub fn target_trait(self, db: &dyn HirDatabase) -> Option<Trait> {
+        let data = self.data(db.upcast());
+        let path = match &data.type_ref_map[data.target_trait?] {
+            TypeRef::Path(path, _) => path,
+            _ => return None,
+        };
+        match self
+            .id
+            .resolver(db.upcast())
+            .resolve_path_as_type_fully(db.upcast(), path)?
+        {
+            (TypeNs::TraitId(id), _) => Some(id.into()),
+            _ => None,
+        }
+    }
+
+    /// Returns all the functions defined in this `impl` block.
+    pub fn functions(self, db: &dyn HirDatabase) -> Vec<Function> {
+        self.data(db.upcast())
+            .functions
+            .iter()
+            .map(|&id| Function { id })
+            .collect()
+    }
+
+    pub fn lower(self, db: &dyn HirDatabase) -> Arc<LowerTyMap> {
+        db.lower_impl(self)
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let data = self.data(db.upcast());
+        let lower = self.lower(db);
+        let file_id = self.file_id(db);
+        lower.add_diagnostics(db, file_id, data.type_ref_source_map(), sink);
+
+        let loc = self.id.lookup(db.upcast());
+        let item_tree = db.item_tree(loc.id.file_id);
+        let src = item_tree.source(db.upcast(), loc.id.value);
+        let impl_ptr = InFile::new(loc.id.file_id, AstPtr::new(&src));
+
+        // `impl` blocks can only be defined for structs of the same package
+        let self_ty = &lower[data.self_ty];
+        let is_valid = match self_ty.as_struct() {
+            Some(s) => s.module(db).id.package == self.module(db).id.package,
+            None => self_ty.is_unknown(),
+        };
+        if !is_valid {
+            if data.target_trait.is_some() {
+                sink.push(InvalidSelfTyTraitImpl { impl_: impl_ptr });
+            } else {
+                sink.push(InvalidSelfTyImpl { impl_: impl_ptr });
+            }
+            return;
+        }
+
+        let target_trait_ref = match data.target_trait {
+            Some(type_ref) => type_ref,
+            None => return,
+        };
+        let target_trait = match self.target_trait(db) {
+            Some(target_trait) => target_trait,
+            None => {
+                if let Some(type_ref) = data.type_ref_source_map().type_ref_syntax(target_trait_ref)
+                {
+                    sink.push(UnresolvedTrait {
+                        file: file_id,
+                        type_ref,
+                    });
+                }
+                return;
+            }
+        };
+        if !target_trait
+            .visibility(db)
+            .is_visible_from(db, self.module(db).id)
+        {
+            if let Some(type_ref) = data.type_ref_source_map().type_ref_syntax(target_trait_ref) {
+                sink.push(PrivateAccess {
+                    file: file_id,
+                    expr: type_ref.syntax_node_ptr(),
+                });
+            }
+        }
+        let trait_name = target_trait.name(db).to_string();
+
+        // Only a single implementation of a trait is allowed per type
+        let s = self_ty.as_struct().expect("self type must be a struct");
+        let first_impl = db
+            .trait_impls_in_package(s.module(db).id.package)
+            .find(target_trait, s);
+        if first_impl != Some(self) {
+            sink.push(ConflictingTraitImpl {
+                impl_: impl_ptr,
+                trait_name,
+                self_ty: self_ty.display(db).to_string(),
+            });
+            return;
+        }
+
+        // Every function of the trait must be implemented with a matching signature
+        let functions = self.functions(db);
+        let missing: Vec<_> = target_trait
+            .functions(db)
+            .into_iter()
+            .map(|function| function.name(db))
+            .filter(|name| !functions.iter().any(|function| function.name(db) == *name))
+            .collect();
+        if !missing.is_empty() {
+            sink.push(MissingTraitFunctions {
+                impl_: impl_ptr,
+                trait_name: trait_name.clone(),
+                functions: missing,
+            });
+        }
+
+        for function in functions {
+            let name = function.name(db);
+            let function_ptr = || {
+                let src = function.source(db.upcast());
+                InFile::new(src.file_id, AstPtr::new(&src.value))
+            };
+            let trait_function = match target_trait.function(db, &name) {
+                Some(trait_function) => trait_function,
+                None => {
+                    sink.push(NotATraitMember {
+                        function: function_ptr(),
+                        name,
+                        trait_name: trait_name.clone(),
+                    });
+                    continue;
+                }
+            };
+
+            // The `self` parameter of the trait function refers to the implementing type
+            let expected = db.callable_sig(trait_function.into());
+            let actual = db.callable_sig(function.into());
+            let params_match = expected.params().len() == actual.params().len()
+                && expected
+                    .params()
+                    .iter()
+                    .zip(actual.params())
+                    .enumerate()
+                    .all(|(idx, (expected, actual))| {
+                        if idx == 0 {
+                            actual == self_ty
+                        } else {
+                            expected == actual
+                        }
+                    });
+            let is_valid = params_match
+                && expected.ret() == actual.ret()
+                && function.data(db.upcast()).has_self_param()
+                && !function.is_generic(db);
+            if !is_valid {
+                sink.push(TraitFunctionSignatureMismatch {
+                    function: function_ptr(),
+                    name,
+                    trait_name: trait_name.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImplData {
+    pub self_ty: LocalTypeRefId,
+    pub target_trait: Option<LocalTypeRefId>,
+    pub functions: Vec<FunctionId>,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl ImplData {
+    pub(crate) fn impl_data_query(db: &dyn DefDatabase, id: ImplId) -> Arc<ImplData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let impl_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id.value);
+
+        let mut type_ref_builder = TypeRefMap::builder();
+        let self_ty = type_ref_builder.alloc_from_node_opt(src.self_type_ref().as_ref());
+        let target_trait = src
+            .trait_type_ref()
+            .map(|type_ref| type_ref_builder.alloc_from_node(&type_ref));
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+
+        let functions = impl_def
+            .functions
+            .iter()
+            .map(|&function| {
+                FunctionLoc {
+                    container: ItemContainerId::ImplId(id),
+                    id: ItemTreeId::new(loc.id.file_id, function),
+                }
+                .intern(db)
+            })
+            .collect();
+
+        Arc::new(ImplData {
+            self_ty,
+            target_trait,
+            functions,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
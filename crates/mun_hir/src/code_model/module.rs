@@ -1,4 +1,5 @@
-use super::{Function, Package, Struct, TypeAlias};
+use super::{Const, Enum, EnumVariant, Function, Impl, Package, Static, Struct, Trait, TypeAlias};
+use crate::builtin_module::{BuiltinModule, IntrinsicFunction};
 use crate::ids::{ItemDefinitionId, ModuleId};
 use crate::primitive_type::PrimitiveType;
 use crate::{DiagnosticSink, FileId, HirDatabase, Name};
@@ -65,6 +66,15 @@ impl Module {
             .collect()
     }
 
+    /// Returns all `impl` blocks defined in this module.
+    pub fn impls(self, db: &dyn HirDatabase) -> Vec<Impl> {
+        let package_defs = db.package_defs(self.id.package);
+        package_defs.modules[self.id.local_id]
+            .impls()
+            .map(Impl::from)
+            .collect()
+    }
+
     /// Iterate over all diagnostics from this `Module` by placing them in the `sink`
     pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
         // Add diagnostics from the package definitions
@@ -84,10 +94,19 @@ impl Module {
             match decl {
                 ModuleDef::Function(f) => f.diagnostics(db, sink),
                 ModuleDef::Struct(s) => s.diagnostics(db, sink),
+                ModuleDef::Enum(e) => e.diagnostics(db, sink),
                 ModuleDef::TypeAlias(t) => t.diagnostics(db, sink),
+                ModuleDef::Trait(t) => t.diagnostics(db, sink),
+                ModuleDef::Const(c) => c.diagnostics(db, sink),
+                ModuleDef::Static(s) => s.diagnostics(db, sink),
                 _ => (),
             }
         }
+
+        // Add diagnostics from the `impl` blocks
+        for imp in self.impls(db) {
+            imp.diagnostics(db, sink);
+        }
     }
 
     /// Returns all the child modules of this module
@@ -135,7 +154,14 @@ pub enum ModuleDef {
     Function(Function),
     PrimitiveType(PrimitiveType),
     Struct(Struct),
+    Enum(Enum),
+    EnumVariant(EnumVariant),
     TypeAlias(TypeAlias),
+    Trait(Trait),
+    Const(Const),
+    Static(Static),
+    BuiltinModule(BuiltinModule),
+    IntrinsicFunction(IntrinsicFunction),
 }
 
 impl From<Function> for ModuleDef {
@@ -156,26 +182,75 @@ impl From<Struct> for ModuleDef {
     }
 }
 
+impl From<Enum> for ModuleDef {
+    fn from(t: Enum) -> Self {
+        ModuleDef::Enum(t)
+    }
+}
+
+impl From<EnumVariant> for ModuleDef {
+    fn from(t: EnumVariant) -> Self {
+        ModuleDef::EnumVariant(t)
+    }
+}
+
 impl From<TypeAlias> for ModuleDef {
     fn from(t: TypeAlias) -> Self {
         ModuleDef::TypeAlias(t)
     }
 }
 
+impl From<Trait> for ModuleDef {
+    fn from(t: Trait) -> Self {
+        ModuleDef::Trait(t)
+    }
+}
+
+impl From<Const> for ModuleDef {
+    fn from(c: Const) -> Self {
+        ModuleDef::Const(c)
+    }
+}
+
+impl From<Static> for ModuleDef {
+    fn from(s: Static) -> Self {
+        ModuleDef::Static(s)
+    }
+}
+
 impl From<Module> for ModuleDef {
     fn from(m: Module) -> Self {
         ModuleDef::Module(m)
     }
 }
 
+impl From<BuiltinModule> for ModuleDef {
+    fn from(m: BuiltinModule) -> Self {
+        ModuleDef::BuiltinModule(m)
+    }
+}
+
+impl From<IntrinsicFunction> for ModuleDef {
+    fn from(f: IntrinsicFunction) -> Self {
+        ModuleDef::IntrinsicFunction(f)
+    }
+}
+
 impl From<ItemDefinitionId> for ModuleDef {
     fn from(id: ItemDefinitionId) -> Self {
         match id {
             ItemDefinitionId::ModuleId(id) => Module { id }.into(),
             ItemDefinitionId::FunctionId(id) => Function { id }.into(),
             ItemDefinitionId::StructId(id) => Struct { id }.into(),
+            ItemDefinitionId::EnumId(id) => Enum { id }.into(),
+            ItemDefinitionId::EnumVariantId(id) => EnumVariant::from(id).into(),
             ItemDefinitionId::TypeAliasId(id) => TypeAlias { id }.into(),
+            ItemDefinitionId::TraitId(id) => Trait { id }.into(),
+            ItemDefinitionId::ConstId(id) => Const { id }.into(),
+            ItemDefinitionId::StaticId(id) => Static { id }.into(),
             ItemDefinitionId::PrimitiveType(id) => id.into(),
+            ItemDefinitionId::BuiltinModule(id) => id.into(),
+            ItemDefinitionId::IntrinsicFunction(id) => id.into(),
         }
     }
 }
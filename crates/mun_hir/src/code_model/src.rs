@@ -1,5 +1,5 @@
-use crate::code_model::{Field, Function, Struct, TypeAlias};
-use crate::ids::{AssocItemLoc, Lookup};
+use crate::code_model::{Const, Enum, Field, Function, Static, Struct, TypeAlias};
+use crate::ids::{AssocItemLoc, FunctionLoc, Lookup};
 use crate::in_file::InFile;
 use crate::item_tree::{ItemTreeId, ItemTreeNode};
 use crate::{DefDatabase, ItemLoc};
@@ -46,6 +46,14 @@ impl<N: ItemTreeNode> HasSource for AssocItemLoc<N> {
     }
 }
 
+impl HasSource for FunctionLoc {
+    type Ast = ast::FunctionDef;
+
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.source(db)
+    }
+}
+
 impl HasSource for Function {
     type Ast = ast::FunctionDef;
     fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
@@ -60,6 +68,13 @@ impl HasSource for Struct {
     }
 }
 
+impl HasSource for Enum {
+    type Ast = ast::EnumDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
 impl HasSource for Field {
     type Ast = ast::RecordFieldDef;
 
@@ -89,3 +104,17 @@ impl HasSource for TypeAlias {
         self.id.lookup(db).source(db)
     }
 }
+
+impl HasSource for Const {
+    type Ast = ast::ConstDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
+impl HasSource for Static {
+    type Ast = ast::StaticDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
@@ -0,0 +1,149 @@
+use super::Module;
+use crate::{
+    const_eval::{self, ConstEvalError, ConstValue},
+    expr::BodySourceMap,
+    ids::{Lookup, StaticId},
+    resolve::HasResolver,
+    type_ref::{LocalTypeRefId, TypeRefMap, TypeRefSourceMap},
+    visibility::RawVisibility,
+    Body, DefDatabase, DiagnosticSink, FileId, HasVisibility, HirDatabase, InferenceResult, Name,
+    Ty, Visibility,
+};
+use mun_syntax::ast::TypeAscriptionOwner;
+use std::{iter::once, sync::Arc};
+
+/// A global variable that is stored in the data section of an assembly, e.g.:
+/// ```mun
+/// static mut SCORE: i32 = 0;
+/// ```
+/// The initial value of a static is evaluated at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Static {
+    pub(crate) id: StaticId,
+}
+
+impl From<StaticId> for Static {
+    fn from(id: StaticId) -> Self {
+        Static { id }
+    }
+}
+
+impl Static {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        Module {
+            id: self.id.lookup(db.upcast()).module,
+        }
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        self.id.lookup(db.upcast()).id.file_id
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<StaticData> {
+        db.static_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.data(db.upcast()).name.clone()
+    }
+
+    /// Returns the full name of the static including all module specifiers (e.g: `foo::SCORE`).
+    /// This is the name by which the host refers to the static.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        itertools::Itertools::intersperse(
+            self.module(db)
+                .path_to_root(db)
+                .into_iter()
+                .filter_map(|module| module.name(db))
+                .chain(once(self.name(db)))
+                .map(|name| name.to_string()),
+            String::from("::"),
+        )
+        .collect()
+    }
+
+    /// Returns true if the static is declared with `mut` and can thus be assigned to.
+    pub fn is_mut(self, db: &dyn HirDatabase) -> bool {
+        self.data(db.upcast()).mutable
+    }
+
+    /// Returns the declared type of the static.
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        let data = self.data(db.upcast());
+        let resolver = self.id.resolver(db.upcast());
+        Ty::from_hir(db, &resolver, data.type_ref_map(), data.type_ref_id).0
+    }
+
+    pub fn body(self, db: &dyn HirDatabase) -> Arc<Body> {
+        db.body(self.id.into())
+    }
+
+    pub fn infer(self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        db.infer(self.id.into())
+    }
+
+    /// Returns the initial value of the static.
+    pub fn initial_value(self, db: &dyn HirDatabase) -> Result<ConstValue, ConstEvalError> {
+        const_eval::eval_body(db, self.id.into())
+    }
+
+    pub(crate) fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
+        db.body_with_source_map(self.id.into()).1
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let body = self.body(db);
+        body.add_diagnostics(db, self.into(), sink);
+        let infer = self.infer(db);
+        infer.add_diagnostics(db, self.into(), sink);
+        if let Err(err) = self.initial_value(db) {
+            err.add_to(db, self.into(), sink);
+        }
+    }
+}
+
+impl HasVisibility for Static {
+    fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
+        self.data(db.upcast())
+            .visibility
+            .resolve(db.upcast(), &self.id.resolver(db.upcast()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StaticData {
+    pub name: Name,
+    pub visibility: RawVisibility,
+    pub mutable: bool,
+    pub type_ref_id: LocalTypeRefId,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl StaticData {
+    pub(crate) fn static_data_query(db: &dyn DefDatabase, id: StaticId) -> Arc<StaticData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let static_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id.value);
+        let mut type_ref_builder = TypeRefMap::builder();
+        let type_ref_id = type_ref_builder.alloc_from_node_opt(src.ascribed_type().as_ref());
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(StaticData {
+            name: static_def.name.clone(),
+            visibility: item_tree[static_def.visibility].clone(),
+            mutable: static_def.mutable,
+            type_ref_id,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
 };
 use mun_syntax::{
     ast,
-    ast::{NameOwner, TypeAscriptionOwner, VisibilityOwner},
+    ast::{NameOwner, TypeAscriptionOwner, TypeParamsOwner, VisibilityOwner},
 };
 use std::{fmt, sync::Arc};
 
@@ -98,6 +98,11 @@ impl Struct {
         .collect()
     }
 
+    /// Returns true if this struct declares type parameters, e.g. `struct Pair<A, B>`.
+    pub fn is_generic(self, db: &dyn HirDatabase) -> bool {
+        !self.data(db.upcast()).type_params.is_empty()
+    }
+
     pub fn fields(self, db: &dyn HirDatabase) -> Vec<Field> {
         self.data(db.upcast())
             .fields
@@ -179,6 +184,8 @@ pub struct StructData {
     pub fields: Arena<FieldData>,
     pub kind: StructKind,
     pub memory_kind: StructMemoryKind,
+    /// The names of the type parameters of the struct, e.g. `A` and `B` in `struct Pair<A, B>`
+    pub type_params: Vec<Name>,
     type_ref_map: TypeRefMap,
     type_ref_source_map: TypeRefSourceMap,
 }
@@ -224,6 +231,19 @@ impl StructData {
         };
 
         let visibility = item_tree[strukt.visibility].clone();
+        let type_params = src
+            .type_param_list()
+            .map(|list| {
+                list.type_params()
+                    .map(|param| {
+                        param
+                            .name()
+                            .map(|n| n.as_name())
+                            .unwrap_or_else(Name::missing)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
 
         let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
         Arc::new(StructData {
@@ -232,6 +252,7 @@ impl StructData {
             fields,
             kind,
             memory_kind,
+            type_params,
             type_ref_map,
             type_ref_source_map,
         })
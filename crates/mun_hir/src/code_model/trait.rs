@@ -0,0 +1,180 @@
+use super::{Function, Impl, Module, Struct};
+use crate::{
+    diagnostics::{InvalidTraitFunction, TraitFunctionWithBody},
+    ids::{FunctionId, FunctionLoc, Intern, ItemContainerId, Lookup, TraitId},
+    item_tree::ItemTreeId,
+    resolve::HasResolver,
+    ty::{Ty, TyKind, TypeWalk},
+    visibility::RawVisibility,
+    DefDatabase, DiagnosticSink, FileId, HasSource, HasVisibility, HirDatabase, InFile, Name,
+    Visibility,
+};
+use mun_syntax::{ast::TypeParamsOwner, AstPtr};
+use std::{iter::once, sync::Arc};
+
+/// A trait that declares the functions that types implementing the trait have to define, e.g.:
+/// ```mun
+/// trait Damageable {
+///     fn damage(self: Self, amount: f32);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Trait {
+    pub(crate) id: TraitId,
+}
+
+impl From<TraitId> for Trait {
+    fn from(id: TraitId) -> Self {
+        Trait { id }
+    }
+}
+
+impl Trait {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        Module {
+            id: self.id.lookup(db.upcast()).module,
+        }
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        self.id.lookup(db.upcast()).id.file_id
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<TraitData> {
+        db.trait_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.data(db.upcast()).name.clone()
+    }
+
+    /// Returns the full name of the trait including all module specifiers (e.g: `foo::Bar`).
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        itertools::Itertools::intersperse(
+            self.module(db)
+                .path_to_root(db)
+                .into_iter()
+                .filter_map(|module| module.name(db))
+                .chain(once(self.name(db)))
+                .map(|name| name.to_string()),
+            String::from("::"),
+        )
+        .collect()
+    }
+
+    /// Returns the functions declared by the trait, in the order in which they are declared.
+    pub fn functions(self, db: &dyn HirDatabase) -> Vec<Function> {
+        self.data(db.upcast())
+            .functions
+            .iter()
+            .map(|&id| Function { id })
+            .collect()
+    }
+
+    /// Returns the function of the trait with the specified name, if any.
+    pub fn function(self, db: &dyn HirDatabase, name: &Name) -> Option<Function> {
+        self.functions(db)
+            .into_iter()
+            .find(|function| function.name(db) == *name)
+    }
+
+    /// Returns the structs that implement the trait together with their implementation, in the
+    /// order in which the implementations are defined.
+    pub fn implementations(self, db: &dyn HirDatabase) -> Vec<(Struct, Impl)> {
+        db.trait_impls_in_package(self.module(db).id.package)
+            .for_trait(self)
+            .collect()
+    }
+
+    /// Returns the type of a trait object of this trait, e.g. `dyn Damageable`.
+    pub fn ty(self) -> Ty {
+        TyKind::DynTrait(self).intern()
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let self_ty = self.ty();
+        for function in self.functions(db) {
+            let data = function.data(db.upcast());
+            let file_id = function.file_id(db);
+            let src = function.source(db.upcast());
+
+            // Report unresolved types in the signature
+            let resolver = function.id.resolver(db.upcast());
+            let mut param_tys = Vec::with_capacity(data.params().len());
+            for &type_ref in data.params().iter().chain(once(data.ret_type())) {
+                let (ty, diagnostics) = Ty::from_hir(db, &resolver, data.type_ref_map(), type_ref);
+                for diagnostic in diagnostics {
+                    diagnostic.add_to(db, file_id, data.type_ref_source_map(), sink);
+                }
+                param_tys.push(ty);
+            }
+            let ret_ty = param_tys.pop().expect("the return type is always lowered");
+
+            if src.value.body().is_some() {
+                sink.push(TraitFunctionWithBody {
+                    function: InFile::new(file_id, AstPtr::new(&src.value)),
+                });
+            }
+
+            // Trait functions must take `self: Self` as their first parameter and cannot refer to
+            // `Self` or declare type parameters anywhere else, so that they can be called through a
+            // trait object.
+            let mentions_self = |ty: &Ty| {
+                let mut found = false;
+                ty.walk(&mut |ty| found |= *ty == self_ty);
+                found
+            };
+            let is_valid = data.has_self_param()
+                && param_tys.first() == Some(&self_ty)
+                && !param_tys.iter().skip(1).any(mentions_self)
+                && !mentions_self(&ret_ty)
+                && src.value.type_param_list().is_none();
+            if !is_valid {
+                sink.push(InvalidTraitFunction {
+                    function: InFile::new(file_id, AstPtr::new(&src.value)),
+                });
+            }
+        }
+    }
+}
+
+impl HasVisibility for Trait {
+    fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
+        self.data(db.upcast())
+            .visibility
+            .resolve(db.upcast(), &self.id.resolver(db.upcast()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TraitData {
+    pub name: Name,
+    pub visibility: RawVisibility,
+    pub functions: Vec<FunctionId>,
+}
+
+impl TraitData {
+    pub(crate) fn trait_data_query(db: &dyn DefDatabase, id: TraitId) -> Arc<TraitData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let trait_def = &item_tree[loc.id.value];
+
+        let functions = trait_def
+            .functions
+            .iter()
+            .map(|&function| {
+                FunctionLoc {
+                    container: ItemContainerId::TraitId(id),
+                    id: ItemTreeId::new(loc.id.file_id, function),
+                }
+                .intern(db)
+            })
+            .collect();
+
+        Arc::new(TraitData {
+            name: trait_def.name.clone(),
+            visibility: item_tree[trait_def.visibility].clone(),
+            functions,
+        })
+    }
+}
@@ -0,0 +1,409 @@
+//! Constant evaluation computes the value of `const` items and the initial value of `static` items
+//! at compile time. Only literals, operators, casts, `if` expressions and references to other
+//! constants can be evaluated.
+
+use crate::{
+    code_model::DefWithBody,
+    diagnostics::{
+        ConstEvalDivisionByZero, ConstEvalOverflow, CyclicConstant, NonConstantExpression,
+    },
+    expr::{ArithOp, BinaryOp, CmpOp, Expr, ExprId, Literal, LogicOp, Ordering, UnaryOp},
+    ids::DefWithBodyId,
+    resolve::{resolver_for_expr, ValueNs},
+    Body, Const, DiagnosticSink, FloatBitness, HasSource, HirDatabase, InferenceResult, IntBitness,
+    IntTy, ResolveBitness, TyKind,
+};
+use mun_syntax::{AstNode, SyntaxNodePtr};
+
+#[cfg(test)]
+mod tests;
+
+/// The value of a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+}
+
+impl Eq for ConstValue {}
+
+/// An error that occurred while evaluating a constant expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstEvalError {
+    /// The expression cannot be evaluated at compile time
+    NotConst(ExprId),
+
+    /// The result of the expression does not fit in its type
+    Overflow(ExprId),
+
+    /// The expression divides by zero
+    DivisionByZero(ExprId),
+
+    /// The value of the constant depends on itself
+    Cycle,
+
+    /// The initializer contains errors that are reported elsewhere, e.g. type errors or errors in
+    /// a referenced constant.
+    Erroneous,
+}
+
+/// Evaluates the value of the specified constant.
+pub(crate) fn const_eval_query(
+    db: &dyn HirDatabase,
+    def: Const,
+) -> Result<ConstValue, ConstEvalError> {
+    eval_body(db, def.id.into())
+}
+
+/// Recover with an error when a constant depends on itself.
+pub(crate) fn const_eval_cycle_recover(
+    _db: &dyn HirDatabase,
+    _cycle: &[String],
+    _def: &Const,
+) -> Result<ConstValue, ConstEvalError> {
+    Err(ConstEvalError::Cycle)
+}
+
+/// Evaluates the initializer of a `const` or `static`.
+pub(crate) fn eval_body(
+    db: &dyn HirDatabase,
+    def: DefWithBodyId,
+) -> Result<ConstValue, ConstEvalError> {
+    let body = db.body(def);
+    let infer = db.infer(def);
+    if !infer.diagnostics.is_empty() {
+        return Err(ConstEvalError::Erroneous);
+    }
+
+    ConstEvaluator {
+        db,
+        body: &body,
+        infer: &infer,
+    }
+    .eval(body.body_expr())
+}
+
+struct ConstEvaluator<'a> {
+    db: &'a dyn HirDatabase,
+    body: &'a Body,
+    infer: &'a InferenceResult,
+}
+
+impl<'a> ConstEvaluator<'a> {
+    fn eval(&self, expr: ExprId) -> Result<ConstValue, ConstEvalError> {
+        match &self.body[expr] {
+            Expr::Literal(literal) => self.eval_literal(expr, literal),
+            Expr::UnaryOp { expr: operand, op } => {
+                let value = self.eval(*operand)?;
+                self.eval_unary_op(expr, *op, value)
+            }
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(op),
+            } => match op {
+                BinaryOp::LogicOp(op) => {
+                    let lhs = self.eval_bool(*lhs)?;
+                    match (op, lhs) {
+                        (LogicOp::And, false) => Ok(ConstValue::Bool(false)),
+                        (LogicOp::Or, true) => Ok(ConstValue::Bool(true)),
+                        _ => self.eval(*rhs),
+                    }
+                }
+                BinaryOp::ArithOp(op) => {
+                    let lhs = self.eval(*lhs)?;
+                    let rhs = self.eval(*rhs)?;
+                    self.eval_arith_op(expr, *op, lhs, rhs)
+                }
+                BinaryOp::CmpOp(op) => {
+                    let lhs = self.eval(*lhs)?;
+                    let rhs = self.eval(*rhs)?;
+                    eval_cmp_op(expr, *op, lhs, rhs)
+                }
+                BinaryOp::Assignment { .. } => Err(ConstEvalError::NotConst(expr)),
+            },
+            Expr::Cast { expr: operand, .. } => {
+                let value = self.eval(*operand)?;
+                self.eval_cast(expr, value)
+            }
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.db.upcast(), self.body.owner(), expr);
+                match resolver.resolve_path_as_value_fully(self.db.upcast(), path) {
+                    Some((ValueNs::ConstId(id), _)) => {
+                        Const::from(id).eval(self.db).map_err(|err| match err {
+                            ConstEvalError::Cycle => ConstEvalError::Cycle,
+                            _ => ConstEvalError::Erroneous,
+                        })
+                    }
+                    _ => Err(ConstEvalError::NotConst(expr)),
+                }
+            }
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch: Some(else_branch),
+            } => {
+                if self.eval_bool(*condition)? {
+                    self.eval(*then_branch)
+                } else {
+                    self.eval(*else_branch)
+                }
+            }
+            Expr::Block {
+                statements,
+                tail: Some(tail),
+            } if statements.is_empty() => self.eval(*tail),
+            _ => Err(ConstEvalError::NotConst(expr)),
+        }
+    }
+
+    /// Evaluates an expression whose type is `bool`.
+    fn eval_bool(&self, expr: ExprId) -> Result<bool, ConstEvalError> {
+        match self.eval(expr)? {
+            ConstValue::Bool(value) => Ok(value),
+            _ => Err(ConstEvalError::Erroneous),
+        }
+    }
+
+    fn eval_literal(&self, expr: ExprId, literal: &Literal) -> Result<ConstValue, ConstEvalError> {
+        match literal {
+            Literal::Bool(value) => Ok(ConstValue::Bool(*value)),
+            Literal::Int(literal) => {
+                let value =
+                    i128::try_from(literal.value).map_err(|_| ConstEvalError::Overflow(expr))?;
+                match self.infer[expr].interned() {
+                    TyKind::Int(_) => self.checked_int(expr, Some(value)),
+                    TyKind::Float(_) => Ok(self.float(expr, value as f64)),
+                    _ => Err(ConstEvalError::Erroneous),
+                }
+            }
+            Literal::Float(literal) => Ok(self.float(expr, literal.value)),
+            Literal::String(_) | Literal::Nil => Err(ConstEvalError::NotConst(expr)),
+        }
+    }
+
+    fn eval_unary_op(
+        &self,
+        expr: ExprId,
+        op: UnaryOp,
+        value: ConstValue,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match (op, value) {
+            (UnaryOp::Neg, ConstValue::Int(value)) => self.checked_int(expr, value.checked_neg()),
+            (UnaryOp::Neg, ConstValue::Float(value)) => Ok(ConstValue::Float(-value)),
+            (UnaryOp::Not, ConstValue::Bool(value)) => Ok(ConstValue::Bool(!value)),
+            (UnaryOp::Not, ConstValue::Int(value)) => Ok(self.wrapping_int(expr, !value)),
+            _ => Err(ConstEvalError::Erroneous),
+        }
+    }
+
+    fn eval_arith_op(
+        &self,
+        expr: ExprId,
+        op: ArithOp,
+        lhs: ConstValue,
+        rhs: ConstValue,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match (lhs, rhs) {
+            (ConstValue::Int(lhs), ConstValue::Int(rhs)) => match op {
+                ArithOp::Add => self.checked_int(expr, lhs.checked_add(rhs)),
+                ArithOp::Subtract => self.checked_int(expr, lhs.checked_sub(rhs)),
+                ArithOp::Multiply => self.checked_int(expr, lhs.checked_mul(rhs)),
+                ArithOp::Divide | ArithOp::Remainder if rhs == 0 => {
+                    Err(ConstEvalError::DivisionByZero(expr))
+                }
+                ArithOp::Divide => self.checked_int(expr, lhs.checked_div(rhs)),
+                ArithOp::Remainder => self.checked_int(expr, lhs.checked_rem(rhs)),
+                ArithOp::LeftShift | ArithOp::RightShift => {
+                    let bits = self.int_ty(expr).map(|ty| self.int_bits(ty)).unwrap_or(128);
+                    let shift = u32::try_from(rhs)
+                        .ok()
+                        .filter(|shift| *shift < bits)
+                        .ok_or(ConstEvalError::Overflow(expr))?;
+                    if op == ArithOp::LeftShift {
+                        Ok(self.wrapping_int(expr, lhs << shift))
+                    } else {
+                        Ok(ConstValue::Int(lhs >> shift))
+                    }
+                }
+                ArithOp::BitAnd => Ok(ConstValue::Int(lhs & rhs)),
+                ArithOp::BitOr => Ok(ConstValue::Int(lhs | rhs)),
+                ArithOp::BitXor => Ok(ConstValue::Int(lhs ^ rhs)),
+            },
+            (ConstValue::Float(lhs), ConstValue::Float(rhs)) => {
+                let value = match op {
+                    ArithOp::Add => lhs + rhs,
+                    ArithOp::Subtract => lhs - rhs,
+                    ArithOp::Multiply => lhs * rhs,
+                    ArithOp::Divide => lhs / rhs,
+                    ArithOp::Remainder => lhs % rhs,
+                    _ => return Err(ConstEvalError::Erroneous),
+                };
+                Ok(self.float(expr, value))
+            }
+            (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => match op {
+                ArithOp::BitAnd => Ok(ConstValue::Bool(lhs & rhs)),
+                ArithOp::BitOr => Ok(ConstValue::Bool(lhs | rhs)),
+                ArithOp::BitXor => Ok(ConstValue::Bool(lhs ^ rhs)),
+                _ => Err(ConstEvalError::Erroneous),
+            },
+            _ => Err(ConstEvalError::Erroneous),
+        }
+    }
+
+    /// Evaluates a cast (e.g. `x as f32`) with the same semantics as Rust: integers are truncated
+    /// and floats are saturated when they are converted to a smaller type.
+    fn eval_cast(&self, expr: ExprId, value: ConstValue) -> Result<ConstValue, ConstEvalError> {
+        match (self.infer[expr].interned(), value) {
+            (TyKind::Int(_), ConstValue::Int(value)) => Ok(self.wrapping_int(expr, value)),
+            (TyKind::Int(_), ConstValue::Bool(value)) => Ok(ConstValue::Int(value.into())),
+            (TyKind::Int(ty), ConstValue::Float(value)) => {
+                let (min, max) = self.int_range(*ty);
+                Ok(ConstValue::Int((value as i128).clamp(min, max)))
+            }
+            (TyKind::Float(_), ConstValue::Int(value)) => Ok(self.float(expr, value as f64)),
+            (TyKind::Float(_), ConstValue::Float(value)) => Ok(self.float(expr, value)),
+            (TyKind::Bool, ConstValue::Bool(value)) => Ok(ConstValue::Bool(value)),
+            _ => Err(ConstEvalError::NotConst(expr)),
+        }
+    }
+
+    /// Returns the integer type of the specified expression.
+    fn int_ty(&self, expr: ExprId) -> Option<IntTy> {
+        match self.infer[expr].interned() {
+            TyKind::Int(ty) => Some(*ty),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bits of the specified integer type.
+    fn int_bits(&self, ty: IntTy) -> u32 {
+        match ty.bitness.resolve(&self.db.target_data_layout()) {
+            IntBitness::X8 => 8,
+            IntBitness::X16 => 16,
+            IntBitness::X32 => 32,
+            IntBitness::X64 => 64,
+            IntBitness::X128 | IntBitness::Xsize => 128,
+        }
+    }
+
+    /// Returns the smallest and largest value of the specified integer type. Values of `u128` are
+    /// limited to the range of `i128`.
+    fn int_range(&self, ty: IntTy) -> (i128, i128) {
+        match (ty.signedness.is_signed(), self.int_bits(ty)) {
+            (true, 128) => (i128::MIN, i128::MAX),
+            (false, 128) => (0, i128::MAX),
+            (true, bits) => (-(1 << (bits - 1)), (1 << (bits - 1)) - 1),
+            (false, bits) => (0, (1 << bits) - 1),
+        }
+    }
+
+    /// Returns the integer `value` as the result of `expr`, or an overflow error if the value
+    /// does not fit in the type of the expression.
+    fn checked_int(&self, expr: ExprId, value: Option<i128>) -> Result<ConstValue, ConstEvalError> {
+        let ty = self.int_ty(expr).ok_or(ConstEvalError::Erroneous)?;
+        let (min, max) = self.int_range(ty);
+        match value {
+            Some(value) if value >= min && value <= max => Ok(ConstValue::Int(value)),
+            _ => Err(ConstEvalError::Overflow(expr)),
+        }
+    }
+
+    /// Returns the integer `value` truncated to the type of `expr`.
+    fn wrapping_int(&self, expr: ExprId, value: i128) -> ConstValue {
+        let ty = match self.int_ty(expr) {
+            Some(ty) => ty,
+            None => return ConstValue::Int(value),
+        };
+        let bits = self.int_bits(ty);
+        let value = match (ty.signedness.is_signed(), bits) {
+            (true, 128) => value,
+            (false, 128) => value & i128::MAX,
+            (true, bits) => (value << (128 - bits)) >> (128 - bits),
+            (false, bits) => value & ((1 << bits) - 1),
+        };
+        ConstValue::Int(value)
+    }
+
+    /// Returns the float `value` rounded to the precision of the type of `expr`.
+    fn float(&self, expr: ExprId, value: f64) -> ConstValue {
+        match self.infer[expr].interned() {
+            TyKind::Float(ty) if ty.bitness == FloatBitness::X32 => {
+                ConstValue::Float(value as f32 as f64)
+            }
+            _ => ConstValue::Float(value),
+        }
+    }
+}
+
+fn eval_cmp_op(
+    expr: ExprId,
+    op: CmpOp,
+    lhs: ConstValue,
+    rhs: ConstValue,
+) -> Result<ConstValue, ConstEvalError> {
+    let ordering = match (lhs, rhs) {
+        (ConstValue::Int(lhs), ConstValue::Int(rhs)) => lhs.partial_cmp(&rhs),
+        (ConstValue::Float(lhs), ConstValue::Float(rhs)) => lhs.partial_cmp(&rhs),
+        (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => lhs.partial_cmp(&rhs),
+        _ => return Err(ConstEvalError::NotConst(expr)),
+    };
+    let value = match (op, ordering) {
+        (CmpOp::Eq { negated }, ordering) => {
+            (ordering == Some(std::cmp::Ordering::Equal)) != negated
+        }
+        (_, None) => false,
+        (CmpOp::Ord { ordering, strict }, Some(result)) => match (ordering, result) {
+            (_, std::cmp::Ordering::Equal) => !strict,
+            (Ordering::Less, result) => result == std::cmp::Ordering::Less,
+            (Ordering::Greater, result) => result == std::cmp::Ordering::Greater,
+        },
+    };
+    Ok(ConstValue::Bool(value))
+}
+
+impl ConstEvalError {
+    /// Adds a diagnostic for this error to the `DiagnosticSink`. Errors that are reported
+    /// elsewhere are ignored.
+    pub(crate) fn add_to(
+        &self,
+        db: &dyn HirDatabase,
+        owner: DefWithBody,
+        sink: &mut DiagnosticSink,
+    ) {
+        let file = owner.file_id(db);
+        let expr_syntax = |expr: &ExprId| {
+            owner
+                .body_source_map(db)
+                .expr_syntax(*expr)
+                .expect("could not retrieve expr from source map")
+                .value
+                .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
+        };
+        match self {
+            ConstEvalError::NotConst(expr) => sink.push(NonConstantExpression {
+                file,
+                expr: expr_syntax(expr),
+            }),
+            ConstEvalError::Overflow(expr) => sink.push(ConstEvalOverflow {
+                file,
+                expr: expr_syntax(expr),
+            }),
+            ConstEvalError::DivisionByZero(expr) => sink.push(ConstEvalDivisionByZero {
+                file,
+                expr: expr_syntax(expr),
+            }),
+            ConstEvalError::Cycle => {
+                if let DefWithBody::Const(def) = owner {
+                    sink.push(CyclicConstant {
+                        file,
+                        definition: SyntaxNodePtr::new(def.source(db.upcast()).value.syntax()),
+                        name: def.name(db),
+                    })
+                }
+            }
+            ConstEvalError::Erroneous => (),
+        }
+    }
+}
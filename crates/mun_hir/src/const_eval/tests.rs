@@ -0,0 +1,125 @@
+use crate::{mock::MockDatabase, with_fixture::WithFixture, ModuleDef, Package};
+use std::fmt::Write;
+
+#[test]
+fn literals_and_operators() {
+    insta::assert_snapshot!(eval(
+        r#"
+    const A: i32 = 1 + 2 * 3;
+    const B: i32 = -(A - 10) % 4;
+    const C: u8 = 250 + 5;
+    const D: i8 = !0;
+    const E: u32 = 1 << 31;
+    const F: i64 = -16 >> 2;
+    const G: bool = A > 5 && !(B == 3) || false;
+    const H: f32 = 0.1 + 0.2;
+    const I: f64 = 0.1 + 0.2;
+    const J: f64 = 7.0 % 4.0 / 2.0;
+    "#),
+    @r###"
+    const A: Ok(Int(7))
+    const B: Ok(Int(3))
+    const C: Ok(Int(255))
+    const D: Ok(Int(-1))
+    const E: Ok(Int(2147483648))
+    const F: Ok(Int(-4))
+    const G: Ok(Bool(false))
+    const H: Ok(Float(0.30000001192092896))
+    const I: Ok(Float(0.30000000000000004))
+    const J: Ok(Float(1.5))
+    "###);
+}
+
+#[test]
+fn casts_and_conditionals() {
+    insta::assert_snapshot!(eval(
+        r#"
+    const A: u8 = 300 as u8;
+    const B: i8 = 200u8 as i8;
+    const C: u8 = -1.5 as u8;
+    const D: f32 = 3 as f32;
+    const E: i32 = true as i32;
+    const F: i32 = if A > 10 { 1 } else { 2 };
+    const G: i32 = { F * 10 };
+    "#),
+    @r###"
+    const A: Ok(Int(44))
+    const B: Ok(Int(-56))
+    const C: Ok(Int(0))
+    const D: Ok(Float(3.0))
+    const E: Ok(Int(1))
+    const F: Ok(Int(1))
+    const G: Ok(Int(10))
+    "###);
+}
+
+#[test]
+fn references_to_constants() {
+    insta::assert_snapshot!(eval(
+        r#"
+    //- /mod.mun
+    const MAX_SPEED: f32 = foo::SPEED * 2.0;
+    static mut SPEED: f32 = MAX_SPEED / 4.0;
+
+    //- /foo.mun
+    pub const SPEED: f32 = 5.0;
+    "#),
+    @r###"
+    const MAX_SPEED: Ok(Float(10.0))
+    static SPEED: Ok(Float(2.5))
+    const foo::SPEED: Ok(Float(5.0))
+    "###);
+}
+
+#[test]
+fn errors() {
+    insta::assert_snapshot!(eval(
+        r#"
+    static mut A: i32 = 1;
+    const B: i32 = A;
+    const C: i32 = B + 1;
+    const D: i32 = 2147483647 + 1;
+    const E: i32 = 10 % 0;
+    const F: u32 = 1 << 32;
+    const G: i32 = G + 1;
+    const H: i32 = undefined;
+    "#),
+    @r###"
+    static A: Ok(Int(1))
+    const B: Err(NotConst(Idx::<Expr>(0)))
+    const C: Err(Erroneous)
+    const D: Err(Overflow(Idx::<Expr>(2)))
+    const E: Err(DivisionByZero(Idx::<Expr>(2)))
+    const F: Err(Overflow(Idx::<Expr>(2)))
+    const G: Err(Cycle)
+    const H: Err(Erroneous)
+    "###);
+}
+
+fn eval(content: &str) -> String {
+    let db = MockDatabase::with_files(content);
+
+    let mut acc = String::new();
+    for item in Package::all(&db)
+        .iter()
+        .flat_map(|pkg| pkg.modules(&db))
+        .flat_map(|module| module.declarations(&db))
+    {
+        match item {
+            ModuleDef::Const(c) => {
+                writeln!(acc, "const {}: {:?}", c.full_name(&db), c.eval(&db)).unwrap()
+            }
+            ModuleDef::Static(s) => writeln!(
+                acc,
+                "static {}: {:?}",
+                s.full_name(&db),
+                s.initial_value(&db)
+            )
+            .unwrap(),
+            _ => (),
+        }
+    }
+
+    acc.truncate(acc.trim_end().len());
+    acc
+}
@@ -8,13 +8,17 @@ use crate::module_tree::ModuleTree;
 use crate::name_resolution::Namespace;
 use crate::package_defs::PackageDefs;
 use crate::ty::lower::LowerTyMap;
-use crate::ty::{CallableDef, FnSig, Ty, TypableDef};
+use crate::ty::{CallableDef, FnSig, InherentImpls, TraitImpls, Ty, TypableDef};
 use crate::{
-    code_model::{FunctionData, StructData, TypeAliasData},
+    code_model::{
+        ConstData, EnumData, FunctionData, ImplData, StaticData, StructData, TraitData,
+        TypeAliasData,
+    },
     ids,
     line_index::LineIndex,
     ty::InferenceResult,
-    AstIdMap, Body, ExprScopes, FileId, PackageId, PackageSet, Struct, TypeAlias,
+    AstIdMap, Body, Const, ConstEvalError, ConstValue, Enum, ExprScopes, FileId, Impl, PackageId,
+    PackageSet, Struct, TypeAlias,
 };
 use mun_paths::RelativePathBuf;
 use mun_syntax::{ast, Parse, SourceFile};
@@ -80,7 +84,17 @@ pub trait InternDatabase: SourceDatabase {
     #[salsa::interned]
     fn intern_struct(&self, loc: ids::StructLoc) -> ids::StructId;
     #[salsa::interned]
+    fn intern_enum(&self, loc: ids::EnumLoc) -> ids::EnumId;
+    #[salsa::interned]
     fn intern_type_alias(&self, loc: ids::TypeAliasLoc) -> ids::TypeAliasId;
+    #[salsa::interned]
+    fn intern_impl(&self, loc: ids::ImplLoc) -> ids::ImplId;
+    #[salsa::interned]
+    fn intern_trait(&self, loc: ids::TraitLoc) -> ids::TraitId;
+    #[salsa::interned]
+    fn intern_const(&self, loc: ids::ConstLoc) -> ids::ConstId;
+    #[salsa::interned]
+    fn intern_static(&self, loc: ids::StaticLoc) -> ids::StaticId;
 }
 
 #[salsa::query_group(DefDatabaseStorage)]
@@ -93,12 +107,27 @@ pub trait DefDatabase: InternDatabase + AstDatabase + Upcast<dyn AstDatabase> {
     #[salsa::invoke(StructData::struct_data_query)]
     fn struct_data(&self, id: ids::StructId) -> Arc<StructData>;
 
+    #[salsa::invoke(EnumData::enum_data_query)]
+    fn enum_data(&self, id: ids::EnumId) -> Arc<EnumData>;
+
     #[salsa::invoke(TypeAliasData::type_alias_data_query)]
     fn type_alias_data(&self, id: ids::TypeAliasId) -> Arc<TypeAliasData>;
 
     #[salsa::invoke(crate::FunctionData::fn_data_query)]
     fn fn_data(&self, func: FunctionId) -> Arc<FunctionData>;
 
+    #[salsa::invoke(ImplData::impl_data_query)]
+    fn impl_data(&self, id: ids::ImplId) -> Arc<ImplData>;
+
+    #[salsa::invoke(TraitData::trait_data_query)]
+    fn trait_data(&self, id: ids::TraitId) -> Arc<TraitData>;
+
+    #[salsa::invoke(ConstData::const_data_query)]
+    fn const_data(&self, id: ids::ConstId) -> Arc<ConstData>;
+
+    #[salsa::invoke(StaticData::static_data_query)]
+    fn static_data(&self, id: ids::StaticId) -> Arc<StaticData>;
+
     /// Returns the `PackageDefs` for the specified `PackageId`. The `PackageDefs` contains all
     /// resolved items defined for every module in the package.
     #[salsa::invoke(crate::package_defs::PackageDefs::package_def_map_query)]
@@ -130,12 +159,31 @@ pub trait HirDatabase: DefDatabase + Upcast<dyn DefDatabase> {
     #[salsa::invoke(crate::ty::lower::lower_struct_query)]
     fn lower_struct(&self, def: Struct) -> Arc<LowerTyMap>;
 
+    #[salsa::invoke(crate::ty::lower::lower_enum_query)]
+    fn lower_enum(&self, def: Enum) -> Arc<LowerTyMap>;
+
     #[salsa::invoke(crate::ty::lower::lower_type_alias_query)]
     fn lower_type_alias(&self, def: TypeAlias) -> Arc<LowerTyMap>;
 
+    #[salsa::invoke(crate::ty::lower::lower_impl_query)]
+    fn lower_impl(&self, def: Impl) -> Arc<LowerTyMap>;
+
+    /// Returns all the inherent `impl` blocks of the structs defined in the specified package.
+    #[salsa::invoke(crate::ty::InherentImpls::inherent_impls_in_package_query)]
+    fn inherent_impls_in_package(&self, package: PackageId) -> Arc<InherentImpls>;
+
+    /// Returns all the implementations of traits for the structs defined in the specified package.
+    #[salsa::invoke(crate::ty::TraitImpls::trait_impls_in_package_query)]
+    fn trait_impls_in_package(&self, package: PackageId) -> Arc<TraitImpls>;
+
     #[salsa::invoke(crate::ty::callable_item_sig)]
     fn callable_sig(&self, def: CallableDef) -> FnSig;
 
+    /// Returns the value of the specified constant.
+    #[salsa::invoke(crate::const_eval::const_eval_query)]
+    #[salsa::cycle(crate::const_eval::const_eval_cycle_recover)]
+    fn const_eval(&self, def: Const) -> Result<ConstValue, ConstEvalError>;
+
     #[salsa::invoke(crate::ty::type_for_def)]
     #[salsa::cycle(crate::ty::type_for_cycle_recover)]
     fn type_for_def(&self, def: TypableDef, ns: Namespace) -> (Ty, bool);
@@ -1,6 +1,6 @@
 use crate::code_model::StructKind;
 use crate::in_file::InFile;
-use crate::{FileId, HirDatabase, IntTy, Name, Ty};
+use crate::{FileId, HirDatabase, IntTy, IntrinsicFunction, Name, Ty};
 use mun_syntax::{ast, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr, TextRange};
 use std::{any::Any, fmt};
 
@@ -137,6 +137,61 @@ impl Diagnostic for CyclicType {
     }
 }
 
+#[derive(Debug)]
+pub struct WrongNumberOfTypeArgs {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Diagnostic for WrongNumberOfTypeArgs {
+    fn message(&self) -> String {
+        format!(
+            "expected {} type argument{}, found {}",
+            self.expected,
+            if self.expected == 1 { "" } else { "s" },
+            self.found
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when a generic function is used with type arguments that do not
+/// support an operator that the function applies to its type parameters, e.g. when
+/// `fn max<T>(a: T, b: T) -> T` compares its arguments and is called with a struct that does not
+/// overload the comparison.
+#[derive(Debug)]
+pub struct UnsupportedTypeArguments {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for UnsupportedTypeArguments {
+    fn message(&self) -> String {
+        format!(
+            "the type arguments of `{}` do not support the operators that are applied to its type parameters",
+            self.name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct PrivateAccess {
     pub file: FileId,
@@ -293,6 +348,53 @@ impl Diagnostic for InvalidLhs {
     }
 }
 
+/// An assignment to a local binding of an enclosing function from within a closure. Closures
+/// capture bindings by value, so the assignment would not be visible outside of the closure.
+#[derive(Debug)]
+pub struct CannotAssignToCapturedVariable {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for CannotAssignToCapturedVariable {
+    fn message(&self) -> String {
+        format!(
+            "cannot assign to `{}` because it is captured by a closure",
+            self.name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// The type of a binding could not be inferred from the way it is used.
+#[derive(Debug)]
+pub struct TypeAnnotationsNeeded {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+}
+
+impl Diagnostic for TypeAnnotationsNeeded {
+    fn message(&self) -> String {
+        "type annotations needed".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct MissingElseBranch {
     pub file: FileId,
@@ -379,6 +481,62 @@ impl Diagnostic for DuplicateDefinition {
     }
 }
 
+#[derive(Debug)]
+pub struct UnknownAttribute {
+    pub attr: InFile<AstPtr<ast::Attr>>,
+    pub name: String,
+}
+
+impl Diagnostic for UnknownAttribute {
+    fn message(&self) -> String {
+        format!("cannot find attribute `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.attr.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// The part of the signature of a `#[test]` function that prevents the test runner from invoking
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidTestSignatureKind {
+    TypeParams,
+    Params,
+    ReturnType,
+}
+
+/// An error that is reported when a function marked with `#[test]` cannot be invoked by the test
+/// runner, e.g. `#[test] fn foo(a: i32) {}`.
+#[derive(Debug)]
+pub struct InvalidTestSignature {
+    pub signature: InFile<SyntaxNodePtr>,
+    pub kind: InvalidTestSignatureKind,
+}
+
+impl Diagnostic for InvalidTestSignature {
+    fn message(&self) -> String {
+        match self.kind {
+            InvalidTestSignatureKind::TypeParams => "test functions cannot be generic",
+            InvalidTestSignatureKind::Params => "test functions cannot have parameters",
+            InvalidTestSignatureKind::ReturnType => "test functions must return `()`",
+        }
+        .to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.signature.clone()
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct ReturnMissingExpression {
     pub file: FileId,
@@ -420,18 +578,18 @@ impl Diagnostic for BreakOutsideLoop {
 }
 
 #[derive(Debug)]
-pub struct BreakWithValueOutsideLoop {
+pub struct ContinueOutsideLoop {
     pub file: FileId,
-    pub break_expr: SyntaxNodePtr,
+    pub continue_expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for BreakWithValueOutsideLoop {
+impl Diagnostic for ContinueOutsideLoop {
     fn message(&self) -> String {
-        "`break` with value can only appear in a `loop`".to_owned()
+        "`continue` outside of a loop".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.break_expr.clone())
+        InFile::new(self.file, self.continue_expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -440,16 +598,15 @@ impl Diagnostic for BreakWithValueOutsideLoop {
 }
 
 #[derive(Debug)]
-pub struct AccessUnknownField {
+pub struct UnresolvedLabel {
     pub file: FileId,
     pub expr: SyntaxNodePtr,
-    pub receiver_ty: Ty,
-    pub name: Name,
+    pub label: Name,
 }
 
-impl Diagnostic for AccessUnknownField {
+impl Diagnostic for UnresolvedLabel {
     fn message(&self) -> String {
-        "attempted to access a non-existent field in a struct.".to_string()
+        format!("use of undeclared label `{}`", self.label)
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -461,23 +618,18 @@ impl Diagnostic for AccessUnknownField {
     }
 }
 
+/// An error that is emitted for a `for` loop that breaks with a value that cannot be optional.
+/// Because a `for` loop evaluates to `nil` if it finishes without breaking, it can only break with
+/// a value of which the type can be made optional.
 #[derive(Debug)]
-pub struct FieldCountMismatch {
+pub struct InvalidForBreakValue {
     pub file: FileId,
     pub expr: SyntaxNodePtr,
-    pub expected: usize,
-    pub found: usize,
 }
 
-impl Diagnostic for FieldCountMismatch {
+impl Diagnostic for InvalidForBreakValue {
     fn message(&self) -> String {
-        format!(
-            "this tuple struct literal has {} field{} but {} field{} supplied",
-            self.expected,
-            if self.expected == 1 { "" } else { "s" },
-            self.found,
-            if self.found == 1 { " was" } else { "s were" },
-        )
+        "a `for` loop evaluates to `nil` if it finishes without breaking, so it can only break with a garbage collected struct, an array or an optional value".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -490,25 +642,18 @@ impl Diagnostic for FieldCountMismatch {
 }
 
 #[derive(Debug)]
-pub struct MissingFields {
+pub struct BreakWithValueOutsideLoop {
     pub file: FileId,
-    pub fields: SyntaxNodePtr,
-    pub struct_ty: Ty,
-    pub field_names: Vec<Name>,
+    pub break_expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for MissingFields {
+impl Diagnostic for BreakWithValueOutsideLoop {
     fn message(&self) -> String {
-        use std::fmt::Write;
-        let mut message = "missing record fields:\n".to_string();
-        for field in &self.field_names {
-            writeln!(message, "- {}", field).unwrap();
-        }
-        message
+        "`break` with value can only appear in a `loop` or a `for` loop".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.fields.clone())
+        InFile::new(self.file, self.break_expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -517,19 +662,15 @@ impl Diagnostic for MissingFields {
 }
 
 #[derive(Debug)]
-pub struct MismatchedStructLit {
+pub struct NotIterable {
     pub file: FileId,
     pub expr: SyntaxNodePtr,
-    pub expected: StructKind,
-    pub found: StructKind,
+    pub ty: Ty,
 }
 
-impl Diagnostic for MismatchedStructLit {
+impl Diagnostic for NotIterable {
     fn message(&self) -> String {
-        format!(
-            "mismatched struct literal kind. expected `{}`, found `{}`",
-            self.expected, self.found
-        )
+        "`for` loops can only iterate over arrays and ranges".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -542,38 +683,39 @@ impl Diagnostic for MismatchedStructLit {
 }
 
 #[derive(Debug)]
-pub struct NoFields {
+pub struct RangeOutsideForLoop {
     pub file: FileId,
-    pub receiver_expr: SyntaxNodePtr,
-    pub found: Ty,
+    pub range_expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for NoFields {
+impl Diagnostic for RangeOutsideForLoop {
     fn message(&self) -> String {
-        "attempted to access a field on a primitive type.".to_string()
+        "range expressions can only appear as the iterable of a `for` loop".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.receiver_expr.clone())
+        InFile::new(self.file, self.range_expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
         self
     }
 }
+
 #[derive(Debug)]
-pub struct NoSuchField {
+pub struct InvalidRangeBound {
     pub file: FileId,
-    pub field: SyntaxNodePtr,
+    pub expr: SyntaxNodePtr,
+    pub ty: Ty,
 }
 
-impl Diagnostic for NoSuchField {
+impl Diagnostic for InvalidRangeBound {
     fn message(&self) -> String {
-        "no such field".to_string()
+        "range bounds must be integers".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.field.clone())
+        InFile::new(self.file, self.expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -582,18 +724,20 @@ impl Diagnostic for NoSuchField {
 }
 
 #[derive(Debug)]
-pub struct PossiblyUninitializedVariable {
+pub struct AccessUnknownField {
     pub file: FileId,
-    pub pat: SyntaxNodePtr,
+    pub expr: SyntaxNodePtr,
+    pub receiver_ty: Ty,
+    pub name: Name,
 }
 
-impl Diagnostic for PossiblyUninitializedVariable {
+impl Diagnostic for AccessUnknownField {
     fn message(&self) -> String {
-        "use of possibly-uninitialized variable".to_string()
+        "attempted to access a non-existent field in a struct.".to_string()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.pat.clone())
+        InFile::new(self.file, self.expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -602,17 +746,20 @@ impl Diagnostic for PossiblyUninitializedVariable {
 }
 
 #[derive(Debug)]
-pub struct ExternCannotHaveBody {
-    pub func: InFile<SyntaxNodePtr>,
+pub struct MethodNotFound {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub receiver_ty: Ty,
+    pub name: Name,
 }
 
-impl Diagnostic for ExternCannotHaveBody {
+impl Diagnostic for MethodNotFound {
     fn message(&self) -> String {
-        "extern functions cannot have bodies".to_string()
+        format!("no method named `{}` found", self.name)
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.func.clone()
+        InFile::new(self.file, self.expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -621,17 +768,20 @@ impl Diagnostic for ExternCannotHaveBody {
 }
 
 #[derive(Debug)]
-pub struct ExternNonPrimitiveParam {
-    pub param: InFile<SyntaxNodePtr>,
+pub struct InvalidCast {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub expr_ty: Ty,
+    pub cast_ty: Ty,
 }
 
-impl Diagnostic for ExternNonPrimitiveParam {
+impl Diagnostic for InvalidCast {
     fn message(&self) -> String {
-        "extern functions can only have primitives as parameter- and return types".to_string()
+        "invalid cast".to_string()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.param.clone()
+        InFile::new(self.file, self.expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -639,19 +789,27 @@ impl Diagnostic for ExternNonPrimitiveParam {
     }
 }
 
-/// An error that is emitted if a literal is too large to even parse
 #[derive(Debug)]
-pub struct IntLiteralTooLarge {
-    pub literal: InFile<AstPtr<ast::Literal>>,
+pub struct FieldCountMismatch {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub expected: usize,
+    pub found: usize,
 }
 
-impl Diagnostic for IntLiteralTooLarge {
+impl Diagnostic for FieldCountMismatch {
     fn message(&self) -> String {
-        "int literal is too large".to_owned()
+        format!(
+            "this tuple struct literal has {} field{} but {} field{} supplied",
+            self.expected,
+            if self.expected == 1 { "" } else { "s" },
+            self.found,
+            if self.found == 1 { " was" } else { "s were" },
+        )
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.clone().map(|ptr| ptr.into())
+        InFile::new(self.file, self.expr.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -659,20 +817,21 @@ impl Diagnostic for IntLiteralTooLarge {
     }
 }
 
-/// An error that is emitted if a literal is too large for its suffix
+/// An error that is emitted for a range pattern whose start is larger than its end, e.g.
+/// `5..=1`, which does not match any value.
 #[derive(Debug)]
-pub struct LiteralOutOfRange {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub int_ty: IntTy,
+pub struct EmptyRangePat {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
 }
 
-impl Diagnostic for LiteralOutOfRange {
+impl Diagnostic for EmptyRangePat {
     fn message(&self) -> String {
-        format!("literal out of range for `{}`", self.int_ty.as_str())
+        "lower range bound must be less than or equal to upper".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.clone().map(|ptr| ptr.into())
+        InFile::new(self.file, self.pat.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -680,20 +839,27 @@ impl Diagnostic for LiteralOutOfRange {
     }
 }
 
-/// An error that is emitted for a literal with an invalid suffix (e.g. `123_foo`)
 #[derive(Debug)]
-pub struct InvalidLiteralSuffix {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub suffix: SmolStr,
+pub struct TuplePatLengthMismatch {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+    pub expected: usize,
+    pub found: usize,
 }
 
-impl Diagnostic for InvalidLiteralSuffix {
+impl Diagnostic for TuplePatLengthMismatch {
     fn message(&self) -> String {
-        format!("invalid suffix `{}`", self.suffix)
+        format!(
+            "mismatched types: expected a tuple with {} element{}, found one with {} element{}",
+            self.expected,
+            if self.expected == 1 { "" } else { "s" },
+            self.found,
+            if self.found == 1 { "" } else { "s" },
+        )
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.clone().map(|ptr| ptr.into())
+        InFile::new(self.file, self.pat.clone())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -701,22 +867,233 @@ impl Diagnostic for InvalidLiteralSuffix {
     }
 }
 
-/// An error that is emitted for a literal with a floating point suffix with a non 10 base (e.g.
-/// `0x123_f32`)
 #[derive(Debug)]
-pub struct InvalidFloatingPointLiteral {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub base: u32,
-}
-
-impl Diagnostic for InvalidFloatingPointLiteral {
+pub struct MissingFields {
+    pub file: FileId,
+    pub fields: SyntaxNodePtr,
+    pub struct_ty: Ty,
+    pub field_names: Vec<Name>,
+}
+
+impl Diagnostic for MissingFields {
     fn message(&self) -> String {
-        match self.base {
-            2 => "binary float literal is not supported".to_owned(),
-            8 => "octal float literal is not supported".to_owned(),
-            16 => "hexadecimal float literal is not supported".to_owned(),
-            _ => "unsupported base for floating pointer literal".to_owned(),
+        use std::fmt::Write;
+        let mut message = "missing record fields:\n".to_string();
+        for field in &self.field_names {
+            writeln!(message, "- {}", field).unwrap();
         }
+        message
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.fields.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if the arms of a match expression do not cover all possible values of
+/// the scrutinee.
+#[derive(Debug)]
+pub struct MissingMatchArms {
+    pub file: FileId,
+    pub scrutinee: SyntaxNodePtr,
+    pub uncovered_patterns: Vec<String>,
+}
+
+impl Diagnostic for MissingMatchArms {
+    fn message(&self) -> String {
+        format!(
+            "non-exhaustive patterns: {} not covered",
+            format_uncovered_patterns(&self.uncovered_patterns)
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.scrutinee.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if the pattern of a let statement or a function parameter does not
+/// match all possible values.
+#[derive(Debug)]
+pub struct RefutablePattern {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+    pub uncovered_patterns: Vec<String>,
+}
+
+impl Diagnostic for RefutablePattern {
+    fn message(&self) -> String {
+        format!(
+            "refutable pattern in binding: {} not covered",
+            format_uncovered_patterns(&self.uncovered_patterns)
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// Formats a list of patterns as "`a`, `b` and `c`". At most three patterns are listed.
+fn format_uncovered_patterns(patterns: &[String]) -> String {
+    const MAX_LISTED: usize = 3;
+    let listed: Vec<String> = patterns
+        .iter()
+        .take(MAX_LISTED)
+        .map(|pat| format!("`{pat}`"))
+        .collect();
+    match listed.split_last() {
+        None => "patterns".to_owned(),
+        Some((_, [])) if patterns.len() == 1 => listed[0].clone(),
+        _ if patterns.len() > MAX_LISTED => format!("{} and more", listed.join(", ")),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+#[derive(Debug)]
+pub struct MismatchedStructLit {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub expected: StructKind,
+    pub found: StructKind,
+}
+
+impl Diagnostic for MismatchedStructLit {
+    fn message(&self) -> String {
+        format!(
+            "mismatched struct literal kind. expected `{}`, found `{}`",
+            self.expected, self.found
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct NoFields {
+    pub file: FileId,
+    pub receiver_expr: SyntaxNodePtr,
+    pub found: Ty,
+}
+
+impl Diagnostic for NoFields {
+    fn message(&self) -> String {
+        "attempted to access a field on a primitive type.".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.receiver_expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+#[derive(Debug)]
+pub struct NoSuchField {
+    pub file: FileId,
+    pub field: SyntaxNodePtr,
+}
+
+impl Diagnostic for NoSuchField {
+    fn message(&self) -> String {
+        "no such field".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.field.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct PossiblyUninitializedVariable {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+}
+
+impl Diagnostic for PossiblyUninitializedVariable {
+    fn message(&self) -> String {
+        "use of possibly-uninitialized variable".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ExternCannotHaveBody {
+    pub func: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for ExternCannotHaveBody {
+    fn message(&self) -> String {
+        "extern functions cannot have bodies".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.func.clone()
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ExternNonPrimitiveParam {
+    pub param: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for ExternNonPrimitiveParam {
+    fn message(&self) -> String {
+        "extern functions can only have primitives as parameter- and return types".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.param.clone()
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if a literal is too large to even parse
+#[derive(Debug)]
+pub struct IntLiteralTooLarge {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+}
+
+impl Diagnostic for IntLiteralTooLarge {
+    fn message(&self) -> String {
+        "int literal is too large".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -728,15 +1105,16 @@ impl Diagnostic for InvalidFloatingPointLiteral {
     }
 }
 
-/// An error that is emitted for a malformed literal (e.g. `0b22222`)
+/// An error that is emitted if a literal is too large for its suffix
 #[derive(Debug)]
-pub struct InvalidLiteral {
+pub struct LiteralOutOfRange {
     pub literal: InFile<AstPtr<ast::Literal>>,
+    pub int_ty: IntTy,
 }
 
-impl Diagnostic for InvalidLiteral {
+impl Diagnostic for LiteralOutOfRange {
     fn message(&self) -> String {
-        "invalid literal value".to_owned()
+        format!("literal out of range for `{}`", self.int_ty.as_str())
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -748,18 +1126,20 @@ impl Diagnostic for InvalidLiteral {
     }
 }
 
+/// An error that is emitted for a literal with an invalid suffix (e.g. `123_foo`)
 #[derive(Debug)]
-pub struct FreeTypeAliasWithoutTypeRef {
-    pub type_alias_def: InFile<SyntaxNodePtr>,
+pub struct InvalidLiteralSuffix {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+    pub suffix: SmolStr,
 }
 
-impl Diagnostic for FreeTypeAliasWithoutTypeRef {
+impl Diagnostic for InvalidLiteralSuffix {
     fn message(&self) -> String {
-        "free type alias without type ref".to_string()
+        format!("invalid suffix `{}`", self.suffix)
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.type_alias_def.clone()
+        self.literal.clone().map(|ptr| ptr.into())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -767,40 +1147,562 @@ impl Diagnostic for FreeTypeAliasWithoutTypeRef {
     }
 }
 
+/// An error that is emitted for a literal with a floating point suffix with a non 10 base (e.g.
+/// `0x123_f32`)
 #[derive(Debug)]
-pub struct UnresolvedImport {
-    pub use_tree: InFile<AstPtr<ast::UseTree>>,
+pub struct InvalidFloatingPointLiteral {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+    pub base: u32,
 }
 
-impl Diagnostic for UnresolvedImport {
+impl Diagnostic for InvalidFloatingPointLiteral {
     fn message(&self) -> String {
-        "unresolved import".to_string()
+        match self.base {
+            2 => "binary float literal is not supported".to_owned(),
+            8 => "octal float literal is not supported".to_owned(),
+            16 => "hexadecimal float literal is not supported".to_owned(),
+            _ => "unsupported base for floating pointer literal".to_owned(),
+        }
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.use_tree.clone().map(Into::into)
+        self.literal.clone().map(|ptr| ptr.into())
     }
 
-    fn as_any(&self) -> &(dyn Any + Send) {
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
         self
     }
 }
 
+/// An error that is emitted for a malformed literal (e.g. `0b22222`)
 #[derive(Debug)]
-pub struct ImportDuplicateDefinition {
-    pub use_tree: InFile<AstPtr<ast::UseTree>>,
+pub struct InvalidLiteral {
+    pub literal: InFile<AstPtr<ast::Literal>>,
 }
 
-impl Diagnostic for ImportDuplicateDefinition {
+impl Diagnostic for InvalidLiteral {
     fn message(&self) -> String {
-        "a second item with the same name imported. Try to use an alias.".to_string()
+        "invalid literal value".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.use_tree.clone().map(Into::into)
+        self.literal.clone().map(|ptr| ptr.into())
     }
 
-    fn as_any(&self) -> &(dyn Any + Send) {
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct FreeTypeAliasWithoutTypeRef {
+    pub type_alias_def: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for FreeTypeAliasWithoutTypeRef {
+    fn message(&self) -> String {
+        "free type alias without type ref".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.type_alias_def.clone()
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct UnresolvedImport {
+    pub use_tree: InFile<AstPtr<ast::UseTree>>,
+}
+
+impl Diagnostic for UnresolvedImport {
+    fn message(&self) -> String {
+        "unresolved import".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.use_tree.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportDuplicateDefinition {
+    pub use_tree: InFile<AstPtr<ast::UseTree>>,
+}
+
+impl Diagnostic for ImportDuplicateDefinition {
+    fn message(&self) -> String {
+        "a second item with the same name imported. Try to use an alias.".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.use_tree.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidSelfTyImpl {
+    pub impl_: InFile<AstPtr<ast::Impl>>,
+}
+
+impl Diagnostic for InvalidSelfTyImpl {
+    fn message(&self) -> String {
+        "inherent `impl` blocks can only be defined for structs declared in the same package"
+            .to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidSelfTyTraitImpl {
+    pub impl_: InFile<AstPtr<ast::Impl>>,
+}
+
+impl Diagnostic for InvalidSelfTyTraitImpl {
+    fn message(&self) -> String {
+        "traits can only be implemented for structs declared in the same package".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct UnresolvedTrait {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+}
+
+impl Diagnostic for UnresolvedTrait {
+    fn message(&self) -> String {
+        "undefined trait".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ExpectedTypeFoundTrait {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+}
+
+impl Diagnostic for ExpectedTypeFoundTrait {
+    fn message(&self) -> String {
+        "expected a type, found a trait; use `dyn` to refer to a trait object".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when an optional type wraps a type that cannot be `nil`, e.g. `?i32`.
+#[derive(Debug)]
+pub struct InvalidOptionalType {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+}
+
+impl Diagnostic for InvalidOptionalType {
+    fn message(&self) -> String {
+        "optional types can only wrap garbage collected structs or arrays".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct TraitFunctionWithBody {
+    pub function: InFile<AstPtr<ast::FunctionDef>>,
+}
+
+impl Diagnostic for TraitFunctionWithBody {
+    fn message(&self) -> String {
+        "trait functions cannot have bodies".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.function.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTraitFunction {
+    pub function: InFile<AstPtr<ast::FunctionDef>>,
+}
+
+impl Diagnostic for InvalidTraitFunction {
+    fn message(&self) -> String {
+        "trait functions must take `self: Self` as their first parameter and cannot use `Self` or \
+         type parameters elsewhere"
+            .to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.function.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingTraitFunctions {
+    pub impl_: InFile<AstPtr<ast::Impl>>,
+    pub trait_name: String,
+    pub functions: Vec<Name>,
+}
+
+impl Diagnostic for MissingTraitFunctions {
+    fn message(&self) -> String {
+        use std::fmt::Write;
+        let mut message = format!(
+            "missing functions of trait `{}` in implementation:\n",
+            self.trait_name
+        );
+        for function in &self.functions {
+            writeln!(message, "- {}", function).unwrap();
+        }
+        message
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct NotATraitMember {
+    pub function: InFile<AstPtr<ast::FunctionDef>>,
+    pub name: Name,
+    pub trait_name: String,
+}
+
+impl Diagnostic for NotATraitMember {
+    fn message(&self) -> String {
+        format!(
+            "function `{}` is not a member of trait `{}`",
+            self.name, self.trait_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.function.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct TraitFunctionSignatureMismatch {
+    pub function: InFile<AstPtr<ast::FunctionDef>>,
+    pub name: Name,
+    pub trait_name: String,
+}
+
+impl Diagnostic for TraitFunctionSignatureMismatch {
+    fn message(&self) -> String {
+        format!(
+            "the signature of `{}` does not match its declaration in trait `{}`",
+            self.name, self.trait_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.function.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ConflictingTraitImpl {
+    pub impl_: InFile<AstPtr<ast::Impl>>,
+    pub trait_name: String,
+    pub self_ty: String,
+}
+
+impl Diagnostic for ConflictingTraitImpl {
+    fn message(&self) -> String {
+        format!(
+            "conflicting implementations of trait `{}` for type `{}`",
+            self.trait_name, self.self_ty
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when assigning to a `static` that is not declared `mut`.
+#[derive(Debug)]
+pub struct CannotAssignToImmutableStatic {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for CannotAssignToImmutableStatic {
+    fn message(&self) -> String {
+        format!(
+            "cannot assign to immutable static `{}`; declare it with `static mut`",
+            self.name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when the initializer of a `const` or `static` contains an
+/// expression that cannot be evaluated at compile time.
+#[derive(Debug)]
+pub struct NonConstantExpression {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for NonConstantExpression {
+    fn message(&self) -> String {
+        "expression cannot be evaluated at compile time".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ConstEvalOverflow {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for ConstEvalOverflow {
+    fn message(&self) -> String {
+        "evaluation of constant expression overflowed".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ConstEvalDivisionByZero {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for ConstEvalDivisionByZero {
+    fn message(&self) -> String {
+        "attempt to divide by zero in constant expression".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when the value of a constant depends on itself.
+#[derive(Debug)]
+pub struct CyclicConstant {
+    pub file: FileId,
+    pub definition: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for CyclicConstant {
+    fn message(&self) -> String {
+        format!("cycle detected when evaluating constant `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.definition.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when the value of an optional type is used without first checking
+/// whether it is `nil`, e.g. `a.b` where `a` is of type `?Foo`.
+#[derive(Debug)]
+pub struct PossiblyNilAccess {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub ty: Ty,
+}
+
+impl Diagnostic for PossiblyNilAccess {
+    fn message(&self) -> String {
+        "value of an optional type may be `nil`; unwrap it with `if let` first".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when the value of a `let` condition is not of an optional type, e.g.
+/// `if let a = 5 {}`.
+#[derive(Debug)]
+pub struct ExpectedOptionalType {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub found: Ty,
+}
+
+impl Diagnostic for ExpectedOptionalType {
+    fn message(&self) -> String {
+        "expected a value of an optional type".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when a function of the `core::math` module is applied to a value of a
+/// type it does not support, e.g. `core::math::sqrt(true)`.
+#[derive(Debug)]
+pub struct UnsupportedIntrinsicArgument {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub function: IntrinsicFunction,
+    pub found: Ty,
+}
+
+impl Diagnostic for UnsupportedIntrinsicArgument {
+    fn message(&self) -> String {
+        if self.function.supports_integers() {
+            format!(
+                "`core::math::{}` can only be applied to integers and floating-point numbers",
+                self.function
+            )
+        } else {
+            format!(
+                "`core::math::{}` can only be applied to floating-point numbers",
+                self.function
+            )
+        }
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is reported when a built-in function is used as a value instead of being called,
+/// e.g. `let f = core::math::sqrt;`.
+#[derive(Debug)]
+pub struct IntrinsicFunctionAsValue {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub function: IntrinsicFunction,
+}
+
+impl Diagnostic for IntrinsicFunctionAsValue {
+    fn message(&self) -> String {
+        format!(
+            "`{}` is an intrinsic function and can only be called directly",
+            self.function.path()
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
         self
     }
 }
@@ -6,17 +6,17 @@ use crate::{
     diagnostics::DiagnosticSink,
     ids::{DefWithBodyId, Lookup},
     in_file::InFile,
-    name::AsName,
+    name::{name, AsName},
     primitive_type::{PrimitiveFloat, PrimitiveInt},
     type_ref::{LocalTypeRefId, TypeRef, TypeRefMap, TypeRefMapBuilder, TypeRefSourceMap},
-    DefDatabase, FileId, HirDatabase, Name, Path,
+    DefDatabase, FileId, HirDatabase, Name, Path, PathKind,
 };
 
 use either::Either;
 pub use mun_syntax::ast::PrefixOp as UnaryOp;
 use mun_syntax::{
     ast,
-    ast::{ArgListOwner, BinOp, LoopBodyOwner, NameOwner, TypeAscriptionOwner},
+    ast::{ArgListOwner, BinOp, LoopBodyOwner, NameOwner, RangeOp, TypeAscriptionOwner},
     AstNode, AstPtr,
 };
 use rustc_hash::FxHashMap;
@@ -74,6 +74,18 @@ impl Body {
                 collector = ExprCollector::new(def, src.file_id, db);
                 collector.collect_fn_body(&src.value)
             }
+            DefWithBodyId::ConstId(c) => {
+                let c = c.lookup(db);
+                let src = c.source(db);
+                collector = ExprCollector::new(def, src.file_id, db);
+                collector.collect_initializer(src.value.body(), src.value.ascribed_type())
+            }
+            DefWithBodyId::StaticId(s) => {
+                let s = s.lookup(db);
+                let src = s.source(db);
+                collector = ExprCollector::new(def, src.file_id, db);
+                collector.collect_initializer(src.value.body(), src.value.ascribed_type())
+            }
         }
 
         let (body, source_map) = collector.finish();
@@ -221,6 +233,8 @@ pub enum Statement {
 pub enum Literal {
     String(String),
     Bool(bool),
+    /// The absence of a value of an optional type
+    Nil,
     Int(LiteralInt),
     Float(LiteralFloat),
 }
@@ -277,6 +291,11 @@ pub enum Expr {
         callee: ExprId,
         args: Vec<ExprId>,
     },
+    MethodCall {
+        receiver: ExprId,
+        method_name: Name,
+        args: Vec<ExprId>,
+    },
     Path(Path),
     If {
         condition: ExprId,
@@ -296,6 +315,10 @@ pub enum Expr {
         base: ExprId,
         index: ExprId,
     },
+    Cast {
+        expr: ExprId,
+        type_ref: LocalTypeRefId,
+    },
     Block {
         statements: Vec<Statement>,
         tail: Option<ExprId>,
@@ -305,13 +328,30 @@ pub enum Expr {
     },
     Break {
         expr: Option<ExprId>,
+        label: Option<Name>,
+    },
+    Continue {
+        label: Option<Name>,
     },
     Loop {
         body: ExprId,
+        label: Option<Name>,
     },
     While {
         condition: ExprId,
         body: ExprId,
+        label: Option<Name>,
+    },
+    For {
+        pat: PatId,
+        iterable: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+    },
+    Range {
+        start: ExprId,
+        end: ExprId,
+        inclusive: bool,
     },
     RecordLit {
         type_id: LocalTypeRefId,
@@ -323,7 +363,36 @@ pub enum Expr {
         name: Name,
     },
     Array(Vec<ExprId>),
+    /// A tuple expression, e.g. `(a, 1.0)`
+    Tuple {
+        exprs: Vec<ExprId>,
+    },
     Literal(Literal),
+    /// The condition of an `if let` or `while let` expression, e.g. `let a = b`. Evaluates to
+    /// `true` if the value of the optional `expr` is not `nil`, in which case the inner value is
+    /// bound to `pat`.
+    Let {
+        pat: PatId,
+        expr: ExprId,
+    },
+    Match {
+        expr: ExprId,
+        arms: Vec<MatchArm>,
+    },
+    /// A closure, e.g. `|a: i32, b| a + b`. The types of the parameters and the return type are
+    /// optional.
+    Closure {
+        args: Vec<PatId>,
+        arg_types: Vec<Option<LocalTypeRefId>>,
+        ret_type: Option<LocalTypeRefId>,
+        body: ExprId,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MatchArm {
+    pub pat: PatId,
+    pub expr: ExprId,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -392,14 +461,21 @@ impl Expr {
                     f(*arg);
                 }
             }
+            Expr::MethodCall { receiver, args, .. } => {
+                f(*receiver);
+                for arg in args {
+                    f(*arg);
+                }
+            }
             Expr::BinaryOp { lhs, rhs, .. } => {
                 f(*lhs);
                 f(*rhs);
             }
-            Expr::Field { expr, .. } | Expr::UnaryOp { expr, .. } => {
+            Expr::Field { expr, .. } | Expr::UnaryOp { expr, .. } | Expr::Let { expr, .. } => {
                 f(*expr);
             }
             Expr::Literal(_) => {}
+            Expr::Continue { .. } => {}
             Expr::If {
                 condition,
                 then_branch,
@@ -416,18 +492,28 @@ impl Expr {
                     f(*expr);
                 }
             }
-            Expr::Break { expr } => {
+            Expr::Break { expr, .. } => {
                 if let Some(expr) = expr {
                     f(*expr);
                 }
             }
-            Expr::Loop { body } => {
+            Expr::Loop { body, .. } => {
                 f(*body);
             }
-            Expr::While { condition, body } => {
+            Expr::While {
+                condition, body, ..
+            } => {
                 f(*condition);
                 f(*body);
             }
+            Expr::For { iterable, body, .. } => {
+                f(*iterable);
+                f(*body);
+            }
+            Expr::Range { start, end, .. } => {
+                f(*start);
+                f(*end);
+            }
             Expr::RecordLit { fields, spread, .. } => {
                 for field in fields {
                     f(field.expr);
@@ -440,26 +526,76 @@ impl Expr {
                 f(*base);
                 f(*index);
             }
-            Expr::Array(exprs) => {
+            Expr::Cast { expr, .. } => {
+                f(*expr);
+            }
+            Expr::Array(exprs) | Expr::Tuple { exprs } => {
                 for expr in exprs {
                     f(*expr);
                 }
             }
+            Expr::Match { expr, arms } => {
+                f(*expr);
+                for arm in arms {
+                    f(arm.expr);
+                }
+            }
+            Expr::Closure { body, .. } => {
+                f(*body);
+            }
         }
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RecordFieldPat {
+    pub name: Name,
+    pub pat: PatId,
+}
+
 /// Similar to `ast::PatKind`
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Pat {
-    Missing,             // Indicates an error
-    Wild,                // `_`
-    Path(Path),          // E.g. `foo::bar`
-    Bind { name: Name }, // E.g. `a`
+    Missing,    // Indicates an error
+    Wild,       // `_`
+    Path(Path), // E.g. `foo::bar`
+    Bind {
+        name: Name,
+    }, // E.g. `a`
+    Lit {
+        expr: ExprId,
+        negated: bool,
+    }, // E.g. `5`, `-5` or `true`
+    Range {
+        start: PatId,
+        end: PatId,
+    }, // E.g. `1..=5`
+    TupleStruct {
+        path: Path,
+        args: Vec<PatId>,
+    }, // E.g. `Foo::Bar(a, _)`
+    Tuple {
+        args: Vec<PatId>,
+    }, // E.g. `(a, _)`
+    Record {
+        path: Path,
+        args: Vec<RecordFieldPat>,
+        ellipsis: bool,
+    }, // E.g. `Foo { a, b: 5, .. }`
 }
 
 impl Pat {
-    pub fn walk_child_pats(&self, mut _f: impl FnMut(PatId)) {}
+    pub fn walk_child_pats(&self, mut f: impl FnMut(PatId)) {
+        match self {
+            Pat::Missing | Pat::Wild | Pat::Path(_) | Pat::Bind { .. } | Pat::Lit { .. } => {}
+            Pat::Range { start, end } => {
+                f(*start);
+                f(*end);
+            }
+            Pat::TupleStruct { args, .. } | Pat::Tuple { args } => args.iter().copied().for_each(f),
+            Pat::Record { args, .. } => args.iter().map(|field| field.pat).for_each(f),
+        }
+    }
 }
 
 // Queries
@@ -555,6 +691,14 @@ impl<'a> ExprCollector<'a> {
         self.ret_type = Some(ret_type);
     }
 
+    /// Collects the initializer of a `const` or `static`. The type of the item is used as the
+    /// return type of the body.
+    fn collect_initializer(&mut self, expr: Option<ast::Expr>, type_ref: Option<ast::TypeRef>) {
+        let body = self.collect_expr_opt(expr);
+        self.body_expr = Some(body);
+        self.ret_type = Some(self.type_ref_builder.alloc_from_node_opt(type_ref.as_ref()));
+    }
+
     fn collect_block_opt(&mut self, block: Option<ast::BlockExpr>) -> ExprId {
         if let Some(block) = block {
             self.collect_block(block)
@@ -610,14 +754,33 @@ impl<'a> ExprCollector<'a> {
         match expr.kind() {
             ast::ExprKind::LoopExpr(expr) => self.collect_loop(expr),
             ast::ExprKind::WhileExpr(expr) => self.collect_while(expr),
+            ast::ExprKind::ForExpr(expr) => self.collect_for(expr),
+            ast::ExprKind::RangeExpr(e) => {
+                let start = self.collect_expr_opt(e.start());
+                let end = self.collect_expr_opt(e.end());
+                let inclusive = e.op_kind() == Some(RangeOp::Inclusive);
+                self.alloc_expr(
+                    Expr::Range {
+                        start,
+                        end,
+                        inclusive,
+                    },
+                    syntax_ptr,
+                )
+            }
             ast::ExprKind::ReturnExpr(r) => self.collect_return(r),
             ast::ExprKind::BreakExpr(r) => self.collect_break(r),
+            ast::ExprKind::ContinueExpr(r) => {
+                let label = r.lifetime_token().map(|t| Name::new(t.text()));
+                self.alloc_expr(Expr::Continue { label }, syntax_ptr)
+            }
             ast::ExprKind::BlockExpr(b) => self.collect_block(b),
             ast::ExprKind::Literal(e) => match e.kind() {
                 ast::LiteralKind::Bool(value) => {
                     let lit = Literal::Bool(value);
                     self.alloc_expr(Expr::Literal(lit), syntax_ptr)
                 }
+                ast::LiteralKind::Nil => self.alloc_expr(Expr::Literal(Literal::Nil), syntax_ptr),
                 ast::LiteralKind::IntNumber(lit) => {
                     let (text, suffix) = lit.split_into_parts();
                     let (lit, errors) = integer_lit(text, suffix);
@@ -642,8 +805,8 @@ impl<'a> ExprCollector<'a> {
 
                     expr_id
                 }
-                ast::LiteralKind::String(_lit) => {
-                    let lit = Literal::String(Default::default());
+                ast::LiteralKind::String(lit) => {
+                    let lit = Literal::String(lit.value().unwrap_or_default());
                     self.alloc_expr(Expr::Literal(lit), syntax_ptr)
                 }
             },
@@ -770,6 +933,14 @@ impl<'a> ExprCollector<'a> {
                 let path = e
                     .path()
                     .and_then(Path::from_ast)
+                    .map(|path| {
+                        // A lone `self` refers to the `self` parameter of a method
+                        if path.kind == PathKind::Super(0) && path.segments.is_empty() {
+                            Path::from(name![SELF_PARAM])
+                        } else {
+                            path
+                        }
+                    })
                     .map(Expr::Path)
                     .unwrap_or(Expr::Missing);
                 self.alloc_expr(path, syntax_ptr)
@@ -866,15 +1037,89 @@ impl<'a> ExprCollector<'a> {
                 };
                 self.alloc_expr(Expr::Call { callee, args }, syntax_ptr)
             }
+            ast::ExprKind::MethodCallExpr(e) => {
+                let receiver = self.collect_expr_opt(e.expr());
+                let args = if let Some(arg_list) = e.arg_list() {
+                    arg_list.args().map(|e| self.collect_expr(e)).collect()
+                } else {
+                    Vec::new()
+                };
+                let method_name = e
+                    .name_ref()
+                    .map(|nr| nr.as_name())
+                    .unwrap_or_else(Name::missing);
+                self.alloc_expr(
+                    Expr::MethodCall {
+                        receiver,
+                        method_name,
+                        args,
+                    },
+                    syntax_ptr,
+                )
+            }
             ast::ExprKind::ArrayExpr(e) => {
                 let exprs = e.exprs().map(|expr| self.collect_expr(expr)).collect();
                 self.alloc_expr(Expr::Array(exprs), syntax_ptr)
             }
+            ast::ExprKind::TupleExpr(e) => {
+                let exprs = e.exprs().map(|expr| self.collect_expr(expr)).collect();
+                self.alloc_expr(Expr::Tuple { exprs }, syntax_ptr)
+            }
             ast::ExprKind::IndexExpr(e) => {
                 let base = self.collect_expr_opt(e.base());
                 let index = self.collect_expr_opt(e.index());
                 self.alloc_expr(Expr::Index { base, index }, syntax_ptr)
             }
+            ast::ExprKind::CastExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let type_ref = self
+                    .type_ref_builder
+                    .alloc_from_node_opt(e.type_ref().as_ref());
+                self.alloc_expr(Expr::Cast { expr, type_ref }, syntax_ptr)
+            }
+            ast::ExprKind::MatchExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let arms = if let Some(match_arm_list) = e.match_arm_list() {
+                    match_arm_list
+                        .arms()
+                        .map(|arm| MatchArm {
+                            pat: self.collect_pat_opt(arm.pat()),
+                            expr: self.collect_expr_opt(arm.expr()),
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                self.alloc_expr(Expr::Match { expr, arms }, syntax_ptr)
+            }
+            ast::ExprKind::ClosureExpr(e) => {
+                let mut args = Vec::new();
+                let mut arg_types = Vec::new();
+                if let Some(param_list) = e.param_list() {
+                    for param in param_list.params() {
+                        args.push(self.collect_pat_opt(param.pat()));
+                        arg_types.push(
+                            param
+                                .ascribed_type()
+                                .map(|type_ref| self.type_ref_builder.alloc_from_node(&type_ref)),
+                        );
+                    }
+                }
+                let ret_type = e
+                    .ret_type()
+                    .and_then(|ret_type| ret_type.type_ref())
+                    .map(|type_ref| self.type_ref_builder.alloc_from_node(&type_ref));
+                let body = self.collect_expr_opt(e.body());
+                self.alloc_expr(
+                    Expr::Closure {
+                        args,
+                        arg_types,
+                        ret_type,
+                        body,
+                    },
+                    syntax_ptr,
+                )
+            }
         }
     }
 
@@ -889,7 +1134,11 @@ impl<'a> ExprCollector<'a> {
     fn collect_condition(&mut self, cond: ast::Condition) -> ExprId {
         match cond.pat() {
             None => self.collect_expr_opt(cond.expr()),
-            _ => unreachable!("patterns in conditions are not yet supported"),
+            Some(pat) => {
+                let pat = self.collect_pat(pat);
+                let expr = self.collect_expr_opt(cond.expr());
+                self.exprs.alloc(Expr::Let { pat, expr })
+            }
         }
     }
 
@@ -903,6 +1152,72 @@ impl<'a> ExprCollector<'a> {
                 Pat::Bind { name }
             }
             ast::PatKind::PlaceholderPat(_) => Pat::Wild,
+            ast::PatKind::LiteralPat(lp) => {
+                let expr = match lp.literal() {
+                    Some(lit) => self.collect_expr(lit.into()),
+                    None => self.missing_expr(),
+                };
+                Pat::Lit {
+                    expr,
+                    negated: lp.is_negative(),
+                }
+            }
+            ast::PatKind::RangePat(rp) => {
+                let start = self.collect_pat_opt(rp.start());
+                let end = self.collect_pat_opt(rp.end());
+                Pat::Range { start, end }
+            }
+            ast::PatKind::PathPat(pp) => match pp.path().and_then(Path::from_ast) {
+                Some(path) => Pat::Path(path),
+                None => Pat::Missing,
+            },
+            ast::PatKind::TupleStructPat(tsp) => match tsp.path().and_then(Path::from_ast) {
+                Some(path) => {
+                    let args = tsp.args().map(|p| self.collect_pat(p)).collect();
+                    Pat::TupleStruct { path, args }
+                }
+                None => Pat::Missing,
+            },
+            ast::PatKind::TuplePat(tp) => {
+                let args = tp.args().map(|p| self.collect_pat(p)).collect();
+                Pat::Tuple { args }
+            }
+            ast::PatKind::ParenPat(pp) => {
+                let inner = self.collect_pat_opt(pp.pat());
+                // make the paren pattern point to the inner pattern as well
+                self.source_map.pat_map.insert(AstPtr::new(&pat), inner);
+                return inner;
+            }
+            ast::PatKind::RecordPat(rp) => match rp.path().and_then(Path::from_ast) {
+                Some(path) => {
+                    let field_list = rp.record_field_pat_list();
+                    let args = field_list
+                        .iter()
+                        .flat_map(|list| list.fields())
+                        .map(|field| {
+                            let pat = field.pat();
+                            let name = match (field.name_ref(), pat.as_ref().map(|p| p.kind())) {
+                                (Some(name_ref), _) => name_ref.as_name(),
+                                (None, Some(ast::PatKind::BindPat(bp))) => {
+                                    bp.name().map(|n| n.as_name()).unwrap_or_else(Name::missing)
+                                }
+                                _ => Name::missing(),
+                            };
+                            RecordFieldPat {
+                                name,
+                                pat: self.collect_pat_opt(pat),
+                            }
+                        })
+                        .collect();
+                    let ellipsis = field_list.map_or(false, |list| list.has_ellipsis());
+                    Pat::Record {
+                        path,
+                        args,
+                        ellipsis,
+                    }
+                }
+                None => Pat::Missing,
+            },
         };
         let ptr = AstPtr::new(&pat);
         self.alloc_pat(pattern, ptr)
@@ -916,21 +1231,48 @@ impl<'a> ExprCollector<'a> {
 
     fn collect_break(&mut self, expr: ast::BreakExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = expr.lifetime_token().map(|t| Name::new(t.text()));
         let expr = expr.expr().map(|e| self.collect_expr(e));
-        self.alloc_expr(Expr::Break { expr }, syntax_node_ptr)
+        self.alloc_expr(Expr::Break { expr, label }, syntax_node_ptr)
     }
 
     fn collect_loop(&mut self, expr: ast::LoopExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = collect_label(&expr);
         let body = self.collect_block_opt(expr.loop_body());
-        self.alloc_expr(Expr::Loop { body }, syntax_node_ptr)
+        self.alloc_expr(Expr::Loop { body, label }, syntax_node_ptr)
     }
 
     fn collect_while(&mut self, expr: ast::WhileExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = collect_label(&expr);
         let condition = self.collect_condition_opt(expr.condition());
         let body = self.collect_block_opt(expr.loop_body());
-        self.alloc_expr(Expr::While { condition, body }, syntax_node_ptr)
+        self.alloc_expr(
+            Expr::While {
+                condition,
+                body,
+                label,
+            },
+            syntax_node_ptr,
+        )
+    }
+
+    fn collect_for(&mut self, expr: ast::ForExpr) -> ExprId {
+        let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = collect_label(&expr);
+        let pat = self.collect_pat_opt(expr.pat());
+        let iterable = self.collect_expr_opt(expr.iterable());
+        let body = self.collect_block_opt(expr.loop_body());
+        self.alloc_expr(
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            },
+            syntax_node_ptr,
+        )
     }
 
     fn finish(mut self) -> (Body, BodySourceMap) {
@@ -953,6 +1295,13 @@ impl<'a> ExprCollector<'a> {
 }
 
 /// Removes any underscores from a string if present
+/// Returns the name of the label of a loop, e.g. `'outer` in `'outer: loop {}`
+fn collect_label(expr: &impl LoopBodyOwner) -> Option<Name> {
+    expr.label()
+        .and_then(|label| label.lifetime_token())
+        .map(|t| Name::new(t.text()))
+}
+
 fn strip_underscores(s: &str) -> Cow<str> {
     if s.contains('_') {
         let mut s = s.to_string();
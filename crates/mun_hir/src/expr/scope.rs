@@ -154,6 +154,74 @@ fn compute_expr_scopes(expr: ExprId, body: &Body, scopes: &mut ExprScopes, scope
         Expr::Block { statements, tail } => {
             compute_block_scopes(statements, *tail, body, scopes, scope);
         }
+        Expr::Match { expr, arms } => {
+            compute_expr_scopes(*expr, body, scopes, scope);
+            for arm in arms {
+                let scope = scopes.new_scope(scope);
+                scopes.add_bindings(body, scope, arm.pat);
+                compute_expr_scopes(arm.expr, body, scopes, scope);
+            }
+        }
+        Expr::For {
+            pat,
+            iterable,
+            body: loop_body,
+            ..
+        } => {
+            compute_expr_scopes(*iterable, body, scopes, scope);
+            let scope = scopes.new_scope(scope);
+            scopes.add_bindings(body, scope, *pat);
+            compute_expr_scopes(*loop_body, body, scopes, scope);
+        }
+        Expr::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            let then_scope = compute_condition_scopes(*condition, body, scopes, scope);
+            compute_expr_scopes(*then_branch, body, scopes, then_scope);
+            if let Some(else_branch) = else_branch {
+                compute_expr_scopes(*else_branch, body, scopes, scope);
+            }
+        }
+        Expr::While {
+            condition,
+            body: loop_body,
+            ..
+        } => {
+            let body_scope = compute_condition_scopes(*condition, body, scopes, scope);
+            compute_expr_scopes(*loop_body, body, scopes, body_scope);
+        }
+        Expr::Closure {
+            args,
+            body: closure_body,
+            ..
+        } => {
+            let scope = scopes.new_scope(scope);
+            for arg in args {
+                scopes.add_bindings(body, scope, *arg);
+            }
+            compute_expr_scopes(*closure_body, body, scopes, scope);
+        }
         e => e.walk_child_exprs(|e| compute_expr_scopes(e, body, scopes, scope)),
     };
 }
+
+/// Computes the scopes of the condition of an `if` or `while` expression. Returns the scope in which
+/// the bindings of a `let` condition are visible.
+fn compute_condition_scopes(
+    condition: ExprId,
+    body: &Body,
+    scopes: &mut ExprScopes,
+    scope: LocalScopeId,
+) -> LocalScopeId {
+    compute_expr_scopes(condition, body, scopes, scope);
+    match &body[condition] {
+        Expr::Let { pat, .. } => {
+            let scope = scopes.new_scope(scope);
+            scopes.add_bindings(body, scope, *pat);
+            scope
+        }
+        _ => scope,
+    }
+}
@@ -1,6 +1,7 @@
 use crate::code_model::src::HasSource;
 use crate::diagnostics::{
     ExportedPrivate, ExternCannotHaveBody, ExternNonPrimitiveParam, FreeTypeAliasWithoutTypeRef,
+    InvalidTestSignature, InvalidTestSignatureKind,
 };
 use crate::expr::BodySourceMap;
 use crate::in_file::InFile;
@@ -10,10 +11,12 @@ use crate::{
 };
 use crate::{HasVisibility, Ty, Visibility};
 
-use mun_syntax::{AstNode, SyntaxNodePtr};
+use mun_syntax::{ast::TypeParamsOwner, AstNode, SyntaxNodePtr};
 use std::sync::Arc;
 
 mod literal_out_of_range;
+mod match_check;
+mod type_param_ops;
 mod uninitialized_access;
 
 #[cfg(test)]
@@ -42,8 +45,53 @@ impl<'a> ExprValidator<'a> {
     pub fn validate_body(&self, sink: &mut DiagnosticSink) {
         self.validate_literal_ranges(sink);
         self.validate_uninitialized_access(sink);
+        self.validate_match_exhaustiveness(sink);
+        self.validate_type_param_ops(sink);
         self.validate_extern(sink);
         self.validate_privacy(sink);
+        self.validate_test(sink);
+    }
+
+    /// Validates that a function marked with `#[test]` can be invoked by the test runner: it must
+    /// not be generic, take no arguments and return `()`.
+    pub fn validate_test(&self, sink: &mut DiagnosticSink) {
+        if !self.func.is_test(self.db) {
+            return;
+        }
+
+        let src = self.func.source(self.db.upcast());
+        let mut push = |node: Option<SyntaxNodePtr>, kind| {
+            let signature = node.unwrap_or_else(|| SyntaxNodePtr::new(src.value.syntax()));
+            sink.push(InvalidTestSignature {
+                signature: InFile::new(src.file_id, signature),
+                kind,
+            })
+        };
+
+        if self.func.is_generic(self.db) {
+            let type_params = src.value.type_param_list();
+            push(
+                type_params.map(|it| SyntaxNodePtr::new(it.syntax())),
+                InvalidTestSignatureKind::TypeParams,
+            );
+        }
+
+        if !self.func.data(self.db.upcast()).params().is_empty() {
+            let params = src.value.param_list();
+            push(
+                params.map(|it| SyntaxNodePtr::new(it.syntax())),
+                InvalidTestSignatureKind::Params,
+            );
+        }
+
+        let ret_ty = self.func.ret_type(self.db);
+        if !ret_ty.is_empty() && ret_ty.is_known() {
+            let ret_type = src.value.ret_type();
+            push(
+                ret_type.map(|it| SyntaxNodePtr::new(it.syntax())),
+                InvalidTestSignatureKind::ReturnType,
+            );
+        }
     }
 
     pub fn validate_privacy(&self, sink: &mut DiagnosticSink) {
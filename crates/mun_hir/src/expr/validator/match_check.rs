@@ -0,0 +1,630 @@
+//! Checks whether the arms of a `match` expression cover every possible value of the scrutinee
+//! and whether the patterns of local bindings and function parameters are irrefutable.
+//!
+//! The implementation is based on the usefulness algorithm described in "Warnings for pattern
+//! matching" by Luc Maranget. Patterns are deconstructed into constructors with fields. Integer
+//! and boolean values are represented by inclusive ranges that are split into disjoint pieces so
+//! that every piece is either fully covered by a pattern or not covered at all.
+
+use super::ExprValidator;
+use crate::code_model::src::HasSource;
+use crate::code_model::StructKind;
+use crate::diagnostics::{DiagnosticSink, MissingMatchArms, RefutablePattern};
+use crate::expr::{Pat, PatId, Statement};
+use crate::ty::{ResolveBitness, TyKind};
+use crate::{EnumVariant, Expr, ExprId, FileId, HirDatabase, Literal, Name, Ty};
+use std::iter;
+
+/// The maximum number of uncovered patterns that are reported for a single match expression.
+const MAX_WITNESSES: usize = 3;
+
+/// Values of signed integers are stored with their sign bit flipped, so that all values can be
+/// compared as unsigned integers.
+const SIGN_BIT: u128 = 1 << 127;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that all match expressions are exhaustive and that the patterns of let statements,
+    /// for loops, function parameters and closure parameters always match.
+    pub fn validate_match_exhaustiveness(&self, sink: &mut DiagnosticSink) {
+        let cx = MatchCheckCtx { db: self.db };
+        let file = self.func.source(self.db.upcast()).file_id;
+
+        for &pat in self.body.params().iter().map(|(pat, _)| pat) {
+            self.check_irrefutable(&cx, pat, file, sink);
+        }
+
+        self.walk_exprs(
+            self.body.body_expr,
+            &mut |expr_id| match &self.body[expr_id] {
+                Expr::Match { expr, arms } => {
+                    let scrutinee_ty = &self.infer[*expr];
+                    let pats: Vec<PatId> = arms.iter().map(|arm| arm.pat).collect();
+                    if let Some(witnesses) = self.uncovered_patterns(&cx, &pats, scrutinee_ty) {
+                        let scrutinee = self
+                            .body_source_map
+                            .expr_syntax(*expr)
+                            .map(|src| src.value.either(|ptr| ptr.into(), |ptr| ptr.into()));
+                        if let Some(scrutinee) = scrutinee {
+                            sink.push(MissingMatchArms {
+                                file,
+                                scrutinee,
+                                uncovered_patterns: witnesses,
+                            });
+                        }
+                    }
+                }
+                Expr::For { pat, .. } => self.check_irrefutable(&cx, *pat, file, sink),
+                Expr::Closure { args, .. } => {
+                    for &pat in args {
+                        self.check_irrefutable(&cx, pat, file, sink);
+                    }
+                }
+                Expr::Block { statements, .. } => {
+                    for statement in statements {
+                        if let Statement::Let { pat, .. } = statement {
+                            self.check_irrefutable(&cx, *pat, file, sink);
+                        }
+                    }
+                }
+                _ => {}
+            },
+        );
+    }
+
+    /// Emits a diagnostic if the specified binding pattern does not match every possible value.
+    fn check_irrefutable(
+        &self,
+        cx: &MatchCheckCtx,
+        pat: PatId,
+        file: FileId,
+        sink: &mut DiagnosticSink,
+    ) {
+        let ty = &self.infer[pat];
+        if let Some(witnesses) = self.uncovered_patterns(cx, &[pat], ty) {
+            if let Some(src) = self.body_source_map.pat_syntax(pat) {
+                sink.push(RefutablePattern {
+                    file,
+                    pat: src.value.syntax_node_ptr(),
+                    uncovered_patterns: witnesses,
+                });
+            }
+        }
+    }
+
+    /// Calls `f` for the specified expression and all of its descendants.
+    fn walk_exprs(&self, expr_id: ExprId, f: &mut impl FnMut(ExprId)) {
+        f(expr_id);
+        self.body[expr_id].walk_child_exprs(|child| self.walk_exprs(child, f));
+    }
+
+    /// Returns a textual representation of the values of type `ty` that are not matched by any of
+    /// the `pats`. Returns `None` if all values are covered, or if the patterns contain errors that
+    /// have already been reported during type inference.
+    fn uncovered_patterns(
+        &self,
+        cx: &MatchCheckCtx,
+        pats: &[PatId],
+        ty: &Ty,
+    ) -> Option<Vec<String>> {
+        if ty.is_unknown() {
+            return None;
+        }
+
+        let rows = pats
+            .iter()
+            .map(|pat| self.lower_pat(cx, *pat, ty).map(|pat| vec![pat]))
+            .collect::<Option<Vec<_>>>()?;
+
+        let witnesses = cx.compute_witnesses(&rows, &[ty.clone()], MAX_WITNESSES + 1);
+        if witnesses.is_empty() {
+            None
+        } else {
+            Some(
+                witnesses
+                    .iter()
+                    .map(|witness| cx.display_pat(&witness[0], ty))
+                    .collect(),
+            )
+        }
+    }
+
+    /// Converts a pattern of type `ty` into its deconstructed form. Returns `None` if the pattern
+    /// is not well-formed.
+    fn lower_pat(&self, cx: &MatchCheckCtx, pat: PatId, ty: &Ty) -> Option<DeconstructedPat> {
+        let ctor = match &self.body[pat] {
+            Pat::Missing | Pat::Wild | Pat::Bind { .. } => return Some(DeconstructedPat::Wild),
+            Pat::Lit { expr, negated } => self.lower_literal(cx, *expr, *negated, ty)?,
+            Pat::Range { start, end } => {
+                let (start, end) = match (&self.body[*start], &self.body[*end]) {
+                    (
+                        Pat::Lit {
+                            expr: start,
+                            negated: start_negated,
+                        },
+                        Pat::Lit {
+                            expr: end,
+                            negated: end_negated,
+                        },
+                    ) => (
+                        self.lower_literal(cx, *start, *start_negated, ty)?,
+                        self.lower_literal(cx, *end, *end_negated, ty)?,
+                    ),
+                    _ => return None,
+                };
+                match (start, end) {
+                    // Empty ranges are reported during type inference
+                    (Constructor::IntRange(start), Constructor::IntRange(end))
+                        if start.lo <= end.hi =>
+                    {
+                        Constructor::IntRange(IntRange {
+                            lo: start.lo,
+                            hi: end.hi,
+                        })
+                    }
+                    _ => Constructor::Opaque,
+                }
+            }
+            Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Record { .. } => match ty.interned() {
+                TyKind::Enum(e) => {
+                    let variant = self.infer.variant_resolution_for_pat(pat)?;
+                    if variant.parent_enum() != *e {
+                        return None;
+                    }
+                    Constructor::Variant(variant)
+                }
+                TyKind::Struct(..) if &self.infer[pat] == ty => Constructor::Single,
+                _ => return None,
+            },
+            Pat::Tuple { .. } => match ty.interned() {
+                TyKind::Tuple(..) => Constructor::Single,
+                _ => return None,
+            },
+        };
+
+        let fields = cx.fields(ty, &ctor);
+        let mut field_pats = vec![DeconstructedPat::Wild; fields.len()];
+        match &self.body[pat] {
+            Pat::TupleStruct { args, .. } | Pat::Tuple { args } => {
+                if args.len() != fields.len() {
+                    return None;
+                }
+                for ((field_pat, arg), (_, field_ty)) in
+                    field_pats.iter_mut().zip(args).zip(fields.iter())
+                {
+                    *field_pat = self.lower_pat(cx, *arg, field_ty)?;
+                }
+            }
+            Pat::Record { args, .. } => {
+                for arg in args {
+                    let idx = fields.iter().position(|(name, _)| name == &arg.name)?;
+                    field_pats[idx] = self.lower_pat(cx, arg.pat, &fields[idx].1)?;
+                }
+            }
+            _ => {}
+        }
+
+        Some(DeconstructedPat::Constructor(ctor, field_pats))
+    }
+
+    /// Converts a literal of type `ty` into a constructor. Literals whose values cannot be listed,
+    /// like strings and floating-point numbers, become an opaque constructor that only a wildcard
+    /// can cover. The same holds for integers that do not fit in `ty`, which are reported during
+    /// type inference. Returns `None` if the literal is not of type `ty`.
+    fn lower_literal(
+        &self,
+        cx: &MatchCheckCtx,
+        expr: ExprId,
+        negated: bool,
+        ty: &Ty,
+    ) -> Option<Constructor> {
+        match (&self.body[expr], ty.interned()) {
+            (Expr::Literal(Literal::Bool(value)), TyKind::Bool) if !negated => {
+                Some(Constructor::IntRange(IntRange::singleton(*value as u128)))
+            }
+            (Expr::Literal(Literal::Int(lit)), TyKind::Int(_)) => Some(
+                cx.int_value(ty, lit.value, negated)
+                    .map_or(Constructor::Opaque, |value| {
+                        Constructor::IntRange(IntRange::singleton(value))
+                    }),
+            ),
+            (Expr::Literal(Literal::Float(_)), TyKind::Float(_))
+            | (Expr::Literal(Literal::String(_)), TyKind::String) => Some(Constructor::Opaque),
+            (Expr::Literal(Literal::Nil), TyKind::Option(_)) if !negated => {
+                Some(Constructor::Opaque)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An inclusive range of integer values. Signed values are stored with their sign bit flipped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct IntRange {
+    lo: u128,
+    hi: u128,
+}
+
+impl IntRange {
+    fn singleton(value: u128) -> Self {
+        IntRange {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    fn is_subrange(&self, other: &IntRange) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Splits this range into disjoint pieces such that each piece is either fully contained in
+    /// or disjoint from every range in `heads`.
+    fn split<'r>(&self, heads: impl Iterator<Item = &'r IntRange>) -> Vec<IntRange> {
+        let mut boundaries = Vec::new();
+        for head in heads {
+            if head.lo > self.lo && head.lo <= self.hi {
+                boundaries.push(head.lo);
+            }
+            if head.hi >= self.lo && head.hi < self.hi {
+                boundaries.push(head.hi + 1);
+            }
+        }
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut lo = self.lo;
+        let mut pieces = Vec::with_capacity(boundaries.len() + 1);
+        for boundary in boundaries {
+            pieces.push(IntRange {
+                lo,
+                hi: boundary - 1,
+            });
+            lo = boundary;
+        }
+        pieces.push(IntRange { lo, hi: self.hi });
+        pieces
+    }
+}
+
+/// Identifies how a value was constructed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Constructor {
+    /// A range of integer or boolean values
+    IntRange(IntRange),
+    /// A variant of an enum
+    Variant(EnumVariant),
+    /// The only constructor of a struct
+    Single,
+    /// A value that we cannot reason about, e.g. a floating-point literal
+    Opaque,
+}
+
+impl Constructor {
+    /// Returns true if every value constructed with `other` is also constructed with `self`.
+    fn covers(&self, other: &Constructor) -> bool {
+        match (self, other) {
+            (Constructor::IntRange(this), Constructor::IntRange(other)) => other.is_subrange(this),
+            (Constructor::Variant(this), Constructor::Variant(other)) => this == other,
+            (Constructor::Single, Constructor::Single) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A pattern that has been deconstructed into a constructor and the patterns of its fields.
+#[derive(Clone, Debug)]
+enum DeconstructedPat {
+    Wild,
+    Constructor(Constructor, Vec<DeconstructedPat>),
+}
+
+struct MatchCheckCtx<'a> {
+    db: &'a dyn HirDatabase,
+}
+
+impl<'a> MatchCheckCtx<'a> {
+    /// Returns the biased value of an integer literal of type `ty`, or `None` if it doesn't fit.
+    fn int_value(&self, ty: &Ty, value: u128, negated: bool) -> Option<u128> {
+        match ty.interned() {
+            TyKind::Int(int_ty) => int_ty
+                .resolve(&self.db.target_data_layout())
+                .biased_value(value, negated),
+            _ => None,
+        }
+    }
+
+    /// Returns all constructors of `ty`, or `None` if the type has too many constructors to list.
+    fn all_constructors(&self, ty: &Ty) -> Option<Vec<Constructor>> {
+        match ty.interned() {
+            TyKind::Bool => Some(vec![
+                Constructor::IntRange(IntRange::singleton(0)),
+                Constructor::IntRange(IntRange::singleton(1)),
+            ]),
+            TyKind::Int(int_ty) => {
+                let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                let max = int_ty.max();
+                let range = if int_ty.signedness.is_signed() {
+                    IntRange {
+                        lo: (-(max as i128) - 1) as u128 ^ SIGN_BIT,
+                        hi: max ^ SIGN_BIT,
+                    }
+                } else {
+                    IntRange { lo: 0, hi: max }
+                };
+                Some(vec![Constructor::IntRange(range)])
+            }
+            TyKind::Enum(e) => Some(
+                e.variants(self.db)
+                    .into_iter()
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            TyKind::Struct(..) | TyKind::Tuple(..) => Some(vec![Constructor::Single]),
+            TyKind::Never => Some(Vec::new()),
+            _ => None,
+        }
+    }
+
+    /// Returns the names and types of the fields of a value of type `ty` that was constructed with
+    /// `ctor`.
+    fn fields(&self, ty: &Ty, ctor: &Constructor) -> Vec<(Name, Ty)> {
+        match (ctor, ty.interned()) {
+            (Constructor::Variant(variant), _) => variant
+                .fields(self.db)
+                .into_iter()
+                .map(|field| (field.name(self.db), field.ty(self.db)))
+                .collect(),
+            (Constructor::Single, TyKind::Struct(s, substs)) => s
+                .fields(self.db)
+                .into_iter()
+                .map(|field| (field.name(self.db), field.ty(self.db).subst(substs)))
+                .collect(),
+            (Constructor::Single, TyKind::Tuple(_, substs)) => substs
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| (Name::new_tuple_field(idx), ty.clone()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Computes up to `limit` vectors of patterns, with types `tys`, that are not matched by any of
+    /// the `rows`.
+    fn compute_witnesses(
+        &self,
+        rows: &[Vec<DeconstructedPat>],
+        tys: &[Ty],
+        limit: usize,
+    ) -> Vec<Vec<DeconstructedPat>> {
+        let (ty, rest_tys) = match tys.split_first() {
+            Some(split) => split,
+            None if rows.is_empty() => return vec![Vec::new()],
+            None => return Vec::new(),
+        };
+
+        let heads: Vec<&Constructor> = rows
+            .iter()
+            .filter_map(|row| match &row[0] {
+                DeconstructedPat::Constructor(ctor, _) => Some(ctor),
+                DeconstructedPat::Wild => None,
+            })
+            .collect();
+
+        let mut witnesses = Vec::new();
+        if let Some(ctors) = self.all_constructors(ty) {
+            let ctors = split_constructors(ctors, &heads);
+            let missing: Vec<&Constructor> = ctors
+                .iter()
+                .filter(|ctor| !heads.iter().any(|head| head.covers(ctor)))
+                .collect();
+
+            if missing.is_empty() {
+                // Every constructor is used by at least one row; the value is uncovered if the
+                // fields of one of the constructors are uncovered.
+                for ctor in ctors.iter() {
+                    let field_tys: Vec<Ty> = self
+                        .fields(ty, ctor)
+                        .into_iter()
+                        .map(|(_, ty)| ty)
+                        .collect();
+                    let arity = field_tys.len();
+                    let specialized: Vec<_> = rows
+                        .iter()
+                        .filter_map(|row| specialize(row, ctor, arity))
+                        .collect();
+                    let sub_tys: Vec<Ty> = field_tys
+                        .into_iter()
+                        .chain(rest_tys.iter().cloned())
+                        .collect();
+                    for witness in
+                        self.compute_witnesses(&specialized, &sub_tys, limit - witnesses.len())
+                    {
+                        let (fields, rest) = witness.split_at(arity);
+                        witnesses.push(
+                            iter::once(DeconstructedPat::Constructor(
+                                ctor.clone(),
+                                fields.to_vec(),
+                            ))
+                            .chain(rest.iter().cloned())
+                            .collect(),
+                        );
+                    }
+                    if witnesses.len() >= limit {
+                        break;
+                    }
+                }
+                return witnesses;
+            }
+
+            // Values constructed with one of the missing constructors are only matched by rows
+            // that start with a wildcard.
+            for witness in self.compute_witnesses(&default_rows(rows), rest_tys, limit) {
+                if heads.is_empty() {
+                    witnesses.push(
+                        iter::once(DeconstructedPat::Wild)
+                            .chain(witness.iter().cloned())
+                            .collect(),
+                    );
+                } else {
+                    for ctor in missing.iter() {
+                        let arity = self.fields(ty, ctor).len();
+                        witnesses.push(
+                            iter::once(DeconstructedPat::Constructor(
+                                (*ctor).clone(),
+                                vec![DeconstructedPat::Wild; arity],
+                            ))
+                            .chain(witness.iter().cloned())
+                            .collect(),
+                        );
+                    }
+                }
+                if witnesses.len() >= limit {
+                    break;
+                }
+            }
+        } else {
+            // The constructors of the type cannot be listed, so only rows that start with a
+            // wildcard can cover all values.
+            for witness in self.compute_witnesses(&default_rows(rows), rest_tys, limit) {
+                witnesses.push(iter::once(DeconstructedPat::Wild).chain(witness).collect());
+            }
+        }
+
+        witnesses.truncate(limit);
+        witnesses
+    }
+
+    /// Returns a textual representation of a pattern of type `ty`.
+    fn display_pat(&self, pat: &DeconstructedPat, ty: &Ty) -> String {
+        let (ctor, fields) = match pat {
+            DeconstructedPat::Wild => return "_".to_owned(),
+            DeconstructedPat::Constructor(ctor, fields) => (ctor, fields),
+        };
+
+        let (name, kind) = match (ctor, ty.interned()) {
+            (Constructor::IntRange(range), TyKind::Bool) => {
+                return match (range.lo, range.hi) {
+                    (0, 0) => "false".to_owned(),
+                    (1, 1) => "true".to_owned(),
+                    _ => "_".to_owned(),
+                };
+            }
+            (Constructor::IntRange(range), TyKind::Int(int_ty)) => {
+                let is_signed = int_ty
+                    .resolve(&self.db.target_data_layout())
+                    .signedness
+                    .is_signed();
+                let display_value = |value: u128| {
+                    if is_signed {
+                        ((value ^ SIGN_BIT) as i128).to_string()
+                    } else {
+                        value.to_string()
+                    }
+                };
+                return if range.lo == range.hi {
+                    display_value(range.lo)
+                } else {
+                    format!("{}..={}", display_value(range.lo), display_value(range.hi))
+                };
+            }
+            (Constructor::Variant(variant), _) => (
+                format!(
+                    "{}::{}",
+                    variant.parent_enum().name(self.db),
+                    variant.name(self.db)
+                ),
+                variant.kind(self.db),
+            ),
+            (Constructor::Single, TyKind::Struct(s, _)) => {
+                (s.name(self.db).to_string(), s.data(self.db.upcast()).kind)
+            }
+            (Constructor::Single, TyKind::Tuple(_, substs)) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .zip(substs.iter())
+                    .map(|(pat, ty)| self.display_pat(pat, ty))
+                    .collect();
+                return if fields.len() == 1 {
+                    format!("({},)", fields[0])
+                } else {
+                    format!("({})", fields.join(", "))
+                };
+            }
+            _ => return "_".to_owned(),
+        };
+
+        let field_tys = self.fields(ty, ctor);
+        let fields = fields
+            .iter()
+            .zip(field_tys.iter())
+            .map(|(pat, (name, ty))| (name, self.display_pat(pat, ty)));
+        match kind {
+            StructKind::Unit => name,
+            StructKind::Tuple => format!(
+                "{}({})",
+                name,
+                fields.map(|(_, pat)| pat).collect::<Vec<_>>().join(", ")
+            ),
+            StructKind::Record => format!(
+                "{} {{ {} }}",
+                name,
+                fields
+                    .map(|(name, pat)| format!("{name}: {pat}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Splits the integer ranges of `ctors` so that each piece is either fully covered or not covered
+/// at all by each of the `heads`.
+fn split_constructors(ctors: Vec<Constructor>, heads: &[&Constructor]) -> Vec<Constructor> {
+    let head_ranges: Vec<&IntRange> = heads
+        .iter()
+        .filter_map(|head| match head {
+            Constructor::IntRange(range) => Some(range),
+            _ => None,
+        })
+        .collect();
+
+    ctors
+        .into_iter()
+        .flat_map(|ctor| match ctor {
+            Constructor::IntRange(range) => range
+                .split(head_ranges.iter().copied())
+                .into_iter()
+                .map(Constructor::IntRange)
+                .collect(),
+            ctor => vec![ctor],
+        })
+        .collect()
+}
+
+/// Returns the remainder of `row` if its first pattern matches values constructed with `ctor`,
+/// with the fields of the constructor prepended.
+fn specialize(
+    row: &[DeconstructedPat],
+    ctor: &Constructor,
+    arity: usize,
+) -> Option<Vec<DeconstructedPat>> {
+    match &row[0] {
+        DeconstructedPat::Wild => Some(
+            iter::repeat(DeconstructedPat::Wild)
+                .take(arity)
+                .chain(row[1..].iter().cloned())
+                .collect(),
+        ),
+        DeconstructedPat::Constructor(head, fields) if head.covers(ctor) => {
+            Some(fields.iter().chain(row[1..].iter()).cloned().collect())
+        }
+        DeconstructedPat::Constructor(..) => None,
+    }
+}
+
+/// Returns the remainder of all rows that start with a wildcard.
+fn default_rows(rows: &[Vec<DeconstructedPat>]) -> Vec<Vec<DeconstructedPat>> {
+    rows.iter()
+        .filter(|row| matches!(row[0], DeconstructedPat::Wild))
+        .map(|row| row[1..].to_vec())
+        .collect()
+}
@@ -160,3 +160,188 @@ fn test_private_leak_alias() {
     "#,
     ), @"");
 }
+
+#[test]
+fn test_match_exhaustiveness() {
+    insta::assert_snapshot!(diagnostics(
+        r#"
+    enum Foo {
+        A,
+        B(bool),
+        C { a: i32 },
+    }
+
+    struct Bar { foo: Foo, b: bool }
+
+    fn exhaustive(foo: Foo, bar: Bar, a: u8) {
+        match foo {
+            Foo::A => {},
+            Foo::B(true) => {},
+            Foo::B(false) => {},
+            Foo::C { .. } => {},
+        };
+        match bar {
+            Bar { foo: Foo::A, .. } => {},
+            Bar { b: true, .. } => {},
+            Bar { b: false, .. } => {},
+        };
+        match a {
+            0..=99 => {},
+            100 => {},
+            101..=255 => {},
+        };
+        let Bar { foo, b } = bar;
+    }
+
+    fn missing_variants(foo: Foo) {
+        match foo {
+            Foo::B(true) => {},
+        };
+    }
+
+    fn missing_fields(bar: Bar) {
+        match bar {
+            Bar { foo: Foo::A, b: true } => {},
+            Bar { foo: Foo::B(_), .. } => {},
+            Bar { foo: Foo::C { a: 0 }, .. } => {},
+        };
+    }
+
+    fn missing_ints(a: i8, b: f64) {
+        match a {
+            -128..=-1 => {},
+            1..=100 => {},
+        };
+        match b {
+            0.0 => {},
+        };
+        match a {};
+    }
+
+    fn refutable(foo: Foo) {
+        let Foo::A = foo;
+    }
+    "#,
+    ), @r###"
+    555..558: non-exhaustive patterns: `Foo::A` and `Foo::C { a: _ }` not covered
+    639..642: non-exhaustive patterns: `Bar { foo: Foo::A, b: false }`, `Bar { foo: Foo::C { a: -2147483648..=-1 }, b: _ }` and `Bar { foo: Foo::C { a: 1..=2147483647 }, b: _ }` not covered
+    832..833: non-exhaustive patterns: `0` and `101..=127` not covered
+    901..902: non-exhaustive patterns: `_` not covered
+    941..942: non-exhaustive patterns: `_` not covered
+    983..989: refutable pattern in binding: `Foo::B(_)` and `Foo::C { a: _ }` not covered
+    "###);
+}
+
+#[test]
+fn test_match_unlistable_literal_patterns() {
+    insta::assert_snapshot!(diagnostics(
+        r#"
+    fn empty_range(x: i32) -> i32 {
+        match x { 5..=1 => 0 }
+    }
+
+    fn strings(s: string) -> i32 {
+        match s { "a" => 0 }
+    }
+
+    fn strings_with_wildcard(s: string) -> i32 {
+        match s { "a" => 0, _ => 1 }
+    }
+
+    fn out_of_range(b: u8, c: i8) -> i32 {
+        match b { 300 => 0, _ => 1 };
+        match c { -129 => 0, -128 => 1, _ => 2 }
+    }
+    "#,
+    ), @r###"
+    42..43: non-exhaustive patterns: `-2147483648..=2147483647` not covered
+    103..104: non-exhaustive patterns: `_` not covered
+    "###);
+}
+
+#[test]
+fn test_invalid_test_signatures() {
+    insta::assert_snapshot!(diagnostics(
+        r#"
+    #[test]
+    fn valid() {}
+
+    #[test]
+    fn explicit_unit() -> () {}
+
+    #[test]
+    fn with_params(a: i32, b: bool) {}
+
+    #[test]
+    fn with_return_type() -> i32 { 0 }
+
+    #[test]
+    fn generic<T>() {}
+
+    #[test]
+    fn everything<T>(a: T) -> T { a }
+    "#,
+    ), @r###"
+    82..99: test functions cannot have parameters
+    134..140: test functions must return `()`
+    166..169: test functions cannot be generic
+    197..200: test functions cannot be generic
+    200..206: test functions cannot have parameters
+    207..211: test functions must return `()`
+    "###);
+}
+
+#[test]
+fn test_type_param_ops() {
+    insta::assert_snapshot!(diagnostics(
+        r#"
+    struct Foo;
+
+    struct(value) Score {
+        value: i32,
+    }
+
+    impl Score {
+        fn gt(self: Score, rhs: Score) -> bool {
+            self.value > rhs.value
+        }
+    }
+
+    fn max<T>(a: T, b: T) -> T {
+        if a > b { a } else { b }
+    }
+
+    fn max3<T>(a: T, b: T, c: T) -> T {
+        max(max(a, b), c)
+    }
+
+    fn negate<T>(a: T) -> T {
+        -a
+    }
+
+    fn recursive<T>(a: T, n: i32) -> T {
+        if n == 0 { -a } else { recursive(a, n - 1) }
+    }
+
+    fn main() {
+        let a = max(1, 2);
+        let b = max(1.0, 2.0);
+        let c = max3(3u8, 4u8, 5u8);
+        let d = max(Score { value: 1 }, Score { value: 2 });
+        let e = negate(1);
+        let f = max(Foo, Foo);              // error: `Foo` cannot be compared
+        let g = max3(Foo, Foo, Foo);        // error: `Foo` cannot be compared
+        let h = negate(true);               // error: `bool` cannot be negated
+        let i = recursive(false, 2);        // error: `bool` cannot be negated
+        let j = max;                        // error: `Foo` cannot be compared
+        j(Foo, Foo);
+    }
+    "#,
+    ), @r###"
+    588..591: the type arguments of `max` do not support the operators that are applied to its type parameters
+    663..667: the type arguments of `max3` do not support the operators that are applied to its type parameters
+    738..744: the type arguments of `negate` do not support the operators that are applied to its type parameters
+    813..822: the type arguments of `recursive` do not support the operators that are applied to its type parameters
+    888..891: the type arguments of `max` do not support the operators that are applied to its type parameters
+    "###);
+}
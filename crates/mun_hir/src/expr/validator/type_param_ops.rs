@@ -0,0 +1,109 @@
+use super::ExprValidator;
+use crate::diagnostics::{DiagnosticSink, UnsupportedTypeArguments};
+use crate::resolve::{resolver_for_expr, ValueNs};
+use crate::ty::{Substitution, TyKind};
+use crate::{CallableDef, Expr, ExprId, Function, HirDatabase, InferenceResult};
+use rustc_hash::FxHashSet;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that the type arguments of the generic functions that are used by the body
+    /// support the operators that those functions apply to their type parameters. Type arguments
+    /// that contain type parameters of this function are validated when this function is
+    /// instantiated.
+    pub fn validate_type_param_ops(&self, sink: &mut DiagnosticSink) {
+        for (expr, function, substs) in generic_instances(self.db, &self.infer) {
+            if substs
+                .iter()
+                .any(|ty| ty.has_type_params() || ty.is_unknown())
+                || !self.refers_to_instance(expr)
+            {
+                continue;
+            }
+
+            if !supports_type_param_ops(self.db, function, &substs, &mut FxHashSet::default()) {
+                sink.push(UnsupportedTypeArguments {
+                    file: self.func.file_id(self.db),
+                    expr: self
+                        .body_source_map
+                        .expr_syntax(expr)
+                        .expect("could not retrieve expr from source map")
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                    name: function.name(self.db),
+                })
+            }
+        }
+    }
+
+    /// Returns true if the expression refers to an instantiation of a generic function by name or
+    /// by calling a method, as opposed to an expression that merely has the type of the function
+    /// (e.g. a local binding to which the function was assigned).
+    fn refers_to_instance(&self, expr: ExprId) -> bool {
+        match &self.body[expr] {
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.db.upcast(), self.body.owner(), expr);
+                !matches!(
+                    resolver.resolve_path_as_value_fully(self.db.upcast(), path),
+                    Some((ValueNs::LocalBinding(_), _))
+                )
+            }
+            Expr::MethodCall { .. } | Expr::BinaryOp { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the expressions that refer to an instantiation of a generic function, either by calling
+/// it or by using it as a value, together with the function and its type arguments.
+fn generic_instances<'a>(
+    db: &'a dyn HirDatabase,
+    infer: &'a InferenceResult,
+) -> impl Iterator<Item = (ExprId, Function, Substitution)> + 'a {
+    let functions = infer
+        .type_of_expr
+        .iter()
+        .filter_map(|(expr, ty)| match ty.interned() {
+            TyKind::FnDef(CallableDef::Function(function), substs) => {
+                Some((expr, *function, substs.clone()))
+            }
+            _ => None,
+        });
+    let methods = infer
+        .method_resolutions
+        .iter()
+        .map(|(expr, (function, substs))| (*expr, *function, substs.clone()));
+    functions
+        .chain(methods)
+        .filter(move |(_, function, _)| function.is_generic(db))
+}
+
+/// Returns true if the types in `substs` support all operators that `function` applies to its
+/// type parameters, including the operators of the generic functions that it instantiates with
+/// its own type parameters.
+fn supports_type_param_ops(
+    db: &dyn HirDatabase,
+    function: Function,
+    substs: &Substitution,
+    visited: &mut FxHashSet<(Function, Substitution)>,
+) -> bool {
+    // A recursive instantiation was already checked
+    if !visited.insert((function, substs.clone())) {
+        return true;
+    }
+
+    let infer = function.infer(db);
+    if !infer.supports_type_param_ops(db, substs) {
+        return false;
+    }
+
+    let instances: Vec<_> = generic_instances(db, &infer)
+        .filter(|(_, _, instance_substs)| instance_substs.iter().any(|ty| ty.has_type_params()))
+        .collect();
+    instances.into_iter().all(|(_, instance, instance_substs)| {
+        let instance_substs = instance_substs
+            .iter()
+            .map(|ty| ty.clone().subst(substs))
+            .collect();
+        supports_type_param_ops(db, instance, &instance_substs, visited)
+    })
+}
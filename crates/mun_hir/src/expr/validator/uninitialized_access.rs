@@ -19,7 +19,7 @@ impl<'d> ExprValidator<'d> {
         // Add all parameter patterns to the set of initialized patterns (they must have been
         // initialized)
         for (pat, _) in self.body.params.iter() {
-            initialized_patterns.insert(*pat);
+            self.initialize_pat(&mut initialized_patterns, *pat);
         }
 
         self.validate_expr_access(
@@ -46,6 +46,12 @@ impl<'d> ExprValidator<'d> {
                     self.validate_expr_access(sink, initialized_patterns, *arg, expr_side);
                 }
             }
+            Expr::MethodCall { receiver, args, .. } => {
+                self.validate_expr_access(sink, initialized_patterns, *receiver, expr_side);
+                for arg in args.iter() {
+                    self.validate_expr_access(sink, initialized_patterns, *arg, expr_side);
+                }
+            }
             Expr::Path(p) => {
                 let resolver = resolver_for_expr(self.db.upcast(), self.body.owner(), expr);
                 self.validate_path_access(
@@ -100,6 +106,10 @@ impl<'d> ExprValidator<'d> {
             Expr::UnaryOp { expr, .. } => {
                 self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
             }
+            Expr::Let { pat, expr } => {
+                self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
+                self.initialize_pat(initialized_patterns, *pat);
+            }
             Expr::BinaryOp { lhs, rhs, op } => {
                 let lhs_expr_kind = match op {
                     Some(BinaryOp::Assignment { op: Some(_) }) => ExprKind::Both,
@@ -122,7 +132,7 @@ impl<'d> ExprValidator<'d> {
                                     *initializer,
                                     ExprKind::Normal,
                                 );
-                                initialized_patterns.insert(*pat);
+                                self.initialize_pat(initialized_patterns, *pat);
                             }
                         }
                         Statement::Expr(expr) => {
@@ -147,15 +157,17 @@ impl<'d> ExprValidator<'d> {
                     self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal)
                 }
             }
-            Expr::Break { expr } => {
+            Expr::Break { expr, .. } => {
                 if let Some(expr) = expr {
                     self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal)
                 }
             }
-            Expr::Loop { body } => {
+            Expr::Loop { body, .. } => {
                 self.validate_expr_access(sink, initialized_patterns, *body, ExprKind::Normal)
             }
-            Expr::While { condition, body } => {
+            Expr::While {
+                condition, body, ..
+            } => {
                 self.validate_expr_access(sink, initialized_patterns, *condition, ExprKind::Normal);
                 self.validate_expr_access(
                     sink,
@@ -164,6 +176,29 @@ impl<'d> ExprValidator<'d> {
                     ExprKind::Normal,
                 );
             }
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                ..
+            } => {
+                self.validate_expr_access(sink, initialized_patterns, *iterable, ExprKind::Normal);
+
+                // The body of the loop might never be executed, so nothing initialized inside of it
+                // is initialized after the loop.
+                let mut body_initialized_patterns = initialized_patterns.clone();
+                self.initialize_pat(&mut body_initialized_patterns, *pat);
+                self.validate_expr_access(
+                    sink,
+                    &mut body_initialized_patterns,
+                    *body,
+                    ExprKind::Normal,
+                );
+            }
+            Expr::Range { start, end, .. } => {
+                self.validate_expr_access(sink, initialized_patterns, *start, ExprKind::Normal);
+                self.validate_expr_access(sink, initialized_patterns, *end, ExprKind::Normal);
+            }
             Expr::RecordLit { fields, spread, .. } => {
                 for field in fields.iter() {
                     self.validate_expr_access(
@@ -184,16 +219,70 @@ impl<'d> ExprValidator<'d> {
                 self.validate_expr_access(sink, initialized_patterns, *base, ExprKind::Normal);
                 self.validate_expr_access(sink, initialized_patterns, *index, ExprKind::Normal);
             }
-            Expr::Array(exprs) => {
+            Expr::Cast { expr, .. } => {
+                self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
+            }
+            Expr::Array(exprs) | Expr::Tuple { exprs } => {
                 for expr in exprs {
                     self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
                 }
             }
+            Expr::Match { expr, arms } => {
+                self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
+
+                // Only bindings that are initialized in all arms that return are initialized after
+                // the match expression.
+                let mut arms_initialized_patterns: Option<HashSet<PatId>> = None;
+                for arm in arms.iter() {
+                    let mut arm_initialized_patterns = initialized_patterns.clone();
+                    self.initialize_pat(&mut arm_initialized_patterns, arm.pat);
+                    self.validate_expr_access(
+                        sink,
+                        &mut arm_initialized_patterns,
+                        arm.expr,
+                        ExprKind::Normal,
+                    );
+                    if !self.infer[arm.expr].is_never() {
+                        arms_initialized_patterns = Some(match arms_initialized_patterns {
+                            Some(patterns) => patterns
+                                .intersection(&arm_initialized_patterns)
+                                .copied()
+                                .collect(),
+                            None => arm_initialized_patterns,
+                        });
+                    }
+                }
+                if let Some(patterns) = arms_initialized_patterns {
+                    initialized_patterns.extend(patterns);
+                }
+            }
+            Expr::Closure { args, body, .. } => {
+                // Captured bindings are copied when the closure is created, so they must be
+                // initialized at that point. Nothing initialized inside of the closure is
+                // initialized outside of it.
+                let mut body_initialized_patterns = initialized_patterns.clone();
+                for arg in args.iter() {
+                    self.initialize_pat(&mut body_initialized_patterns, *arg);
+                }
+                self.validate_expr_access(
+                    sink,
+                    &mut body_initialized_patterns,
+                    *body,
+                    ExprKind::Normal,
+                );
+            }
             Expr::Literal(_) => {}
+            Expr::Continue { .. } => {}
             Expr::Missing => {}
         }
     }
 
+    /// Marks the specified pattern and all its sub-patterns as initialized
+    fn initialize_pat(&self, initialized_patterns: &mut HashSet<PatId>, pat: PatId) {
+        initialized_patterns.insert(pat);
+        self.body[pat].walk_child_pats(|pat| self.initialize_pat(initialized_patterns, pat));
+    }
+
     fn validate_path_access(
         &self,
         sink: &mut DiagnosticSink,
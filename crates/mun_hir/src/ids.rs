@@ -1,5 +1,9 @@
 use crate::{
-    item_tree::{Function, ItemTreeId, ItemTreeNode, Struct, TypeAlias},
+    builtin_module::{BuiltinModule, IntrinsicFunction},
+    code_model::r#enum::LocalEnumVariantId,
+    item_tree::{
+        Const, Enum, Function, Impl, ItemTreeId, ItemTreeNode, Static, Struct, Trait, TypeAlias,
+    },
     module_tree::LocalModuleId,
     primitive_type::PrimitiveType,
     DefDatabase, PackageId,
@@ -103,9 +107,38 @@ pub struct ModuleId {
     pub local_id: LocalModuleId,
 }
 
+/// Represents the item that contains a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemContainerId {
+    /// The function is defined at the top-level of a module
+    ModuleId(ModuleId),
+
+    /// The function is associated with a type through an `impl` block
+    ImplId(ImplId),
+
+    /// The function is declared by a trait
+    TraitId(TraitId),
+}
+
+impl ItemContainerId {
+    /// Returns the module in which the container is defined.
+    pub(crate) fn module(self, db: &dyn DefDatabase) -> ModuleId {
+        match self {
+            ItemContainerId::ModuleId(id) => id,
+            ItemContainerId::ImplId(id) => id.lookup(db).module,
+            ItemContainerId::TraitId(id) => id.lookup(db).module,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FunctionLoc {
+    pub container: ItemContainerId,
+    pub id: ItemTreeId<Function>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct FunctionId(salsa::InternId);
-pub(crate) type FunctionLoc = AssocItemLoc<Function>;
 impl_intern!(
     FunctionId,
     FunctionLoc,
@@ -118,6 +151,18 @@ pub struct StructId(salsa::InternId);
 pub(crate) type StructLoc = AssocItemLoc<Struct>;
 impl_intern!(StructId, StructLoc, intern_struct, lookup_intern_struct);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumId(salsa::InternId);
+pub(crate) type EnumLoc = AssocItemLoc<Enum>;
+impl_intern!(EnumId, EnumLoc, intern_enum, lookup_intern_enum);
+
+/// Represents the id of a single variant of an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumVariantId {
+    pub parent: EnumId,
+    pub local_id: LocalEnumVariantId,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TypeAliasId(salsa::InternId);
 pub(crate) type TypeAliasLoc = AssocItemLoc<TypeAlias>;
@@ -128,6 +173,26 @@ impl_intern!(
     lookup_intern_type_alias
 );
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImplId(salsa::InternId);
+pub(crate) type ImplLoc = AssocItemLoc<Impl>;
+impl_intern!(ImplId, ImplLoc, intern_impl, lookup_intern_impl);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct TraitId(salsa::InternId);
+pub(crate) type TraitLoc = AssocItemLoc<Trait>;
+impl_intern!(TraitId, TraitLoc, intern_trait, lookup_intern_trait);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstId(salsa::InternId);
+pub(crate) type ConstLoc = AssocItemLoc<Const>;
+impl_intern!(ConstId, ConstLoc, intern_const, lookup_intern_const);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticId(salsa::InternId);
+pub(crate) type StaticLoc = AssocItemLoc<Static>;
+impl_intern!(StaticId, StaticLoc, intern_static, lookup_intern_static);
+
 pub trait Intern {
     type ID;
     fn intern(self, db: &dyn DefDatabase) -> Self::ID;
@@ -143,8 +208,15 @@ pub enum ItemDefinitionId {
     ModuleId(ModuleId),
     FunctionId(FunctionId),
     StructId(StructId),
+    EnumId(EnumId),
+    EnumVariantId(EnumVariantId),
     TypeAliasId(TypeAliasId),
+    TraitId(TraitId),
+    ConstId(ConstId),
+    StaticId(StaticId),
     PrimitiveType(PrimitiveType),
+    BuiltinModule(BuiltinModule),
+    IntrinsicFunction(IntrinsicFunction),
 }
 
 impl From<ModuleId> for ItemDefinitionId {
@@ -162,21 +234,61 @@ impl From<StructId> for ItemDefinitionId {
         ItemDefinitionId::StructId(id)
     }
 }
+impl From<EnumId> for ItemDefinitionId {
+    fn from(id: EnumId) -> Self {
+        ItemDefinitionId::EnumId(id)
+    }
+}
+impl From<EnumVariantId> for ItemDefinitionId {
+    fn from(id: EnumVariantId) -> Self {
+        ItemDefinitionId::EnumVariantId(id)
+    }
+}
 impl From<TypeAliasId> for ItemDefinitionId {
     fn from(id: TypeAliasId) -> Self {
         ItemDefinitionId::TypeAliasId(id)
     }
 }
+impl From<TraitId> for ItemDefinitionId {
+    fn from(id: TraitId) -> Self {
+        ItemDefinitionId::TraitId(id)
+    }
+}
+impl From<ConstId> for ItemDefinitionId {
+    fn from(id: ConstId) -> Self {
+        ItemDefinitionId::ConstId(id)
+    }
+}
+impl From<StaticId> for ItemDefinitionId {
+    fn from(id: StaticId) -> Self {
+        ItemDefinitionId::StaticId(id)
+    }
+}
+
 impl From<PrimitiveType> for ItemDefinitionId {
     fn from(id: PrimitiveType) -> Self {
         ItemDefinitionId::PrimitiveType(id)
     }
 }
 
+impl From<BuiltinModule> for ItemDefinitionId {
+    fn from(id: BuiltinModule) -> Self {
+        ItemDefinitionId::BuiltinModule(id)
+    }
+}
+
+impl From<IntrinsicFunction> for ItemDefinitionId {
+    fn from(id: IntrinsicFunction) -> Self {
+        ItemDefinitionId::IntrinsicFunction(id)
+    }
+}
+
 /// Definitions which have a body
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DefWithBodyId {
     FunctionId(FunctionId),
+    ConstId(ConstId),
+    StaticId(StaticId),
 }
 
 impl From<FunctionId> for DefWithBodyId {
@@ -184,3 +296,15 @@ impl From<FunctionId> for DefWithBodyId {
         DefWithBodyId::FunctionId(id)
     }
 }
+
+impl From<ConstId> for DefWithBodyId {
+    fn from(id: ConstId) -> Self {
+        DefWithBodyId::ConstId(id)
+    }
+}
+
+impl From<StaticId> for DefWithBodyId {
+    fn from(id: StaticId) -> Self {
+        DefWithBodyId::StaticId(id)
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
-    ids::ItemDefinitionId, module_tree::LocalModuleId, primitive_type::PrimitiveType,
-    visibility::Visibility, Name, PerNs,
+    builtin_module::{BuiltinModule, IntrinsicFunction},
+    ids::{ImplId, ItemDefinitionId},
+    module_tree::LocalModuleId,
+    primitive_type::PrimitiveType,
+    visibility::Visibility,
+    Name, PerNs,
 };
 use once_cell::sync::Lazy;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -41,6 +45,9 @@ pub struct ItemScope {
 
     /// All items that are defined in this scope
     defs: Vec<ItemDefinitionId>,
+
+    /// All `impl` blocks that are defined in this scope
+    impls: Vec<ImplId>,
 }
 
 /// A struct that is returned from `add_resolution_from_import`.
@@ -63,6 +70,21 @@ pub(crate) static BUILTIN_SCOPE: Lazy<FxHashMap<Name, PerNs<(ItemDefinitionId, V
                     PerNs::types(((*ty).into(), Visibility::Public)),
                 )
             })
+            .chain(std::iter::once((
+                BuiltinModule::Core.name(),
+                PerNs::types((BuiltinModule::Core.into(), Visibility::Public)),
+            )))
+            .chain(
+                IntrinsicFunction::ALL
+                    .iter()
+                    .filter(|(_, function)| function.module().is_none())
+                    .map(|(name, function)| {
+                        (
+                            name.clone(),
+                            PerNs::values(((*function).into(), Visibility::Public)),
+                        )
+                    }),
+            )
             .collect()
     });
 
@@ -75,16 +97,27 @@ impl ItemScope {
         keys.into_iter().map(move |name| (name, self.get(name)))
     }
 
-    /// Returns an iterator over all declarations with this scope
+    /// Returns an iterator over all declarations with this scope. This includes the functions
+    /// defined in `impl` blocks.
     pub fn declarations(&self) -> impl Iterator<Item = ItemDefinitionId> + '_ {
         self.defs.iter().copied()
     }
 
+    /// Returns an iterator over all `impl` blocks defined in this scope
+    pub fn impls(&self) -> impl Iterator<Item = ImplId> + '_ {
+        self.impls.iter().copied()
+    }
+
     /// Adds an item definition to the list of definitions
     pub(crate) fn add_definition(&mut self, def: ItemDefinitionId) {
         self.defs.push(def)
     }
 
+    /// Adds an `impl` block to the list of `impl` blocks
+    pub(crate) fn add_impl(&mut self, imp: ImplId) {
+        self.impls.push(imp)
+    }
+
     /// Adds a named item resolution into the scope. Returns true if adding the resolution changes
     /// the scope.
     pub(crate) fn add_resolution(
@@ -215,9 +248,22 @@ impl PerNs<(ItemDefinitionId, Visibility)> {
                     PerNs::types((def, vis))
                 }
             }
+            ItemDefinitionId::EnumId(_) => PerNs::types((def, vis)),
+            ItemDefinitionId::EnumVariantId(_) => {
+                if has_constructor {
+                    PerNs::both((def, vis), (def, vis))
+                } else {
+                    PerNs::types((def, vis))
+                }
+            }
             ItemDefinitionId::TypeAliasId(_) => PerNs::types((def, vis)),
+            ItemDefinitionId::TraitId(_) => PerNs::types((def, vis)),
+            ItemDefinitionId::ConstId(_) => PerNs::values((def, vis)),
+            ItemDefinitionId::StaticId(_) => PerNs::values((def, vis)),
             ItemDefinitionId::PrimitiveType(_) => PerNs::types((def, vis)),
             ItemDefinitionId::ModuleId(_) => PerNs::types((def, vis)),
+            ItemDefinitionId::BuiltinModule(_) => PerNs::types((def, vis)),
+            ItemDefinitionId::IntrinsicFunction(_) => PerNs::values((def, vis)),
         }
     }
 }
@@ -2,6 +2,7 @@ mod lower;
 #[cfg(test)]
 mod tests;
 
+use crate::attrs::Attrs;
 use crate::path::ImportAlias;
 use crate::type_ref::{LocalTypeRefId, TypeRefMap};
 use crate::{
@@ -11,6 +12,7 @@ use crate::{
     DefDatabase, FileId, InFile, Name, Path,
 };
 use mun_syntax::{ast, AstNode};
+use rustc_hash::FxHashMap;
 use std::{
     any::type_name,
     fmt,
@@ -85,6 +87,25 @@ impl ItemTree {
         let ptr = map.get(id);
         ptr.to_node(&root.syntax_node())
     }
+
+    /// Returns the attributes attached to the specified item, field or enum variant.
+    pub fn attrs(&self, owner: AttrOwner) -> &Attrs {
+        self.data.attrs.get(&owner).unwrap_or(&Attrs::EMPTY)
+    }
+}
+
+/// An item, field or enum variant that attributes can be attached to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AttrOwner {
+    ModItem(ModItem),
+    Field(Idx<Field>),
+    Variant(Idx<Variant>),
+}
+
+impl<T: Into<ModItem>> From<T> for AttrOwner {
+    fn from(item: T) -> Self {
+        AttrOwner::ModItem(item.into())
+    }
 }
 
 #[derive(Default, Debug, Eq, PartialEq)]
@@ -109,9 +130,16 @@ struct ItemTreeData {
     functions: Arena<Function>,
     structs: Arena<Struct>,
     fields: Arena<Field>,
+    enums: Arena<Enum>,
+    variants: Arena<Variant>,
     type_aliases: Arena<TypeAlias>,
+    impls: Arena<Impl>,
+    traits: Arena<Trait>,
+    consts: Arena<Const>,
+    statics: Arena<Static>,
 
     visibilities: ItemVisibilities,
+    attrs: FxHashMap<AttrOwner, Attrs>,
 }
 
 /// Trait implemented by all item nodes in the item tree.
@@ -225,7 +253,12 @@ macro_rules! mod_items {
 mod_items! {
     Function in functions -> ast::FunctionDef,
     Struct in structs -> ast::StructDef,
+    Enum in enums -> ast::EnumDef,
     TypeAlias in type_aliases -> ast::TypeAliasDef,
+    Impl in impls -> ast::Impl,
+    Trait in traits -> ast::TraitDef,
+    Const in consts -> ast::ConstDef,
+    Static in statics -> ast::StaticDef,
     Import in imports -> ast::Use,
 }
 
@@ -243,7 +276,7 @@ macro_rules! impl_index {
     };
 }
 
-impl_index!(fields: Field);
+impl_index!(fields: Field, variants: Variant);
 
 static VIS_PUB: RawVisibility = RawVisibility::Public;
 static VIS_PRIV: RawVisibility = RawVisibility::This;
@@ -315,6 +348,22 @@ pub struct Struct {
     pub kind: StructDefKind,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Enum {
+    pub name: Name,
+    pub visibility: RawVisibilityId,
+    pub types: TypeRefMap,
+    pub variants: IdRange<Variant>,
+    pub ast_id: FileAstId<ast::EnumDef>,
+}
+
+/// A single variant of an enum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: Name,
+    pub fields: Fields,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeAlias {
     pub name: Name,
@@ -324,6 +373,49 @@ pub struct TypeAlias {
     pub ast_id: FileAstId<ast::TypeAliasDef>,
 }
 
+/// An `impl` block (e.g. `impl Foo { ... }`) that associates functions with a type. If the block
+/// implements a trait (e.g. `impl Damageable for Foo { ... }`), `target_trait` refers to the trait.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Impl {
+    pub types: TypeRefMap,
+    pub self_ty: LocalTypeRefId,
+    pub target_trait: Option<LocalTypeRefId>,
+    pub functions: Box<[LocalItemTreeId<Function>]>,
+    pub ast_id: FileAstId<ast::Impl>,
+}
+
+/// A trait definition (e.g. `trait Damageable { ... }`) that declares the functions that types
+/// implementing the trait have to define.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trait {
+    pub name: Name,
+    pub visibility: RawVisibilityId,
+    pub functions: Box<[LocalItemTreeId<Function>]>,
+    pub ast_id: FileAstId<ast::TraitDef>,
+}
+
+/// A constant (e.g. `const MAX: i32 = 10;`) whose value is evaluated at compile time.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Const {
+    pub name: Name,
+    pub visibility: RawVisibilityId,
+    pub types: TypeRefMap,
+    pub type_ref: LocalTypeRefId,
+    pub ast_id: FileAstId<ast::ConstDef>,
+}
+
+/// A global variable (e.g. `static mut COUNTER: i32 = 0;`) that is stored in the data section of
+/// the assembly.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Static {
+    pub name: Name,
+    pub visibility: RawVisibilityId,
+    pub mutable: bool,
+    pub types: TypeRefMap,
+    pub type_ref: LocalTypeRefId,
+    pub ast_id: FileAstId<ast::StaticDef>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum StructDefKind {
     /// `struct S { ... }` - type namespace only.
@@ -398,9 +490,9 @@ impl<T> Eq for IdRange<T> {}
 
 mod diagnostics {
     use super::{ItemTree, ModItem};
-    use crate::diagnostics::DuplicateDefinition;
-    use crate::{DefDatabase, DiagnosticSink, HirDatabase, Name};
-    use mun_syntax::{AstNode, SyntaxNodePtr};
+    use crate::diagnostics::{DuplicateDefinition, UnknownAttribute};
+    use crate::{DefDatabase, DiagnosticSink, HirDatabase, InFile, Name};
+    use mun_syntax::{ast, AstNode, AstPtr, SyntaxNode, SyntaxNodePtr};
 
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub enum ItemTreeDiagnostic {
@@ -409,6 +501,10 @@ mod diagnostics {
             first: ModItem,
             second: ModItem,
         },
+        /// An attribute that is not known to the compiler. `index` is the position of the
+        /// attribute among all attributes in the syntax tree of `item`, including those of its
+        /// fields and enum variants.
+        UnknownAttribute { item: ModItem, index: usize },
     }
 
     impl ItemTreeDiagnostic {
@@ -426,29 +522,50 @@ mod diagnostics {
                 } => sink.push(DuplicateDefinition {
                     file: item_tree.file_id,
                     name: name.to_string(),
-                    first_definition: ast_ptr_from_mod(db.upcast(), item_tree, *first),
-                    definition: ast_ptr_from_mod(db.upcast(), item_tree, *second),
+                    first_definition: SyntaxNodePtr::new(&syntax_from_mod(
+                        db.upcast(),
+                        item_tree,
+                        *first,
+                    )),
+                    definition: SyntaxNodePtr::new(&syntax_from_mod(
+                        db.upcast(),
+                        item_tree,
+                        *second,
+                    )),
                 }),
+                ItemTreeDiagnostic::UnknownAttribute { item, index } => {
+                    let attr = syntax_from_mod(db.upcast(), item_tree, *item)
+                        .descendants()
+                        .filter_map(ast::Attr::cast)
+                        .nth(*index);
+                    if let Some(attr) = attr {
+                        let name = attr
+                            .path()
+                            .map(|path| path.syntax().text().to_string())
+                            .unwrap_or_default();
+                        sink.push(UnknownAttribute {
+                            attr: InFile::new(item_tree.file_id, AstPtr::new(&attr)),
+                            name,
+                        })
+                    }
+                }
             };
 
-            fn ast_ptr_from_mod(
+            fn syntax_from_mod(
                 db: &dyn DefDatabase,
                 item_tree: &ItemTree,
                 item: ModItem,
-            ) -> SyntaxNodePtr {
+            ) -> SyntaxNode {
                 match item {
-                    ModItem::Function(item) => {
-                        SyntaxNodePtr::new(item_tree.source(db, item).syntax())
-                    }
-                    ModItem::Struct(item) => {
-                        SyntaxNodePtr::new(item_tree.source(db, item).syntax())
-                    }
-                    ModItem::TypeAlias(item) => {
-                        SyntaxNodePtr::new(item_tree.source(db, item).syntax())
-                    }
-                    ModItem::Import(item) => {
-                        SyntaxNodePtr::new(item_tree.source(db, item).syntax())
-                    }
+                    ModItem::Function(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Struct(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Enum(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::TypeAlias(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Impl(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Trait(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Const(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Static(item) => item_tree.source(db, item).syntax().clone(),
+                    ModItem::Import(item) => item_tree.source(db, item).syntax().clone(),
                 }
             }
         }
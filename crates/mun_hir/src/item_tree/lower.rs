@@ -1,13 +1,15 @@
 //! This module implements the logic to convert an AST to an `ItemTree`.
 
 use super::{
-    diagnostics, Field, Fields, Function, IdRange, ItemTree, ItemTreeData, ItemTreeNode,
-    LocalItemTreeId, ModItem, RawVisibilityId, Struct, StructDefKind, TypeAlias,
+    diagnostics, AttrOwner, Const, Enum, Field, Fields, Function, IdRange, Impl, ItemTree,
+    ItemTreeData, ItemTreeNode, LocalItemTreeId, ModItem, RawVisibilityId, Static, Struct,
+    StructDefKind, Trait, TypeAlias, Variant,
 };
 use crate::item_tree::Import;
 use crate::type_ref::{TypeRefMap, TypeRefMapBuilder};
 use crate::{
     arena::{Idx, RawId},
+    attrs::{Attr, Attrs},
     name::AsName,
     source_id::AstIdMap,
     visibility::RawVisibility,
@@ -15,7 +17,8 @@ use crate::{
 };
 use mun_syntax::{
     ast,
-    ast::{ExternOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner},
+    ast::{AttrsOwner, ExternOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner},
+    AstNode,
 };
 use smallvec::SmallVec;
 use std::{collections::HashMap, convert::TryInto, marker::PhantomData, sync::Arc};
@@ -72,8 +75,12 @@ impl Context {
             let name = match item {
                 ModItem::Function(item) => Some(&self.data.functions[item.index].name),
                 ModItem::Struct(item) => Some(&self.data.structs[item.index].name),
+                ModItem::Enum(item) => Some(&self.data.enums[item.index].name),
                 ModItem::TypeAlias(item) => Some(&self.data.type_aliases[item.index].name),
-                ModItem::Import(_) => None,
+                ModItem::Trait(item) => Some(&self.data.traits[item.index].name),
+                ModItem::Const(item) => Some(&self.data.consts[item.index].name),
+                ModItem::Static(item) => Some(&self.data.statics[item.index].name),
+                ModItem::Impl(_) | ModItem::Import(_) => None,
             };
             if let Some(name) = name {
                 if let Some(first_item) = set.get(name) {
@@ -89,6 +96,29 @@ impl Context {
             }
         }
 
+        // Check duplicate functions within each `impl` block and trait
+        for item in top_level.iter() {
+            let functions = match item {
+                ModItem::Impl(item) => &self.data.impls[item.index].functions,
+                ModItem::Trait(item) => &self.data.traits[item.index].functions,
+                _ => continue,
+            };
+            let mut set = HashMap::<&Name, LocalItemTreeId<Function>>::new();
+            for &function in functions.iter() {
+                let name = &self.data.functions[function.index].name;
+                if let Some(first_function) = set.get(name) {
+                    self.diagnostics
+                        .push(diagnostics::ItemTreeDiagnostic::DuplicateDefinition {
+                            name: name.clone(),
+                            first: (*first_function).into(),
+                            second: function.into(),
+                        })
+                } else {
+                    set.insert(name, function);
+                }
+            }
+        }
+
         ItemTree {
             file_id: self.file,
             top_level,
@@ -102,7 +132,12 @@ impl Context {
         match item.kind() {
             ast::ModuleItemKind::FunctionDef(ast) => self.lower_function(&ast).map(Into::into),
             ast::ModuleItemKind::StructDef(ast) => self.lower_struct(&ast).map(Into::into),
+            ast::ModuleItemKind::EnumDef(ast) => self.lower_enum(&ast).map(Into::into),
             ast::ModuleItemKind::TypeAliasDef(ast) => self.lower_type_alias(&ast).map(Into::into),
+            ast::ModuleItemKind::Impl(ast) => self.lower_impl(&ast).map(Into::into),
+            ast::ModuleItemKind::TraitDef(ast) => self.lower_trait(&ast).map(Into::into),
+            ast::ModuleItemKind::ConstDef(ast) => self.lower_const(&ast).map(Into::into),
+            ast::ModuleItemKind::StaticDef(ast) => self.lower_static(&ast).map(Into::into),
             ast::ModuleItemKind::Use(ast) => Some(ModItems(
                 self.lower_use(&ast).into_iter().map(Into::into).collect(),
             )),
@@ -113,9 +148,10 @@ impl Context {
     fn lower_use(&mut self, use_item: &ast::Use) -> Vec<LocalItemTreeId<Import>> {
         let visibility = self.lower_visibility(use_item);
         let ast_id = self.source_ast_id_map.ast_id(use_item);
+        let attrs = Attrs::from_ast(use_item);
 
         // Every use item can expand to many `Import`s.
-        let mut imports = Vec::new();
+        let mut imports: Vec<LocalItemTreeId<Import>> = Vec::new();
         let tree = &mut self.data;
         Path::expand_use_item(
             InFile::new(self.file, use_item.clone()),
@@ -135,6 +171,13 @@ impl Context {
             },
         );
 
+        for &import in imports.iter() {
+            self.add_attrs(import.into(), attrs.clone());
+        }
+        if let Some(&first) = imports.first() {
+            self.check_attrs(first.into(), use_item.attrs());
+        }
+
         imports
     }
 
@@ -173,7 +216,13 @@ impl Context {
             ast_id,
         };
 
-        Some(self.data.functions.alloc(res).into())
+        let id: LocalItemTreeId<Function> = self.data.functions.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(func));
+        self.check_attrs(
+            id.into(),
+            func.syntax().descendants().filter_map(ast::Attr::cast),
+        );
+        Some(id)
     }
 
     /// Lowers a struct
@@ -198,7 +247,74 @@ impl Context {
             ast_id,
             kind,
         };
-        Some(self.data.structs.alloc(res).into())
+        let id: LocalItemTreeId<Struct> = self.data.structs.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(strukt));
+        self.check_attrs(
+            id.into(),
+            strukt.syntax().descendants().filter_map(ast::Attr::cast),
+        );
+        Some(id)
+    }
+
+    /// Lowers an enum
+    fn lower_enum(&mut self, enum_def: &ast::EnumDef) -> Option<LocalItemTreeId<Enum>> {
+        let name = enum_def.name()?.as_name();
+        let visibility = self.lower_visibility(enum_def);
+        let mut types = TypeRefMap::builder();
+        let variants = match enum_def.variant_list() {
+            Some(variant_list) => self.lower_variants(&variant_list, &mut types),
+            None => IdRange::new(self.next_variant_idx()..self.next_variant_idx()),
+        };
+        let ast_id = self.source_ast_id_map.ast_id(enum_def);
+
+        let (types, _types_source_map) = types.finish();
+        let res = Enum {
+            name,
+            visibility,
+            types,
+            variants,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Enum> = self.data.enums.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(enum_def));
+        self.check_attrs(
+            id.into(),
+            enum_def.syntax().descendants().filter_map(ast::Attr::cast),
+        );
+        Some(id)
+    }
+
+    /// Lowers the variants of an enum (e.g. `{ A, B(i32), C { a: i32 } }`)
+    fn lower_variants(
+        &mut self,
+        variants: &ast::EnumVariantList,
+        types: &mut TypeRefMapBuilder,
+    ) -> IdRange<Variant> {
+        // The fields of all variants are lowered before the variants themselves are allocated to
+        // ensure that the variants are stored contiguously.
+        let lowered = variants
+            .variants()
+            .filter_map(|variant| self.lower_variant(&variant, types))
+            .collect::<Vec<_>>();
+
+        let start = self.next_variant_idx();
+        for (variant, attrs) in lowered {
+            let idx = self.data.variants.alloc(variant);
+            self.add_attrs(AttrOwner::Variant(idx), attrs);
+        }
+        let end = self.next_variant_idx();
+        IdRange::new(start..end)
+    }
+
+    /// Lowers a single enum variant (e.g. `B(i32)`)
+    fn lower_variant(
+        &mut self,
+        variant: &ast::EnumVariant,
+        types: &mut TypeRefMapBuilder,
+    ) -> Option<(Variant, Attrs)> {
+        let name = variant.name()?.as_name();
+        let fields = self.lower_fields(&variant.kind(), types);
+        Some((Variant { name, fields }, Attrs::from_ast(variant)))
     }
 
     /// Lowers the fields of a struct or enum
@@ -229,7 +345,8 @@ impl Context {
         let start = self.next_field_idx();
         for field in fields.fields() {
             if let Some(data) = self.lower_record_field(&field, types) {
-                let _idx = self.data.fields.alloc(data);
+                let idx = self.data.fields.alloc(data);
+                self.add_attrs(AttrOwner::Field(idx), Attrs::from_ast(&field));
             }
         }
         let end = self.next_field_idx();
@@ -257,7 +374,8 @@ impl Context {
         let start = self.next_field_idx();
         for (i, field) in fields.fields().enumerate() {
             let data = self.lower_tuple_field(i, &field, types);
-            let _idx = self.data.fields.alloc(data);
+            let idx = self.data.fields.alloc(data);
+            self.add_attrs(AttrOwner::Field(idx), Attrs::from_ast(&field));
         }
         let end = self.next_field_idx();
         IdRange::new(start..end)
@@ -293,7 +411,112 @@ impl Context {
             type_ref,
             ast_id,
         };
-        Some(self.data.type_aliases.alloc(res).into())
+        let id: LocalItemTreeId<TypeAlias> = self.data.type_aliases.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(type_alias));
+        self.check_attrs(
+            id.into(),
+            type_alias
+                .syntax()
+                .descendants()
+                .filter_map(ast::Attr::cast),
+        );
+        Some(id)
+    }
+
+    /// Lowers an `impl` block (e.g. `impl Foo { ... }`)
+    fn lower_impl(&mut self, impl_def: &ast::Impl) -> Option<LocalItemTreeId<Impl>> {
+        let mut types = TypeRefMap::builder();
+        let self_ty = types.alloc_from_node_opt(impl_def.self_type_ref().as_ref());
+        let target_trait = impl_def
+            .trait_type_ref()
+            .map(|type_ref| types.alloc_from_node(&type_ref));
+        let functions = impl_def
+            .associated_item_list()
+            .into_iter()
+            .flat_map(|list| list.functions())
+            .filter_map(|func| self.lower_function(&func))
+            .collect();
+        let ast_id = self.source_ast_id_map.ast_id(impl_def);
+        let (types, _types_source_map) = types.finish();
+        let res = Impl {
+            types,
+            self_ty,
+            target_trait,
+            functions,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Impl> = self.data.impls.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(impl_def));
+        // The attributes of the associated functions are checked when lowering the functions
+        self.check_attrs(id.into(), impl_def.attrs());
+        Some(id)
+    }
+
+    /// Lowers a trait definition (e.g. `trait Damageable { ... }`)
+    fn lower_trait(&mut self, trait_def: &ast::TraitDef) -> Option<LocalItemTreeId<Trait>> {
+        let name = trait_def.name()?.as_name();
+        let visibility = self.lower_visibility(trait_def);
+        let functions = trait_def
+            .associated_item_list()
+            .into_iter()
+            .flat_map(|list| list.functions())
+            .filter_map(|func| self.lower_function(&func))
+            .collect();
+        let ast_id = self.source_ast_id_map.ast_id(trait_def);
+        let res = Trait {
+            name,
+            visibility,
+            functions,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Trait> = self.data.traits.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(trait_def));
+        // The attributes of the associated functions are checked when lowering the functions
+        self.check_attrs(id.into(), trait_def.attrs());
+        Some(id)
+    }
+
+    /// Lowers a constant (e.g. `const MAX: i32 = 10;`)
+    fn lower_const(&mut self, const_def: &ast::ConstDef) -> Option<LocalItemTreeId<Const>> {
+        let name = const_def.name()?.as_name();
+        let visibility = self.lower_visibility(const_def);
+        let mut types = TypeRefMap::builder();
+        let type_ref = types.alloc_from_node_opt(const_def.ascribed_type().as_ref());
+        let ast_id = self.source_ast_id_map.ast_id(const_def);
+        let (types, _types_source_map) = types.finish();
+        let res = Const {
+            name,
+            visibility,
+            types,
+            type_ref,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Const> = self.data.consts.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(const_def));
+        self.check_attrs(id.into(), const_def.attrs());
+        Some(id)
+    }
+
+    /// Lowers a global variable (e.g. `static mut COUNTER: i32 = 0;`)
+    fn lower_static(&mut self, static_def: &ast::StaticDef) -> Option<LocalItemTreeId<Static>> {
+        let name = static_def.name()?.as_name();
+        let visibility = self.lower_visibility(static_def);
+        let mut types = TypeRefMap::builder();
+        let type_ref = types.alloc_from_node_opt(static_def.ascribed_type().as_ref());
+        let ast_id = self.source_ast_id_map.ast_id(static_def);
+        let (types, _types_source_map) = types.finish();
+        let res = Static {
+            name,
+            visibility,
+            mutable: static_def.is_mut(),
+            types,
+            type_ref,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Static> = self.data.statics.alloc(res).into();
+        self.add_attrs(id.into(), Attrs::from_ast(static_def));
+        self.check_attrs(id.into(), static_def.attrs());
+        Some(id)
     }
 
     /// Lowers an `ast::VisibilityOwner`
@@ -302,6 +525,38 @@ impl Context {
         self.data.visibilities.alloc(vis)
     }
 
+    /// Stores the attributes of an item, field or enum variant
+    fn add_attrs(&mut self, owner: AttrOwner, attrs: Attrs) {
+        if !attrs.is_empty() {
+            self.data.attrs.insert(owner, attrs);
+        }
+    }
+
+    /// Reports all attributes that are not known to the compiler. `attrs` must yield the
+    /// attributes in the order in which they occur in the syntax tree of `item`.
+    fn check_attrs(&mut self, item: ModItem, attrs: impl Iterator<Item = ast::Attr>) {
+        for (index, attr) in attrs.enumerate() {
+            let is_builtin = Attr::from_ast(&attr)
+                .map(|attr| attr.is_builtin())
+                .unwrap_or(true);
+            if !is_builtin {
+                self.diagnostics
+                    .push(diagnostics::ItemTreeDiagnostic::UnknownAttribute { item, index });
+            }
+        }
+    }
+
+    /// Returns the `Idx` of the next `Variant`
+    fn next_variant_idx(&self) -> Idx<Variant> {
+        let idx: u32 = self
+            .data
+            .variants
+            .len()
+            .try_into()
+            .expect("too many variants");
+        Idx::from_raw(RawId::from(idx))
+    }
+
     /// Returns the `Idx` of the next `Field`
     fn next_field_idx(&self) -> Idx<Field> {
         let idx: u32 = self.data.fields.len().try_into().expect("too many fields");
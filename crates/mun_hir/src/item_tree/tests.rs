@@ -1,9 +1,9 @@
 use crate::with_fixture::WithFixture;
 use crate::{
     item_tree::Fields,
-    item_tree::{ItemTree, ModItem},
+    item_tree::{AttrOwner, ItemTree, ModItem},
     mock::MockDatabase,
-    DefDatabase,
+    Attrs, DefDatabase,
 };
 use std::{fmt, fmt::Write, sync::Arc};
 
@@ -24,8 +24,16 @@ fn print_item_tree(text: &str) -> Result<String, fmt::Error> {
     Ok(out)
 }
 
+fn format_attrs(out: &mut String, attrs: &Attrs) -> fmt::Result {
+    for attr in attrs.iter() {
+        writeln!(out, "{:?}", attr)?;
+    }
+    Ok(())
+}
+
 fn format_mod_item(out: &mut String, tree: &ItemTree, item: ModItem) -> fmt::Result {
     let mut children = String::new();
+    format_attrs(out, tree.attrs(item.into()))?;
     match item {
         ModItem::Function(item) => {
             write!(out, "{:?}", tree[item])?;
@@ -35,15 +43,54 @@ fn format_mod_item(out: &mut String, tree: &ItemTree, item: ModItem) -> fmt::Res
             match &tree[item].fields {
                 Fields::Record(a) | Fields::Tuple(a) => {
                     for field in a.clone() {
+                        format_attrs(&mut children, tree.attrs(AttrOwner::Field(field)))?;
                         writeln!(children, "{:?}", tree[field])?;
                     }
                 }
                 _ => {}
             };
         }
+        ModItem::Enum(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for variant in tree[item].variants.clone() {
+                format_attrs(&mut children, tree.attrs(AttrOwner::Variant(variant)))?;
+                writeln!(children, "{:?}", tree[variant])?;
+                match &tree[variant].fields {
+                    Fields::Record(a) | Fields::Tuple(a) => {
+                        for field in a.clone() {
+                            for attr in tree.attrs(AttrOwner::Field(field)).iter() {
+                                writeln!(children, "  {:?}", attr)?;
+                            }
+                            writeln!(children, "  {:?}", tree[field])?;
+                        }
+                    }
+                    _ => {}
+                };
+            }
+        }
         ModItem::TypeAlias(item) => {
             write!(out, "{:?}", tree[item])?;
         }
+        ModItem::Const(item) => {
+            write!(out, "{:?}", tree[item])?;
+        }
+        ModItem::Static(item) => {
+            write!(out, "{:?}", tree[item])?;
+        }
+        ModItem::Impl(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for function in tree[item].functions.iter() {
+                format_attrs(&mut children, tree.attrs((*function).into()))?;
+                writeln!(children, "{:?}", tree[*function])?;
+            }
+        }
+        ModItem::Trait(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for function in tree[item].functions.iter() {
+                format_attrs(&mut children, tree.attrs((*function).into()))?;
+                writeln!(children, "{:?}", tree[*function])?;
+            }
+        }
         ModItem::Import(item) => {
             write!(out, "{:?}", tree[item])?;
         }
@@ -85,3 +132,45 @@ fn top_level_items() {
     )
     .unwrap());
 }
+
+#[test]
+fn enums() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    enum Foo {
+        A,
+        B(i32, u8),
+        C { a: i32, b: String },
+    }
+    pub enum Bar {}
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn attributes() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    #[test]
+    fn foo() {}
+
+    #[deprecated = "use `Baz` instead"]
+    struct Bar {
+        #[deprecated]
+        a: i32,
+    }
+
+    enum Baz {
+        #[deprecated]
+        A(#[deprecated] i32),
+    }
+
+    impl Bar {
+        #[inline(always)]
+        fn bar(self: Self) {}
+    }
+    "#
+    )
+    .unwrap());
+}
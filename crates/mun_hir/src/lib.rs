@@ -8,6 +8,9 @@
 pub use salsa;
 
 pub use crate::{
+    attrs::{Attr, AttrInput, Attrs},
+    builtin_module::{BuiltinModule, IntrinsicFunction},
+    const_eval::{ConstEvalError, ConstValue},
     db::{
         AstDatabase, AstDatabaseStorage, DefDatabase, DefDatabaseStorage, HirDatabase,
         HirDatabaseStorage, InternDatabase, InternDatabaseStorage, SourceDatabase,
@@ -16,8 +19,8 @@ pub use crate::{
     diagnostics::{Diagnostic, DiagnosticSink},
     display::HirDisplay,
     expr::{
-        ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, ExprScopes, Literal, LogicOp, Ordering, Pat,
-        PatId, RecordLitField, Statement, UnaryOp,
+        ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, ExprScopes, Literal, LogicOp, MatchArm,
+        Ordering, Pat, PatId, RecordFieldPat, RecordLitField, Statement, UnaryOp,
     },
     ids::{ItemLoc, ModuleId},
     in_file::InFile,
@@ -29,23 +32,26 @@ pub use crate::{
     primitive_type::{FloatBitness, IntBitness, Signedness},
     resolve::{resolver_for_expr, resolver_for_scope, Resolver, TypeNs, ValueNs},
     ty::{
-        lower::CallableDef, FloatTy, InferenceResult, IntTy, ResolveBitness, Substitution, Ty,
-        TyKind,
+        lower::CallableDef, FloatTy, FnSig, InferenceResult, IntTy, ResolveBitness, Substitution,
+        Ty, TyKind,
     },
     visibility::{HasVisibility, Visibility},
 };
 use crate::{name::AsName, source_id::AstIdMap};
 
 pub use self::code_model::{
-    Field, Function, FunctionData, HasSource, Module, ModuleDef, Package, Struct, StructMemoryKind,
-    TypeAlias,
+    Const, Enum, EnumVariant, Field, Function, FunctionData, HasSource, Impl, Module, ModuleDef,
+    Package, Static, Struct, StructMemoryKind, Trait, TypeAlias, VariantField,
 };
 
 #[macro_use]
 mod macros;
 #[macro_use]
 mod arena;
+mod attrs;
+mod builtin_module;
 mod code_model;
+mod const_eval;
 mod db;
 pub mod diagnostics;
 mod display;
@@ -54,7 +54,7 @@ impl Name {
         Name::new_text("[missing name]".into())
     }
 
-    pub(crate) fn as_tuple_index(&self) -> Option<usize> {
+    pub fn as_tuple_index(&self) -> Option<usize> {
         match self.0 {
             Repr::TupleField(idx) => Some(idx),
             _ => None,
@@ -104,9 +104,27 @@ pub mod known {
     known_names!(
         // Primitives
         int, isize, i8, i16, i32, i64, i128, uint, usize, u8, u16, u32, u64, u128, float, f32, f64,
-        bool,
+        bool, string,
     );
 
+    // Built-in methods
+    known_names!(len, push, pop, insert, remove);
+
+    // Built-in modules and their functions
+    known_names!(core, math, sqrt, sin, cos, abs, min, max, floor, pow, panic);
+
+    // Methods that overload operators
+    known_names!(add, sub, mul, div, rem, shl, shr, bitand, bitor, bitxor, eq, lt, le, gt, ge);
+
+    // Built-in attributes
+    known_names!(test, inline, deprecated);
+
+    /// The name of the `self` parameter of a method
+    pub const SELF_PARAM: super::Name = super::Name::new_inline("self");
+
+    /// The name of the type for which an `impl` block or trait defines functions
+    pub const SELF_TYPE: super::Name = super::Name::new_inline("Self");
+
     #[macro_export]
     macro_rules! name {
         ($ident:ident) => {
@@ -1,5 +1,6 @@
 use crate::{
-    ids::{ItemDefinitionId, ModuleId},
+    code_model::StructKind,
+    ids::{EnumVariantId, ItemDefinitionId, ModuleId},
     item_scope::BUILTIN_SCOPE,
     module_tree::LocalModuleId,
     package_defs::PackageDefs,
@@ -128,7 +129,29 @@ impl PackageDefs {
 
             curr_per_ns = match curr {
                 ItemDefinitionId::ModuleId(module) => self[module.local_id].get(segment),
-                // TODO: Enum variants
+                ItemDefinitionId::BuiltinModule(module) => module.get(segment),
+                ItemDefinitionId::EnumId(e) => {
+                    let enum_data = db.enum_data(e);
+                    match enum_data.variant(segment) {
+                        Some(local_id) => {
+                            let variant = EnumVariantId {
+                                parent: e,
+                                local_id,
+                            };
+                            let has_constructor =
+                                enum_data.variants[local_id].kind != StructKind::Record;
+                            PerNs::from_definition(variant.into(), vis, has_constructor)
+                        }
+                        None => {
+                            return ResolvePathResult::with(
+                                PerNs::types((e.into(), vis)),
+                                ReachedFixedPoint::Yes,
+                                Some(i),
+                                Some(self.module_tree.package),
+                            );
+                        }
+                    }
+                }
                 s => {
                     return ResolvePathResult::with(
                         PerNs::types((s, vis)),
@@ -1,12 +1,15 @@
 use super::PackageDefs;
 use crate::{
     ids::ItemDefinitionId,
-    ids::{FunctionLoc, Intern, StructLoc, TypeAliasLoc},
+    ids::{
+        ConstLoc, EnumLoc, FunctionLoc, ImplLoc, Intern, ItemContainerId, StaticLoc, StructLoc,
+        TraitLoc, TypeAliasLoc,
+    },
     item_scope::ImportType,
     item_scope::{ItemScope, PerNsGlobImports},
     item_tree::{
-        self, Function, ItemTree, ItemTreeId, LocalItemTreeId, ModItem, Struct, StructDefKind,
-        TypeAlias,
+        self, Const, Enum, Function, Impl, ItemTree, ItemTreeId, LocalItemTreeId, ModItem, Static,
+        Struct, StructDefKind, Trait, TypeAlias,
     },
     module_tree::LocalModuleId,
     name_resolution::ReachedFixedPoint,
@@ -316,6 +319,25 @@ impl<'db> DefCollector<'db> {
                         glob.push((import_module_id, import_visibility, import.source));
                     }
                 }
+                Some((ItemDefinitionId::BuiltinModule(m), _)) => {
+                    // The items of a builtin module are all public and never change
+                    let resolutions = m
+                        .entries()
+                        .into_iter()
+                        .map(|(name, resolution)| ImportResolution {
+                            name: Some(name),
+                            resolution,
+                        })
+                        .collect::<Vec<_>>();
+
+                    self.update(
+                        import_module_id,
+                        import_visibility,
+                        ImportType::Glob,
+                        import.source,
+                        &resolutions,
+                    );
+                }
                 Some((_, _)) => {
                     // Happens when wildcard importing something other than a module. I guess it's ok to do nothing here?
                 }
@@ -502,7 +524,15 @@ impl<'a> ModCollectorContext<'a, '_> {
             } = match item {
                 ModItem::Function(id) => self.collect_function(id),
                 ModItem::Struct(id) => self.collect_struct(id),
+                ModItem::Enum(id) => self.collect_enum(id),
                 ModItem::TypeAlias(id) => self.collect_type_alias(id),
+                ModItem::Trait(id) => self.collect_trait(id),
+                ModItem::Const(id) => self.collect_const(id),
+                ModItem::Static(id) => self.collect_static(id),
+                ModItem::Impl(id) => {
+                    self.collect_impl(id);
+                    continue;
+                }
                 ModItem::Import(id) => {
                     self.collect_import(id);
                     continue;
@@ -531,16 +561,41 @@ impl<'a> ModCollectorContext<'a, '_> {
         });
     }
 
+    /// Collects an `impl` block and the functions defined in it. The functions are added as
+    /// definitions of the module but are not added to its scope; they can only be accessed through
+    /// the type of the `impl`.
+    fn collect_impl(&mut self, id: LocalItemTreeId<Impl>) {
+        let impl_id = ImplLoc {
+            module: ModuleId {
+                package: self.def_collector.package_id,
+                local_id: self.module_id,
+            },
+            id: ItemTreeId::new(self.file_id, id),
+        }
+        .intern(self.def_collector.db);
+
+        let scope = &mut self.def_collector.package_defs.modules[self.module_id];
+        scope.add_impl(impl_id);
+        for &function in self.item_tree[id].functions.iter() {
+            let function_id = FunctionLoc {
+                container: ItemContainerId::ImplId(impl_id),
+                id: ItemTreeId::new(self.file_id, function),
+            }
+            .intern(self.def_collector.db);
+            scope.add_definition(function_id.into());
+        }
+    }
+
     /// Collects the definition data from a `Function`
     #[warn(clippy::unnecessary_wraps)]
     fn collect_function(&self, id: LocalItemTreeId<Function>) -> DefData<'a> {
         let func = &self.item_tree[id];
         DefData {
             id: FunctionLoc {
-                module: ModuleId {
+                container: ItemContainerId::ModuleId(ModuleId {
                     package: self.def_collector.package_id,
                     local_id: self.module_id,
-                },
+                }),
                 id: ItemTreeId::new(self.file_id, id),
             }
             .intern(self.def_collector.db)
@@ -570,6 +625,25 @@ impl<'a> ModCollectorContext<'a, '_> {
         }
     }
 
+    /// Collects the definition data from an `Enum`
+    fn collect_enum(&self, id: LocalItemTreeId<Enum>) -> DefData<'a> {
+        let adt = &self.item_tree[id];
+        DefData {
+            id: EnumLoc {
+                module: ModuleId {
+                    package: self.def_collector.package_id,
+                    local_id: self.module_id,
+                },
+                id: ItemTreeId::new(self.file_id, id),
+            }
+            .intern(self.def_collector.db)
+            .into(),
+            name: &adt.name,
+            visibility: &self.item_tree[adt.visibility],
+            has_constructor: false,
+        }
+    }
+
     /// Collects the definition data from a `TypeAlias`
     fn collect_type_alias(&self, id: LocalItemTreeId<TypeAlias>) -> DefData<'a> {
         let type_alias = &self.item_tree[id];
@@ -588,6 +662,64 @@ impl<'a> ModCollectorContext<'a, '_> {
             has_constructor: false,
         }
     }
+
+    /// Collects the definition data from a `Trait`. The functions declared by the trait are not
+    /// added to the module; they can only be accessed through the trait.
+    fn collect_trait(&self, id: LocalItemTreeId<Trait>) -> DefData<'a> {
+        let trait_def = &self.item_tree[id];
+        DefData {
+            id: TraitLoc {
+                module: ModuleId {
+                    package: self.def_collector.package_id,
+                    local_id: self.module_id,
+                },
+                id: ItemTreeId::new(self.file_id, id),
+            }
+            .intern(self.def_collector.db)
+            .into(),
+            name: &trait_def.name,
+            visibility: &self.item_tree[trait_def.visibility],
+            has_constructor: false,
+        }
+    }
+
+    /// Collects the definition data from a `Const`
+    fn collect_const(&self, id: LocalItemTreeId<Const>) -> DefData<'a> {
+        let const_def = &self.item_tree[id];
+        DefData {
+            id: ConstLoc {
+                module: ModuleId {
+                    package: self.def_collector.package_id,
+                    local_id: self.module_id,
+                },
+                id: ItemTreeId::new(self.file_id, id),
+            }
+            .intern(self.def_collector.db)
+            .into(),
+            name: &const_def.name,
+            visibility: &self.item_tree[const_def.visibility],
+            has_constructor: false,
+        }
+    }
+
+    /// Collects the definition data from a `Static`
+    fn collect_static(&self, id: LocalItemTreeId<Static>) -> DefData<'a> {
+        let static_def = &self.item_tree[id];
+        DefData {
+            id: StaticLoc {
+                module: ModuleId {
+                    package: self.def_collector.package_id,
+                    local_id: self.module_id,
+                },
+                id: ItemTreeId::new(self.file_id, id),
+            }
+            .intern(self.def_collector.db)
+            .into(),
+            name: &static_def.name,
+            visibility: &self.item_tree[static_def.visibility],
+            has_constructor: false,
+        }
+    }
 }
 
 struct DefData<'a> {
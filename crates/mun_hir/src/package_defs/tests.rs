@@ -1,7 +1,7 @@
 use crate::{
     db::DefDatabase, ids::ItemDefinitionId, mock::MockDatabase, package_defs::PackageDefs,
-    with_fixture::WithFixture, DiagnosticSink, Function, HirDatabase, Module, Package, Struct,
-    TypeAlias,
+    with_fixture::WithFixture, Const, DiagnosticSink, Enum, EnumVariant, Function, HirDatabase,
+    Module, Package, Static, Struct, Trait, TypeAlias,
 };
 use rustc_hash::FxHashSet;
 
@@ -176,6 +176,24 @@ fn use_unresolved() {
     "###);
 }
 
+#[test]
+fn use_builtin_module() {
+    insta::assert_snapshot!(resolve(
+        r#"
+    //- /foo.mun
+    use core::math;             // works
+    use core::math::sqrt;       // works
+    use core::math::*;          // works
+    use core::math::tan;        // doesnt work (tan does not exist)
+    "#),
+    @r###"
+    mod mod
+    '-- mod foo
+        +-- ERROR: 115..130: unresolved import
+        '-- use mod core::math
+    "###);
+}
+
 #[test]
 fn use_() {
     insta::assert_snapshot!(resolve(
@@ -309,6 +327,31 @@ fn tree_for_module(
                     node.push(format!("use struct {}", fully_qualified_name));
                 }
             }
+            ItemDefinitionId::EnumId(e) => {
+                let e: Enum = (*e).into();
+                let name = e.name(db);
+                if is_local {
+                    node.push(format!("enum {}", name));
+                } else {
+                    let fully_qualified_name = format!(
+                        "{}::{}",
+                        fully_qualified_module_path(db, e.module(db)),
+                        name
+                    );
+                    node.push(format!("use enum {}", fully_qualified_name));
+                }
+            }
+            ItemDefinitionId::EnumVariantId(variant) => {
+                let variant: EnumVariant = (*variant).into();
+                let parent = variant.parent_enum();
+                let fully_qualified_name = format!(
+                    "{}::{}::{}",
+                    fully_qualified_module_path(db, parent.module(db)),
+                    parent.name(db),
+                    variant.name(db)
+                );
+                node.push(format!("use variant {}", fully_qualified_name));
+            }
             ItemDefinitionId::TypeAliasId(alias) => {
                 let alias: TypeAlias = (*alias).into();
                 let name = alias.name(db);
@@ -323,7 +366,52 @@ fn tree_for_module(
                     node.push(format!("use type {}", fully_qualified_name));
                 }
             }
-            ItemDefinitionId::PrimitiveType(_) => {}
+            ItemDefinitionId::TraitId(t) => {
+                let t: Trait = (*t).into();
+                let name = t.name(db);
+                if is_local {
+                    node.push(format!("trait {}", name));
+                } else {
+                    let fully_qualified_name = format!(
+                        "{}::{}",
+                        fully_qualified_module_path(db, t.module(db)),
+                        name
+                    );
+                    node.push(format!("use trait {}", fully_qualified_name));
+                }
+            }
+            ItemDefinitionId::ConstId(c) => {
+                let c: Const = (*c).into();
+                let name = c.name(db);
+                if is_local {
+                    node.push(format!("const {}", name));
+                } else {
+                    let fully_qualified_name = format!(
+                        "{}::{}",
+                        fully_qualified_module_path(db, c.module(db)),
+                        name
+                    );
+                    node.push(format!("use const {}", fully_qualified_name));
+                }
+            }
+            ItemDefinitionId::StaticId(s) => {
+                let s: Static = (*s).into();
+                let name = s.name(db);
+                if is_local {
+                    node.push(format!("static {}", name));
+                } else {
+                    let fully_qualified_name = format!(
+                        "{}::{}",
+                        fully_qualified_module_path(db, s.module(db)),
+                        name
+                    );
+                    node.push(format!("use static {}", fully_qualified_name));
+                }
+            }
+            ItemDefinitionId::BuiltinModule(m) => {
+                node.push(format!("use mod {}", m));
+            }
+            ItemDefinitionId::PrimitiveType(_) | ItemDefinitionId::IntrinsicFunction(_) => {}
         }
     }
 
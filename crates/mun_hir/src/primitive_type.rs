@@ -45,12 +45,14 @@ pub enum PrimitiveType {
     Float(PrimitiveFloat),
     Int(PrimitiveInt),
     Bool,
+    String,
 }
 
 impl PrimitiveType {
     #[rustfmt::skip]
     pub const ALL: &'static [(Name, PrimitiveType)] = &[
         (name![bool], PrimitiveType::Bool),
+        (name![string], PrimitiveType::String),
 
         (name![isize], PrimitiveType::Int(PrimitiveInt::ISIZE)),
         (name![i8], PrimitiveType::Int(PrimitiveInt::I8)),
@@ -75,6 +77,7 @@ impl fmt::Display for PrimitiveType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let type_name = match self {
             PrimitiveType::Bool => "bool",
+            PrimitiveType::String => "string",
             PrimitiveType::Int(PrimitiveInt {
                 signedness,
                 bitness,
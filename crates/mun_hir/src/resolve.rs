@@ -1,8 +1,11 @@
+use crate::builtin_module::IntrinsicFunction;
 use crate::ids::{
-    DefWithBodyId, FunctionId, ItemDefinitionId, Lookup, ModuleId, StructId, TypeAliasId,
+    ConstId, DefWithBodyId, EnumId, EnumVariantId, FunctionId, ImplId, ItemContainerId,
+    ItemDefinitionId, Lookup, ModuleId, StaticId, StructId, TraitId, TypeAliasId,
 };
 use crate::item_scope::BUILTIN_SCOPE;
 use crate::module_tree::LocalModuleId;
+use crate::name::name;
 use crate::package_defs::PackageDefs;
 use crate::primitive_type::PrimitiveType;
 use crate::visibility::RawVisibility;
@@ -22,6 +25,15 @@ pub(crate) enum Scope {
     /// All the items and imported names of a module
     ModuleScope(ModuleItemMap),
 
+    /// The type parameters of a generic function or struct
+    GenericParams(Arc<[Name]>),
+
+    /// The items of an `impl` block, in which `Self` refers to the type of the block
+    ImplItems(ImplId),
+
+    /// The items of a trait, in which `Self` refers to the trait object type of the trait
+    TraitItems(TraitId),
+
     /// Local bindings
     ExprScope(ExprScope),
 }
@@ -50,13 +62,26 @@ pub enum ValueNs {
     LocalBinding(PatId),
     FunctionId(FunctionId),
     StructId(StructId),
+    EnumVariantId(EnumVariantId),
+    ConstId(ConstId),
+    StaticId(StaticId),
+    IntrinsicFunction(IntrinsicFunction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeNs {
     StructId(StructId),
+    EnumId(EnumId),
+    EnumVariantId(EnumVariantId),
     TypeAliasId(TypeAliasId),
     PrimitiveType(PrimitiveType),
+    /// The type parameter at the specified index of the enclosing generic item
+    GenericParam(u32),
+    TraitId(TraitId),
+    /// `Self` within an `impl` block
+    SelfType(ImplId),
+    /// `Self` within a trait
+    TraitSelfType(TraitId),
 }
 
 /// An item definition visible from a certain scope.
@@ -84,6 +109,26 @@ impl Resolver {
         }))
     }
 
+    /// Adds the type parameters of a generic item to the resolver. Does nothing if the item does
+    /// not have any type parameters.
+    pub(crate) fn push_generic_params_scope(self, type_params: &[Name]) -> Resolver {
+        if type_params.is_empty() {
+            self
+        } else {
+            self.push_scope(Scope::GenericParams(type_params.into()))
+        }
+    }
+
+    /// Adds the scope of the `impl` block or trait that contains a function to the resolver. Does
+    /// nothing if the function is defined at the top-level of a module.
+    pub(crate) fn push_container_scope(self, container: ItemContainerId) -> Resolver {
+        match container {
+            ItemContainerId::ModuleId(_) => self,
+            ItemContainerId::ImplId(id) => self.push_scope(Scope::ImplItems(id)),
+            ItemContainerId::TraitId(id) => self.push_scope(Scope::TraitItems(id)),
+        }
+    }
+
     /// Adds an expression scope from which it can resolve names
     pub(crate) fn push_expr_scope(
         self,
@@ -168,7 +213,10 @@ impl Resolver {
                         ));
                     }
                 }
-                Scope::ExprScope(_) => continue,
+                Scope::ExprScope(_)
+                | Scope::GenericParams(_)
+                | Scope::ImplItems(_)
+                | Scope::TraitItems(_) => continue,
 
                 Scope::ModuleScope(m) => {
                     let (module_def, idx) =
@@ -181,12 +229,21 @@ impl Resolver {
                         Some(idx) => {
                             let ty = match module_def.take_types()? {
                                 (ItemDefinitionId::StructId(id), _) => TypeNs::StructId(id),
+                                (ItemDefinitionId::EnumId(id), _) => TypeNs::EnumId(id),
+                                (ItemDefinitionId::EnumVariantId(id), _) => {
+                                    TypeNs::EnumVariantId(id)
+                                }
                                 (ItemDefinitionId::TypeAliasId(id), _) => TypeNs::TypeAliasId(id),
                                 (ItemDefinitionId::PrimitiveType(id), _) => {
                                     TypeNs::PrimitiveType(id)
                                 }
                                 (ItemDefinitionId::ModuleId(_), _)
-                                | (ItemDefinitionId::FunctionId(_), _) => return None,
+                                | (ItemDefinitionId::FunctionId(_), _)
+                                | (ItemDefinitionId::TraitId(_), _)
+                                | (ItemDefinitionId::ConstId(_), _)
+                                | (ItemDefinitionId::StaticId(_), _)
+                                | (ItemDefinitionId::BuiltinModule(_), _)
+                                | (ItemDefinitionId::IntrinsicFunction(_), _) => return None,
                             };
                             Some(ResolveValueResult::Partial(ty, idx))
                         }
@@ -202,9 +259,18 @@ impl Resolver {
             let (res, vis) = match per_ns.take_values()? {
                 (ItemDefinitionId::FunctionId(id), vis) => (ValueNs::FunctionId(id), vis),
                 (ItemDefinitionId::StructId(id), vis) => (ValueNs::StructId(id), vis),
+                (ItemDefinitionId::EnumVariantId(id), vis) => (ValueNs::EnumVariantId(id), vis),
+                (ItemDefinitionId::ConstId(id), vis) => (ValueNs::ConstId(id), vis),
+                (ItemDefinitionId::StaticId(id), vis) => (ValueNs::StaticId(id), vis),
+                (ItemDefinitionId::IntrinsicFunction(id), vis) => {
+                    (ValueNs::IntrinsicFunction(id), vis)
+                }
                 (ItemDefinitionId::ModuleId(_), _)
+                | (ItemDefinitionId::EnumId(_), _)
                 | (ItemDefinitionId::TypeAliasId(_), _)
-                | (ItemDefinitionId::PrimitiveType(_), _) => return None,
+                | (ItemDefinitionId::TraitId(_), _)
+                | (ItemDefinitionId::PrimitiveType(_), _)
+                | (ItemDefinitionId::BuiltinModule(_), _) => return None,
             };
             Some((res, vis))
         }
@@ -232,6 +298,24 @@ impl Resolver {
         for scope in self.scopes.iter().rev() {
             match scope {
                 Scope::ExprScope(_) => continue,
+                Scope::GenericParams(type_params) => {
+                    let idx = path
+                        .as_ident()
+                        .and_then(|name| type_params.iter().position(|param| param == name));
+                    if let Some(idx) = idx {
+                        return Some((TypeNs::GenericParam(idx as u32), Visibility::Public, None));
+                    }
+                }
+                Scope::ImplItems(id) => {
+                    if path.as_ident() == Some(&name![SELF_TYPE]) {
+                        return Some((TypeNs::SelfType(*id), Visibility::Public, None));
+                    }
+                }
+                Scope::TraitItems(id) => {
+                    if path.as_ident() == Some(&name![SELF_TYPE]) {
+                        return Some((TypeNs::TraitSelfType(*id), Visibility::Public, None));
+                    }
+                }
                 Scope::ModuleScope(m) => {
                     let (module_def, idx) =
                         m.package_defs.resolve_path_in_module(db, m.module_id, path);
@@ -246,10 +330,18 @@ impl Resolver {
         ) -> Option<(TypeNs, Visibility)> {
             let (res, vis) = match per_ns.take_types()? {
                 (ItemDefinitionId::StructId(id), vis) => (TypeNs::StructId(id), vis),
+                (ItemDefinitionId::EnumId(id), vis) => (TypeNs::EnumId(id), vis),
+                (ItemDefinitionId::EnumVariantId(id), vis) => (TypeNs::EnumVariantId(id), vis),
                 (ItemDefinitionId::TypeAliasId(id), vis) => (TypeNs::TypeAliasId(id), vis),
                 (ItemDefinitionId::PrimitiveType(id), vis) => (TypeNs::PrimitiveType(id), vis),
+                (ItemDefinitionId::TraitId(id), vis) => (TypeNs::TraitId(id), vis),
 
-                (ItemDefinitionId::ModuleId(_), _) | (ItemDefinitionId::FunctionId(_), _) => {
+                (ItemDefinitionId::ModuleId(_), _)
+                | (ItemDefinitionId::FunctionId(_), _)
+                | (ItemDefinitionId::ConstId(_), _)
+                | (ItemDefinitionId::StaticId(_), _)
+                | (ItemDefinitionId::BuiltinModule(_), _)
+                | (ItemDefinitionId::IntrinsicFunction(_), _) => {
                     return None;
                 }
             };
@@ -307,6 +399,7 @@ impl Scope {
                     visitor(name.clone(), ScopeDef::PerNs(def));
                 })
             }
+            Scope::GenericParams(_) | Scope::ImplItems(_) | Scope::TraitItems(_) => {}
             Scope::ExprScope(scope) => scope
                 .expr_scopes
                 .entries(scope.scope_id)
@@ -352,11 +445,25 @@ impl HasResolver for ModuleId {
 
 impl HasResolver for FunctionId {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver {
-        self.lookup(db).module.resolver(db)
+        let container = self.lookup(db).container;
+        container
+            .module(db)
+            .resolver(db)
+            .push_container_scope(container)
+            .push_generic_params_scope(db.fn_data(self).type_params())
     }
 }
 
 impl HasResolver for StructId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db)
+            .module
+            .resolver(db)
+            .push_generic_params_scope(&db.struct_data(self).type_params)
+    }
+}
+
+impl HasResolver for EnumId {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver {
         self.lookup(db).module.resolver(db)
     }
@@ -368,10 +475,36 @@ impl HasResolver for TypeAliasId {
     }
 }
 
+impl HasResolver for ImplId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).module.resolver(db)
+    }
+}
+
+impl HasResolver for TraitId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).module.resolver(db)
+    }
+}
+
+impl HasResolver for ConstId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).module.resolver(db)
+    }
+}
+
+impl HasResolver for StaticId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).module.resolver(db)
+    }
+}
+
 impl HasResolver for DefWithBodyId {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver {
         match self {
             DefWithBodyId::FunctionId(f) => f.resolver(db),
+            DefWithBodyId::ConstId(c) => c.resolver(db),
+            DefWithBodyId::StaticId(s) => s.resolver(db),
         }
     }
 }
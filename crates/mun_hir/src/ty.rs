@@ -1,5 +1,6 @@
 mod infer;
 pub(super) mod lower;
+mod method_resolution;
 mod op;
 mod primitives;
 mod resolve;
@@ -7,14 +8,15 @@ mod resolve;
 use crate::{
     display::{HirDisplay, HirFormatter},
     ty::infer::InferTy,
-    ty::lower::fn_sig_for_struct_constructor,
-    HasVisibility, HirDatabase, Struct, StructMemoryKind, TypeAlias, Visibility,
+    ty::lower::{fn_sig_for_enum_variant_constructor, fn_sig_for_struct_constructor},
+    Enum, HasVisibility, HirDatabase, Name, Struct, StructMemoryKind, Trait, TypeAlias, Visibility,
 };
 pub(crate) use infer::infer_query;
 pub use infer::InferenceResult;
 pub(crate) use lower::{
     callable_item_sig, fn_sig_for_fn, type_for_cycle_recover, type_for_def, CallableDef, TypableDef,
 };
+pub use method_resolution::{InherentImpls, TraitImpls};
 pub use primitives::{FloatTy, IntTy};
 pub use resolve::ResolveBitness;
 use smallvec::SmallVec;
@@ -26,9 +28,12 @@ mod tests;
 /// A kind of type.
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum TyKind {
-    /// An abstract datatype (structures, tuples, or enumerations)
-    /// TODO: Add enumerations
-    Struct(Struct),
+    /// An abstract datatype (structures or tuples) with the types that are substituted for its
+    /// type parameters. For example `Pair<i32, f32>`.
+    Struct(Struct, Substitution),
+
+    /// An enumeration of variants. Enums are always value types.
+    Enum(Enum),
 
     /// The primitive floating point type. Written as `float`.
     Float(FloatTy),
@@ -39,12 +44,20 @@ pub enum TyKind {
     /// The primitive boolean type. Written as `bool`.
     Bool,
 
+    /// The primitive string type. Written as `string`. Strings are immutable, UTF-8 encoded and
+    /// allocated by the garbage collector.
+    String,
+
     /// A tuple type. For example `(f32, f64, bool)`.
     Tuple(usize, Substitution),
 
     /// A type variable used during type checking. Not to be confused with a type parameter.
     InferenceVar(InferTy),
 
+    /// A type parameter of a generic function or struct; for example `T` in `fn max<T>(..)`. The
+    /// `idx` is the index of the parameter in the list of type parameters of its definition.
+    Param { idx: u32, name: Name },
+
     /// A type alias
     TypeAlias(TypeAlias),
 
@@ -65,9 +78,21 @@ pub enum TyKind {
     /// ```
     FnDef(CallableDef, Substitution),
 
+    /// A pointer to a function, e.g. `fn(i32) -> bool`. Values of this type are either functions or
+    /// closures. The substitution holds the types of the parameters followed by the return type.
+    FnPtr(Substitution),
+
     /// An dynamically sized array type
     Array(Ty),
 
+    /// An optional reference to a garbage collected struct or an array, e.g. `?Foo`. A value of
+    /// this type is either a reference to a value of the inner type or `nil`.
+    Option(Ty),
+
+    /// A trait object, e.g. `dyn Damageable`. Values of this type are references to garbage
+    /// collected structs that implement the trait.
+    DynTrait(Trait),
+
     /// A placeholder for a type which could not be computed; this is propagated to avoid useless
     /// error messages. Doubles as a placeholder where type variables are inserted before type
     /// checking, since we want to try to infer a better type here anyway -- for the IDE use case,
@@ -95,9 +120,11 @@ impl TyKind {
 impl HasVisibility for TyKind {
     fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
         match self {
-            TyKind::Struct(strukt) => strukt.visibility(db),
+            TyKind::Struct(strukt, _) => strukt.visibility(db),
+            TyKind::Enum(enum_def) => enum_def.visibility(db),
             TyKind::TypeAlias(type_alias) => type_alias.visibility(db),
             TyKind::FnDef(callable_def, _) => callable_def.visibility(db),
+            TyKind::DynTrait(trait_def) => trait_def.visibility(db),
             _ => Visibility::Public,
         }
     }
@@ -126,15 +153,61 @@ impl Ty {
         TyKind::Tuple(0, Substitution::empty()).intern()
     }
 
-    /// Constructs a new struct type
-    pub fn struct_ty(strukt: Struct) -> Ty {
-        TyKind::Struct(strukt).intern()
+    /// Constructs a new struct type with the specified types substituted for its type parameters
+    pub fn struct_ty(strukt: Struct, substs: Substitution) -> Ty {
+        TyKind::Struct(strukt, substs).intern()
+    }
+
+    /// Constructs a new function pointer type with the specified signature
+    pub fn fn_ptr(sig: FnSig) -> Ty {
+        TyKind::FnPtr(sig.params_and_return.iter().cloned().collect()).intern()
     }
 
     /// If this type represents a struct type, returns the type of the struct.
     pub fn as_struct(&self) -> Option<Struct> {
         match self.interned() {
-            TyKind::Struct(s) => Some(*s),
+            TyKind::Struct(s, _) => Some(*s),
+            _ => None,
+        }
+    }
+
+    /// If this type represents a struct type, returns the types of its fields with the type
+    /// arguments of this type substituted for the type parameters of the struct.
+    pub fn struct_field_types(&self, db: &dyn HirDatabase) -> Option<Vec<Ty>> {
+        match self.interned() {
+            TyKind::Struct(s, substs) => Some(
+                s.fields(db)
+                    .into_iter()
+                    .map(|field| field.ty(db).subst(substs))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this type is, or contains, a type parameter.
+    pub fn has_type_params(&self) -> bool {
+        let mut result = false;
+        self.walk(&mut |ty| {
+            if matches!(ty.interned(), TyKind::Param { .. }) {
+                result = true;
+            }
+        });
+        result
+    }
+
+    /// Replaces all type parameters in this type by the type at the same index in `substs`.
+    pub fn subst(self, substs: &Substitution) -> Ty {
+        self.fold(&mut |ty| match ty.interned() {
+            TyKind::Param { idx, .. } => substs.get(*idx as usize).cloned().unwrap_or(ty),
+            _ => ty,
+        })
+    }
+
+    /// If this type represents an enum type, returns the type of the enum.
+    pub fn as_enum(&self) -> Option<Enum> {
+        match self.interned() {
+            TyKind::Enum(e) => Some(*e),
             _ => None,
         }
     }
@@ -155,6 +228,24 @@ impl Ty {
         }
     }
 
+    /// If this type represents an optional type, returns a reference to the inner type.
+    pub fn as_option(&self) -> Option<&Ty> {
+        match self.interned() {
+            TyKind::Option(inner_ty) => Some(inner_ty),
+            _ => None,
+        }
+    }
+
+    /// Returns true if a value of this type is a reference that can be wrapped in an optional type.
+    /// This is the case for garbage collected structs and arrays.
+    pub fn is_nullable_reference(&self, db: &dyn HirDatabase) -> bool {
+        match self.interned() {
+            TyKind::Struct(s, _) => s.data(db.upcast()).memory_kind == StructMemoryKind::Gc,
+            TyKind::Array(_) | TyKind::Unknown => true,
+            _ => false,
+        }
+    }
+
     /// Returns true if this type represents the empty tuple type
     pub fn is_empty(&self) -> bool {
         matches!(self.interned(), TyKind::Tuple(0, _))
@@ -177,7 +268,10 @@ impl Ty {
     /// Returns the callable signature of the type, if the type is callable.
     pub fn callable_sig(&self, db: &dyn HirDatabase) -> Option<FnSig> {
         match self.interned() {
-            TyKind::FnDef(def, _) => Some(db.callable_sig(*def)),
+            TyKind::FnDef(def, substs) => Some(db.callable_sig(*def).subst(substs)),
+            TyKind::FnPtr(params_and_return) => Some(FnSig {
+                params_and_return: params_and_return.iter().cloned().collect(),
+            }),
             _ => None,
         }
     }
@@ -187,8 +281,18 @@ impl Ty {
     /// This name needs to be unique as it is used to generate a type's `Guid`.
     pub fn guid_string(&self, db: &dyn HirDatabase) -> Option<String> {
         match self.interned() {
-            &TyKind::Struct(s) => {
-                let name = s.name(db).to_string();
+            TyKind::Struct(s, substs) => {
+                let mut name = s.name(db).to_string();
+                if !substs.is_empty() {
+                    let type_args: Vec<String> = substs
+                        .iter()
+                        .map(|ty| {
+                            ty.guid_string(db)
+                                .expect("type should be convertible to a string")
+                        })
+                        .collect();
+                    name = format!("{}<{}>", name, type_args.join(","));
+                }
 
                 Some(if s.data(db.upcast()).memory_kind == StructMemoryKind::Gc {
                     format!("struct {}", name)
@@ -199,6 +303,7 @@ impl Ty {
                         .map(|f| {
                             let ty_string = f
                                 .ty(db)
+                                .subst(substs)
                                 .guid_string(db)
                                 .expect("type should be convertible to a string");
                             format!("{}: {}", f.name(db), ty_string)
@@ -212,10 +317,71 @@ impl Ty {
                     )
                 })
             }
+            &TyKind::Enum(e) => {
+                let variants: Vec<String> = e
+                    .variants(db)
+                    .into_iter()
+                    .map(|v| {
+                        let fields: Vec<String> = v
+                            .fields(db)
+                            .into_iter()
+                            .map(|f| {
+                                let ty_string = f
+                                    .ty(db)
+                                    .guid_string(db)
+                                    .expect("type should be convertible to a string");
+                                format!("{}: {}", f.name(db), ty_string)
+                            })
+                            .collect();
+                        format!("{}{{{}}}", v.name(db), fields.join(","))
+                    })
+                    .collect();
+
+                Some(format!(
+                    "enum {name}{{{variants}}}",
+                    name = e.name(db),
+                    variants = variants.join(",")
+                ))
+            }
             TyKind::Bool => Some("core::bool".to_string()),
+            TyKind::String => Some("core::string".to_string()),
             TyKind::Float(ty) => Some(format!("core::{}", ty.as_str())),
             TyKind::Int(ty) => Some(format!("core::{}", ty.as_str())),
             TyKind::Array(ty) => Some(format!("[{}]", ty.display(db))),
+            TyKind::Option(ty) => Some(format!("?{}", ty.guid_string(db)?)),
+            TyKind::FnPtr(params_and_return) => {
+                let (ret, params) = params_and_return
+                    .split_last()
+                    .expect("a function pointer always has a return type");
+                let params: Vec<String> = params
+                    .iter()
+                    .map(|ty| {
+                        ty.guid_string(db)
+                            .expect("type should be convertible to a string")
+                    })
+                    .collect();
+                let ret = if ret.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        " -> {}",
+                        ret.guid_string(db)
+                            .expect("type should be convertible to a string")
+                    )
+                };
+                Some(format!("fn({}){}", params.join(","), ret))
+            }
+            TyKind::DynTrait(t) => Some(format!("dyn {}", t.full_name(db))),
+            TyKind::Tuple(_, elements) if !elements.is_empty() => {
+                let elements: Vec<String> = elements
+                    .iter()
+                    .map(|ty| {
+                        ty.guid_string(db)
+                            .expect("type should be convertible to a string")
+                    })
+                    .collect();
+                Some(format!("({})", elements.join(",")))
+            }
             _ => None,
         }
     }
@@ -234,7 +400,10 @@ impl Ty {
     /// if `self` is an `Option<u32>`, this returns the `u32`
     pub fn type_parameters(&self) -> Option<&Substitution> {
         match self.interned() {
-            TyKind::Tuple(_, substs) | TyKind::FnDef(_, substs) => Some(substs),
+            TyKind::Struct(_, substs)
+            | TyKind::Tuple(_, substs)
+            | TyKind::FnDef(_, substs)
+            | TyKind::FnPtr(substs) => Some(substs),
             _ => None,
         }
     }
@@ -243,7 +412,10 @@ impl Ty {
     /// ADT or function); so if `self` is an `Option<u32>`, this returns the `u32`
     pub fn type_parameters_mut(&mut self) -> Option<&mut Substitution> {
         match self.interned_mut() {
-            TyKind::Tuple(_, substs) | TyKind::FnDef(_, substs) => Some(substs),
+            TyKind::Struct(_, substs)
+            | TyKind::Tuple(_, substs)
+            | TyKind::FnDef(_, substs)
+            | TyKind::FnPtr(substs) => Some(substs),
             _ => None,
         }
     }
@@ -251,13 +423,19 @@ impl Ty {
     /// Returns true if the other type has the same type constructor
     pub fn equals_ctor(&self, other: &Ty) -> bool {
         match (self.interned(), other.interned()) {
-            (TyKind::Struct(s1), TyKind::Struct(s2)) => s1 == s2,
-            (TyKind::Tuple(_, substs1), TyKind::Tuple(_, substs2)) => substs1 == substs2,
+            (TyKind::Struct(s1, _), TyKind::Struct(s2, _)) => s1 == s2,
+            (TyKind::Enum(e1), TyKind::Enum(e2)) => e1 == e2,
+            (TyKind::Tuple(n1, _), TyKind::Tuple(n2, _)) => n1 == n2,
             (TyKind::Array(_), TyKind::Array(_)) => true,
+            (TyKind::Option(_), TyKind::Option(_)) => true,
             (TyKind::Float(f1), TyKind::Float(f2)) => f1 == f2,
             (TyKind::Int(i1), TyKind::Int(i2)) => i1 == i2,
             (TyKind::FnDef(def, _), TyKind::FnDef(def2, _)) => def == def2,
+            (TyKind::FnPtr(substs1), TyKind::FnPtr(substs2)) => substs1.len() == substs2.len(),
+            (TyKind::DynTrait(t1), TyKind::DynTrait(t2)) => t1 == t2,
             (TyKind::Bool, TyKind::Bool) => true,
+            (TyKind::String, TyKind::String) => true,
+            (TyKind::Param { idx: idx1, .. }, TyKind::Param { idx: idx2, .. }) => idx1 == idx2,
             _ => false,
         }
     }
@@ -348,6 +526,17 @@ impl FnSig {
         &self.params_and_return[self.params_and_return.len() - 1]
     }
 
+    /// Replaces all type parameters in the signature by the type at the same index in `substs`.
+    pub fn subst(&self, substs: &Substitution) -> FnSig {
+        FnSig {
+            params_and_return: self
+                .params_and_return
+                .iter()
+                .map(|ty| ty.clone().subst(substs))
+                .collect(),
+        }
+    }
+
     pub fn marshallable(&self, db: &dyn HirDatabase) -> bool {
         for ty in self.params_and_return.iter() {
             if let Some(s) = ty.as_struct() {
@@ -355,6 +544,12 @@ impl FnSig {
                     return false;
                 }
             }
+            if ty.as_enum().is_some() {
+                return false;
+            }
+            if ty.as_tuple().map_or(false, |elements| !elements.is_empty()) {
+                return false;
+            }
         }
         true
     }
@@ -363,10 +558,20 @@ impl FnSig {
 impl HirDisplay for Ty {
     fn hir_fmt(&self, f: &mut HirFormatter) -> fmt::Result {
         match self.interned() {
-            TyKind::Struct(s) => write!(f, "{}", s.name(f.db)),
+            TyKind::Struct(s, substs) => {
+                write!(f, "{}", s.name(f.db))?;
+                if !substs.is_empty() {
+                    write!(f, "<")?;
+                    f.write_joined(substs.iter(), ", ")?;
+                    write!(f, ">")?;
+                }
+                Ok(())
+            }
+            TyKind::Enum(e) => write!(f, "{}", e.name(f.db)),
             TyKind::Float(ty) => write!(f, "{}", ty),
             TyKind::Int(ty) => write!(f, "{}", ty),
             TyKind::Bool => write!(f, "bool"),
+            TyKind::String => write!(f, "string"),
             TyKind::Tuple(_, elems) => {
                 write!(f, "(")?;
                 f.write_joined(elems.iter(), ", ")?;
@@ -380,25 +585,48 @@ impl HirDisplay for Ty {
                 InferTy::Int(_) => write!(f, "{{integer}}"),
                 InferTy::Float(_) => write!(f, "{{float}}"),
             },
+            TyKind::Param { name, .. } => write!(f, "{}", name),
             TyKind::TypeAlias(def) => write!(f, "{}", def.name(f.db)),
             TyKind::Never => write!(f, "never"),
-            &TyKind::FnDef(CallableDef::Function(def), _) => {
-                let sig = fn_sig_for_fn(f.db, def);
+            &TyKind::FnDef(CallableDef::Function(def), ref substs) => {
+                let sig = fn_sig_for_fn(f.db, def).subst(substs);
                 let name = def.name(f.db);
                 write!(f, "function {}", name)?;
                 write!(f, "(")?;
                 f.write_joined(sig.params(), ", ")?;
                 write!(f, ") -> {}", sig.ret().display(f.db))
             }
-            &TyKind::FnDef(CallableDef::Struct(def), _) => {
-                let sig = fn_sig_for_struct_constructor(f.db, def);
+            &TyKind::FnDef(CallableDef::Struct(def), ref substs) => {
+                let sig = fn_sig_for_struct_constructor(f.db, def).subst(substs);
                 let name = def.name(f.db);
                 write!(f, "ctor {}", name)?;
                 write!(f, "(")?;
                 f.write_joined(sig.params(), ", ")?;
                 write!(f, ") -> {}", sig.ret().display(f.db))
             }
+            &TyKind::FnDef(CallableDef::EnumVariant(def), ref substs) => {
+                let sig = fn_sig_for_enum_variant_constructor(f.db, def).subst(substs);
+                let name = def.name(f.db);
+                write!(f, "ctor {}::{}", def.parent_enum().name(f.db), name)?;
+                write!(f, "(")?;
+                f.write_joined(sig.params(), ", ")?;
+                write!(f, ") -> {}", sig.ret().display(f.db))
+            }
+            TyKind::FnPtr(params_and_return) => {
+                let (ret, params) = params_and_return
+                    .split_last()
+                    .expect("a function pointer always has a return type");
+                write!(f, "fn(")?;
+                f.write_joined(params, ", ")?;
+                write!(f, ")")?;
+                if !ret.is_empty() {
+                    write!(f, " -> {}", ret.display(f.db))?;
+                }
+                Ok(())
+            }
             TyKind::Array(elem_ty) => write!(f, "[{}]", elem_ty.display(f.db)),
+            TyKind::Option(inner_ty) => write!(f, "?{}", inner_ty.display(f.db)),
+            TyKind::DynTrait(t) => write!(f, "dyn {}", t.name(f.db)),
             TyKind::Unknown => write!(f, "{{unknown}}"),
         }
     }
@@ -435,7 +663,7 @@ pub trait TypeWalk {
 impl TypeWalk for Ty {
     fn walk(&self, f: &mut impl FnMut(&Ty)) {
         match self.interned() {
-            TyKind::Array(elem_ty) => f(elem_ty),
+            TyKind::Array(elem_ty) | TyKind::Option(elem_ty) => f(elem_ty),
             _ => {
                 if let Some(substs) = self.type_parameters() {
                     substs.walk(f)
@@ -447,7 +675,7 @@ impl TypeWalk for Ty {
 
     fn walk_mut(&mut self, f: &mut impl FnMut(&mut Ty)) {
         match self.interned_mut() {
-            TyKind::Array(elem_ty) => f(elem_ty),
+            TyKind::Array(elem_ty) | TyKind::Option(elem_ty) => f(elem_ty),
             _ => {
                 if let Some(substs) = self.type_parameters_mut() {
                     substs.walk_mut(f)
@@ -1,19 +1,23 @@
 use crate::{
     arena::map::ArenaMap,
-    code_model::{Struct, StructKind},
+    builtin_module::IntrinsicFunction,
+    code_model::{
+        Const, DefWithBody, EnumVariant, Static, Struct, StructKind, StructMemoryKind, Trait,
+    },
     diagnostics::DiagnosticSink,
-    expr::{Body, Expr, ExprId, Literal, Pat, PatId, RecordLitField, Statement, UnaryOp},
+    expr::{Body, Expr, ExprId, Literal, MatchArm, Pat, PatId, RecordLitField, Statement, UnaryOp},
     name_resolution::Namespace,
     resolve::{Resolver, TypeNs, ValueNs},
     ty::infer::diagnostics::InferenceDiagnostic,
     ty::infer::type_variable::TypeVariableTable,
     ty::lower::LowerDiagnostic,
+    ty::method_resolution::{lookup_associated_function, lookup_trait_impl},
     ty::op,
-    ty::{Ty, TypableDef},
-    type_ref::LocalTypeRefId,
-    BinaryOp, Function, HirDatabase, Name, Path,
+    ty::{FnSig, Substitution, Ty, TypableDef},
+    type_ref::{LocalTypeRefId, TypeRef},
+    BinaryOp, Function, HasVisibility, HirDatabase, Name, Path,
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -23,9 +27,10 @@ mod unify;
 
 use crate::expr::{LiteralFloat, LiteralFloatKind, LiteralInt, LiteralIntKind};
 use crate::ids::DefWithBodyId;
+use crate::name::name;
 use crate::resolve::{resolver_for_expr, HasResolver};
 use crate::ty::primitives::{FloatTy, IntTy};
-use crate::ty::TyKind;
+use crate::ty::{ResolveBitness, TyKind};
 pub use type_variable::TypeVarId;
 
 mod coerce;
@@ -44,11 +49,26 @@ impl Default for InternedStandardTypes {
     }
 }
 
+/// An operator that is applied to a value of a type parameter in the body of a generic function.
+/// Whether the operator is supported depends on the type that is substituted for the type
+/// parameter, so it is checked for every instantiation of the function.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) enum TypeParamOp {
+    Binary(BinaryOp),
+    Unary(UnaryOp),
+}
+
 /// The result of type inference: A mapping from expressions and patterns to types.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct InferenceResult {
     pub(crate) type_of_expr: ArenaMap<ExprId, Ty>,
     pub(crate) type_of_pat: ArenaMap<PatId, Ty>,
+    pub(crate) variant_resolutions: FxHashMap<ExprOrPatId, EnumVariant>,
+    pub(crate) method_resolutions: FxHashMap<ExprId, (Function, Substitution)>,
+    pub(crate) intrinsic_resolutions: FxHashMap<ExprId, IntrinsicFunction>,
+    pub(crate) closure_captures: FxHashMap<ExprId, Vec<PatId>>,
+    pub(crate) trait_object_coercions: FxHashMap<ExprId, Trait>,
+    pub(crate) type_param_ops: FxHashMap<ExprId, (TypeParamOp, Ty)>,
     pub(crate) diagnostics: Vec<diagnostics::InferenceDiagnostic>,
 
     /// Interned Unknown to return references to.
@@ -74,11 +94,106 @@ impl Index<PatId> for InferenceResult {
 }
 
 impl InferenceResult {
+    /// Returns the enum variant that is constructed by the specified expression, if the expression
+    /// constructs an enum variant.
+    pub fn variant_resolution_for_expr(&self, id: ExprId) -> Option<EnumVariant> {
+        self.variant_resolutions.get(&id.into()).copied()
+    }
+
+    /// Returns the enum variant that is matched by the specified pattern, if the pattern matches
+    /// an enum variant.
+    pub fn variant_resolution_for_pat(&self, id: PatId) -> Option<EnumVariant> {
+        self.variant_resolutions.get(&id.into()).copied()
+    }
+
+    /// Returns the function that is called by the specified method call expression and the types
+    /// that are substituted for its type parameters, if the method is defined in an `impl` block.
+    /// For a binary operation that is overloaded by a user-defined type, this returns the method
+    /// that implements the operation.
+    pub fn method_resolution(&self, id: ExprId) -> Option<(Function, Substitution)> {
+        self.method_resolutions.get(&id).cloned()
+    }
+
+    /// Returns the function of the `core::math` module that is called by the specified call
+    /// expression, if any.
+    pub fn intrinsic_resolution(&self, id: ExprId) -> Option<IntrinsicFunction> {
+        self.intrinsic_resolutions.get(&id).copied()
+    }
+
+    /// Returns the local bindings of enclosing functions and closures that are captured by the
+    /// specified closure expression, in order of first use.
+    pub fn closure_captures(&self, id: ExprId) -> &[PatId] {
+        self.closure_captures
+            .get(&id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the trait of the trait object that the value of the specified expression is
+    /// converted to, if the expression is a struct that is used as a trait object.
+    pub fn trait_object_coercion(&self, id: ExprId) -> Option<Trait> {
+        self.trait_object_coercions.get(&id).copied()
+    }
+
+    /// Returns a copy of this instance in which all type parameters are replaced by the type at
+    /// the same index in `substs`. This is used to obtain the types of an instantiation of a
+    /// generic function. Binary operations on type parameters that are overloaded by the
+    /// substituted types are resolved to the methods that implement them.
+    pub fn subst(&self, db: &dyn HirDatabase, substs: &Substitution) -> InferenceResult {
+        let mut result = self.clone();
+        for (expr, (op, ty)) in self.type_param_ops.iter() {
+            if let TypeParamOp::Binary(op) = op {
+                let lhs_ty = ty.clone().subst(substs);
+                if op::binary_op_rhs_expectation(*op, lhs_ty.clone()).is_unknown() {
+                    if let Some(method) = op::type_arg_binary_op_method(db, *op, &lhs_ty) {
+                        result
+                            .method_resolutions
+                            .insert(*expr, (method, Substitution::empty()));
+                    }
+                }
+            }
+        }
+        for (_, ty) in result.type_of_expr.iter_mut() {
+            *ty = ty.clone().subst(substs);
+        }
+        for (_, ty) in result.type_of_pat.iter_mut() {
+            *ty = ty.clone().subst(substs);
+        }
+        for (_, (_, method_substs)) in result.method_resolutions.iter_mut() {
+            *method_substs = method_substs
+                .iter()
+                .map(|ty| ty.clone().subst(substs))
+                .collect();
+        }
+        result
+    }
+
+    /// Returns true if the types in `substs` support all operators that are applied to values of
+    /// the type parameters that they are substituted for.
+    pub(crate) fn supports_type_param_ops(
+        &self,
+        db: &dyn HirDatabase,
+        substs: &Substitution,
+    ) -> bool {
+        self.type_param_ops.values().all(|(op, ty)| {
+            let ty = ty.clone().subst(substs);
+            match op {
+                TypeParamOp::Binary(op) => op::is_type_arg_binary_op_supported(db, *op, &ty),
+                TypeParamOp::Unary(op) => op::unary_op_return_ty(*op, ty).is_known(),
+            }
+        })
+    }
+
+    /// Returns the types of all expressions and patterns.
+    pub fn types(&self) -> impl Iterator<Item = &Ty> {
+        self.type_of_expr.values().chain(self.type_of_pat.values())
+    }
+
     /// Adds all the `InferenceDiagnostic`s of the result to the `DiagnosticSink`.
     pub(crate) fn add_diagnostics(
         &self,
         db: &dyn HirDatabase,
-        owner: Function,
+        owner: DefWithBody,
         sink: &mut DiagnosticSink,
     ) {
         self.diagnostics
@@ -96,6 +211,7 @@ pub fn infer_query(db: &dyn HirDatabase, def: DefWithBodyId) -> Arc<InferenceRes
 
     match def {
         DefWithBodyId::FunctionId(_) => ctx.infer_signature(),
+        DefWithBodyId::ConstId(_) | DefWithBodyId::StaticId(_) => ctx.infer_initializer_type(),
     }
 
     ctx.infer_body();
@@ -130,10 +246,29 @@ impl InferTy {
     }
 }
 
+/// A closure that encloses the expression that is being processed.
+struct ActiveClosure {
+    /// The local bindings that are introduced inside of the closure, including its parameters
+    bindings: FxHashSet<PatId>,
+
+    /// The local bindings from outside of the closure that are used inside of it
+    captures: Vec<PatId>,
+}
+
 enum ActiveLoop {
     Loop(Ty, Expectation),
     While,
-    For,
+    For(Ty, Expectation),
+}
+
+/// The struct or enum variant to which the path of a pattern resolves.
+struct PatPathResolution {
+    /// The type that is matched
+    ty: Ty,
+    /// The kind of the struct or variant
+    kind: StructKind,
+    /// The names and types of the fields of the struct or variant
+    fields: Vec<(Name, Ty)>,
 }
 
 /// The inference context contains all information needed during type inference.
@@ -144,18 +279,33 @@ struct InferenceResultBuilder<'a> {
 
     type_of_expr: ArenaMap<ExprId, Ty>,
     type_of_pat: ArenaMap<PatId, Ty>,
+    variant_resolutions: FxHashMap<ExprOrPatId, EnumVariant>,
+    method_resolutions: FxHashMap<ExprId, (Function, Substitution)>,
+    intrinsic_resolutions: FxHashMap<ExprId, IntrinsicFunction>,
     diagnostics: Vec<InferenceDiagnostic>,
 
     type_variables: TypeVariableTable,
 
-    /// Information on the current loop that we're processing (or None if we're not in a loop) the
-    /// entry contains the current type of the loop statement (initially `never`) and the expected
-    /// type of the loop expression. Both these values are updated when a break statement is
-    /// encountered.
-    active_loop: Option<ActiveLoop>,
+    /// Information on the loops that enclose the expression we're processing, together with their
+    /// labels. The innermost loop is last. For a `loop` the entry contains the current type of the
+    /// loop statement (initially `never`) and the expected type of the loop expression. Both these
+    /// values are updated when a break statement is encountered.
+    active_loops: Vec<(Option<Name>, ActiveLoop)>,
+
+    /// The closures that enclose the expression we're processing. The innermost closure is last.
+    active_closures: Vec<ActiveClosure>,
 
-    /// The return type of the function being inferred.
+    /// The return type of the function or closure being inferred.
     return_ty: Ty,
+
+    /// The bindings captured by every closure in the body
+    closure_captures: FxHashMap<ExprId, Vec<PatId>>,
+
+    /// The expressions whose struct value is converted to a trait object
+    trait_object_coercions: FxHashMap<ExprId, Trait>,
+
+    /// The expressions that apply an operator to a value of a type parameter
+    type_param_ops: FxHashMap<ExprId, (TypeParamOp, Ty)>,
 }
 
 impl<'a> InferenceResultBuilder<'a> {
@@ -164,8 +314,15 @@ impl<'a> InferenceResultBuilder<'a> {
         InferenceResultBuilder {
             type_of_expr: ArenaMap::default(),
             type_of_pat: ArenaMap::default(),
+            variant_resolutions: FxHashMap::default(),
+            method_resolutions: FxHashMap::default(),
+            intrinsic_resolutions: FxHashMap::default(),
             diagnostics: Vec::default(),
-            active_loop: None,
+            active_loops: Vec::new(),
+            active_closures: Vec::new(),
+            closure_captures: FxHashMap::default(),
+            trait_object_coercions: FxHashMap::default(),
+            type_param_ops: FxHashMap::default(),
             type_variables: TypeVariableTable::default(),
             db,
             body,
@@ -187,6 +344,16 @@ impl<'a> InferenceResultBuilder<'a> {
     /// Given a `LocalTypeRefId`, resolve the reference to an actual `Ty`. If the the type could not
     /// be resolved an error is emitted and `Ty::Error` is returned.
     fn resolve_type(&mut self, type_ref: LocalTypeRefId) -> Ty {
+        self.resolve_type_inner(type_ref, false)
+    }
+
+    /// Same as `resolve_type` but if the type arguments of a generic struct are omitted (e.g.
+    /// `Pair` instead of `Pair<i32, f32>`), they are inferred instead of reported as an error.
+    fn resolve_type_infer_args(&mut self, type_ref: LocalTypeRefId) -> Ty {
+        self.resolve_type_inner(type_ref, true)
+    }
+
+    fn resolve_type_inner(&mut self, type_ref: LocalTypeRefId, infer_args: bool) -> Ty {
         // Try to resolve the type from the Hir
         let (ty, diagnostics) = Ty::from_hir(
             self.db,
@@ -197,18 +364,86 @@ impl<'a> InferenceResultBuilder<'a> {
         );
 
         // Convert the diagnostics from resolving the type reference
+        let mut omitted_type_args = false;
         for diag in diagnostics {
             let diag = match diag {
+                LowerDiagnostic::WrongNumberOfTypeArgs { id, found: 0, .. }
+                    if infer_args && id == type_ref =>
+                {
+                    omitted_type_args = true;
+                    continue;
+                }
+                LowerDiagnostic::WrongNumberOfTypeArgs {
+                    id,
+                    expected,
+                    found,
+                } => InferenceDiagnostic::WrongNumberOfTypeArgs {
+                    id,
+                    expected,
+                    found,
+                },
                 LowerDiagnostic::UnresolvedType { id } => {
                     InferenceDiagnostic::UnresolvedType { id }
                 }
                 LowerDiagnostic::CyclicType { id } => InferenceDiagnostic::CyclicType { id },
                 LowerDiagnostic::TypeIsPrivate { id } => InferenceDiagnostic::TypeIsPrivate { id },
+                LowerDiagnostic::UnresolvedTrait { id } => {
+                    InferenceDiagnostic::UnresolvedTrait { id }
+                }
+                LowerDiagnostic::ExpectedTypeFoundTrait { id } => {
+                    InferenceDiagnostic::ExpectedTypeFoundTrait { id }
+                }
+                LowerDiagnostic::InvalidOptionalType { id } => {
+                    InferenceDiagnostic::InvalidOptionalType { id }
+                }
             };
             self.diagnostics.push(diag);
         }
 
-        ty
+        if omitted_type_args {
+            self.instantiate_type_params(ty)
+        } else {
+            ty
+        }
+    }
+
+    /// Replaces the type arguments of a generic function or struct type by new type variables.
+    /// This enables inferring the type arguments of a generic item from the way it is used.
+    fn instantiate_type_params(&mut self, ty: Ty) -> Ty {
+        match ty.interned() {
+            TyKind::FnDef(def, substs) if !substs.is_empty() => {
+                let substs = substs
+                    .iter()
+                    .map(|_| self.type_variables.new_type_var())
+                    .collect();
+                TyKind::FnDef(*def, substs).intern()
+            }
+            TyKind::Struct(s, substs) if !substs.is_empty() => {
+                let substs = substs
+                    .iter()
+                    .map(|_| self.type_variables.new_type_var())
+                    .collect();
+                TyKind::Struct(*s, substs).intern()
+            }
+            _ => ty,
+        }
+    }
+
+    /// Given a `LocalTypeRefId`, returns the enum variant that the reference refers to, if it
+    /// refers to an enum variant at all.
+    fn resolve_variant(&self, type_ref: LocalTypeRefId) -> Option<EnumVariant> {
+        match &self.body.type_refs()[type_ref] {
+            TypeRef::Path(path, _) => {
+                match self
+                    .resolver
+                    .resolve_path_as_type_fully(self.db.upcast(), path)?
+                {
+                    (TypeNs::EnumVariantId(id), _) => Some(id.into()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
     }
 }
 
@@ -227,15 +462,270 @@ impl<'a> InferenceResultBuilder<'a> {
         self.return_ty = self.resolve_type(self.body.ret_type())
     }
 
-    /// Record the type of the specified pattern and all sub-patterns.
-    fn infer_pat(&mut self, pat: PatId, ty: Ty) {
-        #[allow(clippy::single_match)]
-        match &self.body[pat] {
+    /// Resolves the declared type of a `const` or `static`. The initializer of the item is
+    /// inferred as if it is the return value of a function.
+    fn infer_initializer_type(&mut self) {
+        self.return_ty = self.resolve_type(self.body.ret_type())
+    }
+
+    /// Record the type of the specified pattern and all sub-patterns. Returns the type of the
+    /// pattern.
+    fn infer_pat(&mut self, pat: PatId, expected: Ty) -> Ty {
+        let ty = match &self.body[pat] {
             Pat::Bind { .. } => {
-                self.set_pat_type(pat, ty);
+                if let Some(closure) = self.active_closures.last_mut() {
+                    closure.bindings.insert(pat);
+                }
+                expected
+            }
+            Pat::Missing | Pat::Wild => expected,
+            Pat::Lit { expr, negated } => {
+                let ty = self.infer_expr(*expr, &Expectation::has_type(expected));
+                if *negated
+                    && !matches!(
+                        ty.interned(),
+                        TyKind::Int(_)
+                            | TyKind::Float(_)
+                            | TyKind::InferenceVar(InferTy::Int(_))
+                            | TyKind::InferenceVar(InferTy::Float(_))
+                            | TyKind::Unknown
+                    )
+                {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::CannotApplyUnaryOp {
+                            id: *expr,
+                            ty: ty.clone(),
+                        });
+                }
+                ty
+            }
+            Pat::Range { start, end } => {
+                let ty = self.infer_pat(*start, expected);
+                self.infer_pat(*end, ty)
+            }
+            Pat::Path(path) => match self.resolve_pat_path(pat, path) {
+                Some(PatPathResolution { ty, kind, .. }) => {
+                    self.check_pat_kind(pat, kind, StructKind::Unit);
+                    self.check_pat_ty(pat, ty, expected)
+                }
+                None => self.error_type(),
+            },
+            Pat::TupleStruct { path, args } => {
+                let resolved = self.resolve_pat_path(pat, path);
+                let field_tys = match &resolved {
+                    Some(PatPathResolution { kind, fields, .. })
+                        if self.check_pat_kind(pat, *kind, StructKind::Tuple) =>
+                    {
+                        if fields.len() != args.len() {
+                            self.diagnostics
+                                .push(InferenceDiagnostic::PatFieldCountMismatch {
+                                    id: pat,
+                                    expected: fields.len(),
+                                    found: args.len(),
+                                });
+                        }
+                        fields.iter().map(|(_, ty)| ty.clone()).collect()
+                    }
+                    _ => Vec::new(),
+                };
+                for (idx, arg) in args.iter().enumerate() {
+                    let field_ty = field_tys
+                        .get(idx)
+                        .cloned()
+                        .unwrap_or_else(|| self.error_type());
+                    self.infer_pat(*arg, field_ty);
+                }
+                match resolved {
+                    Some(PatPathResolution { ty, .. }) => self.check_pat_ty(pat, ty, expected),
+                    None => self.error_type(),
+                }
+            }
+            Pat::Tuple { args } => {
+                let expected = self.replace_if_possible(&expected).into_owned();
+                let element_tys = match expected.interned() {
+                    TyKind::Tuple(_, substs) => substs.iter().cloned().collect(),
+                    _ => Vec::new(),
+                };
+                let length_mismatch =
+                    expected.as_tuple().is_some() && element_tys.len() != args.len();
+                if length_mismatch {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::TuplePatLengthMismatch {
+                            id: pat,
+                            expected: element_tys.len(),
+                            found: args.len(),
+                        });
+                }
+
+                let tys: Substitution = args
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, arg)| {
+                        let element_ty = match element_tys.get(idx) {
+                            Some(ty) => ty.clone(),
+                            None if length_mismatch => self.error_type(),
+                            None => self.type_variables.new_type_var(),
+                        };
+                        self.infer_pat(*arg, element_ty)
+                    })
+                    .collect();
+                let ty = TyKind::Tuple(tys.len(), tys).intern();
+                if length_mismatch {
+                    ty
+                } else {
+                    self.check_pat_ty(pat, ty, expected)
+                }
+            }
+            Pat::Record {
+                path,
+                args,
+                ellipsis,
+            } => {
+                let resolved = self.resolve_pat_path(pat, path);
+                let fields = match &resolved {
+                    Some(PatPathResolution { kind, fields, .. })
+                        if self.check_pat_kind(pat, *kind, StructKind::Record) =>
+                    {
+                        Some(fields)
+                    }
+                    _ => None,
+                };
+                for field in args.iter() {
+                    let field_ty = match fields {
+                        Some(fields) => match fields.iter().find(|(name, _)| *name == field.name) {
+                            Some((_, ty)) => ty.clone(),
+                            None => {
+                                self.diagnostics
+                                    .push(InferenceDiagnostic::NoSuchPatField { id: field.pat });
+                                self.error_type()
+                            }
+                        },
+                        None => self.error_type(),
+                    };
+                    self.infer_pat(field.pat, field_ty);
+                }
+                match resolved {
+                    Some(PatPathResolution { ty, kind, fields }) => {
+                        if !ellipsis && kind == StructKind::Record {
+                            let pat_fields: FxHashSet<_> = args.iter().map(|f| &f.name).collect();
+                            let missed_fields: Vec<Name> = fields
+                                .into_iter()
+                                .map(|(name, _)| name)
+                                .filter(|name| !pat_fields.contains(name))
+                                .collect();
+                            if !missed_fields.is_empty() {
+                                self.diagnostics
+                                    .push(InferenceDiagnostic::MissingPatFields {
+                                        id: pat,
+                                        struct_ty: ty.clone(),
+                                        names: missed_fields,
+                                    });
+                            }
+                        }
+                        self.check_pat_ty(pat, ty, expected)
+                    }
+                    None => self.error_type(),
+                }
+            }
+        };
+
+        let ty = self.resolve_ty_as_far_as_possible(ty);
+        self.set_pat_type(pat, ty.clone());
+        ty
+    }
+
+    /// Resolves the path of a unit, tuple or record pattern to a struct or an enum variant.
+    fn resolve_pat_path(&mut self, pat: PatId, path: &Path) -> Option<PatPathResolution> {
+        let resolved = self
+            .resolver
+            .resolve_path_as_type_fully(self.db.upcast(), path);
+        let (type_ns, vis) = match resolved {
+            Some(resolved) => resolved,
+            None => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::UnresolvedValue { id: pat.into() });
+                return None;
+            }
+        };
+
+        if !vis.is_visible_from(
+            self.db,
+            self.resolver
+                .module()
+                .expect("resolver must have a module to be able to resolve modules"),
+        ) {
+            self.diagnostics
+                .push(InferenceDiagnostic::PathIsPrivate { id: pat.into() })
+        }
+
+        match type_ns {
+            TypeNs::StructId(id) => {
+                let s = Struct::from(id);
+                let ty = self.instantiate_type_params(s.ty(self.db));
+                let substs = ty
+                    .type_parameters()
+                    .cloned()
+                    .unwrap_or_else(Substitution::empty);
+                let fields = s
+                    .fields(self.db)
+                    .into_iter()
+                    .map(|f| (f.name(self.db), f.ty(self.db).subst(&substs)))
+                    .collect();
+                Some(PatPathResolution {
+                    ty,
+                    kind: s.data(self.db.upcast()).kind,
+                    fields,
+                })
+            }
+            TypeNs::EnumVariantId(id) => {
+                let variant = EnumVariant::from(id);
+                self.variant_resolutions.insert(pat.into(), variant);
+                let fields = variant
+                    .fields(self.db)
+                    .into_iter()
+                    .map(|f| (f.name(self.db), f.ty(self.db)))
+                    .collect();
+                Some(PatPathResolution {
+                    ty: variant.parent_enum().ty(self.db),
+                    kind: variant.kind(self.db),
+                    fields,
+                })
             }
-            _ => {}
+            _ => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::UnresolvedValue { id: pat.into() });
+                None
+            }
+        }
+    }
+
+    /// Checks whether the kind of struct or enum variant matched by a pattern matches the kind of
+    /// the pattern itself. Returns `false` and emits a diagnostic if that is not the case.
+    fn check_pat_kind(&mut self, pat: PatId, expected: StructKind, found: StructKind) -> bool {
+        if expected != found {
+            self.diagnostics
+                .push(InferenceDiagnostic::MismatchedStructPat {
+                    id: pat,
+                    expected,
+                    found,
+                });
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Checks whether the type matched by a pattern matches the type that is expected.
+    fn check_pat_ty(&mut self, pat: PatId, ty: Ty, expected: Ty) -> Ty {
+        if !self.unify(&ty, &expected) {
+            self.diagnostics
+                .push(InferenceDiagnostic::MismatchedPatType {
+                    id: pat,
+                    expected,
+                    found: ty.clone(),
+                });
         }
+        ty
     }
 
     /// Infer the types of all the expressions and sub-expressions in the body.
@@ -270,6 +760,11 @@ impl<'a> InferenceResultBuilder<'a> {
     /// Performs implicit coercion of the specified `Ty` to an expected type. Returns the type after
     /// possible coercion. Adds a diagnostic message if coercion failed.
     fn coerce_expr_ty(&mut self, expr: ExprId, ty: Ty, expected: &Expectation) -> Ty {
+        if let Some(t) = self.trait_object_coercion(&ty, &expected.ty) {
+            self.trait_object_coercions.insert(expr, t);
+            return expected.ty.clone();
+        }
+
         let ty = if !self.coerce(&ty, &expected.ty) {
             self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
                 expected: expected.ty.clone(),
@@ -286,6 +781,22 @@ impl<'a> InferenceResultBuilder<'a> {
         self.resolve_ty_as_far_as_possible(ty)
     }
 
+    /// Returns the trait of the expected trait object type, if a value of type `ty` can be
+    /// converted to it. Only garbage collected structs that implement the trait can be used as a
+    /// trait object.
+    fn trait_object_coercion(&mut self, ty: &Ty, expected: &Ty) -> Option<Trait> {
+        let ty = self.replace_if_possible(ty).into_owned();
+        let expected = self.replace_if_possible(expected);
+        match (ty.interned(), expected.interned()) {
+            (TyKind::Struct(s, _), TyKind::DynTrait(t))
+                if s.data(self.db.upcast()).memory_kind == StructMemoryKind::Gc =>
+            {
+                lookup_trait_impl(self.db, &ty, *t).map(|_| *t)
+            }
+            _ => None,
+        }
+    }
+
     /// Returns a type used for errors
     fn error_type(&self) -> Ty {
         TyKind::Unknown.intern()
@@ -326,26 +837,96 @@ impl<'a> InferenceResultBuilder<'a> {
                                 id: tgt_expr,
                                 lhs: *lhs,
                             })
+                        } else if let Some(name) = self.captured_binding_name(&resolver, *lhs) {
+                            self.diagnostics.push(
+                                InferenceDiagnostic::CannotAssignToCapturedVariable {
+                                    id: *lhs,
+                                    name,
+                                },
+                            )
+                        } else if let Some(name) = self.immutable_static_name(&resolver, *lhs) {
+                            self.diagnostics.push(
+                                InferenceDiagnostic::CannotAssignToImmutableStatic {
+                                    id: *lhs,
+                                    name,
+                                },
+                            )
                         }
                     };
+
+                    // If the type of the left hand side is not known yet (e.g. an unannotated
+                    // closure parameter), infer it from the right hand side instead.
+                    let (lhs_ty, rhs_ty) =
+                        if matches!(lhs_ty.interned(), TyKind::InferenceVar(InferTy::Type(_))) {
+                            let rhs_ty = self.infer_expr(*rhs, &Expectation::none());
+                            self.unify(&lhs_ty, &rhs_ty);
+                            (self.resolve_ty_as_far_as_possible(lhs_ty), Some(rhs_ty))
+                        } else {
+                            (lhs_ty, None)
+                        };
+
+                    // Whether an operation on a type parameter is supported is checked for every
+                    // instantiation of the generic function
+                    if matches!(lhs_ty.interned(), TyKind::Param { .. })
+                        && !matches!(op, BinaryOp::LogicOp(_) | BinaryOp::Assignment { op: None })
+                    {
+                        self.type_param_ops
+                            .insert(tgt_expr, (TypeParamOp::Binary(*op), lhs_ty.clone()));
+                    }
+
                     let rhs_expected = op::binary_op_rhs_expectation(*op, lhs_ty.clone());
-                    if lhs_ty.is_known() && rhs_expected.is_unknown() {
-                        self.diagnostics
-                            .push(InferenceDiagnostic::CannotApplyBinaryOp {
-                                id: tgt_expr,
-                                lhs: lhs_ty,
-                                rhs: rhs_expected.clone(),
-                            })
+
+                    // A user-defined type can overload the operation with a method
+                    let overload = if lhs_ty.is_known() && rhs_expected.is_unknown() {
+                        op::binary_op_method(self.db, *op, &lhs_ty)
+                    } else {
+                        None
+                    };
+                    if let Some(method) = overload {
+                        self.infer_overloaded_binary_op(
+                            tgt_expr, *op, *lhs, &lhs_ty, *rhs, rhs_ty, method,
+                        )
+                    } else {
+                        if lhs_ty.is_known() && rhs_expected.is_unknown() {
+                            self.diagnostics
+                                .push(InferenceDiagnostic::CannotApplyBinaryOp {
+                                    id: tgt_expr,
+                                    lhs: lhs_ty.clone(),
+                                    rhs: rhs_expected.clone(),
+                                })
+                        }
+                        let rhs_ty = match rhs_ty {
+                            Some(rhs_ty) => self.resolve_ty_as_far_as_possible(rhs_ty),
+                            // A struct can be assigned to a trait object or an optional type
+                            None if matches!(
+                                rhs_expected.interned(),
+                                TyKind::DynTrait(_) | TyKind::Option(_)
+                            ) =>
+                            {
+                                self.infer_expr_coerce(*rhs, &Expectation::has_type(rhs_expected))
+                            }
+                            None => self.infer_expr(*rhs, &Expectation::has_type(rhs_expected)),
+                        };
+                        // Both operands of an operation on a type parameter have the type of the
+                        // type parameter, even if the right hand side is mismatched
+                        if matches!(lhs_ty.interned(), TyKind::Param { .. }) {
+                            op::binary_op_return_ty(*op, lhs_ty)
+                        } else {
+                            op::binary_op_return_ty(*op, rhs_ty)
+                        }
                     }
-                    let rhs_ty = self.infer_expr(*rhs, &Expectation::has_type(rhs_expected));
-                    op::binary_op_return_ty(*op, rhs_ty)
                 }
                 _ => self.error_type(),
             },
             Expr::Block { statements, tail } => self.infer_block(statements, *tail, expected),
             Expr::Call { callee: call, args } => self.infer_call(tgt_expr, *call, args, expected),
+            Expr::MethodCall {
+                receiver,
+                method_name,
+                args,
+            } => self.infer_method_call(tgt_expr, *receiver, method_name, args),
             Expr::Literal(lit) => match lit {
-                Literal::String(_) => TyKind::Unknown.intern(),
+                Literal::String(_) => TyKind::String.intern(),
                 Literal::Bool(_) => TyKind::Bool.intern(),
                 Literal::Int(LiteralInt {
                     kind: LiteralIntKind::Suffixed(suffix),
@@ -370,60 +951,140 @@ impl<'a> InferenceResultBuilder<'a> {
                     kind: LiteralFloatKind::Unsuffixed,
                     ..
                 }) => self.type_variables.new_float_var(),
+                Literal::Nil => match expected.ty.interned() {
+                    TyKind::Option(_) => expected.ty.clone(),
+                    TyKind::Unknown | TyKind::InferenceVar(InferTy::Type(_)) => {
+                        TyKind::Option(self.type_variables.new_type_var()).intern()
+                    }
+                    // The mismatch with the expected type is reported by the caller
+                    _ => TyKind::Option(self.error_type()).intern(),
+                },
             },
+            Expr::Let { pat, expr } => {
+                self.infer_let_condition(*pat, *expr);
+                TyKind::Bool.intern()
+            }
             Expr::Return { expr } => {
                 if let Some(expr) = expr {
-                    self.infer_expr(*expr, &Expectation::has_type(self.return_ty.clone()));
-                } else if !self.return_ty.is_empty() {
+                    self.infer_expr_coerce(*expr, &Expectation::has_type(self.return_ty.clone()));
+                } else if !self.coerce(&Ty::unit(), &self.return_ty.clone()) {
                     self.diagnostics
                         .push(InferenceDiagnostic::ReturnMissingExpression { id: tgt_expr });
                 }
 
                 TyKind::Never.intern()
             }
-            Expr::Break { expr } => self.infer_break(tgt_expr, *expr),
-            Expr::Loop { body } => self.infer_loop_expr(tgt_expr, *body, expected),
-            Expr::While { condition, body } => {
-                self.infer_while_expr(tgt_expr, *condition, *body, expected)
+            Expr::Break { expr, label } => self.infer_break(tgt_expr, *expr, label.as_ref()),
+            Expr::Continue { label } => {
+                self.find_loop_target(tgt_expr, label.as_ref(), true);
+                TyKind::Never.intern()
+            }
+            Expr::Loop { body, label } => {
+                self.infer_loop_expr(tgt_expr, *body, label.clone(), expected)
+            }
+            Expr::While {
+                condition,
+                body,
+                label,
+            } => self.infer_while_expr(tgt_expr, *condition, *body, label.clone(), expected),
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            } => self.infer_for_expr(tgt_expr, *pat, *iterable, *body, label.clone(), expected),
+            Expr::Range { start, end, .. } => {
+                self.infer_range_bounds(*start, *end);
+                self.diagnostics
+                    .push(InferenceDiagnostic::RangeOutsideForLoop { id: tgt_expr });
+                TyKind::Unknown.intern()
             }
             Expr::RecordLit {
                 type_id,
                 fields,
                 spread,
             } => {
-                let ty = self.resolve_type(*type_id);
+                let ty = self.resolve_type_infer_args(*type_id);
+                let variant = self.resolve_variant(*type_id);
                 let def_id = ty.as_struct();
+                let substs = ty
+                    .type_parameters()
+                    .cloned()
+                    .unwrap_or_else(Substitution::empty);
                 self.unify(&ty, &expected.ty);
+                if let Some(variant) = variant {
+                    self.variant_resolutions.insert(tgt_expr.into(), variant);
+                }
 
                 for (idx, field) in fields.iter().enumerate() {
-                    let field_ty = def_id
-                        .as_ref()
-                        .and_then(|it| match it.field(self.db, &field.name) {
-                            Some(field) => Some(field),
-                            None => {
-                                self.diagnostics.push(InferenceDiagnostic::NoSuchField {
-                                    id: tgt_expr,
-                                    field: idx,
-                                });
-                                None
-                            }
-                        })
-                        .map_or(self.error_type(), |field| field.ty(self.db));
+                    let field_ty = match (variant, def_id) {
+                        (Some(variant), _) => variant
+                            .field(self.db, &field.name)
+                            .map(|field| field.ty(self.db)),
+                        (None, Some(s)) => s
+                            .field(self.db, &field.name)
+                            .map(|f| f.ty(self.db).subst(&substs)),
+                        (None, None) => Some(self.error_type()),
+                    };
+                    let field_ty = field_ty.unwrap_or_else(|| {
+                        self.diagnostics.push(InferenceDiagnostic::NoSuchField {
+                            id: tgt_expr,
+                            field: idx,
+                        });
+                        self.error_type()
+                    });
                     self.infer_expr_coerce(field.expr, &Expectation::has_type(field_ty));
                 }
                 if let Some(expr) = spread {
                     self.infer_expr(*expr, &Expectation::has_type(ty.clone()));
                 }
-                if let Some(s) = ty.as_struct() {
-                    self.check_record_lit(tgt_expr, &ty, s, fields);
+                if let Some(variant) = variant {
+                    let field_names = variant
+                        .fields(self.db)
+                        .into_iter()
+                        .map(|f| f.name(self.db))
+                        .collect();
+                    self.check_record_lit(
+                        tgt_expr,
+                        &ty,
+                        variant.kind(self.db),
+                        field_names,
+                        fields,
+                    );
+                } else if let Some(s) = def_id {
+                    let struct_data = s.data(self.db.upcast());
+                    let field_names = struct_data
+                        .fields
+                        .iter()
+                        .map(|(_, d)| d.name.clone())
+                        .collect();
+                    self.check_record_lit(tgt_expr, &ty, struct_data.kind, field_names, fields);
                 }
                 ty
             }
             Expr::Field { expr, name } => {
                 let receiver_ty = self.infer_expr(*expr, &Expectation::none());
                 match receiver_ty.interned() {
-                    TyKind::Struct(s) => {
-                        match s.field(self.db, name).map(|field| field.ty(self.db)) {
+                    TyKind::Tuple(_, substs) if !substs.is_empty() => {
+                        match name.as_tuple_index().and_then(|idx| substs.get(idx)) {
+                            Some(field_ty) => field_ty.clone(),
+                            None => {
+                                self.diagnostics
+                                    .push(InferenceDiagnostic::AccessUnknownField {
+                                        id: tgt_expr,
+                                        receiver_ty,
+                                        name: name.clone(),
+                                    });
+
+                                self.error_type()
+                            }
+                        }
+                    }
+                    TyKind::Struct(s, substs) => {
+                        match s
+                            .field(self.db, name)
+                            .map(|field| field.ty(self.db).subst(substs))
+                        {
                             Some(field_ty) => field_ty,
                             None => {
                                 self.diagnostics
@@ -437,6 +1098,14 @@ impl<'a> InferenceResultBuilder<'a> {
                             }
                         }
                     }
+                    TyKind::Option(_) => {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::PossiblyNilAccess {
+                                id: *expr,
+                                ty: receiver_ty,
+                            });
+                        self.error_type()
+                    }
                     _ => {
                         self.diagnostics.push(InferenceDiagnostic::NoFields {
                             id: *expr,
@@ -449,34 +1118,20 @@ impl<'a> InferenceResultBuilder<'a> {
             Expr::UnaryOp { expr, op } => {
                 let inner_ty =
                     self.infer_expr_inner(*expr, &Expectation::none(), &CheckParams::default());
-                match op {
-                    UnaryOp::Not => match inner_ty.interned() {
-                        TyKind::Bool | TyKind::Int(_) | TyKind::InferenceVar(InferTy::Int(_)) => {
-                            inner_ty
-                        }
-                        _ => {
-                            self.diagnostics
-                                .push(InferenceDiagnostic::CannotApplyUnaryOp {
-                                    id: *expr,
-                                    ty: inner_ty,
-                                });
-                            self.error_type()
-                        }
-                    },
-                    UnaryOp::Neg => match inner_ty.interned() {
-                        TyKind::Float(_)
-                        | TyKind::Int(_)
-                        | TyKind::InferenceVar(InferTy::Int(_))
-                        | TyKind::InferenceVar(InferTy::Float(_)) => inner_ty,
-                        _ => {
-                            self.diagnostics
-                                .push(InferenceDiagnostic::CannotApplyUnaryOp {
-                                    id: *expr,
-                                    ty: inner_ty,
-                                });
-                            self.error_type()
-                        }
-                    },
+                if matches!(inner_ty.interned(), TyKind::Param { .. }) {
+                    self.type_param_ops
+                        .insert(tgt_expr, (TypeParamOp::Unary(*op), inner_ty.clone()));
+                }
+                let ty = op::unary_op_return_ty(*op, inner_ty.clone());
+                if ty.is_unknown() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::CannotApplyUnaryOp {
+                            id: *expr,
+                            ty: inner_ty,
+                        });
+                    self.error_type()
+                } else {
+                    ty
                 }
             }
             Expr::Array(array) => {
@@ -491,6 +1146,33 @@ impl<'a> InferenceResultBuilder<'a> {
 
                 TyKind::Array(elem_ty).intern()
             }
+            Expr::Tuple { exprs } => {
+                let expected_tys = match expected.ty.interned() {
+                    TyKind::Tuple(_, substs) if substs.len() == exprs.len() => substs.clone(),
+                    _ => Substitution::empty(),
+                };
+
+                let tys: Substitution = exprs
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, expr)| {
+                        let expected = match expected_tys.get(idx) {
+                            Some(ty) => Expectation::has_type(ty.clone()),
+                            None => Expectation::none(),
+                        };
+                        self.infer_expr_coerce(*expr, &expected)
+                    })
+                    .collect();
+                TyKind::Tuple(tys.len(), tys).intern()
+            }
+            Expr::Match { expr, arms } => self.infer_match(*expr, arms, expected),
+            Expr::Closure {
+                args,
+                arg_types,
+                ret_type,
+                body,
+            } => self.infer_closure(tgt_expr, args, arg_types, *ret_type, *body, expected),
+            Expr::Cast { expr, type_ref } => self.infer_cast(tgt_expr, *expr, *type_ref),
             Expr::Index { base, index } => {
                 let elem_ty = if expected.ty.is_unknown() {
                     self.type_variables.new_type_var()
@@ -498,10 +1180,27 @@ impl<'a> InferenceResultBuilder<'a> {
                     expected.ty.clone()
                 };
 
-                let base_ty = self.infer_expr(
+                // Report indexing into an optional array separately from other type mismatches
+                let expected_base_ty = TyKind::Array(elem_ty).intern();
+                let base_ty = self.infer_expr_inner(
                     *base,
-                    &Expectation::has_type(TyKind::Array(elem_ty).intern()),
+                    &Expectation::has_type(expected_base_ty.clone()),
+                    &CheckParams::default(),
                 );
+                if base_ty.as_option().is_some() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::PossiblyNilAccess {
+                            id: *base,
+                            ty: base_ty.clone(),
+                        });
+                } else if !self.unify(&base_ty, &expected_base_ty) {
+                    self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                        expected: expected_base_ty,
+                        found: base_ty.clone(),
+                        id: *base,
+                    });
+                }
+                let base_ty = self.resolve_ty_as_far_as_possible(base_ty);
 
                 let inner_ty = self.type_variables.new_integer_var();
                 let _index_expr = self.infer_expr(*index, &Expectation::has_type(inner_ty));
@@ -518,24 +1217,174 @@ impl<'a> InferenceResultBuilder<'a> {
         ty
     }
 
-    /// Inferences the type of an if statement.
-    fn infer_if(
+    /// Inferences the type of a cast expression, e.g. `a as f32`. Only casts between primitive
+    /// numeric types, from `bool` to an integer type, and casts to the same type are valid.
+    fn infer_cast(&mut self, tgt_expr: ExprId, expr: ExprId, type_ref: LocalTypeRefId) -> Ty {
+        let cast_ty = self.resolve_type(type_ref);
+        let expr_ty = self.infer_expr(expr, &Expectation::none());
+        let expr_ty = self.resolve_ty_as_far_as_possible(expr_ty);
+
+        let is_valid = match (expr_ty.interned(), cast_ty.interned()) {
+            (TyKind::Unknown, _) | (_, TyKind::Unknown) => true,
+            (
+                TyKind::Int(_)
+                | TyKind::Float(_)
+                | TyKind::InferenceVar(InferTy::Int(_))
+                | TyKind::InferenceVar(InferTy::Float(_)),
+                TyKind::Int(_) | TyKind::Float(_),
+            ) => true,
+            (TyKind::Bool, TyKind::Int(_)) => true,
+            _ => expr_ty == cast_ty,
+        };
+
+        if !is_valid {
+            self.diagnostics.push(InferenceDiagnostic::InvalidCast {
+                id: tgt_expr,
+                expr_ty,
+                cast_ty: cast_ty.clone(),
+            });
+        }
+
+        cast_ty
+    }
+
+    /// Inferences the type of a closure expression. Parameter and return types that are not
+    /// annotated are taken from the expected function pointer type, if any, or inferred from the
+    /// body of the closure. Records the bindings that are captured by the closure.
+    fn infer_closure(
         &mut self,
         tgt_expr: ExprId,
+        args: &[PatId],
+        arg_types: &[Option<LocalTypeRefId>],
+        ret_type: Option<LocalTypeRefId>,
+        body: ExprId,
         expected: &Expectation,
-        condition: ExprId,
-        then_branch: ExprId,
-        else_branch: Option<ExprId>,
     ) -> Ty {
-        self.infer_expr(condition, &Expectation::has_type(TyKind::Bool.intern()));
-        let then_ty = self.infer_expr_coerce(then_branch, expected);
-        match else_branch {
-            Some(else_branch) => {
-                let else_ty = self.infer_expr_coerce(else_branch, expected);
-                match self.coerce_merge_branch(&then_ty, &else_ty) {
-                    Some(ty) => ty,
-                    None => {
-                        self.diagnostics
+        let expected_sig = match expected.ty.interned() {
+            TyKind::FnPtr(_) => expected
+                .ty
+                .callable_sig(self.db)
+                .filter(|sig| sig.params().len() == args.len()),
+            _ => None,
+        };
+
+        self.active_closures.push(ActiveClosure {
+            bindings: FxHashSet::default(),
+            captures: Vec::new(),
+        });
+
+        let mut param_tys = Vec::with_capacity(args.len());
+        for (idx, (&arg, arg_type)) in args.iter().zip(arg_types.iter()).enumerate() {
+            let ty = match (arg_type, &expected_sig) {
+                (Some(type_ref), _) => self.resolve_type(*type_ref),
+                (None, Some(sig)) => sig.params()[idx].clone(),
+                (None, None) => self.type_variables.new_type_var(),
+            };
+            param_tys.push(self.infer_pat(arg, ty));
+        }
+
+        let ret_ty = match (ret_type, &expected_sig) {
+            (Some(type_ref), _) => self.resolve_type(type_ref),
+            (None, Some(sig)) => sig.ret().clone(),
+            (None, None) => self.type_variables.new_type_var(),
+        };
+
+        // A `return` inside of the closure returns from the closure and a `break` or `continue`
+        // cannot target a loop outside of it.
+        let outer_return_ty = std::mem::replace(&mut self.return_ty, ret_ty.clone());
+        let outer_loops = std::mem::take(&mut self.active_loops);
+        self.infer_expr_coerce(body, &Expectation::has_type(ret_ty.clone()));
+        self.active_loops = outer_loops;
+
+        // If the body of the closure never returns (e.g. it always panics), nothing constrains its
+        // return type.
+        let ret_ty = self.resolve_ty_as_far_as_possible(ret_ty);
+        let body_diverges = matches!(self.type_of_expr.get(body), Some(ty) if ty.is_never());
+        if body_diverges && matches!(ret_ty.interned(), TyKind::InferenceVar(InferTy::Type(_))) {
+            self.unify(&ret_ty, &Ty::unit());
+        }
+        self.return_ty = outer_return_ty;
+
+        let closure = self
+            .active_closures
+            .pop()
+            .expect("the closure must still be active");
+        self.closure_captures.insert(tgt_expr, closure.captures);
+
+        let ret_ty = self.resolve_ty_as_far_as_possible(ret_ty);
+        let param_tys = param_tys
+            .into_iter()
+            .map(|ty| self.resolve_ty_as_far_as_possible(ty))
+            .collect();
+        Ty::fn_ptr(FnSig::from_params_and_return(param_tys, ret_ty))
+    }
+
+    /// Records that the specified local binding is used by the closures that enclose the current
+    /// expression, up to the closure in which it was introduced.
+    fn capture_binding(&mut self, pat: PatId) {
+        for closure in self.active_closures.iter_mut().rev() {
+            if closure.bindings.contains(&pat) {
+                break;
+            }
+            if !closure.captures.contains(&pat) {
+                closure.captures.push(pat);
+            }
+        }
+    }
+
+    /// If the specified expression refers to a local binding that was introduced outside of the
+    /// innermost enclosing closure, returns the name of that binding.
+    fn captured_binding_name(&self, resolver: &Resolver, expr: ExprId) -> Option<Name> {
+        let closure = self.active_closures.last()?;
+        let path = match &self.body[expr] {
+            Expr::Path(path) => path,
+            _ => return None,
+        };
+        match resolver.resolve_path_as_value_fully(self.db.upcast(), path) {
+            Some((ValueNs::LocalBinding(pat), _)) if !closure.bindings.contains(&pat) => {
+                match &self.body[pat] {
+                    Pat::Bind { name } => Some(name.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// If the specified expression refers to a `static` that is not declared `mut`, returns the
+    /// name of the static.
+    fn immutable_static_name(&self, resolver: &Resolver, expr: ExprId) -> Option<Name> {
+        let path = match &self.body[expr] {
+            Expr::Path(path) => path,
+            _ => return None,
+        };
+        match resolver.resolve_path_as_value_fully(self.db.upcast(), path) {
+            Some((ValueNs::StaticId(id), _)) => {
+                let def = Static::from(id);
+                (!def.is_mut(self.db)).then(|| def.name(self.db))
+            }
+            _ => None,
+        }
+    }
+
+    /// Inferences the type of an if statement.
+    fn infer_if(
+        &mut self,
+        tgt_expr: ExprId,
+        expected: &Expectation,
+        condition: ExprId,
+        then_branch: ExprId,
+        else_branch: Option<ExprId>,
+    ) -> Ty {
+        self.infer_expr(condition, &Expectation::has_type(TyKind::Bool.intern()));
+        let then_ty = self.infer_expr_coerce(then_branch, expected);
+        match else_branch {
+            Some(else_branch) => {
+                let else_ty = self.infer_expr_coerce(else_branch, expected);
+                match self.coerce_merge_branch(&then_ty, &else_ty) {
+                    Some(ty) => ty,
+                    None => {
+                        self.diagnostics
                             .push(InferenceDiagnostic::IncompatibleBranches {
                                 id: tgt_expr,
                                 then_ty: then_ty.clone(),
@@ -558,6 +1407,54 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
+    /// Infers the types of a `let` condition, e.g. `if let a = b`. The value must be of an optional
+    /// type, whose inner type is matched against the pattern.
+    fn infer_let_condition(&mut self, pat: PatId, expr: ExprId) {
+        let expr_ty = self.infer_expr(expr, &Expectation::none());
+        let inner_ty = match expr_ty.interned() {
+            TyKind::Option(inner_ty) => inner_ty.clone(),
+            TyKind::InferenceVar(InferTy::Type(_)) => {
+                let inner_ty = self.type_variables.new_type_var();
+                self.unify(&expr_ty, &TyKind::Option(inner_ty.clone()).intern());
+                inner_ty
+            }
+            TyKind::Unknown => self.error_type(),
+            _ => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::ExpectedOptionalType {
+                        id: expr,
+                        found: expr_ty,
+                    });
+                self.error_type()
+            }
+        };
+        self.infer_pat(pat, inner_ty);
+    }
+
+    /// Inferences the type of a match expression.
+    fn infer_match(&mut self, expr: ExprId, arms: &[MatchArm], expected: &Expectation) -> Ty {
+        let scrutinee_ty = self.infer_expr(expr, &Expectation::none());
+
+        // A match without any arms never produces a value
+        let mut result_ty = TyKind::Never.intern();
+        for arm in arms {
+            self.infer_pat(arm.pat, scrutinee_ty.clone());
+            let arm_ty = self.infer_expr_coerce(arm.expr, expected);
+            result_ty = match self.coerce_merge_branch(&result_ty, &arm_ty) {
+                Some(ty) => ty,
+                None => {
+                    self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                        id: arm.expr,
+                        expected: result_ty.clone(),
+                        found: arm_ty,
+                    });
+                    result_ty
+                }
+            };
+        }
+        result_ty
+    }
+
     /// Inferences the type of a call expression.
     fn infer_call(
         &mut self,
@@ -566,6 +1463,10 @@ impl<'a> InferenceResultBuilder<'a> {
         args: &[ExprId],
         _expected: &Expectation,
     ) -> Ty {
+        if let Some(function) = self.intrinsic_callee(callee) {
+            return self.infer_intrinsic_call(tgt_expr, function, args);
+        }
+
         let callee_ty = self.infer_expr_inner(
             callee,
             &Expectation::none(),
@@ -575,7 +1476,7 @@ impl<'a> InferenceResultBuilder<'a> {
         );
 
         match callee_ty.interned() {
-            TyKind::Struct(s) => {
+            TyKind::Struct(s, _) => {
                 // Erroneously found either a unit struct or record struct literal. Record struct
                 // literals can never be used as a value so that will have already been reported.
                 if s.data(self.db.upcast()).kind == StructKind::Unit {
@@ -594,13 +1495,34 @@ impl<'a> InferenceResultBuilder<'a> {
 
                 callee_ty
             }
+            TyKind::Enum(_) => {
+                // Erroneously found a unit variant. Record variants can never be used as a value
+                // so that will have already been reported.
+                if let Some(variant) = self.variant_resolutions.get(&callee.into()) {
+                    if variant.kind(self.db) == StructKind::Unit {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::MismatchedStructLit {
+                                id: tgt_expr,
+                                expected: StructKind::Unit,
+                                found: StructKind::Tuple,
+                            });
+                    }
+                }
+
+                // Still derive subtypes
+                for arg in args.iter() {
+                    self.infer_expr(*arg, &Expectation::none());
+                }
+
+                callee_ty
+            }
             TyKind::FnDef(def, _substs) => {
                 // Found either a tuple struct literal or function
                 let sig = callee_ty.callable_sig(self.db).unwrap();
                 let (param_tys, ret_ty) = (sig.params().to_vec(), sig.ret().clone());
                 self.check_call_argument_count(
                     tgt_expr,
-                    def.is_struct(),
+                    !def.is_function(),
                     args.len(),
                     param_tys.len(),
                 );
@@ -610,6 +1532,16 @@ impl<'a> InferenceResultBuilder<'a> {
 
                 ret_ty
             }
+            TyKind::FnPtr(_) => {
+                let sig = callee_ty.callable_sig(self.db).unwrap();
+                let (param_tys, ret_ty) = (sig.params().to_vec(), sig.ret().clone());
+                self.check_call_argument_count(tgt_expr, false, args.len(), param_tys.len());
+                for (&arg, param_ty) in args.iter().zip(param_tys.iter()) {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(param_ty.clone()));
+                }
+
+                ret_ty
+            }
             TyKind::Unknown => {
                 // Error has already been emitted somewhere else
                 self.error_type()
@@ -625,6 +1557,254 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
+    /// Returns the built-in function that the `callee` of a call expression refers to, if any.
+    fn intrinsic_callee(&self, callee: ExprId) -> Option<IntrinsicFunction> {
+        let path = match &self.body[callee] {
+            Expr::Path(path) => path,
+            _ => return None,
+        };
+        let resolver = resolver_for_expr(self.db.upcast(), self.body.owner(), callee);
+        match resolver.resolve_path_as_value_fully(self.db.upcast(), path) {
+            Some((ValueNs::IntrinsicFunction(function), _)) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Inferences the type of a call to a built-in function. The functions of the `core::math`
+    /// module are overloaded: all arguments and the return value have the type of the first
+    /// argument.
+    fn infer_intrinsic_call(
+        &mut self,
+        tgt_expr: ExprId,
+        function: IntrinsicFunction,
+        args: &[ExprId],
+    ) -> Ty {
+        self.intrinsic_resolutions.insert(tgt_expr, function);
+
+        let param_count = function.param_count();
+        self.check_call_argument_count(tgt_expr, false, args.len(), param_count);
+
+        // `panic` takes the message of the panic and never returns
+        if function == IntrinsicFunction::Panic {
+            for (idx, &arg) in args.iter().enumerate() {
+                if idx < param_count {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(TyKind::String.intern()));
+                } else {
+                    // Still derive subtypes
+                    self.infer_expr(arg, &Expectation::none());
+                }
+            }
+            return TyKind::Never.intern();
+        }
+
+        let ty = match args.first() {
+            Some(&arg) => self.infer_expr(arg, &Expectation::none()),
+            None => return self.error_type(),
+        };
+        for (idx, &arg) in args.iter().enumerate().skip(1) {
+            if idx < param_count {
+                self.infer_expr_coerce(arg, &Expectation::has_type(ty.clone()));
+            } else {
+                // Still derive subtypes
+                self.infer_expr(arg, &Expectation::none());
+            }
+        }
+
+        let is_supported = match self.type_variables.replace_if_possible(&ty).interned() {
+            TyKind::Float(_) | TyKind::InferenceVar(InferTy::Float(_)) => true,
+            TyKind::Int(_) | TyKind::InferenceVar(InferTy::Int(_)) => function.supports_integers(),
+            // The type is either erroneous or still unknown; any error is reported elsewhere
+            TyKind::Unknown | TyKind::InferenceVar(InferTy::Type(_)) => true,
+            _ => false,
+        };
+        if !is_supported {
+            self.diagnostics
+                .push(InferenceDiagnostic::UnsupportedIntrinsicArgument {
+                    id: args[0],
+                    function,
+                    found: ty,
+                });
+            return self.error_type();
+        }
+
+        ty
+    }
+
+    /// Inferences the type of a method call expression.
+    fn infer_method_call(
+        &mut self,
+        tgt_expr: ExprId,
+        receiver: ExprId,
+        method_name: &Name,
+        args: &[ExprId],
+    ) -> Ty {
+        let receiver_ty = self.infer_expr(receiver, &Expectation::none());
+        match builtin_method_sig(&receiver_ty, method_name) {
+            Some(sig) => {
+                let (param_tys, ret_ty) = (sig.params().to_vec(), sig.ret().clone());
+                self.check_call_argument_count(tgt_expr, false, args.len(), param_tys.len());
+                for (&arg, param_ty) in args.iter().zip(param_tys.iter()) {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(param_ty.clone()));
+                }
+                ret_ty
+            }
+            None => {
+                let method = lookup_associated_function(self.db, &receiver_ty, method_name)
+                    .filter(|function| function.is_method(self.db));
+                if let Some(method) = method {
+                    return self.infer_associated_method_call(
+                        tgt_expr,
+                        receiver,
+                        &receiver_ty,
+                        method,
+                        args,
+                    );
+                }
+
+                if receiver_ty.as_option().is_some() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::PossiblyNilAccess {
+                            id: receiver,
+                            ty: receiver_ty,
+                        });
+                } else if receiver_ty.is_known() {
+                    self.diagnostics.push(InferenceDiagnostic::MethodNotFound {
+                        id: tgt_expr,
+                        receiver_ty,
+                        name: method_name.clone(),
+                    });
+                }
+
+                // Still derive subtypes
+                for arg in args.iter() {
+                    self.infer_expr(*arg, &Expectation::none());
+                }
+
+                self.error_type()
+            }
+        }
+    }
+
+    /// Infers the type of a call to a `method` that is defined in an `impl` block of the type of
+    /// the `receiver`.
+    fn infer_associated_method_call(
+        &mut self,
+        tgt_expr: ExprId,
+        receiver: ExprId,
+        receiver_ty: &Ty,
+        method: Function,
+        args: &[ExprId],
+    ) -> Ty {
+        let sig = self.resolve_method(tgt_expr, method);
+        let (self_ty, param_tys) = sig
+            .params()
+            .split_first()
+            .expect("a method must have a `self` parameter");
+        if !self.unify(self_ty, receiver_ty) {
+            self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                expected: self_ty.clone(),
+                found: receiver_ty.clone(),
+                id: receiver,
+            });
+        }
+
+        self.check_call_argument_count(tgt_expr, false, args.len(), param_tys.len());
+        for (&arg, param_ty) in args.iter().zip(param_tys.iter()) {
+            self.infer_expr_coerce(arg, &Expectation::has_type(param_ty.clone()));
+        }
+        sig.ret().clone()
+    }
+
+    /// Infers the type of the binary operation `tgt_expr` that is overloaded by the `method` of the
+    /// type of `lhs`, e.g. `a + b` calls `a.add(b)`. If the type of `rhs` was already inferred, it
+    /// is passed as `rhs_ty`.
+    #[allow(clippy::too_many_arguments)]
+    fn infer_overloaded_binary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        op: BinaryOp,
+        lhs: ExprId,
+        lhs_ty: &Ty,
+        rhs: ExprId,
+        rhs_ty: Option<Ty>,
+        method: Function,
+    ) -> Ty {
+        let sig = self.resolve_method(tgt_expr, method);
+        let (self_ty, rhs_param_ty) = match sig.params() {
+            [self_ty, rhs_param_ty] => (self_ty, rhs_param_ty),
+            _ => unreachable!("an operator method must have two parameters"),
+        };
+        if !self.unify(self_ty, lhs_ty) {
+            self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                expected: self_ty.clone(),
+                found: lhs_ty.clone(),
+                id: lhs,
+            });
+        }
+        match rhs_ty {
+            Some(rhs_ty) => {
+                if !self.unify(rhs_param_ty, &rhs_ty) {
+                    self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                        expected: rhs_param_ty.clone(),
+                        found: rhs_ty,
+                        id: rhs,
+                    });
+                }
+            }
+            None => {
+                self.infer_expr_coerce(rhs, &Expectation::has_type(rhs_param_ty.clone()));
+            }
+        }
+
+        // Comparisons must result in a boolean and the result of a compound assignment is
+        // assigned to the left hand side, so it must have the same type.
+        let ret_ty = sig.ret().clone();
+        let expected_ret_ty = match op {
+            BinaryOp::CmpOp(_) => Some(TyKind::Bool.intern()),
+            BinaryOp::Assignment { .. } => Some(lhs_ty.clone()),
+            BinaryOp::ArithOp(_) | BinaryOp::LogicOp(_) => None,
+        };
+        match expected_ret_ty {
+            Some(expected) => {
+                if !self.unify(&expected, &ret_ty) {
+                    self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                        expected: expected.clone(),
+                        found: ret_ty,
+                        id: tgt_expr,
+                    });
+                }
+                op::binary_op_return_ty(op, expected)
+            }
+            None => ret_ty,
+        }
+    }
+
+    /// Records that the expression `tgt_expr` calls the specified method, which is defined in an
+    /// `impl` block or trait, and returns the signature of the method with its type parameters
+    /// instantiated.
+    fn resolve_method(&mut self, tgt_expr: ExprId, method: Function) -> FnSig {
+        if !method.visibility(self.db).is_visible_from(
+            self.db,
+            self.resolver
+                .module()
+                .expect("resolver must have a module to be able to resolve modules"),
+        ) {
+            self.diagnostics.push(InferenceDiagnostic::PathIsPrivate {
+                id: tgt_expr.into(),
+            })
+        }
+        let method_ty = self.instantiate_type_params(method.ty(self.db));
+        let substs = method_ty
+            .type_parameters()
+            .cloned()
+            .unwrap_or_else(Substitution::empty);
+        self.method_resolutions.insert(tgt_expr, (method, substs));
+
+        method_ty
+            .callable_sig(self.db)
+            .expect("a function must have a signature")
+    }
+
     /// Checks whether the specified struct type is a unit struct.
     fn check_unit_struct_lit(&mut self, tgt_expr: ExprId, expected: Struct) {
         let struct_data = expected.data(self.db.upcast());
@@ -664,37 +1844,29 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
-    // Checks whether the passed fields match the fields of a struct definition.
+    // Checks whether the passed fields match the fields of a struct or enum variant definition.
     fn check_record_lit(
         &mut self,
         tgt_expr: ExprId,
         ty: &Ty,
-        expected: Struct,
+        expected_kind: StructKind,
+        expected_fields: Vec<Name>,
         fields: &[RecordLitField],
     ) {
-        let struct_data = expected.data(self.db.upcast());
-        if struct_data.kind != StructKind::Record {
+        if expected_kind != StructKind::Record {
             self.diagnostics
                 .push(InferenceDiagnostic::MismatchedStructLit {
                     id: tgt_expr,
-                    expected: struct_data.kind,
+                    expected: expected_kind,
                     found: StructKind::Record,
                 });
             return;
         }
 
         let lit_fields: FxHashSet<_> = fields.iter().map(|f| &f.name).collect();
-        let missed_fields: Vec<Name> = struct_data
-            .fields
-            .iter()
-            .filter_map(|(_f, d)| {
-                let name = d.name.clone();
-                if lit_fields.contains(&name) {
-                    None
-                } else {
-                    Some(name)
-                }
-            })
+        let missed_fields: Vec<Name> = expected_fields
+            .into_iter()
+            .filter(|name| !lit_fields.contains(name))
             .collect();
 
         if !missed_fields.is_empty() {
@@ -723,17 +1895,20 @@ impl<'a> InferenceResultBuilder<'a> {
                         .expect("resolver must have a module to be able to resolve modules"),
                 ) {
                     self.diagnostics
-                        .push(diagnostics::InferenceDiagnostic::PathIsPrivate { id })
+                        .push(diagnostics::InferenceDiagnostic::PathIsPrivate { id: id.into() })
                 }
 
                 // Match based on what type of value we found
                 match value {
-                    ValueNs::LocalBinding(pat) => Some(self.type_of_pat.get(pat)?.clone()),
+                    ValueNs::LocalBinding(pat) => {
+                        self.capture_binding(pat);
+                        Some(self.type_of_pat.get(pat)?.clone())
+                    }
                     ValueNs::FunctionId(f) => {
                         let (ty, _) = self
                             .db
                             .type_for_def(TypableDef::Function(f.into()), Namespace::Values);
-                        Some(ty)
+                        Some(self.instantiate_type_params(ty))
                     }
                     ValueNs::StructId(s) => {
                         if check_params.is_unit_struct {
@@ -742,8 +1917,35 @@ impl<'a> InferenceResultBuilder<'a> {
                         let (ty, _) = self
                             .db
                             .type_for_def(TypableDef::Struct(s.into()), Namespace::Values);
+                        Some(self.instantiate_type_params(ty))
+                    }
+                    ValueNs::EnumVariantId(v) => {
+                        let variant = EnumVariant::from(v);
+                        self.variant_resolutions.insert(id.into(), variant);
+                        if check_params.is_unit_struct {
+                            let kind = variant.kind(self.db);
+                            if kind != StructKind::Unit {
+                                self.diagnostics
+                                    .push(InferenceDiagnostic::MismatchedStructLit {
+                                        id,
+                                        expected: kind,
+                                        found: StructKind::Unit,
+                                    });
+                            }
+                        }
+                        let (ty, _) = self
+                            .db
+                            .type_for_def(TypableDef::EnumVariant(variant), Namespace::Values);
                         Some(ty)
                     }
+                    ValueNs::ConstId(c) => Some(Const::from(c).ty(self.db)),
+                    ValueNs::StaticId(s) => Some(Static::from(s).ty(self.db)),
+                    ValueNs::IntrinsicFunction(function) => {
+                        // Intrinsic functions are overloaded, so they have no single function type
+                        self.diagnostics
+                            .push(InferenceDiagnostic::IntrinsicFunctionAsValue { id, function });
+                        None
+                    }
                 }
             }
 
@@ -779,6 +1981,28 @@ impl<'a> InferenceResultBuilder<'a> {
                     let (ty, _) = self
                         .db
                         .type_for_def(TypableDef::Struct(struct_id.into()), Namespace::Values);
+                    return Some(self.instantiate_type_params(ty));
+                }
+
+                // Same for record enum variants
+                if let Some((TypeNs::EnumVariantId(variant_id), _)) = ty {
+                    let variant = EnumVariant::from(variant_id);
+                    debug_assert_eq!(variant.kind(self.db), StructKind::Record);
+                    self.variant_resolutions.insert(id.into(), variant);
+                    self.diagnostics
+                        .push(InferenceDiagnostic::MismatchedStructLit {
+                            id,
+                            expected: StructKind::Record,
+                            found: if check_params.is_unit_struct {
+                                StructKind::Unit
+                            } else {
+                                StructKind::Tuple
+                            },
+                        });
+
+                    let (ty, _) = self
+                        .db
+                        .type_for_def(TypableDef::EnumVariant(variant), Namespace::Values);
                     return Some(ty);
                 }
 
@@ -800,6 +2024,9 @@ impl<'a> InferenceResultBuilder<'a> {
             let resolved = self.type_variables.resolve_ty_completely(ty.clone());
             if !was_unknown && resolved.is_unknown() {
                 self.report_expr_inference_failure(expr);
+            } else if self.is_unconstrained_nil(expr, ty, &resolved) {
+                self.diagnostics
+                    .push(InferenceDiagnostic::NilInferenceFailed { id: expr });
             }
             *ty = resolved;
         }
@@ -812,13 +2039,25 @@ impl<'a> InferenceResultBuilder<'a> {
             }
             *ty = resolved;
         }
+        self.check_literal_pats(&pat_types);
+        let mut method_resolutions = std::mem::take(&mut self.method_resolutions);
+        for (_, (_, substs)) in method_resolutions.iter_mut() {
+            *substs = substs
+                .iter()
+                .map(|ty| self.type_variables.resolve_ty_completely(ty.clone()))
+                .collect();
+        }
         InferenceResult {
-            //            method_resolutions: self.method_resolutions,
             //            field_resolutions: self.field_resolutions,
-            //            variant_resolutions: self.variant_resolutions,
             //            assoc_resolutions: self.assoc_resolutions,
             type_of_expr: expr_types,
             type_of_pat: pat_types,
+            variant_resolutions: self.variant_resolutions,
+            method_resolutions,
+            intrinsic_resolutions: self.intrinsic_resolutions,
+            closure_captures: self.closure_captures,
+            trait_object_coercions: self.trait_object_coercions,
+            type_param_ops: self.type_param_ops,
             diagnostics: self.diagnostics,
             standard_types: Default::default(),
         }
@@ -879,21 +2118,52 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
-    fn infer_break(&mut self, tgt_expr: ExprId, expr: Option<ExprId>) -> Ty {
-        let expected = match &self.active_loop {
-            Some(ActiveLoop::Loop(_, info)) => info.clone(),
-            Some(_) => {
+    /// Returns the index in `active_loops` of the loop targeted by a `break` or `continue`
+    /// expression. This is the loop with the specified label, or the innermost loop if no label is
+    /// specified. Adds a diagnostic if there is no such loop.
+    fn find_loop_target(
+        &mut self,
+        tgt_expr: ExprId,
+        label: Option<&Name>,
+        is_continue: bool,
+    ) -> Option<usize> {
+        let index = match label {
+            Some(label) => self
+                .active_loops
+                .iter()
+                .rposition(|(name, _)| name.as_ref() == Some(label)),
+            None => self.active_loops.len().checked_sub(1),
+        };
+
+        if index.is_none() {
+            self.diagnostics.push(match label {
+                Some(label) => InferenceDiagnostic::UnresolvedLabel {
+                    id: tgt_expr,
+                    label: label.clone(),
+                },
+                None if is_continue => InferenceDiagnostic::ContinueOutsideLoop { id: tgt_expr },
+                None => InferenceDiagnostic::BreakOutsideLoop { id: tgt_expr },
+            });
+        }
+
+        index
+    }
+
+    fn infer_break(&mut self, tgt_expr: ExprId, expr: Option<ExprId>, label: Option<&Name>) -> Ty {
+        let loop_index = match self.find_loop_target(tgt_expr, label, false) {
+            Some(index) => index,
+            None => return TyKind::Never.intern(),
+        };
+        let (expected, is_for) = match &self.active_loops[loop_index].1 {
+            ActiveLoop::Loop(_, info) => (info.clone(), false),
+            ActiveLoop::For(_, info) => (info.clone(), true),
+            ActiveLoop::While => {
                 if expr.is_some() {
                     self.diagnostics
                         .push(InferenceDiagnostic::BreakWithValueOutsideLoop { id: tgt_expr });
                 }
                 return TyKind::Never.intern();
             }
-            None => {
-                self.diagnostics
-                    .push(InferenceDiagnostic::BreakOutsideLoop { id: tgt_expr });
-                return TyKind::Never.intern();
-            }
         };
 
         // Infer the type of the break expression
@@ -903,27 +2173,55 @@ impl<'a> InferenceResultBuilder<'a> {
             Ty::unit()
         };
 
-        // Verify that it matches what we expected
-        let ty = if !self.unify(&ty, &expected.ty) {
+        // Verify that it matches what we expected. The values of a `for` loop may be coerced to an
+        // optional type, because the loop evaluates to `nil` if it finishes without breaking.
+        let matches = if is_for {
+            self.coerce(&ty, &expected.ty)
+        } else {
+            self.unify(&ty, &expected.ty)
+        };
+        let ty = if !matches {
             self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
                 expected: expected.ty.clone(),
                 found: ty,
                 id: tgt_expr,
             });
             expected.ty
+        } else if is_for && !expected.ty.is_unknown() {
+            expected.ty
+        } else {
+            ty
+        };
+
+        // A `for` loop evaluates to an optional value, so the other breaks of the loop may also
+        // break with `nil`.
+        let ty = self.resolve_ty_as_far_as_possible(ty);
+        let ty = if is_for && !ty.is_unknown() && ty.is_nullable_reference(self.db) {
+            TyKind::Option(ty).intern()
         } else {
             ty
         };
 
         // Update the expected type for the rest of the loop
-        self.active_loop = Some(ActiveLoop::Loop(ty.clone(), Expectation::has_type(ty)));
+        self.active_loops[loop_index].1 = if is_for {
+            ActiveLoop::For(ty.clone(), Expectation::has_type(ty))
+        } else {
+            ActiveLoop::Loop(ty.clone(), Expectation::has_type(ty))
+        };
 
         TyKind::Never.intern()
     }
 
-    fn infer_loop_expr(&mut self, _tgt_expr: ExprId, body: ExprId, expected: &Expectation) -> Ty {
+    fn infer_loop_expr(
+        &mut self,
+        _tgt_expr: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+        expected: &Expectation,
+    ) -> Ty {
         if let ActiveLoop::Loop(ty, _) = self.infer_loop_block(
             body,
+            label,
             ActiveLoop::Loop(TyKind::Never.intern(), expected.clone()),
         ) {
             ty
@@ -932,14 +2230,22 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
-    fn infer_loop_block(&mut self, body: ExprId, lp: ActiveLoop) -> ActiveLoop {
-        let top_level_loop = std::mem::replace(&mut self.active_loop, Some(lp));
+    fn infer_loop_block(
+        &mut self,
+        body: ExprId,
+        label: Option<Name>,
+        lp: ActiveLoop,
+    ) -> ActiveLoop {
+        self.active_loops.push((label, lp));
 
         // Infer the body of the loop
         self.infer_expr_coerce(body, &Expectation::has_type(Ty::unit()));
 
-        // Take the result of the loop information and replace with top level loop
-        std::mem::replace(&mut self.active_loop, top_level_loop).unwrap()
+        // Take the result of the loop information, which leaves the enclosing loop on top
+        self.active_loops
+            .pop()
+            .expect("the active loop must still be present")
+            .1
     }
 
     fn infer_while_expr(
@@ -947,37 +2253,173 @@ impl<'a> InferenceResultBuilder<'a> {
         _tgt_expr: ExprId,
         condition: ExprId,
         body: ExprId,
+        label: Option<Name>,
         _expected: &Expectation,
     ) -> Ty {
         self.infer_expr(condition, &Expectation::has_type(TyKind::Bool.intern()));
-        self.infer_loop_block(body, ActiveLoop::While);
+        self.infer_loop_block(body, label, ActiveLoop::While);
         Ty::unit()
     }
 
-    pub fn report_pat_inference_failure(&mut self, _pat: PatId) {
-        //        self.diagnostics.push(InferenceDiagnostic::PatInferenceFailed {
-        //            pat
-        //        });
-        // Currently this should never happen because we can only infer integer and floating-point
-        // types which always have a fallback value.
-        panic!("pattern failed inferencing");
-    }
-
-    pub fn report_expr_inference_failure(&mut self, _expr: ExprId) {
-        //        self.diagnostics.push(InferenceDiagnostic::ExprInferenceFailed {
-        //            expr
-        //        });
-        // Currently this should never happen because we can only infer integer and floating-point
-        // types which always have a fallback value.
-        panic!("expression failed inferencing");
-    }
-}
-
-/// When inferring an expression, we propagate downward whatever type hint we
-/// are able in the form of an `Expectation`.
-#[derive(Clone, PartialEq, Eq, Debug)]
-struct Expectation {
-    ty: Ty,
+    /// Infers the type of a `for` loop. The iterable is either a range expression, in which case
+    /// the loop variable takes the integer type of its bounds, or an array, in which case the loop
+    /// variable takes the type of its elements.
+    ///
+    /// A `for` loop that breaks with a value of type `T` has type `?T`, because it evaluates to
+    /// `nil` if it finishes without breaking.
+    fn infer_for_expr(
+        &mut self,
+        tgt_expr: ExprId,
+        pat: PatId,
+        iterable: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+        expected: &Expectation,
+    ) -> Ty {
+        let item_ty = if let Expr::Range { start, end, .. } = &self.body[iterable] {
+            let (start, end) = (*start, *end);
+            let ty = self.infer_range_bounds(start, end);
+            self.set_expr_type(iterable, ty.clone());
+            ty
+        } else {
+            let iterable_ty = self.infer_expr(iterable, &Expectation::none());
+            let iterable_ty = self.resolve_ty_as_far_as_possible(iterable_ty);
+            if let Some(element_ty) = iterable_ty.as_array() {
+                element_ty.clone()
+            } else {
+                if !iterable_ty.is_unknown() {
+                    self.diagnostics.push(InferenceDiagnostic::NotIterable {
+                        id: iterable,
+                        ty: iterable_ty,
+                    });
+                }
+                TyKind::Unknown.intern()
+            }
+        };
+
+        self.infer_pat(pat, item_ty);
+
+        // Only an optional type can be expected of the values the loop breaks with
+        let expected = match self.replace_if_possible(&expected.ty).interned() {
+            TyKind::Option(_) => expected.clone(),
+            _ => Expectation::none(),
+        };
+        let break_ty = match self.infer_loop_block(
+            body,
+            label,
+            ActiveLoop::For(TyKind::Never.intern(), expected),
+        ) {
+            ActiveLoop::For(ty, _) => self.resolve_ty_as_far_as_possible(ty),
+            _ => panic!("returned active loop must be a for loop"),
+        };
+
+        match break_ty.interned() {
+            TyKind::Never => Ty::unit(),
+            TyKind::Option(_) | TyKind::Unknown => break_ty,
+            _ if break_ty.is_empty() => break_ty,
+            _ if break_ty.is_nullable_reference(self.db) => TyKind::Option(break_ty).intern(),
+            _ => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::InvalidForBreakValue { id: tgt_expr });
+                self.error_type()
+            }
+        }
+    }
+
+    /// Infers the bounds of a range expression, which must both be of the same integer type.
+    /// Returns the type of the bounds.
+    fn infer_range_bounds(&mut self, start: ExprId, end: ExprId) -> Ty {
+        let start_ty = self.infer_expr(start, &Expectation::none());
+        self.infer_expr_coerce(end, &Expectation::has_type(start_ty.clone()));
+
+        let ty = self.resolve_ty_as_far_as_possible(start_ty);
+        match ty.interned() {
+            TyKind::Int(_) | TyKind::InferenceVar(InferTy::Int(_)) | TyKind::Unknown => ty,
+            _ => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::InvalidRangeBound { id: start, ty });
+                TyKind::Unknown.intern()
+            }
+        }
+    }
+
+    /// Reports integer literal patterns that do not fit in their type, and range patterns that
+    /// do not contain any value. This can only be checked once the types of all patterns are
+    /// known.
+    fn check_literal_pats(&mut self, pat_types: &ArenaMap<PatId, Ty>) {
+        let body = self.body;
+        let data_layout = self.db.target_data_layout();
+        for (pat, ty) in pat_types.iter() {
+            let int_ty = match ty.interned() {
+                TyKind::Int(int_ty) => *int_ty,
+                _ => continue,
+            };
+            let int_value = |pat: PatId| match &body[pat] {
+                Pat::Lit { expr, negated } => match &body[*expr] {
+                    Expr::Literal(Literal::Int(lit)) => Some((
+                        *expr,
+                        int_ty
+                            .resolve(&data_layout)
+                            .biased_value(lit.value, *negated),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match &body[pat] {
+                Pat::Lit { .. } => {
+                    if let Some((expr, None)) = int_value(pat) {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::LiteralOutOfRange {
+                                id: expr,
+                                literal_ty: int_ty,
+                            });
+                    }
+                }
+                Pat::Range { start, end } => {
+                    if let (Some((_, Some(start))), Some((_, Some(end)))) =
+                        (int_value(*start), int_value(*end))
+                    {
+                        if start > end {
+                            self.diagnostics
+                                .push(InferenceDiagnostic::EmptyRangePat { id: pat });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn report_pat_inference_failure(&mut self, pat: PatId) {
+        // This happens for parameters of a closure that are neither annotated nor constrained by
+        // the way they are used.
+        self.diagnostics
+            .push(InferenceDiagnostic::PatInferenceFailed { id: pat });
+    }
+
+    /// Returns true if `expr` is a `nil` literal of which the inner type could not be inferred,
+    /// e.g. `let a = nil;`.
+    fn is_unconstrained_nil(&self, expr: ExprId, ty: &Ty, resolved: &Ty) -> bool {
+        matches!(self.body[expr], Expr::Literal(Literal::Nil))
+            && matches!(
+                ty.as_option().map(Ty::interned),
+                Some(TyKind::InferenceVar(_))
+            )
+            && resolved.as_option().map_or(false, Ty::is_unknown)
+    }
+
+    pub fn report_expr_inference_failure(&mut self, _expr: ExprId) {
+        // An expression can only fail inferencing if it refers to a binding whose type could not
+        // be inferred. This has already been reported for the binding itself.
+    }
+}
+
+/// When inferring an expression, we propagate downward whatever type hint we
+/// are able in the form of an `Expectation`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Expectation {
+    ty: Ty,
     // FIXME: In some cases, we need to be aware whether the expectation is that
     // the type match exactly what we passed, or whether it just needs to be
     // coercible to the expected type. See Expectation::rvalue_hint in rustc.
@@ -1034,20 +2476,57 @@ impl From<PatId> for ExprOrPatId {
     }
 }
 
+/// Returns the signature of a method that is built into the language for the specified receiver
+/// type, if it exists. The receiver itself is not part of the signature.
+fn builtin_method_sig(receiver_ty: &Ty, name: &Name) -> Option<FnSig> {
+    let usize_ty = || TyKind::Int(IntTy::usize()).intern();
+    match receiver_ty.interned() {
+        TyKind::String if *name == name![len] => {
+            Some(FnSig::from_params_and_return(Vec::new(), usize_ty()))
+        }
+        TyKind::Array(element_ty) => {
+            let (params, ret) = if *name == name![len] {
+                (Vec::new(), usize_ty())
+            } else if *name == name![push] {
+                (vec![element_ty.clone()], Ty::unit())
+            } else if *name == name![pop] {
+                (Vec::new(), element_ty.clone())
+            } else if *name == name![insert] {
+                (vec![usize_ty(), element_ty.clone()], Ty::unit())
+            } else if *name == name![remove] {
+                (vec![usize_ty()], element_ty.clone())
+            } else {
+                return None;
+            };
+            Some(FnSig::from_params_and_return(params, ret))
+        }
+        _ => None,
+    }
+}
+
 mod diagnostics {
     use crate::diagnostics::PrivateAccess;
     use crate::{
-        code_model::{src::HasSource, StructKind},
+        code_model::{DefWithBody, StructKind},
         diagnostics::{
             AccessUnknownField, BreakOutsideLoop, BreakWithValueOutsideLoop, CannotApplyBinaryOp,
-            CannotApplyUnaryOp, ExpectedFunction, FieldCountMismatch, IncompatibleBranch,
-            InvalidLhs, LiteralOutOfRange, MismatchedStructLit, MismatchedType, MissingElseBranch,
-            MissingFields, NoFields, NoSuchField, ParameterCountMismatch, ReturnMissingExpression,
+            CannotApplyUnaryOp, CannotAssignToCapturedVariable, CannotAssignToImmutableStatic,
+            ContinueOutsideLoop, EmptyRangePat, ExpectedFunction, ExpectedOptionalType,
+            FieldCountMismatch, IncompatibleBranch, IntrinsicFunctionAsValue, InvalidCast,
+            InvalidForBreakValue, InvalidLhs, InvalidOptionalType, InvalidRangeBound,
+            LiteralOutOfRange, MethodNotFound, MismatchedStructLit, MismatchedType,
+            MissingElseBranch, MissingFields, NoFields, NoSuchField, NotIterable,
+            ParameterCountMismatch, PossiblyNilAccess, RangeOutsideForLoop,
+            ReturnMissingExpression, TuplePatLengthMismatch, TypeAnnotationsNeeded,
+            UnsupportedIntrinsicArgument,
+        },
+        diagnostics::{
+            CyclicType, DiagnosticSink, ExpectedTypeFoundTrait, UnresolvedLabel, UnresolvedTrait,
+            UnresolvedType, UnresolvedValue, WrongNumberOfTypeArgs,
         },
-        diagnostics::{CyclicType, DiagnosticSink, UnresolvedType, UnresolvedValue},
         ty::infer::ExprOrPatId,
         type_ref::LocalTypeRefId,
-        ExprId, Function, HirDatabase, IntTy, Name, Ty,
+        ExprId, HirDatabase, IntTy, IntrinsicFunction, Name, PatId, Ty,
     };
 
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -1061,15 +2540,48 @@ mod diagnostics {
         CyclicType {
             id: LocalTypeRefId,
         },
+        UnresolvedTrait {
+            id: LocalTypeRefId,
+        },
+        ExpectedTypeFoundTrait {
+            id: LocalTypeRefId,
+        },
+        InvalidOptionalType {
+            id: LocalTypeRefId,
+        },
+        WrongNumberOfTypeArgs {
+            id: LocalTypeRefId,
+            expected: usize,
+            found: usize,
+        },
         ExpectedFunction {
             id: ExprId,
             found: Ty,
         },
+        UnsupportedIntrinsicArgument {
+            id: ExprId,
+            function: IntrinsicFunction,
+            found: Ty,
+        },
+        IntrinsicFunctionAsValue {
+            id: ExprId,
+            function: IntrinsicFunction,
+        },
         ParameterCountMismatch {
             id: ExprId,
             found: usize,
             expected: usize,
         },
+        MethodNotFound {
+            id: ExprId,
+            receiver_ty: Ty,
+            name: Name,
+        },
+        InvalidCast {
+            id: ExprId,
+            expr_ty: Ty,
+            cast_ty: Ty,
+        },
         MismatchedTypes {
             id: ExprId,
             expected: Ty,
@@ -1097,6 +2609,28 @@ mod diagnostics {
             id: ExprId,
             lhs: ExprId,
         },
+        CannotAssignToCapturedVariable {
+            id: ExprId,
+            name: Name,
+        },
+        CannotAssignToImmutableStatic {
+            id: ExprId,
+            name: Name,
+        },
+        PatInferenceFailed {
+            id: PatId,
+        },
+        NilInferenceFailed {
+            id: ExprId,
+        },
+        PossiblyNilAccess {
+            id: ExprId,
+            ty: Ty,
+        },
+        ExpectedOptionalType {
+            id: ExprId,
+            found: Ty,
+        },
         ReturnMissingExpression {
             id: ExprId,
         },
@@ -1106,6 +2640,27 @@ mod diagnostics {
         BreakWithValueOutsideLoop {
             id: ExprId,
         },
+        InvalidForBreakValue {
+            id: ExprId,
+        },
+        ContinueOutsideLoop {
+            id: ExprId,
+        },
+        UnresolvedLabel {
+            id: ExprId,
+            label: Name,
+        },
+        NotIterable {
+            id: ExprId,
+            ty: Ty,
+        },
+        RangeOutsideForLoop {
+            id: ExprId,
+        },
+        InvalidRangeBound {
+            id: ExprId,
+            ty: Ty,
+        },
         AccessUnknownField {
             id: ExprId,
             receiver_ty: Ty,
@@ -1142,7 +2697,38 @@ mod diagnostics {
             id: LocalTypeRefId,
         },
         PathIsPrivate {
-            id: ExprId,
+            id: ExprOrPatId,
+        },
+        MismatchedPatType {
+            id: PatId,
+            expected: Ty,
+            found: Ty,
+        },
+        MismatchedStructPat {
+            id: PatId,
+            expected: StructKind,
+            found: StructKind,
+        },
+        PatFieldCountMismatch {
+            id: PatId,
+            expected: usize,
+            found: usize,
+        },
+        TuplePatLengthMismatch {
+            id: PatId,
+            expected: usize,
+            found: usize,
+        },
+        EmptyRangePat {
+            id: PatId,
+        },
+        NoSuchPatField {
+            id: PatId,
+        },
+        MissingPatFields {
+            id: PatId,
+            struct_ty: Ty,
+            names: Vec<Name>,
         },
     }
 
@@ -1150,10 +2736,10 @@ mod diagnostics {
         pub(crate) fn add_to(
             &self,
             db: &dyn HirDatabase,
-            owner: Function,
+            owner: DefWithBody,
             sink: &mut DiagnosticSink,
         ) {
-            let file = owner.source(db.upcast()).file_id;
+            let file = owner.file_id(db);
             let body = owner.body_source_map(db);
             match self {
                 InferenceDiagnostic::UnresolvedValue { id } => {
@@ -1178,6 +2764,31 @@ mod diagnostics {
                     let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
                     sink.push(CyclicType { file, type_ref });
                 }
+                InferenceDiagnostic::UnresolvedTrait { id } => {
+                    let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
+                    sink.push(UnresolvedTrait { file, type_ref });
+                }
+                InferenceDiagnostic::ExpectedTypeFoundTrait { id } => {
+                    let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
+                    sink.push(ExpectedTypeFoundTrait { file, type_ref });
+                }
+                InferenceDiagnostic::InvalidOptionalType { id } => {
+                    let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
+                    sink.push(InvalidOptionalType { file, type_ref });
+                }
+                InferenceDiagnostic::WrongNumberOfTypeArgs {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
+                    sink.push(WrongNumberOfTypeArgs {
+                        file,
+                        type_ref,
+                        expected: *expected,
+                        found: *found,
+                    });
+                }
                 InferenceDiagnostic::TypeIsPrivate { id } => {
                     let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
                     sink.push(PrivateAccess {
@@ -1186,13 +2797,16 @@ mod diagnostics {
                     });
                 }
                 InferenceDiagnostic::PathIsPrivate { id } => {
-                    let expr_syntax = body
-                        .expr_syntax(*id)
-                        .map(|ptr| {
+                    let expr_syntax = match id {
+                        ExprOrPatId::ExprId(id) => body.expr_syntax(*id).map(|ptr| {
                             ptr.value
                                 .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
-                        })
-                        .expect("could not resolve expression to syntax node");
+                        }),
+                        ExprOrPatId::PatId(id) => {
+                            body.pat_syntax(*id).map(|ptr| ptr.value.syntax_node_ptr())
+                        }
+                    }
+                    .expect("could not resolve expression to syntax node");
                     sink.push(PrivateAccess {
                         file,
                         expr: expr_syntax,
@@ -1215,6 +2829,100 @@ mod diagnostics {
                         found: *found,
                     })
                 }
+                InferenceDiagnostic::InvalidCast {
+                    id,
+                    expr_ty,
+                    cast_ty,
+                } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(InvalidCast {
+                        file,
+                        expr,
+                        expr_ty: expr_ty.clone(),
+                        cast_ty: cast_ty.clone(),
+                    });
+                }
+                InferenceDiagnostic::PatInferenceFailed { id } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(TypeAnnotationsNeeded { file, pat })
+                }
+                InferenceDiagnostic::NilInferenceFailed { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(TypeAnnotationsNeeded { file, pat: expr })
+                }
+                InferenceDiagnostic::PossiblyNilAccess { id, ty } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(PossiblyNilAccess {
+                        file,
+                        expr,
+                        ty: ty.clone(),
+                    })
+                }
+                InferenceDiagnostic::ExpectedOptionalType { id, found } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(ExpectedOptionalType {
+                        file,
+                        expr,
+                        found: found.clone(),
+                    })
+                }
+                InferenceDiagnostic::CannotAssignToCapturedVariable { id, name } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotAssignToCapturedVariable {
+                        file,
+                        expr,
+                        name: name.clone(),
+                    })
+                }
+                InferenceDiagnostic::CannotAssignToImmutableStatic { id, name } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotAssignToImmutableStatic {
+                        file,
+                        expr,
+                        name: name.clone(),
+                    })
+                }
+                InferenceDiagnostic::MethodNotFound {
+                    id,
+                    receiver_ty,
+                    name,
+                } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(MethodNotFound {
+                        file,
+                        expr,
+                        receiver_ty: receiver_ty.clone(),
+                        name: name.clone(),
+                    })
+                }
                 InferenceDiagnostic::ExpectedFunction { id, found } => {
                     let expr = body
                         .expr_syntax(*id)
@@ -1227,6 +2935,35 @@ mod diagnostics {
                         found: found.clone(),
                     });
                 }
+                InferenceDiagnostic::UnsupportedIntrinsicArgument {
+                    id,
+                    function,
+                    found,
+                } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(UnsupportedIntrinsicArgument {
+                        file,
+                        expr,
+                        function: *function,
+                        found: found.clone(),
+                    });
+                }
+                InferenceDiagnostic::IntrinsicFunctionAsValue { id, function } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(IntrinsicFunctionAsValue {
+                        file,
+                        expr,
+                        function: *function,
+                    });
+                }
                 InferenceDiagnostic::MismatchedTypes {
                     id,
                     found,
@@ -1348,6 +3085,72 @@ mod diagnostics {
                         break_expr: id,
                     });
                 }
+                InferenceDiagnostic::InvalidForBreakValue { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(InvalidForBreakValue { file, expr });
+                }
+                InferenceDiagnostic::ContinueOutsideLoop { id } => {
+                    let id = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(ContinueOutsideLoop {
+                        file,
+                        continue_expr: id,
+                    });
+                }
+                InferenceDiagnostic::UnresolvedLabel { id, label } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(UnresolvedLabel {
+                        file,
+                        expr,
+                        label: label.clone(),
+                    });
+                }
+                InferenceDiagnostic::NotIterable { id, ty } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(NotIterable {
+                        file,
+                        expr,
+                        ty: ty.clone(),
+                    });
+                }
+                InferenceDiagnostic::RangeOutsideForLoop { id } => {
+                    let id = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(RangeOutsideForLoop {
+                        file,
+                        range_expr: id,
+                    });
+                }
+                InferenceDiagnostic::InvalidRangeBound { id, ty } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(InvalidRangeBound {
+                        file,
+                        expr,
+                        ty: ty.clone(),
+                    });
+                }
                 InferenceDiagnostic::AccessUnknownField {
                     id,
                     receiver_ty,
@@ -1433,6 +3236,79 @@ mod diagnostics {
                     let field = owner.body_source_map(db).field_syntax(*id, *field).into();
                     sink.push(NoSuchField { file, field });
                 }
+                InferenceDiagnostic::MismatchedPatType {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(MismatchedType {
+                        file,
+                        expr: pat,
+                        found: found.clone(),
+                        expected: expected.clone(),
+                    });
+                }
+                InferenceDiagnostic::MismatchedStructPat {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(MismatchedStructLit {
+                        file,
+                        expr: pat,
+                        expected: *expected,
+                        found: *found,
+                    });
+                }
+                InferenceDiagnostic::PatFieldCountMismatch {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(FieldCountMismatch {
+                        file,
+                        expr: pat,
+                        expected: *expected,
+                        found: *found,
+                    });
+                }
+                InferenceDiagnostic::TuplePatLengthMismatch {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(TuplePatLengthMismatch {
+                        file,
+                        pat,
+                        expected: *expected,
+                        found: *found,
+                    });
+                }
+                InferenceDiagnostic::EmptyRangePat { id } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(EmptyRangePat { file, pat });
+                }
+                InferenceDiagnostic::NoSuchPatField { id } => {
+                    let field = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(NoSuchField { file, field });
+                }
+                InferenceDiagnostic::MissingPatFields {
+                    id,
+                    struct_ty,
+                    names,
+                } => {
+                    let fields = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(MissingFields {
+                        file,
+                        struct_ty: struct_ty.clone(),
+                        fields,
+                        field_names: names.to_vec(),
+                    });
+                }
                 InferenceDiagnostic::LiteralOutOfRange { id, literal_ty } => {
                     let literal = body
                         .expr_syntax(*id)
@@ -1,5 +1,8 @@
 use super::InferenceResultBuilder;
-use crate::{ty::TyKind, Ty};
+use crate::{
+    ty::{CallableDef, TyKind},
+    Ty,
+};
 
 impl<'a> InferenceResultBuilder<'a> {
     /// Unify two types, but may coerce the first one to the second using implicit coercion rules if
@@ -17,13 +20,33 @@ impl<'a> InferenceResultBuilder<'a> {
         } else if self.coerce(ty2, ty1) {
             Some(ty1.clone())
         } else {
-            None
+            // Two different functions with the same signature merge into a function pointer
+            self.fn_ptr_for_fn_def(ty1)
+                .filter(|fn_ptr| self.coerce(ty1, fn_ptr) && self.coerce(ty2, fn_ptr))
+        }
+    }
+
+    /// If the type is that of a function, returns the function pointer type with the same
+    /// signature. Struct and enum variant constructors cannot be used as a function pointer.
+    fn fn_ptr_for_fn_def(&self, ty: &Ty) -> Option<Ty> {
+        match ty.interned() {
+            TyKind::FnDef(CallableDef::Function(_), _) => ty.callable_sig(self.db).map(Ty::fn_ptr),
+            _ => None,
         }
     }
 
     fn coerce_inner(&mut self, from_ty: Ty, to_ty: &Ty) -> bool {
         match (from_ty.interned(), to_ty.interned()) {
             (TyKind::Never, ..) => return true,
+            // A reference can be used where an optional reference is expected
+            (TyKind::Struct(..) | TyKind::Array(_), TyKind::Option(inner_ty)) => {
+                return self.unify(&from_ty, inner_ty)
+            }
+            (TyKind::FnDef(..), TyKind::FnPtr(_)) => {
+                if let Some(fn_ptr) = self.fn_ptr_for_fn_def(&from_ty) {
+                    return self.unify(&fn_ptr, to_ty);
+                }
+            }
             _ => {
                 if self.type_variables.unify_inner_trivial(&from_ty, to_ty) {
                     return true;
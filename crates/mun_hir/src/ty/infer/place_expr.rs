@@ -16,8 +16,13 @@ impl<'a> InferenceResultBuilder<'a> {
     /// Checks if the specified path references a memory location.
     fn check_place_path(&mut self, resolver: &Resolver, path: &Path) -> bool {
         match resolver.resolve_path_as_value_fully(self.db.upcast(), path) {
-            Some((ValueNs::LocalBinding(_), _)) => true,
-            Some((ValueNs::FunctionId(_), _)) | Some((ValueNs::StructId(_), _)) | None => false,
+            Some((ValueNs::LocalBinding(_), _)) | Some((ValueNs::StaticId(_), _)) => true,
+            Some((ValueNs::FunctionId(_), _))
+            | Some((ValueNs::StructId(_), _))
+            | Some((ValueNs::EnumVariantId(_), _))
+            | Some((ValueNs::ConstId(_), _))
+            | Some((ValueNs::IntrinsicFunction(_), _))
+            | None => false,
         }
     }
 }
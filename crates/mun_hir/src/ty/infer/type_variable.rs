@@ -136,8 +136,12 @@ impl TypeVariableTable {
         let b = self.replace_if_possible(b);
         if a.equals_ctor(&b) {
             match (a.interned(), b.interned()) {
-                (TyKind::Tuple(_, a), TyKind::Tuple(_, b)) => self.unify_substitutions(a, b),
-                (TyKind::Array(t1), TyKind::Array(t2)) => self.unify_inner(t1, t2),
+                (TyKind::Tuple(_, a), TyKind::Tuple(_, b))
+                | (TyKind::Struct(_, a), TyKind::Struct(_, b))
+                | (TyKind::FnDef(_, a), TyKind::FnDef(_, b))
+                | (TyKind::FnPtr(a), TyKind::FnPtr(b)) => self.unify_substitutions(a, b),
+                (TyKind::Array(t1), TyKind::Array(t2))
+                | (TyKind::Option(t1), TyKind::Option(t2)) => self.unify_inner(t1, t2),
                 _ => true,
             }
         } else {
@@ -12,7 +12,8 @@ use crate::{
     resolve::Resolver,
     ty::{FnSig, Ty},
     type_ref::{LocalTypeRefId, TypeRef, TypeRefMap, TypeRefSourceMap},
-    FileId, Function, HirDatabase, ModuleDef, Path, Struct, TypeAlias,
+    Enum, EnumVariant, FileId, Function, HirDatabase, Impl, ModuleDef, Name, Path, Struct, Trait,
+    TypeAlias,
 };
 use crate::{HasVisibility, Visibility};
 use std::{ops::Index, sync::Arc};
@@ -83,7 +84,15 @@ impl Ty {
         type_ref: LocalTypeRefId,
     ) -> Ty {
         let res = match &type_ref_map[type_ref] {
-            TypeRef::Path(path) => Ty::from_path(db, resolver, type_ref, path, diagnostics),
+            TypeRef::Path(path, type_args) => Ty::from_path(
+                db,
+                resolver,
+                type_ref_map,
+                type_ref,
+                path,
+                type_args,
+                diagnostics,
+            ),
             TypeRef::Error => Some((TyKind::Unknown.intern(), false)),
             TypeRef::Tuple(inner) => {
                 let inner_tys = inner.iter().map(|tr| {
@@ -105,6 +114,38 @@ impl Ty {
                 );
                 Some((TyKind::Array(inner).intern(), false))
             }
+            TypeRef::Option(inner) => {
+                let inner = Self::from_hir_with_diagnostics(
+                    db,
+                    resolver,
+                    type_ref_map,
+                    diagnostics,
+                    *inner,
+                );
+                if !inner.is_nullable_reference(db) {
+                    diagnostics.push(LowerDiagnostic::InvalidOptionalType { id: type_ref });
+                }
+                Some((TyKind::Option(inner).intern(), false))
+            }
+            TypeRef::Fn(params, ret) => {
+                let params_and_return = params
+                    .iter()
+                    .chain(std::iter::once(ret))
+                    .map(|tr| {
+                        Self::from_hir_with_diagnostics(
+                            db,
+                            resolver,
+                            type_ref_map,
+                            diagnostics,
+                            *tr,
+                        )
+                    })
+                    .collect();
+                Some((TyKind::FnPtr(params_and_return).intern(), false))
+            }
+            TypeRef::DynTrait(path) => {
+                return Ty::from_trait_path(db, resolver, type_ref, path, diagnostics);
+            }
         };
         if let Some((ty, is_cyclic)) = res {
             if is_cyclic {
@@ -117,12 +158,38 @@ impl Ty {
         }
     }
 
-    /// Constructs a `Ty` from a path.
+    /// Constructs the trait object type of the trait the `path` refers to, e.g. `dyn Damageable`.
+    fn from_trait_path(
+        db: &dyn HirDatabase,
+        resolver: &Resolver,
+        type_ref: LocalTypeRefId,
+        path: &Path,
+        diagnostics: &mut Vec<LowerDiagnostic>,
+    ) -> Ty {
+        match resolver.resolve_path_as_type_fully(db.upcast(), path) {
+            Some((TypeNs::TraitId(id), vis)) => {
+                if let Some(module) = resolver.module() {
+                    if !vis.is_visible_from(db, module) {
+                        diagnostics.push(LowerDiagnostic::TypeIsPrivate { id: type_ref })
+                    }
+                }
+                Trait::from(id).ty()
+            }
+            _ => {
+                diagnostics.push(LowerDiagnostic::UnresolvedTrait { id: type_ref });
+                TyKind::Unknown.intern()
+            }
+        }
+    }
+
+    /// Constructs a `Ty` from a path and the type arguments of its last segment.
     fn from_path(
         db: &dyn HirDatabase,
         resolver: &Resolver,
+        type_ref_map: &TypeRefMap,
         type_ref: LocalTypeRefId,
         path: &Path,
+        type_args: &[LocalTypeRefId],
         diagnostics: &mut Vec<LowerDiagnostic>,
     ) -> Option<(Self, bool)> {
         // Find the type
@@ -131,8 +198,36 @@ impl Ty {
         // Get the definition and visibility
         let def = match ty {
             TypeNs::StructId(id) => TypableDef::Struct(id.into()),
+            TypeNs::EnumId(id) => TypableDef::Enum(id.into()),
+            TypeNs::EnumVariantId(id) => TypableDef::EnumVariant(id.into()),
             TypeNs::TypeAliasId(id) => TypableDef::TypeAlias(id.into()),
             TypeNs::PrimitiveType(id) => TypableDef::PrimitiveType(id),
+            TypeNs::GenericParam(idx) => {
+                check_type_arg_count(type_ref, 0, type_args.len(), diagnostics);
+                let name = path
+                    .as_ident()
+                    .expect("a type parameter is a single identifier");
+                return Some((
+                    TyKind::Param {
+                        idx,
+                        name: name.clone(),
+                    }
+                    .intern(),
+                    false,
+                ));
+            }
+            TypeNs::SelfType(id) => {
+                check_type_arg_count(type_ref, 0, type_args.len(), diagnostics);
+                return Some((Impl::from(id).self_ty(db), false));
+            }
+            TypeNs::TraitSelfType(id) => {
+                check_type_arg_count(type_ref, 0, type_args.len(), diagnostics);
+                return Some((Trait::from(id).ty(), false));
+            }
+            TypeNs::TraitId(_) => {
+                diagnostics.push(LowerDiagnostic::ExpectedTypeFoundTrait { id: type_ref });
+                return Some((TyKind::Unknown.intern(), false));
+            }
         };
 
         // Get the current module and see if the type is visible from here
@@ -142,7 +237,65 @@ impl Ty {
             }
         }
 
-        Some(db.type_for_def(def, Namespace::Types))
+        // Lower the type arguments and substitute them for the type parameters of the type.
+        // Missing or superfluous type arguments result in unknown types.
+        let (ty, is_cyclic) = db.type_for_def(def, Namespace::Types);
+        let num_type_params = ty.type_parameters().map_or(0, |substs| substs.len());
+        let ty = match ty.interned() {
+            TyKind::Struct(s, _) => {
+                let substs = if check_type_arg_count(
+                    type_ref,
+                    num_type_params,
+                    type_args.len(),
+                    diagnostics,
+                ) {
+                    type_args
+                        .iter()
+                        .map(|type_arg| {
+                            Ty::from_hir_with_diagnostics(
+                                db,
+                                resolver,
+                                type_ref_map,
+                                diagnostics,
+                                *type_arg,
+                            )
+                        })
+                        .collect()
+                } else {
+                    (0..num_type_params)
+                        .map(|_| TyKind::Unknown.intern())
+                        .collect()
+                };
+                TyKind::Struct(*s, substs).intern()
+            }
+            _ => {
+                check_type_arg_count(type_ref, 0, type_args.len(), diagnostics);
+                ty
+            }
+        };
+
+        Some((ty, is_cyclic))
+    }
+}
+
+/// Checks whether the number of type arguments of a type reference matches the number of type
+/// parameters of the type it refers to. Returns `false` and adds a diagnostic if that is not the
+/// case.
+fn check_type_arg_count(
+    type_ref: LocalTypeRefId,
+    expected: usize,
+    found: usize,
+    diagnostics: &mut Vec<LowerDiagnostic>,
+) -> bool {
+    if expected != found {
+        diagnostics.push(LowerDiagnostic::WrongNumberOfTypeArgs {
+            id: type_ref,
+            expected,
+            found,
+        });
+        false
+    } else {
+        true
     }
 }
 
@@ -166,16 +319,44 @@ pub fn lower_struct_query(db: &dyn HirDatabase, s: Struct) -> Arc<LowerTyMap> {
     types_from_hir(db, &s.id.resolver(db.upcast()), data.type_ref_map())
 }
 
+pub fn lower_enum_query(db: &dyn HirDatabase, e: Enum) -> Arc<LowerTyMap> {
+    let data = e.data(db.upcast());
+    types_from_hir(db, &e.id.resolver(db.upcast()), data.type_ref_map())
+}
+
 pub fn lower_type_alias_query(db: &dyn HirDatabase, t: TypeAlias) -> Arc<LowerTyMap> {
     let data = t.data(db.upcast());
     types_from_hir(db, &t.id.resolver(db.upcast()), data.type_ref_map())
 }
 
+pub fn lower_impl_query(db: &dyn HirDatabase, i: Impl) -> Arc<LowerTyMap> {
+    let data = i.data(db.upcast());
+    let resolver = i.id.resolver(db.upcast());
+    let mut result = LowerTyMap::default();
+    for (id, _) in data.type_ref_map().iter() {
+        // The implemented trait is not a type, it is resolved by `Impl::target_trait`
+        if Some(id) == data.target_trait {
+            continue;
+        }
+        let ty = Ty::from_hir_with_diagnostics(
+            db,
+            &resolver,
+            data.type_ref_map(),
+            &mut result.diagnostics,
+            id,
+        );
+        result.type_ref_to_type.insert(id, ty);
+    }
+    Arc::new(result)
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TypableDef {
     Function(Function),
     PrimitiveType(PrimitiveType),
     Struct(Struct),
+    Enum(Enum),
+    EnumVariant(EnumVariant),
     TypeAlias(TypeAlias),
 }
 
@@ -197,14 +378,33 @@ impl From<Struct> for TypableDef {
     }
 }
 
+impl From<Enum> for TypableDef {
+    fn from(f: Enum) -> Self {
+        TypableDef::Enum(f)
+    }
+}
+
+impl From<EnumVariant> for TypableDef {
+    fn from(f: EnumVariant) -> Self {
+        TypableDef::EnumVariant(f)
+    }
+}
+
 impl From<ModuleDef> for Option<TypableDef> {
     fn from(d: ModuleDef) -> Self {
         match d {
             ModuleDef::Function(f) => Some(TypableDef::Function(f)),
             ModuleDef::PrimitiveType(t) => Some(TypableDef::PrimitiveType(t)),
             ModuleDef::Struct(t) => Some(TypableDef::Struct(t)),
+            ModuleDef::Enum(t) => Some(TypableDef::Enum(t)),
+            ModuleDef::EnumVariant(t) => Some(TypableDef::EnumVariant(t)),
             ModuleDef::TypeAlias(t) => Some(TypableDef::TypeAlias(t)),
-            ModuleDef::Module(_) => None,
+            ModuleDef::Module(_)
+            | ModuleDef::Trait(_)
+            | ModuleDef::Const(_)
+            | ModuleDef::Static(_)
+            | ModuleDef::BuiltinModule(_)
+            | ModuleDef::IntrinsicFunction(_) => None,
         }
     }
 }
@@ -213,8 +413,9 @@ impl From<ModuleDef> for Option<TypableDef> {
 pub enum CallableDef {
     Function(Function),
     Struct(Struct),
+    EnumVariant(EnumVariant),
 }
-impl_froms!(CallableDef: Function, Struct);
+impl_froms!(CallableDef: Function, Struct, EnumVariant);
 
 impl CallableDef {
     pub fn is_function(self) -> bool {
@@ -224,6 +425,10 @@ impl CallableDef {
     pub fn is_struct(self) -> bool {
         matches!(self, CallableDef::Struct(_))
     }
+
+    pub fn is_enum_variant(self) -> bool {
+        matches!(self, CallableDef::EnumVariant(_))
+    }
 }
 
 impl HasVisibility for CallableDef {
@@ -231,6 +436,7 @@ impl HasVisibility for CallableDef {
         match self {
             CallableDef::Struct(strukt) => strukt.visibility(db),
             CallableDef::Function(function) => function.visibility(db),
+            CallableDef::EnumVariant(variant) => variant.parent_enum().visibility(db),
         }
     }
 }
@@ -245,11 +451,15 @@ pub(crate) fn type_for_def(db: &dyn HirDatabase, def: TypableDef, ns: Namespace)
         (TypableDef::PrimitiveType(t), Namespace::Types) => type_for_primitive(t),
         (TypableDef::Struct(s), Namespace::Values) => type_for_struct_constructor(db, s),
         (TypableDef::Struct(s), Namespace::Types) => type_for_struct(db, s),
+        (TypableDef::Enum(e), Namespace::Types) => type_for_enum(db, e),
+        (TypableDef::EnumVariant(v), Namespace::Values) => type_for_enum_variant_constructor(db, v),
+        (TypableDef::EnumVariant(v), Namespace::Types) => type_for_enum(db, v.parent_enum()),
         (TypableDef::TypeAlias(t), Namespace::Types) => type_for_type_alias(db, t),
 
         // 'error' cases:
         (TypableDef::Function(_), Namespace::Types) => TyKind::Unknown.intern(),
         (TypableDef::PrimitiveType(_), Namespace::Values) => TyKind::Unknown.intern(),
+        (TypableDef::Enum(_), Namespace::Values) => TyKind::Unknown.intern(),
         (TypableDef::TypeAlias(_), Namespace::Values) => TyKind::Unknown.intern(),
     };
     (ty, false)
@@ -271,20 +481,38 @@ fn type_for_primitive(def: PrimitiveType) -> Ty {
         PrimitiveType::Float(f) => TyKind::Float(f.into()),
         PrimitiveType::Int(i) => TyKind::Int(i.into()),
         PrimitiveType::Bool => TyKind::Bool,
+        PrimitiveType::String => TyKind::String,
     }
     .intern()
 }
 
 /// Build the declared type of a function. This should not need to look at the
 /// function body.
-fn type_for_fn(_db: &dyn HirDatabase, def: Function) -> Ty {
-    TyKind::FnDef(def.into(), Substitution::empty()).intern()
+fn type_for_fn(db: &dyn HirDatabase, def: Function) -> Ty {
+    let data = def.data(db.upcast());
+    TyKind::FnDef(def.into(), identity_substs(data.type_params())).intern()
+}
+
+/// Constructs a substitution that substitutes each of the specified type parameters by itself.
+fn identity_substs(type_params: &[Name]) -> Substitution {
+    type_params
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            TyKind::Param {
+                idx: idx as u32,
+                name: name.clone(),
+            }
+            .intern()
+        })
+        .collect()
 }
 
 pub(crate) fn callable_item_sig(db: &dyn HirDatabase, def: CallableDef) -> FnSig {
     match def {
         CallableDef::Function(f) => fn_sig_for_fn(db, f),
         CallableDef::Struct(s) => fn_sig_for_struct_constructor(db, s),
+        CallableDef::EnumVariant(v) => fn_sig_for_enum_variant_constructor(db, v),
     }
 }
 
@@ -316,14 +544,38 @@ pub(crate) fn fn_sig_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -
 fn type_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -> Ty {
     let struct_data = db.struct_data(def.id);
     if struct_data.kind == StructKind::Tuple {
-        TyKind::FnDef(def.into(), Substitution::empty()).intern()
+        TyKind::FnDef(def.into(), identity_substs(&struct_data.type_params)).intern()
     } else {
         type_for_struct(db, def)
     }
 }
 
-fn type_for_struct(_db: &dyn HirDatabase, def: Struct) -> Ty {
-    TyKind::Struct(def).intern()
+fn type_for_struct(db: &dyn HirDatabase, def: Struct) -> Ty {
+    let struct_data = db.struct_data(def.id);
+    TyKind::Struct(def, identity_substs(&struct_data.type_params)).intern()
+}
+
+pub(crate) fn fn_sig_for_enum_variant_constructor(db: &dyn HirDatabase, def: EnumVariant) -> FnSig {
+    let params = def
+        .fields(db)
+        .into_iter()
+        .map(|field| field.ty(db))
+        .collect::<Vec<_>>();
+    let ret = type_for_enum(db, def.parent_enum());
+    FnSig::from_params_and_return(params, ret)
+}
+
+/// Build the type of an enum variant constructor.
+fn type_for_enum_variant_constructor(db: &dyn HirDatabase, def: EnumVariant) -> Ty {
+    if def.kind(db) == StructKind::Tuple {
+        TyKind::FnDef(def.into(), Substitution::empty()).intern()
+    } else {
+        type_for_enum(db, def.parent_enum())
+    }
+}
+
+fn type_for_enum(_db: &dyn HirDatabase, def: Enum) -> Ty {
+    TyKind::Enum(def).intern()
 }
 
 fn type_for_type_alias(db: &dyn HirDatabase, def: TypeAlias) -> Ty {
@@ -334,7 +586,10 @@ fn type_for_type_alias(db: &dyn HirDatabase, def: TypeAlias) -> Ty {
 }
 
 pub mod diagnostics {
-    use crate::diagnostics::{CyclicType, PrivateAccess, UnresolvedType};
+    use crate::diagnostics::{
+        CyclicType, ExpectedTypeFoundTrait, InvalidOptionalType, PrivateAccess, UnresolvedTrait,
+        UnresolvedType, WrongNumberOfTypeArgs,
+    };
     use crate::{
         diagnostics::DiagnosticSink,
         type_ref::{LocalTypeRefId, TypeRefSourceMap},
@@ -343,9 +598,29 @@ pub mod diagnostics {
 
     #[derive(Debug, PartialEq, Eq, Clone)]
     pub(crate) enum LowerDiagnostic {
-        UnresolvedType { id: LocalTypeRefId },
-        TypeIsPrivate { id: LocalTypeRefId },
-        CyclicType { id: LocalTypeRefId },
+        UnresolvedType {
+            id: LocalTypeRefId,
+        },
+        TypeIsPrivate {
+            id: LocalTypeRefId,
+        },
+        CyclicType {
+            id: LocalTypeRefId,
+        },
+        WrongNumberOfTypeArgs {
+            id: LocalTypeRefId,
+            expected: usize,
+            found: usize,
+        },
+        UnresolvedTrait {
+            id: LocalTypeRefId,
+        },
+        ExpectedTypeFoundTrait {
+            id: LocalTypeRefId,
+        },
+        InvalidOptionalType {
+            id: LocalTypeRefId,
+        },
     }
 
     impl LowerDiagnostic {
@@ -369,6 +644,30 @@ pub mod diagnostics {
                     file: file_id,
                     expr: source_map.type_ref_syntax(*id).unwrap().syntax_node_ptr(),
                 }),
+                LowerDiagnostic::WrongNumberOfTypeArgs {
+                    id,
+                    expected,
+                    found,
+                } => sink.push(WrongNumberOfTypeArgs {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                    expected: *expected,
+                    found: *found,
+                }),
+                LowerDiagnostic::UnresolvedTrait { id } => sink.push(UnresolvedTrait {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                }),
+                LowerDiagnostic::ExpectedTypeFoundTrait { id } => {
+                    sink.push(ExpectedTypeFoundTrait {
+                        file: file_id,
+                        type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                    })
+                }
+                LowerDiagnostic::InvalidOptionalType { id } => sink.push(InvalidOptionalType {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                }),
             }
         }
     }
@@ -0,0 +1,148 @@
+//! Resolution of the methods that are defined for a type in `impl` blocks.
+
+use crate::{
+    code_model::{Package, Struct, Trait},
+    ids::ImplId,
+    Function, HirDatabase, Impl, Name, PackageId, Ty, TyKind,
+};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// Holds the inherent `impl` blocks of all the structs defined in a package.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct InherentImpls {
+    map: FxHashMap<Struct, Vec<ImplId>>,
+}
+
+impl InherentImpls {
+    pub(crate) fn inherent_impls_in_package_query(
+        db: &dyn HirDatabase,
+        package: PackageId,
+    ) -> Arc<Self> {
+        let mut impls = Self::default();
+        for module in (Package { id: package }).modules(db) {
+            for imp in module.impls(db) {
+                // Trait implementations are collected by `TraitImpls`
+                if imp.data(db.upcast()).target_trait.is_some() {
+                    continue;
+                }
+
+                // `impl` blocks for types other than structs of this package are invalid and
+                // reported by `Impl::diagnostics`.
+                if let Some(s) = imp.self_ty(db).as_struct() {
+                    if s.module(db).id.package == package {
+                        impls.map.entry(s).or_default().push(imp.id);
+                    }
+                }
+            }
+        }
+        Arc::new(impls)
+    }
+
+    /// Returns all the `impl` blocks defined for the specified struct.
+    pub fn for_struct(&self, s: Struct) -> impl Iterator<Item = Impl> + '_ {
+        self.map
+            .get(&s)
+            .into_iter()
+            .flat_map(|impls| impls.iter().map(|&id| Impl::from(id)))
+    }
+}
+
+/// Holds the implementations of traits for the structs defined in a package.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TraitImpls {
+    /// The implementations of every trait, in the order in which they are defined
+    by_trait: FxHashMap<Trait, Vec<(Struct, ImplId)>>,
+}
+
+impl TraitImpls {
+    pub(crate) fn trait_impls_in_package_query(
+        db: &dyn HirDatabase,
+        package: PackageId,
+    ) -> Arc<Self> {
+        let mut impls = Self::default();
+        for module in (Package { id: package }).modules(db) {
+            for imp in module.impls(db) {
+                // Invalid implementations are reported by `Impl::diagnostics`.
+                let (target_trait, s) = match (imp.target_trait(db), imp.self_ty(db).as_struct()) {
+                    (Some(target_trait), Some(s)) => (target_trait, s),
+                    _ => continue,
+                };
+                if s.module(db).id.package != package {
+                    continue;
+                }
+
+                // Only the first implementation of a trait for a struct is used, others are
+                // reported as conflicting by `Impl::diagnostics`.
+                let trait_impls = impls.by_trait.entry(target_trait).or_default();
+                if !trait_impls.iter().any(|(other, _)| *other == s) {
+                    trait_impls.push((s, imp.id));
+                }
+            }
+        }
+        Arc::new(impls)
+    }
+
+    /// Returns all implementations of the specified trait, in the order in which they are defined.
+    pub fn for_trait(&self, t: Trait) -> impl Iterator<Item = (Struct, Impl)> + '_ {
+        self.by_trait
+            .get(&t)
+            .into_iter()
+            .flat_map(|impls| impls.iter().map(|&(s, id)| (s, Impl::from(id))))
+    }
+
+    /// Returns the implementation of the specified trait for the struct `s`, if any.
+    pub fn find(&self, t: Trait, s: Struct) -> Option<Impl> {
+        self.for_trait(t)
+            .find(|(other, _)| *other == s)
+            .map(|(_, imp)| imp)
+    }
+
+    /// Returns the implementations of traits for the specified struct.
+    pub fn for_struct(&self, s: Struct) -> impl Iterator<Item = Impl> + '_ {
+        self.by_trait
+            .values()
+            .flat_map(|impls| impls.iter())
+            .filter(move |(other, _)| *other == s)
+            .map(|&(_, id)| Impl::from(id))
+    }
+}
+
+/// Looks up the function with the specified `name` that is defined in one of the `impl` blocks of
+/// the type `ty`. Functions of inherent `impl` blocks take precedence over the functions of trait
+/// implementations. For a trait object the function is looked up in the trait.
+pub(crate) fn lookup_associated_function(
+    db: &dyn HirDatabase,
+    ty: &Ty,
+    name: &Name,
+) -> Option<Function> {
+    if let TyKind::DynTrait(t) = ty.interned() {
+        return t.function(db, name);
+    }
+
+    let s = ty.as_struct()?;
+    let package = s.module(db).id.package;
+    let impls = db.inherent_impls_in_package(package);
+    let function = impls
+        .for_struct(s)
+        .flat_map(|imp| imp.functions(db))
+        .find(|function| function.name(db) == *name);
+    function.or_else(|| {
+        let mut functions = db
+            .trait_impls_in_package(package)
+            .for_struct(s)
+            .flat_map(|imp| imp.functions(db))
+            .filter(|function| function.name(db) == *name)
+            .collect::<Vec<_>>();
+        // Sort to select the same function regardless of the order of the hash map
+        functions.sort();
+        functions.into_iter().next()
+    })
+}
+
+/// Returns the implementation of the trait `t` for the struct type `ty`, if any.
+pub(crate) fn lookup_trait_impl(db: &dyn HirDatabase, ty: &Ty, t: Trait) -> Option<Impl> {
+    let s = ty.as_struct()?;
+    db.trait_impls_in_package(s.module(db).id.package)
+        .find(t, s)
+}
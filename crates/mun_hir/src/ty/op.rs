@@ -1,4 +1,8 @@
-use crate::{ty::infer::InferTy, ty::TyKind, ArithOp, BinaryOp, Ty};
+use crate::{
+    name::{name, Name},
+    ty::{infer::InferTy, method_resolution::lookup_associated_function, TyKind},
+    ArithOp, BinaryOp, CmpOp, Function, HirDatabase, Ordering, Ty, UnaryOp,
+};
 
 /// Given a binary operation and the type on the left of that operation, returns the expected type
 /// for the right hand side of the operation or `Ty::Unknown` if such an operation is invalid.
@@ -6,11 +10,19 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
     match op {
         BinaryOp::LogicOp(..) => TyKind::Bool.intern(),
 
-        // Compare operations are allowed for all scalar types
+        // Optional values can only be checked for equality, e.g. `a == nil`
+        BinaryOp::CmpOp(CmpOp::Eq { .. }) if matches!(lhs_ty.interned(), TyKind::Option(_)) => {
+            lhs_ty
+        }
+
+        // Compare operations are allowed for all scalar types and strings. Operations on type
+        // parameters are checked for every instantiation of the generic function.
         BinaryOp::CmpOp(..) => match lhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
             | TyKind::Bool
+            | TyKind::String
+            | TyKind::Param { .. }
             | TyKind::InferenceVar(InferTy::Float(_))
             | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
             _ => TyKind::Unknown.intern(),
@@ -20,8 +32,14 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
             TyKind::Int(_)
             | TyKind::Float(_)
             | TyKind::Bool
-            | TyKind::Struct(_)
+            | TyKind::String
+            | TyKind::Struct(..)
+            | TyKind::Enum(_)
+            | TyKind::Param { .. }
             | TyKind::Array(_)
+            | TyKind::Option(_)
+            | TyKind::Tuple(..)
+            | TyKind::DynTrait(_)
             | TyKind::InferenceVar(InferTy::Float(_))
             | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
             _ => TyKind::Unknown.intern(),
@@ -46,14 +64,28 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
         | BinaryOp::ArithOp(ArithOp::BitAnd)
         | BinaryOp::ArithOp(ArithOp::BitOr)
         | BinaryOp::ArithOp(ArithOp::BitXor) => match lhs_ty.interned() {
-            TyKind::Int(_) | TyKind::Bool | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
+            TyKind::Int(_)
+            | TyKind::Bool
+            | TyKind::Param { .. }
+            | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
             _ => TyKind::Unknown.intern(),
         },
 
+        // Strings can only be concatenated
+        BinaryOp::Assignment {
+            op: Some(ArithOp::Add),
+        }
+        | BinaryOp::ArithOp(ArithOp::Add)
+            if matches!(lhs_ty.interned(), TyKind::String) =>
+        {
+            lhs_ty
+        }
+
         // Arithmetic operations are supported only on number types
         BinaryOp::Assignment { op: Some(_) } | BinaryOp::ArithOp(_) => match lhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
+            | TyKind::Param { .. }
             | TyKind::InferenceVar(InferTy::Float(_))
             | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
             _ => TyKind::Unknown.intern(),
@@ -65,9 +97,11 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
 /// the return type of that operation.
 pub(super) fn binary_op_return_ty(op: BinaryOp, rhs_ty: Ty) -> Ty {
     match op {
+        BinaryOp::ArithOp(ArithOp::Add) if matches!(rhs_ty.interned(), TyKind::String) => rhs_ty,
         BinaryOp::ArithOp(_) => match rhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
+            | TyKind::Param { .. }
             | TyKind::InferenceVar(InferTy::Float(_))
             | TyKind::InferenceVar(InferTy::Int(_)) => rhs_ty,
             _ => TyKind::Unknown.intern(),
@@ -76,3 +110,102 @@ pub(super) fn binary_op_return_ty(op: BinaryOp, rhs_ty: Ty) -> Ty {
         BinaryOp::Assignment { .. } => Ty::unit(),
     }
 }
+
+/// Returns the name of the method that is called when the binary operation `op` is applied to a
+/// user-defined type, e.g. `a + b` calls `a.add(b)` and `a += b` assigns `a.add(b)` to `a`. Both
+/// `==` and `!=` call `eq`, the result of which is negated for `!=`. Returns `None` if the
+/// operation cannot be overloaded.
+fn binary_op_method_name(op: BinaryOp) -> Option<Name> {
+    let name = match op {
+        BinaryOp::ArithOp(op) | BinaryOp::Assignment { op: Some(op) } => match op {
+            ArithOp::Add => name![add],
+            ArithOp::Subtract => name![sub],
+            ArithOp::Multiply => name![mul],
+            ArithOp::Divide => name![div],
+            ArithOp::Remainder => name![rem],
+            ArithOp::LeftShift => name![shl],
+            ArithOp::RightShift => name![shr],
+            ArithOp::BitAnd => name![bitand],
+            ArithOp::BitOr => name![bitor],
+            ArithOp::BitXor => name![bitxor],
+        },
+        BinaryOp::CmpOp(CmpOp::Eq { .. }) => name![eq],
+        BinaryOp::CmpOp(CmpOp::Ord { ordering, strict }) => match (ordering, strict) {
+            (Ordering::Less, true) => name![lt],
+            (Ordering::Less, false) => name![le],
+            (Ordering::Greater, true) => name![gt],
+            (Ordering::Greater, false) => name![ge],
+        },
+        BinaryOp::LogicOp(_) | BinaryOp::Assignment { op: None } => return None,
+    };
+    Some(name)
+}
+
+/// Looks up the method that overloads the binary operation `op` for the type on the left hand side
+/// of the operation. The method is defined in an `impl` block of the type and takes `self` and
+/// the right hand side of the operation as its parameters.
+pub(super) fn binary_op_method(
+    db: &dyn HirDatabase,
+    op: BinaryOp,
+    lhs_ty: &Ty,
+) -> Option<Function> {
+    let name = binary_op_method_name(op)?;
+    lookup_associated_function(db, lhs_ty, &name)
+        .filter(|function| function.is_method(db) && db.fn_data(function.id).params().len() == 2)
+}
+
+/// Looks up the method that overloads the binary operation `op` for a type that is substituted
+/// for a type parameter. In the body of a generic function both operands of the operation have the
+/// type of the type parameter, so the method must take two values of `lhs_ty` and, for comparisons
+/// and compound assignments, return a `bool` or a value of `lhs_ty` respectively.
+pub(super) fn type_arg_binary_op_method(
+    db: &dyn HirDatabase,
+    op: BinaryOp,
+    lhs_ty: &Ty,
+) -> Option<Function> {
+    let method = binary_op_method(db, op, lhs_ty).filter(|method| !method.is_generic(db))?;
+    let sig = method.ty(db).callable_sig(db)?;
+    let expected_ret_ty = match op {
+        BinaryOp::CmpOp(_) => TyKind::Bool.intern(),
+        _ => lhs_ty.clone(),
+    };
+    if sig.params() == [lhs_ty.clone(), lhs_ty.clone()] && *sig.ret() == expected_ret_ty {
+        Some(method)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the binary operation `op` can be applied to values of a type that is
+/// substituted for a type parameter, either because the type supports the operation or because it
+/// overloads the operation with a method.
+pub(super) fn is_type_arg_binary_op_supported(
+    db: &dyn HirDatabase,
+    op: BinaryOp,
+    lhs_ty: &Ty,
+) -> bool {
+    binary_op_rhs_expectation(op, lhs_ty.clone()).is_known()
+        || type_arg_binary_op_method(db, op, lhs_ty).is_some()
+}
+
+/// Given a unary operation and the type of its operand, returns the type of the result of the
+/// operation or `Ty::Unknown` if such an operation is invalid.
+pub(super) fn unary_op_return_ty(op: UnaryOp, ty: Ty) -> Ty {
+    match op {
+        UnaryOp::Not => match ty.interned() {
+            TyKind::Bool
+            | TyKind::Int(_)
+            | TyKind::Param { .. }
+            | TyKind::InferenceVar(InferTy::Int(_)) => ty,
+            _ => TyKind::Unknown.intern(),
+        },
+        UnaryOp::Neg => match ty.interned() {
+            TyKind::Float(_)
+            | TyKind::Int(_)
+            | TyKind::Param { .. }
+            | TyKind::InferenceVar(InferTy::Int(_))
+            | TyKind::InferenceVar(InferTy::Float(_)) => ty,
+            _ => TyKind::Unknown.intern(),
+        },
+    }
+}
@@ -145,6 +145,29 @@ impl IntTy {
             },
         }
     }
+
+    /// Returns the value of a, possibly negated, integer literal as an unsigned integer that
+    /// compares like the values of this type: signed values are stored with their sign bit
+    /// flipped. Returns `None` if the literal does not fit in this type.
+    pub fn biased_value(self, value: u128, negated: bool) -> Option<u128> {
+        let max = self.max();
+        if self.signedness.is_signed() {
+            // The magnitude of a negative value can be one larger than the maximum
+            if value > max + negated as u128 {
+                return None;
+            }
+            let value = if negated {
+                (value as i128).wrapping_neg()
+            } else {
+                value as i128
+            };
+            Some(value as u128 ^ (1 << 127))
+        } else if value > max || (negated && value != 0) {
+            None
+        } else {
+            Some(value)
+        }
+    }
 }
 
 impl From<abi::Integer> for IntBitness {
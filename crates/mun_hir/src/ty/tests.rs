@@ -973,7 +973,7 @@ fn infer_while() {
     }
     "#),
     @r###"
-    109..116: `break` with value can only appear in a `loop`
+    109..116: `break` with value can only appear in a `loop` or a `for` loop
     9..217 '{     ...; }; }': ()
     19..20 'n': i32
     23..24 '0': i32
@@ -1012,6 +1012,574 @@ fn infer_while() {
     "###);
 }
 
+#[test]
+fn infer_labeled_loops() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn foo() -> i32 {
+        let n = 0;
+        let a = 'outer: loop {
+            'inner: while n < 10 {
+                n += 1;
+                if n == 3 { continue 'inner; }
+                if n == 5 { continue 'outer; }
+                if n == 7 { break 'outer n; }
+                continue;
+            }
+        };
+        'a: for i in 0..n { break 'a; }
+        'b: while true { break 'b 3; }  // error: break with value can only appear in a loop
+        continue;                       // error: continue outside of a loop
+        loop { break 'c; }              // error: undeclared label
+        a
+    }
+    "#),
+    @r###"
+    335..345: `break` with value can only appear in a `loop` or a `for` loop
+    407..415: `continue` outside of a loop
+    487..495: use of undeclared label `'c`
+    16..546 '{     ...   a }': never
+    26..27 'n': i32
+    30..31 '0': i32
+    41..42 'a': i32
+    45..276 ''outer...     }': i32
+    58..276 '{     ...     }': ()
+    68..270 ''inner...     }': ()
+    82..83 'n': i32
+    82..88 'n < 10': bool
+    86..88 '10': i32
+    89..270 '{     ...     }': never
+    103..104 'n': i32
+    103..109 'n += 1': ()
+    108..109 '1': i32
+    123..153 'if n =...ner; }': ()
+    126..127 'n': i32
+    126..132 'n == 3': bool
+    131..132 '3': i32
+    133..153 '{ cont...ner; }': never
+    135..150 'continue 'inner': never
+    166..196 'if n =...ter; }': ()
+    169..170 'n': i32
+    169..175 'n == 5': bool
+    174..175 '5': i32
+    176..196 '{ cont...ter; }': never
+    178..193 'continue 'outer': never
+    209..238 'if n =...r n; }': ()
+    212..213 'n': i32
+    212..218 'n == 7': bool
+    217..218 '7': i32
+    219..238 '{ brea...r n; }': never
+    221..235 'break 'outer n': never
+    234..235 'n': i32
+    251..259 'continue': never
+    282..313 ''a: fo... 'a; }': ()
+    290..291 'i': i32
+    295..296 '0': i32
+    295..299 '0..n': i32
+    298..299 'n': i32
+    300..313 '{ break 'a; }': never
+    302..310 'break 'a': never
+    318..348 ''b: wh...b 3; }': ()
+    328..332 'true': bool
+    333..348 '{ break 'b 3; }': never
+    335..345 'break 'b 3': never
+    407..415 'continue': never
+    480..498 'loop {... 'c; }': never
+    485..498 '{ break 'c; }': never
+    487..495 'break 'c': never
+    543..544 'a': i32
+    "###);
+}
+
+#[test]
+fn infer_for() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn sum(values: [f64], n: u8) -> f64 {
+        let total = 0.0;
+        for value in values { total += value; };
+        for i in 0..n { break; };
+        for i in 0..=10 { };
+        for i in 0..10 { break 3; };    // error: cannot evaluate to `nil`
+        total
+    }
+
+    struct(gc) Foo { a: i32 }
+
+    fn find(foos: [Foo], a: i32) -> ?Foo {
+        let first = for foo in foos { break foo; };
+        let second: ?Foo = for foo in foos {
+            if foo.a == a { break foo; }
+            if foo.a > a { break nil; }
+        };
+        for foo in foos { if foo.a == a { break foo; } else { break second; } }
+    }
+
+    fn errors(a: bool) {
+        for x in a { };                 // error: not iterable
+        for x in true..false { };       // error: range bounds must be integers
+        let r = 0..10;                  // error: range outside of a for loop
+    }
+    "#),
+    @r###"
+    163..190: a `for` loop evaluates to `nil` if it finishes without breaking, so it can only break with a garbage collected struct, an array or an optional value
+    591..592: `for` loops can only iterate over arrays and ranges
+    650..654: range bounds must be integers
+    725..730: range expressions can only appear as the iterable of a `for` loop
+    7..13 'values': [f64]
+    22..23 'n': u8
+    36..241 '{     ...otal }': f64
+    46..51 'total': f64
+    54..57 '0.0': f64
+    63..102 'for va...lue; }': ()
+    67..72 'value': f64
+    76..82 'values': [f64]
+    83..102 '{ tota...lue; }': ()
+    85..90 'total': f64
+    85..99 'total += value': ()
+    94..99 'value': f64
+    108..132 'for i ...eak; }': ()
+    112..113 'i': u8
+    117..118 '0': u8
+    117..121 '0..n': u8
+    120..121 'n': u8
+    122..132 '{ break; }': never
+    124..129 'break': never
+    138..157 'for i ...10 { }': ()
+    142..143 'i': i32
+    147..148 '0': i32
+    147..153 '0..=10': i32
+    151..153 '10': i32
+    154..157 '{ }': ()
+    163..190 'for i ...k 3; }': {unknown}
+    167..168 'i': i32
+    172..173 '0': i32
+    172..177 '0..10': i32
+    175..177 '10': i32
+    178..190 '{ break 3; }': never
+    180..187 'break 3': never
+    186..187 '3': i32
+    234..239 'total': f64
+    278..282 'foos': [Foo]
+    291..292 'a': i32
+    307..555 '{     ... } } }': ?Foo
+    317..322 'first': ?Foo
+    325..355 'for fo...foo; }': ?Foo
+    329..332 'foo': Foo
+    336..340 'foos': [Foo]
+    341..355 '{ break foo; }': never
+    343..352 'break foo': never
+    349..352 'foo': Foo
+    365..371 'second': ?Foo
+    380..476 'for fo...     }': ?Foo
+    384..387 'foo': Foo
+    391..395 'foos': [Foo]
+    396..476 '{     ...     }': ()
+    406..434 'if foo...foo; }': ()
+    409..412 'foo': Foo
+    409..414 'foo.a': i32
+    409..419 'foo.a == a': bool
+    418..419 'a': i32
+    420..434 '{ break foo; }': never
+    422..431 'break foo': never
+    428..431 'foo': Foo
+    443..470 'if foo...nil; }': ()
+    446..449 'foo': Foo
+    446..451 'foo.a': i32
+    446..455 'foo.a > a': bool
+    454..455 'a': i32
+    456..470 '{ break nil; }': never
+    458..467 'break nil': never
+    464..467 'nil': ?Foo
+    482..553 'for fo...d; } }': ?Foo
+    486..489 'foo': Foo
+    493..497 'foos': [Foo]
+    498..553 '{ if f...d; } }': ()
+    500..551 'if foo...ond; }': ()
+    503..506 'foo': Foo
+    503..508 'foo.a': i32
+    503..513 'foo.a == a': bool
+    512..513 'a': i32
+    514..528 '{ break foo; }': never
+    516..525 'break foo': never
+    522..525 'foo': Foo
+    534..551 '{ brea...ond; }': never
+    536..548 'break second': never
+    542..548 'second': ?Foo
+    567..568 'a': bool
+    576..788 '{     ...loop }': ()
+    582..596 'for x in a { }': ()
+    586..587 'x': {unknown}
+    591..592 'a': bool
+    593..596 '{ }': ()
+    641..665 'for x ...se { }': ()
+    645..646 'x': {unknown}
+    650..654 'true': bool
+    650..661 'true..false': {unknown}
+    656..661 'false': bool
+    662..665 '{ }': ()
+    721..722 'r': {unknown}
+    725..726 '0': i32
+    725..730 '0..10': {unknown}
+    728..730 '10': i32
+    "###);
+}
+
+#[test]
+fn infer_cast() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Foo;
+
+    fn numeric(a: i32, b: f64, c: bool) {
+        let d = a as f32;
+        let e = b as u8;
+        let f = c as i64;
+        let g = 300 as u8;
+        let h = -1.5 as i16 as usize;
+        let i = 1 + a as i8 as i32;
+    }
+
+    fn identity(a: Foo) -> Foo {
+        a as Foo
+    }
+
+    fn errors(a: i32, b: Foo) {
+        let c = a as bool;      // error: cannot cast integer to bool
+        let d = b as i32;       // error: cannot cast struct to integer
+        let e = 1.0 as Foo;     // error: cannot cast float to struct
+        let f = a as Bar;       // error: unresolved type
+    }
+    "#),
+    @r###"
+    293..302: invalid cast
+    359..367: invalid cast
+    427..437: invalid cast
+    498..501: undefined type
+    24..25 'a': i32
+    32..33 'b': f64
+    40..41 'c': bool
+    49..206 '{     ...i32; }': ()
+    59..60 'd': f32
+    63..64 'a': i32
+    63..71 'a as f32': f32
+    81..82 'e': u8
+    85..86 'b': f64
+    85..92 'b as u8': u8
+    102..103 'f': i64
+    106..107 'c': bool
+    106..114 'c as i64': i64
+    124..125 'g': u8
+    128..131 '300': i32
+    128..137 '300 as u8': u8
+    147..148 'h': usize
+    151..155 '-1.5': f64
+    151..162 '-1.5 as i16': i16
+    151..171 '-1.5 a... usize': usize
+    152..155 '1.5': f64
+    181..182 'i': i32
+    185..186 '1': i32
+    185..203 '1 + a ...as i32': i32
+    189..190 'a': i32
+    189..196 'a as i8': i8
+    189..203 'a as i8 as i32': i32
+    220..221 'a': Foo
+    235..251 '{     ... Foo }': Foo
+    241..242 'a': Foo
+    241..249 'a as Foo': Foo
+    263..264 'a': i32
+    271..272 'b': Foo
+    279..536 '{     ...type }': ()
+    289..290 'c': bool
+    293..294 'a': i32
+    293..302 'a as bool': bool
+    355..356 'd': i32
+    359..360 'b': Foo
+    359..367 'b as i32': i32
+    423..424 'e': Foo
+    427..430 '1.0': f64
+    427..437 '1.0 as Foo': Foo
+    489..490 'f': {unknown}
+    493..494 'a': i32
+    493..501 'a as Bar': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_string() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn greet(name: string) -> string {
+        let greeting = "Hello, " + name;
+        greeting += "!";
+        greeting
+    }
+
+    fn compare(a: string, b: string) -> bool {
+        a == b || a < b
+    }
+
+    fn length(a: string) -> usize {
+        a.len()
+    }
+
+    fn errors(a: string) {
+        let b = a - "b";    // error: cannot subtract strings
+        a.len(1);           // error: too many arguments
+        a.foo();            // error: no method `foo`
+        let c = 1.len();    // error: no method `len`
+    }
+    "#),
+    @r###"
+    257..264: cannot apply binary operator
+    307..315: this function takes 0 parameters but 1 parameters was supplied
+    360..367: no method named `foo` found
+    418..425: no method named `len` found
+    9..13 'name': string
+    33..107 '{     ...ting }': string
+    43..51 'greeting': string
+    54..63 '"Hello, "': string
+    54..70 '"Hello...+ name': string
+    66..70 'name': string
+    76..84 'greeting': string
+    76..91 'greeting += "!"': ()
+    88..91 '"!"': string
+    97..105 'greeting': string
+    120..121 'a': string
+    131..132 'b': string
+    150..173 '{     ... < b }': bool
+    156..157 'a': string
+    156..162 'a == b': bool
+    156..171 'a == b || a < b': bool
+    161..162 'b': string
+    166..167 'a': string
+    166..171 'a < b': bool
+    170..171 'b': string
+    185..186 'a': string
+    205..220 '{     a.len() }': usize
+    211..212 'a': string
+    211..218 'a.len()': usize
+    232..233 'a': string
+    243..457 '{     ...len` }': ()
+    253..254 'b': {unknown}
+    257..258 'a': string
+    257..264 'a - "b"': {unknown}
+    261..264 '"b"': string
+    307..308 'a': string
+    307..315 'a.len(1)': usize
+    360..361 'a': string
+    360..367 'a.foo()': {unknown}
+    414..415 'c': {unknown}
+    418..419 '1': i32
+    418..425 '1.len()': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_array_methods() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn main(a: [f32]) -> f32 {
+        let b = [];
+        b.push(1);
+        b.insert(0, 2);
+        let c = b.remove(b.len() - 1) + b.pop();
+        a.push(1.0);
+        a.pop()
+    }
+
+    fn errors(a: [i32]) {
+        a.push(true);       // error: mismatched type
+        a.insert(1);        // error: too few arguments
+        a.pop(1);           // error: too many arguments
+        a.clear();          // error: no method `clear`
+    }
+    "#),
+    @r###"
+    188..192: mismatched type
+    231..242: this function takes 2 parameters but 1 parameters was supplied
+    283..291: this function takes 0 parameters but 1 parameters was supplied
+    336..345: no method named `clear` found
+    8..9 'a': [f32]
+    25..153 '{     ...op() }': f32
+    35..36 'b': [i32]
+    39..41 '[]': [i32]
+    47..48 'b': [i32]
+    47..56 'b.push(1)': ()
+    54..55 '1': i32
+    62..63 'b': [i32]
+    62..76 'b.insert(0, 2)': ()
+    71..72 '0': usize
+    74..75 '2': i32
+    86..87 'c': i32
+    90..91 'b': [i32]
+    90..111 'b.remo...) - 1)': i32
+    90..121 'b.remo....pop()': i32
+    99..100 'b': [i32]
+    99..106 'b.len()': usize
+    99..110 'b.len() - 1': usize
+    109..110 '1': usize
+    114..115 'b': [i32]
+    114..121 'b.pop()': i32
+    127..128 'a': [f32]
+    127..138 'a.push(1.0)': ()
+    134..137 '1.0': f32
+    144..145 'a': [f32]
+    144..151 'a.pop()': f32
+    165..166 'a': [i32]
+    175..385 '{     ...ear` }': ()
+    181..182 'a': [i32]
+    181..193 'a.push(true)': ()
+    188..192 'true': bool
+    231..232 'a': [i32]
+    231..242 'a.insert(1)': ()
+    240..241 '1': usize
+    283..284 'a': [i32]
+    283..291 'a.pop(1)': i32
+    336..337 'a': [i32]
+    336..345 'a.clear()': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_intrinsic_functions() {
+    insta::assert_snapshot!(infer(
+        r#"
+    use core::math::{self, min};
+
+    fn foo(a: f32, b: i64, c: u8) {
+        let x = core::math::sqrt(a);
+        let y = math::pow(math::sin(2.0), math::cos(1.0));
+        let z = min(b, 3);
+        let v = math::max(c, 1);
+        let w = math::abs(math::floor(-1.5f64));
+    }
+
+    fn errors(a: bool) {
+        math::sqrt(1);                  // error: only floats
+        math::abs(a);                   // error: only numbers
+        math::min(1.0);                 // error: too few arguments
+        math::max(1.0, true);           // error: mismatched types
+        let f = math::sin;              // error: can only be called
+    }
+    "#
+    ), @r###"
+    286..287: `core::math::sqrt` can only be applied to floating-point numbers
+    343..344: `core::math::abs` can only be applied to integers and floating-point numbers
+    392..406: this function takes 2 parameters but 1 parameters was supplied
+    471..475: mismatched type
+    527..536: `core::math::sin` is an intrinsic function and can only be called directly
+    37..38 'a': f32
+    45..46 'b': i64
+    53..54 'c': u8
+    60..248 '{     ...4)); }': ()
+    70..71 'x': f32
+    74..93 'core::...qrt(a)': f32
+    91..92 'a': f32
+    103..104 'y': f64
+    107..148 'math::...(1.0))': f64
+    117..131 'math::sin(2.0)': f64
+    127..130 '2.0': f64
+    133..147 'math::cos(1.0)': f64
+    143..146 '1.0': f64
+    158..159 'z': i64
+    162..171 'min(b, 3)': i64
+    166..167 'b': i64
+    169..170 '3': i64
+    181..182 'v': u8
+    185..200 'math::max(c, 1)': u8
+    195..196 'c': u8
+    198..199 '1': u8
+    210..211 'w': f64
+    214..245 'math::...5f64))': f64
+    224..244 'math::....5f64)': f64
+    236..243 '-1.5f64': f64
+    237..243 '1.5f64': f64
+    260..261 'a': bool
+    269..581 '{     ...lled }': ()
+    275..288 'math::sqrt(1)': {unknown}
+    286..287 '1': i32
+    333..345 'math::abs(a)': {unknown}
+    343..344 'a': bool
+    392..406 'math::min(1.0)': f64
+    402..405 '1.0': f64
+    456..476 'math::... true)': f64
+    466..469 '1.0': f64
+    471..475 'true': bool
+    523..524 'f': {unknown}
+    527..536 'math::sin': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_panic() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn checked_div(a: i32, b: i32) -> i32 {
+        if b == 0 {
+            panic("division by zero")
+        } else {
+            a / b
+        }
+    }
+
+    fn unreachable() -> bool {
+        panic("unreachable");
+    }
+
+    fn check_positive(a: i32) -> i32 {
+        if a < 0 {
+            panic("negative")
+        }
+        a
+    }
+
+    fn errors() {
+        panic(1);                       // error: mismatched types
+        panic();                        // error: too few arguments
+        let f = panic;                  // error: can only be called
+    }
+    "#
+    ), @r###"
+    297..298: mismatched type
+    354..361: this function takes 1 parameters but 0 parameters was supplied
+    426..431: `panic` is an intrinsic function and can only be called directly
+    15..16 'a': i32
+    23..24 'b': i32
+    38..124 '{     ...   } }': i32
+    44..122 'if b =...     }': i32
+    47..48 'b': i32
+    47..53 'b == 0': bool
+    52..53 '0': i32
+    54..95 '{     ...     }': never
+    64..89 'panic(...zero")': never
+    70..88 '"divis... zero"': string
+    101..122 '{     ...     }': i32
+    111..112 'a': i32
+    111..116 'a / b': i32
+    115..116 'b': i32
+    151..180 '{     ...e"); }': never
+    157..177 'panic(...able")': never
+    163..176 '"unreachable"': string
+    200..201 'a': i32
+    215..271 '{     ...   a }': i32
+    221..263 'if a <...     }': ()
+    224..225 'a': i32
+    224..229 'a < 0': bool
+    228..229 '0': i32
+    230..263 '{     ...     }': never
+    240..257 'panic(...tive")': never
+    246..256 '"negative"': string
+    268..269 'a': i32
+    285..480 '{     ...lled }': never
+    291..299 'panic(1)': never
+    297..298 '1': i32
+    354..361 'panic()': never
+    422..423 'f': {unknown}
+    426..431 'panic': {unknown}
+    "###);
+}
+
 #[test]
 fn invalid_binary_ops() {
     insta::assert_snapshot!(infer(
@@ -1029,6 +1597,7 @@ fn invalid_binary_ops() {
     23..28 'false': bool
     38..39 'n': i32
     42..43 '1': i32
+    53..54 '_': i32
     57..58 'b': bool
     57..62 'b + n': i32
     61..62 'n': i32
@@ -1194,6 +1763,336 @@ fn struct_field_index() {
     "###);
 }
 
+#[test]
+fn enum_decl() {
+    insta::assert_snapshot!(infer(
+        r#"
+    enum Foo {
+        A,
+        B(i32, f64),
+        C { a: i32, b: bool },
+    }
+
+    fn main() {
+        let a: Foo = Foo::A;
+        let b = Foo::B(1, 2.0);
+        let c = Foo::C { a: 1, b: true };
+    }
+    "#),
+    @r###"
+    75..169 '{     ...e }; }': ()
+    85..86 'a': Foo
+    94..100 'Foo::A': Foo
+    110..111 'b': Foo
+    114..120 'Foo::B': ctor Foo::B(i32, f64) -> Foo
+    114..128 'Foo::B(1, 2.0)': Foo
+    121..122 '1': i32
+    124..127 '2.0': f64
+    138..139 'c': Foo
+    142..166 'Foo::C...true }': Foo
+    154..155 '1': i32
+    160..164 'true': bool
+    "###);
+}
+
+#[test]
+fn enum_lit_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    enum Foo {
+        A,
+        B(i32),
+        C { a: i32 },
+    }
+
+    fn main() {
+        let a = Foo::A(1);
+        let b = Foo::B;
+        let c = Foo::B(1, 2);
+        let d = Foo::C;
+        let e = Foo::C { };
+        let f = Foo::C { b: 1 };
+        let g = Foo::D;
+    }
+    "#),
+    @r###"
+    75..84: mismatched struct literal kind. expected `unit struct`, found `tuple`
+    98..104: mismatched struct literal kind. expected `tuple`, found `unit struct`
+    118..130: this tuple struct literal has 1 field but 2 fields were supplied
+    144..150: mismatched struct literal kind. expected `record`, found `unit struct`
+    164..174: missing record fields:
+    - a
+
+    197..201: no such field
+    188..203: missing record fields:
+    - a
+
+    217..223: undefined value
+    61..226 '{     ...::D; }': ()
+    71..72 'a': Foo
+    75..81 'Foo::A': Foo
+    75..84 'Foo::A(1)': Foo
+    82..83 '1': i32
+    94..95 'b': ctor Foo::B(i32) -> Foo
+    98..104 'Foo::B': ctor Foo::B(i32) -> Foo
+    114..115 'c': Foo
+    118..124 'Foo::B': ctor Foo::B(i32) -> Foo
+    118..130 'Foo::B(1, 2)': Foo
+    125..126 '1': i32
+    140..141 'd': Foo
+    144..150 'Foo::C': Foo
+    160..161 'e': Foo
+    164..174 'Foo::C { }': Foo
+    184..185 'f': Foo
+    188..203 'Foo::C { b: 1 }': Foo
+    200..201 '1': i32
+    213..214 'g': {unknown}
+    217..223 'Foo::D': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_match() {
+    insta::assert_snapshot!(infer(
+        r#"
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    struct Point { x: i32, y: i32 }
+
+    fn area(shape: Shape) -> f64 {
+        match shape {
+            Shape::Empty => 0.0,
+            Shape::Circle(r) => 3.14 * r * r,
+            Shape::Rect { width, height: h } => width * h,
+        }
+    }
+
+    fn classify(a: i32, p: Point) -> bool {
+        let b = match a {
+            -5..=-1 => false,
+            0 => return true,
+            _ => true,
+        };
+        match p {
+            Point { x: 0, .. } => b,
+            Point { x, y } => x == y,
+        }
+    }
+    "#),
+    @r###"
+    123..128 'shape': Shape
+    144..297 '{     ...   } }': f64
+    150..295 'match ...     }': f64
+    156..161 'shape': Shape
+    172..184 'Shape::Empty': Shape
+    188..191 '0.0': f64
+    201..217 'Shape:...cle(r)': Shape
+    215..216 'r': f64
+    221..225 '3.14': f64
+    221..229 '3.14 * r': f64
+    221..233 '3.14 * r * r': f64
+    228..229 'r': f64
+    232..233 'r': f64
+    243..275 'Shape:...t: h }': Shape
+    257..262 'width': f64
+    272..273 'h': f64
+    279..284 'width': f64
+    279..288 'width * h': f64
+    287..288 'h': f64
+    311..312 'a': i32
+    319..320 'p': Point
+    337..527 '{     ...   } }': bool
+    347..348 'b': bool
+    351..437 'match ...     }': bool
+    357..358 'a': i32
+    369..371 '-5': i32
+    369..376 '-5..=-1': i32
+    370..371 '5': i32
+    374..376 '-1': i32
+    375..376 '1': i32
+    380..385 'false': bool
+    395..396 '0': i32
+    395..396 '0': i32
+    400..411 'return true': never
+    407..411 'true': bool
+    421..422 '_': i32
+    426..430 'true': bool
+    443..525 'match ...     }': bool
+    449..450 'p': Point
+    461..479 'Point ..., .. }': Point
+    472..473 '0': i32
+    472..473 '0': i32
+    483..484 'b': bool
+    494..508 'Point { x, y }': Point
+    502..503 'x': i32
+    505..506 'y': i32
+    512..513 'x': i32
+    512..518 'x == y': bool
+    517..518 'y': i32
+    "###);
+}
+
+#[test]
+fn match_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    enum Foo {
+        A,
+        B(i32),
+        C { a: i32 },
+    }
+
+    struct Bar { a: i32 }
+
+    fn main(foo: Foo, bar: Bar) {
+        let a = match foo {
+            Foo::A(x) => 1,
+            Foo::B => 2,
+            Foo::B(x, y) => 3,
+            Foo::C { a, b } => 4,
+            Foo::D => 5,
+        };
+        match bar {
+            Bar { a: true } => {},
+            Foo::A => {},
+            Bar { } => {},
+        };
+        let b = match 1 {
+            1 => 1,
+            _ => false,
+        };
+    }
+    "#),
+    @r###"
+    136..145: mismatched struct literal kind. expected `unit struct`, found `tuple`
+    160..166: mismatched struct literal kind. expected `tuple`, found `unit struct`
+    181..193: this tuple struct literal has 1 field but 2 fields were supplied
+    220..221: no such field
+    238..244: undefined value
+    291..295: mismatched type
+    313..319: mismatched type
+    335..342: missing record fields:
+    - a
+
+    408..413: mismatched type
+    82..85 'foo': Foo
+    92..95 'bar': Bar
+    102..423 '{     ...  }; }': ()
+    112..113 'a': i32
+    116..256 'match ...     }': i32
+    122..125 'foo': Foo
+    136..145 'Foo::A(x)': Foo
+    143..144 'x': {unknown}
+    149..150 '1': i32
+    160..166 'Foo::B': Foo
+    170..171 '2': i32
+    181..193 'Foo::B(x, y)': Foo
+    188..189 'x': i32
+    191..192 'y': {unknown}
+    197..198 '3': i32
+    208..223 'Foo::C { a, b }': Foo
+    217..218 'a': i32
+    220..221 'b': {unknown}
+    227..228 '4': i32
+    238..244 'Foo::D': {unknown}
+    248..249 '5': i32
+    262..355 'match ...     }': ()
+    268..271 'bar': Bar
+    282..297 'Bar { a: true }': Bar
+    291..295 'true': bool
+    291..295 'true': bool
+    301..303 '{}': ()
+    313..319 'Foo::A': Foo
+    323..325 '{}': ()
+    335..342 'Bar { }': Bar
+    346..348 '{}': ()
+    365..366 'b': i32
+    369..420 'match ...     }': i32
+    375..376 '1': i32
+    387..388 '1': i32
+    387..388 '1': i32
+    392..393 '1': i32
+    403..404 '_': i32
+    408..413 'false': bool
+    "###);
+}
+
+#[test]
+fn match_literal_pat_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn main(a: u8, b: i8) {
+        match a {
+            300 => {},
+            5..=1 => {},
+            1..=1 => {},
+            _ => {},
+        };
+        match b {
+            -129 => {},
+            -128 => {},
+            1..=-1 => {},
+            -1..=1 => {},
+            _ => {},
+        };
+    }
+    "#),
+    @r###"
+    46..49: literal out of range for `u8`
+    65..70: lower range bound must be less than or equal to upper
+    146..149: literal out of range for `i8`
+    185..191: lower range bound must be less than or equal to upper
+    8..9 'a': u8
+    15..16 'b': i8
+    22..246 '{     ...  }; }': ()
+    28..121 'match ...     }': ()
+    34..35 'a': u8
+    46..49 '300': u8
+    46..49 '300': u8
+    53..55 '{}': ()
+    65..66 '5': u8
+    65..66 '5': u8
+    65..70 '5..=1': u8
+    69..70 '1': u8
+    69..70 '1': u8
+    74..76 '{}': ()
+    86..87 '1': u8
+    86..87 '1': u8
+    86..91 '1..=1': u8
+    90..91 '1': u8
+    90..91 '1': u8
+    95..97 '{}': ()
+    107..108 '_': u8
+    112..114 '{}': ()
+    127..243 'match ...     }': ()
+    133..134 'b': i8
+    145..149 '-129': i8
+    146..149 '129': i8
+    153..155 '{}': ()
+    165..169 '-128': i8
+    166..169 '128': i8
+    173..175 '{}': ()
+    185..186 '1': i8
+    185..186 '1': i8
+    185..191 '1..=-1': i8
+    189..191 '-1': i8
+    190..191 '1': i8
+    195..197 '{}': ()
+    207..209 '-1': i8
+    207..213 '-1..=1': i8
+    208..209 '1': i8
+    212..213 '1': i8
+    212..213 '1': i8
+    217..219 '{}': ()
+    229..230 '_': i8
+    234..236 '{}': ()
+    "###);
+}
+
 #[test]
 fn primitives() {
     insta::assert_snapshot!(infer(
@@ -1310,6 +2209,1152 @@ fn recursive_alias() {
     "###);
 }
 
+#[test]
+fn infer_impl() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Foo {
+        a: i32,
+    }
+
+    impl Foo {
+        fn new(a: i32) -> Foo {
+            Foo { a }
+        }
+
+        pub fn add(self: Foo, b: i32) -> i32 {
+            self.a + b
+        }
+    }
+
+    fn main() -> i32 {
+        let foo = Foo { a: 3 };
+        foo.add(4)
+    }
+    "#),
+    @r###"
+    113..116: can't leak private type
+    50..51 'a': i32
+    65..90 '{     ...     }': Foo
+    75..84 'Foo { a }': Foo
+    81..82 'a': i32
+    107..111 'self': Foo
+    118..119 'b': i32
+    133..159 '{     ...     }': i32
+    143..147 'self': Foo
+    143..149 'self.a': i32
+    143..153 'self.a + b': i32
+    152..153 'b': i32
+    180..226 '{     ...d(4) }': i32
+    190..193 'foo': Foo
+    196..208 'Foo { a: 3 }': Foo
+    205..206 '3': i32
+    214..217 'foo': Foo
+    214..224 'foo.add(4)': i32
+    222..223 '4': i32
+    "###);
+}
+
+#[test]
+fn impl_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Foo;
+    struct Bar;
+
+    impl Foo {
+        fn bar(self: Bar) {}
+        fn baz(self: Foo, a: i32) {}
+        fn new() -> Foo { Foo }
+        fn baz(self: Foo) {}    // error: duplicate definition
+    }
+
+    impl i32 {}                 // error: not a struct
+
+    fn main() {
+        let foo = Foo;
+        foo.bar();              // error: mismatched `self` type
+        foo.baz();              // error: too few arguments
+        foo.baz(true);          // error: mismatched argument type
+        foo.new();              // error: not a method
+        foo.qux();              // error: no method `qux`
+    }
+    "#),
+    @r###"
+    121..146: the name `baz` is defined multiple times
+    271..274: mismatched type
+    332..341: this function takes 1 parameters but 0 parameters was supplied
+    396..400: mismatched type
+    451..460: no method named `new` found
+    502..511: no method named `qux` found
+    184..195: inherent `impl` blocks can only be defined for structs declared in the same package
+    47..51 'self': Bar
+    58..60 '{}': ()
+    72..76 'self': Foo
+    83..84 'a': i32
+    91..93 '{}': ()
+    114..121 '{ Foo }': Foo
+    116..119 'Foo': Foo
+    133..137 'self': Foo
+    144..146 '{}': ()
+    246..553 '{     ...qux` }': ()
+    256..259 'foo': Foo
+    262..265 'Foo': Foo
+    271..274 'foo': Foo
+    271..280 'foo.bar()': ()
+    332..335 'foo': Foo
+    332..341 'foo.baz()': ()
+    388..391 'foo': Foo
+    388..401 'foo.baz(true)': ()
+    396..400 'true': bool
+    451..454 'foo': Foo
+    451..460 'foo.new()': {unknown}
+    502..505 'foo': Foo
+    502..511 'foo.qux()': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_generics() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Pair<A, B> {
+        a: A,
+        b: B,
+    }
+
+    struct Wrapper<T>(T);
+
+    fn first<A, B>(pair: Pair<A, B>) -> A {
+        pair.a
+    }
+
+    fn id<T>(value: T) -> T {
+        let copy: T = value;
+        copy
+    }
+
+    fn main() {
+        let pair = Pair { a: 3, b: 4.0 };
+        let a = first(pair);
+        let b: f32 = id(pair.b);
+        let wrapper = Wrapper(true);
+        let c = id(wrapper).0;
+        let Pair { a: d, b: e } = Pair { a: wrapper, b: pair };
+        let f: Pair<i8, bool> = Pair { a: 1, b: false };
+    }
+    "#),
+    @r###"
+    81..85 'pair': Pair<A, B>
+    104..118 '{     pair.a }': A
+    110..114 'pair': Pair<A, B>
+    110..116 'pair.a': A
+    129..134 'value': T
+    144..181 '{     ...copy }': T
+    154..158 'copy': T
+    164..169 'value': T
+    175..179 'copy': T
+    193..461 '{     ...e }; }': ()
+    203..207 'pair': Pair<i32, f32>
+    210..231 'Pair {... 4.0 }': Pair<i32, f32>
+    220..221 '3': i32
+    226..229 '4.0': f32
+    241..242 'a': i32
+    245..250 'first': function first(Pair<i32, f32>) -> i32
+    245..256 'first(pair)': i32
+    251..255 'pair': Pair<i32, f32>
+    266..267 'b': f32
+    275..277 'id': function id(f32) -> f32
+    275..285 'id(pair.b)': f32
+    278..282 'pair': Pair<i32, f32>
+    278..284 'pair.b': f32
+    295..302 'wrapper': Wrapper<bool>
+    305..312 'Wrapper': ctor Wrapper(bool) -> Wrapper<bool>
+    305..318 'Wrapper(true)': Wrapper<bool>
+    313..317 'true': bool
+    328..329 'c': bool
+    332..334 'id': function id(Wrapper<bool>) -> Wrapper<bool>
+    332..343 'id(wrapper)': Wrapper<bool>
+    332..345 'id(wrapper).0': bool
+    335..342 'wrapper': Wrapper<bool>
+    355..374 'Pair {...b: e }': Pair<Wrapper<bool>, Pair<i32, f32>>
+    365..366 'd': Wrapper<bool>
+    371..372 'e': Pair<i32, f32>
+    377..405 'Pair {...pair }': Pair<Wrapper<bool>, Pair<i32, f32>>
+    387..394 'wrapper': Wrapper<bool>
+    399..403 'pair': Pair<i32, f32>
+    415..416 'f': Pair<i8, bool>
+    435..458 'Pair {...alse }': Pair<i8, bool>
+    445..446 '1': i8
+    451..456 'false': bool
+    "###);
+}
+
+#[test]
+fn generics_operators() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn max<T>(a: T, b: T) -> T {
+        if a > b { a } else { b }
+    }
+
+    fn sum<T>(a: T, b: T) -> T {
+        let c = a;
+        c += b;
+        c - -a
+    }
+
+    fn main() {
+        let a = max(1, 2);
+        let b = sum(1.0, 2.0);
+    }
+    "#),
+    @r###"
+    10..11 'a': T
+    16..17 'b': T
+    27..60 '{     ... b } }': T
+    33..58 'if a >... { b }': T
+    36..37 'a': T
+    36..41 'a > b': bool
+    40..41 'b': T
+    42..47 '{ a }': T
+    44..45 'a': T
+    53..58 '{ b }': T
+    55..56 'b': T
+    72..73 'a': T
+    78..79 'b': T
+    89..130 '{     ...- -a }': T
+    99..100 'c': T
+    103..104 'a': T
+    110..111 'c': T
+    110..116 'c += b': ()
+    115..116 'b': T
+    122..123 'c': T
+    122..128 'c - -a': T
+    126..128 '-a': T
+    127..128 'a': T
+    142..195 '{     ....0); }': ()
+    152..153 'a': i32
+    156..159 'max': function max(i32, i32) -> i32
+    156..165 'max(1, 2)': i32
+    160..161 '1': i32
+    163..164 '2': i32
+    175..176 'b': f64
+    179..182 'sum': function sum(f64, f64) -> f64
+    179..192 'sum(1.0, 2.0)': f64
+    183..186 '1.0': f64
+    188..191 '2.0': f64
+    "###);
+}
+
+#[test]
+fn generics_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Pair<A, B> {
+        a: A,
+        b: B,
+    }
+
+    fn add<T>(a: T, b: T) -> T {
+        a + b
+    }
+
+    fn add_one<T>(a: T) -> T {
+        a + 1                       // error: mismatched types
+    }
+
+    fn foo(a: Pair<i32>) {}        // error: wrong number of type arguments
+    fn bar(a: i32<f32>) {}          // error: wrong number of type arguments
+    fn baz<T>(a: T<i32>) {}         // error: wrong number of type arguments
+
+    fn main() {
+        let a: Pair = Pair { a: 1, b: 2 };  // error: missing type arguments
+        let b = add(1, true);               // error: mismatched types
+        let c = Pair { a: 1, b: 2 }.c;      // error: no such field
+    }
+    "#),
+    @r###"
+    120..121: mismatched type
+    184..193: expected 2 type arguments, found 1
+    256..264: expected 0 type arguments, found 1
+    332..338: expected 0 type arguments, found 1
+    416..420: expected 2 type arguments, found 0
+    497..501: mismatched type
+    557..578: attempted to access a non-existent field in a struct.
+    53..54 'a': T
+    59..60 'b': T
+    70..83 '{     a + b }': T
+    76..77 'a': T
+    76..81 'a + b': T
+    80..81 'b': T
+    99..100 'a': T
+    110..172 '{     ...ypes }': T
+    116..117 'a': T
+    116..121 'a + 1': T
+    120..121 '1': i32
+    181..182 'a': Pair<{unknown}, {unknown}>
+    195..197 '{}': ()
+    253..254 'a': i32
+    266..268 '{}': ()
+    329..330 'a': T
+    340..342 '{}': ()
+    403..610 '{     ...ield }': ()
+    413..414 'a': Pair<{unknown}, {unknown}>
+    423..442 'Pair {...b: 2 }': Pair<i32, i32>
+    433..434 '1': i32
+    439..440 '2': i32
+    486..487 'b': i32
+    490..493 'add': function add(i32, i32) -> i32
+    490..502 'add(1, true)': i32
+    494..495 '1': i32
+    497..501 'true': bool
+    553..554 'c': {unknown}
+    557..576 'Pair {...b: 2 }': Pair<i32, i32>
+    557..578 'Pair {... 2 }.c': {unknown}
+    567..568 '1': i32
+    573..574 '2': i32
+    "###);
+}
+
+#[test]
+fn infer_closures() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn apply(f: fn(i32) -> i32, a: i32) -> i32 {
+        f(a)
+    }
+
+    fn double(a: i32) -> i32 { a * 2 }
+    fn triple(a: i32) -> i32 { a * 3 }
+
+    fn main() {
+        let offset = 3;
+        let add = |x| x + offset;
+        let a = apply(add, 4);
+        let b = apply(double, a);
+        let c = apply(|x| x - 1, b);
+        let typed = |a: f32, b: f32| -> bool { a < b };
+        let unit = || {};
+        let f: fn(i32) -> i32 = double;
+        let g = if a > b { double } else { triple };
+        let nested = |x: i32| { let y = 2; |z: i32| x + y + z + offset };
+    }
+    "#),
+    @r###"
+    9..10 'f': fn(i32) -> i32
+    28..29 'a': i32
+    43..55 '{     f(a) }': i32
+    49..50 'f': fn(i32) -> i32
+    49..53 'f(a)': i32
+    51..52 'a': i32
+    67..68 'a': i32
+    82..91 '{ a * 2 }': i32
+    84..85 'a': i32
+    84..89 'a * 2': i32
+    88..89 '2': i32
+    102..103 'a': i32
+    117..126 '{ a * 3 }': i32
+    119..120 'a': i32
+    119..124 'a * 3': i32
+    123..124 '3': i32
+    138..510 '{     ...t }; }': ()
+    148..154 'offset': i32
+    157..158 '3': i32
+    168..171 'add': fn(i32) -> i32
+    174..188 '|x| x + offset': fn(i32) -> i32
+    175..176 'x': i32
+    178..179 'x': i32
+    178..188 'x + offset': i32
+    182..188 'offset': i32
+    198..199 'a': i32
+    202..207 'apply': function apply(fn(i32) -> i32, i32) -> i32
+    202..215 'apply(add, 4)': i32
+    208..211 'add': fn(i32) -> i32
+    213..214 '4': i32
+    225..226 'b': i32
+    229..234 'apply': function apply(fn(i32) -> i32, i32) -> i32
+    229..245 'apply(...le, a)': i32
+    235..241 'double': function double(i32) -> i32
+    243..244 'a': i32
+    255..256 'c': i32
+    259..264 'apply': function apply(fn(i32) -> i32, i32) -> i32
+    259..278 'apply(... 1, b)': i32
+    265..274 '|x| x - 1': fn(i32) -> i32
+    266..267 'x': i32
+    269..270 'x': i32
+    269..274 'x - 1': i32
+    273..274 '1': i32
+    276..277 'b': i32
+    288..293 'typed': fn(f32, f32) -> bool
+    296..330 '|a: f3... < b }': fn(f32, f32) -> bool
+    297..298 'a': f32
+    305..306 'b': f32
+    321..330 '{ a < b }': bool
+    323..324 'a': f32
+    323..328 'a < b': bool
+    327..328 'b': f32
+    340..344 'unit': fn()
+    347..352 '|| {}': fn()
+    350..352 '{}': ()
+    362..363 'f': fn(i32) -> i32
+    382..388 'double': function double(i32) -> i32
+    398..399 'g': fn(i32) -> i32
+    402..437 'if a >...iple }': fn(i32) -> i32
+    405..406 'a': i32
+    405..410 'a > b': bool
+    409..410 'b': i32
+    411..421 '{ double }': function double(i32) -> i32
+    413..419 'double': function double(i32) -> i32
+    427..437 '{ triple }': function triple(i32) -> i32
+    429..435 'triple': function triple(i32) -> i32
+    447..453 'nested': fn(i32) -> fn(i32) -> i32
+    456..507 '|x: i3...fset }': fn(i32) -> fn(i32) -> i32
+    457..458 'x': i32
+    465..507 '{ let ...fset }': fn(i32) -> i32
+    471..472 'y': i32
+    475..476 '2': i32
+    478..505 '|z: i3...offset': fn(i32) -> i32
+    479..480 'z': i32
+    487..488 'x': i32
+    487..492 'x + y': i32
+    487..496 'x + y + z': i32
+    487..505 'x + y ...offset': i32
+    491..492 'y': i32
+    495..496 'z': i32
+    499..505 'offset': i32
+    "###);
+}
+
+#[test]
+fn closure_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Foo(i32);
+
+    fn main() {
+        let a = 3;
+        let f = |x: i32| { a = x; };        // error: cannot assign to captured binding
+        let g: fn(i32) -> bool = |x| x;     // error: mismatched type
+        let h: fn(i32) -> Foo = Foo;        // error: constructors are not function pointers
+        let i = |x| x;                      // error: type annotations needed
+        let j = || { break; };              // error: break outside loop
+        let k = || { return; };
+        f(1, 2);                            // error: parameter count mismatch
+    }
+    "#),
+    @r###"
+    68..69: cannot assign to `a` because it is captured by a closure
+    162..163: mismatched type
+    223..226: mismatched struct literal kind. expected `tuple`, found `unit struct`
+    223..226: mismatched type
+    375..380: `break` outside of a loop
+    459..466: this function takes 1 parameters but 2 parameters was supplied
+    297..298: type annotations needed
+    28..531 '{     ...atch }': ()
+    38..39 'a': i32
+    42..43 '3': i32
+    53..54 'f': fn(i32)
+    57..76 '|x: i3...= x; }': fn(i32)
+    58..59 'x': i32
+    66..76 '{ a = x; }': ()
+    68..69 'a': i32
+    68..73 'a = x': ()
+    72..73 'x': i32
+    137..138 'g': fn(i32) -> bool
+    158..163 '|x| x': fn(i32) -> bool
+    159..160 'x': i32
+    162..163 'x': i32
+    203..204 'h': ctor Foo(i32) -> Foo
+    223..226 'Foo': ctor Foo(i32) -> Foo
+    292..293 'i': fn({unknown}) -> {unknown}
+    296..301 '|x| x': fn({unknown}) -> {unknown}
+    297..298 'x': {unknown}
+    300..301 'x': {unknown}
+    366..367 'j': fn()
+    370..383 '|| { break; }': fn()
+    373..383 '{ break; }': never
+    375..380 'break': never
+    435..436 'k': fn()
+    439..453 '|| { return; }': fn()
+    442..453 '{ return; }': never
+    444..450 'return': never
+    459..460 'f': fn(i32)
+    459..466 'f(1, 2)': ()
+    461..462 '1': i32
+    "###);
+}
+
+#[test]
+fn infer_traits() {
+    insta::assert_snapshot!(infer(
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+        fn health(self: Self) -> f32;
+    }
+
+    struct(gc) Enemy {
+        health: f32,
+    }
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: f32) {
+            self.health -= amount;
+        }
+
+        fn health(self: Enemy) -> f32 {
+            self.health
+        }
+    }
+
+    fn hit(target: dyn Damageable) -> f32 {
+        target.damage(5.0);
+        target.health()
+    }
+
+    fn main() -> f32 {
+        let enemy = Enemy { health: 10.0 };
+        enemy.damage(1.0);
+        let target: dyn Damageable = enemy;
+        target = Enemy { health: 3.0 };
+        hit(enemy)
+    }
+    "#),
+    @r###"
+    177..181 'self': Enemy
+    190..196 'amount': f32
+    203..241 '{     ...     }': ()
+    213..217 'self': Enemy
+    213..224 'self.health': f32
+    213..234 'self.h...amount': ()
+    228..234 'amount': f32
+    257..261 'self': Enemy
+    277..304 '{     ...     }': f32
+    287..291 'self': Enemy
+    287..298 'self.health': f32
+    315..321 'target': dyn Damageable
+    346..393 '{     ...th() }': f32
+    352..358 'target': dyn Damageable
+    352..370 'target...e(5.0)': ()
+    366..369 '5.0': f32
+    376..382 'target': dyn Damageable
+    376..391 'target.health()': f32
+    412..569 '{     ...emy) }': f32
+    422..427 'enemy': Enemy
+    430..452 'Enemy ...10.0 }': Enemy
+    446..450 '10.0': f32
+    458..463 'enemy': Enemy
+    458..475 'enemy....e(1.0)': ()
+    471..474 '1.0': f32
+    485..491 'target': dyn Damageable
+    510..515 'enemy': Enemy
+    521..527 'target': dyn Damageable
+    521..551 'target... 3.0 }': ()
+    530..551 'Enemy ... 3.0 }': Enemy
+    546..549 '3.0': f32
+    557..560 'hit': function hit(dyn Damageable) -> f32
+    557..567 'hit(enemy)': f32
+    561..566 'enemy': Enemy
+    "###);
+}
+
+#[test]
+fn infer_operator_overloading() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    impl Vec2 {
+        fn add(self: Vec2, rhs: Vec2) -> Vec2 {
+            Vec2 { x: self.x + rhs.x, y: self.y + rhs.y }
+        }
+
+        fn eq(self: Vec2, rhs: Vec2) -> bool {
+            self.x == rhs.x && self.y == rhs.y
+        }
+
+        fn lt(self: Vec2, rhs: Vec2) -> f32 {
+            self.x - rhs.x
+        }
+    }
+
+    trait Mul {
+        fn mul(self: Self, rhs: f32) -> Vec2;
+    }
+
+    impl Mul for Vec2 {
+        fn mul(self: Vec2, rhs: f32) -> Vec2 {
+            Vec2 { x: self.x * rhs, y: self.y * rhs }
+        }
+    }
+
+    fn main() {
+        let a = Vec2 { x: 1.0, y: 2.0 };
+        let b = a + a * 2.0;
+        let c = a == b;
+        let d = a != b;
+        a += b;
+        a *= 3.0;
+        let e = a - b;     // no overload
+        let f = a + 1.0;   // mismatched right hand side
+        let g = a < b;     // comparison must return a bool
+    }
+    "#),
+    @r###"
+    663..668: cannot apply binary operator
+    705..708: mismatched type
+    754..759: mismatched type
+    71..75 'self': Vec2
+    83..86 'rhs': Vec2
+    102..163 '{     ...     }': Vec2
+    112..157 'Vec2 {...hs.y }': Vec2
+    122..126 'self': Vec2
+    122..128 'self.x': f32
+    122..136 'self.x + rhs.x': f32
+    131..134 'rhs': Vec2
+    131..136 'rhs.x': f32
+    141..145 'self': Vec2
+    141..147 'self.y': f32
+    141..155 'self.y + rhs.y': f32
+    150..153 'rhs': Vec2
+    150..155 'rhs.y': f32
+    175..179 'self': Vec2
+    187..190 'rhs': Vec2
+    206..256 '{     ...     }': bool
+    216..220 'self': Vec2
+    216..222 'self.x': f32
+    216..231 'self.x == rhs.x': bool
+    216..250 'self.x... rhs.y': bool
+    226..229 'rhs': Vec2
+    226..231 'rhs.x': f32
+    235..239 'self': Vec2
+    235..241 'self.y': f32
+    235..250 'self.y == rhs.y': bool
+    245..248 'rhs': Vec2
+    245..250 'rhs.y': f32
+    268..272 'self': Vec2
+    280..283 'rhs': Vec2
+    298..328 '{     ...     }': f32
+    308..312 'self': Vec2
+    308..314 'self.x': f32
+    308..322 'self.x - rhs.x': f32
+    317..320 'rhs': Vec2
+    317..322 'rhs.x': f32
+    420..424 'self': Vec2
+    432..435 'rhs': f32
+    450..507 '{     ...     }': Vec2
+    460..501 'Vec2 {... rhs }': Vec2
+    470..474 'self': Vec2
+    470..476 'self.x': f32
+    470..482 'self.x * rhs': f32
+    479..482 'rhs': f32
+    487..491 'self': Vec2
+    487..493 'self.y': f32
+    487..499 'self.y * rhs': f32
+    496..499 'rhs': f32
+    521..799 '{     ...bool }': ()
+    531..532 'a': Vec2
+    535..558 'Vec2 {... 2.0 }': Vec2
+    545..548 '1.0': f32
+    553..556 '2.0': f32
+    568..569 'b': Vec2
+    572..573 'a': Vec2
+    572..583 'a + a * 2.0': Vec2
+    576..577 'a': Vec2
+    576..583 'a * 2.0': Vec2
+    580..583 '2.0': f32
+    593..594 'c': bool
+    597..598 'a': Vec2
+    597..603 'a == b': bool
+    602..603 'b': Vec2
+    613..614 'd': bool
+    617..618 'a': Vec2
+    617..623 'a != b': bool
+    622..623 'b': Vec2
+    629..630 'a': Vec2
+    629..635 'a += b': ()
+    634..635 'b': Vec2
+    641..642 'a': Vec2
+    641..649 'a *= 3.0': ()
+    646..649 '3.0': f32
+    659..660 'e': {unknown}
+    663..664 'a': Vec2
+    663..668 'a - b': {unknown}
+    667..668 'b': Vec2
+    697..698 'f': Vec2
+    701..702 'a': Vec2
+    701..708 'a + 1.0': Vec2
+    705..708 '1.0': f64
+    750..751 'g': bool
+    754..755 'a': Vec2
+    754..759 'a < b': bool
+    758..759 'b': Vec2
+    "###);
+}
+
+#[test]
+fn trait_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+
+    trait Invalid {
+        fn no_self(a: i32);             // error: no self parameter
+        fn returns_self(self: Self) -> Self; // error: mentions `Self`
+        fn body(self: Self) {}          // error: function with a body
+    }
+
+    struct(gc) Enemy;
+    struct(value) Wall;
+    struct(gc) Tree;
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: i32) {} // error: signature mismatch
+        fn heal(self: Enemy) {}             // error: not a member
+    }
+
+    impl Damageable for Enemy {}            // error: conflicting implementation
+
+    impl Damageable for Tree {}             // error: missing `damage`
+
+    impl Unknown for Enemy {}               // error: unresolved trait
+
+    impl Damageable for Wall {
+        fn damage(self: Wall, amount: f32) {}
+    }
+
+    fn main() {
+        let a: dyn Damageable = Enemy;
+        let b: dyn Damageable = Wall;   // error: value structs cannot be trait objects
+        let c: Damageable = Enemy;      // error: expected a type
+        a.heal();                       // error: no method `heal`
+    }
+    "#),
+    @r###"
+    77..101: trait functions must take `self: Self` as their first parameter and cannot use `Self` or type parameters elsewhere
+    101..182: trait functions must take `self: Self` as their first parameter and cannot use `Self` or type parameters elsewhere
+    182..235: trait functions cannot have bodies
+    862..866: mismatched type
+    929..939: expected a type, found a trait; use `dyn` to refer to a trait object
+    984..992: no method named `heal` found
+    362..405: the signature of `damage` does not match its declaration in trait `Damageable`
+    405..462: function `heal` is not a member of trait `Damageable`
+    501..529: conflicting implementations of trait `Damageable` for type `Enemy`
+    579..606: missing functions of trait `Damageable` in implementation:
+    - damage
+
+    652..659: undefined trait
+    377..381 'self': Enemy
+    390..396 'amount': i32
+    403..405 '{}': ()
+    447..451 'self': Enemy
+    460..462 '{}': ()
+    756..760 'self': Wall
+    768..774 'amount': f32
+    781..783 '{}': ()
+    797..1044 '{     ...eal` }': ()
+    807..808 'a': dyn Damageable
+    827..832 'Enemy': Enemy
+    842..843 'b': Wall
+    862..866 'Wall': Wall
+    926..927 'c': Enemy
+    942..947 'Enemy': Enemy
+    984..985 'a': dyn Damageable
+    984..992 'a.heal()': {unknown}
+    "###);
+}
+
+#[test]
+fn infer_tuples() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn swap(pair: (i32, f32)) -> (f32, i32) {
+        let (a, b) = pair;
+        (b, a)
+    }
+
+    fn main() -> f32 {
+        let unit: () = ();
+        let single = (true,);
+        let nested = (1, (2.0, false));
+        let (x, (y, _)) = nested;
+        let pair = swap((x, 3.0));
+        pair.1 = 5;
+        pair.0 + y
+    }
+    "#),
+    @r###"
+    8..12 'pair': (i32, f32)
+    40..77 '{     ..., a) }': (f32, i32)
+    50..56 '(a, b)': (i32, f32)
+    51..52 'a': i32
+    54..55 'b': f32
+    59..63 'pair': (i32, f32)
+    69..75 '(b, a)': (f32, i32)
+    70..71 'b': f32
+    73..74 'a': i32
+    96..276 '{     ... + y }': f32
+    106..110 'unit': ()
+    117..119 '()': ()
+    129..135 'single': (bool,)
+    138..145 '(true,)': (bool,)
+    139..143 'true': bool
+    155..161 'nested': (i32, (f32, bool))
+    164..181 '(1, (2...alse))': (i32, (f32, bool))
+    165..166 '1': i32
+    168..180 '(2.0, false)': (f32, bool)
+    169..172 '2.0': f32
+    174..179 'false': bool
+    191..202 '(x, (y, _))': (i32, (f32, bool))
+    192..193 'x': i32
+    195..201 '(y, _)': (f32, bool)
+    196..197 'y': f32
+    199..200 '_': bool
+    205..211 'nested': (i32, (f32, bool))
+    221..225 'pair': (f32, i32)
+    228..232 'swap': function swap((i32, f32)) -> (f32, i32)
+    228..242 'swap((x, 3.0))': (f32, i32)
+    233..241 '(x, 3.0)': (i32, f32)
+    234..235 'x': i32
+    237..240 '3.0': f32
+    248..252 'pair': (f32, i32)
+    248..254 'pair.1': i32
+    248..258 'pair.1 = 5': ()
+    257..258 '5': i32
+    264..268 'pair': (f32, i32)
+    264..270 'pair.0': f32
+    264..274 'pair.0 + y': f32
+    273..274 'y': f32
+    "###);
+}
+
+#[test]
+fn tuple_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn main() {
+        let pair = (1, 2.0);
+        let (a, b, c) = pair;           // error: expected a tuple with 2 elements
+        let d = pair.2;                 // error: no field `2`
+        let e: (i32, bool) = pair;      // error: mismatched type
+        match pair {                    // error: missing match arms
+            (0, _) => {},
+        }
+    }
+    "#),
+    @r###"
+    45..54: mismatched types: expected a tuple with 2 elements, found one with 3 elements
+    128..134: attempted to access a non-existent field in a struct.
+    200..204: mismatched type
+    247..251: non-exhaustive patterns: `(-2147483648..=-1, _)` and `(1..=2147483647, _)` not covered
+    10..331 '{     ...   } }': ()
+    20..24 'pair': (i32, f64)
+    27..35 '(1, 2.0)': (i32, f64)
+    28..29 '1': i32
+    31..34 '2.0': f64
+    45..54 '(a, b, c)': (i32, f64, {unknown})
+    46..47 'a': i32
+    49..50 'b': f64
+    52..53 'c': {unknown}
+    57..61 'pair': (i32, f64)
+    124..125 'd': {unknown}
+    128..132 'pair': (i32, f64)
+    128..134 'pair.2': {unknown}
+    183..184 'e': (i32, f64)
+    200..204 'pair': (i32, f64)
+    241..329 'match ...     }': ()
+    247..251 'pair': (i32, f64)
+    310..316 '(0, _)': (i32, f64)
+    311..312 '0': i32
+    311..312 '0': i32
+    314..315 '_': f64
+    320..322 '{}': ()
+    "###);
+}
+
+#[test]
+fn infer_consts_and_statics() {
+    insta::assert_snapshot!(infer(
+        r#"
+    const MAX_SPEED: f32 = 10.0;
+    const DOUBLE_MAX_SPEED: f32 = MAX_SPEED * 2.0;
+    static mut SCORE: i64 = 0;
+
+    fn main() -> f32 {
+        SCORE += 1;
+        let speed = DOUBLE_MAX_SPEED;
+        speed
+    }
+    "#),
+    @r###"
+    23..27 '10.0': f32
+    59..68 'MAX_SPEED': f32
+    59..74 'MAX_SPEED * 2.0': f32
+    71..74 '2.0': f32
+    100..101 '0': i64
+    121..184 '{     ...peed }': f32
+    127..132 'SCORE': i64
+    127..137 'SCORE += 1': ()
+    136..137 '1': i64
+    147..152 'speed': f32
+    155..171 'DOUBLE..._SPEED': f32
+    177..182 'speed': f32
+    "###);
+}
+
+#[test]
+fn const_and_static_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    const A: i32 = 1;
+    static B: i32 = 2;
+    static mut C: i32 = 3;
+    const D: i32 = C;                   // error: not a constant expression
+    const E: u8 = 255 + 1;              // error: overflow
+    const F: i32 = 1 / 0;               // error: division by zero
+    const G: i32 = H;                   // error: cycle
+    const H: i32 = G;                   // error: cycle
+    const I: bool = 1;                  // error: mismatched type
+
+    fn main() {
+        A = 2;                          // error: invalid left hand side
+        B = 3;                          // error: cannot assign to immutable static
+        C = 4;
+    }
+    "#),
+    @r###"
+    75..76: expression cannot be evaluated at compile time
+    146..153: evaluation of constant expression overflowed
+    202..207: attempt to divide by zero in constant expression
+    250..267: cycle detected when evaluating constant `G`
+    302..319: cycle detected when evaluating constant `H`
+    370..371: mismatched type
+    433..434: invalid left hand side of expression
+    502..503: cannot assign to immutable static `B`; declare it with `static mut`
+    15..16 '1': i32
+    34..35 '2': i32
+    57..58 '3': i32
+    75..76 'C': i32
+    146..149 '255': u8
+    146..153 '255 + 1': u8
+    152..153 '1': u8
+    202..203 '1': i32
+    202..207 '1 / 0': i32
+    206..207 '0': i32
+    265..266 'H': i32
+    317..318 'G': i32
+    370..371 '1': i32
+    427..590 '{     ...= 4; }': ()
+    433..434 'A': i32
+    433..438 'A = 2': ()
+    437..438 '2': i32
+    502..503 'B': i32
+    502..507 'B = 3': ()
+    506..507 '3': i32
+    582..583 'C': i32
+    582..587 'C = 4': ()
+    586..587 '4': i32
+    "###);
+}
+
+#[test]
+fn infer_optionals() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Node { value: i32, next: ?Node }
+
+    fn last(node: Node) -> Node {
+        let current = node;
+        while let next = current.next {
+            current = next;
+        }
+        current
+    }
+
+    fn find(node: ?Node, value: i32) -> ?Node {
+        if let n = node {
+            if n.value == value { n } else { find(n.next, value) }
+        } else {
+            nil
+        }
+    }
+
+    fn main() {
+        let a: ?[i32] = nil;
+        let b = Node { value: 0, next: nil };
+        b.next = b;
+        let is_empty = a == nil;
+    }
+    "#),
+    @r###"
+    49..53 'node': Node
+    69..174 '{     ...rent }': Node
+    79..86 'current': Node
+    89..93 'node': Node
+    99..160 'while ...     }': ()
+    109..113 'next': Node
+    116..123 'current': Node
+    116..128 'current.next': ?Node
+    129..160 '{     ...     }': ()
+    139..146 'current': Node
+    139..153 'current = next': ()
+    149..153 'next': Node
+    165..172 'current': Node
+    184..188 'node': ?Node
+    197..202 'value': i32
+    218..337 '{     ...   } }': ?Node
+    224..335 'if let...     }': ?Node
+    231..232 'n': Node
+    235..239 'node': ?Node
+    240..310 '{     ...     }': ?Node
+    250..304 'if n.v...lue) }': ?Node
+    253..254 'n': Node
+    253..260 'n.value': i32
+    253..269 'n.valu... value': bool
+    264..269 'value': i32
+    270..275 '{ n }': ?Node
+    272..273 'n': Node
+    281..304 '{ find...lue) }': ?Node
+    283..287 'find': function find(?Node, i32) -> ?Node
+    283..302 'find(n...value)': ?Node
+    288..289 'n': Node
+    288..294 'n.next': ?Node
+    296..301 'value': i32
+    316..335 '{     ...     }': ?Node
+    326..329 'nil': ?Node
+    349..464 '{     ...nil; }': ()
+    359..360 'a': ?[i32]
+    371..374 'nil': ?[i32]
+    384..385 'b': Node
+    388..416 'Node {... nil }': Node
+    402..403 '0': i32
+    411..414 'nil': ?Node
+    422..423 'b': Node
+    422..428 'b.next': ?Node
+    422..432 'b.next = b': ()
+    431..432 'b': Node
+    442..450 'is_empty': bool
+    453..454 'a': ?[i32]
+    453..461 'a == nil': bool
+    458..461 'nil': ?[i32]
+    "###);
+}
+
+#[test]
+fn optional_errors() {
+    insta::assert_snapshot!(infer(
+        r#"
+    struct Node { next: ?Node }
+    struct(value) Vec2 { x: f32, y: f32 }
+
+    fn main(a: ?i32, b: ?Vec2, node: ?Node, arr: ?[i32]) {  // error: invalid optional types
+        let c = nil;                    // error: type annotations needed
+        let d: Node = nil;              // error: mismatched type
+        node.next;                      // error: possibly nil
+        arr[0];                         // error: possibly nil
+        if let e = 5 {}                 // error: expected optional
+        let f: Node = node;             // error: mismatched type
+        node < node;                    // error: cannot apply binary operator
+        match node { nil => {} }        // error: missing match arms
+        match node { nil => {}, n => {} }
+    }
+    "#),
+    @r###"
+    78..82: optional types can only wrap garbage collected structs or arrays
+    87..92: optional types can only wrap garbage collected structs or arrays
+    244..247: mismatched type
+    292..296: value of an optional type may be `nil`; unwrap it with `if let` first
+    351..354: value of an optional type may be `nil`; unwrap it with `if let` first
+    421..422: expected a value of an optional type
+    488..492: mismatched type
+    536..547: cannot apply binary operator
+    168..171: type annotations needed
+    617..621: non-exhaustive patterns: `_` not covered
+    75..76 'a': ?i32
+    84..85 'b': ?Vec2
+    94..98 'node': ?Node
+    107..110 'arr': ?[i32]
+    120..711 '{  // ...{} } }': ()
+    164..165 'c': ?{unknown}
+    168..171 'nil': ?{unknown}
+    234..235 'd': ?{unknown}
+    244..247 'nil': ?{unknown}
+    292..296 'node': ?Node
+    292..301 'node.next': {unknown}
+    351..354 'arr': ?[i32]
+    351..357 'arr[0]': {unknown}
+    355..356 '0': i32
+    410..425 'if let e = 5 {}': ()
+    417..418 'e': {unknown}
+    421..422 '5': i32
+    423..425 '{}': ()
+    478..479 'f': ?Node
+    488..492 'node': ?Node
+    536..540 'node': ?Node
+    536..547 'node < node': bool
+    543..547 'node': ?Node
+    611..635 'match ...> {} }': ()
+    617..621 'node': ?Node
+    624..627 'nil': ?Node
+    624..627 'nil': ?Node
+    631..633 '{}': ()
+    676..709 'match ...> {} }': ()
+    682..686 'node': ?Node
+    689..692 'nil': ?Node
+    689..692 'nil': ?Node
+    696..698 '{}': ()
+    700..701 'n': ?Node
+    705..707 '{}': ()
+    "###);
+}
+
+#[test]
+fn attributes() {
+    insta::assert_snapshot!(infer(
+        r#"
+    #[test]
+    fn foo() {}
+
+    #[inline]
+    #[deprecated = "use `foo` instead"]
+    fn bar() {}
+
+    #[foo]                  // error: cannot find attribute `foo`
+    struct Bar {
+        #[deprecated]
+        a: i32,
+        #[rename(b)]        // error: cannot find attribute `rename`
+        c: i32,
+    }
+
+    enum Baz {
+        #[core::deprecated] // error: cannot find attribute `core::deprecated`
+        A,
+    }
+
+    impl Bar {
+        #[export]           // error: cannot find attribute `export`
+        fn baz(self: Self) {}
+    }
+    "#),
+    @r###"
+    80..86: cannot find attribute `foo`
+    189..201: cannot find attribute `rename`
+    280..299: cannot find attribute `core::deprecated`
+    376..385: cannot find attribute `export`
+    17..19 '{}': ()
+    76..78 '{}': ()
+    448..452 'self': Bar
+    460..462 '{}': ()
+    "###);
+}
+
 fn infer(content: &str) -> String {
     let db = MockDatabase::with_files(content);
 
@@ -1376,10 +3421,11 @@ fn infer(content: &str) -> String {
         .flat_map(|pkg| pkg.modules(&db))
         .flat_map(|module| module.declarations(&db))
     {
-        if let ModuleDef::Function(fun) = item {
-            let source_map = fun.body_source_map(&db);
-            let infer_result = fun.infer(&db);
-            infer_def(infer_result, source_map);
+        match item {
+            ModuleDef::Function(fun) => infer_def(fun.infer(&db), fun.body_source_map(&db)),
+            ModuleDef::Const(c) => infer_def(c.infer(&db), c.body_source_map(&db)),
+            ModuleDef::Static(s) => infer_def(s.infer(&db), s.body_source_map(&db)),
+            _ => (),
         }
     }
 
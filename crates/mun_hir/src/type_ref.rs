@@ -5,7 +5,10 @@ use crate::{
     arena::{map::ArenaMap, Arena, Idx},
     Path,
 };
-use mun_syntax::{ast, AstPtr};
+use mun_syntax::{
+    ast::{self, TypeAscriptionOwner},
+    AstPtr,
+};
 use rustc_hash::FxHashMap;
 use std::ops::Index;
 
@@ -15,10 +18,17 @@ pub type LocalTypeRefId = Idx<TypeRef>;
 /// Compare ty::Ty
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum TypeRef {
-    Path(Path),
+    /// A path to a type with the type arguments of its last segment, e.g. `Pair<i32, f32>`
+    Path(Path, Vec<LocalTypeRefId>),
     Array(LocalTypeRefId),
+    /// An optional type, e.g. `?Foo`
+    Option(LocalTypeRefId),
     Never,
     Tuple(Vec<LocalTypeRefId>),
+    /// A function pointer type with its parameter types and return type, e.g. `fn(i32) -> bool`
+    Fn(Vec<LocalTypeRefId>, LocalTypeRefId),
+    /// A trait object type, e.g. `dyn Damageable`
+    DynTrait(Path),
     Error,
 }
 
@@ -104,13 +114,59 @@ impl TypeRefMapBuilder {
         use mun_syntax::ast::TypeRefKind::*;
         let ptr = AstPtr::new(node);
         let type_ref = match node.kind() {
-            PathType(path) => path
+            PathType(path) => match path.path() {
+                Some(path) => {
+                    let type_args = path
+                        .segment()
+                        .and_then(|segment| segment.type_arg_list())
+                        .map(|type_args| {
+                            type_args
+                                .type_args()
+                                .map(|arg| self.alloc_from_node_opt(arg.type_ref().as_ref()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    Path::from_ast(path)
+                        .map(|path| TypeRef::Path(path, type_args))
+                        .unwrap_or(TypeRef::Error)
+                }
+                None => TypeRef::Error,
+            },
+            NeverType(_) => TypeRef::Never,
+            ArrayType(inner) => TypeRef::Array(self.alloc_from_node_opt(inner.type_ref().as_ref())),
+            OptionType(inner) => {
+                TypeRef::Option(self.alloc_from_node_opt(inner.type_ref().as_ref()))
+            }
+            FnPointerType(inner) => {
+                let params = inner
+                    .param_list()
+                    .into_iter()
+                    .flat_map(|param_list| param_list.params())
+                    .map(|param| self.alloc_from_node_opt(param.ascribed_type().as_ref()))
+                    .collect();
+                let ret = match inner.ret_type().and_then(|ret| ret.type_ref()) {
+                    Some(ret) => self.alloc_from_node(&ret),
+                    None => self.unit(),
+                };
+                TypeRef::Fn(params, ret)
+            }
+            DynTraitType(inner) => inner
                 .path()
                 .and_then(Path::from_ast)
-                .map(TypeRef::Path)
+                .map(TypeRef::DynTrait)
                 .unwrap_or(TypeRef::Error),
-            NeverType(_) => TypeRef::Never,
-            ArrayType(inner) => TypeRef::Array(self.alloc_from_node_opt(inner.type_ref().as_ref())),
+            TupleType(inner) => TypeRef::Tuple(
+                inner
+                    .fields()
+                    .map(|field| self.alloc_from_node(&field))
+                    .collect(),
+            ),
+            ParenType(inner) => {
+                let id = self.alloc_from_node_opt(inner.type_ref().as_ref());
+                // make the paren type point to the inner type as well
+                self.source_map.type_ref_map.insert(ptr, id);
+                return id;
+            }
         };
         self.alloc_type_ref(type_ref, ptr)
     }
@@ -45,11 +45,16 @@ impl CompletionItemKind {
     pub(crate) fn tag(&self) -> &'static str {
         match self {
             CompletionItemKind::SymbolKind(kind) => match kind {
+                SymbolKind::Const => "ct",
+                SymbolKind::Enum => "en",
+                SymbolKind::EnumVariant => "ev",
                 SymbolKind::Field => "fd",
                 SymbolKind::Function => "fn",
                 SymbolKind::Local => "lc",
                 SymbolKind::Module => "md",
+                SymbolKind::Static => "sc",
                 SymbolKind::Struct => "st",
+                SymbolKind::Trait => "tt",
                 SymbolKind::TypeAlias => "ta",
             },
             CompletionItemKind::Attribute => "at",
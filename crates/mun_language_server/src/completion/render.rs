@@ -71,9 +71,22 @@ impl<'a> Render<'a> {
             }
             ScopeDef::ModuleDef(PrimitiveType(_)) => CompletionItemKind::BuiltinType,
             ScopeDef::ModuleDef(Struct(_)) => CompletionItemKind::SymbolKind(SymbolKind::Struct),
+            ScopeDef::ModuleDef(Enum(_)) => CompletionItemKind::SymbolKind(SymbolKind::Enum),
+            ScopeDef::ModuleDef(EnumVariant(_)) => {
+                CompletionItemKind::SymbolKind(SymbolKind::EnumVariant)
+            }
             ScopeDef::ModuleDef(TypeAlias(_)) => {
                 CompletionItemKind::SymbolKind(SymbolKind::TypeAlias)
             }
+            ScopeDef::ModuleDef(Trait(_)) => CompletionItemKind::SymbolKind(SymbolKind::Trait),
+            ScopeDef::ModuleDef(Const(_)) => CompletionItemKind::SymbolKind(SymbolKind::Const),
+            ScopeDef::ModuleDef(Static(_)) => CompletionItemKind::SymbolKind(SymbolKind::Static),
+            ScopeDef::ModuleDef(BuiltinModule(_)) => {
+                CompletionItemKind::SymbolKind(SymbolKind::Module)
+            }
+            ScopeDef::ModuleDef(IntrinsicFunction(_)) => {
+                CompletionItemKind::SymbolKind(SymbolKind::Function)
+            }
             ScopeDef::Local(_) => CompletionItemKind::SymbolKind(SymbolKind::Local),
             ScopeDef::Unknown => {
                 let item = CompletionItem::builder(CompletionKind::Reference, local_name)
@@ -1,6 +1,6 @@
 use crate::SymbolKind;
 use mun_syntax::{
-    ast::{self, NameOwner},
+    ast::{self, NameOwner, TypeAscriptionOwner},
     match_ast, AstNode, SourceFile, SyntaxNode, TextRange, WalkEvent,
 };
 
@@ -125,7 +125,12 @@ fn try_convert_to_structure_node(node: &SyntaxNode) -> Option<StructureNode> {
                 decl_with_detail(&it, Some(detail), SymbolKind::Function)
             },
             ast::StructDef(it) => decl(it, SymbolKind::Struct),
+            ast::EnumDef(it) => decl(it, SymbolKind::Enum),
+            ast::EnumVariant(it) => decl(it, SymbolKind::EnumVariant),
             ast::TypeAliasDef(it) => decl_with_type_ref(&it, it.type_ref(), SymbolKind::TypeAlias),
+            ast::TraitDef(it) => decl(it, SymbolKind::Trait),
+            ast::ConstDef(it) => decl_with_type_ref(&it, it.ascribed_type(), SymbolKind::Const),
+            ast::StaticDef(it) => decl_with_type_ref(&it, it.ascribed_type(), SymbolKind::Static),
             _ => None
         }
     }
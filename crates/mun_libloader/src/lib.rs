@@ -40,6 +40,10 @@ impl MunLibrary {
             .library()
             .get(abi::SET_ALLOCATOR_HANDLE_FN_NAME.as_bytes())?;
 
+        let _set_panic_handle_fn: libloading::Symbol<'_, extern "C" fn(*mut c_void)> = library
+            .library()
+            .get(abi::SET_PANIC_HANDLE_FN_NAME.as_bytes())?;
+
         Ok(MunLibrary(library))
     }
 
@@ -95,4 +99,21 @@ impl MunLibrary {
 
         set_allocator_handle_fn(allocator_ptr);
     }
+
+    /// Stores the panic handle inside the shared object. This is used by the internals of the
+    /// library to signal and detect panics.
+    ///
+    /// # Safety
+    ///
+    /// This operations executes a function in the munlib. There is no guarantee that the execution
+    /// of the function wont result in undefined behavior.
+    pub unsafe fn set_panic_handle(&mut self, panic_ptr: *mut c_void) {
+        let set_panic_handle_fn: libloading::Symbol<'_, extern "C" fn(*mut c_void)> = self
+            .0
+            .library()
+            .get(abi::SET_PANIC_HANDLE_FN_NAME.as_bytes())
+            .unwrap();
+
+        set_panic_handle_fn(panic_ptr);
+    }
 }
@@ -55,6 +55,11 @@ pub trait GcRuntime: Send + Sync {
     /// Allocates an array of the given type. `ty` must be an array type.
     fn alloc_array(&self, ty: &Type, n: usize) -> Self::Array;
 
+    /// Ensures that the array referred to by `handle` has capacity for at least `additional` more
+    /// elements. If required, the elements of the array are moved to a larger block of memory.
+    /// The handle itself remains valid.
+    fn reserve_array(&self, handle: GcPtr, additional: usize);
+
     /// Returns the type of the specified `obj`.
     fn ptr_type(&self, obj: GcPtr) -> Type;
 
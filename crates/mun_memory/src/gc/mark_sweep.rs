@@ -8,7 +8,7 @@ use crate::{
     r#type::Type,
     TypeKind,
 };
-use mapping::{Mapping, StructMapping};
+use mapping::{EnumMapping, Mapping, StructMapping};
 use parking_lot::RwLock;
 use std::{
     alloc::{Layout, LayoutError},
@@ -31,7 +31,10 @@ impl Trace {
         };
         let obj_ref = unsafe { obj.as_ref() };
         match obj_ref.ty.kind() {
-            TypeKind::Primitive(_) | TypeKind::Pointer(_) => {}
+            TypeKind::Primitive(_)
+            | TypeKind::Pointer(_)
+            | TypeKind::Enum(_)
+            | TypeKind::Optional(_) => {}
             TypeKind::Struct(_) => {
                 trace.stack.push_back(CompositeTrace::Struct(StructTrace {
                     struct_ptr: unsafe { obj_ref.data.ptr },
@@ -59,6 +62,7 @@ impl Iterator for Trace {
             let top_stack = self.stack.back_mut()?;
             let event = match top_stack {
                 CompositeTrace::Struct(s) => s.next(),
+                CompositeTrace::Enum(e) => e.next(),
                 CompositeTrace::Array(a) => a.next(),
             };
 
@@ -70,6 +74,7 @@ impl Iterator for Trace {
                 Some(TraceEvent::InlineStruct(s)) => {
                     self.stack.push_back(CompositeTrace::Struct(s))
                 }
+                Some(TraceEvent::InlineEnum(e)) => self.stack.push_back(CompositeTrace::Enum(e)),
             }
         }
     }
@@ -80,6 +85,9 @@ enum CompositeTrace {
     /// A struct
     Struct(StructTrace),
 
+    /// An enum
+    Enum(EnumTrace),
+
     /// An array
     Array(ArrayTrace),
 }
@@ -87,6 +95,7 @@ enum CompositeTrace {
 enum TraceEvent {
     Reference(NonNull<ObjectInfo>),
     InlineStruct(StructTrace),
+    InlineEnum(EnumTrace),
 }
 
 impl TraceEvent {
@@ -96,8 +105,7 @@ impl TraceEvent {
             TypeKind::Primitive(_) | TypeKind::Pointer(_) => None,
             TypeKind::Struct(s) => {
                 return if s.is_gc_struct() {
-                    let deref_ptr = unsafe { ptr.cast::<NonNull<ObjectInfo>>().as_ref() };
-                    Some(TraceEvent::Reference(*deref_ptr))
+                    unsafe { read_handle(ptr) }.map(TraceEvent::Reference)
                 } else {
                     Some(TraceEvent::InlineStruct(StructTrace {
                         struct_ptr: ptr.cast(),
@@ -106,11 +114,28 @@ impl TraceEvent {
                     }))
                 }
             }
-            TypeKind::Array(_) => Some(TraceEvent::Reference(ptr.cast())),
+            TypeKind::Enum(_) => Some(TraceEvent::InlineEnum(EnumTrace {
+                enum_ptr: ptr,
+                enum_type: ty.into_owned(),
+                field_index: 0,
+            })),
+            TypeKind::Array(_) | TypeKind::Optional(_) => {
+                unsafe { read_handle(ptr) }.map(TraceEvent::Reference)
+            }
         }
     }
 }
 
+/// Reads the GC handle that is stored at the specified location. Returns `None` if the location
+/// contains a `nil` reference.
+///
+/// # Safety
+///
+/// `ptr` must point to memory that contains a GC handle.
+unsafe fn read_handle(ptr: NonNull<u8>) -> Option<NonNull<ObjectInfo>> {
+    NonNull::new(*ptr.cast::<*mut ObjectInfo>().as_ref())
+}
+
 /// A struct that enables iterating over all GC references in a struct. Structs can be stored inline
 /// or on the heap. This struct supports both.
 struct StructTrace {
@@ -143,6 +168,39 @@ impl Iterator for StructTrace {
     }
 }
 
+/// A struct that enables iterating over all GC references in an enum. Enums are always stored
+/// inline. Only the fields of the variant that is currently stored in the enum are visited.
+struct EnumTrace {
+    enum_ptr: NonNull<u8>,
+    enum_type: Type,
+    field_index: usize,
+}
+
+impl Iterator for EnumTrace {
+    type Item = TraceEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let enum_ty = self.enum_type.as_enum()?;
+        let tag = unsafe { enum_ty.read_tag(self.enum_ptr) };
+        let fields = enum_ty.variant(tag)?.fields();
+        let field_count = fields.len();
+        while self.field_index < field_count {
+            let index = self.field_index;
+            self.field_index += 1;
+
+            let field = fields.get(index).unwrap();
+            let field_ty = field.ty();
+            let field_ptr =
+                unsafe { NonNull::new_unchecked(self.enum_ptr.as_ptr().add(field.offset())) };
+
+            if let Some(event) = TraceEvent::new(field_ptr, Cow::Owned(field_ty)) {
+                return Some(event);
+            }
+        }
+        None
+    }
+}
+
 /// A struct that enables iterating over all GC references in a struct.
 ///
 /// TODO: if the element type doesnt contain any references it's a bit of a waste to iterate over
@@ -224,6 +282,17 @@ where
     pub fn observer(&self) -> &O {
         &self.observer
     }
+
+    /// Calls `f` with the type and a pointer to the data of every allocated struct object.
+    pub fn for_each_struct(&self, mut f: impl FnMut(&Type, NonNull<u8>)) {
+        let objects = self.objects.read();
+        for object in objects.values() {
+            if object.ty.is_struct() {
+                // Safety: the data of a struct object is not an array
+                f(&object.ty, unsafe { object.data.ptr });
+            }
+        }
+    }
 }
 
 fn alloc_obj(ty: Type) -> Pin<Box<ObjectInfo>> {
@@ -253,6 +322,10 @@ impl From<LayoutError> for MemoryLayoutError {
     }
 }
 
+/// The minimum capacity of an array that grows, which avoids frequent reallocations of small
+/// arrays.
+const MIN_ARRAY_CAPACITY: usize = 4;
+
 /// Helper object to work with GcPtr that represents an array.
 ///
 /// Arrays are stored in memory with a header which holds the length and capacity. The memory layout
@@ -423,6 +496,19 @@ fn alloc_array(ty: Type, length: usize) -> Pin<Box<ObjectInfo>> {
     })
 }
 
+/// Returns the memory layout of an array with elements of type `element_ty` that has room for
+/// `capacity` elements.
+fn array_layout(element_ty: &Type, capacity: usize) -> Layout {
+    let header_layout = Layout::new::<ArrayHeader>();
+    let element_ty_layout = element_ty.reference_layout();
+    let elements_layout = repeat_layout(element_ty_layout, capacity)
+        .expect("unable to create a memory layout for array elemets");
+    let (layout, _) = header_layout
+        .extend(elements_layout)
+        .expect("unable to create memory layout for array");
+    layout
+}
+
 /// Constructs an array header for an array type with `length` elements.
 fn array_header(ty: &Type, length: usize) -> NonNull<ArrayHeader> {
     let array_ty = ty
@@ -430,13 +516,7 @@ fn array_header(ty: &Type, length: usize) -> NonNull<ArrayHeader> {
         .expect("array type doesnt have an element type");
 
     // Allocate memory for the array data
-    let header_layout = Layout::new::<ArrayHeader>();
-    let element_ty_layout = array_ty.element_type().reference_layout();
-    let elements_layout = repeat_layout(element_ty_layout, length)
-        .expect("unable to create a memory layout for array elemets");
-    let (layout, _) = header_layout
-        .extend(elements_layout)
-        .expect("unable to create memory layout for array");
+    let layout = array_layout(&array_ty.element_type(), length);
 
     let mut array_header: NonNull<ArrayHeader> =
         NonNull::new(unsafe { std::alloc::alloc_zeroed(layout).cast() })
@@ -490,6 +570,60 @@ where
         }
     }
 
+    fn reserve_array(&self, handle: GcPtr, additional: usize) {
+        let _lock = self.objects.write();
+
+        // Convert the handle to our internal representation
+        let object_info: *mut ObjectInfo = handle.into();
+        let object_info = unsafe { &mut *object_info };
+        let element_ty = object_info
+            .ty
+            .as_array()
+            .expect("cannot reserve memory for a type that is not an array")
+            .element_type();
+
+        let header = unsafe { object_info.data.array.as_ref() };
+        let required = header
+            .length
+            .checked_add(additional)
+            .expect("array capacity overflow");
+        if required <= header.capacity {
+            return;
+        }
+
+        // Grow exponentially to amortize the cost of reallocating
+        let old_capacity = header.capacity;
+        let new_capacity = required.max(old_capacity * 2).max(MIN_ARRAY_CAPACITY);
+        let old_layout = array_layout(&element_ty, old_capacity);
+        let new_layout = array_layout(&element_ty, new_capacity);
+
+        // The handle remains valid, only the memory it refers to is moved
+        let ptr = unsafe {
+            std::alloc::realloc(
+                object_info.data.array.as_ptr().cast(),
+                old_layout,
+                new_layout.size(),
+            )
+        };
+        let mut array = NonNull::new(ptr)
+            .expect("error reallocating memory for array")
+            .cast::<ArrayHeader>();
+
+        // Keep the unused capacity zero-initialized, like newly allocated memory
+        unsafe {
+            std::ptr::write_bytes(
+                ptr.add(old_layout.size()),
+                0,
+                new_layout.size() - old_layout.size(),
+            );
+            array.as_mut().capacity = new_capacity;
+        }
+        object_info.data = ObjectInfoData { array };
+
+        let mut stats = self.stats.write();
+        stats.allocated_memory += new_layout.size() - old_layout.size();
+    }
+
     fn ptr_type(&self, handle: GcPtr) -> Type {
         let _lock = self.objects.read();
 
@@ -662,6 +796,7 @@ where
                     map_struct(
                         &mut new_allocations,
                         &mapping.struct_mappings,
+                        &mapping.enum_mappings,
                         &conversion.field_mapping,
                         src,
                         dest,
@@ -686,16 +821,59 @@ where
                 let mut ty = object_info.ty.clone();
                 let mut stack = Vec::new();
 
-                while let Some(array) = ty.as_array() {
-                    stack.push(ty.clone());
-                    ty = array.element_type();
+                // Arrays can also contain optional references to arrays or structs
+                while let Some(element_ty) = ty
+                    .as_array()
+                    .map(|array| array.element_type())
+                    .or_else(|| ty.as_optional().map(|optional| optional.inner_type()))
+                {
+                    stack.push(ty.is_optional());
+                    ty = element_ty;
                 }
 
                 let old_element_ty = ty;
-                if let Some(conversion) = mapping.struct_mappings.get(&old_element_ty) {
+                if let Some(conversion) = mapping.enum_mappings.get(&old_element_ty) {
+                    let mut new_ty = conversion.new_ty.clone();
+                    while let Some(is_optional) = stack.pop() {
+                        new_ty = if is_optional {
+                            new_ty.optional_type()
+                        } else {
+                            new_ty.array_type()
+                        };
+                    }
+
+                    // Only arrays containing enums need to be mapped, as an array of arrays merely
+                    // contains `GcPtr`s.
+                    if new_ty.as_array().unwrap().element_type().is_enum() {
+                        let element_action = Action::EnumMap {
+                            old_ty: old_element_ty,
+                            old_offset: 0,
+                        };
+
+                        map_array(
+                            &mut new_allocations,
+                            &mapping.struct_mappings,
+                            &mapping.enum_mappings,
+                            unsafe {
+                                NonNull::new_unchecked(
+                                    object_info.as_mut().deref_mut() as *mut ObjectInfo
+                                )
+                            },
+                            &element_action,
+                            &new_ty,
+                        );
+                    } else {
+                        // Update the type of arrays of arrays
+                        object_info.as_mut().ty = new_ty;
+                    }
+                } else if let Some(conversion) = mapping.struct_mappings.get(&old_element_ty) {
                     let mut new_ty = conversion.new_ty.clone();
-                    while stack.pop().is_some() {
-                        new_ty = new_ty.array_type();
+                    while let Some(is_optional) = stack.pop() {
+                        new_ty = if is_optional {
+                            new_ty.optional_type()
+                        } else {
+                            new_ty.array_type()
+                        };
                     }
 
                     // Only arrays containing structs need to be mapped, as an array of arrays merely
@@ -711,6 +889,7 @@ where
                         map_array(
                             &mut new_allocations,
                             &mapping.struct_mappings,
+                            &mapping.enum_mappings,
                             unsafe {
                                 NonNull::new_unchecked(
                                     object_info.as_mut().deref_mut() as *mut ObjectInfo
@@ -720,8 +899,8 @@ where
                             &new_ty,
                         );
                     } else {
-                        // Update the type of arrays of arrays
-                        object_info.as_mut().ty = conversion.new_ty.clone();
+                        // Update the type of arrays of arrays or optionals
+                        object_info.as_mut().ty = new_ty;
                     }
                 }
             });
@@ -749,6 +928,7 @@ where
         fn map_array(
             new_allocations: &mut Vec<Pin<Box<ObjectInfo>>>,
             conversions: &HashMap<Type, StructMapping>,
+            enum_conversions: &HashMap<Type, EnumMapping>,
             mut src_object: NonNull<ObjectInfo>,
             element_action: &Action,
             new_ty: &Type,
@@ -777,6 +957,7 @@ where
                     map_type(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         src,
                         dest,
                         element_action,
@@ -794,11 +975,21 @@ where
         fn map_type(
             new_allocations: &mut Vec<Pin<Box<ObjectInfo>>>,
             conversions: &HashMap<Type, StructMapping>,
+            enum_conversions: &HashMap<Type, EnumMapping>,
             src: NonNull<u8>,
             dest: NonNull<u8>,
             action: &mapping::Action,
             new_ty: &Type,
         ) {
+            // An optional reference is stored the same way as the reference itself
+            let new_inner_ty;
+            let new_ty = if let Some(new_optional) = new_ty.as_optional() {
+                new_inner_ty = new_optional.inner_type();
+                &new_inner_ty
+            } else {
+                new_ty
+            };
+
             match action {
                 mapping::Action::ArrayAlloc => {
                     // Initialize the array with no values
@@ -830,6 +1021,7 @@ where
                     map_type(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         unsafe { get_field_ptr(src, *old_offset) },
                         array_handle.data(),
                         element_action,
@@ -852,11 +1044,16 @@ where
                     let src_ptr = unsafe { get_field_ptr(src, *old_offset) };
 
                     // Safety: we already hold a write lock on `objects`, so this is legal.
-                    let src_obj = unsafe { *src_ptr.cast::<NonNull<ObjectInfo>>().as_ref() };
+                    let src_obj = match unsafe { read_handle(src_ptr) } {
+                        Some(src_obj) => src_obj,
+                        // A `nil` reference remains `nil`
+                        None => return,
+                    };
 
                     map_array(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         src_obj,
                         element_action,
                         new_ty,
@@ -897,24 +1094,31 @@ where
                     old_offset,
                 } => {
                     // Safety: we already hold a write lock on `objects`, so this is legal.
-                    let obj = unsafe {
-                        *get_field_ptr(src, *old_offset)
-                            .cast::<NonNull<ObjectInfo>>()
-                            .as_ref()
-                    };
+                    let obj = unsafe { read_handle(get_field_ptr(src, *old_offset)) };
 
-                    let array_handle = ArrayHandle { obj };
-
-                    if array_handle.header().length > 0 {
+                    if let Some(array_handle) = obj
+                        .map(|obj| ArrayHandle { obj })
+                        .filter(|array_handle| array_handle.header().length > 0)
+                    {
                         // Map single element from array
                         map_type(
                             new_allocations,
                             conversions,
+                            enum_conversions,
                             array_handle.data(),
                             dest,
                             element_action,
                             new_ty,
                         )
+                    } else if new_ty.is_enum() {
+                        init_enum(
+                            new_allocations,
+                            conversions,
+                            enum_conversions,
+                            src,
+                            dest,
+                            new_ty,
+                        );
                     } else {
                         // zero initialize
                     }
@@ -940,16 +1144,17 @@ where
                     });
 
                     // Safety: we already hold a write lock on `objects`, so this is legal.
-                    let object = unsafe {
-                        *get_field_ptr(src, *old_offset)
-                            .cast::<NonNull<ObjectInfo>>()
-                            .as_ref()
+                    let object = match unsafe { read_handle(get_field_ptr(src, *old_offset)) } {
+                        Some(object) => object,
+                        // A `nil` reference maps to a zero-initialized value
+                        None => return,
                     };
 
                     // Map heap-allocated struct to in-memory struct
                     map_struct(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         &conversion.field_mapping,
                         // SAFETY: pointer is guaranteed to be valid
                         unsafe { object.as_ref().data.ptr },
@@ -970,6 +1175,7 @@ where
                     map_struct(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         &conversion.field_mapping,
                         unsafe { get_field_ptr(src, *old_offset) },
                         // SAFETY: pointer is guaranteed to be valid
@@ -996,21 +1202,144 @@ where
                     map_struct(
                         new_allocations,
                         conversions,
+                        enum_conversions,
                         &conversion.field_mapping,
                         unsafe { get_field_ptr(src, *old_offset) },
                         dest,
                     );
                 }
+                mapping::Action::OptionalMap {
+                    element_action,
+                    nil_action,
+                    old_offset,
+                } => {
+                    // Safety: we already hold a write lock on `objects`, so this is legal.
+                    let action =
+                        if unsafe { read_handle(get_field_ptr(src, *old_offset)) }.is_some() {
+                            element_action
+                        } else {
+                            nil_action
+                        };
+
+                    map_type(
+                        new_allocations,
+                        conversions,
+                        enum_conversions,
+                        src,
+                        dest,
+                        action,
+                        new_ty,
+                    );
+                }
+                mapping::Action::EnumMap { old_ty, old_offset } => {
+                    if let Some(conversion) = enum_conversions.get(old_ty) {
+                        map_enum(
+                            new_allocations,
+                            conversions,
+                            enum_conversions,
+                            old_ty,
+                            conversion,
+                            unsafe { get_field_ptr(src, *old_offset) },
+                            dest,
+                        );
+                    } else {
+                        // The enum is unknown. Use the first variant instead
+                        init_enum(
+                            new_allocations,
+                            conversions,
+                            enum_conversions,
+                            src,
+                            dest,
+                            new_ty,
+                        );
+                    }
+                }
+                mapping::Action::EnumInitialize => init_enum(
+                    new_allocations,
+                    conversions,
+                    enum_conversions,
+                    src,
+                    dest,
+                    new_ty,
+                ),
                 mapping::Action::ZeroInitialize => {
                     // Use previously zero-initialized memory
                 }
             }
         }
 
+        #[allow(clippy::mutable_key_type)]
+        fn map_enum(
+            new_allocations: &mut Vec<Pin<Box<ObjectInfo>>>,
+            conversions: &HashMap<Type, StructMapping>,
+            enum_conversions: &HashMap<Type, EnumMapping>,
+            old_ty: &Type,
+            conversion: &EnumMapping,
+            src: NonNull<u8>,
+            dest: NonNull<u8>,
+        ) {
+            let old_enum = old_ty.as_enum().expect("Must be an enum.");
+            let new_enum = conversion.new_ty.as_enum().expect("Must be an enum.");
+
+            let old_tag = unsafe { old_enum.read_tag(src) };
+            if let Some(Some(variant)) = conversion.variant_mapping.get(old_tag) {
+                unsafe { new_enum.write_tag(dest, variant.new_index) };
+                map_struct(
+                    new_allocations,
+                    conversions,
+                    enum_conversions,
+                    &variant.field_mapping,
+                    src,
+                    dest,
+                );
+            } else {
+                // The variant was deleted. Use the first variant instead.
+                init_enum(
+                    new_allocations,
+                    conversions,
+                    enum_conversions,
+                    src,
+                    dest,
+                    &conversion.new_ty,
+                );
+            }
+        }
+
+        /// Initializes the zero-initialized enum value at `dest` as the first variant of `new_ty`.
+        /// Fields of that variant that are not value types are allocated, similar to the fields
+        /// of an inserted struct.
+        #[allow(clippy::mutable_key_type)]
+        fn init_enum(
+            new_allocations: &mut Vec<Pin<Box<ObjectInfo>>>,
+            conversions: &HashMap<Type, StructMapping>,
+            enum_conversions: &HashMap<Type, EnumMapping>,
+            src: NonNull<u8>,
+            dest: NonNull<u8>,
+            new_ty: &Type,
+        ) {
+            let new_enum = new_ty.as_enum().expect("Must be an enum.");
+            if let Some(variant) = new_enum.variant(0) {
+                unsafe { new_enum.write_tag(dest, variant.index()) };
+                for field in variant.fields().iter() {
+                    let field_ty = field.ty();
+                    map_type(
+                        new_allocations,
+                        conversions,
+                        enum_conversions,
+                        src,
+                        unsafe { get_field_ptr(dest, field.offset()) },
+                        &mapping::resolve_insert(&field_ty),
+                        &field_ty,
+                    );
+                }
+            }
+        }
+
         #[allow(clippy::mutable_key_type)]
         fn map_struct(
             new_allocations: &mut Vec<Pin<Box<ObjectInfo>>>,
             conversions: &HashMap<Type, StructMapping>,
+            enum_conversions: &HashMap<Type, EnumMapping>,
             mapping: &[FieldMapping],
             src: NonNull<u8>,
             dest: NonNull<u8>,
@@ -1025,6 +1354,7 @@ where
                 map_type(
                     new_allocations,
                     conversions,
+                    enum_conversions,
                     src,
                     field_dest,
                     action,
@@ -1102,17 +1432,14 @@ impl ObjectInfo {
     /// Returns the layout of the data pointed to by data
     pub fn layout(&self) -> Layout {
         match self.ty.kind() {
-            TypeKind::Struct(_) | TypeKind::Primitive(_) | TypeKind::Pointer(_) => {
-                self.ty.value_layout()
-            }
+            TypeKind::Struct(_)
+            | TypeKind::Enum(_)
+            | TypeKind::Primitive(_)
+            | TypeKind::Pointer(_)
+            | TypeKind::Optional(_) => self.ty.value_layout(),
             TypeKind::Array(array) => {
                 let elem_count = unsafe { self.data.array.as_ref().capacity };
-                let elem_layout = repeat_layout(array.element_type().value_layout(), elem_count)
-                    .expect("unable to determine layout of array elements");
-                let (layout, _) = Layout::new::<ArrayHeader>()
-                    .extend(elem_layout)
-                    .expect("unable to determine layout of array");
-                layout
+                array_layout(&array.element_type(), elem_count)
             }
         }
     }
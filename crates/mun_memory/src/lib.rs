@@ -1,8 +1,8 @@
 extern crate core;
 
 pub use r#type::{
-    ArrayType, Field, FieldData, HasStaticType, PointerType, StructType, StructTypeBuilder, Type,
-    TypeCollectionStats, TypeKind,
+    ArrayType, EnumType, EnumTypeBuilder, Field, FieldData, HasStaticType, OptionalType,
+    PointerType, StructType, StructTypeBuilder, Type, TypeCollectionStats, TypeKind, Variant,
 };
 
 pub mod ffi {
@@ -21,7 +21,7 @@ use thiserror::Error;
 pub mod prelude {
     pub use crate::diff::{compute_struct_diff, FieldDiff, FieldEditKind, StructDiff};
     pub use crate::mapping::{Action, FieldMapping};
-    pub use crate::r#type::{Field, PointerType, StructType, Type, TypeKind};
+    pub use crate::r#type::{EnumType, Field, PointerType, StructType, Type, TypeKind, Variant};
 }
 
 /// An error that can occur when trying to convert from an abi type to an internal type.
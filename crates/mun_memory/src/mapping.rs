@@ -1,12 +1,16 @@
 use mun_abi::Guid;
 
 use crate::{
+    cast,
     diff::{compute_struct_diff, FieldDiff, StructDiff},
     gc::GcPtr,
     r#type::Type,
-    ArrayType, TypeKind,
+    ArrayType, OptionalType, TypeKind,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ptr::NonNull,
 };
-use std::collections::{HashMap, HashSet};
 
 /// The type mapping needed to convert an old into a new set of unique and ordered values.
 pub struct Mapping {
@@ -14,6 +18,8 @@ pub struct Mapping {
     pub deletions: HashSet<Type>,
     /// The mappings of structs whose fields changed
     pub struct_mappings: HashMap<Type, StructMapping>,
+    /// The mappings of enums whose variants changed
+    pub enum_mappings: HashMap<Type, EnumMapping>,
     /// The types that didn't change
     pub identical: Vec<(Type, Type)>,
 }
@@ -26,6 +32,25 @@ pub struct StructMapping {
     pub new_ty: Type,
 }
 
+/// The enum mapping needed to convert an old into a new enum of unique variants.
+///
+/// Variants are matched by name, which allows variants to be added, removed, or reordered. Values
+/// of variants that no longer exist are initialized as the first variant of the new enum.
+pub struct EnumMapping {
+    /// The variant mappings for each original enum variant, or `None` if the variant was deleted
+    pub variant_mapping: Vec<Option<VariantMapping>>,
+    /// The new enum type
+    pub new_ty: Type,
+}
+
+/// The mapping of a single variant of an enum.
+pub struct VariantMapping {
+    /// The index of the variant in the new enum
+    pub new_index: usize,
+    /// The field mappings for each new variant field
+    pub field_mapping: Vec<FieldMapping>,
+}
+
 /// Description of the mapping of a single field. When stored together with the new index, this
 /// provides all information necessary for a mapping function.
 #[derive(Debug)]
@@ -64,6 +89,11 @@ pub enum Action {
         element_action: Box<Action>,
         old_offset: usize,
     },
+    /// Map an enum value in-place, matching its variants by name.
+    EnumMap { old_ty: Type, old_offset: usize },
+    /// Initialize an enum with its first variant, allocating the fields of that variant that are
+    /// not value types.
+    EnumInitialize,
     /// Allocate a new struct and ensure zero-initalization.
     StructAlloc,
     /// Allocate a new struct and map from a heap-allocated struct.
@@ -72,6 +102,13 @@ pub enum Action {
     StructMapFromValue { old_ty: Type, old_offset: usize },
     /// Map a value struct in-place.
     StructMapInPlace { old_ty: Type, old_offset: usize },
+    /// Map an optional reference, using `element_action` if the old reference is not `nil` and
+    /// `nil_action` otherwise.
+    OptionalMap {
+        element_action: Box<Action>,
+        nil_action: Box<Action>,
+        old_offset: usize,
+    },
     /// Ensure the memory is zero-initialized.
     ZeroInitialize,
 }
@@ -156,9 +193,24 @@ impl Mapping {
         // We should have matched all remaining candidates
         debug_assert!(new_candidates.is_empty());
 
+        // Enums are always value types, so there are never any objects of enum types. However,
+        // values of enums can be stored in structs and arrays, so we need to know how to map
+        // them. Enums are matched by name.
+        let enum_mappings = old
+            .iter()
+            .filter(|old_ty| old_ty.is_enum())
+            .filter_map(|old_ty| {
+                new.iter()
+                    .find(|new_ty| new_ty.is_enum() && new_ty.name() == old_ty.name())
+                    .filter(|new_ty| old_ty != *new_ty)
+                    .map(|new_ty| (old_ty.clone(), enum_mapping(old_ty, new_ty)))
+            })
+            .collect();
+
         Self {
             deletions,
             struct_mappings: conversions,
+            enum_mappings,
             identical,
         }
     }
@@ -222,16 +274,7 @@ pub unsafe fn field_mapping(old_ty: &Type, new_ty: &Type, diff: &[FieldDiff]) ->
                     .expect("The old field must exist.");
                 (*new_index, resolve_edit(old_type, new_type, old_offset))
             }),
-            FieldDiff::Insert { index, new_type } => Some((
-                *index,
-                if new_type.is_struct() && !new_type.is_value_type() {
-                    Action::StructAlloc
-                } else if new_type.is_array() {
-                    Action::ArrayAlloc
-                } else {
-                    Action::ZeroInitialize
-                },
-            )),
+            FieldDiff::Insert { index, new_type } => Some((*index, resolve_insert(new_type))),
             FieldDiff::Move {
                 ty,
                 old_index,
@@ -303,14 +346,157 @@ pub unsafe fn field_mapping(old_ty: &Type, new_ty: &Type, diff: &[FieldDiff]) ->
     }
 }
 
+/// Calculates the mapping of the variants of `old_ty` to the variants of `new_ty`. Variants are
+/// matched by name. Fields of matching variants are also matched by name; newly inserted fields
+/// are initialized with their default value.
+///
+/// # Panics
+///
+/// Panics if either `old_ty` or `new_ty` is not an enum.
+pub fn enum_mapping(old_ty: &Type, new_ty: &Type) -> EnumMapping {
+    let old_enum = old_ty.as_enum().expect("old type must be an enum");
+    let new_enum = new_ty.as_enum().expect("new type must be an enum");
+
+    let variant_mapping = old_enum
+        .variants()
+        .map(|old_variant| {
+            let new_variant = new_enum.find_variant_by_name(old_variant.name())?;
+            let old_fields = old_variant.fields();
+            let field_mapping = new_variant
+                .fields()
+                .iter()
+                .map(|new_field| {
+                    let new_ty = new_field.ty();
+                    let action = match old_fields.find_by_name(new_field.name()) {
+                        Some(old_field) => {
+                            resolve_edit(&old_field.ty(), &new_ty, old_field.offset())
+                        }
+                        None => resolve_insert(&new_ty),
+                    };
+                    FieldMapping {
+                        new_ty,
+                        new_offset: new_field.offset(),
+                        action,
+                    }
+                })
+                .collect();
+
+            Some(VariantMapping {
+                new_index: new_variant.index(),
+                field_mapping,
+            })
+        })
+        .collect();
+
+    EnumMapping {
+        variant_mapping,
+        new_ty: new_ty.clone(),
+    }
+}
+
+/// Maps the value of a global of type `old_ty` at `src` to a global of type `new_ty` at `dest`.
+/// Globals only store primitive values, which are copied if the types are identical or cast
+/// otherwise. Returns whether the value was mapped; if not, `dest` retains its initial value.
+///
+/// # Safety
+///
+/// `src` and `dest` must point to valid values of respectively `old_ty` and `new_ty`.
+pub unsafe fn map_global(
+    old_ty: &Type,
+    new_ty: &Type,
+    src: NonNull<u8>,
+    dest: NonNull<u8>,
+) -> bool {
+    if !old_ty.is_primitive() || !new_ty.is_primitive() {
+        return false;
+    }
+
+    match resolve_edit(old_ty, new_ty, 0) {
+        Action::Copy { size, .. } => {
+            std::ptr::copy_nonoverlapping(src.as_ptr(), dest.as_ptr(), size);
+            true
+        }
+        Action::Cast { old_ty, .. } => cast::try_cast_from_to(old_ty, new_ty.clone(), src, dest),
+        _ => false,
+    }
+}
+
+/// Returns the action required to initialize a newly inserted value of type `new_type`.
+pub fn resolve_insert(new_type: &Type) -> Action {
+    if new_type.is_struct() && !new_type.is_value_type() {
+        Action::StructAlloc
+    } else if new_type.is_array() {
+        Action::ArrayAlloc
+    } else if new_type.is_enum() {
+        Action::EnumInitialize
+    } else {
+        Action::ZeroInitialize
+    }
+}
+
 pub fn resolve_edit(old_ty: &Type, new_ty: &Type, old_offset: usize) -> Action {
+    // An optional reference is stored the same way as the reference itself, so optionals are
+    // resolved using their inner types.
+    if let Some(old_optional) = old_ty.as_optional() {
+        return resolve_optional_edit(&old_optional, new_ty, old_offset);
+    } else if let Some(new_optional) = new_ty.as_optional() {
+        return resolve_to_optional_edit(old_ty, &new_optional, old_offset);
+    }
+
     match &old_ty.kind() {
         TypeKind::Primitive(old_guid) => {
             resolve_primitive_edit(old_ty, new_ty, old_guid, old_offset)
         }
         TypeKind::Struct(_) => resolve_struct_edit(old_ty, new_ty, old_offset),
+        TypeKind::Enum(_) => resolve_enum_edit(old_ty, new_ty, old_offset),
         TypeKind::Pointer(_) => resolve_pointer_edit(old_ty, new_ty),
         TypeKind::Array(old_array) => resolve_array_edit(old_array, new_ty, old_offset),
+        TypeKind::Optional(_) => unreachable!(),
+    }
+}
+
+fn resolve_optional_edit(old_optional: &OptionalType, new_ty: &Type, old_offset: usize) -> Action {
+    let old_inner_ty = old_optional.inner_type();
+    match new_ty.as_optional() {
+        // ?T -> ?U
+        Some(new_optional) => {
+            let new_inner_ty = new_optional.inner_type();
+            if old_inner_ty == new_inner_ty {
+                Action::Copy {
+                    old_offset,
+                    size: std::mem::size_of::<GcPtr>(),
+                }
+            } else {
+                Action::OptionalMap {
+                    element_action: Box::new(resolve_edit(
+                        &old_inner_ty,
+                        &new_inner_ty,
+                        old_offset,
+                    )),
+                    nil_action: Box::new(Action::ZeroInitialize),
+                    old_offset,
+                }
+            }
+        }
+        // ?T -> U, a `nil` reference is replaced by a newly allocated default value
+        None => Action::OptionalMap {
+            element_action: Box::new(resolve_edit(&old_inner_ty, new_ty, old_offset)),
+            nil_action: Box::new(resolve_insert(new_ty)),
+            old_offset,
+        },
+    }
+}
+
+fn resolve_to_optional_edit(
+    old_ty: &Type,
+    new_optional: &OptionalType,
+    old_offset: usize,
+) -> Action {
+    // T -> ?U, instead of allocating a default value for an unrelated type the reference becomes
+    // `nil`
+    match resolve_edit(old_ty, &new_optional.inner_type(), old_offset) {
+        Action::ArrayAlloc | Action::StructAlloc => Action::ZeroInitialize,
+        action => action,
     }
 }
 
@@ -331,10 +517,12 @@ fn resolve_primitive_edit(
                 Action::StructAlloc
             }
         }
+        TypeKind::Enum(_) => Action::EnumInitialize,
         TypeKind::Pointer(_) => unreachable!(),
         TypeKind::Array(new_array) => {
             resolve_primitive_to_array_edit(old_ty, new_array, old_offset)
         }
+        TypeKind::Optional(_) => unreachable!(),
     }
 }
 
@@ -371,9 +559,11 @@ fn resolve_primitive_to_array_edit(
 fn resolve_struct_edit(old_ty: &Type, new_ty: &Type, old_offset: usize) -> Action {
     match &new_ty.kind() {
         TypeKind::Primitive(_) => Action::ZeroInitialize,
+        TypeKind::Enum(_) => Action::EnumInitialize,
         TypeKind::Struct(_) => resolve_struct_to_struct_edit(old_ty, new_ty, old_offset),
         TypeKind::Pointer(_) => unreachable!(),
         TypeKind::Array(new_array) => resolve_struct_to_array_edit(old_ty, new_array, old_offset),
+        TypeKind::Optional(_) => unreachable!(),
     }
 }
 
@@ -440,6 +630,42 @@ fn resolve_struct_to_array_edit(old_ty: &Type, new_array: &ArrayType, old_offset
     }
 }
 
+fn resolve_enum_edit(old_ty: &Type, new_ty: &Type, old_offset: usize) -> Action {
+    match &new_ty.kind() {
+        TypeKind::Primitive(_) => Action::ZeroInitialize,
+        TypeKind::Struct(s) => {
+            if s.is_value_struct() {
+                Action::ZeroInitialize
+            } else {
+                Action::StructAlloc
+            }
+        }
+        TypeKind::Enum(_) => {
+            if *old_ty == *new_ty {
+                Action::Copy {
+                    old_offset,
+                    size: old_ty.value_layout().size(),
+                }
+            } else if old_ty.name() == new_ty.name() {
+                // ASSUMPTION: When the name is the same, we are dealing with the same enum, but
+                // different variants
+                Action::EnumMap {
+                    old_ty: old_ty.clone(),
+                    old_offset,
+                }
+            } else {
+                Action::EnumInitialize
+            }
+        }
+        TypeKind::Pointer(_) => unreachable!(),
+        TypeKind::Array(new_array) => Action::ArrayFromValue {
+            element_action: Box::new(resolve_edit(old_ty, &new_array.element_type(), 0)),
+            old_offset,
+        },
+        TypeKind::Optional(_) => unreachable!(),
+    }
+}
+
 fn resolve_pointer_edit(_old_ty: &Type, _new_ty: &Type) -> Action {
     // Not supported in the language - yet
     unreachable!()
@@ -448,9 +674,12 @@ fn resolve_pointer_edit(_old_ty: &Type, _new_ty: &Type) -> Action {
 fn resolve_array_edit(old_array: &ArrayType, new_ty: &Type, old_offset: usize) -> Action {
     match &new_ty.kind() {
         TypeKind::Primitive(_) => resolve_array_to_primitive_edit(old_array, new_ty, old_offset),
-        TypeKind::Struct(_) => resolve_array_to_struct_edit(old_array, new_ty, old_offset),
+        TypeKind::Struct(_) | TypeKind::Enum(_) => {
+            resolve_array_to_struct_edit(old_array, new_ty, old_offset)
+        }
         TypeKind::Pointer(_) => unreachable!(),
         TypeKind::Array(new_array) => resolve_array_to_array_edit(old_array, new_array, old_offset),
+        TypeKind::Optional(_) => unreachable!(),
     }
 }
 
@@ -0,0 +1,346 @@
+use std::{
+    ffi::{c_void, CString},
+    mem::ManuallyDrop,
+    os::raw::c_char,
+    ptr,
+    sync::Arc,
+};
+
+use mun_abi::Guid;
+use mun_capi_utils::{mun_error_try, try_deref_mut, ErrorHandle};
+
+use crate::{
+    r#type::ffi::r#struct::{Field, Fields},
+    r#type::{EnumData, EnumType as RustEnumType, TypeDataStore, VariantData},
+    FieldData,
+};
+
+/// Additional information of an enum [`Type`].
+///
+/// Ownership of this type lies with the [`Type`] that created this instance. As long as the
+/// original type is not released through [`mun_type_release`] this type stays alive.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct EnumInfo(pub(super) *const c_void, pub(super) *const c_void);
+
+impl<'t> From<RustEnumType<'t>> for EnumInfo {
+    fn from(ty: RustEnumType<'t>) -> Self {
+        EnumInfo(
+            (ty.inner as *const EnumData).cast(),
+            (&ty.store as *const &Arc<TypeDataStore>).cast(),
+        )
+    }
+}
+
+impl EnumInfo {
+    /// Returns the enum info associated with the Type
+    unsafe fn inner(&self) -> Result<&EnumData, String> {
+        match (self.0 as *const EnumData).as_ref() {
+            Some(store) => Ok(store),
+            None => Err(String::from("null pointer")),
+        }
+    }
+}
+
+/// Returns the globally unique identifier (GUID) of the enum.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_enum_type_guid(ty: EnumInfo, guid: *mut Guid) -> ErrorHandle {
+    let ty = mun_error_try!(ty
+        .inner()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let guid = try_deref_mut!(guid);
+    *guid = ty.guid;
+    ErrorHandle::default()
+}
+
+/// Returns the size of the tag of the enum in bytes. The tag is stored at the start of the enum and
+/// holds the index of the variant that is currently stored in the enum.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_enum_type_tag_size(ty: EnumInfo, tag_size: *mut usize) -> ErrorHandle {
+    let ty = mun_error_try!(ty
+        .inner()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let tag_size = try_deref_mut!(tag_size);
+    *tag_size = ty.tag_size as usize;
+    ErrorHandle::default()
+}
+
+/// An array of [`Variant`]s.
+///
+/// This is backed by a dynamically allocated array. Ownership is transferred via this struct
+/// and its contents must be destroyed with [`mun_variants_destroy`].
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Variants {
+    pub variants: *const Variant,
+    pub count: usize,
+}
+
+/// Destroys the contents of a [`Variants`] struct.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `Variants` has been deallocated
+/// by a previous call to [`mun_variants_destroy`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_variants_destroy(variants: Variants) -> ErrorHandle {
+    if variants.variants.is_null() && variants.count > 0 {
+        return ErrorHandle::new("Variants contains invalid pointer");
+    } else if variants.count > 0 {
+        let _ = Vec::from_raw_parts(
+            variants.variants as *mut Variant,
+            variants.count,
+            variants.count,
+        );
+    }
+    ErrorHandle::default()
+}
+
+/// Retrieves all the variants of the specified enum type. The index of a variant in the returned
+/// array is equal to the value of its tag.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `EnumInfo` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_enum_type_variants(
+    ty: EnumInfo,
+    variants: *mut Variants,
+) -> ErrorHandle {
+    let inner = mun_error_try!(ty
+        .inner()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let variants = try_deref_mut!(variants);
+
+    // Get all variants
+    let mut variants_vec = Vec::from_iter(
+        inner
+            .variants
+            .iter()
+            .map(|variant| Variant((variant as *const VariantData).cast(), ty.1)),
+    );
+
+    // Ensures that the length and the capacity are the same
+    variants_vec.shrink_to_fit();
+    debug_assert!(variants_vec.len() == variants_vec.capacity());
+
+    // Transfer ownership over the FFI
+    let variants_vec = ManuallyDrop::new(variants_vec);
+    *variants = Variants {
+        variants: if variants_vec.is_empty() {
+            ptr::null()
+        } else {
+            variants_vec.as_ptr()
+        },
+        count: variants_vec.len(),
+    };
+
+    ErrorHandle::default()
+}
+
+/// Information of a variant of an enum [`Type`].
+///
+/// Ownership of this type lies with the [`Type`] that created this instance. As long as the
+/// original type is not released through [`mun_type_release`] this type stays alive.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Variant(*const c_void, *const c_void);
+
+impl Variant {
+    /// Returns the variant info associated with this instance
+    unsafe fn inner(&self) -> Result<&VariantData, String> {
+        match (self.0 as *const VariantData).as_ref() {
+            Some(info) => Ok(info),
+            None => Err(String::from("null pointer")),
+        }
+    }
+}
+
+/// Returns the name of the variant in the parent enum. Ownership of the name is transferred and
+/// must be destroyed with [`mun_string_destroy`]. If this function fails a nullptr is returned.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `Variant` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_variant_name(
+    variant: Variant,
+    name: *mut *const c_char,
+) -> ErrorHandle {
+    let inner = mun_error_try!(variant
+        .inner()
+        .map_err(|e| format!("invalid argument 'variant': {e}")));
+    let name = try_deref_mut!(name);
+    *name = CString::new(inner.name.clone()).unwrap().into_raw() as *const _;
+    ErrorHandle::default()
+}
+
+/// Retrieves all the fields of the specified variant. The offsets of the fields are relative to the
+/// start of the enum.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `Variant` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_variant_fields(variant: Variant, fields: *mut Fields) -> ErrorHandle {
+    let inner = mun_error_try!(variant
+        .inner()
+        .map_err(|e| format!("invalid argument 'variant': {e}")));
+    let fields = try_deref_mut!(fields);
+
+    // Get all fields
+    let mut fields_vec = Vec::from_iter(
+        inner
+            .fields
+            .iter()
+            .map(|field| Field((field as *const FieldData).cast(), variant.1)),
+    );
+
+    // Ensures that the length and the capacity are the same
+    fields_vec.shrink_to_fit();
+    debug_assert!(fields_vec.len() == fields_vec.capacity());
+
+    // Transfer ownership over the FFI
+    let fields_vec = ManuallyDrop::new(fields_vec);
+    *fields = Fields {
+        fields: if fields_vec.is_empty() {
+            ptr::null()
+        } else {
+            fields_vec.as_ptr()
+        },
+        count: fields_vec.len(),
+    };
+
+    ErrorHandle::default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CStr;
+    use std::{mem::MaybeUninit, ptr, slice};
+
+    use mun_capi_utils::{assert_error_snapshot, assert_getter1, mun_string_destroy};
+
+    use crate::r#type::ffi::r#struct::{
+        mun_field_name, mun_field_offset, mun_field_type, mun_fields_destroy,
+    };
+    use crate::{EnumTypeBuilder, HasStaticType};
+
+    use super::{
+        super::{mun_type_kind, mun_type_release, Type, TypeKind},
+        mun_enum_type_guid, mun_enum_type_tag_size, mun_enum_type_variants, mun_variant_fields,
+        mun_variant_name, mun_variants_destroy, EnumInfo,
+    };
+
+    unsafe fn enum_type(ty: Type) -> (Type, EnumInfo) {
+        assert_getter1!(mun_type_kind(ty, ty_kind));
+        let enum_ty = match ty_kind {
+            TypeKind::Enum(e) => e,
+            _ => panic!("invalid type kind for enum"),
+        };
+
+        (ty, enum_ty)
+    }
+
+    #[test]
+    fn test_mun_enum_type_guid() {
+        let rust_ty = EnumTypeBuilder::new("Foo")
+            .add_variant("A", [("0", i32::type_info().clone())])
+            .finish();
+
+        let guid = rust_ty.as_enum().unwrap().guid().clone();
+        let (ty, enum_ty) = unsafe { enum_type(rust_ty.into()) };
+
+        assert_getter1!(mun_enum_type_guid(enum_ty, ffi_guid));
+        assert_eq!(ffi_guid, guid);
+
+        assert!(unsafe { mun_type_release(ty) }.is_ok());
+    }
+
+    #[test]
+    fn test_mun_enum_type_guid_invalid_null() {
+        let mut guid = MaybeUninit::uninit();
+        assert_error_snapshot!(
+            unsafe {
+                mun_enum_type_guid(EnumInfo(ptr::null(), ptr::null()), guid.as_mut_ptr())
+            },
+            @r###""invalid argument \'ty\': null pointer""###
+        );
+    }
+
+    #[test]
+    fn test_mun_enum_type_tag_size() {
+        let rust_ty = EnumTypeBuilder::new("Foo")
+            .add_variant::<&str>("A", [])
+            .add_variant::<&str>("B", [])
+            .finish();
+
+        let (ty, enum_ty) = unsafe { enum_type(rust_ty.into()) };
+
+        assert_getter1!(mun_enum_type_tag_size(enum_ty, tag_size));
+        assert_eq!(tag_size, 1);
+
+        assert!(unsafe { mun_type_release(ty) }.is_ok());
+    }
+
+    #[test]
+    fn test_mun_enum_type_variants() {
+        let rust_ty = EnumTypeBuilder::new("Foo")
+            .add_variant::<&str>("A", [])
+            .add_variant("B", [("0", i32::type_info().clone())])
+            .finish();
+
+        let (ty, enum_ty) = unsafe { enum_type(rust_ty.into()) };
+
+        assert_getter1!(mun_enum_type_variants(enum_ty, variants));
+        assert_eq!(variants.count, 2);
+
+        let variants_slice = unsafe { slice::from_raw_parts(variants.variants, variants.count) };
+
+        assert_getter1!(mun_variant_name(variants_slice[1], variant_name));
+        assert_eq!(unsafe { CStr::from_ptr(variant_name) }.to_str(), Ok("B"));
+
+        assert_getter1!(mun_variant_fields(variants_slice[1], fields));
+        assert_eq!(fields.count, 1);
+
+        let fields_slice = unsafe { slice::from_raw_parts(fields.fields, fields.count) };
+        assert_getter1!(mun_field_name(fields_slice[0], field_name));
+        assert_getter1!(mun_field_offset(fields_slice[0], field_offset));
+        assert_getter1!(mun_field_type(fields_slice[0], field_type));
+        let field_type = unsafe { field_type.to_owned() }.expect("unable to convert to rust");
+
+        assert_eq!(unsafe { CStr::from_ptr(field_name) }.to_str(), Ok("0"));
+        assert_eq!(field_offset, 4);
+        assert_eq!(&field_type, i32::type_info());
+
+        unsafe { mun_string_destroy(field_name) };
+        unsafe { mun_string_destroy(variant_name) };
+        assert!(unsafe { mun_fields_destroy(fields) }.is_ok());
+        assert!(unsafe { mun_variants_destroy(variants) }.is_ok());
+        assert!(unsafe { mun_type_release(ty) }.is_ok());
+    }
+
+    #[test]
+    fn test_mun_enum_type_variants_invalid_null() {
+        let mut variants = MaybeUninit::uninit();
+        assert_error_snapshot!(
+            unsafe {
+                mun_enum_type_variants(EnumInfo(ptr::null(), ptr::null()), variants.as_mut_ptr())
+            },
+            @r###""invalid argument \'ty\': null pointer""###
+        );
+    }
+}
@@ -10,12 +10,19 @@ use std::{
 use mun_abi::Guid;
 use mun_capi_utils::{mun_error_try, try_deref_mut, ErrorHandle};
 pub use r#array::ArrayInfo;
+pub use r#enum::{EnumInfo, Variant, Variants};
+pub use r#optional::OptionalInfo;
 pub use r#pointer::PointerInfo;
 pub use r#struct::{Field, Fields, StructInfo};
 
-use crate::r#type::{ArrayData, PointerData, StructData, TypeData, TypeDataKind, TypeDataStore};
+use crate::r#type::{
+    ArrayData, EnumData, OptionalData, PointerData, StructData, TypeData, TypeDataKind,
+    TypeDataStore,
+};
 
 mod array;
+mod r#enum;
+mod optional;
 mod pointer;
 mod primitive;
 mod r#struct;
@@ -229,6 +236,25 @@ pub unsafe extern "C" fn mun_type_array_type(ty: Type, array_ty: *mut Type) -> E
     ErrorHandle::default()
 }
 
+/// Returns a new [`Type`] that is an optional reference to the specified type.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `Type`s have been deallocated in a
+/// previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_type_optional_type(ty: Type, optional_ty: *mut Type) -> ErrorHandle {
+    let optional_ty = try_deref_mut!(optional_ty);
+    let store = mun_error_try!(ty
+        .store()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let inner = mun_error_try!(ty
+        .inner()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    *optional_ty = inner.optional_type(&store).into();
+    ErrorHandle::default()
+}
+
 /// An enum that defines the kind of type.
 #[repr(u8)]
 pub enum TypeKind {
@@ -236,6 +262,8 @@ pub enum TypeKind {
     Pointer(r#pointer::PointerInfo),
     Struct(r#struct::StructInfo),
     Array(r#array::ArrayInfo),
+    Enum(r#enum::EnumInfo),
+    Optional(r#optional::OptionalInfo),
 }
 
 /// Returns information about what kind of type this is.
@@ -264,10 +292,18 @@ pub unsafe extern "C" fn mun_type_kind(ty: Type, kind: *mut TypeKind) -> ErrorHa
             (s as *const StructData).cast(),
             Arc::as_ptr(ManuallyDrop::deref(&store)) as *const _,
         )),
+        TypeDataKind::Enum(e) => TypeKind::Enum(EnumInfo(
+            (e as *const EnumData).cast(),
+            Arc::as_ptr(ManuallyDrop::deref(&store)) as *const _,
+        )),
         TypeDataKind::Array(a) => TypeKind::Array(ArrayInfo(
             (a as *const ArrayData).cast(),
             Arc::as_ptr(ManuallyDrop::deref(&store)) as *const _,
         )),
+        TypeDataKind::Optional(o) => TypeKind::Optional(OptionalInfo(
+            (o as *const OptionalData).cast(),
+            Arc::as_ptr(ManuallyDrop::deref(&store)) as *const _,
+        )),
         TypeDataKind::Uninitialized => unreachable!(),
     };
 
@@ -0,0 +1,129 @@
+use crate::ffi::Type;
+use crate::r#type::{OptionalData, Type as RustType, TypeDataStore};
+use mun_capi_utils::{mun_error_try, try_deref_mut, ErrorHandle};
+use std::ffi::c_void;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Additional information of an optional [`Type`].
+///
+/// Ownership of this type lies with the [`Type`] that created this instance. As long as the
+/// original type is not released through [`mun_type_release`] this type stays alive.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptionalInfo(pub(super) *const c_void, pub(super) *const c_void);
+
+impl<'t> From<crate::OptionalType<'t>> for OptionalInfo {
+    fn from(ty: crate::OptionalType<'t>) -> Self {
+        OptionalInfo(
+            (ty.inner as *const OptionalData).cast(),
+            (&ty.store as *const &Arc<TypeDataStore>).cast(),
+        )
+    }
+}
+
+impl OptionalInfo {
+    /// Returns the store associated with this instance
+    unsafe fn store(&self) -> Result<ManuallyDrop<Arc<TypeDataStore>>, String> {
+        if self.1.is_null() {
+            return Err(String::from("null pointer"));
+        }
+
+        Ok(ManuallyDrop::new(Arc::from_raw(
+            self.1 as *const TypeDataStore,
+        )))
+    }
+
+    /// Returns the struct info associated with the Type
+    unsafe fn inner(&self) -> Result<&OptionalData, String> {
+        match (self.0 as *const OptionalData).as_ref() {
+            Some(store) => Ok(store),
+            None => Err(String::from("null pointer")),
+        }
+    }
+}
+
+/// Returns the type that is referenced if the optional is not `nil`. Ownership is transferred if
+/// this function returns successfully.
+///
+/// # Safety
+///
+/// This function results in undefined behavior if the passed in `OptionalInfo` has been deallocated
+/// by a previous call to [`mun_type_release`].
+#[no_mangle]
+pub unsafe extern "C" fn mun_optional_type_inner_type(
+    ty: OptionalInfo,
+    inner_ty: *mut Type,
+) -> ErrorHandle {
+    let store = mun_error_try!(ty
+        .store()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let ty = mun_error_try!(ty
+        .inner()
+        .map_err(|e| format!("invalid argument 'ty': {e}")));
+    let inner_ty = try_deref_mut!(inner_ty);
+    *inner_ty = RustType::new_unchecked(ty.inner_ty, ManuallyDrop::deref(&store).clone()).into();
+    ErrorHandle::default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{mun_optional_type_inner_type, OptionalInfo};
+    use crate::ffi::{
+        mun_type_equal, mun_type_kind, mun_type_optional_type, mun_type_release, Type, TypeKind,
+    };
+    use crate::r#type::ffi::primitive::{mun_type_primitive, PrimitiveType};
+    use mun_capi_utils::{assert_error_snapshot, assert_getter1};
+    use std::{mem::MaybeUninit, ptr};
+
+    /// Returns the optional type of the specified type. Asserts if that fails.
+    unsafe fn optional_type(ty: Type) -> (Type, OptionalInfo) {
+        assert_getter1!(mun_type_optional_type(ty, optional_ty));
+
+        assert_getter1!(mun_type_kind(optional_ty, ty_kind));
+        let optional_info = match ty_kind {
+            TypeKind::Optional(o) => o,
+            _ => panic!("invalid type kind for optional"),
+        };
+
+        (optional_ty, optional_info)
+    }
+
+    #[test]
+    fn test_mun_optional_type_inner() {
+        let ffi_f32 = mun_type_primitive(PrimitiveType::F32);
+        let (ffi_f32_opt, optional_info) = unsafe { optional_type(ffi_f32) };
+
+        assert_getter1!(mun_optional_type_inner_type(optional_info, inner_ty));
+        assert!(unsafe { mun_type_equal(inner_ty, ffi_f32) });
+
+        unsafe { mun_type_release(inner_ty) };
+        unsafe { mun_type_release(ffi_f32_opt) };
+        unsafe { mun_type_release(ffi_f32) };
+    }
+
+    #[test]
+    fn test_mun_optional_type_inner_invalid_null() {
+        let mut inner_ty = MaybeUninit::uninit();
+        assert_error_snapshot!(
+            unsafe {
+                mun_optional_type_inner_type(
+                    OptionalInfo(ptr::null(), ptr::null()),
+                    inner_ty.as_mut_ptr(),
+                )
+            },
+            @r###""invalid argument \'ty\': null pointer""###
+        );
+
+        let ffi_f32 = mun_type_primitive(PrimitiveType::F32);
+        let (ffi_f32_opt, optional_info) = unsafe { optional_type(ffi_f32) };
+        assert_error_snapshot!(
+            unsafe { mun_optional_type_inner_type(optional_info, ptr::null_mut()) },
+            @r###""invalid argument \'inner_ty\': null pointer""###
+        );
+
+        unsafe { mun_type_release(ffi_f32_opt) };
+        unsafe { mun_type_release(ffi_f32) };
+    }
+}
@@ -20,6 +20,7 @@ pub enum PrimitiveType {
     F64,
     Empty,
     Void,
+    String,
 }
 
 /// Returns a [`Type`] that represents the specified primitive type.
@@ -41,6 +42,7 @@ pub extern "C" fn mun_type_primitive(primitive_type: PrimitiveType) -> Type {
         PrimitiveType::F64 => f64::type_info(),
         PrimitiveType::Empty => <()>::type_info(),
         PrimitiveType::Void => <std::ffi::c_void>::type_info(),
+        PrimitiveType::String => <str>::type_info(),
     }
     .clone()
     .into()
@@ -74,6 +76,12 @@ mod test {
         test_primitive::<()>(Empty);
         test_primitive::<std::ffi::c_void>(Void);
 
+        // Strings are stored as arrays of bytes
+        let ffi_ty = mun_type_primitive(String);
+        assert_getter1!(mun_type_kind(ffi_ty, ffi_kind));
+        assert!(matches!(ffi_kind, TypeKind::Array(_)));
+        assert_eq!(&unsafe { ffi_ty.to_owned() }.unwrap(), <str>::type_info());
+
         fn test_primitive<T: HasStaticType>(primitive_type: PrimitiveType) {
             let ffi_ty = mun_type_primitive(primitive_type);
 
@@ -199,7 +199,7 @@ pub unsafe extern "C" fn mun_struct_type_fields(
 /// original type is not released through [`mun_type_release`] this type stays alive.
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct Field(*const c_void, *const c_void);
+pub struct Field(pub(super) *const c_void, pub(super) *const c_void);
 
 impl Field {
     /// Returns the store associated with this instance
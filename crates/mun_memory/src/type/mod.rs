@@ -96,6 +96,16 @@ impl TypeDataStore {
                         }
                     }
                 }
+                TypeDataKind::Enum(e) => {
+                    for field in e.variants.iter().flat_map(|v| v.fields.iter()) {
+                        let mut field_ty = field.type_info;
+                        let field_ty = unsafe { field_ty.as_mut() };
+                        if field_ty.mark == Mark::Unused {
+                            field_ty.mark = Mark::Used;
+                            queue.push_back(field.type_info);
+                        }
+                    }
+                }
                 TypeDataKind::Pointer(p) => {
                     let mut pointee = p.pointee;
                     let pointee = unsafe { pointee.as_mut() };
@@ -112,6 +122,14 @@ impl TypeDataStore {
                         queue.push_back(a.element_ty);
                     }
                 }
+                TypeDataKind::Optional(o) => {
+                    let mut inner_ty = o.inner_ty;
+                    let inner_ty = unsafe { inner_ty.as_mut() };
+                    if inner_ty.mark == Mark::Unused {
+                        inner_ty.mark = Mark::Used;
+                        queue.push_back(o.inner_ty);
+                    }
+                }
                 TypeDataKind::Primitive(_) | TypeDataKind::Uninitialized => {}
             }
 
@@ -122,6 +140,7 @@ impl TypeDataStore {
                 &ty.mutable_pointer_type,
                 &ty.immutable_pointer_type,
                 &ty.array_type,
+                &ty.optional_type,
             ] {
                 let read_lock = indirection.read();
                 if let &Some(mut indirection_ref) = read_lock.deref() {
@@ -190,6 +209,7 @@ impl TypeDataStore {
                 abi::TypeDefinitionData::Struct(s) => {
                     StructData::try_from_abi(s, &type_table)?.into()
                 }
+                abi::TypeDefinitionData::Enum(e) => EnumData::try_from_abi(e, &type_table)?.into(),
             };
             inner_ty.data = type_data;
 
@@ -217,6 +237,7 @@ impl TypeDataStore {
             immutable_pointer_type: Default::default(),
             mutable_pointer_type: Default::default(),
             array_type: Default::default(),
+            optional_type: Default::default(),
             mark: Mark::Initializing,
         }));
 
@@ -307,8 +328,10 @@ impl Display for Type {
         match self.kind() {
             TypeKind::Primitive(_) => std::fmt::Display::fmt(self.name(), f),
             TypeKind::Struct(s) => std::fmt::Display::fmt(&s, f),
+            TypeKind::Enum(e) => std::fmt::Display::fmt(&e, f),
             TypeKind::Pointer(p) => std::fmt::Display::fmt(&p, f),
             TypeKind::Array(a) => std::fmt::Display::fmt(&a, f),
+            TypeKind::Optional(o) => std::fmt::Display::fmt(&o, f),
         }
     }
 }
@@ -374,6 +397,9 @@ pub struct TypeData {
     /// The type of an array of this type
     array_type: RwLock<Option<NonNull<TypeData>>>,
 
+    /// The type of an optional reference to this type
+    optional_type: RwLock<Option<NonNull<TypeData>>>,
+
     /// The state of instance with regards to its usage.
     mark: Mark,
 }
@@ -483,6 +509,56 @@ impl TypeData {
 
         ty
     }
+
+    /// Returns the type that represents an optional reference to this type
+    fn optional_type(&self, store: &Arc<TypeDataStore>) -> Type {
+        let cache_key = &self.optional_type;
+
+        {
+            let read_lock = cache_key.read();
+
+            // Fast path, the type already exists, return it immediately.
+            if let Some(ty) = read_lock.deref().as_ref() {
+                return Type {
+                    inner: *ty,
+                    store: store.clone(),
+                };
+            }
+        }
+
+        // No type is currently stored, allocate a new one.
+        let mut ty = store.allocate_uninitialized(
+            format!("?{}", self.name),
+            Layout::new::<*const std::ffi::c_void>(),
+            OptionalData {
+                inner_ty: self.into(),
+            }
+            .into(),
+        );
+
+        // Acquire the write lock
+        let mut write_lock = cache_key.write();
+
+        // Get the reference to the inner data, we need this to mark it properly.
+        let inner = unsafe { ty.inner.as_mut() };
+
+        // Recheck if another thread acquired the write lock in the mean time
+        if let Some(inner_ty) = write_lock.deref() {
+            inner.mark = Mark::Used;
+            return Type {
+                inner: *inner_ty,
+                store: store.clone(),
+            };
+        }
+
+        // We store the reference to the optional type in the current type. After which we mark
+        // the type as used. This ensures that the garbage collector never removes the type from
+        // under our noses.
+        *write_lock = Some(ty.inner);
+        inner.mark = Mark::Used;
+
+        ty
+    }
 }
 
 impl PartialEq for TypeData {
@@ -503,10 +579,14 @@ enum TypeDataKind {
     Primitive(abi::Guid),
     /// Struct types (i.e. record, tuple, or unit structs)
     Struct(StructData),
+    /// Enum types (i.e. tagged unions of record, tuple, or unit variants)
+    Enum(EnumData),
     /// A pointer to another type
     Pointer(PointerData),
     /// An array
     Array(ArrayData),
+    /// An optional reference to another type
+    Optional(OptionalData),
     /// Indicates that the type has been allocated but it has not yet been initialized,
     /// this indicates that it still needs to be properly initialized.
     Uninitialized,
@@ -518,10 +598,14 @@ pub enum TypeKind<'t> {
     Primitive(&'t abi::Guid),
     /// Struct types (i.e. record, tuple, or unit structs)
     Struct(StructType<'t>),
+    /// Enum types (i.e. tagged unions of record, tuple, or unit variants)
+    Enum(EnumType<'t>),
     /// A pointer to another type
     Pointer(PointerType<'t>),
     /// An array of values
     Array(ArrayType<'t>),
+    /// An optional reference to another type, which can be `nil`
+    Optional(OptionalType<'t>),
 }
 
 /// A linked version of [`mun_abi::StructInfo`] that has resolved all occurrences of `TypeId` with `TypeInfo`.
@@ -571,7 +655,7 @@ impl<'t> StructType<'t> {
     /// Returns an iterator over all fields
     pub fn fields(&self) -> Fields<'t> {
         Fields {
-            inner: self.inner,
+            inner: &self.inner.fields,
             store: self.store,
         }
     }
@@ -590,22 +674,22 @@ impl<'t> Display for StructType<'t> {
     }
 }
 
-/// A collection of fields of a struct
+/// A collection of fields of a struct or an enum variant
 #[derive(Copy, Clone)]
 pub struct Fields<'t> {
-    inner: &'t StructData,
+    inner: &'t [FieldData],
     store: &'t Arc<TypeDataStore>,
 }
 
 impl<'t> Fields<'t> {
     /// Returns the number of fields in the struct
     pub fn len(&self) -> usize {
-        self.inner.fields.len()
+        self.inner.len()
     }
 
     /// Returns the field at the given index, or `None` if `index` exceeds the number of fields.
     pub fn get(&self, index: usize) -> Option<Field<'t>> {
-        self.inner.fields.get(index).map(|field| Field {
+        self.inner.get(index).map(|field| Field {
             inner: field,
             store: self.store,
         })
@@ -620,7 +704,7 @@ impl<'t> Fields<'t> {
     /// Returns an iterator over all fields
     pub fn iter(&self) -> FieldsIterator<'t> {
         FieldsIterator {
-            iter: self.inner.fields.iter(),
+            iter: self.inner.iter(),
             store: self.store,
         }
     }
@@ -632,7 +716,7 @@ impl<'t> IntoIterator for Fields<'t> {
 
     fn into_iter(self) -> Self::IntoIter {
         FieldsIterator {
-            iter: self.inner.fields.iter(),
+            iter: self.inner.iter(),
             store: self.store,
         }
     }
@@ -654,6 +738,156 @@ impl<'t> Iterator for FieldsIterator<'t> {
     }
 }
 
+/// A linked version of [`mun_abi::EnumDefinition`] that has resolved all occurrences of `TypeId`
+/// with `TypeInfo`.
+#[derive(Clone, Debug)]
+struct EnumData {
+    /// The unique identifier of this enum
+    pub guid: abi::Guid,
+    /// Enum variants
+    pub variants: Vec<VariantData>,
+    /// The size of the tag in bytes
+    pub tag_size: u8,
+}
+
+/// A linked version of a single enum variant.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct VariantData {
+    /// The variant's name
+    pub name: String,
+    /// The variant's fields. The offsets of the fields are relative to the start of the enum.
+    pub fields: Vec<FieldData>,
+}
+
+/// Reference information of an enum
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct EnumType<'t> {
+    inner: &'t EnumData,
+    store: &'t Arc<TypeDataStore>,
+}
+
+impl<'t> EnumType<'t> {
+    /// Returns the unique identifier of this enum
+    pub fn guid<'s>(&'s self) -> &'t abi::Guid
+    where
+        't: 's,
+    {
+        &self.inner.guid
+    }
+
+    /// Returns the size of the tag in bytes. The tag is stored at the start of the enum.
+    pub fn tag_size(&self) -> usize {
+        self.inner.tag_size as usize
+    }
+
+    /// Returns the number of variants
+    pub fn num_variants(&self) -> usize {
+        self.inner.variants.len()
+    }
+
+    /// Returns the variant at the given index, or `None` if `index` exceeds the number of variants.
+    pub fn variant(&self, index: usize) -> Option<Variant<'t>> {
+        self.inner.variants.get(index).map(|variant| Variant {
+            inner: variant,
+            index,
+            store: self.store,
+        })
+    }
+
+    /// Returns the variant with the given name, or `None` if no such variant exists.
+    pub fn find_variant_by_name(&self, name: impl AsRef<str>) -> Option<Variant<'t>> {
+        let variant_name = name.as_ref();
+        self.variants()
+            .find(|variant| variant.name() == variant_name)
+    }
+
+    /// Returns an iterator over all variants
+    pub fn variants(&self) -> impl Iterator<Item = Variant<'t>> + 't {
+        let store = self.store;
+        self.inner
+            .variants
+            .iter()
+            .enumerate()
+            .map(move |(index, variant)| Variant {
+                inner: variant,
+                index,
+                store,
+            })
+    }
+
+    /// Reads the tag of the enum value stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of this enum type.
+    pub unsafe fn read_tag(&self, ptr: NonNull<u8>) -> usize {
+        match self.inner.tag_size {
+            1 => *ptr.as_ptr() as usize,
+            2 => *ptr.cast::<u16>().as_ptr() as usize,
+            size => unreachable!("invalid enum tag size: {}", size),
+        }
+    }
+
+    /// Writes the `tag` of the enum value stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to memory that can hold a value of this enum type.
+    pub unsafe fn write_tag(&self, ptr: NonNull<u8>, tag: usize) {
+        match self.inner.tag_size {
+            1 => *ptr.as_ptr() = tag.try_into().expect("enum tag out of range"),
+            2 => *ptr.cast::<u16>().as_ptr() = tag.try_into().expect("enum tag out of range"),
+            size => unreachable!("invalid enum tag size: {}", size),
+        }
+    }
+}
+
+impl<'t> Display for EnumType<'t> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("enum {")?;
+        self.variants().try_for_each(|variant| {
+            f.write_fmt(format_args!("{} {{", variant.name()))?;
+            variant.fields().iter().try_for_each(|field| {
+                f.write_fmt(format_args!("{}: {}, ", field.name(), field.ty()))
+            })?;
+            f.write_str("}, ")
+        })?;
+        f.write_str("}")
+    }
+}
+
+/// Reference information of an enum variant
+#[derive(Copy, Clone)]
+pub struct Variant<'t> {
+    inner: &'t VariantData,
+    index: usize,
+    store: &'t Arc<TypeDataStore>,
+}
+
+impl<'t> Variant<'t> {
+    /// Returns the name of the variant
+    pub fn name<'s>(&'s self) -> &'t str
+    where
+        't: 's,
+    {
+        self.inner.name.as_str()
+    }
+
+    /// Returns the index of the variant in its parent enum. This is also the value of its tag.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns an iterator over all fields of the variant
+    pub fn fields(&self) -> Fields<'t> {
+        Fields {
+            inner: &self.inner.fields,
+            store: self.store,
+        }
+    }
+}
+
 /// A linked version of [`mun_abi::PointerInfo`] that has resolved all occurrences of `TypeId` with `TypeInfo`.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 struct PointerData {
@@ -711,6 +945,19 @@ impl PartialEq for StructData {
 }
 impl Eq for StructData {}
 
+impl Hash for EnumData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.guid.hash(state)
+    }
+}
+
+impl PartialEq for EnumData {
+    fn eq(&self, other: &Self) -> bool {
+        self.guid == other.guid
+    }
+}
+impl Eq for EnumData {}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 struct ArrayData {
     pub element_ty: NonNull<TypeData>,
@@ -740,12 +987,46 @@ impl<'t> Display for ArrayType<'t> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct OptionalData {
+    pub inner_ty: NonNull<TypeData>,
+}
+
+/// Reference information of an optional reference
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptionalType<'t> {
+    inner: &'t OptionalData,
+    store: &'t Arc<TypeDataStore>,
+}
+
+impl<'t> OptionalType<'t> {
+    /// Returns the type that is referenced if the optional is not `nil`
+    pub fn inner_type(&self) -> Type {
+        // Safety: this operation is safe due to the lifetime constraints on this type
+        unsafe { Type::new_unchecked(self.inner.inner_ty, self.store.clone()) }
+    }
+}
+
+impl<'t> Display for OptionalType<'t> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("?")?;
+        std::fmt::Display::fmt(&self.inner_type(), f)
+    }
+}
+
 impl From<StructData> for TypeDataKind {
     fn from(s: StructData) -> Self {
         TypeDataKind::Struct(s)
     }
 }
 
+impl From<EnumData> for TypeDataKind {
+    fn from(e: EnumData) -> Self {
+        TypeDataKind::Enum(e)
+    }
+}
+
 impl From<PointerData> for TypeDataKind {
     fn from(p: PointerData) -> Self {
         TypeDataKind::Pointer(p)
@@ -758,6 +1039,12 @@ impl From<ArrayData> for TypeDataKind {
     }
 }
 
+impl From<OptionalData> for TypeDataKind {
+    fn from(o: OptionalData) -> Self {
+        TypeDataKind::Optional(o)
+    }
+}
+
 impl Hash for TypeData {
     fn hash<H: Hasher>(&self, state: &mut H) {
         Hash::hash(&self.data, state);
@@ -799,6 +1086,40 @@ impl Type {
         )
     }
 
+    /// Constructs a new enum type
+    pub fn new_enum(
+        name: impl Into<String>,
+        layout: Layout,
+        guid: abi::Guid,
+        tag_size: u8,
+        variants: impl IntoIterator<Item = (String, Vec<(String, Type, u16)>)>,
+    ) -> Type {
+        let variants = variants
+            .into_iter()
+            .map(|(name, fields)| VariantData {
+                name,
+                fields: fields
+                    .into_iter()
+                    .map(|(name, ty, offset)| FieldData {
+                        name,
+                        type_info: ty.inner,
+                        offset,
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        GLOBAL_TYPE_STORE.allocate(
+            name,
+            layout,
+            EnumData {
+                guid,
+                variants,
+                tag_size,
+            }
+            .into(),
+        )
+    }
+
     /// Returns a reference to the [`TypeInner`]
     fn inner(&self) -> &TypeData {
         // Safety: taking the reference is always ok because the garbage collector ensures that as
@@ -832,8 +1153,9 @@ impl Type {
     pub fn is_reference_type(&self) -> bool {
         match self.kind() {
             TypeKind::Primitive(_) | TypeKind::Pointer(_) => false,
-            TypeKind::Array(_) => true,
+            TypeKind::Array(_) | TypeKind::Optional(_) => true,
             TypeKind::Struct(s) => s.is_gc_struct(),
+            TypeKind::Enum(_) => false,
         }
     }
 
@@ -842,8 +1164,9 @@ impl Type {
     pub fn is_value_type(&self) -> bool {
         match self.kind() {
             TypeKind::Primitive(_) | TypeKind::Pointer(_) => true,
-            TypeKind::Array(_) => false,
+            TypeKind::Array(_) | TypeKind::Optional(_) => false,
             TypeKind::Struct(s) => s.is_value_struct(),
+            TypeKind::Enum(_) => true,
         }
     }
 
@@ -854,7 +1177,7 @@ impl Type {
     /// assert!(i64::type_info().equals::<i64>());
     /// assert!(!i64::type_info().equals::<f64>())
     /// ```
-    pub fn equals<T: HasStaticType>(&self) -> bool {
+    pub fn equals<T: HasStaticType + ?Sized>(&self) -> bool {
         T::type_info() == self
     }
 
@@ -868,6 +1191,11 @@ impl Type {
         matches!(self.kind(), TypeKind::Struct(_))
     }
 
+    /// Returns whether this is an enum type.
+    pub fn is_enum(&self) -> bool {
+        matches!(self.kind(), TypeKind::Enum(_))
+    }
+
     /// Returns whether this is a pointer type.
     pub fn is_pointer(&self) -> bool {
         matches!(self.kind(), TypeKind::Pointer(_))
@@ -878,6 +1206,11 @@ impl Type {
         matches!(self.kind(), TypeKind::Array(_))
     }
 
+    /// Returns whether this is an optional type.
+    pub fn is_optional(&self) -> bool {
+        matches!(self.kind(), TypeKind::Optional(_))
+    }
+
     /// Returns the kind of the type
     pub fn kind(&self) -> TypeKind<'_> {
         match &self.inner().data {
@@ -886,6 +1219,10 @@ impl Type {
                 inner: s,
                 store: &self.store,
             }),
+            TypeDataKind::Enum(e) => TypeKind::Enum(EnumType {
+                inner: e,
+                store: &self.store,
+            }),
             TypeDataKind::Pointer(p) => TypeKind::Pointer(PointerType {
                 inner: p,
                 store: &self.store,
@@ -894,6 +1231,10 @@ impl Type {
                 inner: a,
                 store: &self.store,
             }),
+            TypeDataKind::Optional(o) => TypeKind::Optional(OptionalType {
+                inner: o,
+                store: &self.store,
+            }),
             TypeDataKind::Uninitialized => {
                 unreachable!("should never be able to query the kind of an uninitialized type")
             }
@@ -904,8 +1245,8 @@ impl Type {
     /// refer to another type like a pointer.
     pub fn is_concrete(&self) -> bool {
         match self.kind() {
-            TypeKind::Primitive(_) | TypeKind::Struct(_) => true,
-            TypeKind::Pointer(_) | TypeKind::Array(_) => false,
+            TypeKind::Primitive(_) | TypeKind::Struct(_) | TypeKind::Enum(_) => true,
+            TypeKind::Pointer(_) | TypeKind::Array(_) | TypeKind::Optional(_) => false,
         }
     }
 
@@ -914,7 +1255,8 @@ impl Type {
         match self.kind() {
             TypeKind::Primitive(g) => Some(g),
             TypeKind::Struct(s) => Some(s.guid()),
-            TypeKind::Pointer(_) | TypeKind::Array(_) => None,
+            TypeKind::Enum(e) => Some(e.guid()),
+            TypeKind::Pointer(_) | TypeKind::Array(_) | TypeKind::Optional(_) => None,
         }
     }
 
@@ -927,6 +1269,15 @@ impl Type {
         }
     }
 
+    /// Retrieves the type's enum information, if available.
+    pub fn as_enum(&self) -> Option<EnumType<'_>> {
+        if let TypeKind::Enum(e) = self.kind() {
+            Some(e)
+        } else {
+            None
+        }
+    }
+
     /// Retrieves the type's pointer information, if available.
     pub fn as_pointer(&self) -> Option<PointerType<'_>> {
         if let TypeKind::Pointer(p) = self.kind() {
@@ -945,6 +1296,15 @@ impl Type {
         }
     }
 
+    /// Retrieves the type's optional information, if available.
+    pub fn as_optional(&self) -> Option<OptionalType<'_>> {
+        if let TypeKind::Optional(o) = self.kind() {
+            Some(o)
+        } else {
+            None
+        }
+    }
+
     /// Tries to convert multiple [`abi::TypeDefinition`] to internal type representations. If
     /// the conversion succeeds an updated [`TypeTable`] is returned
     pub fn try_from_abi<'abi>(
@@ -964,6 +1324,11 @@ impl Type {
         self.inner().array_type(&self.store)
     }
 
+    /// Returns the type that represents an optional reference to this type
+    pub fn optional_type(&self) -> Type {
+        self.inner().optional_type(&self.store)
+    }
+
     /// Consumes the `Type`, returning a wrapped raw pointer.
     ///
     /// After calling this function, the caller is responsible for the memory previously managed by
@@ -1028,6 +1393,48 @@ impl StructData {
     }
 }
 
+impl EnumData {
+    /// Tries to convert from an `abi::EnumDefinition`.
+    fn try_from_abi<'abi>(
+        enum_info: &'abi abi::EnumDefinition<'abi>,
+        type_table: &TypeTable,
+    ) -> Result<EnumData, TryFromAbiError<'abi>> {
+        let variants: Result<Vec<VariantData>, TryFromAbiError> = enum_info
+            .variants()
+            .iter()
+            .map(|variant| {
+                let fields: Result<Vec<FieldData>, TryFromAbiError> = izip!(
+                    variant.field_names(),
+                    variant.field_types(),
+                    variant.field_offsets()
+                )
+                .map(|(name, type_id, offset)| {
+                    type_table
+                        .find_type_info_by_id(type_id)
+                        .ok_or_else(|| TryFromAbiError::UnknownTypeId(type_id.clone()))
+                        .map(|type_info| FieldData {
+                            name: name.to_owned(),
+                            type_info: type_info.inner,
+                            offset: *offset,
+                        })
+                })
+                .collect();
+
+                fields.map(|fields| VariantData {
+                    name: variant.name().to_owned(),
+                    fields,
+                })
+            })
+            .collect();
+
+        variants.map(|variants| EnumData {
+            guid: enum_info.guid,
+            variants,
+            tag_size: enum_info.tag_size,
+        })
+    }
+}
+
 /// A linked version of a struct field.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FieldData {
@@ -1157,6 +1564,130 @@ impl StructTypeBuilder {
     }
 }
 
+/// A helper struct to create an enum type.
+pub struct EnumTypeBuilder {
+    /// The name of the enum type
+    name: String,
+
+    /// The variants of the enum and their fields
+    variants: Vec<(String, Vec<(String, Type)>)>,
+
+    /// Optional explicit type of the enum
+    guid: Option<abi::Guid>,
+}
+
+impl EnumTypeBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            variants: Vec::new(),
+            guid: None,
+        }
+    }
+
+    /// Adds a variant with the specified fields to the enum
+    pub fn add_variant<N: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        fields: impl IntoIterator<Item = (N, Type)>,
+    ) -> Self {
+        self.variants.push((
+            name.into(),
+            fields
+                .into_iter()
+                .map(|(name, ty)| (name.into(), ty))
+                .collect(),
+        ));
+        self
+    }
+
+    /// Finishes building the enum returning the corresponding [`Type`].
+    ///
+    /// The enum is laid out as a tag followed by a payload. The payload starts at the first offset
+    /// after the tag that satisfies the alignment of all fields. The fields of every variant are
+    /// laid out consecutively from the start of the payload.
+    pub fn finish(self) -> Type {
+        let tag_layout = if self.variants.len() <= (u8::MAX as usize + 1) {
+            Layout::new::<u8>()
+        } else {
+            Layout::new::<u16>()
+        };
+
+        let payload_align = self
+            .variants
+            .iter()
+            .flat_map(|(_, fields)| fields.iter())
+            .map(|(_, ty)| ty.reference_layout().align())
+            .max()
+            .unwrap_or(1);
+        let (_, payload_offset) = tag_layout
+            .extend(Layout::from_size_align(0, payload_align).expect("invalid payload alignment"))
+            .expect("cannot extend enum layout");
+
+        let mut layout = tag_layout
+            .align_to(payload_align)
+            .expect("invalid enum alignment");
+        let variants = self
+            .variants
+            .into_iter()
+            .map(|(name, fields)| {
+                let mut variant_layout =
+                    Layout::from_size_align(0, 1).expect("invalid default layout");
+                let fields = fields
+                    .into_iter()
+                    .map(|(name, ty)| {
+                        let (new_layout, offset) = variant_layout
+                            .extend(ty.reference_layout())
+                            .expect("cannot extend variant layout");
+                        variant_layout = new_layout;
+                        (name, ty, payload_offset + offset)
+                    })
+                    .collect::<Vec<_>>();
+                let size = layout.size().max(payload_offset + variant_layout.size());
+                layout = Layout::from_size_align(size, layout.align()).expect("invalid enum size");
+                (name, fields)
+            })
+            .collect::<Vec<_>>();
+        let layout = layout.pad_to_align();
+
+        let guid = if let Some(guid) = self.guid {
+            guid
+        } else {
+            let guid_string = build_enum_guid_string(
+                &self.name,
+                variants.iter().map(|(name, fields)| {
+                    (
+                        name.as_str(),
+                        fields
+                            .iter()
+                            .map(|(name, ty, offset)| (name, Cow::Borrowed(ty), *offset))
+                            .collect::<Vec<_>>(),
+                    )
+                }),
+            );
+            abi::Guid::from_str(&guid_string)
+        };
+
+        Type::new_enum(
+            self.name,
+            layout,
+            guid,
+            tag_layout.size() as u8,
+            variants.into_iter().map(|(name, fields)| {
+                (
+                    name,
+                    fields
+                        .into_iter()
+                        .map(|(name, ty, offset)| {
+                            (name, ty, offset.try_into().expect("offset too large"))
+                        })
+                        .collect(),
+                )
+            }),
+        )
+    }
+}
+
 /// Constructs a string that unique identifies a struct with the given name and fields.
 fn build_struct_guid_string<'t, N: AsRef<str> + 't>(
     name: &str,
@@ -1176,6 +1707,31 @@ fn build_struct_guid_string<'t, N: AsRef<str> + 't>(
     )
 }
 
+/// Constructs a string that unique identifies an enum with the given name and variants.
+fn build_enum_guid_string<'t, N: AsRef<str> + 't>(
+    name: &str,
+    variants: impl Iterator<Item = (&'t str, Vec<(N, Cow<'t, Type>, usize)>)>,
+) -> String {
+    let variants: Vec<String> = variants
+        .map(|(name, fields)| {
+            let fields: Vec<String> = fields
+                .into_iter()
+                .map(|(name, ty, _offset)| {
+                    let ty_string = build_type_guid_string(ty.as_ref());
+                    format!("{}: {}", name.as_ref(), ty_string)
+                })
+                .collect();
+            format!("{}{{{}}}", name, fields.join(","))
+        })
+        .collect();
+
+    format!(
+        "enum {name}{{{variants}}}",
+        name = name,
+        variants = variants.join(",")
+    )
+}
+
 /// Constructs a string that unique identifies the specified type.
 fn build_type_guid_string(ty: &Type) -> String {
     match ty.kind() {
@@ -1191,7 +1747,22 @@ fn build_type_guid_string(ty: &Type) -> String {
                 )
             }
         }
-        TypeKind::Array(_) | TypeKind::Primitive(_) | TypeKind::Pointer(_) => ty.name().to_owned(),
+        TypeKind::Enum(e) => build_enum_guid_string(
+            ty.name(),
+            e.variants().map(|v| {
+                (
+                    v.name(),
+                    v.fields()
+                        .iter()
+                        .map(|f| (f.name(), Cow::Owned(f.ty()), f.offset()))
+                        .collect::<Vec<_>>(),
+                )
+            }),
+        ),
+        TypeKind::Array(_)
+        | TypeKind::Optional(_)
+        | TypeKind::Primitive(_)
+        | TypeKind::Pointer(_) => ty.name().to_owned(),
     }
 }
 
@@ -1239,6 +1810,25 @@ impl_primitive_type!(
     std::ffi::c_void
 );
 
+/// Strings are stored on the heap as arrays of UTF-8 encoded bytes. The string type therefore
+/// shares its memory layout with `[u8]`, but it has its own name and is identified by the
+/// primitive `core::string` GUID.
+impl HasStaticType for str {
+    fn type_info() -> &'static Type {
+        static TYPE_INFO: once_cell::sync::OnceCell<Type> = once_cell::sync::OnceCell::new();
+        TYPE_INFO.get_or_init(|| {
+            GLOBAL_TYPE_STORE.allocate(
+                <str as abi::PrimitiveType>::name(),
+                Layout::new::<*const std::ffi::c_void>(),
+                ArrayData {
+                    element_ty: u8::type_info().inner,
+                }
+                .into(),
+            )
+        })
+    }
+}
+
 /// Every type that has at least a type name also has a valid pointer type name
 impl<T: HasStaticType + 'static> HasStaticType for *mut T {
     fn type_info() -> &'static Type {
@@ -26,6 +26,9 @@ impl TypeTable {
             abi::TypeId::Array(a) => self
                 .find_type_info_by_id(a.element)
                 .map(|ty| ty.array_type()),
+            abi::TypeId::Optional(o) => self
+                .find_type_info_by_id(o.inner)
+                .map(|ty| ty.optional_type()),
         }
     }
 
@@ -113,6 +116,13 @@ impl Default for TypeTable {
         type_table.insert_static_type::<()>();
         type_table.insert_static_type::<std::ffi::c_void>();
 
+        // Strings are stored as byte arrays, so they are not concrete types by themselves. They
+        // are still identified by their primitive GUID though.
+        type_table.insert_concrete_type(
+            *<str as abi::PrimitiveType>::guid(),
+            str::type_info().clone(),
+        );
+
         type_table
     }
 }
@@ -1,6 +1,6 @@
 use super::util::EventAggregator;
 use mun_memory::{
-    gc::{Event, GcRootPtr, GcRuntime, MarkSweep},
+    gc::{Array, Event, GcRootPtr, GcRuntime, HasIndirectionPtr, MarkSweep},
     HasStaticType,
 };
 use std::sync::Arc;
@@ -66,3 +66,41 @@ fn collect_rooted() {
     assert_eq!(events.next(), Some(Event::End));
     assert_eq!(events.next(), None);
 }
+
+#[test]
+fn reserve_array() {
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let array_type = i64::type_info().array_type();
+    let array = runtime.alloc_array(&array_type, 2);
+    let handle = array.as_raw();
+
+    // Initialize the elements of the array
+    let elements: Vec<_> = array.elements().collect();
+    for (value, element) in elements.into_iter().enumerate() {
+        unsafe { *element.cast::<i64>().as_mut() = value as i64 + 1 };
+    }
+
+    // Reserving less than the unused capacity doesn't reallocate
+    let data = unsafe { handle.deref::<u8>() };
+    runtime.reserve_array(handle, 0);
+    assert_eq!(unsafe { handle.deref::<u8>() }, data);
+
+    // Reserving more grows the capacity, but retains the elements
+    runtime.reserve_array(handle, 3);
+    let array = runtime
+        .array(handle)
+        .expect("handle must refer to an array");
+    assert_eq!(array.length(), 2);
+    assert!(array.capacity() >= 5);
+    assert_eq!(
+        array
+            .elements()
+            .map(|element| unsafe { *element.cast::<i64>().as_ref() })
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    let mut events = runtime.observer().take_all().into_iter();
+    assert_eq!(events.next(), Some(Event::Allocation(handle)));
+    assert_eq!(events.next(), None);
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use mun_memory::{
+    gc::{Event, GcPtr, GcRootPtr, GcRuntime, HasIndirectionPtr, MarkSweep, TypeTrace},
+    mapping::{Mapping, MemoryMapper},
+    EnumTypeBuilder, HasStaticType, StructTypeBuilder,
+};
+
+use super::util::EventAggregator;
+
+#[repr(C)]
+struct OptionalBar {
+    tag: u8,
+    bar: GcPtr,
+}
+
+#[test]
+fn trace_active_variant() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+
+    let enum_type_info = EnumTypeBuilder::new("OptionalBar")
+        .add_variant::<&str>("None", [])
+        .add_variant("Some", [("0", bar_type_info.clone())])
+        .finish();
+
+    let foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", enum_type_info)
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut foo_handle = runtime.alloc(&foo_type_info);
+    let bar_handle = runtime.alloc(&bar_type_info);
+
+    // The `None` variant doesn't reference anything
+    let mut trace = foo_type_info.trace(foo_handle);
+    assert_eq!(trace.next(), None);
+
+    // Assign `Some(bar)` to foo.bar
+    unsafe {
+        *foo_handle.deref_mut::<OptionalBar>() = OptionalBar {
+            tag: 1,
+            bar: bar_handle,
+        };
+    }
+
+    let mut trace = foo_type_info.trace(foo_handle);
+    assert_eq!(trace.next(), Some(bar_handle));
+    assert_eq!(trace.next(), None)
+}
+
+#[test]
+fn trace_collect_active_variant() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+
+    let enum_type_info = EnumTypeBuilder::new("OptionalBar")
+        .add_variant::<&str>("None", [])
+        .add_variant("Some", [("0", bar_type_info.clone())])
+        .finish();
+
+    let foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", enum_type_info)
+        .finish();
+
+    let runtime = Arc::new(MarkSweep::<EventAggregator<Event>>::default());
+    let mut foo_ptr = GcRootPtr::new(&runtime, runtime.alloc(&foo_type_info));
+    let bar = runtime.alloc(&bar_type_info);
+
+    // Assign `Some(bar)` to foo.bar
+    unsafe {
+        *foo_ptr.deref_mut::<OptionalBar>() = OptionalBar { tag: 1, bar };
+    }
+
+    // Collect garbage, bar should not be collected
+    runtime.collect();
+
+    // Assign `None` to foo.bar
+    unsafe {
+        (*foo_ptr.deref_mut::<OptionalBar>()).tag = 0;
+    }
+
+    // Collect garbage, bar should be collected
+    runtime.collect();
+
+    let mut events = runtime.observer().take_all().into_iter();
+    assert_eq!(events.next(), Some(Event::Allocation(foo_ptr.handle())));
+    assert_eq!(events.next(), Some(Event::Allocation(bar)));
+    assert_eq!(events.next(), Some(Event::Start));
+    assert_eq!(events.next(), Some(Event::End));
+    assert_eq!(events.next(), Some(Event::Start));
+    assert_eq!(events.next(), Some(Event::Deallocation(bar)));
+    assert_eq!(events.next(), Some(Event::End));
+    assert_eq!(events.next(), None);
+}
+
+#[repr(C)]
+struct Shape {
+    tag: u8,
+    value: f64,
+}
+
+#[test]
+fn map_reordered_variants() {
+    let old_enum = EnumTypeBuilder::new("Shape")
+        .add_variant::<&str>("Empty", [])
+        .add_variant("Circle", [("radius", f64::type_info().clone())])
+        .finish();
+    let old_struct = StructTypeBuilder::new("Foo")
+        .add_field("shape", old_enum.clone())
+        .finish();
+
+    let new_enum = EnumTypeBuilder::new("Shape")
+        .add_variant("Circle", [("radius", f64::type_info().clone())])
+        .add_variant("Square", [("width", f64::type_info().clone())])
+        .add_variant::<&str>("Empty", [])
+        .finish();
+    let new_struct = StructTypeBuilder::new("Foo")
+        .add_field("shape", new_enum.clone())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut circle = runtime.alloc(&old_struct);
+    let mut empty = runtime.alloc(&old_struct);
+    unsafe {
+        *circle.deref_mut::<Shape>() = Shape { tag: 1, value: 4.0 };
+        *empty.deref_mut::<Shape>() = Shape { tag: 0, value: 0.0 };
+    }
+
+    let mapping = Mapping::new(&[old_enum, old_struct], &[new_enum, new_struct.clone()]);
+    assert!(runtime.map_memory(mapping).is_empty());
+
+    assert_eq!(runtime.ptr_type(circle), new_struct);
+    let circle = unsafe { circle.deref::<Shape>().as_ref() }.unwrap();
+    assert_eq!(circle.tag, 0);
+    assert_eq!(circle.value, 4.0);
+
+    let empty = unsafe { empty.deref::<Shape>().as_ref() }.unwrap();
+    assert_eq!(empty.tag, 2);
+}
+
+#[test]
+fn map_deleted_variant() {
+    let old_enum = EnumTypeBuilder::new("Shape")
+        .add_variant::<&str>("Empty", [])
+        .add_variant("Circle", [("radius", f64::type_info().clone())])
+        .finish();
+    let old_struct = StructTypeBuilder::new("Foo")
+        .add_field("shape", old_enum.clone())
+        .finish();
+
+    let new_enum = EnumTypeBuilder::new("Shape")
+        .add_variant::<&str>("Empty", [])
+        .add_variant("Square", [("width", f64::type_info().clone())])
+        .finish();
+    let new_struct = StructTypeBuilder::new("Foo")
+        .add_field("shape", new_enum.clone())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut circle = runtime.alloc(&old_struct);
+    unsafe {
+        *circle.deref_mut::<Shape>() = Shape { tag: 1, value: 4.0 };
+    }
+
+    let mapping = Mapping::new(&[old_enum, old_struct], &[new_enum, new_struct]);
+    assert!(runtime.map_memory(mapping).is_empty());
+
+    // Values of deleted variants are reset to the first variant
+    let circle = unsafe { circle.deref::<Shape>().as_ref() }.unwrap();
+    assert_eq!(circle.tag, 0);
+    assert_eq!(circle.value, 0.0);
+}
+
+#[test]
+fn map_deleted_variant_allocates_first_variant() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+
+    let old_enum = EnumTypeBuilder::new("Value")
+        .add_variant("Bar", [("0", bar_type_info.clone())])
+        .add_variant("Int", [("0", i64::type_info().clone())])
+        .finish();
+    let old_struct = StructTypeBuilder::new("Foo")
+        .add_field("value", old_enum.clone())
+        .finish();
+
+    let new_enum = EnumTypeBuilder::new("Value")
+        .add_variant("Bar", [("0", bar_type_info.clone())])
+        .finish();
+    let new_struct = StructTypeBuilder::new("Foo")
+        .add_field("value", new_enum.clone())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut foo = runtime.alloc(&old_struct);
+    unsafe {
+        *foo.deref_mut::<[u64; 2]>() = [1, 5];
+    }
+
+    let mapping = Mapping::new(
+        &[bar_type_info.clone(), old_enum, old_struct],
+        &[bar_type_info.clone(), new_enum, new_struct],
+    );
+    assert!(runtime.map_memory(mapping).is_empty());
+
+    // Values of deleted variants are reset to the first variant, for which a `Bar` is allocated
+    let foo = unsafe { foo.deref::<OptionalBar>().as_ref() }.unwrap();
+    assert_eq!(foo.tag, 0);
+    assert_eq!(runtime.ptr_type(foo.bar), bar_type_info);
+    assert_eq!(unsafe { *foo.bar.deref::<i64>() }, 0);
+}
@@ -1,4 +1,5 @@
 mod alloc;
+mod enums;
 mod structs;
 #[macro_use]
 mod util;
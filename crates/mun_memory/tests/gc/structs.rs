@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
 use mun_memory::{
-    gc::{Event, GcPtr, GcRootPtr, GcRuntime, HasIndirectionPtr, MarkSweep, TypeTrace},
+    gc::{Event, GcPtr, GcRootPtr, GcRuntime, HasIndirectionPtr, MarkSweep, RawGcPtr, TypeTrace},
+    mapping::{Mapping, MemoryMapper},
     type_table::TypeTable,
+    HasStaticType, StructTypeBuilder,
 };
 
 use crate::{assert_variant, fake_struct};
@@ -45,6 +47,39 @@ fn test_trace() {
     assert_eq!(trace.next(), None)
 }
 
+#[test]
+fn trace_nil_reference() {
+    let mut type_table = TypeTable::default();
+
+    let bar_type_info = fake_struct!(type_table, "core::Bar", "a" => i64);
+    type_table.insert_type(bar_type_info);
+
+    let foo_type_info = fake_struct!(type_table, "core::Foo", "bar" => Bar);
+    type_table.insert_type(foo_type_info.clone());
+
+    let runtime = Arc::new(MarkSweep::<EventAggregator<Event>>::default());
+    let foo_ptr = GcRootPtr::new(&runtime, runtime.alloc(&foo_type_info));
+
+    // A newly allocated foo.bar is `nil`, which should not be traced
+    let mut trace = foo_type_info.trace(foo_ptr.handle());
+    assert_eq!(trace.next(), None);
+
+    // Collect garbage, foo should not be collected
+    runtime.collect();
+
+    let foo = foo_ptr.unroot();
+    runtime.collect();
+
+    let mut events = runtime.observer().take_all().into_iter();
+    assert_eq!(events.next(), Some(Event::Allocation(foo)));
+    assert_eq!(events.next(), Some(Event::Start));
+    assert_eq!(events.next(), Some(Event::End));
+    assert_eq!(events.next(), Some(Event::Start));
+    assert_eq!(events.next(), Some(Event::Deallocation(foo)));
+    assert_eq!(events.next(), Some(Event::End));
+    assert_eq!(events.next(), None);
+}
+
 #[test]
 fn trace_collect() {
     let mut type_table = TypeTable::default();
@@ -121,3 +156,102 @@ fn trace_cycle() {
     assert_eq!(events.next(), Some(Event::End));
     assert_eq!(events.next(), None);
 }
+
+#[test]
+fn trace_optional_reference() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+    let foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", bar_type_info.optional_type())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut foo_handle = runtime.alloc(&foo_type_info);
+    let bar_handle = runtime.alloc(&bar_type_info);
+
+    // A `nil` reference is not traced
+    let mut trace = foo_type_info.trace(foo_handle);
+    assert_eq!(trace.next(), None);
+
+    // Assign bar to foo.bar
+    unsafe {
+        (*foo_handle.deref_mut::<FooObject>()).bar = bar_handle;
+    }
+
+    let mut trace = foo_type_info.trace(foo_handle);
+    assert_eq!(trace.next(), Some(bar_handle));
+    assert_eq!(trace.next(), None)
+}
+
+#[test]
+fn map_optional_to_reference() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+    let old_foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", bar_type_info.optional_type())
+        .finish();
+    let new_foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", bar_type_info.clone())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let nil_foo = runtime.alloc(&old_foo_type_info);
+    let mut some_foo = runtime.alloc(&old_foo_type_info);
+    let bar_handle = runtime.alloc(&bar_type_info);
+    unsafe {
+        (*some_foo.deref_mut::<FooObject>()).bar = bar_handle;
+    }
+
+    let mapping = Mapping::new(
+        &[bar_type_info.clone(), old_foo_type_info],
+        &[bar_type_info.clone(), new_foo_type_info.clone()],
+    );
+    assert!(runtime.map_memory(mapping).is_empty());
+
+    // The existing reference is kept
+    assert_eq!(runtime.ptr_type(some_foo), new_foo_type_info);
+    assert_eq!(unsafe { (*some_foo.deref::<FooObject>()).bar }, bar_handle);
+
+    // A `nil` reference is replaced by a newly allocated value
+    assert_eq!(runtime.ptr_type(nil_foo), new_foo_type_info);
+    let new_bar = unsafe { (*nil_foo.deref::<FooObject>()).bar };
+    assert_ne!(new_bar, bar_handle);
+    assert_eq!(runtime.ptr_type(new_bar), bar_type_info);
+}
+
+#[test]
+fn map_reference_to_optional() {
+    let bar_type_info = StructTypeBuilder::new("Bar")
+        .add_field("a", i64::type_info().clone())
+        .finish();
+    let old_foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", bar_type_info.clone())
+        .add_field("a", i64::type_info().clone())
+        .finish();
+    let new_foo_type_info = StructTypeBuilder::new("Foo")
+        .add_field("bar", bar_type_info.optional_type())
+        .add_field("a", bar_type_info.optional_type())
+        .finish();
+
+    let runtime = MarkSweep::<EventAggregator<Event>>::default();
+    let mut foo = runtime.alloc(&old_foo_type_info);
+    let bar_handle = runtime.alloc(&bar_type_info);
+    unsafe {
+        (*foo.deref_mut::<FooObject>()).bar = bar_handle;
+    }
+
+    let mapping = Mapping::new(
+        &[bar_type_info.clone(), old_foo_type_info],
+        &[bar_type_info, new_foo_type_info.clone()],
+    );
+    assert!(runtime.map_memory(mapping).is_empty());
+
+    assert_eq!(runtime.ptr_type(foo), new_foo_type_info);
+    let new_foo = unsafe { foo.deref::<[GcPtr; 2]>().as_ref() }.unwrap();
+
+    // The existing reference is kept, while an incompatible value becomes `nil`
+    assert_eq!(new_foo[0], bar_handle);
+    assert!(RawGcPtr::from(new_foo[1]).is_null());
+}
@@ -0,0 +1,78 @@
+use mun_memory::{mapping::map_global, HasStaticType, StructTypeBuilder};
+use std::ptr::NonNull;
+
+#[test]
+fn map_identical_global() {
+    let mut old = 42i32;
+    let mut new = 0i32;
+
+    let mapped = unsafe {
+        map_global(
+            i32::type_info(),
+            i32::type_info(),
+            NonNull::from(&mut old).cast(),
+            NonNull::from(&mut new).cast(),
+        )
+    };
+
+    assert!(mapped);
+    assert_eq!(new, 42);
+}
+
+#[test]
+fn map_cast_global() {
+    let mut old = 42i32;
+    let mut new = 0i64;
+
+    let mapped = unsafe {
+        map_global(
+            i32::type_info(),
+            i64::type_info(),
+            NonNull::from(&mut old).cast(),
+            NonNull::from(&mut new).cast(),
+        )
+    };
+
+    assert!(mapped);
+    assert_eq!(new, 42);
+}
+
+#[test]
+fn map_lossy_global() {
+    let mut old = 42i64;
+    let mut new = 7i32;
+
+    let mapped = unsafe {
+        map_global(
+            i64::type_info(),
+            i32::type_info(),
+            NonNull::from(&mut old).cast(),
+            NonNull::from(&mut new).cast(),
+        )
+    };
+
+    assert!(!mapped);
+    assert_eq!(new, 7);
+}
+
+#[test]
+fn map_incompatible_global() {
+    let struct_ty = StructTypeBuilder::new("Foo")
+        .add_field("a", i32::type_info().clone())
+        .finish();
+
+    let mut old = 42i32;
+    let mut new = 7i32;
+
+    let mapped = unsafe {
+        map_global(
+            &struct_ty,
+            i32::type_info(),
+            NonNull::from(&mut old).cast(),
+            NonNull::from(&mut new).cast(),
+        )
+    };
+
+    assert!(!mapped);
+    assert_eq!(new, 7);
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::Path;
 use std::str::FromStr;
@@ -9,6 +10,7 @@ mod toml;
 pub struct Manifest {
     package_id: PackageId,
     metadata: ManifestMetadata,
+    profiles: BTreeMap<String, Profile>,
 }
 
 /// General metadata for a package.
@@ -17,6 +19,13 @@ pub struct ManifestMetadata {
     pub authors: Vec<String>,
 }
 
+/// The build settings of a named profile, e.g. the `[profile.dev]` section of a mun.toml file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// Whether integer arithmetic is checked for overflow
+    pub overflow_checks: bool,
+}
+
 /// Unique identifier of a package and version
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PackageId {
@@ -52,6 +61,12 @@ impl Manifest {
     pub fn metadata(&self) -> &ManifestMetadata {
         &self.metadata
     }
+
+    /// Returns the build settings of the profile with the specified name, if the manifest
+    /// defines it.
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
 }
 
 impl PackageId {
@@ -85,7 +100,7 @@ impl std::str::FromStr for Manifest {
 
 #[cfg(test)]
 mod tests {
-    use crate::Manifest;
+    use crate::{Manifest, Profile};
     use std::str::FromStr;
 
     #[test]
@@ -107,5 +122,32 @@ mod tests {
         );
         assert_eq!(manifest.metadata().authors, vec!["Mun Team"]);
         assert_eq!(format!("{}", manifest.package_id()), "test v0.2.0");
+        assert_eq!(manifest.profile("dev"), None);
+    }
+
+    #[test]
+    fn parse_profiles() {
+        let manifest = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [profile.dev]
+        overflow-checks = true
+
+        [profile.release]
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.profile("dev"),
+            Some(&Profile {
+                overflow_checks: true
+            })
+        );
+        assert_eq!(manifest.profile("release"), Some(&Profile::default()));
+        assert_eq!(manifest.profile("test"), None);
     }
 }
@@ -1,11 +1,13 @@
-use super::{Manifest, ManifestMetadata, PackageId};
+use super::{Manifest, ManifestMetadata, PackageId, Profile};
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A manifest as specified in a mun.toml file.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct TomlManifest {
     package: TomlProject,
+    profile: Option<BTreeMap<String, TomlProfile>>,
 }
 
 /// Represents the `package` section of a mun.toml file.
@@ -16,6 +18,13 @@ pub struct TomlProject {
     authors: Option<Vec<String>>,
 }
 
+/// Represents a `profile.<name>` section of a mun.toml file.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct TomlProfile {
+    overflow_checks: Option<bool>,
+}
+
 impl TomlManifest {
     /// Convert this toml manifest into a "real" manifest.
     pub fn into_real_manifest(self) -> Result<Manifest, anyhow::Error> {
@@ -32,6 +41,17 @@ impl TomlManifest {
             metadata: ManifestMetadata {
                 authors: self.package.authors.unwrap_or_default(),
             },
+            profiles: self
+                .profile
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, profile)| {
+                    let profile = Profile {
+                        overflow_checks: profile.overflow_checks.unwrap_or_default(),
+                    };
+                    (name, profile)
+                })
+                .collect(),
         })
     }
 }
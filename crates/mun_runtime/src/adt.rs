@@ -5,10 +5,11 @@ use crate::{
     GarbageCollector, Runtime,
 };
 use mun_memory::{
-    gc::{GcPtr, GcRuntime, HasIndirectionPtr},
-    Type,
+    gc::{GcPtr, GcRuntime, HasIndirectionPtr, RawGcPtr},
+    HasStaticType, Type,
 };
 use std::{
+    ffi::c_void,
     ptr::{self, NonNull},
     sync::Arc,
 };
@@ -16,7 +17,7 @@ use std::{
 /// Represents a Mun struct pointer.
 #[repr(transparent)]
 #[derive(Clone)]
-pub struct RawStruct(GcPtr);
+pub struct RawStruct(pub(crate) GcPtr);
 
 impl RawStruct {
     /// Returns a pointer to the struct memory.
@@ -135,8 +136,8 @@ impl<'s> StructRef<'s> {
                 )
             })?;
 
-        let value_type = value.type_info(self.runtime);
-        if field_info.ty() != value_type {
+        if !value.is_assignable_to(&field_info.ty(), self.runtime) {
+            let value_type = value.type_info(self.runtime);
             return Err(format!(
                 "Mismatched types for `{}::{}`. Expected: `{}`. Found: `{}`.",
                 type_info.name(),
@@ -149,7 +150,7 @@ impl<'s> StructRef<'s> {
         // SAFETY: The offset in the ABI is always valid.
         let field_ptr = unsafe { self.get_field_ptr_unchecked::<T::MunType>(field_info.offset()) };
         let old = Marshal::marshal_from_ptr(field_ptr, self.runtime, &field_info.ty());
-        Marshal::marshal_to_ptr(value, field_ptr, &field_info.ty());
+        Marshal::marshal_to_ptr(value, field_ptr, self.runtime, &field_info.ty());
         Ok(old)
     }
 
@@ -175,8 +176,8 @@ impl<'s> StructRef<'s> {
                 )
             })?;
 
-        let value_type = value.type_info(self.runtime);
-        if field_info.ty() != value_type {
+        if !value.is_assignable_to(&field_info.ty(), self.runtime) {
+            let value_type = value.type_info(self.runtime);
             return Err(format!(
                 "Mismatched types for `{}::{}`. Expected: `{}`. Found: `{}`.",
                 type_info.name(),
@@ -188,7 +189,7 @@ impl<'s> StructRef<'s> {
 
         // SAFETY: The offset in the ABI is always valid.
         let field_ptr = unsafe { self.get_field_ptr_unchecked::<T::MunType>(field_info.offset()) };
-        Marshal::marshal_to_ptr(value, field_ptr, &field_info.ty());
+        Marshal::marshal_to_ptr(value, field_ptr, self.runtime, &field_info.ty());
         Ok(())
     }
 }
@@ -197,6 +198,12 @@ impl<'r> ArgumentReflection for StructRef<'r> {
     fn type_info(&self, _runtime: &Runtime) -> Type {
         self.type_info()
     }
+
+    /// A struct can also be assigned to an optional reference to its type.
+    fn is_assignable_to(&self, ty: &Type, _runtime: &Runtime) -> bool {
+        let type_info = self.type_info();
+        *ty == type_info || *ty == type_info.optional_type()
+    }
 }
 
 impl<'s> Marshal<'s> for StructRef<'s> {
@@ -209,7 +216,7 @@ impl<'s> Marshal<'s> for StructRef<'s> {
         StructRef::new(value, runtime)
     }
 
-    fn marshal_into<'r>(self) -> Self::MunType {
+    fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
         self.into_raw()
     }
 
@@ -239,15 +246,29 @@ impl<'s> Marshal<'s> for StructRef<'s> {
             gc_handle
         } else {
             // For a gc struct, `ptr` points to a `GcPtr`.
-            unsafe { *ptr.cast::<GcPtr>().as_ptr() }
+            let gc_handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+            assert!(
+                !RawGcPtr::from(gc_handle).is_null(),
+                "Struct `{}` is `nil`. Use `Option<StructRef>` to marshal optional references.",
+                type_info.name()
+            );
+            gc_handle
         };
 
         StructRef::new(RawStruct(gc_handle), runtime)
     }
 
-    fn marshal_to_ptr(value: Self, mut ptr: NonNull<Self::MunType>, type_info: &Type) {
-        let struct_info = type_info.as_struct().unwrap();
-        if struct_info.is_value_struct() {
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        type_info: &Type,
+    ) {
+        // An optional struct is always a gc struct, so `ptr` points to a `GcPtr`.
+        let is_value_struct = type_info
+            .as_struct()
+            .map_or(false, |struct_info| struct_info.is_value_struct());
+        if is_value_struct {
             let dest = ptr.cast::<u8>().as_ptr();
             unsafe {
                 ptr::copy_nonoverlapping(
@@ -273,6 +294,92 @@ impl<'r> ReturnTypeReflection for StructRef<'r> {
     }
 }
 
+impl<'s> Marshal<'s> for Option<StructRef<'s>> {
+    type MunType = RawStruct;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        'r: 's,
+    {
+        if RawGcPtr::from(value.0).is_null() {
+            None
+        } else {
+            Some(StructRef::new(value, runtime))
+        }
+    }
+
+    fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
+        self.map_or_else(
+            || RawStruct(GcPtr::from(ptr::null() as RawGcPtr)),
+            StructRef::into_raw,
+        )
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        type_info: &Type,
+    ) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        // An optional struct is always a gc struct, so `ptr` points to a `GcPtr`.
+        assert!(<Self as ReturnTypeReflection>::accepts_type(type_info));
+        Self::marshal_from(unsafe { ptr.as_ptr().read() }, runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        type_info: &Type,
+    ) {
+        assert!(<Self as ReturnTypeReflection>::accepts_type(type_info));
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
+
+impl<'r> ArgumentReflection for Option<StructRef<'r>> {
+    /// Returns the optional type of the referenced struct. A `nil` reference does not know the
+    /// type of the struct it refers to, so it is reported as an optional `core::void`.
+    fn type_info(&self, _runtime: &Runtime) -> Type {
+        match self {
+            Some(value) => value.type_info().optional_type(),
+            None => <c_void as HasStaticType>::type_info().optional_type(),
+        }
+    }
+
+    /// A `nil` reference can be assigned to any optional struct.
+    fn is_assignable_to(&self, ty: &Type, _runtime: &Runtime) -> bool {
+        match self {
+            Some(value) => *ty == value.type_info().optional_type(),
+            None => ty.as_optional().map_or(false, |optional| {
+                optional
+                    .inner_type()
+                    .as_struct()
+                    .map_or(false, |struct_info| struct_info.is_gc_struct())
+            }),
+        }
+    }
+}
+
+impl<'r> ReturnTypeReflection for Option<StructRef<'r>> {
+    /// Returns true if this specified type can be stored in an instance of this type
+    fn accepts_type(ty: &Type) -> bool {
+        // A reference that can never be `nil` can also be stored
+        let ty = ty
+            .as_optional()
+            .map_or_else(|| ty.clone(), |optional| optional.inner_type());
+        ty.as_struct()
+            .map_or(false, |struct_info| struct_info.is_gc_struct())
+    }
+
+    fn type_hint() -> &'static str {
+        "optional struct"
+    }
+}
+
 /// Type-agnostic wrapper for interoperability with a Mun struct, that has been rooted. To marshal,
 /// obtain a `StructRef` for the `RootedStruct`.
 #[derive(Clone)]
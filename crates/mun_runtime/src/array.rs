@@ -3,11 +3,12 @@ use crate::{
     ReturnTypeReflection, Runtime,
 };
 use mun_memory::{
-    gc::{Array, GcPtr, GcRuntime, HasIndirectionPtr},
-    Type,
+    gc::{Array, GcPtr, GcRuntime, HasIndirectionPtr, RawGcPtr},
+    HasStaticType, Type,
 };
+use std::ffi::c_void;
 use std::marker::PhantomData;
-use std::ptr::NonNull;
+use std::ptr::{self, NonNull};
 use std::sync::Arc;
 
 /// Represents a Mun array pointer.
@@ -26,6 +27,82 @@ impl RawArray {
     }
 }
 
+/// Returns a pointer to the slot of the element at `index` in the array referred to by `handle`.
+fn element_slot(gc: &GarbageCollector, handle: GcPtr, index: usize) -> NonNull<u8> {
+    let array_handle = gc
+        .array(handle)
+        .expect("the internal handle does not refer to an array");
+
+    // Safety: the caller guarantees that `index` lies within the capacity of the array
+    unsafe {
+        NonNull::new_unchecked(
+            array_handle
+                .data()
+                .as_ptr()
+                .add(index * array_handle.element_stride()),
+        )
+    }
+}
+
+/// Inserts the element stored at `element` at position `index` of the array referred to by
+/// `handle`, shifting all elements after it to the right. The capacity of the array grows if
+/// required.
+///
+/// # Safety
+///
+/// `element` must point to a valid value of the array's element type.
+pub(crate) unsafe fn insert_element(
+    gc: &GarbageCollector,
+    handle: GcPtr,
+    index: usize,
+    element: *const u8,
+) {
+    gc.reserve_array(handle, 1);
+
+    let mut array_handle = gc
+        .array(handle)
+        .expect("the internal handle does not refer to an array");
+    let length = array_handle.length();
+    assert!(
+        index <= length,
+        "insertion index (is {index}) should be <= len (is {length})"
+    );
+
+    let stride = array_handle.element_stride();
+    let slot = element_slot(gc, handle, index).as_ptr();
+    ptr::copy(slot, slot.add(stride), (length - index) * stride);
+    ptr::copy_nonoverlapping(element, slot, array_handle.element_layout().size());
+    array_handle.set_length(length + 1);
+}
+
+/// Removes the element at position `index` of the array referred to by `handle` and stores it at
+/// `element`, shifting all elements after it to the left.
+///
+/// # Safety
+///
+/// `element` must point to memory that can hold a value of the array's element type.
+pub(crate) unsafe fn remove_element(
+    gc: &GarbageCollector,
+    handle: GcPtr,
+    index: usize,
+    element: *mut u8,
+) {
+    let mut array_handle = gc
+        .array(handle)
+        .expect("the internal handle does not refer to an array");
+    let length = array_handle.length();
+    assert!(
+        index < length,
+        "removal index (is {index}) should be < len (is {length})"
+    );
+
+    let stride = array_handle.element_stride();
+    let slot = element_slot(gc, handle, index).as_ptr();
+    ptr::copy_nonoverlapping(slot, element, array_handle.element_layout().size());
+    ptr::copy(slot.add(stride), slot, (length - index - 1) * stride);
+    array_handle.set_length(length - 1);
+}
+
 /// Type-agnostic wrapper for interoperability with a Mun array. This is merely a reference to the
 /// Mun array, that will be garbage collected unless it is rooted.
 #[derive(Clone)]
@@ -88,6 +165,63 @@ impl<'array, T: Marshal<'array> + 'array> ArrayRef<'array, T> {
             .capacity()
     }
 
+    /// Appends `value` to the end of the array, growing its capacity if required.
+    pub fn push(&mut self, value: T) {
+        let gc = &self.runtime.gc;
+        gc.reserve_array(self.raw.0, 1);
+
+        let mut array_handle = gc
+            .array(self.raw.0)
+            .expect("the internal handle does not refer to an array");
+        let length = array_handle.length();
+        let slot = element_slot(gc, self.raw.0, length);
+        T::marshal_to_ptr(
+            value,
+            slot.cast(),
+            self.runtime,
+            &array_handle.element_type(),
+        );
+
+        // Safety: the element at `length` was just initialized
+        unsafe { array_handle.set_length(length + 1) };
+    }
+
+    /// Replaces the element at position `index` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        let length = self.len();
+        assert!(
+            index < length,
+            "index out of bounds: the length is {length} but the index is {index}"
+        );
+
+        let element_type = self
+            .runtime
+            .gc
+            .array(self.raw.0)
+            .expect("the internal handle does not refer to an array")
+            .element_type();
+        let slot = element_slot(&self.runtime.gc, self.raw.0, index);
+        T::marshal_to_ptr(value, slot.cast(), self.runtime, &element_type);
+    }
+
+    /// Shortens the array, keeping the first `length` elements. This has no effect if `length` is
+    /// greater than or equal to the array's current length.
+    pub fn truncate(&mut self, length: usize) {
+        let mut array_handle = self
+            .runtime
+            .gc
+            .array(self.raw.0)
+            .expect("the internal handle does not refer to an array");
+        if length < array_handle.length() {
+            // Safety: the remaining elements are all initialized
+            unsafe { array_handle.set_length(length) };
+        }
+    }
+
     /// Returns an iterator to iterate over the elements of the array.
     pub fn iter(&self) -> impl Iterator<Item = T> + 'array
     where
@@ -126,6 +260,12 @@ impl<'a, T: Marshal<'a> + ArgumentReflection + 'a> ArgumentReflection for ArrayR
     fn type_info(&self, _runtime: &Runtime) -> Type {
         self.type_info()
     }
+
+    /// An array can also be assigned to an optional reference to its type.
+    fn is_assignable_to(&self, ty: &Type, _runtime: &Runtime) -> bool {
+        let type_info = self.type_info();
+        *ty == type_info || *ty == type_info.optional_type()
+    }
 }
 
 impl<'a, T: Marshal<'a> + 'a> Marshal<'a> for ArrayRef<'a, T> {
@@ -139,7 +279,7 @@ impl<'a, T: Marshal<'a> + 'a> Marshal<'a> for ArrayRef<'a, T> {
         ArrayRef::new(value, runtime)
     }
 
-    fn marshal_into(self) -> Self::MunType {
+    fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
         self.raw
     }
 
@@ -156,11 +296,95 @@ impl<'a, T: Marshal<'a> + 'a> Marshal<'a> for ArrayRef<'a, T> {
         ArrayRef::new(RawArray(handle), runtime)
     }
 
-    fn marshal_to_ptr(value: Self, mut ptr: NonNull<Self::MunType>, _type_info: &Type) {
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        _type_info: &Type,
+    ) {
         unsafe { *ptr.as_mut() = value.into_raw() };
     }
 }
 
+impl<'a, T: Marshal<'a> + ArgumentReflection + 'a> ArgumentReflection for Option<ArrayRef<'a, T>> {
+    /// Returns the optional type of the referenced array. A `nil` reference does not know the
+    /// type of the array it refers to, so it is reported as an optional `core::void`.
+    fn type_info(&self, _runtime: &Runtime) -> Type {
+        match self {
+            Some(value) => value.type_info().optional_type(),
+            None => <c_void as HasStaticType>::type_info().optional_type(),
+        }
+    }
+
+    /// A `nil` reference can be assigned to any optional array.
+    fn is_assignable_to(&self, ty: &Type, _runtime: &Runtime) -> bool {
+        match self {
+            Some(value) => *ty == value.type_info().optional_type(),
+            None => ty
+                .as_optional()
+                .map_or(false, |optional| optional.inner_type().is_array()),
+        }
+    }
+}
+
+impl<'a, T: Marshal<'a> + ReturnTypeReflection> ReturnTypeReflection for Option<ArrayRef<'a, T>> {
+    fn accepts_type(ty: &Type) -> bool {
+        // A reference that can never be `nil` can also be stored
+        let ty = ty
+            .as_optional()
+            .map_or_else(|| ty.clone(), |optional| optional.inner_type());
+        ArrayRef::<'a, T>::accepts_type(&ty)
+    }
+
+    fn type_hint() -> &'static str {
+        "optional array"
+    }
+}
+
+impl<'a, T: Marshal<'a> + 'a> Marshal<'a> for Option<ArrayRef<'a, T>> {
+    type MunType = RawArray;
+
+    fn marshal_from<'runtime>(value: Self::MunType, runtime: &'runtime Runtime) -> Self
+    where
+        Self: 'a,
+        'runtime: 'a,
+    {
+        if RawGcPtr::from(value.0).is_null() {
+            None
+        } else {
+            Some(ArrayRef::new(value, runtime))
+        }
+    }
+
+    fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
+        self.map_or_else(
+            || RawArray(GcPtr::from(std::ptr::null() as RawGcPtr)),
+            ArrayRef::into_raw,
+        )
+    }
+
+    fn marshal_from_ptr<'runtime>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'runtime Runtime,
+        _type_info: &Type,
+    ) -> Self
+    where
+        Self: 'a,
+        'runtime: 'a,
+    {
+        Self::marshal_from(unsafe { ptr.as_ptr().read() }, runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        _type_info: &Type,
+    ) {
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
+
 /// Type-agnostic wrapper for interoperability with a Mun struct, that has been rooted. To marshal,
 /// obtain a `ArrayRef` for the `RootedArray`.
 #[derive(Clone)]
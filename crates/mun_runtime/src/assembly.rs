@@ -2,22 +2,26 @@ use std::{
     collections::{HashMap, VecDeque},
     ffi::c_void,
     path::{Path, PathBuf},
+    ptr::NonNull,
     sync::Arc,
 };
 
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
-use log::error;
+use log::{error, info};
 
 use mun_abi as abi;
 use mun_libloader::{MunLibrary, TempLibrary};
 use mun_memory::{
-    mapping::{Mapping, MemoryMapper},
+    mapping::{self, Mapping, MemoryMapper},
     type_table::TypeTable,
     Type,
 };
 
-use crate::{garbage_collector::GarbageCollector, DispatchTable};
+use crate::{
+    garbage_collector::GarbageCollector, global_table::GlobalTable, is_function_value_env,
+    panic::PanicState, DispatchTable,
+};
 
 /// An assembly is a hot reloadable compilation unit, consisting of one or more Mun modules.
 pub struct Assembly {
@@ -46,6 +50,7 @@ impl Assembly {
     pub unsafe fn load(
         library_path: &Path,
         gc: Arc<GarbageCollector>,
+        panic_state: Arc<PanicState>,
     ) -> Result<Self, anyhow::Error> {
         let mut library = MunLibrary::new(library_path)?;
 
@@ -61,6 +66,9 @@ impl Assembly {
         let allocator_ptr = Arc::into_raw(gc.clone()) as *mut std::ffi::c_void;
         library.set_allocator_handle(allocator_ptr);
 
+        let panic_ptr = Arc::into_raw(panic_state) as *mut std::ffi::c_void;
+        library.set_panic_handle(panic_ptr);
+
         let assembly = Assembly {
             info: library.get_info(),
             library_path: library_path.to_path_buf(),
@@ -95,6 +103,108 @@ impl Assembly {
         Ok(())
     }
 
+    /// Links the globals in `to_link` to the globals in the `global_table`. The globals are only
+    /// updated if all of them can be linked.
+    fn link_all_globals<'abi>(
+        global_table: &GlobalTable,
+        type_table: &TypeTable,
+        to_link: impl Iterator<Item = (&'abi abi::TypeId<'abi>, &'abi mut *mut c_void, &'abi str)>,
+    ) -> anyhow::Result<()> {
+        let mut linked = Vec::new();
+        let mut missing_globals = vec![];
+        for (type_id, global_ptr, name) in to_link {
+            // Ensure that the global is in the runtime global table
+            if let Some(global_def) = global_table.get_global(name) {
+                let ty = type_table.find_type_info_by_id(type_id).ok_or_else(|| {
+                    anyhow!("failed to link global '{}': unknown type {}", name, type_id)
+                })?;
+
+                if ty != global_def.ty {
+                    return Err(anyhow!(
+                        "a global with the same name does exist, but the types do not match.\nExpected:\n\tstatic {name}: {}\n\nGlobal that exists:\n\tstatic {name}: {}",
+                        ty.name(),
+                        global_def.ty.name()
+                    ))
+                    .with_context(|| format!("failed to link global '{}'", name));
+                }
+
+                linked.push((global_ptr, global_def.ptr));
+            } else {
+                error!("Failed to link: global `{}` is missing.", name);
+                missing_globals.push(format!("- {}", name));
+            }
+        }
+
+        if !missing_globals.is_empty() {
+            return Err(anyhow!(
+                "Failed to link due to missing dependencies.\n{}",
+                missing_globals.join("\n")
+            ));
+        }
+
+        for (global_ptr, ptr) in linked {
+            *global_ptr = ptr;
+        }
+
+        Ok(())
+    }
+
+    /// Copies the values of the globals of the `old_assembly` to the globals with the same name
+    /// of the `new_assembly`, such that their values are preserved across hot reloads. The
+    /// `global_table` must still contain the globals of the `old_assembly`.
+    fn map_globals(
+        old_assembly: &Assembly,
+        new_assembly: &Assembly,
+        global_table: &GlobalTable,
+        type_table: &TypeTable,
+    ) {
+        // Immutable globals cannot have changed at runtime, so they take their new initial value
+        for new_global in new_assembly
+            .info
+            .symbols
+            .globals()
+            .iter()
+            .filter(|global| global.mutable)
+        {
+            let old_global = global_table
+                .get_global(new_global.name())
+                .filter(|global_def| {
+                    old_assembly
+                        .info
+                        .symbols
+                        .globals()
+                        .iter()
+                        .any(|old_global| old_global.ptr == global_def.ptr)
+                });
+
+            let (old_global, new_ty) = match (
+                old_global,
+                type_table.find_type_info_by_id(&new_global.type_id),
+            ) {
+                (Some(old_global), Some(new_ty)) => (old_global, new_ty),
+                _ => continue,
+            };
+
+            // SAFETY: The pointers of globals are never null and point to values of their type.
+            let mapped = unsafe {
+                mapping::map_global(
+                    &old_global.ty,
+                    &new_ty,
+                    NonNull::new_unchecked(old_global.ptr.cast()),
+                    NonNull::new_unchecked(new_global.ptr.cast()),
+                )
+            };
+
+            if !mapped {
+                info!(
+                    "Could not preserve the value of global `{}` of type `{}`; it was reinitialized.",
+                    new_global.name(),
+                    new_ty.name()
+                );
+            }
+        }
+    }
+
     /// Private implementation of runtime linking
     fn link_all_functions<'abi>(
         dispatch_table: &DispatchTable,
@@ -205,7 +315,8 @@ impl Assembly {
         assemblies: impl Iterator<Item = &'a mut Assembly>,
         dispatch_table: &DispatchTable,
         type_table: &TypeTable,
-    ) -> anyhow::Result<(DispatchTable, TypeTable)> {
+        global_table: &GlobalTable,
+    ) -> anyhow::Result<(DispatchTable, TypeTable, GlobalTable)> {
         let mut assemblies: Vec<&'a mut _> = assemblies.collect();
 
         // Load all types, this creates a new type table that contains the types loaded
@@ -235,7 +346,7 @@ impl Assembly {
         }
 
         let functions_to_link = assemblies
-            .into_iter()
+            .iter_mut()
             .flat_map(|asm| asm.info_mut().dispatch_table.iter_mut())
             // Only take signatures into account that do *not* yet have a function pointer assigned
             // by the compiler.
@@ -243,10 +354,24 @@ impl Assembly {
 
         Assembly::link_all_functions(&dispatch_table, &type_table, functions_to_link)?;
 
+        // Clone the global table, such that we can roll back if linking fails
+        let mut global_table = global_table.clone();
+
+        // Insert all assemblies' globals into the global table
+        for assembly in assemblies.iter() {
+            global_table.insert_module(&assembly.info().symbols, &type_table);
+        }
+
+        let globals_to_link = assemblies
+            .into_iter()
+            .flat_map(|asm| asm.info_mut().global_lut.iter_mut());
+
+        Assembly::link_all_globals(&global_table, &type_table, globals_to_link)?;
+
         // Collect remaining types
         Type::collect_unreferenced_type_data();
 
-        Ok((dispatch_table, type_table))
+        Ok((dispatch_table, type_table, global_table))
     }
 
     /// Tries to link the `unlinked_assemblies`, resulting in a new [`DispatchTable`] on success.
@@ -256,7 +381,8 @@ impl Assembly {
         linked_assemblies: &mut HashMap<PathBuf, Assembly>,
         dispatch_table: &DispatchTable,
         type_table: &TypeTable,
-    ) -> anyhow::Result<(DispatchTable, TypeTable)> {
+        global_table: &GlobalTable,
+    ) -> anyhow::Result<(DispatchTable, TypeTable, GlobalTable)> {
         let mut dependencies: HashMap<String, Vec<String>> = unlinked_assemblies
             .values()
             .map(|assembly| {
@@ -280,6 +406,9 @@ impl Assembly {
         // Clone the dispatch table, such that we can roll back if linking fails
         let mut dispatch_table = dispatch_table.clone();
 
+        // Clone the global table, such that we can roll back if linking fails
+        let mut global_table = global_table.clone();
+
         while let Some(mut entry) = assemblies_to_link.pop_front() {
             let (ref old_assembly, ref mut new_assembly) = entry;
 
@@ -328,6 +457,19 @@ impl Assembly {
 
             // Memory map allocated object
             if let Some((old_assembly, old_types)) = old_types {
+                // The environment of a function value is only mapped to the environment of the
+                // same closure. Otherwise a function value could end up calling another closure
+                // with the same captures.
+                let old_types: Vec<Type> = old_types
+                    .into_iter()
+                    .filter(|ty| !is_function_value_env(ty) || new_types.contains(ty))
+                    .collect();
+                let new_types: Vec<Type> = new_types
+                    .iter()
+                    .filter(|ty| !is_function_value_env(ty) || old_types.contains(ty))
+                    .cloned()
+                    .collect();
+
                 let mapping = Mapping::new(&old_types, &new_types);
                 let _deleted_objects = old_assembly.allocator.map_memory(mapping);
                 // DISCUSSION: Do we need to maintain an assembly for the type LUT of allocated objects with deleted types?
@@ -358,6 +500,17 @@ impl Assembly {
             // or the signature of a function doesnt match.
             Assembly::link_all_functions(&dispatch_table, &type_table, functions_to_link)?;
 
+            // Preserve the values of the old assembly's globals and replace them in the global
+            // table
+            if let Some(old_assembly) = old_assembly {
+                Assembly::map_globals(old_assembly, new_assembly, &global_table, &type_table);
+                global_table.remove_module(&old_assembly.info.symbols);
+            }
+            global_table.insert_module(&new_assembly.info.symbols, &type_table);
+
+            let globals_to_link = new_assembly.info_mut().global_lut.iter_mut();
+            Assembly::link_all_globals(&global_table, &type_table, globals_to_link)?;
+
             // Remove this assembly from the dependencies
             dependencies
                 .values_mut()
@@ -367,6 +520,14 @@ impl Assembly {
             dependencies.retain(|_, dependencies| !dependencies.is_empty());
         }
 
+        // The globals of reloaded assemblies moved, so relink the globals used by the assemblies
+        // that were not reloaded.
+        let globals_to_link = linked_assemblies
+            .iter_mut()
+            .filter(|(path, _)| !unlinked_assemblies.contains_key(*path))
+            .flat_map(|(_, asm)| asm.info_mut().global_lut.iter_mut());
+        Assembly::link_all_globals(&global_table, &type_table, globals_to_link)?;
+
         let mut newly_linked = HashMap::new();
         std::mem::swap(unlinked_assemblies, &mut newly_linked);
 
@@ -383,7 +544,7 @@ impl Assembly {
         // Collect types
         Type::collect_unreferenced_type_data();
 
-        Ok((dispatch_table, type_table))
+        Ok((dispatch_table, type_table, global_table))
     }
 
     /// Returns the assembly's information.
@@ -0,0 +1,74 @@
+use mun_abi as abi;
+use mun_memory::{type_table::TypeTable, TryFromAbiError, Type};
+use rustc_hash::FxHashMap;
+use std::{ffi::c_void, sync::Arc};
+
+/// A linked version of [`mun_abi::GlobalDefinition`] that has resolved its `TypeId` to a `Type`.
+#[derive(Clone)]
+pub struct GlobalDefinition {
+    /// Global name
+    pub name: String,
+    /// The type of the global
+    pub ty: Type,
+    /// Pointer to the memory of the global
+    pub ptr: *mut c_void,
+    /// Whether the global can be modified
+    pub mutable: bool,
+}
+
+unsafe impl Send for GlobalDefinition {}
+unsafe impl Sync for GlobalDefinition {}
+
+impl GlobalDefinition {
+    /// Tries to convert from an `abi::GlobalDefinition`.
+    pub fn try_from_abi<'abi>(
+        global_def: &'abi abi::GlobalDefinition<'abi>,
+        type_table: &TypeTable,
+    ) -> Result<Self, TryFromAbiError<'abi>> {
+        let ty = type_table
+            .find_type_info_by_id(&global_def.type_id)
+            .ok_or_else(|| TryFromAbiError::UnknownTypeId(global_def.type_id.clone()))?;
+
+        Ok(Self {
+            name: global_def.name().to_owned(),
+            ty,
+            ptr: global_def.ptr,
+            mutable: global_def.mutable,
+        })
+    }
+}
+
+/// A runtime table that maps full paths to the globals of all loaded assemblies.
+#[derive(Clone, Default)]
+pub struct GlobalTable {
+    globals: FxHashMap<String, Arc<GlobalDefinition>>,
+}
+
+impl GlobalTable {
+    /// Retrieves the [`GlobalDefinition`] corresponding to `global_path`, if it exists.
+    pub fn get_global(&self, global_path: &str) -> Option<Arc<GlobalDefinition>> {
+        self.globals.get(global_path).map(Clone::clone)
+    }
+
+    /// Removes the global definitions of the given assembly from this global table.
+    pub fn remove_module(&mut self, assembly: &abi::ModuleInfo) {
+        for global in assembly.globals() {
+            if let Some(value) = self.globals.get(global.name()) {
+                if value.ptr == global.ptr {
+                    self.globals.remove(global.name());
+                }
+            }
+        }
+    }
+
+    /// Adds the global definitions of the given assembly to this global table.
+    pub fn insert_module(&mut self, assembly: &abi::ModuleInfo, type_table: &TypeTable) {
+        for global_def in assembly.globals() {
+            let global_def = GlobalDefinition::try_from_abi(global_def, type_table)
+                .expect("All types from a loaded assembly must exist in the type table.");
+
+            self.globals
+                .insert(global_def.name.clone(), Arc::new(global_def));
+        }
+    }
+}
@@ -11,12 +11,17 @@ mod adt;
 mod array;
 mod dispatch_table;
 mod function_info;
+mod global_table;
 mod marshal;
+mod panic;
 mod reflection;
+mod string;
+mod tuple;
 
 use anyhow::Result;
 use dispatch_table::DispatchTable;
 use garbage_collector::GarbageCollector;
+use global_table::GlobalTable;
 use log::{debug, error, info};
 use mun_abi as abi;
 use mun_memory::{
@@ -47,13 +52,21 @@ pub use crate::{
     function_info::{
         FunctionDefinition, FunctionPrototype, FunctionSignature, IntoFunctionDefinition,
     },
+    global_table::GlobalDefinition,
     marshal::Marshal,
+    panic::Panic,
     reflection::{ArgumentReflection, ReturnTypeReflection},
+    string::{RawString, RootedString, StringRef},
 };
 // Re-export some useful types so crates dont have to depend on mun_memory as well.
-use crate::array::RawArray;
+use crate::{array::RawArray, panic::PanicState};
 pub use mun_memory::{Field, FieldData, HasStaticType, PointerType, StructType, Type};
 
+/// Returns true if `ty` is the type of the environment of a function value.
+fn is_function_value_env(ty: &Type) -> bool {
+    ty.name().ends_with('}') && ty.name().contains("::{closure#")
+}
+
 /// Options for the construction of a [`Runtime`].
 pub struct RuntimeOptions {
     /// Path to the entry point library
@@ -74,6 +87,16 @@ unsafe fn get_allocator(alloc_handle: *mut ffi::c_void) -> Arc<GarbageCollector>
     Arc::from_raw(alloc_handle as *const GarbageCollector)
 }
 
+/// Retrieve the panic state using the provided handle.
+///
+/// # Safety
+///
+/// The panic state must have been set using the `set_panic_handle` call - exposed by the Mun
+/// library.
+unsafe fn get_panic_state(panic_handle: *mut ffi::c_void) -> Arc<PanicState> {
+    Arc::from_raw(panic_handle as *const PanicState)
+}
+
 /// Retrieve the `TypeInfo` using the provided handle.
 ///
 /// # Safety
@@ -121,6 +144,159 @@ extern "C" fn new_array(
     handle.as_raw().into()
 }
 
+extern "C" fn array_push(
+    array: *const *mut ffi::c_void,
+    element: *const u8,
+    alloc_handle: *mut ffi::c_void,
+) {
+    // Safety: `array_push` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    let length = allocator
+        .array(array.into())
+        .expect("the internal handle does not refer to an array")
+        .length();
+
+    // Safety: the Mun Compiler guarantees that `element` points to a valid element of the array.
+    unsafe { array::insert_element(&allocator, array.into(), length, element) };
+}
+
+extern "C" fn array_insert(
+    array: *const *mut ffi::c_void,
+    index: usize,
+    element: *const u8,
+    alloc_handle: *mut ffi::c_void,
+) {
+    // Safety: `array_insert` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    // Safety: the Mun Compiler guarantees that `element` points to a valid element of the array
+    // and that `index` lies within the bounds of the array.
+    unsafe { array::insert_element(&allocator, array.into(), index, element) };
+}
+
+extern "C" fn array_remove(
+    array: *const *mut ffi::c_void,
+    index: usize,
+    element: *mut u8,
+    alloc_handle: *mut ffi::c_void,
+) {
+    // Safety: `array_remove` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    // Safety: the Mun Compiler guarantees that `element` can hold an element of the array and that
+    // `index` lies within the bounds of the array.
+    unsafe { array::remove_element(&allocator, array.into(), index, element) };
+}
+
+extern "C" fn new_string(
+    bytes: *const u8,
+    length: usize,
+    alloc_handle: *mut ffi::c_void,
+) -> *const *mut ffi::c_void {
+    // Safety: `new_string` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    // Safety: the Mun Compiler guarantees that `bytes` points to `length` bytes of valid UTF-8.
+    let bytes = unsafe { std::slice::from_raw_parts(bytes, length) };
+
+    string::alloc_string(&allocator, bytes).into()
+}
+
+extern "C" fn string_concat(
+    lhs: *const *mut ffi::c_void,
+    rhs: *const *mut ffi::c_void,
+    alloc_handle: *mut ffi::c_void,
+) -> *const *mut ffi::c_void {
+    // Safety: `string_concat` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    // Safety: the Mun Compiler guarantees that both arguments are valid strings.
+    let (lhs, rhs) = unsafe {
+        (
+            string::string_bytes(&allocator, &RawString(lhs.into())),
+            string::string_bytes(&allocator, &RawString(rhs.into())),
+        )
+    };
+
+    let mut bytes = Vec::with_capacity(lhs.len() + rhs.len());
+    bytes.extend_from_slice(lhs);
+    bytes.extend_from_slice(rhs);
+
+    string::alloc_string(&allocator, &bytes).into()
+}
+
+extern "C" fn string_compare(
+    lhs: *const *mut ffi::c_void,
+    rhs: *const *mut ffi::c_void,
+    alloc_handle: *mut ffi::c_void,
+) -> i32 {
+    // Safety: `string_compare` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = ManuallyDrop::new(unsafe { get_allocator(alloc_handle) });
+
+    // Safety: the Mun Compiler guarantees that both arguments are valid strings.
+    let (lhs, rhs) = unsafe {
+        (
+            string::string_bytes(&allocator, &RawString(lhs.into())),
+            string::string_bytes(&allocator, &RawString(rhs.into())),
+        )
+    };
+
+    lhs.cmp(rhs) as i32
+}
+
+extern "C" fn panic_bounds_check(index: usize, length: usize, panic_handle: *mut ffi::c_void) {
+    // Safety: `panic_bounds_check` is only called from within Mun assemblies' core logic, so we
+    // are guaranteed that the `Runtime` and its `PanicState` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let panic_state = ManuallyDrop::new(unsafe { get_panic_state(panic_handle) });
+
+    panic_state.raise(format!(
+        "index out of bounds: the length is {length} but the index is {index}"
+    ));
+}
+
+extern "C" fn panic(message: *const u8, length: usize, panic_handle: *mut ffi::c_void) {
+    // Safety: `panic` is only called from within Mun assemblies' core logic, so we are guaranteed
+    // that the `Runtime` and its `PanicState` still exist if this function is called, and will
+    // continue to do so for the duration of this function.
+    let panic_state = ManuallyDrop::new(unsafe { get_panic_state(panic_handle) });
+
+    // Safety: the Mun Compiler guarantees that `message` points to `length` bytes of valid UTF-8.
+    let message = unsafe { std::slice::from_raw_parts(message, length) };
+
+    panic_state.raise(String::from_utf8_lossy(message).into_owned());
+}
+
+extern "C" fn panic_unwind(
+    function_name: *const u8,
+    length: usize,
+    panic_handle: *mut ffi::c_void,
+) {
+    // Safety: `panic_unwind` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `PanicState` still exist if this function is called,
+    // and will continue to do so for the duration of this function.
+    let panic_state = ManuallyDrop::new(unsafe { get_panic_state(panic_handle) });
+
+    // Safety: the Mun Compiler guarantees that `function_name` points to `length` bytes of valid
+    // UTF-8.
+    let function_name = unsafe { std::slice::from_raw_parts(function_name, length) };
+
+    panic_state.unwind(String::from_utf8_lossy(function_name).into_owned());
+}
+
 /// A builder for the [`Runtime`].
 pub struct RuntimeBuilder {
     options: RuntimeOptions,
@@ -182,10 +358,12 @@ pub struct Runtime {
     assemblies_to_relink: VecDeque<(PathBuf, PathBuf)>,
     dispatch_table: DispatchTable,
     type_table: TypeTable,
+    global_table: GlobalTable,
     watcher: RecommendedWatcher,
     watcher_rx: Receiver<notify::Result<Event>>,
     renamed_files: HashMap<usize, PathBuf>,
     gc: Arc<GarbageCollector>,
+    panic_state: Arc<PanicState>,
 }
 
 impl Runtime {
@@ -232,6 +410,64 @@ impl Runtime {
             "new_array",
         ));
 
+        options.user_functions.push(IntoFunctionDefinition::into(
+            array_push as extern "C" fn(*const *mut ffi::c_void, *const u8, *mut ffi::c_void),
+            "array_push",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            array_insert
+                as extern "C" fn(*const *mut ffi::c_void, usize, *const u8, *mut ffi::c_void),
+            "array_insert",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            array_remove
+                as extern "C" fn(*const *mut ffi::c_void, usize, *mut u8, *mut ffi::c_void),
+            "array_remove",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            new_string
+                as extern "C" fn(*const u8, usize, *mut ffi::c_void) -> *const *mut ffi::c_void,
+            "new_string",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            string_concat
+                as extern "C" fn(
+                    *const *mut ffi::c_void,
+                    *const *mut ffi::c_void,
+                    *mut ffi::c_void,
+                ) -> *const *mut ffi::c_void,
+            "string_concat",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            string_compare
+                as extern "C" fn(
+                    *const *mut ffi::c_void,
+                    *const *mut ffi::c_void,
+                    *mut ffi::c_void,
+                ) -> i32,
+            "string_compare",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic_bounds_check as extern "C" fn(usize, usize, *mut ffi::c_void),
+            "panic_bounds_check",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic as extern "C" fn(*const u8, usize, *mut ffi::c_void),
+            "panic",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic_unwind as extern "C" fn(*const u8, usize, *mut ffi::c_void),
+            "panic_unwind",
+        ));
+
         options.user_functions.into_iter().for_each(|fn_def| {
             dispatch_table.insert_fn(fn_def.prototype.name.clone(), Arc::new(fn_def));
         });
@@ -244,10 +480,12 @@ impl Runtime {
             assemblies_to_relink: VecDeque::new(),
             dispatch_table,
             type_table,
+            global_table: GlobalTable::default(),
             watcher,
             watcher_rx: rx,
             renamed_files: HashMap::new(),
             gc: Arc::new(self::garbage_collector::GarbageCollector::default()),
+            panic_state: Arc::new(PanicState::default()),
         };
 
         runtime.add_assembly(&options.library_path)?;
@@ -289,7 +527,8 @@ impl Runtime {
                 continue;
             }
 
-            let assembly = Assembly::load(&library_path, self.gc.clone())?;
+            let assembly =
+                Assembly::load(&library_path, self.gc.clone(), self.panic_state.clone())?;
 
             let parent = library_path.parent().expect("Invalid library path");
             let extension = library_path.extension();
@@ -310,8 +549,12 @@ impl Runtime {
             }
         }
 
-        (self.dispatch_table, self.type_table) =
-            Assembly::link_all(loaded.values_mut(), &self.dispatch_table, &self.type_table)?;
+        (self.dispatch_table, self.type_table, self.global_table) = Assembly::link_all(
+            loaded.values_mut(),
+            &self.dispatch_table,
+            &self.type_table,
+            &self.global_table,
+        )?;
 
         for (library_path, assembly) in loaded.into_iter() {
             self.watcher
@@ -339,6 +582,66 @@ impl Runtime {
         self.type_table.find_type_info_by_id(type_id)
     }
 
+    /// Retrieves the global definition corresponding to `global_name`, if available.
+    pub fn get_global_definition(&self, global_name: &str) -> Option<Arc<GlobalDefinition>> {
+        self.global_table.get_global(global_name)
+    }
+
+    /// Retrieves the value of the global called `global_name`.
+    pub fn get_global<'t, T: ReturnTypeReflection + Marshal<'t> + 't>(
+        &'t self,
+        global_name: &str,
+    ) -> Result<T, String> {
+        let global_def = self
+            .get_global_definition(global_name)
+            .ok_or_else(|| format!("global `{}` does not exist.", global_name))?;
+
+        if !T::accepts_type(&global_def.ty) {
+            return Err(format!(
+                "Mismatched types for `{}`. Expected: `{}`. Found: `{}`.",
+                global_name,
+                T::type_hint(),
+                global_def.ty.name()
+            ));
+        }
+
+        // SAFETY: The pointer of a global is never null and points to a value of its type.
+        let ptr = unsafe { NonNull::new_unchecked(global_def.ptr.cast::<T::MunType>()) };
+        Ok(T::marshal_from_ptr(ptr, self, &global_def.ty))
+    }
+
+    /// Sets the value of the mutable global called `global_name` to `value`.
+    pub fn set_global<'t, T: ArgumentReflection + Marshal<'t> + 't>(
+        &'t self,
+        global_name: &str,
+        value: T,
+    ) -> Result<(), String> {
+        let global_def = self
+            .get_global_definition(global_name)
+            .ok_or_else(|| format!("global `{}` does not exist.", global_name))?;
+
+        if !global_def.mutable {
+            return Err(format!(
+                "cannot assign to immutable global `{}`.",
+                global_name
+            ));
+        }
+
+        if !value.is_assignable_to(&global_def.ty, self) {
+            return Err(format!(
+                "Mismatched types for `{}`. Expected: `{}`. Found: `{}`.",
+                global_name,
+                global_def.ty.name(),
+                value.type_info(self).name()
+            ));
+        }
+
+        // SAFETY: The pointer of a global is never null and points to a value of its type.
+        let ptr = unsafe { NonNull::new_unchecked(global_def.ptr.cast::<T::MunType>()) };
+        T::marshal_to_ptr(value, ptr, self, &global_def.ty);
+        Ok(())
+    }
+
     /// Updates the state of the runtime. This includes checking for file changes, and reloading
     /// compiled assemblies.
     /// # Safety
@@ -360,7 +663,7 @@ impl Runtime {
 
         unsafe fn relink_assemblies(
             runtime: &mut Runtime,
-        ) -> anyhow::Result<(DispatchTable, TypeTable)> {
+        ) -> anyhow::Result<(DispatchTable, TypeTable, GlobalTable)> {
             let mut loaded = HashMap::new();
             let to_load = &mut runtime.assemblies_to_relink;
 
@@ -380,7 +683,8 @@ impl Runtime {
                     continue;
                 }
 
-                let assembly = Assembly::load(&new_path, runtime.gc.clone())?;
+                let assembly =
+                    Assembly::load(&new_path, runtime.gc.clone(), runtime.panic_state.clone())?;
 
                 let parent = new_path.parent().expect("Invalid library path");
                 let extension = new_path.extension();
@@ -408,6 +712,7 @@ impl Runtime {
                 &mut runtime.assemblies,
                 &runtime.dispatch_table,
                 &runtime.type_table,
+                &runtime.global_table,
             )
         }
 
@@ -454,12 +759,14 @@ impl Runtime {
                 debug!("The compiler didn't write a munlib.");
             } else {
                 match relink_assemblies(self) {
-                    Ok((dispatch_table, type_table)) => {
+                    Ok((dispatch_table, type_table, global_table)) => {
                         info!("Succesfully reloaded assemblies.");
 
                         self.dispatch_table = dispatch_table;
                         self.type_table = type_table;
+                        self.global_table = global_table;
                         self.assemblies_to_relink.clear();
+                        self.relink_function_values();
 
                         return true;
                     }
@@ -471,6 +778,38 @@ impl Runtime {
         false
     }
 
+    /// Points all existing function values to the functions in the current dispatch table.
+    ///
+    /// The environment of a function value has the same name as the closure function that it
+    /// calls, e.g. `main::{closure#1a2b3c4d}`, and stores a pointer to that function in its first
+    /// field. After hot reloading, the pointer would still point to the function in the unloaded
+    /// assembly. A function value is only pointed to the reloaded function if that function still
+    /// takes the same environment. Otherwise the function value is pointed to a trap that panics
+    /// when the function value is called.
+    fn relink_function_values(&self) {
+        let trap = self.dispatch_table.get_fn(abi::FN_VALUE_TRAP_FN_NAME);
+        self.gc.for_each_struct(|ty, data| {
+            if !is_function_value_env(ty) {
+                return;
+            }
+
+            let fn_ptr = match self.dispatch_table.get_fn(ty.name()) {
+                Some(fn_def) if fn_def.prototype.signature.arg_types.first() == Some(ty) => {
+                    fn_def.fn_ptr
+                }
+                // The function value can only be called from an assembly that defines a trap
+                _ => match &trap {
+                    Some(trap) => trap.fn_ptr,
+                    None => return,
+                },
+            };
+
+            // Safety: the first field of the environment of a function value is a pointer to its
+            // function
+            unsafe { *data.cast::<*const c_void>().as_ptr() = fn_ptr };
+        });
+    }
+
     /// Returns a shared reference to the runtime's garbage collector.
     ///
     /// We cannot return an `Arc` here, because the lifetime of data contained in `GarbageCollector`
@@ -519,12 +858,13 @@ impl Runtime {
                 idx < array_capacity,
                 "looks like the size_hint was not properly implemented"
             );
-            assert_eq!(&element.type_info(self), element_type);
+            assert!(element.is_assignable_to(element_type, self));
 
             // Safety: the element_ptr came from NonNull and is only moved forward.
             T::marshal_to_ptr(
                 element,
                 unsafe { NonNull::new_unchecked(element_ptr).cast() },
+                self,
                 element_type,
             );
 
@@ -573,6 +913,7 @@ impl Runtime {
             T::marshal_to_ptr(
                 element,
                 unsafe { NonNull::new_unchecked(element_ptr).cast() },
+                self,
                 element_type,
             );
 
@@ -590,24 +931,64 @@ impl Runtime {
 
         ArrayRef::new(RawArray(array_handle.as_raw()), self)
     }
+
+    /// Returns the panic that occurred during the last call of a Mun function, and resets the
+    /// panic state. [`Runtime::invoke`] already does this for you; this is only required when
+    /// calling a Mun function directly through its function pointer.
+    pub fn take_panic(&self) -> Option<Panic> {
+        self.panic_state.take()
+    }
+
+    /// Constructs a string that contains a copy of `value`
+    pub fn construct_string(&self, value: &str) -> StringRef<'_> {
+        StringRef::new(
+            RawString(string::alloc_string(&self.gc, value.as_bytes())),
+            self,
+        )
+    }
 }
 
 /// An error that might occur when calling a mun function from Rust.
 pub struct InvokeErr<'name, T> {
     msg: String,
+    stack_trace: Vec<String>,
     function_name: &'name str,
     arguments: T,
 }
 
+impl<'name, T> InvokeErr<'name, T> {
+    /// Constructs an error that occurred before the function was invoked.
+    fn new(msg: String, function_name: &'name str, arguments: T) -> Self {
+        Self {
+            msg,
+            stack_trace: Vec::new(),
+            function_name,
+            arguments,
+        }
+    }
+
+    /// Returns the message that describes the error.
+    pub fn message(&self) -> &str {
+        &self.msg
+    }
+
+    /// Returns the names of the Mun functions that a panic unwound through, starting with the
+    /// function in which the panic occurred. This is empty if the error was not caused by a panic.
+    pub fn stack_trace(&self) -> &[String] {
+        &self.stack_trace
+    }
+}
+
 impl<'name, T> Debug for InvokeErr<'name, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", &self.msg)
+        Display::fmt(self, f)
     }
 }
 
 impl<'name, T> Display for InvokeErr<'name, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", &self.msg)
+        write!(f, "{}", &self.msg)?;
+        panic::fmt_stack_trace(f, &self.stack_trace)
     }
 }
 
@@ -662,7 +1043,7 @@ impl<'name, T: InvokeArgs> InvokeErr<'name, T> {
         #[allow(clippy::cast_ref_to_mut)]
         let runtime = &mut *(runtime as *const Runtime as *mut Runtime);
 
-        eprintln!("{}", self.msg);
+        eprintln!("{}", self);
         while !runtime.update() {
             // Wait until there has been an update that might fix the error
         }
@@ -686,14 +1067,14 @@ pub trait InvokeArgs {
     /// # Safety
     ///
     /// The `fn_ptr` is cast and invoked which might result in undefined behavior.
-    unsafe fn invoke<ReturnType>(self, fn_ptr: *const c_void) -> ReturnType;
+    unsafe fn invoke<ReturnType>(&self, runtime: &Runtime, fn_ptr: *const c_void) -> ReturnType;
 }
 
 // Implement `InvokeTraits` for tuples up to and including 20 elements
 seq_macro::seq!(N in 0..=20 {#(
 seq_macro::seq!(I in 0..N {
     #[allow(clippy::extra_unused_lifetimes)]
-    impl<'arg, #(T~I: ArgumentReflection + Marshal<'arg>,)*> InvokeArgs for (#(T~I,)*) {
+    impl<'arg, #(T~I: ArgumentReflection + Marshal<'arg> + Clone,)*> InvokeArgs for (#(T~I,)*) {
         #[allow(unused_variables)]
         fn can_invoke<'runtime>(&self, runtime: &'runtime Runtime, signature: &FunctionSignature) -> Result<(), String> {
             let arg_types = &signature.arg_types;
@@ -705,7 +1086,7 @@ seq_macro::seq!(I in 0..N {
             }
 
             #(
-            if arg_types[I] != self.I.type_info(runtime) {
+            if !self.I.is_assignable_to(&arg_types[I], runtime) {
                 return Err(format!(
                     "Invalid argument type at index {}. Expected: {}. Found: {}.",
                     I,
@@ -718,10 +1099,11 @@ seq_macro::seq!(I in 0..N {
             Ok(())
         }
 
-        unsafe fn invoke<ReturnType>(self, fn_ptr: *const c_void) -> ReturnType {
+        #[allow(unused_variables)]
+        unsafe fn invoke<ReturnType>(&self, runtime: &Runtime, fn_ptr: *const c_void) -> ReturnType {
             #[allow(clippy::type_complexity)]
             let function: fn(#(T~I::MunType,)*) -> ReturnType = core::mem::transmute(fn_ptr);
-            function(#(self.I.marshal_into(),)*)
+            function(#(self.I.clone().marshal_into(runtime),)*)
         }
     }
 });
@@ -751,41 +1133,40 @@ impl Runtime {
             )
         }) {
             Ok(function_info) => function_info,
-            Err(msg) => {
-                return Err(InvokeErr {
-                    msg,
-                    function_name,
-                    arguments,
-                })
-            }
+            Err(msg) => return Err(InvokeErr::new(msg, function_name, arguments)),
         };
 
         // Validate the arguments
         match arguments.can_invoke(self, &function_info.prototype.signature) {
             Ok(_) => {}
-            Err(msg) => {
-                return Err(InvokeErr {
-                    msg,
-                    function_name,
-                    arguments,
-                })
-            }
+            Err(msg) => return Err(InvokeErr::new(msg, function_name, arguments)),
         };
 
         // Validate the return type
         if !ReturnType::accepts_type(&function_info.prototype.signature.return_type) {
-            return Err(InvokeErr {
-                msg: format!(
+            return Err(InvokeErr::new(
+                format!(
                     "unexpected return type, got '{}', expected '{}",
                     &function_info.prototype.signature.return_type.name(),
                     ReturnType::type_hint()
                 ),
                 function_name,
                 arguments,
+            ));
+        }
+
+        let result: ReturnType::MunType = unsafe { arguments.invoke(self, function_info.fn_ptr) };
+
+        // A panic in Mun code is reported instead of the (meaningless) return value
+        if let Some(panic) = self.take_panic() {
+            return Err(InvokeErr {
+                msg: panic.message,
+                stack_trace: panic.stack_trace,
+                function_name,
+                arguments,
             });
         }
 
-        let result: ReturnType::MunType = unsafe { arguments.invoke(function_info.fn_ptr) };
         Ok(Marshal::marshal_from(result, self))
     }
 }
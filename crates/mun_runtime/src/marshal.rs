@@ -18,7 +18,7 @@ pub trait Marshal<'t>: Sized {
         'r: 't;
 
     /// Marshals itself into a `Marshalled` value (i.e. Rust -> Mun).
-    fn marshal_into(self) -> Self::MunType;
+    fn marshal_into(self, runtime: &Runtime) -> Self::MunType;
 
     /// Marshals the value at memory location `ptr` into a `Marshalled` value (i.e. Mun -> Rust).
     fn marshal_from_ptr<'r>(
@@ -31,5 +31,10 @@ pub trait Marshal<'t>: Sized {
         'r: 't;
 
     /// Marshals `value` to memory location `ptr` (i.e. Rust -> Mun).
-    fn marshal_to_ptr(value: Self, ptr: NonNull<Self::MunType>, type_info: &Type);
+    fn marshal_to_ptr(
+        value: Self,
+        ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        type_info: &Type,
+    );
 }
@@ -0,0 +1,87 @@
+use parking_lot::Mutex;
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A panic that occurred while executing Mun code.
+#[derive(Clone, Debug)]
+pub struct Panic {
+    pub(crate) message: String,
+    pub(crate) stack_trace: Vec<String>,
+}
+
+impl Panic {
+    /// Returns the message that describes the cause of the panic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the names of the Mun functions that the panic unwound through, starting with the
+    /// function in which the panic occurred.
+    pub fn stack_trace(&self) -> &[String] {
+        &self.stack_trace
+    }
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        fmt_stack_trace(f, &self.stack_trace)
+    }
+}
+
+/// Formats the names of the Mun functions in `stack_trace`, one per line.
+pub(crate) fn fmt_stack_trace(f: &mut Formatter<'_>, stack_trace: &[String]) -> fmt::Result {
+    if !stack_trace.is_empty() {
+        write!(f, "\nstack backtrace:")?;
+        for (idx, function_name) in stack_trace.iter().enumerate() {
+            write!(f, "\n{idx:>4}: {function_name}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Keeps track of a panic that occurred while executing Mun code.
+///
+/// Every munlib receives a pointer to the `PanicState` of its runtime through its
+/// `set_panic_handle` function. After calling another Mun function, generated code reads the first
+/// byte of this struct to determine whether it should return early to propagate a panic. The
+/// `panicking` flag must therefore remain the first field.
+#[repr(C)]
+#[derive(Default)]
+pub struct PanicState {
+    panicking: AtomicBool,
+    panic: Mutex<Option<Panic>>,
+}
+
+impl PanicState {
+    /// Signals that Mun code panicked with the specified `message`. If Mun code is already
+    /// panicking, the original panic is retained.
+    pub fn raise(&self, message: String) {
+        let mut panic = self.panic.lock();
+        if panic.is_none() {
+            *panic = Some(Panic {
+                message,
+                stack_trace: Vec::new(),
+            });
+        }
+        self.panicking.store(true, Ordering::Release);
+    }
+
+    /// Records that the current panic unwound through the Mun function called `function_name`.
+    pub fn unwind(&self, function_name: String) {
+        if let Some(panic) = self.panic.lock().as_mut() {
+            panic.stack_trace.push(function_name);
+        }
+    }
+
+    /// Resets the panic state, returning the panic if Mun code panicked.
+    pub fn take(&self) -> Option<Panic> {
+        if self.panicking.swap(false, Ordering::AcqRel) {
+            self.panic.lock().take()
+        } else {
+            None
+        }
+    }
+}
@@ -14,6 +14,12 @@ pub trait ReturnTypeReflection: Sized {
 pub trait ArgumentReflection: Sized {
     /// Retrieves the argument's type information.
     fn type_info(&self, runtime: &Runtime) -> Type;
+
+    /// Returns true if the argument can be assigned to a value of the specified type. By default,
+    /// this is only the case if the argument is of that type.
+    fn is_assignable_to(&self, ty: &Type, runtime: &Runtime) -> bool {
+        self.type_info(runtime) == *ty
+    }
 }
 
 macro_rules! impl_primitive_type {
@@ -46,7 +52,7 @@ macro_rules! impl_primitive_type {
                     value
                 }
 
-                fn marshal_into<'r>(self) -> Self::MunType {
+                fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
                     self
                 }
 
@@ -67,6 +73,7 @@ macro_rules! impl_primitive_type {
                 fn marshal_to_ptr(
                     value: Self,
                     mut ptr: std::ptr::NonNull<Self::MunType>,
+                    _runtime: &Runtime,
                     _type_info: &Type,
                 ) {
                     unsafe { *ptr.as_mut() = value };
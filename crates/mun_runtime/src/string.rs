@@ -0,0 +1,283 @@
+use crate::{
+    garbage_collector::GcRootPtr, ArgumentReflection, GarbageCollector, Marshal,
+    ReturnTypeReflection, Runtime,
+};
+use mun_memory::{
+    gc::{Array, GcPtr, GcRuntime},
+    HasStaticType, Type,
+};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    ptr::{self, NonNull},
+    sync::Arc,
+};
+
+/// Represents a Mun string pointer.
+///
+/// Strings are stored in memory as arrays of UTF-8 encoded bytes.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct RawString(pub(crate) GcPtr);
+
+/// Allocates a new string in the garbage collector `gc` that contains a copy of `value`.
+pub(crate) fn alloc_string(gc: &GarbageCollector, value: &[u8]) -> GcPtr {
+    let array_handle = gc.alloc_array(<str as HasStaticType>::type_info(), value.len());
+
+    // Safety: the array was allocated with enough capacity to hold all bytes of `value`.
+    unsafe {
+        ptr::copy_nonoverlapping(value.as_ptr(), array_handle.data().as_ptr(), value.len());
+    }
+
+    array_handle.as_raw()
+}
+
+/// Returns the UTF-8 encoded bytes stored in the Mun string referred to by `raw`.
+///
+/// # Safety
+///
+/// The returned slice is only valid as long as the string is not garbage collected.
+pub(crate) unsafe fn string_bytes<'s>(gc: &GarbageCollector, raw: &RawString) -> &'s [u8] {
+    let array_handle = gc
+        .array(raw.0)
+        .expect("the internal handle does not refer to a string");
+    std::slice::from_raw_parts(array_handle.data().as_ptr(), array_handle.length())
+}
+
+/// Type-agnostic wrapper for interoperability with a Mun string. This is merely a reference to the
+/// Mun string, that will be garbage collected unless it is rooted.
+#[derive(Clone)]
+pub struct StringRef<'s> {
+    raw: RawString,
+    runtime: &'s Runtime,
+}
+
+impl<'s> StringRef<'s> {
+    /// Creates a `StringRef` that wraps a raw Mun string.
+    pub(crate) fn new<'r>(raw: RawString, runtime: &'r Runtime) -> Self
+    where
+        'r: 's,
+    {
+        Self { raw, runtime }
+    }
+
+    /// Consumes the `StringRef`, returning a raw Mun string.
+    pub fn into_raw(self) -> RawString {
+        self.raw
+    }
+
+    /// Roots the `StringRef`.
+    pub fn root(self) -> RootedString {
+        RootedString::new(&self.runtime.gc, self.raw)
+    }
+
+    /// Returns the number of bytes in the string.
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns true if the string does not contain any bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the contents of the string.
+    pub fn as_str(&self) -> &str {
+        // Safety: Mun strings always contain valid UTF-8 and the string is kept alive by the
+        // borrow of the runtime.
+        unsafe { std::str::from_utf8_unchecked(string_bytes(&self.runtime.gc, &self.raw)) }
+    }
+}
+
+impl<'s> Display for StringRef<'s> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<'s> Debug for StringRef<'s> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<'s> ReturnTypeReflection for StringRef<'s> {
+    fn accepts_type(ty: &Type) -> bool {
+        ty == <str as HasStaticType>::type_info()
+    }
+
+    fn type_hint() -> &'static str {
+        <str as HasStaticType>::type_info().name()
+    }
+}
+
+impl<'s> ArgumentReflection for StringRef<'s> {
+    fn type_info(&self, _runtime: &Runtime) -> Type {
+        <str as HasStaticType>::type_info().clone()
+    }
+}
+
+impl<'s> Marshal<'s> for StringRef<'s> {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        StringRef::new(value, runtime)
+    }
+
+    fn marshal_into(self, _runtime: &Runtime) -> Self::MunType {
+        self.raw
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        _type_info: &Type,
+    ) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        let handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+        StringRef::new(RawString(handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        _type_info: &Type,
+    ) {
+        unsafe { *ptr.as_mut() = value.into_raw() };
+    }
+}
+
+impl ReturnTypeReflection for String {
+    fn accepts_type(ty: &Type) -> bool {
+        ty == <str as HasStaticType>::type_info()
+    }
+
+    fn type_hint() -> &'static str {
+        <str as HasStaticType>::type_info().name()
+    }
+}
+
+impl ArgumentReflection for String {
+    fn type_info(&self, _runtime: &Runtime) -> Type {
+        <str as HasStaticType>::type_info().clone()
+    }
+}
+
+/// A `String` is marshalled by copying its contents from or into a newly allocated Mun string.
+impl<'s> Marshal<'s> for String {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        StringRef::new(value, runtime).as_str().to_owned()
+    }
+
+    fn marshal_into(self, runtime: &Runtime) -> Self::MunType {
+        RawString(alloc_string(&runtime.gc, self.as_bytes()))
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        type_info: &Type,
+    ) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        StringRef::marshal_from_ptr(ptr, runtime, type_info)
+            .as_str()
+            .to_owned()
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        _type_info: &Type,
+    ) {
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
+
+impl ArgumentReflection for &str {
+    fn type_info(&self, _runtime: &Runtime) -> Type {
+        <str as HasStaticType>::type_info().clone()
+    }
+}
+
+/// A `&str` is marshalled into Mun by copying its contents into a newly allocated Mun string. When
+/// marshalling from Mun, the returned slice borrows the memory of the Mun string, which is only
+/// valid until the string is garbage collected.
+impl<'s> Marshal<'s> for &'s str {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        // Safety: Mun strings always contain valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(string_bytes(&runtime.gc, &value)) }
+    }
+
+    fn marshal_into(self, runtime: &Runtime) -> Self::MunType {
+        RawString(alloc_string(&runtime.gc, self.as_bytes()))
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        _type_info: &Type,
+    ) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        let handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+        Self::marshal_from(RawString(handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        _type_info: &Type,
+    ) {
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
+
+/// Type-agnostic wrapper for interoperability with a Mun string, that has been rooted. To marshal,
+/// obtain a `StringRef` for the `RootedString`.
+#[derive(Clone)]
+pub struct RootedString {
+    handle: GcRootPtr,
+}
+
+impl RootedString {
+    /// Creates a `RootedString` that wraps a raw Mun string.
+    fn new(gc: &Arc<GarbageCollector>, raw: RawString) -> Self {
+        assert!(gc.ptr_type(raw.0) == *<str as HasStaticType>::type_info());
+        Self {
+            handle: GcRootPtr::new(gc, raw.0),
+        }
+    }
+
+    /// Converts the `RootedString` into a `StringRef`, using an external shared reference to a
+    /// `Runtime`.
+    pub fn as_ref<'r>(&self, runtime: &'r Runtime) -> StringRef<'r> {
+        assert_eq!(Arc::as_ptr(&runtime.gc), self.handle.runtime().as_ptr());
+        StringRef::new(RawString(self.handle.handle()), runtime)
+    }
+}
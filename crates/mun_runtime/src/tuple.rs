@@ -0,0 +1,159 @@
+//! Rust tuples are marshalled to and from Mun tuples. A Mun tuple is a value struct whose fields
+//! are named after their index. Like value structs, tuples are passed by reference in the public
+//! API.
+
+use crate::{
+    adt::RawStruct,
+    marshal::Marshal,
+    reflection::{ArgumentReflection, ReturnTypeReflection},
+    Runtime,
+};
+use mun_abi as abi;
+use mun_memory::{
+    gc::{GcRuntime, HasIndirectionPtr},
+    StructTypeBuilder, Type,
+};
+use std::ptr::NonNull;
+
+/// Returns the type of the tuple with the specified element types. If the runtime knows the type,
+/// because it is used by a Mun function, the type of the runtime is returned.
+fn tuple_type_info(runtime: &Runtime, element_types: &[Type]) -> Type {
+    let element_names: Vec<&str> = element_types.iter().map(Type::name).collect();
+    let name = if element_names.len() == 1 {
+        format!("({},)", element_names[0])
+    } else {
+        format!("({})", element_names.join(", "))
+    };
+
+    runtime.get_type_info_by_name(&name).unwrap_or_else(|| {
+        StructTypeBuilder::new(name)
+            .set_memory_kind(abi::StructMemoryKind::Value)
+            .add_fields(
+                element_types
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, ty)| (idx.to_string(), ty.clone())),
+            )
+            .finish()
+    })
+}
+
+/// Returns true if `ty` describes a tuple with `len` elements.
+fn is_tuple_type(ty: &Type, len: usize) -> bool {
+    let struct_type = match ty.as_struct() {
+        Some(struct_type) if struct_type.is_value_struct() => struct_type,
+        _ => return false,
+    };
+
+    let fields = struct_type.fields();
+    fields.len() == len
+        && fields
+            .iter()
+            .enumerate()
+            .all(|(idx, field)| field.name() == idx.to_string())
+}
+
+/// Returns a pointer to the element at `offset` of the tuple at `ptr`.
+///
+/// # Safety
+///
+/// The offset must be the location of a variable of type T.
+unsafe fn element_ptr<T>(ptr: NonNull<RawStruct>, offset: usize) -> NonNull<T> {
+    NonNull::new_unchecked(ptr.cast::<u8>().as_ptr().add(offset).cast::<T>())
+}
+
+// Implement marshalling for tuples up to and including 12 elements
+seq_macro::seq!(N in 1..=12 {#(
+seq_macro::seq!(I in 0..N {
+    impl<#(T~I: ArgumentReflection,)*> ArgumentReflection for (#(T~I,)*) {
+        fn type_info(&self, runtime: &Runtime) -> Type {
+            tuple_type_info(runtime, &[#(self.I.type_info(runtime),)*])
+        }
+    }
+
+    impl<#(T~I: ReturnTypeReflection,)*> ReturnTypeReflection for (#(T~I,)*) {
+        fn accepts_type(ty: &Type) -> bool {
+            if !is_tuple_type(ty, N) {
+                return false;
+            }
+
+            // Safety: `is_tuple_type` guarantees that the type is a struct with `N` fields.
+            let fields = ty.as_struct().unwrap().fields();
+            [#(T~I::accepts_type(&fields.get(I).unwrap().ty()),)*]
+                .iter()
+                .all(|accepted| *accepted)
+        }
+
+        fn type_hint() -> &'static str {
+            "tuple"
+        }
+    }
+
+    impl<'t, #(T~I: ArgumentReflection + Marshal<'t>,)*> Marshal<'t> for (#(T~I,)*) {
+        type MunType = RawStruct;
+
+        fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+        where
+            Self: 't,
+            'r: 't,
+        {
+            let type_info = runtime.gc().ptr_type(value.0);
+
+            // SAFETY: The memory of an allocated tuple is never null
+            let ptr = unsafe { NonNull::new_unchecked(value.get_ptr() as *mut RawStruct) };
+            Self::marshal_from_ptr(ptr, runtime, &type_info)
+        }
+
+        fn marshal_into(self, runtime: &Runtime) -> Self::MunType {
+            let type_info = self.type_info(runtime);
+            let mut gc_handle = runtime.gc().alloc(&type_info);
+
+            // SAFETY: The memory of an allocated tuple is never null
+            let ptr = unsafe { NonNull::new_unchecked(gc_handle.deref_mut::<RawStruct>()) };
+            Self::marshal_to_ptr(self, ptr, runtime, &type_info);
+            RawStruct(gc_handle)
+        }
+
+        fn marshal_from_ptr<'r>(
+            ptr: NonNull<Self::MunType>,
+            runtime: &'r Runtime,
+            type_info: &Type,
+        ) -> Self
+        where
+            Self: 't,
+            'r: 't,
+        {
+            let fields = type_info
+                .as_struct()
+                .expect("a tuple must be a struct")
+                .fields();
+            (#({
+                let field = fields.get(I).expect("missing tuple element");
+
+                // SAFETY: The offset in the ABI is always valid.
+                let field_ptr = unsafe { element_ptr::<T~I::MunType>(ptr, field.offset()) };
+                T~I::marshal_from_ptr(field_ptr, runtime, &field.ty())
+            },)*)
+        }
+
+        fn marshal_to_ptr(
+            value: Self,
+            ptr: NonNull<Self::MunType>,
+            runtime: &Runtime,
+            type_info: &Type,
+        ) {
+            let fields = type_info
+                .as_struct()
+                .expect("a tuple must be a struct")
+                .fields();
+            #({
+                let field = fields.get(I).expect("missing tuple element");
+
+                // SAFETY: The offset in the ABI is always valid.
+                let field_ptr = unsafe { element_ptr::<T~I::MunType>(ptr, field.offset()) };
+                T~I::marshal_to_ptr(value.I, field_ptr, runtime, &field.ty());
+            })*
+        }
+    }
+});
+)*});
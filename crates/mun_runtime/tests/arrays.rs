@@ -114,3 +114,187 @@ fn construct_array_simple() {
     assert_eq!(array.len(), test_data.len());
     assert_eq!(array.iter().collect_vec(), test_data);
 }
+
+#[test]
+fn for_loop_over_array() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn sum(array: [i32]) -> i32 {
+        let total = 0;
+        for value in array {
+            total += value;
+        }
+        total
+    }
+
+    pub fn sum_literal() -> i32 {
+        sum([1, 2, 3, 4, 5])
+    }
+
+    pub fn first_negative(array: [i32]) -> i32 {
+        let found = 0;
+        for value in array {
+            if value < 0 {
+                found = value;
+                break;
+            }
+        }
+        found
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("sum_literal", ()).unwrap();
+    assert_eq!(result, 15);
+
+    let array = driver.runtime.construct_array(vec![3, -4, 5, -6]);
+    let result: i32 = driver.runtime.invoke("sum", (array,)).unwrap();
+    assert_eq!(result, -2);
+
+    let array = driver.runtime.construct_array(vec![3, -4, 5, -6]);
+    let result: i32 = driver.runtime.invoke("first_negative", (array,)).unwrap();
+    assert_eq!(result, -4);
+
+    let array = driver.runtime.construct_array(Vec::<i32>::new());
+    let result: i32 = driver.runtime.invoke("sum", (array,)).unwrap();
+    assert_eq!(result, 0);
+}
+
+#[test]
+fn index_out_of_bounds() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn get(index: i32) -> i32 {
+        let array = [1, 2, 3];
+        array[index]
+    }
+
+    pub fn set(index: usize) -> [i32] {
+        let array = [1, 2, 3];
+        array[index] = 0;
+        array
+    }
+
+    fn nested(index: i32) -> i32 { get(index) + 1 }
+    pub fn outer(index: i32) -> i32 { nested(index) * 2 }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("get", (2i32,)).unwrap();
+    assert_eq!(result, 3);
+
+    let result: Result<i32, _> = driver.runtime.invoke("get", (3i32,));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "index out of bounds: the length is 3 but the index is 3"
+    );
+
+    let result: Result<i32, _> = driver.runtime.invoke("get", (-1i32,));
+    assert!(result.is_err());
+
+    let result: Result<ArrayRef<'_, i32>, _> = driver.runtime.invoke("set", (5usize,));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "index out of bounds: the length is 3 but the index is 5"
+    );
+
+    // A panic propagates through Mun functions
+    let result: Result<i32, _> = driver.runtime.invoke("outer", (4i32,));
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.message(),
+        "index out of bounds: the length is 3 but the index is 4"
+    );
+    assert_eq!(err.stack_trace(), ["get", "nested", "outer"]);
+
+    // The runtime recovers after a panic
+    let result: i32 = driver.runtime.invoke("outer", (1i32,)).unwrap();
+    assert_eq!(result, 6);
+}
+
+#[test]
+fn array_methods() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn build(count: usize) -> [i32] {
+        let array = [];
+        let i = 0;
+        while i < count {
+            array.push(i as i32);
+            i += 1;
+        }
+        array
+    }
+
+    pub fn shuffle() -> [i32] {
+        let array = [1, 2, 3];
+        array.insert(0, 4);
+        array.insert(array.len(), 5);
+        let last = array.pop();
+        let second = array.remove(1);
+        array.push(last + second);
+        array
+    }
+
+    pub fn len(array: [i32]) -> usize { array.len() }
+    pub fn pop_all(array: [i32]) -> i32 {
+        let sum = 0;
+        while array.len() > 0 {
+            sum += array.pop();
+        }
+        sum
+    }
+
+    pub fn pop_empty() -> i32 {
+        let array: [i32] = [];
+        array.pop()
+    }
+    pub fn insert_past_end() {
+        let array = [1];
+        array.insert(2, 0);
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    // Growing an array reallocates its elements
+    let result: ArrayRef<'_, i32> = driver.runtime.invoke("build", (100usize,)).unwrap();
+    assert_eq!(result.len(), 100);
+    assert!(result.capacity() >= 100);
+    assert_eq!(
+        result.iter().collect::<Vec<_>>(),
+        (0..100).collect::<Vec<_>>()
+    );
+
+    let result: ArrayRef<'_, i32> = driver.runtime.invoke("shuffle", ()).unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), vec![4, 2, 3, 6]);
+
+    // Arrays modified by the host are observed by Mun
+    let mut array = driver.runtime.construct_array(vec![1i32, 2, 3]);
+    array.push(4);
+    array.set(0, 10);
+    let result: usize = driver.runtime.invoke("len", (array.clone(),)).unwrap();
+    assert_eq!(result, 4);
+    array.truncate(2);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![10, 2]);
+    let result: i32 = driver.runtime.invoke("pop_all", (array.clone(),)).unwrap();
+    assert_eq!(result, 12);
+    assert_eq!(array.len(), 0);
+
+    let result: Result<i32, _> = driver.runtime.invoke("pop_empty", ());
+    assert_eq!(
+        result.unwrap_err().message(),
+        "cannot pop from an empty array"
+    );
+
+    let result: Result<(), _> = driver.runtime.invoke("insert_past_end", ());
+    assert_eq!(
+        result.unwrap_err().message(),
+        "index out of bounds: the length is 1 but the index is 2"
+    );
+}
@@ -0,0 +1,116 @@
+#[macro_use]
+mod util;
+
+use mun_test::CompileAndRunTestDriver;
+
+#[test]
+fn const_and_static() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    const STEP: i32 = 2 * 5;
+    pub static mut COUNTER: i32 = 1;
+
+    pub fn increment() -> i32 {
+        COUNTER += STEP;
+        COUNTER
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_eq!(driver.runtime.get_global::<i32>("COUNTER"), Ok(1));
+    assert_invoke_eq!(i32, 11, driver, "increment");
+    assert_eq!(driver.runtime.get_global::<i32>("COUNTER"), Ok(11));
+
+    driver
+        .runtime
+        .set_global("COUNTER", 3i32)
+        .expect("failed to set global");
+    assert_invoke_eq!(i32, 13, driver, "increment");
+}
+
+#[test]
+fn static_errors() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub static LIMIT: f64 = 1.5;
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_eq!(driver.runtime.get_global::<f64>("LIMIT"), Ok(1.5));
+    assert!(driver.runtime.get_global::<i32>("LIMIT").is_err());
+    assert!(driver.runtime.get_global::<f64>("UNKNOWN").is_err());
+    assert!(driver.runtime.set_global("LIMIT", 2.0f64).is_err());
+}
+
+#[test]
+fn hotreload_static_value() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r"
+    pub static mut SCORE: i32 = 0;
+    pub static mut LIVES: bool = true;
+
+    pub fn score() -> i32 {
+        SCORE += 1;
+        SCORE
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 1, driver, "score");
+    assert_invoke_eq!(i32, 2, driver, "score");
+    driver
+        .runtime
+        .set_global("LIVES", false)
+        .expect("failed to set global");
+
+    driver.update(
+        "mod.mun",
+        r"
+    pub static mut SCORE: i64 = 0;
+    pub static mut LIVES: bool = true;
+
+    pub fn score() -> i64 {
+        SCORE += 10;
+        SCORE
+    }
+    ",
+    );
+
+    // The value is preserved and cast to the new type
+    assert_eq!(driver.runtime.get_global::<i64>("SCORE"), Ok(2));
+    assert_eq!(driver.runtime.get_global::<bool>("LIVES"), Ok(false));
+    assert_invoke_eq!(i64, 12, driver, "score");
+}
+
+#[test]
+fn static_from_other_module() {
+    let driver = CompileAndRunTestDriver::from_fixture(
+        r#"
+    //- /mun.toml
+    [package]
+    name="foo"
+    version="0.0.0"
+
+    //- /src/mod.mun
+    pub fn next_id() -> u32 {
+        package::ids::NEXT_ID += package::ids::STEP;
+        package::ids::NEXT_ID
+    }
+
+    //- /src/ids.mun
+    pub const STEP: u32 = 1;
+    pub static mut NEXT_ID: u32 = 100;
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(u32, 101, driver, "next_id");
+    assert_eq!(driver.runtime.get_global::<u32>("ids::NEXT_ID"), Ok(101));
+}
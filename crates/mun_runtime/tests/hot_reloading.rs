@@ -1,6 +1,7 @@
 #[macro_use]
 mod util;
 
+use mun_runtime::StructRef;
 use mun_test::CompileAndRunTestDriver;
 
 #[test]
@@ -62,3 +63,498 @@ fn hotreload_struct_decl() {
     "#,
     );
 }
+
+#[test]
+fn hotreload_function_value() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        Holder { f: |x: i32| x + offset }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let holder: StructRef = driver.runtime.invoke("make", (2i32,)).unwrap();
+    let root = holder.root();
+
+    // The environment of the closure is traced through the rooted struct
+    assert!(!driver.runtime.gc_collect());
+    assert_invoke_eq!(i32, 5, driver, "call", root.as_ref(&driver.runtime), 3i32);
+
+    driver.update(
+        "mod.mun",
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        Holder { f: |x: i32| x * offset }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+    );
+
+    // Existing function values call the reloaded closure
+    assert_invoke_eq!(i32, 6, driver, "call", root.as_ref(&driver.runtime), 3i32);
+}
+
+#[test]
+fn hotreload_function_value_insert_closure() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        Holder { f: |x: i32| x + offset }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let holder: StructRef = driver.runtime.invoke("make", (2i32,)).unwrap();
+    let root = holder.root();
+    assert_invoke_eq!(i32, 5, driver, "call", root.as_ref(&driver.runtime), 3i32);
+
+    driver.update(
+        "mod.mun",
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        let scale = 2;
+        let scaled = |x: i32| x * scale;
+        Holder { f: |x: i32| x + offset + 10 }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+    );
+
+    // Inserting a closure before an existing closure doesn't change which closure an existing
+    // function value calls
+    assert_invoke_eq!(i32, 15, driver, "call", root.as_ref(&driver.runtime), 3i32);
+}
+
+#[test]
+fn hotreload_function_value_remove_closure() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        Holder { f: |x: i32| x + offset }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let holder: StructRef = driver.runtime.invoke("make", (2i32,)).unwrap();
+    let root = holder.root();
+
+    driver.update(
+        "mod.mun",
+        r#"
+    pub struct Holder {
+        f: fn(i32) -> i32,
+    }
+
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    pub fn make(offset: i32) -> Holder {
+        Holder { f: double }
+    }
+
+    pub fn call(holder: Holder, x: i32) -> i32 {
+        let f = holder.f;
+        f(x)
+    }
+    "#,
+    );
+
+    // Calling a function value whose closure was removed panics
+    let result: Result<i32, _> = driver
+        .runtime
+        .invoke("call", (root.as_ref(&driver.runtime), 3i32));
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.message(),
+        "the function of the function value was removed by hot reloading"
+    );
+    assert_eq!(err.stack_trace(), ["call"]);
+
+    // New function values call the new function
+    let holder: StructRef = driver.runtime.invoke("make", (2i32,)).unwrap();
+    assert_invoke_eq!(i32, 6, driver, "call", holder, 3i32);
+}
+
+#[test]
+fn hotreload_trait_impl() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+
+    pub struct Enemy {
+        health: f32,
+    }
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: f32) {
+            self.health -= amount;
+        }
+    }
+
+    pub struct Holder {
+        target: dyn Damageable,
+    }
+
+    pub fn make(enemy: Enemy) -> Holder {
+        Holder { target: enemy }
+    }
+
+    pub fn hit(holder: Holder) {
+        holder.target.damage(1.0);
+    }
+
+    pub fn new_enemy() -> Enemy {
+        Enemy { health: 10.0 }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let enemy: StructRef = driver.runtime.invoke("new_enemy", ()).unwrap();
+    let enemy = enemy.root();
+    let holder: StructRef = driver
+        .runtime
+        .invoke("make", (enemy.as_ref(&driver.runtime),))
+        .unwrap();
+    let holder = holder.root();
+
+    let _: () = driver
+        .runtime
+        .invoke("hit", (holder.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(
+        enemy.as_ref(&driver.runtime).get::<f32>("health").unwrap(),
+        9.0
+    );
+
+    driver.update(
+        "mod.mun",
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+
+    pub struct Enemy {
+        health: f32,
+    }
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: f32) {
+            self.health -= amount * 2.0;
+        }
+    }
+
+    pub struct Holder {
+        target: dyn Damageable,
+    }
+
+    pub fn make(enemy: Enemy) -> Holder {
+        Holder { target: enemy }
+    }
+
+    pub fn hit(holder: Holder) {
+        holder.target.damage(1.0);
+    }
+
+    pub fn new_enemy() -> Enemy {
+        Enemy { health: 10.0 }
+    }
+    "#,
+    );
+
+    // Existing trait objects call the reloaded implementation
+    let _: () = driver
+        .runtime
+        .invoke("hit", (holder.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(
+        enemy.as_ref(&driver.runtime).get::<f32>("health").unwrap(),
+        7.0
+    );
+}
+
+#[test]
+fn hotreload_remove_trait_impl() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+
+    pub struct Enemy {
+        health: f32,
+    }
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: f32) {
+            self.health -= amount;
+        }
+    }
+
+    pub struct Holder {
+        target: dyn Damageable,
+    }
+
+    pub fn make(enemy: Enemy) -> Holder {
+        Holder { target: enemy }
+    }
+
+    pub fn hit(holder: Holder) {
+        holder.target.damage(1.0);
+    }
+
+    pub fn new_enemy() -> Enemy {
+        Enemy { health: 10.0 }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let enemy: StructRef = driver.runtime.invoke("new_enemy", ()).unwrap();
+    let enemy = enemy.root();
+    let holder: StructRef = driver
+        .runtime
+        .invoke("make", (enemy.as_ref(&driver.runtime),))
+        .unwrap();
+    let holder = holder.root();
+
+    driver.update(
+        "mod.mun",
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+
+    pub struct Enemy {
+        health: f32,
+    }
+
+    pub struct Wall {
+        hits: i32,
+    }
+
+    impl Damageable for Wall {
+        fn damage(self: Wall, amount: f32) {
+            self.hits += 1;
+        }
+    }
+
+    pub struct Holder {
+        target: dyn Damageable,
+    }
+
+    pub fn hit(holder: Holder) {
+        holder.target.damage(1.0);
+    }
+
+    pub fn new_enemy() -> Enemy {
+        Enemy { health: 10.0 }
+    }
+    "#,
+    );
+
+    // Calling a method through a trait object whose type no longer implements the trait panics
+    let result: Result<(), _> = driver
+        .runtime
+        .invoke("hit", (holder.as_ref(&driver.runtime),));
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.message(),
+        "the type of the trait object no longer implements `Damageable`"
+    );
+    assert_eq!(err.stack_trace(), ["hit"]);
+    assert_eq!(
+        enemy.as_ref(&driver.runtime).get::<f32>("health").unwrap(),
+        10.0
+    );
+}
+
+#[test]
+fn hotreload_optional_to_reference() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Bar {
+        value: i32,
+    }
+
+    pub struct Foo {
+        bar: ?Bar,
+    }
+
+    pub fn empty_foo() -> Foo {
+        Foo { bar: nil }
+    }
+
+    pub fn foo_with_bar() -> Foo {
+        Foo { bar: Bar { value: 5 } }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let empty_foo: StructRef = driver.runtime.invoke("empty_foo", ()).unwrap();
+    let empty_foo = empty_foo.root();
+    let foo_with_bar: StructRef = driver.runtime.invoke("foo_with_bar", ()).unwrap();
+    let foo_with_bar = foo_with_bar.root();
+
+    driver.update(
+        "mod.mun",
+        r#"
+    pub struct Bar {
+        value: i32,
+    }
+
+    pub struct Foo {
+        bar: Bar,
+    }
+
+    pub fn bar_value(foo: Foo) -> i32 {
+        foo.bar.value
+    }
+    "#,
+    );
+
+    // A `nil` reference is replaced by a newly allocated default value
+    let bar_value: i32 = driver
+        .runtime
+        .invoke("bar_value", (empty_foo.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(bar_value, 0);
+
+    // An existing reference is kept
+    let bar_value: i32 = driver
+        .runtime
+        .invoke("bar_value", (foo_with_bar.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(bar_value, 5);
+}
+
+#[test]
+fn hotreload_delete_enum_variant() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Bar {
+        value: i32,
+    }
+
+    pub enum Value {
+        Ref(Bar),
+        Int(i32),
+    }
+
+    pub struct Foo {
+        value: Value,
+    }
+
+    pub fn int_foo() -> Foo {
+        Foo { value: Value::Int(5) }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let int_foo: StructRef = driver.runtime.invoke("int_foo", ()).unwrap();
+    let int_foo = int_foo.root();
+
+    driver.update(
+        "mod.mun",
+        r#"
+    pub struct Bar {
+        value: i32,
+    }
+
+    pub enum Value {
+        Ref(Bar),
+    }
+
+    pub struct Foo {
+        value: Value,
+        inserted: Value,
+    }
+
+    pub fn bar_value(foo: Foo) -> i32 {
+        match foo.value {
+            Value::Ref(bar) => bar.value,
+        }
+    }
+
+    pub fn inserted_bar_value(foo: Foo) -> i32 {
+        match foo.inserted {
+            Value::Ref(bar) => bar.value,
+        }
+    }
+    "#,
+    );
+
+    // The value of a deleted variant is replaced by the first variant, with a newly allocated
+    // default value
+    let bar_value: i32 = driver
+        .runtime
+        .invoke("bar_value", (int_foo.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(bar_value, 0);
+
+    // An inserted enum field is initialized the same way
+    let bar_value: i32 = driver
+        .runtime
+        .invoke("inserted_bar_value", (int_foo.as_ref(&driver.runtime),))
+        .unwrap();
+    assert_eq!(bar_value, 0);
+}
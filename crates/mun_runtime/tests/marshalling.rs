@@ -1,4 +1,4 @@
-use mun_runtime::{ArgumentReflection, Marshal, ReturnTypeReflection, StructRef};
+use mun_runtime::{ArgumentReflection, ArrayRef, Marshal, ReturnTypeReflection, StructRef};
 
 use mun_test::CompileAndRunTestDriver;
 
@@ -735,3 +735,163 @@ fn signed_and_unsigned_rem() {
     assert_invoke_eq!(i32, -2, driver, "signed");
     assert_invoke_eq!(i32, 2, driver, "unsigned");
 }
+
+#[test]
+fn numeric_casts() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn int_to_float(a: i32) -> f64 { a as f64 }
+    pub fn float_to_int(a: f32) -> i64 { a as i64 }
+    pub fn truncate(a: u32) -> u8 { a as u8 }
+    pub fn sign_extend(a: i8) -> i64 { a as i64 }
+    pub fn zero_extend(a: u8) -> i32 { a as i32 }
+    pub fn signed_to_unsigned(a: i16) -> u16 { a as u16 }
+    pub fn bool_to_int(a: bool) -> u8 { a as u8 }
+    pub fn narrow_float(a: f64) -> f32 { a as f32 }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f64, -3.0, driver, "int_to_float", -3i32);
+    assert_invoke_eq!(i64, 2, driver, "float_to_int", 2.75f32);
+    assert_invoke_eq!(u8, 44, driver, "truncate", 300u32);
+    assert_invoke_eq!(i64, -5, driver, "sign_extend", -5i8);
+    assert_invoke_eq!(i32, 255, driver, "zero_extend", 255u8);
+    assert_invoke_eq!(u16, 65535, driver, "signed_to_unsigned", -1i16);
+    assert_invoke_eq!(u8, 1, driver, "bool_to_int", true);
+    assert_invoke_eq!(f32, 1.5, driver, "narrow_float", 1.5f64);
+}
+
+#[test]
+fn saturating_float_to_int_casts() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn to_i32(a: f64) -> i32 { a as i32 }
+    pub fn to_u8(a: f32) -> u8 { a as u8 }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 0, driver, "to_i32", f64::NAN);
+    assert_invoke_eq!(i32, i32::MAX, driver, "to_i32", f64::INFINITY);
+    assert_invoke_eq!(i32, i32::MIN, driver, "to_i32", f64::NEG_INFINITY);
+    assert_invoke_eq!(i32, i32::MAX, driver, "to_i32", 1e10f64);
+    assert_invoke_eq!(i32, i32::MIN, driver, "to_i32", -1e10f64);
+    assert_invoke_eq!(i32, -7, driver, "to_i32", -7.9f64);
+
+    assert_invoke_eq!(u8, 0, driver, "to_u8", f32::NAN);
+    assert_invoke_eq!(u8, u8::MAX, driver, "to_u8", f32::INFINITY);
+    assert_invoke_eq!(u8, 0, driver, "to_u8", f32::NEG_INFINITY);
+    assert_invoke_eq!(u8, u8::MAX, driver, "to_u8", 256.5f32);
+    assert_invoke_eq!(u8, 0, driver, "to_u8", -1.5f32);
+    assert_invoke_eq!(u8, 42, driver, "to_u8", 42.9f32);
+}
+
+#[test]
+fn marshal_tuple() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn div_rem(a: i32, b: i32) -> (i32, i32) {
+        (a / b, a % b)
+    }
+
+    pub fn swap(pair: (f32, i32)) -> (i32, f32) {
+        let (a, b) = pair;
+        (b, a)
+    }
+
+    pub fn sum(point: (i64, i64, i64)) -> i64 {
+        point.0 + point.1 + point.2
+    }
+
+    pub fn nested() -> ((bool, u8), f64) {
+        let inner = (true, 7);
+        inner.1 += 1;
+        (inner, 2.5)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!((i32, i32), (3, 1), driver, "div_rem", 7i32, 2i32);
+    assert_invoke_eq!((i32, f32), (4, 1.5), driver, "swap", (1.5f32, 4i32));
+    assert_invoke_eq!(i64, 6, driver, "sum", (1i64, 2i64, 3i64));
+    assert_invoke_eq!(((bool, u8), f64), ((true, 8), 2.5), driver, "nested");
+
+    let result: Result<(i32, f32), _> = driver.runtime.invoke("div_rem", (7i32, 2i32));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "unexpected return type, got '(core::i32, core::i32)', expected 'tuple"
+    );
+}
+
+#[test]
+fn marshal_optional() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Node { value: i32, next: ?Node }
+
+    pub fn new_list() -> Node {
+        let tail = Node { value: 2, next: nil };
+        Node { value: 1, next: tail }
+    }
+
+    pub fn find(node: Node, value: i32) -> ?Node {
+        let current: ?Node = node;
+        while let n = current {
+            if n.value == value {
+                return n;
+            }
+            current = n.next;
+        }
+        nil
+    }
+
+    pub fn values(node: ?Node) -> ?[i32] {
+        if let n = node { [n.value] } else { nil }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let list: StructRef = driver.runtime.invoke("new_list", ()).unwrap();
+    let next: Option<StructRef> = list.get("next").unwrap();
+    let tail = next.expect("the list must contain a second node");
+    assert_eq!(tail.get::<i32>("value"), Ok(2));
+    assert!(tail.get::<Option<StructRef>>("next").unwrap().is_none());
+
+    let found: Option<StructRef> = driver.runtime.invoke("find", (list.clone(), 2i32)).unwrap();
+    assert_eq!(found.unwrap().get::<i32>("value"), Ok(2));
+
+    let found: Option<StructRef> = driver.runtime.invoke("find", (list.clone(), 3i32)).unwrap();
+    assert!(found.is_none());
+
+    let values: Option<ArrayRef<i32>> = driver.runtime.invoke("values", (list.clone(),)).unwrap();
+    assert_eq!(values.unwrap().iter().collect::<Vec<_>>(), vec![1]);
+
+    // `nil` can be passed to an optional parameter
+    let values: Option<ArrayRef<i32>> = driver
+        .runtime
+        .invoke("values", (None::<StructRef>,))
+        .unwrap();
+    assert!(values.is_none());
+
+    // `nil` can be assigned to an optional field, but not to a field of another type
+    let mut list = list;
+    assert!(list.set("value", None::<StructRef>).is_err());
+    list.set("next", None::<StructRef>).unwrap();
+    assert!(list.get::<Option<StructRef>>("next").unwrap().is_none());
+
+    list.set("next", Some(tail)).unwrap();
+    assert_eq!(
+        list.get::<Option<StructRef>>("next")
+            .unwrap()
+            .unwrap()
+            .get::<i32>("value"),
+        Ok(2)
+    );
+}
@@ -0,0 +1,51 @@
+#[macro_use]
+mod util;
+
+use mun_test::CompileAndRunTestDriver;
+
+#[test]
+fn float_intrinsics() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    use core::math;
+
+    pub fn length(x: f32, y: f32) -> f32 { math::sqrt(x * x + y * y) }
+    pub fn wave(x: f64) -> f64 { math::sin(x) + math::cos(x) }
+    pub fn clamp(x: f64, lo: f64, hi: f64) -> f64 { math::min(math::max(x, lo), hi) }
+    pub fn round_down(x: f32) -> f32 { math::floor(x) }
+    pub fn distance(a: f64, b: f64) -> f64 { math::abs(a - b) }
+    pub fn cube(x: f64) -> f64 { core::math::pow(x, 3.0) }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f32, 5.0, driver, "length", 3.0f32, 4.0f32);
+    assert_invoke_eq!(f64, 1.0, driver, "wave", 0.0f64);
+    assert_invoke_eq!(f64, 2.0, driver, "clamp", 5.0f64, 1.0f64, 2.0f64);
+    assert_invoke_eq!(f64, 1.0, driver, "clamp", -5.0f64, 1.0f64, 2.0f64);
+    assert_invoke_eq!(f32, -2.0, driver, "round_down", -1.5f32);
+    assert_invoke_eq!(f64, 1.5, driver, "distance", 1.0f64, 2.5f64);
+    assert_invoke_eq!(f64, 8.0, driver, "cube", 2.0f64);
+}
+
+#[test]
+fn integer_intrinsics() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    use core::math::*;
+
+    pub fn abs_i32(x: i32) -> i32 { abs(x) }
+    pub fn abs_u8(x: u8) -> u8 { abs(x) }
+    pub fn min_i64(a: i64, b: i64) -> i64 { min(a, b) }
+    pub fn max_u32(a: u32, b: u32) -> u32 { max(a, b) }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 7, driver, "abs_i32", -7i32);
+    assert_invoke_eq!(u8, 200, driver, "abs_u8", 200u8);
+    assert_invoke_eq!(i64, -3, driver, "min_i64", -3i64, 4i64);
+    assert_invoke_eq!(u32, u32::MAX, driver, "max_u32", u32::MAX, 1u32);
+}
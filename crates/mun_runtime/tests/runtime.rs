@@ -1,3 +1,5 @@
+use mun_compiler::Config;
+use mun_runtime::StructRef;
 use mun_test::CompileAndRunTestDriver;
 use std::io;
 
@@ -89,6 +91,300 @@ fn arrays() {
     assert_invoke_eq!(u16, 9, driver, "main");
 }
 
+#[test]
+fn impl_methods() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    impl Vec2 {
+        pub fn new(x: f32, y: f32) -> Vec2 {
+            Vec2 { x, y }
+        }
+
+        fn dot(self: Vec2, other: Vec2) -> f32 {
+            self.x * other.x + self.y * other.y
+        }
+
+        pub fn length_squared(self: Vec2) -> f32 {
+            self.dot(self)
+        }
+    }
+
+    pub fn main() -> f32 {
+        let a = Vec2 { x: 3.0, y: 4.0 };
+        a.length_squared()
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f32, 25.0, driver, "main");
+
+    // Functions in `impl` blocks are exported with the name of their type as prefix
+    let vec: StructRef = driver
+        .runtime
+        .invoke("Vec2::new", (3.0f32, 4.0f32))
+        .unwrap();
+    assert_eq!(vec.get::<f32>("y").unwrap(), 4.0);
+    let length_squared: f32 = driver
+        .runtime
+        .invoke("Vec2::length_squared", (vec,))
+        .unwrap();
+    assert_eq!(length_squared, 25.0);
+}
+
+#[test]
+fn generics() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Pair<A, B> {
+        a: A,
+        b: B,
+    }
+
+    fn select<T>(condition: bool, a: T, b: T) -> T {
+        if condition { a } else { b }
+    }
+
+    fn swap<A, B>(pair: Pair<A, B>) -> Pair<B, A> {
+        Pair { a: pair.b, b: pair.a }
+    }
+
+    pub fn make_pair(a: i32, b: f32) -> Pair<i32, f32> {
+        Pair { a, b }
+    }
+
+    pub fn main() -> f32 {
+        let pair = swap(make_pair(3, 4.0));
+        select(true, pair.a, 2.0) + select(false, 1.0, 2.0) + swap(pair).a as f32
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f32, 9.0, driver, "main");
+
+    // Instances of generic structs can be used from the host
+    let pair: StructRef = driver.runtime.invoke("make_pair", (1i32, 2.0f32)).unwrap();
+    assert_eq!(pair.get::<i32>("a").unwrap(), 1);
+    assert_eq!(pair.get::<f32>("b").unwrap(), 2.0);
+}
+
+#[test]
+fn generic_operators() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) Score {
+        value: i32,
+    }
+
+    impl Score {
+        fn gt(self: Score, rhs: Score) -> bool {
+            self.value > rhs.value
+        }
+    }
+
+    fn max<T>(a: T, b: T) -> T {
+        if a > b { a } else { b }
+    }
+
+    fn sub<T>(a: T, b: T) -> T {
+        a + -b
+    }
+
+    pub fn max_i32(a: i32, b: i32) -> i32 {
+        max(a, b)
+    }
+
+    pub fn max_f64(a: f64, b: f64) -> f64 {
+        max(a, b)
+    }
+
+    pub fn max_score(a: i32, b: i32) -> i32 {
+        max(Score { value: a }, Score { value: b }).value
+    }
+
+    pub fn sub_f32(a: f32, b: f32) -> f32 {
+        sub(a, b)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 4, driver, "max_i32", 3i32, 4i32);
+    assert_invoke_eq!(f64, 3.5, driver, "max_f64", 3.5f64, -4.0f64);
+    assert_invoke_eq!(i32, 7, driver, "max_score", 7i32, 2i32);
+    assert_invoke_eq!(f32, 1.5, driver, "sub_f32", 4.0f32, 2.5f32);
+}
+
+#[test]
+fn closures() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct Counter {
+        count: i32,
+    }
+
+    fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+        f(x)
+    }
+
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    fn make_adder(offset: i32) -> fn(i32) -> i32 {
+        |x: i32| x + offset
+    }
+
+    pub fn main() -> i32 {
+        let counter = Counter { count: 3 };
+        let add_count = |x: i32| -> i32 { x + counter.count };
+        let add_five = make_adder(5);
+        apply(double, 1) + apply(add_five, 2) + add_count(4) + (|| 1)()
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 17, driver, "main");
+}
+
+#[test]
+fn traits() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    trait Damageable {
+        fn damage(self: Self, amount: f32);
+        fn health(self: Self) -> f32;
+    }
+
+    struct Enemy {
+        health: f32,
+    }
+
+    struct Wall {
+        hits: i32,
+    }
+
+    impl Damageable for Enemy {
+        fn damage(self: Enemy, amount: f32) {
+            self.health -= amount;
+        }
+
+        fn health(self: Enemy) -> f32 {
+            self.health
+        }
+    }
+
+    impl Damageable for Wall {
+        fn damage(self: Wall, amount: f32) {
+            self.hits += 1;
+        }
+
+        fn health(self: Wall) -> f32 {
+            100.0 - self.hits as f32
+        }
+    }
+
+    fn hit(target: dyn Damageable) -> f32 {
+        target.damage(2.0);
+        target.health()
+    }
+
+    pub fn main() -> f32 {
+        let enemy = Enemy { health: 10.0 };
+        let wall: dyn Damageable = Wall { hits: 0 };
+        hit(enemy) + hit(wall) + enemy.health()
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f32, 115.0, driver, "main");
+}
+
+#[test]
+fn operator_overloading() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) Vec3 {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    impl Vec3 {
+        fn add(self: Vec3, rhs: Vec3) -> Vec3 {
+            Vec3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+        }
+
+        fn sub(self: Vec3, rhs: Vec3) -> Vec3 {
+            Vec3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+        }
+
+        fn mul(self: Vec3, rhs: f32) -> Vec3 {
+            Vec3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+        }
+
+        fn eq(self: Vec3, rhs: Vec3) -> bool {
+            self.x == rhs.x && self.y == rhs.y && self.z == rhs.z
+        }
+    }
+
+    struct Score {
+        value: i32,
+    }
+
+    trait Compare {
+        fn lt(self: Self, rhs: Score) -> bool;
+    }
+
+    impl Compare for Score {
+        fn lt(self: Score, rhs: Score) -> bool {
+            self.value < rhs.value
+        }
+    }
+
+    impl Score {
+        fn add(self: Score, rhs: i32) -> Score {
+            Score { value: self.value + rhs }
+        }
+    }
+
+    pub fn main() -> f32 {
+        let a = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let b = a * 2.0 - a;
+        let c = a + b;
+        c += a;
+        if a == b && a != c { c.x + c.y + c.z } else { 0.0 }
+    }
+
+    pub fn is_lower(a: i32, b: i32) -> bool {
+        let a = Score { value: a };
+        let b = Score { value: b };
+        a += 1;
+        a < b
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f32, 18.0, driver, "main");
+    assert_invoke_eq!(bool, true, driver, "is_lower", 1i32, 3i32);
+    assert_invoke_eq!(bool, false, driver, "is_lower", 2i32, 3i32);
+}
+
 #[test]
 fn multiple_modules() {
     let driver = CompileAndRunTestDriver::from_fixture(
@@ -230,3 +526,398 @@ fn cyclic_struct() {
         .ty();
     assert_eq!(foo_foo_ty, foo_ty);
 }
+
+#[test]
+fn match_expr() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    enum Shape {
+        Empty,
+        Square(f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    struct(value) Point { x: i32, y: i32 }
+
+    fn area(shape: Shape) -> f64 {
+        match shape {
+            Shape::Empty => 0.0,
+            Shape::Square(size) => size * size,
+            Shape::Rect { width, height } => width * height,
+        }
+    }
+
+    fn quadrant(p: Point) -> i32 {
+        match p {
+            Point { x: 0, .. } => 0,
+            Point { y: 0, .. } => 0,
+            Point { x: 1..=2147483647, y: 1..=2147483647 } => 1,
+            Point { x: -2147483648..=-1, y: 1..=2147483647 } => 2,
+            Point { x, y } => if x < 0 { 3 } else { 4 },
+        }
+    }
+
+    pub fn total_area(size: f64) -> f64 {
+        area(Shape::Empty) + area(Shape::Square(size)) + area(Shape::Rect { width: size, height: 2.0 })
+    }
+
+    pub fn classify(x: i32, y: i32) -> i32 {
+        quadrant(Point { x, y })
+    }
+
+    pub fn is_small(a: u8) -> bool {
+        match a {
+            0..=9 => true,
+            _ => false,
+        }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: f64 = driver.runtime.invoke("total_area", (3.0f64,)).unwrap();
+    assert_eq!(result, 15.0);
+
+    for ((x, y), quadrant) in [
+        ((0, 5), 0),
+        ((1, 1), 1),
+        ((-1, 1), 2),
+        ((-1, -1), 3),
+        ((1, -1), 4),
+    ] {
+        let result: i32 = driver.runtime.invoke("classify", (x, y)).unwrap();
+        assert_eq!(result, quadrant);
+    }
+
+    let result: bool = driver.runtime.invoke("is_small", (5u8,)).unwrap();
+    assert!(result);
+    let result: bool = driver.runtime.invoke("is_small", (10u8,)).unwrap();
+    assert!(!result);
+}
+
+#[test]
+fn for_loop_over_range() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn sum_exclusive(n: i32) -> i32 {
+        let total = 0;
+        for i in 0..n {
+            total += i;
+        }
+        total
+    }
+
+    pub fn sum_inclusive(n: i32) -> i32 {
+        let total = 0;
+        for i in 0..=n {
+            total += i;
+        }
+        total
+    }
+
+    pub fn count_to_max() -> u32 {
+        let count = 0;
+        for i in 250u8..=255 {
+            count += 1;
+        }
+        count
+    }
+
+    pub fn first_multiple(n: i64, of: i64) -> i64 {
+        let result = -1;
+        for i in 1..n {
+            if i % of == 0 {
+                result = i;
+                break;
+            }
+        }
+        result
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("sum_exclusive", (10i32,)).unwrap();
+    assert_eq!(result, 45);
+    let result: i32 = driver.runtime.invoke("sum_exclusive", (-5i32,)).unwrap();
+    assert_eq!(result, 0);
+    let result: i32 = driver.runtime.invoke("sum_inclusive", (10i32,)).unwrap();
+    assert_eq!(result, 55);
+    let result: u32 = driver.runtime.invoke("count_to_max", ()).unwrap();
+    assert_eq!(result, 6);
+    let result: i64 = driver
+        .runtime
+        .invoke("first_multiple", (100i64, 7i64))
+        .unwrap();
+    assert_eq!(result, 7);
+    let result: i64 = driver
+        .runtime
+        .invoke("first_multiple", (5i64, 7i64))
+        .unwrap();
+    assert_eq!(result, -1);
+}
+
+#[test]
+fn for_loop_break_value() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Item { value: i32 }
+
+    pub fn items() -> [Item] {
+        [Item { value: 3 }, Item { value: -4 }, Item { value: 5 }]
+    }
+
+    pub fn find(items: [Item], value: i32) -> ?Item {
+        for item in items {
+            if item.value == value {
+                break item;
+            }
+        }
+    }
+
+    pub fn first_negative(items: [Item]) -> i32 {
+        let found: ?Item = for item in items {
+            if item.value < 0 { break item; }
+        };
+        if let item = found { item.value } else { 0 }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let items: mun_runtime::ArrayRef<'_, StructRef> = driver.runtime.invoke("items", ()).unwrap();
+    let found: Option<StructRef> = driver
+        .runtime
+        .invoke("find", (items.clone(), 5i32))
+        .unwrap();
+    assert_eq!(found.unwrap().get::<i32>("value"), Ok(5));
+    let found: Option<StructRef> = driver
+        .runtime
+        .invoke("find", (items.clone(), 6i32))
+        .unwrap();
+    assert!(found.is_none());
+
+    let result: i32 = driver.runtime.invoke("first_negative", (items,)).unwrap();
+    assert_eq!(result, -4);
+}
+
+#[test]
+fn continue_and_labeled_loops() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn sum_odd(n: i32) -> i32 {
+        let total = 0;
+        for i in 0..n {
+            if i % 2 == 0 {
+                continue;
+            }
+            total += i;
+        }
+        total
+    }
+
+    pub fn count_while(n: i32) -> i32 {
+        let i = 0;
+        let count = 0;
+        while i < n {
+            i += 1;
+            if i == 2 {
+                continue;
+            }
+            count += 1;
+        }
+        count
+    }
+
+    pub fn find_pair(sum: i32) -> i32 {
+        'outer: loop {
+            let a = 0;
+            loop {
+                let b = 0;
+                'inner: while b < 10 {
+                    if a + b == sum {
+                        break 'outer a * 10 + b;
+                    }
+                    b += 1;
+                    if b > a {
+                        break 'inner;
+                    }
+                }
+                a += 1;
+                if a == 10 {
+                    break 'outer -1;
+                }
+            }
+        }
+    }
+
+    pub fn skip_rows(n: i32) -> i32 {
+        let count = 0;
+        'rows: for row in 0..n {
+            for column in 0..n {
+                if column > row {
+                    continue 'rows;
+                }
+                count += 1;
+            }
+        }
+        count
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("sum_odd", (10i32,)).unwrap();
+    assert_eq!(result, 25);
+    let result: i32 = driver.runtime.invoke("count_while", (5i32,)).unwrap();
+    assert_eq!(result, 4);
+    let result: i32 = driver.runtime.invoke("find_pair", (5i32,)).unwrap();
+    assert_eq!(result, 32);
+    let result: i32 = driver.runtime.invoke("find_pair", (100i32,)).unwrap();
+    assert_eq!(result, -1);
+    let result: i32 = driver.runtime.invoke("skip_rows", (4i32,)).unwrap();
+    assert_eq!(result, 10);
+}
+
+#[test]
+fn division_by_zero_panics() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn divide(a: i32, b: i32) -> i32 { a / b }
+    pub fn remainder(a: u8, b: u8) -> u8 { a % b }
+    pub fn divide_assign(a: i64, b: i64) -> i64 {
+        a /= b;
+        a
+    }
+    pub fn average(sum: i32, count: i32) -> i32 { divide(sum, count) }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 3, driver, "divide", 7i32, 2i32);
+    assert_invoke_eq!(u8, 1, driver, "remainder", 7u8, 2u8);
+
+    let result: Result<i32, _> = driver.runtime.invoke("divide", (7i32, 0i32));
+    let err = result.unwrap_err();
+    assert_eq!(err.message(), "attempt to divide by zero");
+    assert_eq!(err.stack_trace(), ["divide"]);
+
+    let result: Result<i32, _> = driver.runtime.invoke("divide", (i32::MIN, -1i32));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to divide with overflow"
+    );
+
+    let result: Result<u8, _> = driver.runtime.invoke("remainder", (7u8, 0u8));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to calculate the remainder with a divisor of zero"
+    );
+
+    let result: Result<i64, _> = driver.runtime.invoke("divide_assign", (7i64, 0i64));
+    assert_eq!(result.unwrap_err().message(), "attempt to divide by zero");
+
+    let result: Result<i32, _> = driver.runtime.invoke("average", (10i32, 0i32));
+    let err = result.unwrap_err();
+    assert_eq!(err.stack_trace(), ["divide", "average"]);
+    assert_eq!(
+        err.to_string(),
+        "attempt to divide by zero\nstack backtrace:\n   0: divide\n   1: average"
+    );
+}
+
+#[test]
+fn explicit_panic() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    fn check_positive(value: i32) -> i32 {
+        if value < 0 {
+            panic("value must be positive")
+        }
+        value
+    }
+
+    pub fn double_positive(value: i32) -> i32 {
+        check_positive(value) * 2
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 8, driver, "double_positive", 4i32);
+
+    let result: Result<i32, _> = driver.runtime.invoke("double_positive", (-1i32,));
+    let err = result.unwrap_err();
+    assert_eq!(err.message(), "value must be positive");
+    assert_eq!(err.stack_trace(), ["check_positive", "double_positive"]);
+}
+
+#[test]
+fn overflow_checks() {
+    let source = r"
+    pub fn add(a: u8, b: u8) -> u8 { a + b }
+    pub fn subtract(a: u32, b: u32) -> u32 { a - b }
+    pub fn multiply_assign(a: i16, b: i16) -> i16 {
+        a *= b;
+        a
+    }
+    pub fn negate(a: i64) -> i64 { -a }
+    pub fn shift_left(a: i32, b: i32) -> i32 { a << b }
+    ";
+
+    // Without overflow checks, arithmetic wraps around
+    let driver = CompileAndRunTestDriver::new(source, |builder| builder)
+        .expect("Failed to build test driver");
+    assert_invoke_eq!(u8, 0, driver, "add", 255u8, 1u8);
+    assert_invoke_eq!(u32, u32::MAX, driver, "subtract", 0u32, 1u32);
+
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        source,
+        Config {
+            overflow_checks: true,
+            ..Config::default()
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(u8, 255, driver, "add", 254u8, 1u8);
+    assert_invoke_eq!(i16, -600, driver, "multiply_assign", 20i16, -30i16);
+    assert_invoke_eq!(i64, -5, driver, "negate", 5i64);
+    assert_invoke_eq!(i32, 8, driver, "shift_left", 1i32, 3i32);
+
+    let result: Result<u8, _> = driver.runtime.invoke("add", (255u8, 1u8));
+    let err = result.unwrap_err();
+    assert_eq!(err.message(), "attempt to add with overflow");
+    assert_eq!(err.stack_trace(), ["add"]);
+
+    let result: Result<u32, _> = driver.runtime.invoke("subtract", (0u32, 1u32));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to subtract with overflow"
+    );
+
+    let result: Result<i16, _> = driver.runtime.invoke("multiply_assign", (300i16, 300i16));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to multiply with overflow"
+    );
+
+    let result: Result<i64, _> = driver.runtime.invoke("negate", (i64::MIN,));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to negate with overflow"
+    );
+
+    let result: Result<i32, _> = driver.runtime.invoke("shift_left", (1i32, 32i32));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "attempt to shift left with overflow"
+    );
+}
@@ -0,0 +1,99 @@
+use mun_runtime::StringRef;
+use mun_test::CompileAndRunTestDriver;
+
+#[macro_use]
+mod util;
+
+#[test]
+fn string_literal() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn main() -> string { "Hello, \"world\"!\n" }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: StringRef<'_> = driver.runtime.invoke("main", ()).unwrap();
+    assert_eq!(result.as_str(), "Hello, \"world\"!\n");
+    assert_eq!(result.len(), 16);
+
+    let result: String = driver.runtime.invoke("main", ()).unwrap();
+    assert_eq!(result, "Hello, \"world\"!\n");
+}
+
+#[test]
+fn string_concatenation() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn greet(name: string) -> string {
+        let greeting = "Hello, " + name;
+        greeting += "!";
+        greeting
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: String = driver.runtime.invoke("greet", ("Mun",)).unwrap();
+    assert_eq!(result, "Hello, Mun!");
+
+    let name = driver.runtime.construct_string("ünïcödé");
+    let result: StringRef<'_> = driver.runtime.invoke("greet", (name,)).unwrap();
+    assert_eq!(result.as_str(), "Hello, ünïcödé!");
+}
+
+#[test]
+fn string_comparison() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn equal(a: string, b: string) -> bool { a == b }
+    pub fn not_equal(a: string, b: string) -> bool { a != b }
+    pub fn less(a: string, b: string) -> bool { a < b }
+    pub fn greater_equal(a: string, b: string) -> bool { a >= b }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(bool, true, driver, "equal", "abc", "abc");
+    assert_invoke_eq!(bool, false, driver, "equal", "abc", "abd");
+    assert_invoke_eq!(bool, true, driver, "not_equal", "abc", "ab");
+    assert_invoke_eq!(bool, true, driver, "less", "ab", "abc");
+    assert_invoke_eq!(bool, false, driver, "less", "b", "abc");
+    assert_invoke_eq!(bool, true, driver, "greater_equal", "b", "b");
+}
+
+#[test]
+fn string_len() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn len(s: string) -> usize { s.len() }
+    pub fn literal_len() -> usize { "Mun".len() }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(usize, 6, driver, "len", String::from("ünï"));
+    assert_invoke_eq!(usize, 3, driver, "literal_len");
+}
+
+#[test]
+fn root_string() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn main() -> string { "rooted" + " string" }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: StringRef<'_> = driver.runtime.invoke("main", ()).unwrap();
+    let rooted = result.root();
+
+    driver.runtime.gc_collect();
+
+    assert_eq!(rooted.as_ref(&driver.runtime).as_str(), "rooted string");
+}
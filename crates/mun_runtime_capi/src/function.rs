@@ -3,6 +3,8 @@
 use mun_capi_utils::error::ErrorHandle;
 use mun_capi_utils::{mun_error_try, try_deref_mut};
 use mun_memory::ffi::{Type, Types};
+
+use crate::runtime::Runtime;
 use std::{
     ffi::{c_void, CString},
     os::raw::c_char,
@@ -177,9 +179,34 @@ pub unsafe extern "C" fn mun_function_return_type(
     ErrorHandle::default()
 }
 
+/// Checks whether the last call of a Mun function - through its function pointer - panicked. If
+/// so, a non-zero error handle is returned that contains the panic message followed by the names
+/// of the Mun functions that the panic unwound through, and the panic is cleared. The return value
+/// of a function that panicked is meaningless and must not be used.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_function_check_panic(runtime: Runtime) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    match runtime.take_panic() {
+        Some(panic) => ErrorHandle::new(panic.to_string()),
+        None => ErrorHandle::default(),
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
+    use crate::{test_invalid_runtime, test_util::TestDriver};
+    use mun_capi_utils::error::mun_error_destroy;
     use mun_capi_utils::{
         assert_error_snapshot, assert_getter1, mun_string_destroy, try_convert_c_string,
     };
@@ -392,4 +419,33 @@ pub(crate) mod tests {
 
         assert!(unsafe { mun_type_equal(return_type, i32::type_info().clone().into()) });
     }
+
+    test_invalid_runtime!(function_check_panic());
+
+    #[test]
+    fn test_function_check_panic() {
+        let driver = TestDriver::new(
+            r#"
+        pub fn divide(a: i32, b: i32) -> i32 { a / b }
+        pub fn average(sum: i32, count: i32) -> i32 { divide(sum, count) }
+    "#,
+        );
+
+        let runtime = unsafe { driver.runtime.inner() }.unwrap();
+        let average: extern "C" fn(i32, i32) -> i32 = unsafe {
+            std::mem::transmute(runtime.get_function_definition("average").unwrap().fn_ptr)
+        };
+
+        assert_eq!(average(10, 2), 5);
+        assert!(unsafe { mun_function_check_panic(driver.runtime) }.is_ok());
+
+        average(10, 0);
+        assert_error_snapshot!(
+            unsafe { mun_function_check_panic(driver.runtime) },
+            @r###""attempt to divide by zero\nstack backtrace:\n   0: divide\n   1: average""###
+        );
+
+        // The panic is cleared after it has been checked
+        assert!(unsafe { mun_function_check_panic(driver.runtime) }.is_ok());
+    }
 }
@@ -6,6 +6,7 @@
 
 pub mod gc;
 pub mod runtime;
+pub mod string;
 
 pub mod function;
 
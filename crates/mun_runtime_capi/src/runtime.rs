@@ -308,6 +308,113 @@ pub unsafe extern "C" fn mun_runtime_get_type_info_by_id(
     ErrorHandle::default()
 }
 
+/// Retrieves the value of the global called `global_name` from the `runtime` and copies it to
+/// `value`. If successful, `value` is set, otherwise a non-zero error handle is returned. The
+/// `global_type` must match the type of the global.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_runtime_get_global(
+    runtime: Runtime,
+    global_name: *const c_char,
+    global_type: Type,
+    value: *mut c_void,
+) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    let global_name = mun_error_try!(try_convert_c_string(global_name)
+        .map_err(|e| format!("invalid argument 'global_name': {e}")));
+    let global_type = mun_error_try!(global_type
+        .to_owned()
+        .map_err(|e| format!("invalid argument 'global_type': {e}"))
+        .map(ManuallyDrop::new));
+    if value.is_null() {
+        return ErrorHandle::new("invalid argument 'value': null pointer");
+    }
+
+    let global_def = mun_error_try!(runtime
+        .get_global_definition(global_name)
+        .ok_or_else(|| format!("global `{global_name}` does not exist.")));
+    if global_def.ty != *global_type {
+        return ErrorHandle::new(format!(
+            "Mismatched types for `{}`. Expected: `{}`. Found: `{}`.",
+            global_name,
+            global_type.name(),
+            global_def.ty.name()
+        ));
+    }
+
+    std::ptr::copy_nonoverlapping(
+        global_def.ptr as *const u8,
+        value as *mut u8,
+        global_def.ty.value_layout().size(),
+    );
+    ErrorHandle::default()
+}
+
+/// Sets the value of the mutable global called `global_name` in the `runtime` to the value
+/// pointed to by `value`. The `global_type` must match the type of the global. If unsuccessful, a
+/// non-zero error handle is returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_runtime_set_global(
+    runtime: Runtime,
+    global_name: *const c_char,
+    global_type: Type,
+    value: *const c_void,
+) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    let global_name = mun_error_try!(try_convert_c_string(global_name)
+        .map_err(|e| format!("invalid argument 'global_name': {e}")));
+    let global_type = mun_error_try!(global_type
+        .to_owned()
+        .map_err(|e| format!("invalid argument 'global_type': {e}"))
+        .map(ManuallyDrop::new));
+    if value.is_null() {
+        return ErrorHandle::new("invalid argument 'value': null pointer");
+    }
+
+    let global_def = mun_error_try!(runtime
+        .get_global_definition(global_name)
+        .ok_or_else(|| format!("global `{global_name}` does not exist.")));
+    if !global_def.mutable {
+        return ErrorHandle::new(format!(
+            "cannot assign to immutable global `{global_name}`."
+        ));
+    }
+    if global_def.ty != *global_type {
+        return ErrorHandle::new(format!(
+            "Mismatched types for `{}`. Expected: `{}`. Found: `{}`.",
+            global_name,
+            global_def.ty.name(),
+            global_type.name()
+        ));
+    }
+
+    std::ptr::copy_nonoverlapping(
+        value as *const u8,
+        global_def.ptr as *mut u8,
+        global_def.ty.value_layout().size(),
+    );
+    ErrorHandle::default()
+}
+
 /// Updates the runtime corresponding to `handle`. If successful, `updated` is set, otherwise a
 /// non-zero error handle is returned.
 ///
@@ -341,6 +448,8 @@ mod tests {
         runtime_find_function_definition(ptr::null(), 0, ptr::null_mut(), ptr::null_mut()),
         runtime_get_type_info_by_name(ptr::null(), ptr::null_mut(), ptr::null_mut()),
         runtime_get_type_info_by_id(ptr::null(), ptr::null_mut(), ptr::null_mut()),
+        runtime_get_global(ptr::null(), Type::null(), ptr::null_mut()),
+        runtime_set_global(ptr::null(), Type::null(), ptr::null()),
         runtime_update(ptr::null_mut())
     );
 
@@ -910,4 +1019,93 @@ mod tests {
 
         assert_getter1!(mun_runtime_update(driver.runtime, _updated));
     }
+
+    #[test]
+    fn test_runtime_get_global_invalid_global_name() {
+        let driver = TestDriver::new(
+            r#"
+            pub static mut COUNTER: i32 = 5;
+    "#,
+        );
+
+        let mut value = 0i32;
+        assert_error_snapshot!(
+            unsafe {
+                mun_runtime_get_global(
+                    driver.runtime,
+                    ptr::null(),
+                    i32::type_info().clone().into(),
+                    &mut value as *mut i32 as *mut _,
+                )
+            },
+            @r###""invalid argument \'global_name\': null pointer""###
+        );
+    }
+
+    #[test]
+    fn test_runtime_get_and_set_global() {
+        let driver = TestDriver::new(
+            r#"
+            pub static mut COUNTER: i32 = 5;
+    "#,
+        );
+
+        let global_name = CString::new("COUNTER").expect("Invalid global name");
+        let mut value = 0i32;
+        let handle = unsafe {
+            mun_runtime_get_global(
+                driver.runtime,
+                global_name.as_ptr(),
+                i32::type_info().clone().into(),
+                &mut value as *mut i32 as *mut _,
+            )
+        };
+        assert!(handle.is_ok());
+        assert_eq!(value, 5);
+
+        let new_value = 7i32;
+        let handle = unsafe {
+            mun_runtime_set_global(
+                driver.runtime,
+                global_name.as_ptr(),
+                i32::type_info().clone().into(),
+                &new_value as *const i32 as *const _,
+            )
+        };
+        assert!(handle.is_ok());
+
+        let handle = unsafe {
+            mun_runtime_get_global(
+                driver.runtime,
+                global_name.as_ptr(),
+                i32::type_info().clone().into(),
+                &mut value as *mut i32 as *mut _,
+            )
+        };
+        assert!(handle.is_ok());
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_runtime_set_global_immutable() {
+        let driver = TestDriver::new(
+            r#"
+            pub static LIMIT: i32 = 10;
+    "#,
+        );
+
+        let global_name = CString::new("LIMIT").expect("Invalid global name");
+        let value = 7i32;
+        assert_error_snapshot!(
+            unsafe {
+                mun_runtime_set_global(
+                    driver.runtime,
+                    global_name.as_ptr(),
+                    i32::type_info().clone().into(),
+                    &value as *const i32 as *const _,
+                )
+            },
+            @r###""cannot assign to immutable global `LIMIT`.""###
+        );
+    }
 }
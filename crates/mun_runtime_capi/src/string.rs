@@ -0,0 +1,217 @@
+//! Exposes Mun strings.
+
+use mun_capi_utils::error::ErrorHandle;
+use mun_capi_utils::{mun_error_try, try_deref_mut};
+use std::{os::raw::c_char, ptr, slice};
+
+use crate::{gc::GcPtr, runtime::Runtime};
+use mun_memory::{
+    gc::{Array, GcRuntime},
+    HasStaticType,
+};
+
+/// Allocates a string in the runtime that contains a copy of the `len` UTF-8 encoded bytes
+/// pointed to by `value`. If successful, `obj` is set, otherwise a non-zero error handle is
+/// returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_new(
+    runtime: Runtime,
+    value: *const c_char,
+    len: usize,
+    obj: *mut GcPtr,
+) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    if value.is_null() {
+        return ErrorHandle::new("invalid argument 'value': null pointer");
+    }
+    let value = mun_error_try!(
+        std::str::from_utf8(slice::from_raw_parts(value as *const u8, len))
+            .map_err(|_error| String::from("invalid argument 'value': invalid UTF-8 encoded"))
+    );
+    let obj = try_deref_mut!(obj);
+
+    let array_handle = runtime
+        .gc()
+        .alloc_array(<str as HasStaticType>::type_info(), value.len());
+    ptr::copy_nonoverlapping(value.as_ptr(), array_handle.data().as_ptr(), value.len());
+    *obj = array_handle.as_raw();
+
+    ErrorHandle::default()
+}
+
+/// Retrieves the number of bytes in the string `obj`. If successful, `len` is set, otherwise a
+/// non-zero error handle is returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_len(
+    runtime: Runtime,
+    obj: GcPtr,
+    len: *mut usize,
+) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    if runtime.gc().ptr_type(obj) != *<str as HasStaticType>::type_info() {
+        return ErrorHandle::new("invalid argument 'obj': not a string");
+    }
+    let len = try_deref_mut!(len);
+    *len = runtime
+        .gc()
+        .array(obj)
+        .expect("a string must be an array")
+        .length();
+    ErrorHandle::default()
+}
+
+/// Retrieves a pointer to the UTF-8 encoded bytes of the string `obj`. If successful, `data` is
+/// set, otherwise a non-zero error handle is returned. The bytes are not null-terminated; use
+/// [`mun_string_len`] to retrieve the number of bytes. The pointer is only valid as long as the
+/// string is not collected.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_data(
+    runtime: Runtime,
+    obj: GcPtr,
+    data: *mut *const c_char,
+) -> ErrorHandle {
+    let runtime = mun_error_try!(runtime
+        .inner()
+        .map_err(|e| format!("invalid argument 'runtime': {e}")));
+    if runtime.gc().ptr_type(obj) != *<str as HasStaticType>::type_info() {
+        return ErrorHandle::new("invalid argument 'obj': not a string");
+    }
+    let data = try_deref_mut!(data);
+    *data = runtime
+        .gc()
+        .array(obj)
+        .expect("a string must be an array")
+        .data()
+        .as_ptr() as *const c_char;
+    ErrorHandle::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gc::{mun_gc_collect, mun_gc_root, mun_gc_unroot},
+        test_invalid_runtime,
+        test_util::TestDriver,
+    };
+    use mun_capi_utils::error::mun_error_destroy;
+    use mun_capi_utils::{assert_error_snapshot, assert_getter1, assert_getter2, assert_getter3};
+    use std::{mem, ptr};
+
+    test_invalid_runtime!(
+        string_new(ptr::null(), 0, ptr::null_mut()),
+        string_len(mem::zeroed::<GcPtr>(), ptr::null_mut()),
+        string_data(mem::zeroed::<GcPtr>(), ptr::null_mut())
+    );
+
+    #[test]
+    fn test_string_new_invalid_value() {
+        let driver = TestDriver::new(
+            r#"
+        pub fn main() -> string { "Mun" }
+    "#,
+        );
+
+        assert_error_snapshot!(
+            unsafe { mun_string_new(driver.runtime, ptr::null(), 0, ptr::null_mut()) },
+            @r###""invalid argument \'value\': null pointer""###
+        );
+
+        let invalid = [0xffu8, 0xfe];
+        assert_error_snapshot!(
+            unsafe {
+                mun_string_new(
+                    driver.runtime,
+                    invalid.as_ptr() as *const c_char,
+                    invalid.len(),
+                    ptr::null_mut(),
+                )
+            },
+            @r###""invalid argument \'value\': invalid UTF-8 encoded""###
+        );
+    }
+
+    #[test]
+    fn test_string_new_invalid_obj() {
+        let driver = TestDriver::new(
+            r#"
+        pub fn main() -> string { "Mun" }
+    "#,
+        );
+
+        let value = "Mun";
+        assert_error_snapshot!(
+            unsafe {
+                mun_string_new(
+                    driver.runtime,
+                    value.as_ptr() as *const c_char,
+                    value.len(),
+                    ptr::null_mut(),
+                )
+            },
+            @r###""invalid argument \'obj\': null pointer""###
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        let driver = TestDriver::new(
+            r#"
+        pub fn main() -> string { "Mun" }
+    "#,
+        );
+
+        let value = "Hello, Mun!";
+        assert_getter3!(mun_string_new(
+            driver.runtime,
+            value.as_ptr() as *const c_char,
+            value.len(),
+            obj,
+        ));
+
+        assert!(unsafe { mun_gc_root(driver.runtime, obj) }.is_ok());
+
+        assert_getter1!(mun_gc_collect(driver.runtime, reclaimed));
+        assert!(!reclaimed);
+
+        assert_getter2!(mun_string_len(driver.runtime, obj, len));
+        assert_eq!(len, value.len());
+
+        assert_getter2!(mun_string_data(driver.runtime, obj, data));
+        let bytes = unsafe { slice::from_raw_parts(data as *const u8, len) };
+        assert_eq!(bytes, value.as_bytes());
+
+        assert!(unsafe { mun_gc_unroot(driver.runtime, obj) }.is_ok());
+
+        assert_getter1!(mun_gc_collect(driver.runtime, reclaimed));
+        assert!(reclaimed);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ast::{self, child_opt, children, AstChildren, AstToken, BinExpr, Literal},
-    AstNode, SyntaxKind, SyntaxToken, TextRange, TextSize,
+    AstNode, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
 };
 use std::ops::Add;
 
@@ -154,6 +154,38 @@ impl BinExpr {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangeOp {
+    /// The `..` operator for a range that excludes its end
+    Exclusive,
+    /// The `..=` operator for a range that includes its end
+    Inclusive,
+}
+
+impl ast::RangeExpr {
+    pub fn op_kind(&self) -> Option<RangeOp> {
+        self.op_token().map(|token| match token.kind() {
+            T![..=] => RangeOp::Inclusive,
+            _ => RangeOp::Exclusive,
+        })
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|token| matches!(token.kind(), T![..] | T![..=]))
+    }
+
+    pub fn start(&self) -> Option<ast::Expr> {
+        children(self).next()
+    }
+
+    pub fn end(&self) -> Option<ast::Expr> {
+        children(self).nth(1)
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub enum FieldKind {
     Name(ast::NameRef),
@@ -201,6 +233,7 @@ pub enum LiteralKind {
     IntNumber(ast::IntNumber),
     FloatNumber(ast::FloatNumber),
     Bool(bool),
+    Nil,
 }
 
 impl Literal {
@@ -226,6 +259,7 @@ impl Literal {
         match token.kind() {
             T![true] => LiteralKind::Bool(true),
             T![false] => LiteralKind::Bool(false),
+            T![nil] => LiteralKind::Nil,
             _ => unreachable!(),
         }
     }
@@ -265,3 +299,27 @@ impl ast::IndexExpr {
         children(self).nth(1)
     }
 }
+
+impl ast::Label {
+    pub fn lifetime_token(&self) -> Option<SyntaxToken> {
+        lifetime_token(self.syntax())
+    }
+}
+
+impl ast::BreakExpr {
+    pub fn lifetime_token(&self) -> Option<SyntaxToken> {
+        lifetime_token(self.syntax())
+    }
+}
+
+impl ast::ContinueExpr {
+    pub fn lifetime_token(&self) -> Option<SyntaxToken> {
+        lifetime_token(self.syntax())
+    }
+}
+
+fn lifetime_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .find(|e| e.kind() == SyntaxKind::LIFETIME)
+        .and_then(|e| e.into_token())
+}
@@ -1,6 +1,6 @@
 use crate::{
-    ast::{self, child_opt, AstNode, NameOwner},
-    SyntaxKind, SyntaxNode, TokenText, T,
+    ast::{self, child_opt, children, AstNode, NameOwner},
+    SmolStr, SyntaxKind, SyntaxNode, TokenText, T,
 };
 use mun_abi::StructMemoryKind;
 use rowan::{GreenNodeData, GreenTokenData, NodeOrToken};
@@ -176,6 +176,107 @@ impl ast::StructDef {
     }
 }
 
+impl ast::EnumDef {
+    pub fn signature_range(&self) -> TextRange {
+        let enum_kw = self
+            .syntax()
+            .children_with_tokens()
+            .find(|p| p.kind() == T![enum])
+            .map(|kw| kw.text_range());
+        let name = self.name().map(|n| n.syntax.text_range());
+
+        let start = enum_kw
+            .map(|kw| kw.start())
+            .unwrap_or_else(|| self.syntax.text_range().start());
+
+        let end = name
+            .map(|name| name.end())
+            .or_else(|| enum_kw.map(|kw| kw.end()))
+            .unwrap_or_else(|| self.syntax().text_range().end());
+
+        TextRange::new(start, end)
+    }
+}
+
+impl ast::Impl {
+    /// Returns true if the impl block implements a trait, e.g. `impl Damageable for Enemy`.
+    pub fn has_for_token(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![for])
+    }
+
+    /// Returns the type for which the functions are implemented, e.g. `Enemy` in both
+    /// `impl Enemy` and `impl Damageable for Enemy`.
+    pub fn self_type_ref(&self) -> Option<ast::TypeRef> {
+        if self.has_for_token() {
+            children(self).nth(1)
+        } else {
+            children(self).next()
+        }
+    }
+
+    /// Returns the trait that is implemented, e.g. `Damageable` in
+    /// `impl Damageable for Enemy`.
+    pub fn trait_type_ref(&self) -> Option<ast::TypeRef> {
+        if self.has_for_token() {
+            children(self).next()
+        } else {
+            None
+        }
+    }
+}
+
+impl ast::TraitDef {
+    pub fn signature_range(&self) -> TextRange {
+        let trait_kw = self
+            .syntax()
+            .children_with_tokens()
+            .find(|p| p.kind() == T![trait])
+            .map(|kw| kw.text_range());
+        let name = self.name().map(|n| n.syntax.text_range());
+
+        let start = trait_kw
+            .map(|kw| kw.start())
+            .unwrap_or_else(|| self.syntax.text_range().start());
+
+        let end = name
+            .map(|name| name.end())
+            .or_else(|| trait_kw.map(|kw| kw.end()))
+            .unwrap_or_else(|| self.syntax().text_range().end());
+
+        TextRange::new(start, end)
+    }
+}
+
+impl ast::StaticDef {
+    /// Returns true if the static can be assigned to, e.g. `static mut COUNTER: i32 = 0;`.
+    pub fn is_mut(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![mut])
+    }
+}
+
+impl ast::EnumVariant {
+    pub fn kind(&self) -> StructKind {
+        StructKind::from_node(self)
+    }
+}
+
+impl ast::Attr {
+    /// Returns the name of the attribute if its path consists of a single identifier, e.g. `test`
+    /// for `#[test]`.
+    pub fn simple_name(&self) -> Option<SmolStr> {
+        let path = self.path()?;
+        if path.qualifier().is_some() {
+            return None;
+        }
+        let name_ref = path.segment()?.name_ref()?;
+        Some(SmolStr::new(name_ref.text()))
+    }
+}
+
 pub enum VisibilityKind {
     PubPackage,
     PubSuper,
@@ -213,3 +314,33 @@ impl ast::UseTree {
             .any(|it| it.kind() == T![*])
     }
 }
+
+impl ast::LiteralPat {
+    /// Returns true if the literal is preceded by a minus sign, e.g. `-1`.
+    pub fn is_negative(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![-])
+    }
+}
+
+impl ast::RangePat {
+    /// Returns the inclusive lower bound of the range.
+    pub fn start(&self) -> Option<ast::Pat> {
+        children(self).next()
+    }
+
+    /// Returns the inclusive upper bound of the range.
+    pub fn end(&self) -> Option<ast::Pat> {
+        children(self).nth(1)
+    }
+}
+
+impl ast::RecordFieldPatList {
+    /// Returns true if the list ends with `..`, indicating that the remaining fields are ignored.
+    pub fn has_ellipsis(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![..])
+    }
+}
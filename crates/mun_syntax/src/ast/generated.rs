@@ -99,6 +99,70 @@ impl ArrayType {
     }
 }
 
+// AssociatedItemList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssociatedItemList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for AssociatedItemList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ASSOCIATED_ITEM_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(AssociatedItemList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AssociatedItemList {
+    pub fn functions(&self) -> impl Iterator<Item = FunctionDef> {
+        super::children(self)
+    }
+}
+
+// Attr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Attr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ATTR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Attr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Attr {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+
+    pub fn literal(&self) -> Option<Literal> {
+        super::child_opt(self)
+    }
+
+    pub fn token_tree(&self) -> Option<TokenTree> {
+        super::child_opt(self)
+    }
+}
+
 // BinExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -241,6 +305,74 @@ impl CallExpr {
     }
 }
 
+// CastExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CastExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for CastExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CAST_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(CastExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl CastExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
+// ClosureExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClosureExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ClosureExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CLOSURE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ClosureExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ClosureExpr {
+    pub fn param_list(&self) -> Option<ParamList> {
+        super::child_opt(self)
+    }
+
+    pub fn ret_type(&self) -> Option<RetType> {
+        super::child_opt(self)
+    }
+
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
 // Condition
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -273,6 +405,178 @@ impl Condition {
     }
 }
 
+// ConstDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ConstDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CONST_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ConstDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for ConstDef {}
+impl ast::VisibilityOwner for ConstDef {}
+impl ast::DocCommentsOwner for ConstDef {}
+impl ast::TypeAscriptionOwner for ConstDef {}
+impl ast::AttrsOwner for ConstDef {}
+impl ConstDef {
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// ContinueExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinueExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ContinueExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CONTINUE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ContinueExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ContinueExpr {}
+
+// DynTraitType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynTraitType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for DynTraitType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, DYN_TRAIT_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(DynTraitType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl DynTraitType {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// EnumDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for EnumDef {}
+impl ast::VisibilityOwner for EnumDef {}
+impl ast::DocCommentsOwner for EnumDef {}
+impl ast::AttrsOwner for EnumDef {}
+impl EnumDef {
+    pub fn variant_list(&self) -> Option<EnumVariantList> {
+        super::child_opt(self)
+    }
+}
+
+// EnumVariant
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumVariant {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_VARIANT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumVariant { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for EnumVariant {}
+impl ast::DocCommentsOwner for EnumVariant {}
+impl ast::AttrsOwner for EnumVariant {}
+impl EnumVariant {}
+
+// EnumVariantList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumVariantList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumVariantList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_VARIANT_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumVariantList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl EnumVariantList {
+    pub fn variants(&self) -> impl Iterator<Item = EnumVariant> {
+        super::children(self)
+    }
+}
+
 // Expr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -289,17 +593,25 @@ impl AstNode for Expr {
                 | PATH_EXPR
                 | BIN_EXPR
                 | PAREN_EXPR
+                | TUPLE_EXPR
                 | CALL_EXPR
+                | METHOD_CALL_EXPR
                 | FIELD_EXPR
                 | IF_EXPR
                 | LOOP_EXPR
                 | WHILE_EXPR
+                | FOR_EXPR
                 | RETURN_EXPR
                 | BREAK_EXPR
+                | CONTINUE_EXPR
                 | BLOCK_EXPR
                 | ARRAY_EXPR
+                | CLOSURE_EXPR
                 | INDEX_EXPR
+                | CAST_EXPR
                 | RECORD_LIT
+                | MATCH_EXPR
+                | RANGE_EXPR
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -320,17 +632,25 @@ pub enum ExprKind {
     PathExpr(PathExpr),
     BinExpr(BinExpr),
     ParenExpr(ParenExpr),
+    TupleExpr(TupleExpr),
     CallExpr(CallExpr),
+    MethodCallExpr(MethodCallExpr),
     FieldExpr(FieldExpr),
     IfExpr(IfExpr),
     LoopExpr(LoopExpr),
     WhileExpr(WhileExpr),
+    ForExpr(ForExpr),
     ReturnExpr(ReturnExpr),
     BreakExpr(BreakExpr),
+    ContinueExpr(ContinueExpr),
     BlockExpr(BlockExpr),
     ArrayExpr(ArrayExpr),
+    ClosureExpr(ClosureExpr),
     IndexExpr(IndexExpr),
+    CastExpr(CastExpr),
     RecordLit(RecordLit),
+    MatchExpr(MatchExpr),
+    RangeExpr(RangeExpr),
 }
 impl From<Literal> for Expr {
     fn from(n: Literal) -> Expr {
@@ -357,11 +677,21 @@ impl From<ParenExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<TupleExpr> for Expr {
+    fn from(n: TupleExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<CallExpr> for Expr {
     fn from(n: CallExpr) -> Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<MethodCallExpr> for Expr {
+    fn from(n: MethodCallExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<FieldExpr> for Expr {
     fn from(n: FieldExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -382,6 +712,11 @@ impl From<WhileExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<ForExpr> for Expr {
+    fn from(n: ForExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<ReturnExpr> for Expr {
     fn from(n: ReturnExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -392,6 +727,11 @@ impl From<BreakExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<ContinueExpr> for Expr {
+    fn from(n: ContinueExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<BlockExpr> for Expr {
     fn from(n: BlockExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -402,16 +742,36 @@ impl From<ArrayExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<ClosureExpr> for Expr {
+    fn from(n: ClosureExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<IndexExpr> for Expr {
     fn from(n: IndexExpr) -> Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<CastExpr> for Expr {
+    fn from(n: CastExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<RecordLit> for Expr {
     fn from(n: RecordLit) -> Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<MatchExpr> for Expr {
+    fn from(n: MatchExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<RangeExpr> for Expr {
+    fn from(n: RangeExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 
 impl Expr {
     pub fn kind(&self) -> ExprKind {
@@ -421,17 +781,29 @@ impl Expr {
             PATH_EXPR => ExprKind::PathExpr(PathExpr::cast(self.syntax.clone()).unwrap()),
             BIN_EXPR => ExprKind::BinExpr(BinExpr::cast(self.syntax.clone()).unwrap()),
             PAREN_EXPR => ExprKind::ParenExpr(ParenExpr::cast(self.syntax.clone()).unwrap()),
+            TUPLE_EXPR => ExprKind::TupleExpr(TupleExpr::cast(self.syntax.clone()).unwrap()),
             CALL_EXPR => ExprKind::CallExpr(CallExpr::cast(self.syntax.clone()).unwrap()),
+            METHOD_CALL_EXPR => {
+                ExprKind::MethodCallExpr(MethodCallExpr::cast(self.syntax.clone()).unwrap())
+            }
             FIELD_EXPR => ExprKind::FieldExpr(FieldExpr::cast(self.syntax.clone()).unwrap()),
             IF_EXPR => ExprKind::IfExpr(IfExpr::cast(self.syntax.clone()).unwrap()),
             LOOP_EXPR => ExprKind::LoopExpr(LoopExpr::cast(self.syntax.clone()).unwrap()),
             WHILE_EXPR => ExprKind::WhileExpr(WhileExpr::cast(self.syntax.clone()).unwrap()),
+            FOR_EXPR => ExprKind::ForExpr(ForExpr::cast(self.syntax.clone()).unwrap()),
             RETURN_EXPR => ExprKind::ReturnExpr(ReturnExpr::cast(self.syntax.clone()).unwrap()),
             BREAK_EXPR => ExprKind::BreakExpr(BreakExpr::cast(self.syntax.clone()).unwrap()),
+            CONTINUE_EXPR => {
+                ExprKind::ContinueExpr(ContinueExpr::cast(self.syntax.clone()).unwrap())
+            }
             BLOCK_EXPR => ExprKind::BlockExpr(BlockExpr::cast(self.syntax.clone()).unwrap()),
             ARRAY_EXPR => ExprKind::ArrayExpr(ArrayExpr::cast(self.syntax.clone()).unwrap()),
+            CLOSURE_EXPR => ExprKind::ClosureExpr(ClosureExpr::cast(self.syntax.clone()).unwrap()),
             INDEX_EXPR => ExprKind::IndexExpr(IndexExpr::cast(self.syntax.clone()).unwrap()),
+            CAST_EXPR => ExprKind::CastExpr(CastExpr::cast(self.syntax.clone()).unwrap()),
             RECORD_LIT => ExprKind::RecordLit(RecordLit::cast(self.syntax.clone()).unwrap()),
+            MATCH_EXPR => ExprKind::MatchExpr(MatchExpr::cast(self.syntax.clone()).unwrap()),
+            RANGE_EXPR => ExprKind::RangeExpr(RangeExpr::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -499,20 +871,85 @@ impl FieldExpr {
     }
 }
 
-// FunctionDef
+// FnPointerType
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct FunctionDef {
+pub struct FnPointerType {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for FunctionDef {
+impl AstNode for FnPointerType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, FUNCTION_DEF)
+        matches!(kind, FN_POINTER_TYPE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(FunctionDef { syntax })
+            Some(FnPointerType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl FnPointerType {
+    pub fn param_list(&self) -> Option<ParamList> {
+        super::child_opt(self)
+    }
+
+    pub fn ret_type(&self) -> Option<RetType> {
+        super::child_opt(self)
+    }
+}
+
+// ForExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ForExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, FOR_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ForExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::LoopBodyOwner for ForExpr {}
+impl ForExpr {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+
+    pub fn iterable(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// FunctionDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for FunctionDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, FUNCTION_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(FunctionDef { syntax })
         } else {
             None
         }
@@ -522,9 +959,11 @@ impl AstNode for FunctionDef {
     }
 }
 impl ast::NameOwner for FunctionDef {}
+impl ast::TypeParamsOwner for FunctionDef {}
 impl ast::VisibilityOwner for FunctionDef {}
 impl ast::DocCommentsOwner for FunctionDef {}
 impl ast::ExternOwner for FunctionDef {}
+impl ast::AttrsOwner for FunctionDef {}
 impl FunctionDef {
     pub fn param_list(&self) -> Option<ParamList> {
         super::child_opt(self)
@@ -567,6 +1006,40 @@ impl IfExpr {
     }
 }
 
+// Impl
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Impl {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Impl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, IMPL)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Impl { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::DocCommentsOwner for Impl {}
+impl ast::AttrsOwner for Impl {}
+impl Impl {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+
+    pub fn associated_item_list(&self) -> Option<AssociatedItemList> {
+        super::child_opt(self)
+    }
+}
+
 // IndexExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -591,6 +1064,30 @@ impl AstNode for IndexExpr {
 }
 impl IndexExpr {}
 
+// Label
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Label {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, LABEL)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Label { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Label {}
+
 // LetStmt
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -648,6 +1145,34 @@ impl AstNode for Literal {
 }
 impl Literal {}
 
+// LiteralPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LiteralPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for LiteralPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, LITERAL_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(LiteralPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl LiteralPat {
+    pub fn literal(&self) -> Option<Literal> {
+        super::child_opt(self)
+    }
+}
+
 // LoopExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -673,6 +1198,98 @@ impl AstNode for LoopExpr {
 impl ast::LoopBodyOwner for LoopExpr {}
 impl LoopExpr {}
 
+// MatchArm
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchArm {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for MatchArm {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, MATCH_ARM)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MatchArm { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl MatchArm {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// MatchArmList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchArmList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for MatchArmList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, MATCH_ARM_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MatchArmList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl MatchArmList {
+    pub fn arms(&self) -> impl Iterator<Item = MatchArm> {
+        super::children(self)
+    }
+}
+
+// MatchExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for MatchExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, MATCH_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MatchExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl MatchExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+
+    pub fn match_arm_list(&self) -> Option<MatchArmList> {
+        super::child_opt(self)
+    }
+}
+
 // MemoryTypeSpecifier
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -697,6 +1314,39 @@ impl AstNode for MemoryTypeSpecifier {
 }
 impl MemoryTypeSpecifier {}
 
+// MethodCallExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MethodCallExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for MethodCallExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, METHOD_CALL_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MethodCallExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::ArgListOwner for MethodCallExpr {}
+impl MethodCallExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+
+    pub fn name_ref(&self) -> Option<NameRef> {
+        super::child_opt(self)
+    }
+}
+
 // ModuleItem
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -706,7 +1356,17 @@ pub struct ModuleItem {
 
 impl AstNode for ModuleItem {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, USE | FUNCTION_DEF | STRUCT_DEF | TYPE_ALIAS_DEF)
+        matches!(
+            kind,
+            USE | FUNCTION_DEF
+                | STRUCT_DEF
+                | ENUM_DEF
+                | TYPE_ALIAS_DEF
+                | IMPL
+                | TRAIT_DEF
+                | CONST_DEF
+                | STATIC_DEF
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -724,7 +1384,12 @@ pub enum ModuleItemKind {
     Use(Use),
     FunctionDef(FunctionDef),
     StructDef(StructDef),
+    EnumDef(EnumDef),
     TypeAliasDef(TypeAliasDef),
+    Impl(Impl),
+    TraitDef(TraitDef),
+    ConstDef(ConstDef),
+    StaticDef(StaticDef),
 }
 impl From<Use> for ModuleItem {
     fn from(n: Use) -> ModuleItem {
@@ -741,11 +1406,36 @@ impl From<StructDef> for ModuleItem {
         ModuleItem { syntax: n.syntax }
     }
 }
+impl From<EnumDef> for ModuleItem {
+    fn from(n: EnumDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
 impl From<TypeAliasDef> for ModuleItem {
     fn from(n: TypeAliasDef) -> ModuleItem {
         ModuleItem { syntax: n.syntax }
     }
 }
+impl From<Impl> for ModuleItem {
+    fn from(n: Impl) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<TraitDef> for ModuleItem {
+    fn from(n: TraitDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<ConstDef> for ModuleItem {
+    fn from(n: ConstDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<StaticDef> for ModuleItem {
+    fn from(n: StaticDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
 
 impl ModuleItem {
     pub fn kind(&self) -> ModuleItemKind {
@@ -755,9 +1445,14 @@ impl ModuleItem {
                 ModuleItemKind::FunctionDef(FunctionDef::cast(self.syntax.clone()).unwrap())
             }
             STRUCT_DEF => ModuleItemKind::StructDef(StructDef::cast(self.syntax.clone()).unwrap()),
+            ENUM_DEF => ModuleItemKind::EnumDef(EnumDef::cast(self.syntax.clone()).unwrap()),
             TYPE_ALIAS_DEF => {
                 ModuleItemKind::TypeAliasDef(TypeAliasDef::cast(self.syntax.clone()).unwrap())
             }
+            IMPL => ModuleItemKind::Impl(Impl::cast(self.syntax.clone()).unwrap()),
+            TRAIT_DEF => ModuleItemKind::TraitDef(TraitDef::cast(self.syntax.clone()).unwrap()),
+            CONST_DEF => ModuleItemKind::ConstDef(ConstDef::cast(self.syntax.clone()).unwrap()),
+            STATIC_DEF => ModuleItemKind::StaticDef(StaticDef::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -837,20 +1532,20 @@ impl AstNode for NeverType {
 }
 impl NeverType {}
 
-// Param
+// OptionType
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Param {
+pub struct OptionType {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Param {
+impl AstNode for OptionType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PARAM)
+        matches!(kind, OPTION_TYPE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Param { syntax })
+            Some(OptionType { syntax })
         } else {
             None
         }
@@ -859,27 +1554,26 @@ impl AstNode for Param {
         &self.syntax
     }
 }
-impl ast::TypeAscriptionOwner for Param {}
-impl Param {
-    pub fn pat(&self) -> Option<Pat> {
+impl OptionType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
 }
 
-// ParamList
+// Param
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ParamList {
+pub struct Param {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for ParamList {
+impl AstNode for Param {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PARAM_LIST)
+        matches!(kind, PARAM)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(ParamList { syntax })
+            Some(Param { syntax })
         } else {
             None
         }
@@ -888,7 +1582,36 @@ impl AstNode for ParamList {
         &self.syntax
     }
 }
-impl ParamList {
+impl ast::TypeAscriptionOwner for Param {}
+impl Param {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+}
+
+// ParamList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParamList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PARAM_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParamList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParamList {
     pub fn params(&self) -> impl Iterator<Item = Param> {
         super::children(self)
     }
@@ -922,6 +1645,62 @@ impl ParenExpr {
     }
 }
 
+// ParenPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParenPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParenPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PAREN_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParenPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParenPat {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+}
+
+// ParenType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParenType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParenType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PAREN_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParenType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParenType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
 // Pat
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -931,7 +1710,18 @@ pub struct Pat {
 
 impl AstNode for Pat {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, BIND_PAT | PLACEHOLDER_PAT)
+        matches!(
+            kind,
+            BIND_PAT
+                | PLACEHOLDER_PAT
+                | LITERAL_PAT
+                | RANGE_PAT
+                | PATH_PAT
+                | TUPLE_STRUCT_PAT
+                | TUPLE_PAT
+                | PAREN_PAT
+                | RECORD_PAT
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -948,6 +1738,13 @@ impl AstNode for Pat {
 pub enum PatKind {
     BindPat(BindPat),
     PlaceholderPat(PlaceholderPat),
+    LiteralPat(LiteralPat),
+    RangePat(RangePat),
+    PathPat(PathPat),
+    TupleStructPat(TupleStructPat),
+    TuplePat(TuplePat),
+    ParenPat(ParenPat),
+    RecordPat(RecordPat),
 }
 impl From<BindPat> for Pat {
     fn from(n: BindPat) -> Pat {
@@ -959,6 +1756,41 @@ impl From<PlaceholderPat> for Pat {
         Pat { syntax: n.syntax }
     }
 }
+impl From<LiteralPat> for Pat {
+    fn from(n: LiteralPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<RangePat> for Pat {
+    fn from(n: RangePat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<PathPat> for Pat {
+    fn from(n: PathPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<TupleStructPat> for Pat {
+    fn from(n: TupleStructPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<TuplePat> for Pat {
+    fn from(n: TuplePat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<ParenPat> for Pat {
+    fn from(n: ParenPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<RecordPat> for Pat {
+    fn from(n: RecordPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
 
 impl Pat {
     pub fn kind(&self) -> PatKind {
@@ -967,6 +1799,15 @@ impl Pat {
             PLACEHOLDER_PAT => {
                 PatKind::PlaceholderPat(PlaceholderPat::cast(self.syntax.clone()).unwrap())
             }
+            LITERAL_PAT => PatKind::LiteralPat(LiteralPat::cast(self.syntax.clone()).unwrap()),
+            RANGE_PAT => PatKind::RangePat(RangePat::cast(self.syntax.clone()).unwrap()),
+            PATH_PAT => PatKind::PathPat(PathPat::cast(self.syntax.clone()).unwrap()),
+            TUPLE_STRUCT_PAT => {
+                PatKind::TupleStructPat(TupleStructPat::cast(self.syntax.clone()).unwrap())
+            }
+            TUPLE_PAT => PatKind::TuplePat(TuplePat::cast(self.syntax.clone()).unwrap()),
+            PAREN_PAT => PatKind::ParenPat(ParenPat::cast(self.syntax.clone()).unwrap()),
+            RECORD_PAT => PatKind::RecordPat(RecordPat::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -1034,6 +1875,34 @@ impl PathExpr {
     }
 }
 
+// PathPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PathPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PathPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PathPat {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
 // PathSegment
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1060,6 +1929,10 @@ impl PathSegment {
     pub fn name_ref(&self) -> Option<NameRef> {
         super::child_opt(self)
     }
+
+    pub fn type_arg_list(&self) -> Option<TypeArgList> {
+        super::child_opt(self)
+    }
 }
 
 // PathType
@@ -1142,6 +2015,54 @@ impl PrefixExpr {
     }
 }
 
+// RangeExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RangeExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RANGE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RangeExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RangeExpr {}
+
+// RangePat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangePat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RangePat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RANGE_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RangePat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RangePat {}
+
 // RecordField
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1200,6 +2121,7 @@ impl ast::NameOwner for RecordFieldDef {}
 impl ast::VisibilityOwner for RecordFieldDef {}
 impl ast::DocCommentsOwner for RecordFieldDef {}
 impl ast::TypeAscriptionOwner for RecordFieldDef {}
+impl ast::AttrsOwner for RecordFieldDef {}
 impl RecordFieldDef {}
 
 // RecordFieldDefList
@@ -1262,6 +2184,66 @@ impl RecordFieldList {
     }
 }
 
+// RecordFieldPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordFieldPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordFieldPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordFieldPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordFieldPat {
+    pub fn name_ref(&self) -> Option<NameRef> {
+        super::child_opt(self)
+    }
+
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+}
+
+// RecordFieldPatList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordFieldPatList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordFieldPatList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD_PAT_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordFieldPatList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordFieldPatList {
+    pub fn fields(&self) -> impl Iterator<Item = RecordFieldPat> {
+        super::children(self)
+    }
+}
+
 // RecordLit
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1294,6 +2276,38 @@ impl RecordLit {
     }
 }
 
+// RecordPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordPat {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+
+    pub fn record_field_pat_list(&self) -> Option<RecordFieldPatList> {
+        super::child_opt(self)
+    }
+}
+
 // Rename
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1401,6 +2415,39 @@ impl ast::ModuleItemOwner for SourceFile {}
 impl ast::FunctionDefOwner for SourceFile {}
 impl SourceFile {}
 
+// StaticDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StaticDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for StaticDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, STATIC_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(StaticDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for StaticDef {}
+impl ast::VisibilityOwner for StaticDef {}
+impl ast::DocCommentsOwner for StaticDef {}
+impl ast::TypeAscriptionOwner for StaticDef {}
+impl ast::AttrsOwner for StaticDef {}
+impl StaticDef {
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
 // Stmt
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1474,14 +2521,100 @@ impl AstNode for StructDef {
     }
 }
 impl ast::NameOwner for StructDef {}
+impl ast::TypeParamsOwner for StructDef {}
 impl ast::VisibilityOwner for StructDef {}
 impl ast::DocCommentsOwner for StructDef {}
+impl ast::AttrsOwner for StructDef {}
 impl StructDef {
     pub fn memory_type_specifier(&self) -> Option<MemoryTypeSpecifier> {
         super::child_opt(self)
     }
 }
 
+// TokenTree
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenTree {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TokenTree {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOKEN_TREE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TokenTree { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TokenTree {}
+
+// TraitDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TraitDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TraitDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TRAIT_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TraitDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for TraitDef {}
+impl ast::VisibilityOwner for TraitDef {}
+impl ast::DocCommentsOwner for TraitDef {}
+impl ast::AttrsOwner for TraitDef {}
+impl TraitDef {
+    pub fn associated_item_list(&self) -> Option<AssociatedItemList> {
+        super::child_opt(self)
+    }
+}
+
+// TupleExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TupleExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TupleExpr {
+    pub fn exprs(&self) -> impl Iterator<Item = Expr> {
+        super::children(self)
+    }
+}
+
 // TupleFieldDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1505,6 +2638,7 @@ impl AstNode for TupleFieldDef {
     }
 }
 impl ast::VisibilityOwner for TupleFieldDef {}
+impl ast::AttrsOwner for TupleFieldDef {}
 impl TupleFieldDef {
     pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
@@ -1539,6 +2673,94 @@ impl TupleFieldDefList {
     }
 }
 
+// TuplePat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TuplePat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TuplePat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TuplePat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TuplePat {
+    pub fn args(&self) -> impl Iterator<Item = Pat> {
+        super::children(self)
+    }
+}
+
+// TupleStructPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleStructPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleStructPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_STRUCT_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TupleStructPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TupleStructPat {
+    pub fn args(&self) -> impl Iterator<Item = Pat> {
+        super::children(self)
+    }
+
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// TupleType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TupleType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TupleType {
+    pub fn fields(&self) -> impl Iterator<Item = TypeRef> {
+        super::children(self)
+    }
+}
+
 // TypeAliasDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1564,12 +2786,122 @@ impl AstNode for TypeAliasDef {
 impl ast::NameOwner for TypeAliasDef {}
 impl ast::VisibilityOwner for TypeAliasDef {}
 impl ast::DocCommentsOwner for TypeAliasDef {}
+impl ast::AttrsOwner for TypeAliasDef {}
 impl TypeAliasDef {
     pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
 }
 
+// TypeArg
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeArg {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TypeArg {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TYPE_ARG)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TypeArg { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TypeArg {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
+// TypeArgList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeArgList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TypeArgList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TYPE_ARG_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TypeArgList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TypeArgList {
+    pub fn type_args(&self) -> impl Iterator<Item = TypeArg> {
+        super::children(self)
+    }
+}
+
+// TypeParam
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeParam {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TypeParam {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TYPE_PARAM)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TypeParam { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for TypeParam {}
+impl TypeParam {}
+
+// TypeParamList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeParamList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TypeParamList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TYPE_PARAM_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TypeParamList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TypeParamList {
+    pub fn type_params(&self) -> impl Iterator<Item = TypeParam> {
+        super::children(self)
+    }
+}
+
 // TypeRef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1579,7 +2911,17 @@ pub struct TypeRef {
 
 impl AstNode for TypeRef {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH_TYPE | ARRAY_TYPE | NEVER_TYPE)
+        matches!(
+            kind,
+            PATH_TYPE
+                | ARRAY_TYPE
+                | OPTION_TYPE
+                | NEVER_TYPE
+                | FN_POINTER_TYPE
+                | DYN_TRAIT_TYPE
+                | TUPLE_TYPE
+                | PAREN_TYPE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1596,7 +2938,12 @@ impl AstNode for TypeRef {
 pub enum TypeRefKind {
     PathType(PathType),
     ArrayType(ArrayType),
+    OptionType(OptionType),
     NeverType(NeverType),
+    FnPointerType(FnPointerType),
+    DynTraitType(DynTraitType),
+    TupleType(TupleType),
+    ParenType(ParenType),
 }
 impl From<PathType> for TypeRef {
     fn from(n: PathType) -> TypeRef {
@@ -1608,18 +2955,52 @@ impl From<ArrayType> for TypeRef {
         TypeRef { syntax: n.syntax }
     }
 }
+impl From<OptionType> for TypeRef {
+    fn from(n: OptionType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
 impl From<NeverType> for TypeRef {
     fn from(n: NeverType) -> TypeRef {
         TypeRef { syntax: n.syntax }
     }
 }
+impl From<FnPointerType> for TypeRef {
+    fn from(n: FnPointerType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<DynTraitType> for TypeRef {
+    fn from(n: DynTraitType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<TupleType> for TypeRef {
+    fn from(n: TupleType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<ParenType> for TypeRef {
+    fn from(n: ParenType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
 
 impl TypeRef {
     pub fn kind(&self) -> TypeRefKind {
         match self.syntax.kind() {
             PATH_TYPE => TypeRefKind::PathType(PathType::cast(self.syntax.clone()).unwrap()),
             ARRAY_TYPE => TypeRefKind::ArrayType(ArrayType::cast(self.syntax.clone()).unwrap()),
+            OPTION_TYPE => TypeRefKind::OptionType(OptionType::cast(self.syntax.clone()).unwrap()),
             NEVER_TYPE => TypeRefKind::NeverType(NeverType::cast(self.syntax.clone()).unwrap()),
+            FN_POINTER_TYPE => {
+                TypeRefKind::FnPointerType(FnPointerType::cast(self.syntax.clone()).unwrap())
+            }
+            DYN_TRAIT_TYPE => {
+                TypeRefKind::DynTraitType(DynTraitType::cast(self.syntax.clone()).unwrap())
+            }
+            TUPLE_TYPE => TypeRefKind::TupleType(TupleType::cast(self.syntax.clone()).unwrap()),
+            PAREN_TYPE => TypeRefKind::ParenType(ParenType::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -1650,6 +3031,7 @@ impl AstNode for Use {
     }
 }
 impl ast::VisibilityOwner for Use {}
+impl ast::AttrsOwner for Use {}
 impl Use {
     pub fn use_tree(&self) -> Option<UseTree> {
         super::child_opt(self)
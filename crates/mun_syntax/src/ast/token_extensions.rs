@@ -17,6 +17,42 @@ impl ast::FloatNumber {
     }
 }
 
+impl ast::String {
+    /// Returns the value of the string literal with its quotes removed and all escape sequences
+    /// resolved. Returns `None` if the literal is unterminated or contains an invalid escape
+    /// sequence.
+    pub fn value(&self) -> Option<String> {
+        unescape_string_text(self.text())
+    }
+}
+
+/// Given the text of a string literal including its quotes (e.g. `"a\tb"`), returns the value of
+/// the literal.
+fn unescape_string_text(text: &str) -> Option<String> {
+    let quote = text.chars().next()?;
+    let text = text[quote.len_utf8()..].strip_suffix(quote)?;
+
+    let mut value = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        value.push(match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            _ => return None,
+        });
+    }
+    Some(value)
+}
+
 /// Given a string containing an integer literal (e.g `0x123` or `1234u32`), splits the string in the
 /// value part and the suffix part.
 fn split_int_text_and_suffix(text: &str) -> (&str, Option<&str>) {
@@ -97,7 +133,7 @@ fn split_float_text_and_suffix(text: &str) -> (&str, Option<&str>) {
 
 #[cfg(test)]
 mod tests {
-    use super::{split_float_text_and_suffix, split_int_text_and_suffix};
+    use super::{split_float_text_and_suffix, split_int_text_and_suffix, unescape_string_text};
 
     #[test]
     fn split_int_and_suffix() {
@@ -152,4 +188,18 @@ mod tests {
             ("123.123E10", Some("f32"))
         );
     }
+
+    #[test]
+    fn unescape_string() {
+        assert_eq!(unescape_string_text(r#""""#), Some(String::new()));
+        assert_eq!(unescape_string_text(r#""foo""#), Some("foo".to_owned()));
+        assert_eq!(unescape_string_text("'foo'"), Some("foo".to_owned()));
+        assert_eq!(
+            unescape_string_text(r#""a\tb\n\"c\"\\""#),
+            Some("a\tb\n\"c\"\\".to_owned())
+        );
+        assert_eq!(unescape_string_text(r#"'it\'s'"#), Some("it's".to_owned()));
+        assert_eq!(unescape_string_text(r#""foo"#), None);
+        assert_eq!(unescape_string_text(r#""\q""#), None);
+    }
 }
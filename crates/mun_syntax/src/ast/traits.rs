@@ -20,6 +20,12 @@ pub trait NameOwner: AstNode {
     }
 }
 
+pub trait TypeParamsOwner: AstNode {
+    fn type_param_list(&self) -> Option<ast::TypeParamList> {
+        child_opt(self)
+    }
+}
+
 pub trait TypeAscriptionOwner: AstNode {
     fn ascribed_type(&self) -> Option<ast::TypeRef> {
         child_opt(self)
@@ -32,10 +38,26 @@ pub trait VisibilityOwner: AstNode {
     }
 }
 
+pub trait AttrsOwner: AstNode {
+    fn attrs(&self) -> AstChildren<ast::Attr> {
+        children(self)
+    }
+
+    /// Returns true if this node has an attribute with the given name, e.g. `test` for `#[test]`.
+    fn has_attr(&self, name: &str) -> bool {
+        self.attrs()
+            .any(|attr| attr.simple_name().as_deref() == Some(name))
+    }
+}
+
 pub trait LoopBodyOwner: AstNode {
     fn loop_body(&self) -> Option<ast::BlockExpr> {
         child_opt(self)
     }
+
+    fn label(&self) -> Option<ast::Label> {
+        child_opt(self)
+    }
 }
 
 pub trait ArgListOwner: AstNode {
@@ -207,8 +207,13 @@ fn api_walkthrough() {
         match item.kind() {
             ast::ModuleItemKind::FunctionDef(f) => func = Some(f),
             ast::ModuleItemKind::StructDef(_) => (),
+            ast::ModuleItemKind::EnumDef(_) => (),
             ast::ModuleItemKind::TypeAliasDef(_) => (),
             ast::ModuleItemKind::Use(_) => (),
+            ast::ModuleItemKind::Impl(_) => (),
+            ast::ModuleItemKind::TraitDef(_) => (),
+            ast::ModuleItemKind::ConstDef(_) => (),
+            ast::ModuleItemKind::StaticDef(_) => (),
         }
     }
 
@@ -1,9 +1,12 @@
 mod adt;
+mod attributes;
 mod declarations;
 mod expressions;
 mod params;
 mod paths;
 mod patterns;
+mod type_args;
+mod type_params;
 mod types;
 
 use super::{
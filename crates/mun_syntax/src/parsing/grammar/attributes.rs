@@ -0,0 +1,57 @@
+use super::*;
+
+/// Parses the attributes preceding an item, a field or an enum variant, e.g. `#[test]` or
+/// `#[deprecated = "use `bar` instead"]`.
+pub(super) fn outer_attrs(p: &mut Parser) {
+    while p.at(T![#]) {
+        attr(p);
+    }
+}
+
+fn attr(p: &mut Parser) {
+    assert!(p.at(T![#]));
+    let m = p.start();
+    p.bump(T![#]);
+    if p.eat(T!['[']) {
+        if paths::is_path_start(p) {
+            paths::type_path_without_args(p);
+        } else {
+            p.error("expected an attribute name");
+        }
+        match p.current() {
+            T![=] => {
+                p.bump(T![=]);
+                if expressions::literal(p).is_none() {
+                    p.error("expected a literal");
+                }
+            }
+            T!['('] | T!['['] | T!['{'] => token_tree(p),
+            _ => (),
+        }
+        p.expect(T![']']);
+    } else {
+        p.error("expected `[`");
+    }
+    m.complete(p, ATTR);
+}
+
+/// Parses a sequence of tokens enclosed in balanced delimiters, e.g. `(since = "0.5")`.
+fn token_tree(p: &mut Parser) {
+    let closing = match p.current() {
+        T!['('] => T![')'],
+        T!['['] => T![']'],
+        T!['{'] => T!['}'],
+        _ => unreachable!("token trees always start with an opening delimiter"),
+    };
+    let m = p.start();
+    p.bump_any();
+    while !p.at(EOF) && !p.at(closing) {
+        match p.current() {
+            T!['('] | T!['['] | T!['{'] => token_tree(p),
+            T![')'] | T![']'] | T!['}'] => break,
+            _ => p.bump_any(),
+        }
+    }
+    p.expect(closing);
+    m.complete(p, TOKEN_TREE);
+}
@@ -1,8 +1,19 @@
 use super::*;
 use crate::{parsing::grammar::paths::is_use_path_start, T};
 
-pub(super) const DECLARATION_RECOVERY_SET: TokenSet =
-    TokenSet::new(&[T![fn], T![pub], T![struct], T![use], T![;]]);
+pub(super) const DECLARATION_RECOVERY_SET: TokenSet = TokenSet::new(&[
+    T![#],
+    T![fn],
+    T![pub],
+    T![struct],
+    T![enum],
+    T![impl],
+    T![trait],
+    T![const],
+    T![static],
+    T![use],
+    T![;],
+]);
 
 pub(super) fn mod_contents(p: &mut Parser) {
     while !p.at(EOF) {
@@ -33,6 +44,7 @@ pub(super) fn declaration(p: &mut Parser) {
 }
 
 pub(super) fn maybe_declaration(p: &mut Parser, m: Marker) -> Result<(), Marker> {
+    attributes::outer_attrs(p);
     opt_visibility(p);
 
     let m = match declarations_without_modifiers(p, m) {
@@ -69,9 +81,24 @@ fn declarations_without_modifiers(p: &mut Parser, m: Marker) -> Result<(), Marke
         T![struct] => {
             adt::struct_def(p, m);
         }
+        T![enum] => {
+            adt::enum_def(p, m);
+        }
         T![type] => {
             adt::type_alias_def(p, m);
         }
+        T![impl] => {
+            impl_(p, m);
+        }
+        T![trait] => {
+            trait_def(p, m);
+        }
+        T![const] => {
+            const_or_static_def(p, m, CONST_DEF);
+        }
+        T![static] => {
+            const_or_static_def(p, m, STATIC_DEF);
+        }
         _ => return Err(m),
     };
     Ok(())
@@ -83,6 +110,8 @@ pub(super) fn fn_def(p: &mut Parser) {
 
     name_recovery(p, DECLARATION_RECOVERY_SET.union(TokenSet::new(&[T![')']])));
 
+    type_params::opt_type_param_list(p);
+
     if p.at(T!['(']) {
         params::param_list(p);
     } else {
@@ -98,7 +127,92 @@ pub(super) fn fn_def(p: &mut Parser) {
     }
 }
 
-fn opt_fn_ret_type(p: &mut Parser) -> bool {
+/// Parses an `impl` block, such as `impl Foo { fn bar(self: Foo) {} }`.
+fn impl_(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![impl]));
+    p.bump(T![impl]);
+    types::type_(p);
+    if p.eat(T![for]) {
+        types::type_(p);
+    }
+    if p.at(T!['{']) {
+        associated_item_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, IMPL);
+}
+
+/// Parses a trait definition, e.g. `trait Damageable { fn damage(self: Self, amount: f32); }`.
+fn trait_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![trait]));
+    p.bump(T![trait]);
+    name_recovery(p, DECLARATION_RECOVERY_SET.union(TokenSet::new(&[T!['{']])));
+    if p.at(T!['{']) {
+        associated_item_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, TRAIT_DEF);
+}
+
+/// Parses a constant or a static definition, e.g. `const MAX_SPEED: f32 = 10.0;` or
+/// `static mut COUNTER: i32 = 0;`.
+fn const_or_static_def(p: &mut Parser, m: Marker, kind: SyntaxKind) {
+    assert!(p.at(T![const]) || p.at(T![static]));
+    p.bump_any();
+    if kind == STATIC_DEF {
+        p.eat(T![mut]);
+    }
+    name_recovery(
+        p,
+        DECLARATION_RECOVERY_SET.union(TokenSet::new(&[T![:], T![=]])),
+    );
+    if p.at(T![:]) {
+        types::ascription(p);
+    } else {
+        p.error("missing type for `const` or `static`");
+    }
+    if p.eat(T![=]) {
+        expressions::expr(p);
+    } else {
+        p.error("expected `=`");
+    }
+    p.expect(T![;]);
+    m.complete(p, kind);
+}
+
+/// Parses the list of functions associated with an `impl` block or a trait.
+fn associated_item_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected an associated function");
+            continue;
+        }
+
+        let item = p.start();
+        attributes::outer_attrs(p);
+        opt_visibility(p);
+        if p.at(T![fn]) {
+            fn_def(p);
+            item.complete(p, FUNCTION_DEF);
+        } else {
+            item.abandon(p);
+            if p.at(T!['}']) {
+                p.error("expected an associated function");
+            } else {
+                p.error_and_bump("expected an associated function");
+            }
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, ASSOCIATED_ITEM_LIST);
+}
+
+pub(super) fn opt_fn_ret_type(p: &mut Parser) -> bool {
     if p.at(T![->]) {
         let m = p.start();
         p.bump(T![->]);
@@ -1,8 +1,14 @@
 use super::*;
 use crate::parsing::grammar::paths::PATH_FIRST;
 
-pub(crate) const LITERAL_FIRST: TokenSet =
-    TokenSet::new(&[T![true], T![false], INT_NUMBER, FLOAT_NUMBER, STRING]);
+pub(crate) const LITERAL_FIRST: TokenSet = TokenSet::new(&[
+    T![true],
+    T![false],
+    T![nil],
+    INT_NUMBER,
+    FLOAT_NUMBER,
+    STRING,
+]);
 
 const EXPR_RECOVERY_SET: TokenSet = TokenSet::new(&[T![let]]);
 
@@ -15,7 +21,12 @@ const ATOM_EXPR_FIRST: TokenSet = LITERAL_FIRST.union(PATH_FIRST).union(TokenSet
     T![loop],
     T![return],
     T![break],
+    T![continue],
     T![while],
+    T![for],
+    T![match],
+    T![|],
+    LIFETIME,
 ]));
 
 const LHS_FIRST: TokenSet = ATOM_EXPR_FIRST.union(TokenSet::new(&[T![!], T![-]]));
@@ -142,11 +153,23 @@ fn expr_bp(p: &mut Parser, r: Restrictions, bp: u8) -> (Option<CompletedMarker>,
             break;
         }
 
+        if op == T![as] {
+            lhs = cast_expr(p, lhs);
+            continue;
+        }
+
         let m = lhs.precede(p);
         p.bump(op);
 
         expr_bp(p, r, op_bp + 1);
-        lhs = m.complete(p, BIN_EXPR);
+        lhs = m.complete(
+            p,
+            if matches!(op, T![..] | T![..=]) {
+                RANGE_EXPR
+            } else {
+                BIN_EXPR
+            },
+        );
     }
 
     (Some(lhs), BlockLike::NotBlock)
@@ -183,6 +206,9 @@ fn current_op(p: &Parser) -> (u8, SyntaxKind) {
         T![<] if p.at(T![<<=]) => (1, T![<<=]),
         T![<] if p.at(T![<<]) => (9, T![<<]),
         T![<] => (5, T![<]),
+        T![.] if p.at(T![..=]) => (2, T![..=]),
+        T![.] if p.at(T![..]) => (2, T![..]),
+        T![as] => (12, T![as]),
         _ => (0, T![_]),
     }
 }
@@ -217,7 +243,7 @@ fn postfix_expr(
         lhs = match p.current() {
             T!['('] if allow_calls => call_expr(p, lhs),
             T!['['] if allow_calls => index_expr(p, lhs),
-            T![.] => postfix_dot_expr(p, lhs),
+            T![.] if !p.at(T![..]) => postfix_dot_expr(p, lhs),
             INDEX => field_expr(p, lhs),
             _ => break,
         };
@@ -227,6 +253,14 @@ fn postfix_expr(
     (lhs, blocklike)
 }
 
+fn cast_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T![as]));
+    let m = lhs.precede(p);
+    p.bump(T![as]);
+    types::type_without_args(p);
+    m.complete(p, CAST_EXPR)
+}
+
 fn call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     assert!(p.at(T!['(']));
     let m = lhs.precede(p);
@@ -265,13 +299,21 @@ fn arg_list(p: &mut Parser) {
 fn postfix_dot_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     assert!(p.at(T![.]));
     if p.nth(1) == IDENT && p.nth(2) == T!['('] {
-        // TODO: Implement method calls here
-        //unimplemented!("Method calls are not supported yet.");
+        return method_call_expr(p, lhs);
     }
 
     field_expr(p, lhs)
 }
 
+fn method_call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T![.]) && p.nth(1) == IDENT && p.nth(2) == T!['(']);
+    let m = lhs.precede(p);
+    p.bump(T![.]);
+    name_ref(p);
+    arg_list(p);
+    m.complete(p, METHOD_CALL_EXPR)
+}
+
 fn field_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     assert!(p.at(T![.]) || p.at(INDEX));
     let m = lhs.precede(p);
@@ -300,21 +342,38 @@ fn atom_expr(p: &mut Parser, r: Restrictions) -> Option<(CompletedMarker, BlockL
     }
 
     let marker = match p.current() {
-        T!['('] => paren_expr(p),
+        LIFETIME if p.nth_at(1, T![:]) => {
+            let m = p.start();
+            label(p);
+            match p.current() {
+                T![loop] => loop_expr(p, Some(m)),
+                T![while] => while_expr(p, Some(m)),
+                T![for] => for_expr(p, Some(m)),
+                _ => {
+                    p.error("expected a loop");
+                    m.complete(p, ERROR)
+                }
+            }
+        }
+        T!['('] => paren_or_tuple_expr(p),
         T!['{'] => block_expr(p),
         T!['['] => array_expr(p),
         T![if] => if_expr(p),
-        T![loop] => loop_expr(p),
+        T![loop] => loop_expr(p, None),
         T![return] => ret_expr(p),
-        T![while] => while_expr(p),
+        T![while] => while_expr(p, None),
+        T![for] => for_expr(p, None),
         T![break] => break_expr(p, r),
+        T![continue] => continue_expr(p),
+        T![match] => match_expr(p),
+        T![|] => closure_expr(p),
         _ => {
             p.error_recover("expected expression", EXPR_RECOVERY_SET);
             return None;
         }
     };
     let blocklike = match marker.kind() {
-        IF_EXPR | WHILE_EXPR | LOOP_EXPR | BLOCK_EXPR => BlockLike::Block,
+        IF_EXPR | WHILE_EXPR | LOOP_EXPR | FOR_EXPR | BLOCK_EXPR | MATCH_EXPR => BlockLike::Block,
         _ => BlockLike::NotBlock,
     };
     Some((marker, blocklike))
@@ -334,7 +393,7 @@ fn path_expr(p: &mut Parser, r: Restrictions) -> (CompletedMarker, BlockLike) {
     }
 }
 
-fn literal(p: &mut Parser) -> Option<CompletedMarker> {
+pub(super) fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(LITERAL_FIRST) {
         return None;
     }
@@ -343,13 +402,36 @@ fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     Some(m.complete(p, LITERAL))
 }
 
-fn paren_expr(p: &mut Parser) -> CompletedMarker {
+/// Parses a parenthesized expression, e.g. `(a + b)`, or a tuple expression, e.g. `()`, `(a,)` or
+/// `(a, b)`.
+fn paren_or_tuple_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T!['(']));
     let m = p.start();
     p.bump(T!['(']);
-    expr(p);
+
+    let mut saw_comma = false;
+    let mut saw_expr = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        saw_expr = true;
+        if !p.at_ts(EXPR_FIRST) {
+            p.error("expected expression");
+            break;
+        }
+        expr(p);
+        if !p.at(T![')']) {
+            saw_comma = true;
+            p.expect(T![,]);
+        }
+    }
     p.expect(T![')']);
-    m.complete(p, PAREN_EXPR)
+    m.complete(
+        p,
+        if saw_expr && !saw_comma {
+            PAREN_EXPR
+        } else {
+            TUPLE_EXPR
+        },
+    )
 }
 
 fn if_expr(p: &mut Parser) -> CompletedMarker {
@@ -369,16 +451,29 @@ fn if_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, IF_EXPR)
 }
 
-fn loop_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(T![loop]));
+fn label(p: &mut Parser) {
+    assert!(p.at(LIFETIME) && p.nth_at(1, T![:]));
     let m = p.start();
+    p.bump(LIFETIME);
+    p.bump(T![:]);
+    m.complete(p, LABEL);
+}
+
+fn loop_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![loop]));
+    let m = m.unwrap_or_else(|| p.start());
     p.bump(T![loop]);
     block(p);
     m.complete(p, LOOP_EXPR)
 }
 
+/// Parses the condition of an `if` or `while` expression, e.g. `a < b` or `let a = b`.
 fn cond(p: &mut Parser) {
     let m = p.start();
+    if p.eat(T![let]) {
+        patterns::pattern_r(p, TokenSet::new(&[T![=]]));
+        p.expect(T![=]);
+    }
     expr_no_struct(p);
     m.complete(p, CONDITION);
 }
@@ -393,25 +488,108 @@ fn ret_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, RETURN_EXPR)
 }
 
+/// Parses a closure expression, e.g. `|x| x + 1` or `|x: i32| -> i32 { x + 1 }`. If a return type
+/// is specified, the body must be a block.
+fn closure_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![|]));
+    let m = p.start();
+    params::closure_param_list(p);
+    if declarations::opt_fn_ret_type(p) {
+        block(p);
+    } else if p.at_ts(EXPR_FIRST) {
+        expr(p);
+    } else {
+        p.error("expected expression");
+    }
+    m.complete(p, CLOSURE_EXPR)
+}
+
 fn break_expr(p: &mut Parser, r: Restrictions) -> CompletedMarker {
     assert!(p.at(T![break]));
     let m = p.start();
     p.bump(T![break]);
+    p.eat(LIFETIME);
     if p.at_ts(EXPR_FIRST) && !(r.forbid_structs && p.at(T!['{'])) {
         expr(p);
     }
     m.complete(p, BREAK_EXPR)
 }
 
-fn while_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(T![while]));
+fn continue_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![continue]));
     let m = p.start();
+    p.bump(T![continue]);
+    p.eat(LIFETIME);
+    m.complete(p, CONTINUE_EXPR)
+}
+
+fn while_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![while]));
+    let m = m.unwrap_or_else(|| p.start());
     p.bump(T![while]);
     cond(p);
     block(p);
     m.complete(p, WHILE_EXPR)
 }
 
+fn for_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![for]));
+    let m = m.unwrap_or_else(|| p.start());
+    p.bump(T![for]);
+    patterns::pattern(p);
+    p.expect(T![in]);
+    expr_no_struct(p);
+    block(p);
+    m.complete(p, FOR_EXPR)
+}
+
+fn match_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![match]));
+    let m = p.start();
+    p.bump(T![match]);
+    expr_no_struct(p);
+    if p.at(T!['{']) {
+        match_arm_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, MATCH_EXPR)
+}
+
+fn match_arm_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected match arm");
+            continue;
+        }
+
+        if !p.at_ts(patterns::PATTERN_FIRST) {
+            p.error_and_bump("expected pattern");
+            continue;
+        }
+
+        // A comma is optional after an arm whose body is a block
+        let blocklike = match_arm(p);
+        if !p.eat(T![,]) && !blocklike.is_block() && !p.at(T!['}']) {
+            p.error("expected `,`");
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, MATCH_ARM_LIST);
+}
+
+fn match_arm(p: &mut Parser) -> BlockLike {
+    let m = p.start();
+    patterns::pattern_r(p, TokenSet::empty());
+    p.expect(T![=>]);
+    let (_, blocklike) = expr_stmt(p);
+    m.complete(p, MATCH_ARM);
+    blocklike
+}
+
 fn record_field_list(p: &mut Parser) {
     assert!(p.at(T!['{']));
     let m = p.start();
@@ -4,16 +4,75 @@ pub(super) fn param_list(p: &mut Parser) {
     list(p)
 }
 
+/// Parses the parameters of a function pointer type, e.g. `(i32, f32)`. These only consist of
+/// types.
+pub(super) fn fn_pointer_param_list(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(types::TYPE_FIRST) {
+            p.error("expected a type");
+            break;
+        }
+        let param = p.start();
+        types::type_(p);
+        param.complete(p, PARAM);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+    m.complete(p, PARAM_LIST);
+}
+
+/// Parses the parameters of a closure, e.g. `|a: i32, b|`. The type ascription of a parameter is
+/// optional.
+pub(super) fn closure_param_list(p: &mut Parser) {
+    let m = p.start();
+    if p.at(T![||]) {
+        p.bump(T![||]);
+    } else {
+        p.bump(T![|]);
+        while !p.at(EOF) && !p.at(T![|]) {
+            if !p.at_ts(VALUE_PARAMETER_FIRST) {
+                p.error("expected value parameter");
+                break;
+            }
+            let param = p.start();
+            patterns::pattern(p);
+            if p.at(T![:]) {
+                types::ascription(p);
+            }
+            param.complete(p, PARAM);
+            if !p.at(T![|]) {
+                p.expect(T![,]);
+            }
+        }
+        p.expect(T![|]);
+    }
+    m.complete(p, PARAM_LIST);
+}
+
 fn list(p: &mut Parser) {
     assert!(p.at(T!['(']));
     let m = p.start();
     p.bump(T!['(']);
+    let mut is_first = true;
     while !p.at(EOF) && !p.at(T![')']) {
         if !p.at_ts(VALUE_PARAMETER_FIRST) {
             p.error("expected value parameter");
             break;
         }
-        param(p);
+        if p.at(T![self]) {
+            if !is_first {
+                p.error("`self` is only allowed as the first parameter");
+            }
+            self_param(p);
+        } else {
+            param(p);
+        }
+        is_first = false;
         if !p.at(T![')']) {
             p.expect(T![,]);
         }
@@ -24,6 +83,20 @@ fn list(p: &mut Parser) {
 
 const VALUE_PARAMETER_FIRST: TokenSet = patterns::PATTERN_FIRST;
 
+/// Parses a `self` parameter, such as `self: Foo`. The `self` keyword is used as the name of the
+/// binding.
+fn self_param(p: &mut Parser) {
+    assert!(p.at(T![self]));
+    let m = p.start();
+    let pat = p.start();
+    let name = p.start();
+    p.bump(T![self]);
+    name.complete(p, NAME);
+    pat.complete(p, BIND_PAT);
+    types::ascription(p);
+    m.complete(p, PARAM);
+}
+
 fn param(p: &mut Parser) {
     let m = p.start();
     patterns::pattern(p);
@@ -18,6 +18,9 @@ pub(super) fn is_use_path_start(p: &Parser, top_level: bool) -> bool {
 pub(super) fn type_path(p: &mut Parser) {
     path(p, Mode::Type, true)
 }
+pub(super) fn type_path_without_args(p: &mut Parser) {
+    path(p, Mode::TypeWithoutArgs, true)
+}
 pub(super) fn expr_path(p: &mut Parser) {
     path(p, Mode::Expr, true)
 }
@@ -28,6 +31,7 @@ pub(super) fn use_path(p: &mut Parser, top_level: bool) {
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum Mode {
     Type,
+    TypeWithoutArgs,
     Expr,
     Use,
 }
@@ -50,11 +54,14 @@ fn path(p: &mut Parser, mode: Mode, top_level: bool) {
     }
 }
 
-fn path_segment(p: &mut Parser, _mode: Mode, top_level: bool) {
+fn path_segment(p: &mut Parser, mode: Mode, top_level: bool) {
     let m = p.start();
     match p.current() {
         IDENT => {
             name_ref(p);
+            if mode == Mode::Type {
+                type_args::opt_type_arg_list(p);
+            }
         }
         T![super] | T![package] if top_level => p.bump_any(),
         T![self] => p.bump(T![self]),
@@ -2,24 +2,42 @@ use super::*;
 
 pub(super) const PATTERN_FIRST: TokenSet = expressions::LITERAL_FIRST
     .union(paths::PATH_FIRST)
-    .union(TokenSet::new(&[T![-], T![_]]));
+    .union(TokenSet::new(&[T![-], T![_], T!['(']]));
 
 pub(super) fn pattern(p: &mut Parser) {
     pattern_r(p, PATTERN_FIRST);
 }
 
 pub(super) fn pattern_r(p: &mut Parser, recovery_set: TokenSet) {
-    atom_pat(p, recovery_set);
+    if let Some(lhs) = atom_pat(p, recovery_set) {
+        if lhs.kind() == LITERAL_PAT && p.at(T![..=]) {
+            range_pat(p, lhs);
+        }
+    }
 }
 
 fn atom_pat(p: &mut Parser, recovery_set: TokenSet) -> Option<CompletedMarker> {
     let t1 = p.nth(0);
     if t1 == IDENT {
+        // An identifier followed by `::`, `(` or `{` refers to a path (e.g. an enum variant or a
+        // struct), otherwise it introduces a new binding.
+        if p.nth_at(1, T![::]) || p.nth(1) == T!['('] || p.nth(1) == T!['{'] {
+            return Some(path_or_struct_pat(p));
+        }
         return Some(bind_pat(p));
     }
 
+    if paths::is_path_start(p) {
+        return Some(path_or_struct_pat(p));
+    }
+
+    if p.at(T![-]) || p.at_ts(expressions::LITERAL_FIRST) {
+        return Some(literal_pat(p));
+    }
+
     let m = match t1 {
         T![_] => placeholder_pat(p),
+        T!['('] => paren_or_tuple_pat(p),
         _ => {
             p.error_recover("expected pattern", recovery_set);
             return None;
@@ -35,8 +53,131 @@ fn placeholder_pat(p: &mut Parser) -> CompletedMarker {
     m.complete(p, PLACEHOLDER_PAT)
 }
 
+/// Parses a parenthesized pattern, e.g. `(a)`, or a tuple pattern, e.g. `()`, `(a,)` or `(a, b)`.
+fn paren_or_tuple_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+
+    let mut saw_comma = false;
+    let mut saw_pat = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        saw_pat = true;
+        if !p.at_ts(PATTERN_FIRST) {
+            p.error("expected a pattern");
+            break;
+        }
+        pattern(p);
+        if !p.at(T![')']) {
+            saw_comma = true;
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+    m.complete(
+        p,
+        if saw_pat && !saw_comma {
+            PAREN_PAT
+        } else {
+            TUPLE_PAT
+        },
+    )
+}
+
 fn bind_pat(p: &mut Parser) -> CompletedMarker {
     let m = p.start();
     name(p);
     m.complete(p, BIND_PAT)
 }
+
+fn literal_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![-]) || p.at_ts(expressions::LITERAL_FIRST));
+    let m = p.start();
+    p.eat(T![-]);
+    if expressions::literal(p).is_none() {
+        p.error("expected a literal");
+    }
+    m.complete(p, LITERAL_PAT)
+}
+
+fn range_pat(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T![..=]));
+    let m = lhs.precede(p);
+    p.bump(T![..=]);
+    if p.at(T![-]) || p.at_ts(expressions::LITERAL_FIRST) {
+        literal_pat(p);
+    } else {
+        p.error("expected the end of the range");
+    }
+    m.complete(p, RANGE_PAT)
+}
+
+fn path_or_struct_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(paths::is_path_start(p));
+    let m = p.start();
+    paths::expr_path(p);
+    let kind = match p.current() {
+        T!['('] => {
+            tuple_pat_fields(p);
+            TUPLE_STRUCT_PAT
+        }
+        T!['{'] => {
+            record_field_pat_list(p);
+            RECORD_PAT
+        }
+        _ => PATH_PAT,
+    };
+    m.complete(p, kind)
+}
+
+fn tuple_pat_fields(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    p.bump(T!['(']);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(PATTERN_FIRST) {
+            p.error("expected a pattern");
+            break;
+        }
+
+        pattern(p);
+        if !p.at(T![')']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![')']);
+}
+
+fn record_field_pat_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        match p.current() {
+            T![.] if p.at(T![..]) => {
+                p.bump(T![..]);
+                if !p.at(T!['}']) {
+                    p.error("`..` must be at the end of the pattern");
+                }
+            }
+            IDENT | INT_NUMBER if p.nth(1) == T![:] && !p.nth_at(1, T![::]) => {
+                let m = p.start();
+                name_ref_or_index(p);
+                p.bump(T![:]);
+                pattern(p);
+                m.complete(p, RECORD_FIELD_PAT);
+            }
+            IDENT => {
+                let m = p.start();
+                bind_pat(p);
+                m.complete(p, RECORD_FIELD_PAT);
+            }
+            T!['{'] => error_block(p, "expected a field"),
+            _ => p.error_and_bump("expected an identifier"),
+        }
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, RECORD_FIELD_PAT_LIST);
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// Parses an optional list of type arguments, such as `<i32, f32>` in `Pair<i32, f32>`.
+pub(super) fn opt_type_arg_list(p: &mut Parser) {
+    if !p.at(T![<]) {
+        return;
+    }
+    let m = p.start();
+    p.bump(T![<]);
+
+    while !p.at(EOF) && !p.at(T![>]) {
+        type_arg(p);
+        if !p.at(T![>]) && !p.eat(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![>]);
+    m.complete(p, TYPE_ARG_LIST);
+}
+
+fn type_arg(p: &mut Parser) {
+    let m = p.start();
+    types::type_(p);
+    m.complete(p, TYPE_ARG);
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Parses an optional list of type parameters, such as `<A, B>` in `struct Pair<A, B>`.
+pub(super) fn opt_type_param_list(p: &mut Parser) {
+    if !p.at(T![<]) {
+        return;
+    }
+    type_param_list(p);
+}
+
+fn type_param_list(p: &mut Parser) {
+    assert!(p.at(T![<]));
+    let m = p.start();
+    p.bump(T![<]);
+
+    while !p.at(EOF) && !p.at(T![>]) {
+        if p.at(IDENT) {
+            type_param(p);
+        } else {
+            p.error_and_bump("expected type parameter");
+        }
+        if !p.at(T![>]) && !p.eat(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![>]);
+    m.complete(p, TYPE_PARAM_LIST);
+}
+
+fn type_param(p: &mut Parser) {
+    assert!(p.at(IDENT));
+    let m = p.start();
+    name(p);
+    m.complete(p, TYPE_PARAM);
+}
@@ -1,7 +1,13 @@
 use super::*;
 
-pub(super) const TYPE_FIRST: TokenSet =
-    paths::PATH_FIRST.union(TokenSet::new(&[T![never], T!['[']]));
+pub(super) const TYPE_FIRST: TokenSet = paths::PATH_FIRST.union(TokenSet::new(&[
+    T![never],
+    T!['['],
+    T![?],
+    T!['('],
+    T![fn],
+    T![dyn],
+]));
 
 pub(super) const TYPE_RECOVERY_SET: TokenSet = TokenSet::new(&[T!['('], T![,], T![pub]]);
 
@@ -12,8 +18,12 @@ pub(super) fn ascription(p: &mut Parser) {
 
 pub(super) fn type_(p: &mut Parser) {
     match p.current() {
+        T!['('] => paren_or_tuple_type(p),
         T!['['] => array_type(p),
+        T![?] => option_type(p),
         T![never] => never_type(p),
+        T![fn] => fn_pointer_type(p),
+        T![dyn] => dyn_trait_type(p),
         _ if paths::is_path_start(p) => path_type(p),
         _ => {
             p.error_recover("expected type", TYPE_RECOVERY_SET);
@@ -21,6 +31,18 @@ pub(super) fn type_(p: &mut Parser) {
     }
 }
 
+/// Parses a type whose path cannot have type arguments. This is used for the target type of a cast,
+/// where a `<` is parsed as a comparison instead, e.g. `a as u8 < b`.
+pub(super) fn type_without_args(p: &mut Parser) {
+    if paths::is_path_start(p) {
+        let m = p.start();
+        paths::type_path_without_args(p);
+        m.complete(p, PATH_TYPE);
+    } else {
+        type_(p)
+    }
+}
+
 pub(super) fn path_type(p: &mut Parser) {
     let m = p.start();
     paths::type_path(p);
@@ -34,6 +56,38 @@ fn never_type(p: &mut Parser) {
     m.complete(p, NEVER_TYPE);
 }
 
+/// Parses a parenthesized type, e.g. `(i32)`, or a tuple type, e.g. `()`, `(i32,)` or
+/// `(i32, f32)`.
+fn paren_or_tuple_type(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+
+    let mut saw_comma = false;
+    let mut saw_type = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        saw_type = true;
+        if !p.at_ts(TYPE_FIRST) {
+            p.error("expected a type");
+            break;
+        }
+        type_(p);
+        if !p.at(T![')']) {
+            saw_comma = true;
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+    m.complete(
+        p,
+        if saw_type && !saw_comma {
+            PAREN_TYPE
+        } else {
+            TUPLE_TYPE
+        },
+    );
+}
+
 fn array_type(p: &mut Parser) {
     assert!(p.at(T!['[']));
     let m = p.start();
@@ -42,3 +96,39 @@ fn array_type(p: &mut Parser) {
     p.expect(T![']']);
     m.complete(p, ARRAY_TYPE);
 }
+
+/// Parses an optional type, e.g. `?Foo`.
+fn option_type(p: &mut Parser) {
+    assert!(p.at(T![?]));
+    let m = p.start();
+    p.bump(T![?]);
+    type_(p);
+    m.complete(p, OPTION_TYPE);
+}
+
+/// Parses a function pointer type, e.g. `fn(i32, f32) -> bool`.
+fn fn_pointer_type(p: &mut Parser) {
+    assert!(p.at(T![fn]));
+    let m = p.start();
+    p.bump(T![fn]);
+    if p.at(T!['(']) {
+        params::fn_pointer_param_list(p);
+    } else {
+        p.error("expected parameters");
+    }
+    declarations::opt_fn_ret_type(p);
+    m.complete(p, FN_POINTER_TYPE);
+}
+
+/// Parses a trait object type, e.g. `dyn Damageable`.
+fn dyn_trait_type(p: &mut Parser) {
+    assert!(p.at(T![dyn]));
+    let m = p.start();
+    p.bump(T![dyn]);
+    if paths::is_path_start(p) {
+        paths::type_path_without_args(p);
+    } else {
+        p.error("expected a trait");
+    }
+    m.complete(p, DYN_TRAIT_TYPE);
+}
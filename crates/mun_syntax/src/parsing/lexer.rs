@@ -27,7 +27,20 @@ pub fn tokenize(text: &str) -> Vec<Token> {
     let mut text = text;
     let mut result = Vec::new();
     while !text.is_empty() {
-        let token = next_token(text);
+        // The second dot of a range operator is never the start of an index, e.g. `0..10`
+        let token = if text.starts_with('.') && result.last().map(|t: &Token| t.kind) == Some(DOT) {
+            Token {
+                kind: DOT,
+                len: TextSize::from(1),
+            }
+        } else if let Some(len) = lifetime_len(text, previous_non_trivia(&result)) {
+            Token {
+                kind: LIFETIME,
+                len,
+            }
+        } else {
+            next_token(text)
+        };
         result.push(token);
         let len: u32 = token.len.into();
         text = &text[len as usize..];
@@ -35,6 +48,34 @@ pub fn tokenize(text: &str) -> Vec<Token> {
     result
 }
 
+/// Returns the kind of the last token that is not whitespace or a comment.
+fn previous_non_trivia(tokens: &[Token]) -> Option<SyntaxKind> {
+    tokens
+        .iter()
+        .rev()
+        .map(|t| t.kind)
+        .find(|kind| !kind.is_trivia())
+}
+
+/// Returns the length of the lifetime at the start of `text`, e.g. `'outer`. Lifetimes are
+/// ambiguous with single quoted strings, so they are only recognized when they are followed by a
+/// colon (`'outer: loop`) or when they follow a `break` or `continue` (`break 'outer`).
+fn lifetime_len(text: &str, previous: Option<SyntaxKind>) -> Option<TextSize> {
+    let mut cursor = Cursor::new(text);
+    if cursor.bump() != Some('\'') || !cursor.matches_nth_if(0, is_ident_start) {
+        return None;
+    }
+    cursor.bump_while(is_ident_continue);
+
+    let is_label = cursor.matches(':') && !cursor.matches_str("::");
+    let is_jump_target = matches!(previous, Some(BREAK_KW) | Some(CONTINUE_KW));
+    if (is_label || is_jump_target) && !cursor.matches('\'') {
+        Some(cursor.into_len())
+    } else {
+        None
+    }
+}
+
 /// Get the next token from a string
 pub fn next_token(text: &str) -> Token {
     assert!(!text.is_empty());
@@ -46,6 +46,7 @@ pub enum SyntaxKind {
     COLON,
     COMMA,
     EXCLAMATION,
+    QUESTION,
     UNDERSCORE,
     EQEQ,
     NEQ,
@@ -66,6 +67,7 @@ pub enum SyntaxKind {
     DOTDOTEQ,
     COLONCOLON,
     THIN_ARROW,
+    FAT_ARROW,
     AMPAMP,
     PIPEPIPE,
     SHL,
@@ -85,13 +87,21 @@ pub enum SyntaxKind {
     TRUE_KW,
     WHILE_KW,
     LOOP_KW,
+    MATCH_KW,
+    CONTINUE_KW,
     LET_KW,
     MUT_KW,
     CLASS_KW,
     STRUCT_KW,
+    ENUM_KW,
     NEVER_KW,
     PUB_KW,
     TYPE_KW,
+    IMPL_KW,
+    TRAIT_KW,
+    DYN_KW,
+    CONST_KW,
+    STATIC_KW,
     PACKAGE_KW,
     SUPER_KW,
     SELF_KW,
@@ -102,6 +112,7 @@ pub enum SyntaxKind {
     ERROR,
     IDENT,
     INDEX,
+    LIFETIME,
     WHITESPACE,
     COMMENT,
     GC_KW,
@@ -111,6 +122,8 @@ pub enum SyntaxKind {
     EXTERN,
     RET_TYPE,
     VISIBILITY,
+    ATTR,
+    TOKEN_TREE,
     PARAM_LIST,
     PARAM,
     STRUCT_DEF,
@@ -120,9 +133,22 @@ pub enum SyntaxKind {
     RECORD_FIELD_DEF,
     TUPLE_FIELD_DEF_LIST,
     TUPLE_FIELD_DEF,
+    ENUM_DEF,
+    ENUM_VARIANT_LIST,
+    ENUM_VARIANT,
+    IMPL,
+    TRAIT_DEF,
+    ASSOCIATED_ITEM_LIST,
+    CONST_DEF,
+    STATIC_DEF,
     PATH_TYPE,
     ARRAY_TYPE,
+    OPTION_TYPE,
     NEVER_TYPE,
+    FN_POINTER_TYPE,
+    DYN_TRAIT_TYPE,
+    TUPLE_TYPE,
+    PAREN_TYPE,
     LET_STMT,
     EXPR_STMT,
     PATH_EXPR,
@@ -130,24 +156,48 @@ pub enum SyntaxKind {
     LITERAL,
     BIN_EXPR,
     PAREN_EXPR,
+    TUPLE_EXPR,
     CALL_EXPR,
+    METHOD_CALL_EXPR,
     FIELD_EXPR,
     IF_EXPR,
     INDEX_EXPR,
+    CAST_EXPR,
     BLOCK_EXPR,
     RETURN_EXPR,
     WHILE_EXPR,
     LOOP_EXPR,
+    FOR_EXPR,
+    RANGE_EXPR,
     BREAK_EXPR,
+    CONTINUE_EXPR,
+    LABEL,
     ARRAY_EXPR,
+    CLOSURE_EXPR,
+    MATCH_EXPR,
+    MATCH_ARM_LIST,
+    MATCH_ARM,
     CONDITION,
     BIND_PAT,
     PLACEHOLDER_PAT,
+    LITERAL_PAT,
+    RANGE_PAT,
+    PATH_PAT,
+    TUPLE_STRUCT_PAT,
+    TUPLE_PAT,
+    PAREN_PAT,
+    RECORD_PAT,
+    RECORD_FIELD_PAT_LIST,
+    RECORD_FIELD_PAT,
     ARG_LIST,
     NAME,
     NAME_REF,
     PATH,
     PATH_SEGMENT,
+    TYPE_PARAM_LIST,
+    TYPE_PARAM,
+    TYPE_ARG_LIST,
+    TYPE_ARG,
     RECORD_LIT,
     RECORD_FIELD_LIST,
     RECORD_FIELD,
@@ -232,6 +282,9 @@ macro_rules! T {
     (!) => {
         $crate::SyntaxKind::EXCLAMATION
     };
+    (?) => {
+        $crate::SyntaxKind::QUESTION
+    };
     (_) => {
         $crate::SyntaxKind::UNDERSCORE
     };
@@ -292,6 +345,9 @@ macro_rules! T {
     (->) => {
         $crate::SyntaxKind::THIN_ARROW
     };
+    (=>) => {
+        $crate::SyntaxKind::FAT_ARROW
+    };
     (&&) => {
         $crate::SyntaxKind::AMPAMP
     };
@@ -349,6 +405,12 @@ macro_rules! T {
     (loop) => {
         $crate::SyntaxKind::LOOP_KW
     };
+    (match) => {
+        $crate::SyntaxKind::MATCH_KW
+    };
+    (continue) => {
+        $crate::SyntaxKind::CONTINUE_KW
+    };
     (let) => {
         $crate::SyntaxKind::LET_KW
     };
@@ -361,6 +423,9 @@ macro_rules! T {
     (struct) => {
         $crate::SyntaxKind::STRUCT_KW
     };
+    (enum) => {
+        $crate::SyntaxKind::ENUM_KW
+    };
     (never) => {
         $crate::SyntaxKind::NEVER_KW
     };
@@ -370,6 +435,21 @@ macro_rules! T {
     (type) => {
         $crate::SyntaxKind::TYPE_KW
     };
+    (impl) => {
+        $crate::SyntaxKind::IMPL_KW
+    };
+    (trait) => {
+        $crate::SyntaxKind::TRAIT_KW
+    };
+    (dyn) => {
+        $crate::SyntaxKind::DYN_KW
+    };
+    (const) => {
+        $crate::SyntaxKind::CONST_KW
+    };
+    (static) => {
+        $crate::SyntaxKind::STATIC_KW
+    };
     (package) => {
         $crate::SyntaxKind::PACKAGE_KW
     };
@@ -416,13 +496,21 @@ impl SyntaxKind {
         | TRUE_KW
         | WHILE_KW
         | LOOP_KW
+        | MATCH_KW
+        | CONTINUE_KW
         | LET_KW
         | MUT_KW
         | CLASS_KW
         | STRUCT_KW
+        | ENUM_KW
         | NEVER_KW
         | PUB_KW
         | TYPE_KW
+        | IMPL_KW
+        | TRAIT_KW
+        | DYN_KW
+        | CONST_KW
+        | STATIC_KW
         | PACKAGE_KW
         | SUPER_KW
         | SELF_KW
@@ -456,6 +544,7 @@ impl SyntaxKind {
         | COLON
         | COMMA
         | EXCLAMATION
+        | QUESTION
         | UNDERSCORE
         | EQEQ
         | NEQ
@@ -476,6 +565,7 @@ impl SyntaxKind {
         | DOTDOTEQ
         | COLONCOLON
         | THIN_ARROW
+        | FAT_ARROW
         | AMPAMP
         | PIPEPIPE
         | SHL
@@ -518,6 +608,7 @@ impl SyntaxKind {
             COLON => &SyntaxInfo { name: "COLON" },
             COMMA => &SyntaxInfo { name: "COMMA" },
             EXCLAMATION => &SyntaxInfo { name: "EXCLAMATION" },
+            QUESTION => &SyntaxInfo { name: "QUESTION" },
             UNDERSCORE => &SyntaxInfo { name: "UNDERSCORE" },
             EQEQ => &SyntaxInfo { name: "EQEQ" },
             NEQ => &SyntaxInfo { name: "NEQ" },
@@ -538,6 +629,7 @@ impl SyntaxKind {
             DOTDOTEQ => &SyntaxInfo { name: "DOTDOTEQ" },
             COLONCOLON => &SyntaxInfo { name: "COLONCOLON" },
             THIN_ARROW => &SyntaxInfo { name: "THIN_ARROW" },
+            FAT_ARROW => &SyntaxInfo { name: "FAT_ARROW" },
             AMPAMP => &SyntaxInfo { name: "AMPAMP" },
             PIPEPIPE => &SyntaxInfo { name: "PIPEPIPE" },
             SHL => &SyntaxInfo { name: "SHL" },
@@ -557,13 +649,21 @@ impl SyntaxKind {
             TRUE_KW => &SyntaxInfo { name: "TRUE_KW" },
             WHILE_KW => &SyntaxInfo { name: "WHILE_KW" },
             LOOP_KW => &SyntaxInfo { name: "LOOP_KW" },
+            MATCH_KW => &SyntaxInfo { name: "MATCH_KW" },
+            CONTINUE_KW => &SyntaxInfo { name: "CONTINUE_KW" },
             LET_KW => &SyntaxInfo { name: "LET_KW" },
             MUT_KW => &SyntaxInfo { name: "MUT_KW" },
             CLASS_KW => &SyntaxInfo { name: "CLASS_KW" },
             STRUCT_KW => &SyntaxInfo { name: "STRUCT_KW" },
+            ENUM_KW => &SyntaxInfo { name: "ENUM_KW" },
             NEVER_KW => &SyntaxInfo { name: "NEVER_KW" },
             PUB_KW => &SyntaxInfo { name: "PUB_KW" },
             TYPE_KW => &SyntaxInfo { name: "TYPE_KW" },
+            IMPL_KW => &SyntaxInfo { name: "IMPL_KW" },
+            TRAIT_KW => &SyntaxInfo { name: "TRAIT_KW" },
+            DYN_KW => &SyntaxInfo { name: "DYN_KW" },
+            CONST_KW => &SyntaxInfo { name: "CONST_KW" },
+            STATIC_KW => &SyntaxInfo { name: "STATIC_KW" },
             PACKAGE_KW => &SyntaxInfo { name: "PACKAGE_KW" },
             SUPER_KW => &SyntaxInfo { name: "SUPER_KW" },
             SELF_KW => &SyntaxInfo { name: "SELF_KW" },
@@ -574,6 +674,7 @@ impl SyntaxKind {
             ERROR => &SyntaxInfo { name: "ERROR" },
             IDENT => &SyntaxInfo { name: "IDENT" },
             INDEX => &SyntaxInfo { name: "INDEX" },
+            LIFETIME => &SyntaxInfo { name: "LIFETIME" },
             WHITESPACE => &SyntaxInfo { name: "WHITESPACE" },
             COMMENT => &SyntaxInfo { name: "COMMENT" },
             GC_KW => &SyntaxInfo { name: "GC_KW" },
@@ -583,6 +684,8 @@ impl SyntaxKind {
             EXTERN => &SyntaxInfo { name: "EXTERN" },
             RET_TYPE => &SyntaxInfo { name: "RET_TYPE" },
             VISIBILITY => &SyntaxInfo { name: "VISIBILITY" },
+            ATTR => &SyntaxInfo { name: "ATTR" },
+            TOKEN_TREE => &SyntaxInfo { name: "TOKEN_TREE" },
             PARAM_LIST => &SyntaxInfo { name: "PARAM_LIST" },
             PARAM => &SyntaxInfo { name: "PARAM" },
             STRUCT_DEF => &SyntaxInfo { name: "STRUCT_DEF" },
@@ -592,9 +695,22 @@ impl SyntaxKind {
             RECORD_FIELD_DEF => &SyntaxInfo { name: "RECORD_FIELD_DEF" },
             TUPLE_FIELD_DEF_LIST => &SyntaxInfo { name: "TUPLE_FIELD_DEF_LIST" },
             TUPLE_FIELD_DEF => &SyntaxInfo { name: "TUPLE_FIELD_DEF" },
+            ENUM_DEF => &SyntaxInfo { name: "ENUM_DEF" },
+            ENUM_VARIANT_LIST => &SyntaxInfo { name: "ENUM_VARIANT_LIST" },
+            ENUM_VARIANT => &SyntaxInfo { name: "ENUM_VARIANT" },
+            IMPL => &SyntaxInfo { name: "IMPL" },
+            TRAIT_DEF => &SyntaxInfo { name: "TRAIT_DEF" },
+            ASSOCIATED_ITEM_LIST => &SyntaxInfo { name: "ASSOCIATED_ITEM_LIST" },
+            CONST_DEF => &SyntaxInfo { name: "CONST_DEF" },
+            STATIC_DEF => &SyntaxInfo { name: "STATIC_DEF" },
             PATH_TYPE => &SyntaxInfo { name: "PATH_TYPE" },
             ARRAY_TYPE => &SyntaxInfo { name: "ARRAY_TYPE" },
+            OPTION_TYPE => &SyntaxInfo { name: "OPTION_TYPE" },
             NEVER_TYPE => &SyntaxInfo { name: "NEVER_TYPE" },
+            FN_POINTER_TYPE => &SyntaxInfo { name: "FN_POINTER_TYPE" },
+            DYN_TRAIT_TYPE => &SyntaxInfo { name: "DYN_TRAIT_TYPE" },
+            TUPLE_TYPE => &SyntaxInfo { name: "TUPLE_TYPE" },
+            PAREN_TYPE => &SyntaxInfo { name: "PAREN_TYPE" },
             LET_STMT => &SyntaxInfo { name: "LET_STMT" },
             EXPR_STMT => &SyntaxInfo { name: "EXPR_STMT" },
             PATH_EXPR => &SyntaxInfo { name: "PATH_EXPR" },
@@ -602,24 +718,48 @@ impl SyntaxKind {
             LITERAL => &SyntaxInfo { name: "LITERAL" },
             BIN_EXPR => &SyntaxInfo { name: "BIN_EXPR" },
             PAREN_EXPR => &SyntaxInfo { name: "PAREN_EXPR" },
+            TUPLE_EXPR => &SyntaxInfo { name: "TUPLE_EXPR" },
             CALL_EXPR => &SyntaxInfo { name: "CALL_EXPR" },
+            METHOD_CALL_EXPR => &SyntaxInfo { name: "METHOD_CALL_EXPR" },
             FIELD_EXPR => &SyntaxInfo { name: "FIELD_EXPR" },
             IF_EXPR => &SyntaxInfo { name: "IF_EXPR" },
             INDEX_EXPR => &SyntaxInfo { name: "INDEX_EXPR" },
+            CAST_EXPR => &SyntaxInfo { name: "CAST_EXPR" },
             BLOCK_EXPR => &SyntaxInfo { name: "BLOCK_EXPR" },
             RETURN_EXPR => &SyntaxInfo { name: "RETURN_EXPR" },
             WHILE_EXPR => &SyntaxInfo { name: "WHILE_EXPR" },
             LOOP_EXPR => &SyntaxInfo { name: "LOOP_EXPR" },
+            FOR_EXPR => &SyntaxInfo { name: "FOR_EXPR" },
+            RANGE_EXPR => &SyntaxInfo { name: "RANGE_EXPR" },
             BREAK_EXPR => &SyntaxInfo { name: "BREAK_EXPR" },
+            CONTINUE_EXPR => &SyntaxInfo { name: "CONTINUE_EXPR" },
+            LABEL => &SyntaxInfo { name: "LABEL" },
             ARRAY_EXPR => &SyntaxInfo { name: "ARRAY_EXPR" },
+            CLOSURE_EXPR => &SyntaxInfo { name: "CLOSURE_EXPR" },
+            MATCH_EXPR => &SyntaxInfo { name: "MATCH_EXPR" },
+            MATCH_ARM_LIST => &SyntaxInfo { name: "MATCH_ARM_LIST" },
+            MATCH_ARM => &SyntaxInfo { name: "MATCH_ARM" },
             CONDITION => &SyntaxInfo { name: "CONDITION" },
             BIND_PAT => &SyntaxInfo { name: "BIND_PAT" },
             PLACEHOLDER_PAT => &SyntaxInfo { name: "PLACEHOLDER_PAT" },
+            LITERAL_PAT => &SyntaxInfo { name: "LITERAL_PAT" },
+            RANGE_PAT => &SyntaxInfo { name: "RANGE_PAT" },
+            PATH_PAT => &SyntaxInfo { name: "PATH_PAT" },
+            TUPLE_STRUCT_PAT => &SyntaxInfo { name: "TUPLE_STRUCT_PAT" },
+            TUPLE_PAT => &SyntaxInfo { name: "TUPLE_PAT" },
+            PAREN_PAT => &SyntaxInfo { name: "PAREN_PAT" },
+            RECORD_PAT => &SyntaxInfo { name: "RECORD_PAT" },
+            RECORD_FIELD_PAT_LIST => &SyntaxInfo { name: "RECORD_FIELD_PAT_LIST" },
+            RECORD_FIELD_PAT => &SyntaxInfo { name: "RECORD_FIELD_PAT" },
             ARG_LIST => &SyntaxInfo { name: "ARG_LIST" },
             NAME => &SyntaxInfo { name: "NAME" },
             NAME_REF => &SyntaxInfo { name: "NAME_REF" },
             PATH => &SyntaxInfo { name: "PATH" },
             PATH_SEGMENT => &SyntaxInfo { name: "PATH_SEGMENT" },
+            TYPE_PARAM_LIST => &SyntaxInfo { name: "TYPE_PARAM_LIST" },
+            TYPE_PARAM => &SyntaxInfo { name: "TYPE_PARAM" },
+            TYPE_ARG_LIST => &SyntaxInfo { name: "TYPE_ARG_LIST" },
+            TYPE_ARG => &SyntaxInfo { name: "TYPE_ARG" },
             RECORD_LIT => &SyntaxInfo { name: "RECORD_LIT" },
             RECORD_FIELD_LIST => &SyntaxInfo { name: "RECORD_FIELD_LIST" },
             RECORD_FIELD => &SyntaxInfo { name: "RECORD_FIELD" },
@@ -650,13 +790,21 @@ impl SyntaxKind {
             "true" => TRUE_KW,
             "while" => WHILE_KW,
             "loop" => LOOP_KW,
+            "match" => MATCH_KW,
+            "continue" => CONTINUE_KW,
             "let" => LET_KW,
             "mut" => MUT_KW,
             "class" => CLASS_KW,
             "struct" => STRUCT_KW,
+            "enum" => ENUM_KW,
             "never" => NEVER_KW,
             "pub" => PUB_KW,
             "type" => TYPE_KW,
+            "impl" => IMPL_KW,
+            "trait" => TRAIT_KW,
+            "dyn" => DYN_KW,
+            "const" => CONST_KW,
+            "static" => STATIC_KW,
             "package" => PACKAGE_KW,
             "super" => SUPER_KW,
             "self" => SELF_KW,
@@ -691,6 +839,7 @@ impl SyntaxKind {
             ':' => COLON,
             ',' => COMMA,
             '!' => EXCLAMATION,
+            '?' => QUESTION,
             '_' => UNDERSCORE,
             _ => return None,
         };
@@ -400,3 +400,46 @@ fn binary_cmp() {
     WHITESPACE 5 "\n    "
     "###);
 }
+
+#[test]
+fn ranges() {
+    insta::assert_snapshot!(dump_text_tokens(r#"0..10 a..=b x.0..x.1"#), @r###"
+    INT_NUMBER 1 "0"
+    DOT 1 "."
+    DOT 1 "."
+    INT_NUMBER 2 "10"
+    WHITESPACE 1 " "
+    IDENT 1 "a"
+    DOT 1 "."
+    DOT 1 "."
+    EQ 1 "="
+    IDENT 1 "b"
+    WHITESPACE 1 " "
+    IDENT 1 "x"
+    INDEX 2 ".0"
+    DOT 1 "."
+    DOT 1 "."
+    IDENT 1 "x"
+    INDEX 2 ".1"
+    "###);
+}
+
+#[test]
+fn lifetimes() {
+    insta::assert_snapshot!(dump_text_tokens(r#"'outer: 'a b' break 'outer continue 'a 'c::d'"#), @r###"
+    LIFETIME 6 "'outer"
+    COLON 1 ":"
+    WHITESPACE 1 " "
+    STRING 5 "'a b'"
+    WHITESPACE 1 " "
+    BREAK_KW 5 "break"
+    WHITESPACE 1 " "
+    LIFETIME 6 "'outer"
+    WHITESPACE 1 " "
+    CONTINUE_KW 8 "continue"
+    WHITESPACE 1 " "
+    LIFETIME 2 "'a"
+    WHITESPACE 1 " "
+    STRING 6 "'c::d'"
+    "###);
+}
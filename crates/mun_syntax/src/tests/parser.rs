@@ -909,6 +909,140 @@ fn struct_def() {
     "###);
 }
 
+#[test]
+fn enum_def() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    enum Foo        // error: expected a '{'
+    enum Foo {}
+    enum Foo {
+        A,
+        B(f64, i32),
+        C { a: f64 },
+    }
+    enum Foo { A, }
+    enum Foo { ,A } // error: expected an enum variant
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..216
+      WHITESPACE@0..5 "\n    "
+      ENUM_DEF@5..13
+        ENUM_KW@5..9 "enum"
+        WHITESPACE@9..10 " "
+        NAME@10..13
+          IDENT@10..13 "Foo"
+      WHITESPACE@13..21 "        "
+      COMMENT@21..45 "// error: expected a '{'"
+      WHITESPACE@45..50 "\n    "
+      ENUM_DEF@50..61
+        ENUM_KW@50..54 "enum"
+        WHITESPACE@54..55 " "
+        NAME@55..58
+          IDENT@55..58 "Foo"
+        WHITESPACE@58..59 " "
+        ENUM_VARIANT_LIST@59..61
+          L_CURLY@59..60 "{"
+          R_CURLY@60..61 "}"
+      WHITESPACE@61..66 "\n    "
+      ENUM_DEF@66..136
+        ENUM_KW@66..70 "enum"
+        WHITESPACE@70..71 " "
+        NAME@71..74
+          IDENT@71..74 "Foo"
+        WHITESPACE@74..75 " "
+        ENUM_VARIANT_LIST@75..136
+          L_CURLY@75..76 "{"
+          WHITESPACE@76..85 "\n        "
+          ENUM_VARIANT@85..86
+            NAME@85..86
+              IDENT@85..86 "A"
+          COMMA@86..87 ","
+          WHITESPACE@87..96 "\n        "
+          ENUM_VARIANT@96..107
+            NAME@96..97
+              IDENT@96..97 "B"
+            TUPLE_FIELD_DEF_LIST@97..107
+              L_PAREN@97..98 "("
+              TUPLE_FIELD_DEF@98..101
+                PATH_TYPE@98..101
+                  PATH@98..101
+                    PATH_SEGMENT@98..101
+                      NAME_REF@98..101
+                        IDENT@98..101 "f64"
+              COMMA@101..102 ","
+              WHITESPACE@102..103 " "
+              TUPLE_FIELD_DEF@103..106
+                PATH_TYPE@103..106
+                  PATH@103..106
+                    PATH_SEGMENT@103..106
+                      NAME_REF@103..106
+                        IDENT@103..106 "i32"
+              R_PAREN@106..107 ")"
+          COMMA@107..108 ","
+          WHITESPACE@108..117 "\n        "
+          ENUM_VARIANT@117..129
+            NAME@117..118
+              IDENT@117..118 "C"
+            WHITESPACE@118..119 " "
+            RECORD_FIELD_DEF_LIST@119..129
+              L_CURLY@119..120 "{"
+              WHITESPACE@120..121 " "
+              RECORD_FIELD_DEF@121..127
+                NAME@121..122
+                  IDENT@121..122 "a"
+                COLON@122..123 ":"
+                WHITESPACE@123..124 " "
+                PATH_TYPE@124..127
+                  PATH@124..127
+                    PATH_SEGMENT@124..127
+                      NAME_REF@124..127
+                        IDENT@124..127 "f64"
+              WHITESPACE@127..128 " "
+              R_CURLY@128..129 "}"
+          COMMA@129..130 ","
+          WHITESPACE@130..135 "\n    "
+          R_CURLY@135..136 "}"
+      WHITESPACE@136..141 "\n    "
+      ENUM_DEF@141..156
+        ENUM_KW@141..145 "enum"
+        WHITESPACE@145..146 " "
+        NAME@146..149
+          IDENT@146..149 "Foo"
+        WHITESPACE@149..150 " "
+        ENUM_VARIANT_LIST@150..156
+          L_CURLY@150..151 "{"
+          WHITESPACE@151..152 " "
+          ENUM_VARIANT@152..153
+            NAME@152..153
+              IDENT@152..153 "A"
+          COMMA@153..154 ","
+          WHITESPACE@154..155 " "
+          R_CURLY@155..156 "}"
+      WHITESPACE@156..161 "\n    "
+      ENUM_DEF@161..176
+        ENUM_KW@161..165 "enum"
+        WHITESPACE@165..166 " "
+        NAME@166..169
+          IDENT@166..169 "Foo"
+        WHITESPACE@169..170 " "
+        ENUM_VARIANT_LIST@170..176
+          L_CURLY@170..171 "{"
+          WHITESPACE@171..172 " "
+          ERROR@172..173
+            COMMA@172..173 ","
+          ENUM_VARIANT@173..174
+            NAME@173..174
+              IDENT@173..174 "A"
+          WHITESPACE@174..175 " "
+          R_CURLY@175..176 "}"
+      WHITESPACE@176..177 " "
+      COMMENT@177..211 "// error: expected an ..."
+      WHITESPACE@211..216 "\n    "
+    error Offset(13): expected a '{'
+    error Offset(172): expected an enum variant
+    "###);
+}
+
 #[test]
 fn unary_expr() {
     insta::assert_snapshot!(SourceFile::parse(
@@ -3498,3 +3632,3257 @@ fn use_() {
     error Offset(369): expected a declaration
     "###);
 }
+
+#[test]
+fn match_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo(a: i32, b: Foo) {
+        match a {
+            0 => 1,
+            -1 => { 2 }
+            1..=10 => 3,
+            _ => 4
+        };
+        match b {
+            Foo::A => 0,
+            Foo::B(x, _) => x,
+            Foo::C { a, b: y } => a + y,
+            Foo::D { .. } => 1,
+        }
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..314
+      FUNCTION_DEF@0..309
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..27
+          L_PAREN@11..12 "("
+          PARAM@12..18
+            BIND_PAT@12..13
+              NAME@12..13
+                IDENT@12..13 "a"
+            COLON@13..14 ":"
+            WHITESPACE@14..15 " "
+            PATH_TYPE@15..18
+              PATH@15..18
+                PATH_SEGMENT@15..18
+                  NAME_REF@15..18
+                    IDENT@15..18 "i32"
+          COMMA@18..19 ","
+          WHITESPACE@19..20 " "
+          PARAM@20..26
+            BIND_PAT@20..21
+              NAME@20..21
+                IDENT@20..21 "b"
+            COLON@21..22 ":"
+            WHITESPACE@22..23 " "
+            PATH_TYPE@23..26
+              PATH@23..26
+                PATH_SEGMENT@23..26
+                  NAME_REF@23..26
+                    IDENT@23..26 "Foo"
+          R_PAREN@26..27 ")"
+        WHITESPACE@27..28 " "
+        BLOCK_EXPR@28..309
+          L_CURLY@28..29 "{"
+          WHITESPACE@29..38 "\n        "
+          EXPR_STMT@38..146
+            MATCH_EXPR@38..145
+              MATCH_KW@38..43 "match"
+              WHITESPACE@43..44 " "
+              PATH_EXPR@44..45
+                PATH@44..45
+                  PATH_SEGMENT@44..45
+                    NAME_REF@44..45
+                      IDENT@44..45 "a"
+              WHITESPACE@45..46 " "
+              MATCH_ARM_LIST@46..145
+                L_CURLY@46..47 "{"
+                WHITESPACE@47..60 "\n            "
+                MATCH_ARM@60..66
+                  LITERAL_PAT@60..61
+                    LITERAL@60..61
+                      INT_NUMBER@60..61 "0"
+                  WHITESPACE@61..62 " "
+                  FAT_ARROW@62..64 "=>"
+                  WHITESPACE@64..65 " "
+                  LITERAL@65..66
+                    INT_NUMBER@65..66 "1"
+                COMMA@66..67 ","
+                WHITESPACE@67..80 "\n            "
+                MATCH_ARM@80..91
+                  LITERAL_PAT@80..82
+                    MINUS@80..81 "-"
+                    LITERAL@81..82
+                      INT_NUMBER@81..82 "1"
+                  WHITESPACE@82..83 " "
+                  FAT_ARROW@83..85 "=>"
+                  WHITESPACE@85..86 " "
+                  BLOCK_EXPR@86..91
+                    L_CURLY@86..87 "{"
+                    WHITESPACE@87..88 " "
+                    LITERAL@88..89
+                      INT_NUMBER@88..89 "2"
+                    WHITESPACE@89..90 " "
+                    R_CURLY@90..91 "}"
+                WHITESPACE@91..104 "\n            "
+                MATCH_ARM@104..115
+                  RANGE_PAT@104..110
+                    LITERAL_PAT@104..105
+                      LITERAL@104..105
+                        INT_NUMBER@104..105 "1"
+                    DOTDOTEQ@105..108 "..="
+                    LITERAL_PAT@108..110
+                      LITERAL@108..110
+                        INT_NUMBER@108..110 "10"
+                  WHITESPACE@110..111 " "
+                  FAT_ARROW@111..113 "=>"
+                  WHITESPACE@113..114 " "
+                  LITERAL@114..115
+                    INT_NUMBER@114..115 "3"
+                COMMA@115..116 ","
+                WHITESPACE@116..129 "\n            "
+                MATCH_ARM@129..135
+                  PLACEHOLDER_PAT@129..130
+                    UNDERSCORE@129..130 "_"
+                  WHITESPACE@130..131 " "
+                  FAT_ARROW@131..133 "=>"
+                  WHITESPACE@133..134 " "
+                  LITERAL@134..135
+                    INT_NUMBER@134..135 "4"
+                WHITESPACE@135..144 "\n        "
+                R_CURLY@144..145 "}"
+            SEMI@145..146 ";"
+          WHITESPACE@146..155 "\n        "
+          MATCH_EXPR@155..303
+            MATCH_KW@155..160 "match"
+            WHITESPACE@160..161 " "
+            PATH_EXPR@161..162
+              PATH@161..162
+                PATH_SEGMENT@161..162
+                  NAME_REF@161..162
+                    IDENT@161..162 "b"
+            WHITESPACE@162..163 " "
+            MATCH_ARM_LIST@163..303
+              L_CURLY@163..164 "{"
+              WHITESPACE@164..177 "\n            "
+              MATCH_ARM@177..188
+                PATH_PAT@177..183
+                  PATH@177..183
+                    PATH@177..180
+                      PATH_SEGMENT@177..180
+                        NAME_REF@177..180
+                          IDENT@177..180 "Foo"
+                    COLONCOLON@180..182 "::"
+                    PATH_SEGMENT@182..183
+                      NAME_REF@182..183
+                        IDENT@182..183 "A"
+                WHITESPACE@183..184 " "
+                FAT_ARROW@184..186 "=>"
+                WHITESPACE@186..187 " "
+                LITERAL@187..188
+                  INT_NUMBER@187..188 "0"
+              COMMA@188..189 ","
+              WHITESPACE@189..202 "\n            "
+              MATCH_ARM@202..219
+                TUPLE_STRUCT_PAT@202..214
+                  PATH@202..208
+                    PATH@202..205
+                      PATH_SEGMENT@202..205
+                        NAME_REF@202..205
+                          IDENT@202..205 "Foo"
+                    COLONCOLON@205..207 "::"
+                    PATH_SEGMENT@207..208
+                      NAME_REF@207..208
+                        IDENT@207..208 "B"
+                  L_PAREN@208..209 "("
+                  BIND_PAT@209..210
+                    NAME@209..210
+                      IDENT@209..210 "x"
+                  COMMA@210..211 ","
+                  WHITESPACE@211..212 " "
+                  PLACEHOLDER_PAT@212..213
+                    UNDERSCORE@212..213 "_"
+                  R_PAREN@213..214 ")"
+                WHITESPACE@214..215 " "
+                FAT_ARROW@215..217 "=>"
+                WHITESPACE@217..218 " "
+                PATH_EXPR@218..219
+                  PATH@218..219
+                    PATH_SEGMENT@218..219
+                      NAME_REF@218..219
+                        IDENT@218..219 "x"
+              COMMA@219..220 ","
+              WHITESPACE@220..233 "\n            "
+              MATCH_ARM@233..260
+                RECORD_PAT@233..251
+                  PATH@233..239
+                    PATH@233..236
+                      PATH_SEGMENT@233..236
+                        NAME_REF@233..236
+                          IDENT@233..236 "Foo"
+                    COLONCOLON@236..238 "::"
+                    PATH_SEGMENT@238..239
+                      NAME_REF@238..239
+                        IDENT@238..239 "C"
+                  WHITESPACE@239..240 " "
+                  RECORD_FIELD_PAT_LIST@240..251
+                    L_CURLY@240..241 "{"
+                    WHITESPACE@241..242 " "
+                    RECORD_FIELD_PAT@242..243
+                      BIND_PAT@242..243
+                        NAME@242..243
+                          IDENT@242..243 "a"
+                    COMMA@243..244 ","
+                    WHITESPACE@244..245 " "
+                    RECORD_FIELD_PAT@245..249
+                      NAME_REF@245..246
+                        IDENT@245..246 "b"
+                      COLON@246..247 ":"
+                      WHITESPACE@247..248 " "
+                      BIND_PAT@248..249
+                        NAME@248..249
+                          IDENT@248..249 "y"
+                    WHITESPACE@249..250 " "
+                    R_CURLY@250..251 "}"
+                WHITESPACE@251..252 " "
+                FAT_ARROW@252..254 "=>"
+                WHITESPACE@254..255 " "
+                BIN_EXPR@255..260
+                  PATH_EXPR@255..256
+                    PATH@255..256
+                      PATH_SEGMENT@255..256
+                        NAME_REF@255..256
+                          IDENT@255..256 "a"
+                  WHITESPACE@256..257 " "
+                  PLUS@257..258 "+"
+                  WHITESPACE@258..259 " "
+                  PATH_EXPR@259..260
+                    PATH@259..260
+                      PATH_SEGMENT@259..260
+                        NAME_REF@259..260
+                          IDENT@259..260 "y"
+              COMMA@260..261 ","
+              WHITESPACE@261..274 "\n            "
+              MATCH_ARM@274..292
+                RECORD_PAT@274..287
+                  PATH@274..280
+                    PATH@274..277
+                      PATH_SEGMENT@274..277
+                        NAME_REF@274..277
+                          IDENT@274..277 "Foo"
+                    COLONCOLON@277..279 "::"
+                    PATH_SEGMENT@279..280
+                      NAME_REF@279..280
+                        IDENT@279..280 "D"
+                  WHITESPACE@280..281 " "
+                  RECORD_FIELD_PAT_LIST@281..287
+                    L_CURLY@281..282 "{"
+                    WHITESPACE@282..283 " "
+                    DOTDOT@283..285 ".."
+                    WHITESPACE@285..286 " "
+                    R_CURLY@286..287 "}"
+                WHITESPACE@287..288 " "
+                FAT_ARROW@288..290 "=>"
+                WHITESPACE@290..291 " "
+                LITERAL@291..292
+                  INT_NUMBER@291..292 "1"
+              COMMA@292..293 ","
+              WHITESPACE@293..302 "\n        "
+              R_CURLY@302..303 "}"
+          WHITESPACE@303..308 "\n    "
+          R_CURLY@308..309 "}"
+      WHITESPACE@309..314 "\n    "
+    "###);
+}
+
+#[test]
+fn match_expr_errors() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo(a: i32) {
+        match a {
+            0 => 1
+            1 => 2,
+            => 3,
+            Foo { .., a } => 4,
+        }
+        match a
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..165
+      FUNCTION_DEF@0..160
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..19
+          L_PAREN@11..12 "("
+          PARAM@12..18
+            BIND_PAT@12..13
+              NAME@12..13
+                IDENT@12..13 "a"
+            COLON@13..14 ":"
+            WHITESPACE@14..15 " "
+            PATH_TYPE@15..18
+              PATH@15..18
+                PATH_SEGMENT@15..18
+                  NAME_REF@15..18
+                    IDENT@15..18 "i32"
+          R_PAREN@18..19 ")"
+        WHITESPACE@19..20 " "
+        BLOCK_EXPR@20..160
+          L_CURLY@20..21 "{"
+          WHITESPACE@21..30 "\n        "
+          EXPR_STMT@30..138
+            MATCH_EXPR@30..138
+              MATCH_KW@30..35 "match"
+              WHITESPACE@35..36 " "
+              PATH_EXPR@36..37
+                PATH@36..37
+                  PATH_SEGMENT@36..37
+                    NAME_REF@36..37
+                      IDENT@36..37 "a"
+              WHITESPACE@37..38 " "
+              MATCH_ARM_LIST@38..138
+                L_CURLY@38..39 "{"
+                WHITESPACE@39..52 "\n            "
+                MATCH_ARM@52..58
+                  LITERAL_PAT@52..53
+                    LITERAL@52..53
+                      INT_NUMBER@52..53 "0"
+                  WHITESPACE@53..54 " "
+                  FAT_ARROW@54..56 "=>"
+                  WHITESPACE@56..57 " "
+                  LITERAL@57..58
+                    INT_NUMBER@57..58 "1"
+                WHITESPACE@58..71 "\n            "
+                MATCH_ARM@71..77
+                  LITERAL_PAT@71..72
+                    LITERAL@71..72
+                      INT_NUMBER@71..72 "1"
+                  WHITESPACE@72..73 " "
+                  FAT_ARROW@73..75 "=>"
+                  WHITESPACE@75..76 " "
+                  LITERAL@76..77
+                    INT_NUMBER@76..77 "2"
+                COMMA@77..78 ","
+                WHITESPACE@78..91 "\n            "
+                ERROR@91..92
+                  EQ@91..92 "="
+                ERROR@92..93
+                  GT@92..93 ">"
+                WHITESPACE@93..94 " "
+                MATCH_ARM@94..96
+                  LITERAL_PAT@94..95
+                    LITERAL@94..95
+                      INT_NUMBER@94..95 "3"
+                  ERROR@95..96
+                    COMMA@95..96 ","
+                WHITESPACE@96..109 "\n            "
+                MATCH_ARM@109..127
+                  RECORD_PAT@109..122
+                    PATH@109..112
+                      PATH_SEGMENT@109..112
+                        NAME_REF@109..112
+                          IDENT@109..112 "Foo"
+                    WHITESPACE@112..113 " "
+                    RECORD_FIELD_PAT_LIST@113..122
+                      L_CURLY@113..114 "{"
+                      WHITESPACE@114..115 " "
+                      DOTDOT@115..117 ".."
+                      COMMA@117..118 ","
+                      WHITESPACE@118..119 " "
+                      RECORD_FIELD_PAT@119..120
+                        BIND_PAT@119..120
+                          NAME@119..120
+                            IDENT@119..120 "a"
+                      WHITESPACE@120..121 " "
+                      R_CURLY@121..122 "}"
+                  WHITESPACE@122..123 " "
+                  FAT_ARROW@123..125 "=>"
+                  WHITESPACE@125..126 " "
+                  LITERAL@126..127
+                    INT_NUMBER@126..127 "4"
+                COMMA@127..128 ","
+                WHITESPACE@128..137 "\n        "
+                R_CURLY@137..138 "}"
+          WHITESPACE@138..147 "\n        "
+          MATCH_EXPR@147..154
+            MATCH_KW@147..152 "match"
+            WHITESPACE@152..153 " "
+            PATH_EXPR@153..154
+              PATH@153..154
+                PATH_SEGMENT@153..154
+                  NAME_REF@153..154
+                    IDENT@153..154 "a"
+          WHITESPACE@154..159 "\n    "
+          R_CURLY@159..160 "}"
+      WHITESPACE@160..165 "\n    "
+    error Offset(58): expected `,`
+    error Offset(91): expected pattern
+    error Offset(92): expected pattern
+    error Offset(95): expected FAT_ARROW
+    error Offset(95): expected expression
+    error Offset(96): expected `,`
+    error Offset(117): `..` must be at the end of the pattern
+    error Offset(154): expected `{`
+    "###);
+}
+
+#[test]
+fn for_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo() {
+        for x in [1, 2] {};
+        for i in 0..n { break; }
+        for _ in a.b..=c * 2 {}
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..119
+      FUNCTION_DEF@0..114
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..13
+          L_PAREN@11..12 "("
+          R_PAREN@12..13 ")"
+        WHITESPACE@13..14 " "
+        BLOCK_EXPR@14..114
+          L_CURLY@14..15 "{"
+          WHITESPACE@15..24 "\n        "
+          EXPR_STMT@24..43
+            FOR_EXPR@24..42
+              FOR_KW@24..27 "for"
+              WHITESPACE@27..28 " "
+              BIND_PAT@28..29
+                NAME@28..29
+                  IDENT@28..29 "x"
+              WHITESPACE@29..30 " "
+              IN_KW@30..32 "in"
+              WHITESPACE@32..33 " "
+              ARRAY_EXPR@33..39
+                L_BRACKET@33..34 "["
+                LITERAL@34..35
+                  INT_NUMBER@34..35 "1"
+                COMMA@35..36 ","
+                WHITESPACE@36..37 " "
+                LITERAL@37..38
+                  INT_NUMBER@37..38 "2"
+                R_BRACKET@38..39 "]"
+              WHITESPACE@39..40 " "
+              BLOCK_EXPR@40..42
+                L_CURLY@40..41 "{"
+                R_CURLY@41..42 "}"
+            SEMI@42..43 ";"
+          WHITESPACE@43..52 "\n        "
+          EXPR_STMT@52..76
+            FOR_EXPR@52..76
+              FOR_KW@52..55 "for"
+              WHITESPACE@55..56 " "
+              BIND_PAT@56..57
+                NAME@56..57
+                  IDENT@56..57 "i"
+              WHITESPACE@57..58 " "
+              IN_KW@58..60 "in"
+              WHITESPACE@60..61 " "
+              RANGE_EXPR@61..65
+                LITERAL@61..62
+                  INT_NUMBER@61..62 "0"
+                DOTDOT@62..64 ".."
+                PATH_EXPR@64..65
+                  PATH@64..65
+                    PATH_SEGMENT@64..65
+                      NAME_REF@64..65
+                        IDENT@64..65 "n"
+              WHITESPACE@65..66 " "
+              BLOCK_EXPR@66..76
+                L_CURLY@66..67 "{"
+                WHITESPACE@67..68 " "
+                EXPR_STMT@68..74
+                  BREAK_EXPR@68..73
+                    BREAK_KW@68..73 "break"
+                  SEMI@73..74 ";"
+                WHITESPACE@74..75 " "
+                R_CURLY@75..76 "}"
+          WHITESPACE@76..85 "\n        "
+          FOR_EXPR@85..108
+            FOR_KW@85..88 "for"
+            WHITESPACE@88..89 " "
+            PLACEHOLDER_PAT@89..90
+              UNDERSCORE@89..90 "_"
+            WHITESPACE@90..91 " "
+            IN_KW@91..93 "in"
+            WHITESPACE@93..94 " "
+            RANGE_EXPR@94..105
+              FIELD_EXPR@94..97
+                PATH_EXPR@94..95
+                  PATH@94..95
+                    PATH_SEGMENT@94..95
+                      NAME_REF@94..95
+                        IDENT@94..95 "a"
+                DOT@95..96 "."
+                NAME_REF@96..97
+                  IDENT@96..97 "b"
+              DOTDOTEQ@97..100 "..="
+              BIN_EXPR@100..105
+                PATH_EXPR@100..101
+                  PATH@100..101
+                    PATH_SEGMENT@100..101
+                      NAME_REF@100..101
+                        IDENT@100..101 "c"
+                WHITESPACE@101..102 " "
+                STAR@102..103 "*"
+                WHITESPACE@103..104 " "
+                LITERAL@104..105
+                  INT_NUMBER@104..105 "2"
+            WHITESPACE@105..106 " "
+            BLOCK_EXPR@106..108
+              L_CURLY@106..107 "{"
+              R_CURLY@107..108 "}"
+          WHITESPACE@108..113 "\n    "
+          R_CURLY@113..114 "}"
+      WHITESPACE@114..119 "\n    "
+    "###);
+}
+
+#[test]
+fn range_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo() {
+        let a = 0..10;
+        let b = 1 + 2..=x.y;
+        let c = 0.0..1.0;
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..104
+      FUNCTION_DEF@0..99
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..13
+          L_PAREN@11..12 "("
+          R_PAREN@12..13 ")"
+        WHITESPACE@13..14 " "
+        BLOCK_EXPR@14..99
+          L_CURLY@14..15 "{"
+          WHITESPACE@15..24 "\n        "
+          LET_STMT@24..38
+            LET_KW@24..27 "let"
+            WHITESPACE@27..28 " "
+            BIND_PAT@28..29
+              NAME@28..29
+                IDENT@28..29 "a"
+            WHITESPACE@29..30 " "
+            EQ@30..31 "="
+            WHITESPACE@31..32 " "
+            RANGE_EXPR@32..37
+              LITERAL@32..33
+                INT_NUMBER@32..33 "0"
+              DOTDOT@33..35 ".."
+              LITERAL@35..37
+                INT_NUMBER@35..37 "10"
+            SEMI@37..38 ";"
+          WHITESPACE@38..47 "\n        "
+          LET_STMT@47..67
+            LET_KW@47..50 "let"
+            WHITESPACE@50..51 " "
+            BIND_PAT@51..52
+              NAME@51..52
+                IDENT@51..52 "b"
+            WHITESPACE@52..53 " "
+            EQ@53..54 "="
+            WHITESPACE@54..55 " "
+            RANGE_EXPR@55..66
+              BIN_EXPR@55..60
+                LITERAL@55..56
+                  INT_NUMBER@55..56 "1"
+                WHITESPACE@56..57 " "
+                PLUS@57..58 "+"
+                WHITESPACE@58..59 " "
+                LITERAL@59..60
+                  INT_NUMBER@59..60 "2"
+              DOTDOTEQ@60..63 "..="
+              FIELD_EXPR@63..66
+                PATH_EXPR@63..64
+                  PATH@63..64
+                    PATH_SEGMENT@63..64
+                      NAME_REF@63..64
+                        IDENT@63..64 "x"
+                DOT@64..65 "."
+                NAME_REF@65..66
+                  IDENT@65..66 "y"
+            SEMI@66..67 ";"
+          WHITESPACE@67..76 "\n        "
+          LET_STMT@76..93
+            LET_KW@76..79 "let"
+            WHITESPACE@79..80 " "
+            BIND_PAT@80..81
+              NAME@80..81
+                IDENT@80..81 "c"
+            WHITESPACE@81..82 " "
+            EQ@82..83 "="
+            WHITESPACE@83..84 " "
+            RANGE_EXPR@84..92
+              LITERAL@84..87
+                FLOAT_NUMBER@84..87 "0.0"
+              DOTDOT@87..89 ".."
+              LITERAL@89..92
+                FLOAT_NUMBER@89..92 "1.0"
+            SEMI@92..93 ";"
+          WHITESPACE@93..98 "\n    "
+          R_CURLY@98..99 "}"
+      WHITESPACE@99..104 "\n    "
+    "###);
+}
+
+#[test]
+fn labeled_loops() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo() {
+        'outer: loop {
+            'inner: while true {
+                continue 'outer;
+                break 'inner;
+            }
+            continue;
+        }
+        'a: for i in 0..10 { break 'a; }
+        'b: if true {}
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..255
+      FUNCTION_DEF@0..250
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..13
+          L_PAREN@11..12 "("
+          R_PAREN@12..13 ")"
+        WHITESPACE@13..14 " "
+        BLOCK_EXPR@14..250
+          L_CURLY@14..15 "{"
+          WHITESPACE@15..24 "\n        "
+          EXPR_STMT@24..180
+            LOOP_EXPR@24..180
+              LABEL@24..31
+                LIFETIME@24..30 "'outer"
+                COLON@30..31 ":"
+              WHITESPACE@31..32 " "
+              LOOP_KW@32..36 "loop"
+              WHITESPACE@36..37 " "
+              BLOCK_EXPR@37..180
+                L_CURLY@37..38 "{"
+                WHITESPACE@38..51 "\n            "
+                EXPR_STMT@51..148
+                  WHILE_EXPR@51..148
+                    LABEL@51..58
+                      LIFETIME@51..57 "'inner"
+                      COLON@57..58 ":"
+                    WHITESPACE@58..59 " "
+                    WHILE_KW@59..64 "while"
+                    WHITESPACE@64..65 " "
+                    CONDITION@65..69
+                      LITERAL@65..69
+                        TRUE_KW@65..69 "true"
+                    WHITESPACE@69..70 " "
+                    BLOCK_EXPR@70..148
+                      L_CURLY@70..71 "{"
+                      WHITESPACE@71..88 "\n                "
+                      EXPR_STMT@88..104
+                        CONTINUE_EXPR@88..103
+                          CONTINUE_KW@88..96 "continue"
+                          WHITESPACE@96..97 " "
+                          LIFETIME@97..103 "'outer"
+                        SEMI@103..104 ";"
+                      WHITESPACE@104..121 "\n                "
+                      EXPR_STMT@121..134
+                        BREAK_EXPR@121..133
+                          BREAK_KW@121..126 "break"
+                          WHITESPACE@126..127 " "
+                          LIFETIME@127..133 "'inner"
+                        SEMI@133..134 ";"
+                      WHITESPACE@134..147 "\n            "
+                      R_CURLY@147..148 "}"
+                WHITESPACE@148..161 "\n            "
+                EXPR_STMT@161..170
+                  CONTINUE_EXPR@161..169
+                    CONTINUE_KW@161..169 "continue"
+                  SEMI@169..170 ";"
+                WHITESPACE@170..179 "\n        "
+                R_CURLY@179..180 "}"
+          WHITESPACE@180..189 "\n        "
+          EXPR_STMT@189..221
+            FOR_EXPR@189..221
+              LABEL@189..192
+                LIFETIME@189..191 "'a"
+                COLON@191..192 ":"
+              WHITESPACE@192..193 " "
+              FOR_KW@193..196 "for"
+              WHITESPACE@196..197 " "
+              BIND_PAT@197..198
+                NAME@197..198
+                  IDENT@197..198 "i"
+              WHITESPACE@198..199 " "
+              IN_KW@199..201 "in"
+              WHITESPACE@201..202 " "
+              RANGE_EXPR@202..207
+                LITERAL@202..203
+                  INT_NUMBER@202..203 "0"
+                DOTDOT@203..205 ".."
+                LITERAL@205..207
+                  INT_NUMBER@205..207 "10"
+              WHITESPACE@207..208 " "
+              BLOCK_EXPR@208..221
+                L_CURLY@208..209 "{"
+                WHITESPACE@209..210 " "
+                EXPR_STMT@210..219
+                  BREAK_EXPR@210..218
+                    BREAK_KW@210..215 "break"
+                    WHITESPACE@215..216 " "
+                    LIFETIME@216..218 "'a"
+                  SEMI@218..219 ";"
+                WHITESPACE@219..220 " "
+                R_CURLY@220..221 "}"
+          WHITESPACE@221..230 "\n        "
+          EXPR_STMT@230..233
+            ERROR@230..233
+              LABEL@230..233
+                LIFETIME@230..232 "'b"
+                COLON@232..233 ":"
+          WHITESPACE@233..234 " "
+          IF_EXPR@234..244
+            IF_KW@234..236 "if"
+            WHITESPACE@236..237 " "
+            CONDITION@237..241
+              LITERAL@237..241
+                TRUE_KW@237..241 "true"
+            WHITESPACE@241..242 " "
+            BLOCK_EXPR@242..244
+              L_CURLY@242..243 "{"
+              R_CURLY@243..244 "}"
+          WHITESPACE@244..249 "\n    "
+          R_CURLY@249..250 "}"
+      WHITESPACE@250..255 "\n    "
+    error Offset(233): expected a loop
+    "###);
+}
+
+#[test]
+fn method_call_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo() {
+        let a = "foo".len();
+        let b = a.bar(1, c).baz();
+        let c = a.field;
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..115
+      FUNCTION_DEF@0..110
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..13
+          L_PAREN@11..12 "("
+          R_PAREN@12..13 ")"
+        WHITESPACE@13..14 " "
+        BLOCK_EXPR@14..110
+          L_CURLY@14..15 "{"
+          WHITESPACE@15..24 "\n        "
+          LET_STMT@24..44
+            LET_KW@24..27 "let"
+            WHITESPACE@27..28 " "
+            BIND_PAT@28..29
+              NAME@28..29
+                IDENT@28..29 "a"
+            WHITESPACE@29..30 " "
+            EQ@30..31 "="
+            WHITESPACE@31..32 " "
+            METHOD_CALL_EXPR@32..43
+              LITERAL@32..37
+                STRING@32..37 "\"foo\""
+              DOT@37..38 "."
+              NAME_REF@38..41
+                IDENT@38..41 "len"
+              ARG_LIST@41..43
+                L_PAREN@41..42 "("
+                R_PAREN@42..43 ")"
+            SEMI@43..44 ";"
+          WHITESPACE@44..53 "\n        "
+          LET_STMT@53..79
+            LET_KW@53..56 "let"
+            WHITESPACE@56..57 " "
+            BIND_PAT@57..58
+              NAME@57..58
+                IDENT@57..58 "b"
+            WHITESPACE@58..59 " "
+            EQ@59..60 "="
+            WHITESPACE@60..61 " "
+            METHOD_CALL_EXPR@61..78
+              METHOD_CALL_EXPR@61..72
+                PATH_EXPR@61..62
+                  PATH@61..62
+                    PATH_SEGMENT@61..62
+                      NAME_REF@61..62
+                        IDENT@61..62 "a"
+                DOT@62..63 "."
+                NAME_REF@63..66
+                  IDENT@63..66 "bar"
+                ARG_LIST@66..72
+                  L_PAREN@66..67 "("
+                  LITERAL@67..68
+                    INT_NUMBER@67..68 "1"
+                  COMMA@68..69 ","
+                  WHITESPACE@69..70 " "
+                  PATH_EXPR@70..71
+                    PATH@70..71
+                      PATH_SEGMENT@70..71
+                        NAME_REF@70..71
+                          IDENT@70..71 "c"
+                  R_PAREN@71..72 ")"
+              DOT@72..73 "."
+              NAME_REF@73..76
+                IDENT@73..76 "baz"
+              ARG_LIST@76..78
+                L_PAREN@76..77 "("
+                R_PAREN@77..78 ")"
+            SEMI@78..79 ";"
+          WHITESPACE@79..88 "\n        "
+          LET_STMT@88..104
+            LET_KW@88..91 "let"
+            WHITESPACE@91..92 " "
+            BIND_PAT@92..93
+              NAME@92..93
+                IDENT@92..93 "c"
+            WHITESPACE@93..94 " "
+            EQ@94..95 "="
+            WHITESPACE@95..96 " "
+            FIELD_EXPR@96..103
+              PATH_EXPR@96..97
+                PATH@96..97
+                  PATH_SEGMENT@96..97
+                    NAME_REF@96..97
+                      IDENT@96..97 "a"
+              DOT@97..98 "."
+              NAME_REF@98..103
+                IDENT@98..103 "field"
+            SEMI@103..104 ";"
+          WHITESPACE@104..109 "\n    "
+          R_CURLY@109..110 "}"
+      WHITESPACE@110..115 "\n    "
+    "###);
+}
+
+#[test]
+fn cast_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn foo() {
+        let a = 82 as i32;
+        let b = 81 as i8 + 1;
+        let c = 1 + 2 as f64 * 3;
+        let d = -1 as u8 as u16;
+        let e = a as b <= 0x37;
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..182
+      FUNCTION_DEF@0..177
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..11
+          IDENT@8..11 "foo"
+        PARAM_LIST@11..13
+          L_PAREN@11..12 "("
+          R_PAREN@12..13 ")"
+        WHITESPACE@13..14 " "
+        BLOCK_EXPR@14..177
+          L_CURLY@14..15 "{"
+          WHITESPACE@15..24 "\n        "
+          LET_STMT@24..42
+            LET_KW@24..27 "let"
+            WHITESPACE@27..28 " "
+            BIND_PAT@28..29
+              NAME@28..29
+                IDENT@28..29 "a"
+            WHITESPACE@29..30 " "
+            EQ@30..31 "="
+            WHITESPACE@31..32 " "
+            CAST_EXPR@32..41
+              LITERAL@32..34
+                INT_NUMBER@32..34 "82"
+              WHITESPACE@34..35 " "
+              AS_KW@35..37 "as"
+              WHITESPACE@37..38 " "
+              PATH_TYPE@38..41
+                PATH@38..41
+                  PATH_SEGMENT@38..41
+                    NAME_REF@38..41
+                      IDENT@38..41 "i32"
+            SEMI@41..42 ";"
+          WHITESPACE@42..51 "\n        "
+          LET_STMT@51..72
+            LET_KW@51..54 "let"
+            WHITESPACE@54..55 " "
+            BIND_PAT@55..56
+              NAME@55..56
+                IDENT@55..56 "b"
+            WHITESPACE@56..57 " "
+            EQ@57..58 "="
+            WHITESPACE@58..59 " "
+            BIN_EXPR@59..71
+              CAST_EXPR@59..67
+                LITERAL@59..61
+                  INT_NUMBER@59..61 "81"
+                WHITESPACE@61..62 " "
+                AS_KW@62..64 "as"
+                WHITESPACE@64..65 " "
+                PATH_TYPE@65..67
+                  PATH@65..67
+                    PATH_SEGMENT@65..67
+                      NAME_REF@65..67
+                        IDENT@65..67 "i8"
+              WHITESPACE@67..68 " "
+              PLUS@68..69 "+"
+              WHITESPACE@69..70 " "
+              LITERAL@70..71
+                INT_NUMBER@70..71 "1"
+            SEMI@71..72 ";"
+          WHITESPACE@72..81 "\n        "
+          LET_STMT@81..106
+            LET_KW@81..84 "let"
+            WHITESPACE@84..85 " "
+            BIND_PAT@85..86
+              NAME@85..86
+                IDENT@85..86 "c"
+            WHITESPACE@86..87 " "
+            EQ@87..88 "="
+            WHITESPACE@88..89 " "
+            BIN_EXPR@89..105
+              LITERAL@89..90
+                INT_NUMBER@89..90 "1"
+              WHITESPACE@90..91 " "
+              PLUS@91..92 "+"
+              WHITESPACE@92..93 " "
+              BIN_EXPR@93..105
+                CAST_EXPR@93..101
+                  LITERAL@93..94
+                    INT_NUMBER@93..94 "2"
+                  WHITESPACE@94..95 " "
+                  AS_KW@95..97 "as"
+                  WHITESPACE@97..98 " "
+                  PATH_TYPE@98..101
+                    PATH@98..101
+                      PATH_SEGMENT@98..101
+                        NAME_REF@98..101
+                          IDENT@98..101 "f64"
+                WHITESPACE@101..102 " "
+                STAR@102..103 "*"
+                WHITESPACE@103..104 " "
+                LITERAL@104..105
+                  INT_NUMBER@104..105 "3"
+            SEMI@105..106 ";"
+          WHITESPACE@106..115 "\n        "
+          LET_STMT@115..139
+            LET_KW@115..118 "let"
+            WHITESPACE@118..119 " "
+            BIND_PAT@119..120
+              NAME@119..120
+                IDENT@119..120 "d"
+            WHITESPACE@120..121 " "
+            EQ@121..122 "="
+            WHITESPACE@122..123 " "
+            CAST_EXPR@123..138
+              CAST_EXPR@123..131
+                PREFIX_EXPR@123..125
+                  MINUS@123..124 "-"
+                  LITERAL@124..125
+                    INT_NUMBER@124..125 "1"
+                WHITESPACE@125..126 " "
+                AS_KW@126..128 "as"
+                WHITESPACE@128..129 " "
+                PATH_TYPE@129..131
+                  PATH@129..131
+                    PATH_SEGMENT@129..131
+                      NAME_REF@129..131
+                        IDENT@129..131 "u8"
+              WHITESPACE@131..132 " "
+              AS_KW@132..134 "as"
+              WHITESPACE@134..135 " "
+              PATH_TYPE@135..138
+                PATH@135..138
+                  PATH_SEGMENT@135..138
+                    NAME_REF@135..138
+                      IDENT@135..138 "u16"
+            SEMI@138..139 ";"
+          WHITESPACE@139..148 "\n        "
+          LET_STMT@148..171
+            LET_KW@148..151 "let"
+            WHITESPACE@151..152 " "
+            BIND_PAT@152..153
+              NAME@152..153
+                IDENT@152..153 "e"
+            WHITESPACE@153..154 " "
+            EQ@154..155 "="
+            WHITESPACE@155..156 " "
+            BIN_EXPR@156..170
+              CAST_EXPR@156..162
+                PATH_EXPR@156..157
+                  PATH@156..157
+                    PATH_SEGMENT@156..157
+                      NAME_REF@156..157
+                        IDENT@156..157 "a"
+                WHITESPACE@157..158 " "
+                AS_KW@158..160 "as"
+                WHITESPACE@160..161 " "
+                PATH_TYPE@161..162
+                  PATH@161..162
+                    PATH_SEGMENT@161..162
+                      NAME_REF@161..162
+                        IDENT@161..162 "b"
+              WHITESPACE@162..163 " "
+              LTEQ@163..165 "<="
+              WHITESPACE@165..166 " "
+              LITERAL@166..170
+                INT_NUMBER@166..170 "0x37"
+            SEMI@170..171 ";"
+          WHITESPACE@171..176 "\n    "
+          R_CURLY@176..177 "}"
+      WHITESPACE@177..182 "\n    "
+    "###);
+}
+
+#[test]
+fn impl_def() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    impl Foo {}
+    impl Foo {
+        fn new() -> Foo { Foo }
+        pub fn bar(self: Foo, a: i32) -> i32 { self.a + a }
+    }
+    impl Foo        // error: expected `{`
+    impl Foo { struct Bar; } // error: expected an associated function
+    fn baz(a: i32, self: Foo) {} // error: `self` is only allowed as the first parameter
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..337
+      WHITESPACE@0..5 "\n    "
+      IMPL@5..16
+        IMPL_KW@5..9 "impl"
+        WHITESPACE@9..10 " "
+        PATH_TYPE@10..13
+          PATH@10..13
+            PATH_SEGMENT@10..13
+              NAME_REF@10..13
+                IDENT@10..13 "Foo"
+        WHITESPACE@13..14 " "
+        ASSOCIATED_ITEM_LIST@14..16
+          L_CURLY@14..15 "{"
+          R_CURLY@15..16 "}"
+      WHITESPACE@16..21 "\n    "
+      IMPL@21..129
+        IMPL_KW@21..25 "impl"
+        WHITESPACE@25..26 " "
+        PATH_TYPE@26..29
+          PATH@26..29
+            PATH_SEGMENT@26..29
+              NAME_REF@26..29
+                IDENT@26..29 "Foo"
+        WHITESPACE@29..30 " "
+        ASSOCIATED_ITEM_LIST@30..129
+          L_CURLY@30..31 "{"
+          FUNCTION_DEF@31..63
+            WHITESPACE@31..40 "\n        "
+            FN_KW@40..42 "fn"
+            WHITESPACE@42..43 " "
+            NAME@43..46
+              IDENT@43..46 "new"
+            PARAM_LIST@46..48
+              L_PAREN@46..47 "("
+              R_PAREN@47..48 ")"
+            WHITESPACE@48..49 " "
+            RET_TYPE@49..55
+              THIN_ARROW@49..51 "->"
+              WHITESPACE@51..52 " "
+              PATH_TYPE@52..55
+                PATH@52..55
+                  PATH_SEGMENT@52..55
+                    NAME_REF@52..55
+                      IDENT@52..55 "Foo"
+            WHITESPACE@55..56 " "
+            BLOCK_EXPR@56..63
+              L_CURLY@56..57 "{"
+              WHITESPACE@57..58 " "
+              PATH_EXPR@58..61
+                PATH@58..61
+                  PATH_SEGMENT@58..61
+                    NAME_REF@58..61
+                      IDENT@58..61 "Foo"
+              WHITESPACE@61..62 " "
+              R_CURLY@62..63 "}"
+          FUNCTION_DEF@63..123
+            WHITESPACE@63..72 "\n        "
+            VISIBILITY@72..75
+              PUB_KW@72..75 "pub"
+            WHITESPACE@75..76 " "
+            FN_KW@76..78 "fn"
+            WHITESPACE@78..79 " "
+            NAME@79..82
+              IDENT@79..82 "bar"
+            PARAM_LIST@82..101
+              L_PAREN@82..83 "("
+              PARAM@83..92
+                BIND_PAT@83..87
+                  NAME@83..87
+                    SELF_KW@83..87 "self"
+                COLON@87..88 ":"
+                WHITESPACE@88..89 " "
+                PATH_TYPE@89..92
+                  PATH@89..92
+                    PATH_SEGMENT@89..92
+                      NAME_REF@89..92
+                        IDENT@89..92 "Foo"
+              COMMA@92..93 ","
+              WHITESPACE@93..94 " "
+              PARAM@94..100
+                BIND_PAT@94..95
+                  NAME@94..95
+                    IDENT@94..95 "a"
+                COLON@95..96 ":"
+                WHITESPACE@96..97 " "
+                PATH_TYPE@97..100
+                  PATH@97..100
+                    PATH_SEGMENT@97..100
+                      NAME_REF@97..100
+                        IDENT@97..100 "i32"
+              R_PAREN@100..101 ")"
+            WHITESPACE@101..102 " "
+            RET_TYPE@102..108
+              THIN_ARROW@102..104 "->"
+              WHITESPACE@104..105 " "
+              PATH_TYPE@105..108
+                PATH@105..108
+                  PATH_SEGMENT@105..108
+                    NAME_REF@105..108
+                      IDENT@105..108 "i32"
+            WHITESPACE@108..109 " "
+            BLOCK_EXPR@109..123
+              L_CURLY@109..110 "{"
+              WHITESPACE@110..111 " "
+              BIN_EXPR@111..121
+                FIELD_EXPR@111..117
+                  PATH_EXPR@111..115
+                    PATH@111..115
+                      PATH_SEGMENT@111..115
+                        SELF_KW@111..115 "self"
+                  DOT@115..116 "."
+                  NAME_REF@116..117
+                    IDENT@116..117 "a"
+                WHITESPACE@117..118 " "
+                PLUS@118..119 "+"
+                WHITESPACE@119..120 " "
+                PATH_EXPR@120..121
+                  PATH@120..121
+                    PATH_SEGMENT@120..121
+                      NAME_REF@120..121
+                        IDENT@120..121 "a"
+              WHITESPACE@121..122 " "
+              R_CURLY@122..123 "}"
+          WHITESPACE@123..128 "\n    "
+          R_CURLY@128..129 "}"
+      WHITESPACE@129..134 "\n    "
+      IMPL@134..142
+        IMPL_KW@134..138 "impl"
+        WHITESPACE@138..139 " "
+        PATH_TYPE@139..142
+          PATH@139..142
+            PATH_SEGMENT@139..142
+              NAME_REF@139..142
+                IDENT@139..142 "Foo"
+      WHITESPACE@142..150 "        "
+      COMMENT@150..172 "// error: expected `{`"
+      WHITESPACE@172..177 "\n    "
+      IMPL@177..201
+        IMPL_KW@177..181 "impl"
+        WHITESPACE@181..182 " "
+        PATH_TYPE@182..185
+          PATH@182..185
+            PATH_SEGMENT@182..185
+              NAME_REF@182..185
+                IDENT@182..185 "Foo"
+        WHITESPACE@185..186 " "
+        ASSOCIATED_ITEM_LIST@186..201
+          L_CURLY@186..187 "{"
+          WHITESPACE@187..188 " "
+          ERROR@188..194
+            STRUCT_KW@188..194 "struct"
+          WHITESPACE@194..195 " "
+          ERROR@195..198
+            IDENT@195..198 "Bar"
+          ERROR@198..199
+            SEMI@198..199 ";"
+          WHITESPACE@199..200 " "
+          R_CURLY@200..201 "}"
+      FUNCTION_DEF@201..276
+        WHITESPACE@201..202 " "
+        COMMENT@202..243 "// error: expected an ..."
+        WHITESPACE@243..248 "\n    "
+        FN_KW@248..250 "fn"
+        WHITESPACE@250..251 " "
+        NAME@251..254
+          IDENT@251..254 "baz"
+        PARAM_LIST@254..273
+          L_PAREN@254..255 "("
+          PARAM@255..261
+            BIND_PAT@255..256
+              NAME@255..256
+                IDENT@255..256 "a"
+            COLON@256..257 ":"
+            WHITESPACE@257..258 " "
+            PATH_TYPE@258..261
+              PATH@258..261
+                PATH_SEGMENT@258..261
+                  NAME_REF@258..261
+                    IDENT@258..261 "i32"
+          COMMA@261..262 ","
+          WHITESPACE@262..263 " "
+          PARAM@263..272
+            BIND_PAT@263..267
+              NAME@263..267
+                SELF_KW@263..267 "self"
+            COLON@267..268 ":"
+            WHITESPACE@268..269 " "
+            PATH_TYPE@269..272
+              PATH@269..272
+                PATH_SEGMENT@269..272
+                  NAME_REF@269..272
+                    IDENT@269..272 "Foo"
+          R_PAREN@272..273 ")"
+        WHITESPACE@273..274 " "
+        BLOCK_EXPR@274..276
+          L_CURLY@274..275 "{"
+          R_CURLY@275..276 "}"
+      WHITESPACE@276..277 " "
+      COMMENT@277..332 "// error: `self` is o ..."
+      WHITESPACE@332..337 "\n    "
+    error Offset(142): expected `{`
+    error Offset(188): expected an associated function
+    error Offset(195): expected an associated function
+    error Offset(198): expected an associated function
+    error Offset(262): `self` is only allowed as the first parameter
+    "###);
+}
+
+#[test]
+fn generics() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    struct Pair<A, B> { a: A, b: B }
+    struct Wrapper<T>(T);
+    fn first<A, B>(pair: Pair<A, B>) -> A { pair.a }
+    fn nested(a: Pair<Wrapper<i32>, [Pair<f32, f64>]>) {}
+    fn less(a: i32, b: i32) -> bool { a < b }
+    struct Foo<T,>;
+    fn bar<1>() {}      // error: expected type parameter
+    fn baz(a: Pair<i32) {}  // error: expected `>`
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..354
+      WHITESPACE@0..5 "\n    "
+      STRUCT_DEF@5..37
+        STRUCT_KW@5..11 "struct"
+        WHITESPACE@11..12 " "
+        NAME@12..16
+          IDENT@12..16 "Pair"
+        TYPE_PARAM_LIST@16..22
+          LT@16..17 "<"
+          TYPE_PARAM@17..18
+            NAME@17..18
+              IDENT@17..18 "A"
+          COMMA@18..19 ","
+          WHITESPACE@19..20 " "
+          TYPE_PARAM@20..21
+            NAME@20..21
+              IDENT@20..21 "B"
+          GT@21..22 ">"
+        WHITESPACE@22..23 " "
+        RECORD_FIELD_DEF_LIST@23..37
+          L_CURLY@23..24 "{"
+          WHITESPACE@24..25 " "
+          RECORD_FIELD_DEF@25..29
+            NAME@25..26
+              IDENT@25..26 "a"
+            COLON@26..27 ":"
+            WHITESPACE@27..28 " "
+            PATH_TYPE@28..29
+              PATH@28..29
+                PATH_SEGMENT@28..29
+                  NAME_REF@28..29
+                    IDENT@28..29 "A"
+          COMMA@29..30 ","
+          WHITESPACE@30..31 " "
+          RECORD_FIELD_DEF@31..35
+            NAME@31..32
+              IDENT@31..32 "b"
+            COLON@32..33 ":"
+            WHITESPACE@33..34 " "
+            PATH_TYPE@34..35
+              PATH@34..35
+                PATH_SEGMENT@34..35
+                  NAME_REF@34..35
+                    IDENT@34..35 "B"
+          WHITESPACE@35..36 " "
+          R_CURLY@36..37 "}"
+      WHITESPACE@37..42 "\n    "
+      STRUCT_DEF@42..63
+        STRUCT_KW@42..48 "struct"
+        WHITESPACE@48..49 " "
+        NAME@49..56
+          IDENT@49..56 "Wrapper"
+        TYPE_PARAM_LIST@56..59
+          LT@56..57 "<"
+          TYPE_PARAM@57..58
+            NAME@57..58
+              IDENT@57..58 "T"
+          GT@58..59 ">"
+        TUPLE_FIELD_DEF_LIST@59..63
+          L_PAREN@59..60 "("
+          TUPLE_FIELD_DEF@60..61
+            PATH_TYPE@60..61
+              PATH@60..61
+                PATH_SEGMENT@60..61
+                  NAME_REF@60..61
+                    IDENT@60..61 "T"
+          R_PAREN@61..62 ")"
+          SEMI@62..63 ";"
+      FUNCTION_DEF@63..116
+        WHITESPACE@63..68 "\n    "
+        FN_KW@68..70 "fn"
+        WHITESPACE@70..71 " "
+        NAME@71..76
+          IDENT@71..76 "first"
+        TYPE_PARAM_LIST@76..82
+          LT@76..77 "<"
+          TYPE_PARAM@77..78
+            NAME@77..78
+              IDENT@77..78 "A"
+          COMMA@78..79 ","
+          WHITESPACE@79..80 " "
+          TYPE_PARAM@80..81
+            NAME@80..81
+              IDENT@80..81 "B"
+          GT@81..82 ">"
+        PARAM_LIST@82..100
+          L_PAREN@82..83 "("
+          PARAM@83..99
+            BIND_PAT@83..87
+              NAME@83..87
+                IDENT@83..87 "pair"
+            COLON@87..88 ":"
+            WHITESPACE@88..89 " "
+            PATH_TYPE@89..99
+              PATH@89..99
+                PATH_SEGMENT@89..99
+                  NAME_REF@89..93
+                    IDENT@89..93 "Pair"
+                  TYPE_ARG_LIST@93..99
+                    LT@93..94 "<"
+                    TYPE_ARG@94..95
+                      PATH_TYPE@94..95
+                        PATH@94..95
+                          PATH_SEGMENT@94..95
+                            NAME_REF@94..95
+                              IDENT@94..95 "A"
+                    COMMA@95..96 ","
+                    WHITESPACE@96..97 " "
+                    TYPE_ARG@97..98
+                      PATH_TYPE@97..98
+                        PATH@97..98
+                          PATH_SEGMENT@97..98
+                            NAME_REF@97..98
+                              IDENT@97..98 "B"
+                    GT@98..99 ">"
+          R_PAREN@99..100 ")"
+        WHITESPACE@100..101 " "
+        RET_TYPE@101..105
+          THIN_ARROW@101..103 "->"
+          WHITESPACE@103..104 " "
+          PATH_TYPE@104..105
+            PATH@104..105
+              PATH_SEGMENT@104..105
+                NAME_REF@104..105
+                  IDENT@104..105 "A"
+        WHITESPACE@105..106 " "
+        BLOCK_EXPR@106..116
+          L_CURLY@106..107 "{"
+          WHITESPACE@107..108 " "
+          FIELD_EXPR@108..114
+            PATH_EXPR@108..112
+              PATH@108..112
+                PATH_SEGMENT@108..112
+                  NAME_REF@108..112
+                    IDENT@108..112 "pair"
+            DOT@112..113 "."
+            NAME_REF@113..114
+              IDENT@113..114 "a"
+          WHITESPACE@114..115 " "
+          R_CURLY@115..116 "}"
+      FUNCTION_DEF@116..174
+        WHITESPACE@116..121 "\n    "
+        FN_KW@121..123 "fn"
+        WHITESPACE@123..124 " "
+        NAME@124..130
+          IDENT@124..130 "nested"
+        PARAM_LIST@130..171
+          L_PAREN@130..131 "("
+          PARAM@131..170
+            BIND_PAT@131..132
+              NAME@131..132
+                IDENT@131..132 "a"
+            COLON@132..133 ":"
+            WHITESPACE@133..134 " "
+            PATH_TYPE@134..170
+              PATH@134..170
+                PATH_SEGMENT@134..170
+                  NAME_REF@134..138
+                    IDENT@134..138 "Pair"
+                  TYPE_ARG_LIST@138..170
+                    LT@138..139 "<"
+                    TYPE_ARG@139..151
+                      PATH_TYPE@139..151
+                        PATH@139..151
+                          PATH_SEGMENT@139..151
+                            NAME_REF@139..146
+                              IDENT@139..146 "Wrapper"
+                            TYPE_ARG_LIST@146..151
+                              LT@146..147 "<"
+                              TYPE_ARG@147..150
+                                PATH_TYPE@147..150
+                                  PATH@147..150
+                                    PATH_SEGMENT@147..150
+                                      NAME_REF@147..150
+                                        IDENT@147..150 "i32"
+                              GT@150..151 ">"
+                    COMMA@151..152 ","
+                    WHITESPACE@152..153 " "
+                    TYPE_ARG@153..169
+                      ARRAY_TYPE@153..169
+                        L_BRACKET@153..154 "["
+                        PATH_TYPE@154..168
+                          PATH@154..168
+                            PATH_SEGMENT@154..168
+                              NAME_REF@154..158
+                                IDENT@154..158 "Pair"
+                              TYPE_ARG_LIST@158..168
+                                LT@158..159 "<"
+                                TYPE_ARG@159..162
+                                  PATH_TYPE@159..162
+                                    PATH@159..162
+                                      PATH_SEGMENT@159..162
+                                        NAME_REF@159..162
+                                          IDENT@159..162 "f32"
+                                COMMA@162..163 ","
+                                WHITESPACE@163..164 " "
+                                TYPE_ARG@164..167
+                                  PATH_TYPE@164..167
+                                    PATH@164..167
+                                      PATH_SEGMENT@164..167
+                                        NAME_REF@164..167
+                                          IDENT@164..167 "f64"
+                                GT@167..168 ">"
+                        R_BRACKET@168..169 "]"
+                    GT@169..170 ">"
+          R_PAREN@170..171 ")"
+        WHITESPACE@171..172 " "
+        BLOCK_EXPR@172..174
+          L_CURLY@172..173 "{"
+          R_CURLY@173..174 "}"
+      FUNCTION_DEF@174..220
+        WHITESPACE@174..179 "\n    "
+        FN_KW@179..181 "fn"
+        WHITESPACE@181..182 " "
+        NAME@182..186
+          IDENT@182..186 "less"
+        PARAM_LIST@186..202
+          L_PAREN@186..187 "("
+          PARAM@187..193
+            BIND_PAT@187..188
+              NAME@187..188
+                IDENT@187..188 "a"
+            COLON@188..189 ":"
+            WHITESPACE@189..190 " "
+            PATH_TYPE@190..193
+              PATH@190..193
+                PATH_SEGMENT@190..193
+                  NAME_REF@190..193
+                    IDENT@190..193 "i32"
+          COMMA@193..194 ","
+          WHITESPACE@194..195 " "
+          PARAM@195..201
+            BIND_PAT@195..196
+              NAME@195..196
+                IDENT@195..196 "b"
+            COLON@196..197 ":"
+            WHITESPACE@197..198 " "
+            PATH_TYPE@198..201
+              PATH@198..201
+                PATH_SEGMENT@198..201
+                  NAME_REF@198..201
+                    IDENT@198..201 "i32"
+          R_PAREN@201..202 ")"
+        WHITESPACE@202..203 " "
+        RET_TYPE@203..210
+          THIN_ARROW@203..205 "->"
+          WHITESPACE@205..206 " "
+          PATH_TYPE@206..210
+            PATH@206..210
+              PATH_SEGMENT@206..210
+                NAME_REF@206..210
+                  IDENT@206..210 "bool"
+        WHITESPACE@210..211 " "
+        BLOCK_EXPR@211..220
+          L_CURLY@211..212 "{"
+          WHITESPACE@212..213 " "
+          BIN_EXPR@213..218
+            PATH_EXPR@213..214
+              PATH@213..214
+                PATH_SEGMENT@213..214
+                  NAME_REF@213..214
+                    IDENT@213..214 "a"
+            WHITESPACE@214..215 " "
+            LT@215..216 "<"
+            WHITESPACE@216..217 " "
+            PATH_EXPR@217..218
+              PATH@217..218
+                PATH_SEGMENT@217..218
+                  NAME_REF@217..218
+                    IDENT@217..218 "b"
+          WHITESPACE@218..219 " "
+          R_CURLY@219..220 "}"
+      WHITESPACE@220..225 "\n    "
+      STRUCT_DEF@225..240
+        STRUCT_KW@225..231 "struct"
+        WHITESPACE@231..232 " "
+        NAME@232..235
+          IDENT@232..235 "Foo"
+        TYPE_PARAM_LIST@235..239
+          LT@235..236 "<"
+          TYPE_PARAM@236..237
+            NAME@236..237
+              IDENT@236..237 "T"
+          COMMA@237..238 ","
+          GT@238..239 ">"
+        SEMI@239..240 ";"
+      FUNCTION_DEF@240..259
+        WHITESPACE@240..245 "\n    "
+        FN_KW@245..247 "fn"
+        WHITESPACE@247..248 " "
+        NAME@248..251
+          IDENT@248..251 "bar"
+        TYPE_PARAM_LIST@251..254
+          LT@251..252 "<"
+          ERROR@252..253
+            INT_NUMBER@252..253 "1"
+          GT@253..254 ">"
+        PARAM_LIST@254..256
+          L_PAREN@254..255 "("
+          R_PAREN@255..256 ")"
+        WHITESPACE@256..257 " "
+        BLOCK_EXPR@257..259
+          L_CURLY@257..258 "{"
+          R_CURLY@258..259 "}"
+      FUNCTION_DEF@259..325
+        WHITESPACE@259..265 "      "
+        COMMENT@265..298 "// error: expected ty ..."
+        WHITESPACE@298..303 "\n    "
+        FN_KW@303..305 "fn"
+        WHITESPACE@305..306 " "
+        NAME@306..309
+          IDENT@306..309 "baz"
+        PARAM_LIST@309..322
+          L_PAREN@309..310 "("
+          PARAM@310..321
+            BIND_PAT@310..311
+              NAME@310..311
+                IDENT@310..311 "a"
+            COLON@311..312 ":"
+            WHITESPACE@312..313 " "
+            PATH_TYPE@313..321
+              PATH@313..321
+                PATH_SEGMENT@313..321
+                  NAME_REF@313..317
+                    IDENT@313..317 "Pair"
+                  TYPE_ARG_LIST@317..321
+                    LT@317..318 "<"
+                    TYPE_ARG@318..321
+                      PATH_TYPE@318..321
+                        PATH@318..321
+                          PATH_SEGMENT@318..321
+                            NAME_REF@318..321
+                              IDENT@318..321 "i32"
+          R_PAREN@321..322 ")"
+        WHITESPACE@322..323 " "
+        BLOCK_EXPR@323..325
+          L_CURLY@323..324 "{"
+          R_CURLY@324..325 "}"
+      WHITESPACE@325..327 "  "
+      COMMENT@327..349 "// error: expected `>`"
+      WHITESPACE@349..354 "\n    "
+    error Offset(252): expected type parameter
+    error Offset(321): expected GT
+    "###);
+}
+
+#[test]
+fn closures_and_fn_pointers() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn apply(f: fn(i32) -> i32, a: i32) -> i32 { f(a) }
+    fn callbacks(a: fn(), b: fn(f32, bool,)) {}
+    fn main() {
+        let offset = 3;
+        let add = |x| x + offset;
+        let typed = |a: i32, b: i32| -> i32 { a * b };
+        let nothing = || {};
+        apply(|x: i32| x * 2, 4) | 1;
+        let missing = |a| ;     // error: expected expression
+    }
+    fn bad(a: fn i32) {}        // error: expected parameters
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..435
+      FUNCTION_DEF@0..56
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..13
+          IDENT@8..13 "apply"
+        PARAM_LIST@13..40
+          L_PAREN@13..14 "("
+          PARAM@14..31
+            BIND_PAT@14..15
+              NAME@14..15
+                IDENT@14..15 "f"
+            COLON@15..16 ":"
+            WHITESPACE@16..17 " "
+            FN_POINTER_TYPE@17..31
+              FN_KW@17..19 "fn"
+              PARAM_LIST@19..24
+                L_PAREN@19..20 "("
+                PARAM@20..23
+                  PATH_TYPE@20..23
+                    PATH@20..23
+                      PATH_SEGMENT@20..23
+                        NAME_REF@20..23
+                          IDENT@20..23 "i32"
+                R_PAREN@23..24 ")"
+              WHITESPACE@24..25 " "
+              RET_TYPE@25..31
+                THIN_ARROW@25..27 "->"
+                WHITESPACE@27..28 " "
+                PATH_TYPE@28..31
+                  PATH@28..31
+                    PATH_SEGMENT@28..31
+                      NAME_REF@28..31
+                        IDENT@28..31 "i32"
+          COMMA@31..32 ","
+          WHITESPACE@32..33 " "
+          PARAM@33..39
+            BIND_PAT@33..34
+              NAME@33..34
+                IDENT@33..34 "a"
+            COLON@34..35 ":"
+            WHITESPACE@35..36 " "
+            PATH_TYPE@36..39
+              PATH@36..39
+                PATH_SEGMENT@36..39
+                  NAME_REF@36..39
+                    IDENT@36..39 "i32"
+          R_PAREN@39..40 ")"
+        WHITESPACE@40..41 " "
+        RET_TYPE@41..47
+          THIN_ARROW@41..43 "->"
+          WHITESPACE@43..44 " "
+          PATH_TYPE@44..47
+            PATH@44..47
+              PATH_SEGMENT@44..47
+                NAME_REF@44..47
+                  IDENT@44..47 "i32"
+        WHITESPACE@47..48 " "
+        BLOCK_EXPR@48..56
+          L_CURLY@48..49 "{"
+          WHITESPACE@49..50 " "
+          CALL_EXPR@50..54
+            PATH_EXPR@50..51
+              PATH@50..51
+                PATH_SEGMENT@50..51
+                  NAME_REF@50..51
+                    IDENT@50..51 "f"
+            ARG_LIST@51..54
+              L_PAREN@51..52 "("
+              PATH_EXPR@52..53
+                PATH@52..53
+                  PATH_SEGMENT@52..53
+                    NAME_REF@52..53
+                      IDENT@52..53 "a"
+              R_PAREN@53..54 ")"
+          WHITESPACE@54..55 " "
+          R_CURLY@55..56 "}"
+      FUNCTION_DEF@56..104
+        WHITESPACE@56..61 "\n    "
+        FN_KW@61..63 "fn"
+        WHITESPACE@63..64 " "
+        NAME@64..73
+          IDENT@64..73 "callbacks"
+        PARAM_LIST@73..101
+          L_PAREN@73..74 "("
+          PARAM@74..81
+            BIND_PAT@74..75
+              NAME@74..75
+                IDENT@74..75 "a"
+            COLON@75..76 ":"
+            WHITESPACE@76..77 " "
+            FN_POINTER_TYPE@77..81
+              FN_KW@77..79 "fn"
+              PARAM_LIST@79..81
+                L_PAREN@79..80 "("
+                R_PAREN@80..81 ")"
+          COMMA@81..82 ","
+          WHITESPACE@82..83 " "
+          PARAM@83..100
+            BIND_PAT@83..84
+              NAME@83..84
+                IDENT@83..84 "b"
+            COLON@84..85 ":"
+            WHITESPACE@85..86 " "
+            FN_POINTER_TYPE@86..100
+              FN_KW@86..88 "fn"
+              PARAM_LIST@88..100
+                L_PAREN@88..89 "("
+                PARAM@89..92
+                  PATH_TYPE@89..92
+                    PATH@89..92
+                      PATH_SEGMENT@89..92
+                        NAME_REF@89..92
+                          IDENT@89..92 "f32"
+                COMMA@92..93 ","
+                WHITESPACE@93..94 " "
+                PARAM@94..98
+                  PATH_TYPE@94..98
+                    PATH@94..98
+                      PATH_SEGMENT@94..98
+                        NAME_REF@94..98
+                          IDENT@94..98 "bool"
+                COMMA@98..99 ","
+                R_PAREN@99..100 ")"
+          R_PAREN@100..101 ")"
+        WHITESPACE@101..102 " "
+        BLOCK_EXPR@102..104
+          L_CURLY@102..103 "{"
+          R_CURLY@103..104 "}"
+      FUNCTION_DEF@104..368
+        WHITESPACE@104..109 "\n    "
+        FN_KW@109..111 "fn"
+        WHITESPACE@111..112 " "
+        NAME@112..116
+          IDENT@112..116 "main"
+        PARAM_LIST@116..118
+          L_PAREN@116..117 "("
+          R_PAREN@117..118 ")"
+        WHITESPACE@118..119 " "
+        BLOCK_EXPR@119..368
+          L_CURLY@119..120 "{"
+          WHITESPACE@120..129 "\n        "
+          LET_STMT@129..144
+            LET_KW@129..132 "let"
+            WHITESPACE@132..133 " "
+            BIND_PAT@133..139
+              NAME@133..139
+                IDENT@133..139 "offset"
+            WHITESPACE@139..140 " "
+            EQ@140..141 "="
+            WHITESPACE@141..142 " "
+            LITERAL@142..143
+              INT_NUMBER@142..143 "3"
+            SEMI@143..144 ";"
+          WHITESPACE@144..153 "\n        "
+          LET_STMT@153..178
+            LET_KW@153..156 "let"
+            WHITESPACE@156..157 " "
+            BIND_PAT@157..160
+              NAME@157..160
+                IDENT@157..160 "add"
+            WHITESPACE@160..161 " "
+            EQ@161..162 "="
+            WHITESPACE@162..163 " "
+            CLOSURE_EXPR@163..177
+              PARAM_LIST@163..166
+                PIPE@163..164 "|"
+                PARAM@164..165
+                  BIND_PAT@164..165
+                    NAME@164..165
+                      IDENT@164..165 "x"
+                PIPE@165..166 "|"
+              WHITESPACE@166..167 " "
+              BIN_EXPR@167..177
+                PATH_EXPR@167..168
+                  PATH@167..168
+                    PATH_SEGMENT@167..168
+                      NAME_REF@167..168
+                        IDENT@167..168 "x"
+                WHITESPACE@168..169 " "
+                PLUS@169..170 "+"
+                WHITESPACE@170..171 " "
+                PATH_EXPR@171..177
+                  PATH@171..177
+                    PATH_SEGMENT@171..177
+                      NAME_REF@171..177
+                        IDENT@171..177 "offset"
+            SEMI@177..178 ";"
+          WHITESPACE@178..187 "\n        "
+          LET_STMT@187..233
+            LET_KW@187..190 "let"
+            WHITESPACE@190..191 " "
+            BIND_PAT@191..196
+              NAME@191..196
+                IDENT@191..196 "typed"
+            WHITESPACE@196..197 " "
+            EQ@197..198 "="
+            WHITESPACE@198..199 " "
+            CLOSURE_EXPR@199..232
+              PARAM_LIST@199..215
+                PIPE@199..200 "|"
+                PARAM@200..206
+                  BIND_PAT@200..201
+                    NAME@200..201
+                      IDENT@200..201 "a"
+                  COLON@201..202 ":"
+                  WHITESPACE@202..203 " "
+                  PATH_TYPE@203..206
+                    PATH@203..206
+                      PATH_SEGMENT@203..206
+                        NAME_REF@203..206
+                          IDENT@203..206 "i32"
+                COMMA@206..207 ","
+                WHITESPACE@207..208 " "
+                PARAM@208..214
+                  BIND_PAT@208..209
+                    NAME@208..209
+                      IDENT@208..209 "b"
+                  COLON@209..210 ":"
+                  WHITESPACE@210..211 " "
+                  PATH_TYPE@211..214
+                    PATH@211..214
+                      PATH_SEGMENT@211..214
+                        NAME_REF@211..214
+                          IDENT@211..214 "i32"
+                PIPE@214..215 "|"
+              WHITESPACE@215..216 " "
+              RET_TYPE@216..222
+                THIN_ARROW@216..218 "->"
+                WHITESPACE@218..219 " "
+                PATH_TYPE@219..222
+                  PATH@219..222
+                    PATH_SEGMENT@219..222
+                      NAME_REF@219..222
+                        IDENT@219..222 "i32"
+              WHITESPACE@222..223 " "
+              BLOCK_EXPR@223..232
+                L_CURLY@223..224 "{"
+                WHITESPACE@224..225 " "
+                BIN_EXPR@225..230
+                  PATH_EXPR@225..226
+                    PATH@225..226
+                      PATH_SEGMENT@225..226
+                        NAME_REF@225..226
+                          IDENT@225..226 "a"
+                  WHITESPACE@226..227 " "
+                  STAR@227..228 "*"
+                  WHITESPACE@228..229 " "
+                  PATH_EXPR@229..230
+                    PATH@229..230
+                      PATH_SEGMENT@229..230
+                        NAME_REF@229..230
+                          IDENT@229..230 "b"
+                WHITESPACE@230..231 " "
+                R_CURLY@231..232 "}"
+            SEMI@232..233 ";"
+          WHITESPACE@233..242 "\n        "
+          LET_STMT@242..262
+            LET_KW@242..245 "let"
+            WHITESPACE@245..246 " "
+            BIND_PAT@246..253
+              NAME@246..253
+                IDENT@246..253 "nothing"
+            WHITESPACE@253..254 " "
+            EQ@254..255 "="
+            WHITESPACE@255..256 " "
+            CLOSURE_EXPR@256..261
+              PARAM_LIST@256..258
+                PIPEPIPE@256..258 "||"
+              WHITESPACE@258..259 " "
+              BLOCK_EXPR@259..261
+                L_CURLY@259..260 "{"
+                R_CURLY@260..261 "}"
+            SEMI@261..262 ";"
+          WHITESPACE@262..271 "\n        "
+          EXPR_STMT@271..300
+            BIN_EXPR@271..299
+              CALL_EXPR@271..295
+                PATH_EXPR@271..276
+                  PATH@271..276
+                    PATH_SEGMENT@271..276
+                      NAME_REF@271..276
+                        IDENT@271..276 "apply"
+                ARG_LIST@276..295
+                  L_PAREN@276..277 "("
+                  CLOSURE_EXPR@277..291
+                    PARAM_LIST@277..285
+                      PIPE@277..278 "|"
+                      PARAM@278..284
+                        BIND_PAT@278..279
+                          NAME@278..279
+                            IDENT@278..279 "x"
+                        COLON@279..280 ":"
+                        WHITESPACE@280..281 " "
+                        PATH_TYPE@281..284
+                          PATH@281..284
+                            PATH_SEGMENT@281..284
+                              NAME_REF@281..284
+                                IDENT@281..284 "i32"
+                      PIPE@284..285 "|"
+                    WHITESPACE@285..286 " "
+                    BIN_EXPR@286..291
+                      PATH_EXPR@286..287
+                        PATH@286..287
+                          PATH_SEGMENT@286..287
+                            NAME_REF@286..287
+                              IDENT@286..287 "x"
+                      WHITESPACE@287..288 " "
+                      STAR@288..289 "*"
+                      WHITESPACE@289..290 " "
+                      LITERAL@290..291
+                        INT_NUMBER@290..291 "2"
+                  COMMA@291..292 ","
+                  WHITESPACE@292..293 " "
+                  LITERAL@293..294
+                    INT_NUMBER@293..294 "4"
+                  R_PAREN@294..295 ")"
+              WHITESPACE@295..296 " "
+              PIPE@296..297 "|"
+              WHITESPACE@297..298 " "
+              LITERAL@298..299
+                INT_NUMBER@298..299 "1"
+            SEMI@299..300 ";"
+          WHITESPACE@300..309 "\n        "
+          LET_STMT@309..328
+            LET_KW@309..312 "let"
+            WHITESPACE@312..313 " "
+            BIND_PAT@313..320
+              NAME@313..320
+                IDENT@313..320 "missing"
+            WHITESPACE@320..321 " "
+            EQ@321..322 "="
+            WHITESPACE@322..323 " "
+            CLOSURE_EXPR@323..326
+              PARAM_LIST@323..326
+                PIPE@323..324 "|"
+                PARAM@324..325
+                  BIND_PAT@324..325
+                    NAME@324..325
+                      IDENT@324..325 "a"
+                PIPE@325..326 "|"
+            WHITESPACE@326..327 " "
+            SEMI@327..328 ";"
+          WHITESPACE@328..333 "     "
+          COMMENT@333..362 "// error: expected ex ..."
+          WHITESPACE@362..367 "\n    "
+          R_CURLY@367..368 "}"
+      FUNCTION_DEF@368..393
+        WHITESPACE@368..373 "\n    "
+        FN_KW@373..375 "fn"
+        WHITESPACE@375..376 " "
+        NAME@376..379
+          IDENT@376..379 "bad"
+        PARAM_LIST@379..390
+          L_PAREN@379..380 "("
+          PARAM@380..385
+            BIND_PAT@380..381
+              NAME@380..381
+                IDENT@380..381 "a"
+            COLON@381..382 ":"
+            WHITESPACE@382..383 " "
+            FN_POINTER_TYPE@383..385
+              FN_KW@383..385 "fn"
+          WHITESPACE@385..386 " "
+          PARAM@386..390
+            BIND_PAT@386..389
+              NAME@386..389
+                IDENT@386..389 "i32"
+            ERROR@389..390
+              R_PAREN@389..390 ")"
+        WHITESPACE@390..391 " "
+        BLOCK_EXPR@391..393
+          L_CURLY@391..392 "{"
+          R_CURLY@392..393 "}"
+      WHITESPACE@393..401 "        "
+      COMMENT@401..430 "// error: expected pa ..."
+      WHITESPACE@430..435 "\n    "
+    error Offset(326): expected expression
+    error Offset(385): expected parameters
+    error Offset(385): expected COMMA
+    error Offset(389): expected COLON
+    error Offset(389): expected type
+    error Offset(390): expected COMMA
+    error Offset(390): expected value parameter
+    error Offset(390): expected R_PAREN
+    "###);
+}
+
+#[test]
+fn traits() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    pub trait Damageable {
+        fn damage(self: Self, amount: f32);
+    }
+    impl Damageable for Enemy {
+        fn damage(self: Self, amount: f32) { self.health -= amount; }
+    }
+    fn hit(target: dyn Damageable) {}
+    trait {}                // error: expected a name
+    impl Damageable for {}  // error: expected type
+    fn foo(a: dyn) {}       // error: expected a trait
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..389
+      WHITESPACE@0..5 "\n    "
+      TRAIT_DEF@5..77
+        VISIBILITY@5..8
+          PUB_KW@5..8 "pub"
+        WHITESPACE@8..9 " "
+        TRAIT_KW@9..14 "trait"
+        WHITESPACE@14..15 " "
+        NAME@15..25
+          IDENT@15..25 "Damageable"
+        WHITESPACE@25..26 " "
+        ASSOCIATED_ITEM_LIST@26..77
+          L_CURLY@26..27 "{"
+          FUNCTION_DEF@27..71
+            WHITESPACE@27..36 "\n        "
+            FN_KW@36..38 "fn"
+            WHITESPACE@38..39 " "
+            NAME@39..45
+              IDENT@39..45 "damage"
+            PARAM_LIST@45..70
+              L_PAREN@45..46 "("
+              PARAM@46..56
+                BIND_PAT@46..50
+                  NAME@46..50
+                    SELF_KW@46..50 "self"
+                COLON@50..51 ":"
+                WHITESPACE@51..52 " "
+                PATH_TYPE@52..56
+                  PATH@52..56
+                    PATH_SEGMENT@52..56
+                      NAME_REF@52..56
+                        IDENT@52..56 "Self"
+              COMMA@56..57 ","
+              WHITESPACE@57..58 " "
+              PARAM@58..69
+                BIND_PAT@58..64
+                  NAME@58..64
+                    IDENT@58..64 "amount"
+                COLON@64..65 ":"
+                WHITESPACE@65..66 " "
+                PATH_TYPE@66..69
+                  PATH@66..69
+                    PATH_SEGMENT@66..69
+                      NAME_REF@66..69
+                        IDENT@66..69 "f32"
+              R_PAREN@69..70 ")"
+            SEMI@70..71 ";"
+          WHITESPACE@71..76 "\n    "
+          R_CURLY@76..77 "}"
+      WHITESPACE@77..82 "\n    "
+      IMPL@82..185
+        IMPL_KW@82..86 "impl"
+        WHITESPACE@86..87 " "
+        PATH_TYPE@87..97
+          PATH@87..97
+            PATH_SEGMENT@87..97
+              NAME_REF@87..97
+                IDENT@87..97 "Damageable"
+        WHITESPACE@97..98 " "
+        FOR_KW@98..101 "for"
+        WHITESPACE@101..102 " "
+        PATH_TYPE@102..107
+          PATH@102..107
+            PATH_SEGMENT@102..107
+              NAME_REF@102..107
+                IDENT@102..107 "Enemy"
+        WHITESPACE@107..108 " "
+        ASSOCIATED_ITEM_LIST@108..185
+          L_CURLY@108..109 "{"
+          FUNCTION_DEF@109..179
+            WHITESPACE@109..118 "\n        "
+            FN_KW@118..120 "fn"
+            WHITESPACE@120..121 " "
+            NAME@121..127
+              IDENT@121..127 "damage"
+            PARAM_LIST@127..152
+              L_PAREN@127..128 "("
+              PARAM@128..138
+                BIND_PAT@128..132
+                  NAME@128..132
+                    SELF_KW@128..132 "self"
+                COLON@132..133 ":"
+                WHITESPACE@133..134 " "
+                PATH_TYPE@134..138
+                  PATH@134..138
+                    PATH_SEGMENT@134..138
+                      NAME_REF@134..138
+                        IDENT@134..138 "Self"
+              COMMA@138..139 ","
+              WHITESPACE@139..140 " "
+              PARAM@140..151
+                BIND_PAT@140..146
+                  NAME@140..146
+                    IDENT@140..146 "amount"
+                COLON@146..147 ":"
+                WHITESPACE@147..148 " "
+                PATH_TYPE@148..151
+                  PATH@148..151
+                    PATH_SEGMENT@148..151
+                      NAME_REF@148..151
+                        IDENT@148..151 "f32"
+              R_PAREN@151..152 ")"
+            WHITESPACE@152..153 " "
+            BLOCK_EXPR@153..179
+              L_CURLY@153..154 "{"
+              WHITESPACE@154..155 " "
+              EXPR_STMT@155..177
+                BIN_EXPR@155..176
+                  FIELD_EXPR@155..166
+                    PATH_EXPR@155..159
+                      PATH@155..159
+                        PATH_SEGMENT@155..159
+                          SELF_KW@155..159 "self"
+                    DOT@159..160 "."
+                    NAME_REF@160..166
+                      IDENT@160..166 "health"
+                  WHITESPACE@166..167 " "
+                  MINUSEQ@167..169 "-="
+                  WHITESPACE@169..170 " "
+                  PATH_EXPR@170..176
+                    PATH@170..176
+                      PATH_SEGMENT@170..176
+                        NAME_REF@170..176
+                          IDENT@170..176 "amount"
+                SEMI@176..177 ";"
+              WHITESPACE@177..178 " "
+              R_CURLY@178..179 "}"
+          WHITESPACE@179..184 "\n    "
+          R_CURLY@184..185 "}"
+      FUNCTION_DEF@185..223
+        WHITESPACE@185..190 "\n    "
+        FN_KW@190..192 "fn"
+        WHITESPACE@192..193 " "
+        NAME@193..196
+          IDENT@193..196 "hit"
+        PARAM_LIST@196..220
+          L_PAREN@196..197 "("
+          PARAM@197..219
+            BIND_PAT@197..203
+              NAME@197..203
+                IDENT@197..203 "target"
+            COLON@203..204 ":"
+            WHITESPACE@204..205 " "
+            DYN_TRAIT_TYPE@205..219
+              DYN_KW@205..208 "dyn"
+              WHITESPACE@208..209 " "
+              PATH@209..219
+                PATH_SEGMENT@209..219
+                  NAME_REF@209..219
+                    IDENT@209..219 "Damageable"
+          R_PAREN@219..220 ")"
+        WHITESPACE@220..221 " "
+        BLOCK_EXPR@221..223
+          L_CURLY@221..222 "{"
+          R_CURLY@222..223 "}"
+      WHITESPACE@223..228 "\n    "
+      TRAIT_DEF@228..236
+        TRAIT_KW@228..233 "trait"
+        WHITESPACE@233..234 " "
+        ASSOCIATED_ITEM_LIST@234..236
+          L_CURLY@234..235 "{"
+          R_CURLY@235..236 "}"
+      WHITESPACE@236..252 "                "
+      COMMENT@252..277 "// error: expected a  ..."
+      WHITESPACE@277..282 "\n    "
+      IMPL@282..304
+        IMPL_KW@282..286 "impl"
+        WHITESPACE@286..287 " "
+        PATH_TYPE@287..297
+          PATH@287..297
+            PATH_SEGMENT@287..297
+              NAME_REF@287..297
+                IDENT@287..297 "Damageable"
+        WHITESPACE@297..298 " "
+        FOR_KW@298..301 "for"
+        WHITESPACE@301..302 " "
+        ASSOCIATED_ITEM_LIST@302..304
+          L_CURLY@302..303 "{"
+          R_CURLY@303..304 "}"
+      FUNCTION_DEF@304..351
+        WHITESPACE@304..306 "  "
+        COMMENT@306..329 "// error: expected type"
+        WHITESPACE@329..334 "\n    "
+        FN_KW@334..336 "fn"
+        WHITESPACE@336..337 " "
+        NAME@337..340
+          IDENT@337..340 "foo"
+        PARAM_LIST@340..348
+          L_PAREN@340..341 "("
+          PARAM@341..347
+            BIND_PAT@341..342
+              NAME@341..342
+                IDENT@341..342 "a"
+            COLON@342..343 ":"
+            WHITESPACE@343..344 " "
+            DYN_TRAIT_TYPE@344..347
+              DYN_KW@344..347 "dyn"
+          R_PAREN@347..348 ")"
+        WHITESPACE@348..349 " "
+        BLOCK_EXPR@349..351
+          L_CURLY@349..350 "{"
+          R_CURLY@350..351 "}"
+      WHITESPACE@351..358 "       "
+      COMMENT@358..384 "// error: expected a  ..."
+      WHITESPACE@384..389 "\n    "
+    error Offset(233): expected a name
+    error Offset(301): expected type
+    error Offset(347): expected a trait
+    "###);
+}
+
+#[test]
+fn tuples() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn swap(pair: (i32, f32)) -> (f32, i32) {
+        let (a, b) = pair;
+        (b, a)
+    }
+    fn foo() {
+        let unit: () = ();
+        let single: (i32,) = (1,);
+        let paren: (i32) = (1);
+        let (x, (y, _)) = (1, (2.0, true));
+        let first = pair.0;
+        let a = (1, 2;      // error: expected COMMA
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..339
+      FUNCTION_DEF@0..94
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..12
+          IDENT@8..12 "swap"
+        PARAM_LIST@12..30
+          L_PAREN@12..13 "("
+          PARAM@13..29
+            BIND_PAT@13..17
+              NAME@13..17
+                IDENT@13..17 "pair"
+            COLON@17..18 ":"
+            WHITESPACE@18..19 " "
+            TUPLE_TYPE@19..29
+              L_PAREN@19..20 "("
+              PATH_TYPE@20..23
+                PATH@20..23
+                  PATH_SEGMENT@20..23
+                    NAME_REF@20..23
+                      IDENT@20..23 "i32"
+              COMMA@23..24 ","
+              WHITESPACE@24..25 " "
+              PATH_TYPE@25..28
+                PATH@25..28
+                  PATH_SEGMENT@25..28
+                    NAME_REF@25..28
+                      IDENT@25..28 "f32"
+              R_PAREN@28..29 ")"
+          R_PAREN@29..30 ")"
+        WHITESPACE@30..31 " "
+        RET_TYPE@31..44
+          THIN_ARROW@31..33 "->"
+          WHITESPACE@33..34 " "
+          TUPLE_TYPE@34..44
+            L_PAREN@34..35 "("
+            PATH_TYPE@35..38
+              PATH@35..38
+                PATH_SEGMENT@35..38
+                  NAME_REF@35..38
+                    IDENT@35..38 "f32"
+            COMMA@38..39 ","
+            WHITESPACE@39..40 " "
+            PATH_TYPE@40..43
+              PATH@40..43
+                PATH_SEGMENT@40..43
+                  NAME_REF@40..43
+                    IDENT@40..43 "i32"
+            R_PAREN@43..44 ")"
+        WHITESPACE@44..45 " "
+        BLOCK_EXPR@45..94
+          L_CURLY@45..46 "{"
+          WHITESPACE@46..55 "\n        "
+          LET_STMT@55..73
+            LET_KW@55..58 "let"
+            WHITESPACE@58..59 " "
+            TUPLE_PAT@59..65
+              L_PAREN@59..60 "("
+              BIND_PAT@60..61
+                NAME@60..61
+                  IDENT@60..61 "a"
+              COMMA@61..62 ","
+              WHITESPACE@62..63 " "
+              BIND_PAT@63..64
+                NAME@63..64
+                  IDENT@63..64 "b"
+              R_PAREN@64..65 ")"
+            WHITESPACE@65..66 " "
+            EQ@66..67 "="
+            WHITESPACE@67..68 " "
+            PATH_EXPR@68..72
+              PATH@68..72
+                PATH_SEGMENT@68..72
+                  NAME_REF@68..72
+                    IDENT@68..72 "pair"
+            SEMI@72..73 ";"
+          WHITESPACE@73..82 "\n        "
+          TUPLE_EXPR@82..88
+            L_PAREN@82..83 "("
+            PATH_EXPR@83..84
+              PATH@83..84
+                PATH_SEGMENT@83..84
+                  NAME_REF@83..84
+                    IDENT@83..84 "b"
+            COMMA@84..85 ","
+            WHITESPACE@85..86 " "
+            PATH_EXPR@86..87
+              PATH@86..87
+                PATH_SEGMENT@86..87
+                  NAME_REF@86..87
+                    IDENT@86..87 "a"
+            R_PAREN@87..88 ")"
+          WHITESPACE@88..93 "\n    "
+          R_CURLY@93..94 "}"
+      FUNCTION_DEF@94..334
+        WHITESPACE@94..99 "\n    "
+        FN_KW@99..101 "fn"
+        WHITESPACE@101..102 " "
+        NAME@102..105
+          IDENT@102..105 "foo"
+        PARAM_LIST@105..107
+          L_PAREN@105..106 "("
+          R_PAREN@106..107 ")"
+        WHITESPACE@107..108 " "
+        BLOCK_EXPR@108..334
+          L_CURLY@108..109 "{"
+          WHITESPACE@109..118 "\n        "
+          LET_STMT@118..136
+            LET_KW@118..121 "let"
+            WHITESPACE@121..122 " "
+            BIND_PAT@122..126
+              NAME@122..126
+                IDENT@122..126 "unit"
+            COLON@126..127 ":"
+            WHITESPACE@127..128 " "
+            TUPLE_TYPE@128..130
+              L_PAREN@128..129 "("
+              R_PAREN@129..130 ")"
+            WHITESPACE@130..131 " "
+            EQ@131..132 "="
+            WHITESPACE@132..133 " "
+            TUPLE_EXPR@133..135
+              L_PAREN@133..134 "("
+              R_PAREN@134..135 ")"
+            SEMI@135..136 ";"
+          WHITESPACE@136..145 "\n        "
+          LET_STMT@145..171
+            LET_KW@145..148 "let"
+            WHITESPACE@148..149 " "
+            BIND_PAT@149..155
+              NAME@149..155
+                IDENT@149..155 "single"
+            COLON@155..156 ":"
+            WHITESPACE@156..157 " "
+            TUPLE_TYPE@157..163
+              L_PAREN@157..158 "("
+              PATH_TYPE@158..161
+                PATH@158..161
+                  PATH_SEGMENT@158..161
+                    NAME_REF@158..161
+                      IDENT@158..161 "i32"
+              COMMA@161..162 ","
+              R_PAREN@162..163 ")"
+            WHITESPACE@163..164 " "
+            EQ@164..165 "="
+            WHITESPACE@165..166 " "
+            TUPLE_EXPR@166..170
+              L_PAREN@166..167 "("
+              LITERAL@167..168
+                INT_NUMBER@167..168 "1"
+              COMMA@168..169 ","
+              R_PAREN@169..170 ")"
+            SEMI@170..171 ";"
+          WHITESPACE@171..180 "\n        "
+          LET_STMT@180..203
+            LET_KW@180..183 "let"
+            WHITESPACE@183..184 " "
+            BIND_PAT@184..189
+              NAME@184..189
+                IDENT@184..189 "paren"
+            COLON@189..190 ":"
+            WHITESPACE@190..191 " "
+            PAREN_TYPE@191..196
+              L_PAREN@191..192 "("
+              PATH_TYPE@192..195
+                PATH@192..195
+                  PATH_SEGMENT@192..195
+                    NAME_REF@192..195
+                      IDENT@192..195 "i32"
+              R_PAREN@195..196 ")"
+            WHITESPACE@196..197 " "
+            EQ@197..198 "="
+            WHITESPACE@198..199 " "
+            PAREN_EXPR@199..202
+              L_PAREN@199..200 "("
+              LITERAL@200..201
+                INT_NUMBER@200..201 "1"
+              R_PAREN@201..202 ")"
+            SEMI@202..203 ";"
+          WHITESPACE@203..212 "\n        "
+          LET_STMT@212..247
+            LET_KW@212..215 "let"
+            WHITESPACE@215..216 " "
+            TUPLE_PAT@216..227
+              L_PAREN@216..217 "("
+              BIND_PAT@217..218
+                NAME@217..218
+                  IDENT@217..218 "x"
+              COMMA@218..219 ","
+              WHITESPACE@219..220 " "
+              TUPLE_PAT@220..226
+                L_PAREN@220..221 "("
+                BIND_PAT@221..222
+                  NAME@221..222
+                    IDENT@221..222 "y"
+                COMMA@222..223 ","
+                WHITESPACE@223..224 " "
+                PLACEHOLDER_PAT@224..225
+                  UNDERSCORE@224..225 "_"
+                R_PAREN@225..226 ")"
+              R_PAREN@226..227 ")"
+            WHITESPACE@227..228 " "
+            EQ@228..229 "="
+            WHITESPACE@229..230 " "
+            TUPLE_EXPR@230..246
+              L_PAREN@230..231 "("
+              LITERAL@231..232
+                INT_NUMBER@231..232 "1"
+              COMMA@232..233 ","
+              WHITESPACE@233..234 " "
+              TUPLE_EXPR@234..245
+                L_PAREN@234..235 "("
+                LITERAL@235..238
+                  FLOAT_NUMBER@235..238 "2.0"
+                COMMA@238..239 ","
+                WHITESPACE@239..240 " "
+                LITERAL@240..244
+                  TRUE_KW@240..244 "true"
+                R_PAREN@244..245 ")"
+              R_PAREN@245..246 ")"
+            SEMI@246..247 ";"
+          WHITESPACE@247..256 "\n        "
+          LET_STMT@256..275
+            LET_KW@256..259 "let"
+            WHITESPACE@259..260 " "
+            BIND_PAT@260..265
+              NAME@260..265
+                IDENT@260..265 "first"
+            WHITESPACE@265..266 " "
+            EQ@266..267 "="
+            WHITESPACE@267..268 " "
+            FIELD_EXPR@268..274
+              PATH_EXPR@268..272
+                PATH@268..272
+                  PATH_SEGMENT@268..272
+                    NAME_REF@268..272
+                      IDENT@268..272 "pair"
+              INDEX@272..274 ".0"
+            SEMI@274..275 ";"
+          WHITESPACE@275..284 "\n        "
+          LET_STMT@284..298
+            LET_KW@284..287 "let"
+            WHITESPACE@287..288 " "
+            BIND_PAT@288..289
+              NAME@288..289
+                IDENT@288..289 "a"
+            WHITESPACE@289..290 " "
+            EQ@290..291 "="
+            WHITESPACE@291..292 " "
+            TUPLE_EXPR@292..297
+              L_PAREN@292..293 "("
+              LITERAL@293..294
+                INT_NUMBER@293..294 "1"
+              COMMA@294..295 ","
+              WHITESPACE@295..296 " "
+              LITERAL@296..297
+                INT_NUMBER@296..297 "2"
+            SEMI@297..298 ";"
+          WHITESPACE@298..304 "      "
+          COMMENT@304..328 "// error: expected COMMA"
+          WHITESPACE@328..333 "\n    "
+          R_CURLY@333..334 "}"
+      WHITESPACE@334..339 "\n    "
+    error Offset(297): expected COMMA
+    error Offset(297): expected expression
+    error Offset(297): expected R_PAREN
+    "###);
+}
+
+#[test]
+fn consts_and_statics() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    pub const MAX_SPEED: f32 = 10.0;
+    const DOUBLE_SPEED: f32 = MAX_SPEED * 2.0;
+    static mut COUNTER: i32 = 0;
+    static NAME: bool = true;
+    const MISSING = 5;      // error: missing type
+    static mut FOO: i32;    // error: expected `=`
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..254
+      WHITESPACE@0..5 "\n    "
+      CONST_DEF@5..37
+        VISIBILITY@5..8
+          PUB_KW@5..8 "pub"
+        WHITESPACE@8..9 " "
+        CONST_KW@9..14 "const"
+        WHITESPACE@14..15 " "
+        NAME@15..24
+          IDENT@15..24 "MAX_SPEED"
+        COLON@24..25 ":"
+        WHITESPACE@25..26 " "
+        PATH_TYPE@26..29
+          PATH@26..29
+            PATH_SEGMENT@26..29
+              NAME_REF@26..29
+                IDENT@26..29 "f32"
+        WHITESPACE@29..30 " "
+        EQ@30..31 "="
+        WHITESPACE@31..32 " "
+        LITERAL@32..36
+          FLOAT_NUMBER@32..36 "10.0"
+        SEMI@36..37 ";"
+      WHITESPACE@37..42 "\n    "
+      CONST_DEF@42..84
+        CONST_KW@42..47 "const"
+        WHITESPACE@47..48 " "
+        NAME@48..60
+          IDENT@48..60 "DOUBLE_SPEED"
+        COLON@60..61 ":"
+        WHITESPACE@61..62 " "
+        PATH_TYPE@62..65
+          PATH@62..65
+            PATH_SEGMENT@62..65
+              NAME_REF@62..65
+                IDENT@62..65 "f32"
+        WHITESPACE@65..66 " "
+        EQ@66..67 "="
+        WHITESPACE@67..68 " "
+        BIN_EXPR@68..83
+          PATH_EXPR@68..77
+            PATH@68..77
+              PATH_SEGMENT@68..77
+                NAME_REF@68..77
+                  IDENT@68..77 "MAX_SPEED"
+          WHITESPACE@77..78 " "
+          STAR@78..79 "*"
+          WHITESPACE@79..80 " "
+          LITERAL@80..83
+            FLOAT_NUMBER@80..83 "2.0"
+        SEMI@83..84 ";"
+      WHITESPACE@84..89 "\n    "
+      STATIC_DEF@89..117
+        STATIC_KW@89..95 "static"
+        WHITESPACE@95..96 " "
+        MUT_KW@96..99 "mut"
+        WHITESPACE@99..100 " "
+        NAME@100..107
+          IDENT@100..107 "COUNTER"
+        COLON@107..108 ":"
+        WHITESPACE@108..109 " "
+        PATH_TYPE@109..112
+          PATH@109..112
+            PATH_SEGMENT@109..112
+              NAME_REF@109..112
+                IDENT@109..112 "i32"
+        WHITESPACE@112..113 " "
+        EQ@113..114 "="
+        WHITESPACE@114..115 " "
+        LITERAL@115..116
+          INT_NUMBER@115..116 "0"
+        SEMI@116..117 ";"
+      WHITESPACE@117..122 "\n    "
+      STATIC_DEF@122..147
+        STATIC_KW@122..128 "static"
+        WHITESPACE@128..129 " "
+        NAME@129..133
+          IDENT@129..133 "NAME"
+        COLON@133..134 ":"
+        WHITESPACE@134..135 " "
+        PATH_TYPE@135..139
+          PATH@135..139
+            PATH_SEGMENT@135..139
+              NAME_REF@135..139
+                IDENT@135..139 "bool"
+        WHITESPACE@139..140 " "
+        EQ@140..141 "="
+        WHITESPACE@141..142 " "
+        LITERAL@142..146
+          TRUE_KW@142..146 "true"
+        SEMI@146..147 ";"
+      WHITESPACE@147..152 "\n    "
+      CONST_DEF@152..170
+        CONST_KW@152..157 "const"
+        WHITESPACE@157..158 " "
+        NAME@158..165
+          IDENT@158..165 "MISSING"
+        WHITESPACE@165..166 " "
+        EQ@166..167 "="
+        WHITESPACE@167..168 " "
+        LITERAL@168..169
+          INT_NUMBER@168..169 "5"
+        SEMI@169..170 ";"
+      WHITESPACE@170..176 "      "
+      COMMENT@176..198 "// error: missing type"
+      WHITESPACE@198..203 "\n    "
+      STATIC_DEF@203..223
+        STATIC_KW@203..209 "static"
+        WHITESPACE@209..210 " "
+        MUT_KW@210..213 "mut"
+        WHITESPACE@213..214 " "
+        NAME@214..217
+          IDENT@214..217 "FOO"
+        COLON@217..218 ":"
+        WHITESPACE@218..219 " "
+        PATH_TYPE@219..222
+          PATH@219..222
+            PATH_SEGMENT@219..222
+              NAME_REF@219..222
+                IDENT@219..222 "i32"
+        SEMI@222..223 ";"
+      WHITESPACE@223..227 "    "
+      COMMENT@227..249 "// error: expected `=`"
+      WHITESPACE@249..254 "\n    "
+    error Offset(165): missing type for `const` or `static`
+    error Offset(222): expected `=`
+    "###);
+}
+
+#[test]
+fn optionals() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    struct Node { next: ?Node, children: ?[Node] }
+    fn foo(node: ?Node) -> ?Node {
+        let a: ?Node = nil;
+        if let n = node {
+            n.next
+        } else {
+            nil
+        }
+        while let next = node.next {}
+        let b: ? = a;   // error: expected type
+        if let = a {}   // error: expected pattern
+    }
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..350
+      WHITESPACE@0..5 "\n    "
+      STRUCT_DEF@5..51
+        STRUCT_KW@5..11 "struct"
+        WHITESPACE@11..12 " "
+        NAME@12..16
+          IDENT@12..16 "Node"
+        WHITESPACE@16..17 " "
+        RECORD_FIELD_DEF_LIST@17..51
+          L_CURLY@17..18 "{"
+          WHITESPACE@18..19 " "
+          RECORD_FIELD_DEF@19..30
+            NAME@19..23
+              IDENT@19..23 "next"
+            COLON@23..24 ":"
+            WHITESPACE@24..25 " "
+            OPTION_TYPE@25..30
+              QUESTION@25..26 "?"
+              PATH_TYPE@26..30
+                PATH@26..30
+                  PATH_SEGMENT@26..30
+                    NAME_REF@26..30
+                      IDENT@26..30 "Node"
+          COMMA@30..31 ","
+          WHITESPACE@31..32 " "
+          RECORD_FIELD_DEF@32..49
+            NAME@32..40
+              IDENT@32..40 "children"
+            COLON@40..41 ":"
+            WHITESPACE@41..42 " "
+            OPTION_TYPE@42..49
+              QUESTION@42..43 "?"
+              ARRAY_TYPE@43..49
+                L_BRACKET@43..44 "["
+                PATH_TYPE@44..48
+                  PATH@44..48
+                    PATH_SEGMENT@44..48
+                      NAME_REF@44..48
+                        IDENT@44..48 "Node"
+                R_BRACKET@48..49 "]"
+          WHITESPACE@49..50 " "
+          R_CURLY@50..51 "}"
+      FUNCTION_DEF@51..345
+        WHITESPACE@51..56 "\n    "
+        FN_KW@56..58 "fn"
+        WHITESPACE@58..59 " "
+        NAME@59..62
+          IDENT@59..62 "foo"
+        PARAM_LIST@62..75
+          L_PAREN@62..63 "("
+          PARAM@63..74
+            BIND_PAT@63..67
+              NAME@63..67
+                IDENT@63..67 "node"
+            COLON@67..68 ":"
+            WHITESPACE@68..69 " "
+            OPTION_TYPE@69..74
+              QUESTION@69..70 "?"
+              PATH_TYPE@70..74
+                PATH@70..74
+                  PATH_SEGMENT@70..74
+                    NAME_REF@70..74
+                      IDENT@70..74 "Node"
+          R_PAREN@74..75 ")"
+        WHITESPACE@75..76 " "
+        RET_TYPE@76..84
+          THIN_ARROW@76..78 "->"
+          WHITESPACE@78..79 " "
+          OPTION_TYPE@79..84
+            QUESTION@79..80 "?"
+            PATH_TYPE@80..84
+              PATH@80..84
+                PATH_SEGMENT@80..84
+                  NAME_REF@80..84
+                    IDENT@80..84 "Node"
+        WHITESPACE@84..85 " "
+        BLOCK_EXPR@85..345
+          L_CURLY@85..86 "{"
+          WHITESPACE@86..95 "\n        "
+          LET_STMT@95..114
+            LET_KW@95..98 "let"
+            WHITESPACE@98..99 " "
+            BIND_PAT@99..100
+              NAME@99..100
+                IDENT@99..100 "a"
+            COLON@100..101 ":"
+            WHITESPACE@101..102 " "
+            OPTION_TYPE@102..107
+              QUESTION@102..103 "?"
+              PATH_TYPE@103..107
+                PATH@103..107
+                  PATH_SEGMENT@103..107
+                    NAME_REF@103..107
+                      IDENT@103..107 "Node"
+            WHITESPACE@107..108 " "
+            EQ@108..109 "="
+            WHITESPACE@109..110 " "
+            LITERAL@110..113
+              NIL_KW@110..113 "nil"
+            SEMI@113..114 ";"
+          WHITESPACE@114..123 "\n        "
+          EXPR_STMT@123..202
+            IF_EXPR@123..202
+              IF_KW@123..125 "if"
+              WHITESPACE@125..126 " "
+              CONDITION@126..138
+                LET_KW@126..129 "let"
+                WHITESPACE@129..130 " "
+                BIND_PAT@130..131
+                  NAME@130..131
+                    IDENT@130..131 "n"
+                WHITESPACE@131..132 " "
+                EQ@132..133 "="
+                WHITESPACE@133..134 " "
+                PATH_EXPR@134..138
+                  PATH@134..138
+                    PATH_SEGMENT@134..138
+                      NAME_REF@134..138
+                        IDENT@134..138 "node"
+              WHITESPACE@138..139 " "
+              BLOCK_EXPR@139..169
+                L_CURLY@139..140 "{"
+                WHITESPACE@140..153 "\n            "
+                FIELD_EXPR@153..159
+                  PATH_EXPR@153..154
+                    PATH@153..154
+                      PATH_SEGMENT@153..154
+                        NAME_REF@153..154
+                          IDENT@153..154 "n"
+                  DOT@154..155 "."
+                  NAME_REF@155..159
+                    IDENT@155..159 "next"
+                WHITESPACE@159..168 "\n        "
+                R_CURLY@168..169 "}"
+              WHITESPACE@169..170 " "
+              ELSE_KW@170..174 "else"
+              WHITESPACE@174..175 " "
+              BLOCK_EXPR@175..202
+                L_CURLY@175..176 "{"
+                WHITESPACE@176..189 "\n            "
+                LITERAL@189..192
+                  NIL_KW@189..192 "nil"
+                WHITESPACE@192..201 "\n        "
+                R_CURLY@201..202 "}"
+          WHITESPACE@202..211 "\n        "
+          EXPR_STMT@211..240
+            WHILE_EXPR@211..240
+              WHILE_KW@211..216 "while"
+              WHITESPACE@216..217 " "
+              CONDITION@217..237
+                LET_KW@217..220 "let"
+                WHITESPACE@220..221 " "
+                BIND_PAT@221..225
+                  NAME@221..225
+                    IDENT@221..225 "next"
+                WHITESPACE@225..226 " "
+                EQ@226..227 "="
+                WHITESPACE@227..228 " "
+                FIELD_EXPR@228..237
+                  PATH_EXPR@228..232
+                    PATH@228..232
+                      PATH_SEGMENT@228..232
+                        NAME_REF@228..232
+                          IDENT@228..232 "node"
+                  DOT@232..233 "."
+                  NAME_REF@233..237
+                    IDENT@233..237 "next"
+              WHITESPACE@237..238 " "
+              BLOCK_EXPR@238..240
+                L_CURLY@238..239 "{"
+                R_CURLY@239..240 "}"
+          WHITESPACE@240..249 "\n        "
+          LET_STMT@249..259
+            LET_KW@249..252 "let"
+            WHITESPACE@252..253 " "
+            BIND_PAT@253..254
+              NAME@253..254
+                IDENT@253..254 "b"
+            COLON@254..255 ":"
+            WHITESPACE@255..256 " "
+            OPTION_TYPE@256..259
+              QUESTION@256..257 "?"
+              WHITESPACE@257..258 " "
+              ERROR@258..259
+                EQ@258..259 "="
+          WHITESPACE@259..260 " "
+          EXPR_STMT@260..262
+            PATH_EXPR@260..261
+              PATH@260..261
+                PATH_SEGMENT@260..261
+                  NAME_REF@260..261
+                    IDENT@260..261 "a"
+            SEMI@261..262 ";"
+          WHITESPACE@262..265 "   "
+          COMMENT@265..288 "// error: expected type"
+          WHITESPACE@288..297 "\n        "
+          IF_EXPR@297..310
+            IF_KW@297..299 "if"
+            WHITESPACE@299..300 " "
+            CONDITION@300..307
+              LET_KW@300..303 "let"
+              WHITESPACE@303..304 " "
+              EQ@304..305 "="
+              WHITESPACE@305..306 " "
+              PATH_EXPR@306..307
+                PATH@306..307
+                  PATH_SEGMENT@306..307
+                    NAME_REF@306..307
+                      IDENT@306..307 "a"
+            WHITESPACE@307..308 " "
+            BLOCK_EXPR@308..310
+              L_CURLY@308..309 "{"
+              R_CURLY@309..310 "}"
+          WHITESPACE@310..313 "   "
+          COMMENT@313..339 "// error: expected pa ..."
+          WHITESPACE@339..344 "\n    "
+          R_CURLY@344..345 "}"
+      WHITESPACE@345..350 "\n    "
+    error Offset(258): expected type
+    error Offset(303): expected pattern
+    "###);
+}
+
+#[test]
+fn attributes() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    /// Doc comment
+    #[test]
+    fn foo() {}
+    #[deprecated = "use `foo` instead"]
+    #[inline(always)]
+    pub fn bar() {}
+    #[derive(Clone)]
+    struct Foo {
+        #[deprecated]
+        pub a: i32,
+    }
+    struct Bar(#[deprecated] i32);
+    enum Baz {
+        #[deprecated]
+        A,
+    }
+    impl Foo {
+        #[inline]
+        fn baz(self: Self) {}
+    }
+    #[]             // error: expected an attribute name
+    fn missing() {}
+    #[foo = ]       // error: expected a literal
+    fn literal() {}
+    #[foo(bar]      // error: expected `)`
+    fn unbalanced() {}
+    "#,
+    ).debug_dump(), @r###"
+    SOURCE_FILE@0..591
+      FUNCTION_DEF@0..48
+        WHITESPACE@0..5 "\n    "
+        COMMENT@5..20 "/// Doc comment"
+        WHITESPACE@20..25 "\n    "
+        ATTR@25..32
+          HASH@25..26 "#"
+          L_BRACKET@26..27 "["
+          PATH@27..31
+            PATH_SEGMENT@27..31
+              NAME_REF@27..31
+                IDENT@27..31 "test"
+          R_BRACKET@31..32 "]"
+        WHITESPACE@32..37 "\n    "
+        FN_KW@37..39 "fn"
+        WHITESPACE@39..40 " "
+        NAME@40..43
+          IDENT@40..43 "foo"
+        PARAM_LIST@43..45
+          L_PAREN@43..44 "("
+          R_PAREN@44..45 ")"
+        WHITESPACE@45..46 " "
+        BLOCK_EXPR@46..48
+          L_CURLY@46..47 "{"
+          R_CURLY@47..48 "}"
+      FUNCTION_DEF@48..130
+        WHITESPACE@48..53 "\n    "
+        ATTR@53..88
+          HASH@53..54 "#"
+          L_BRACKET@54..55 "["
+          PATH@55..65
+            PATH_SEGMENT@55..65
+              NAME_REF@55..65
+                IDENT@55..65 "deprecated"
+          WHITESPACE@65..66 " "
+          EQ@66..67 "="
+          WHITESPACE@67..68 " "
+          LITERAL@68..87
+            STRING@68..87 "\"use `foo` instead\""
+          R_BRACKET@87..88 "]"
+        WHITESPACE@88..93 "\n    "
+        ATTR@93..110
+          HASH@93..94 "#"
+          L_BRACKET@94..95 "["
+          PATH@95..101
+            PATH_SEGMENT@95..101
+              NAME_REF@95..101
+                IDENT@95..101 "inline"
+          TOKEN_TREE@101..109
+            L_PAREN@101..102 "("
+            IDENT@102..108 "always"
+            R_PAREN@108..109 ")"
+          R_BRACKET@109..110 "]"
+        WHITESPACE@110..115 "\n    "
+        VISIBILITY@115..118
+          PUB_KW@115..118 "pub"
+        WHITESPACE@118..119 " "
+        FN_KW@119..121 "fn"
+        WHITESPACE@121..122 " "
+        NAME@122..125
+          IDENT@122..125 "bar"
+        PARAM_LIST@125..127
+          L_PAREN@125..126 "("
+          R_PAREN@126..127 ")"
+        WHITESPACE@127..128 " "
+        BLOCK_EXPR@128..130
+          L_CURLY@128..129 "{"
+          R_CURLY@129..130 "}"
+      WHITESPACE@130..135 "\n    "
+      STRUCT_DEF@135..216
+        ATTR@135..151
+          HASH@135..136 "#"
+          L_BRACKET@136..137 "["
+          PATH@137..143
+            PATH_SEGMENT@137..143
+              NAME_REF@137..143
+                IDENT@137..143 "derive"
+          TOKEN_TREE@143..150
+            L_PAREN@143..144 "("
+            IDENT@144..149 "Clone"
+            R_PAREN@149..150 ")"
+          R_BRACKET@150..151 "]"
+        WHITESPACE@151..156 "\n    "
+        STRUCT_KW@156..162 "struct"
+        WHITESPACE@162..163 " "
+        NAME@163..166
+          IDENT@163..166 "Foo"
+        WHITESPACE@166..167 " "
+        RECORD_FIELD_DEF_LIST@167..216
+          L_CURLY@167..168 "{"
+          WHITESPACE@168..177 "\n        "
+          RECORD_FIELD_DEF@177..209
+            ATTR@177..190
+              HASH@177..178 "#"
+              L_BRACKET@178..179 "["
+              PATH@179..189
+                PATH_SEGMENT@179..189
+                  NAME_REF@179..189
+                    IDENT@179..189 "deprecated"
+              R_BRACKET@189..190 "]"
+            WHITESPACE@190..199 "\n        "
+            VISIBILITY@199..202
+              PUB_KW@199..202 "pub"
+            WHITESPACE@202..203 " "
+            NAME@203..204
+              IDENT@203..204 "a"
+            COLON@204..205 ":"
+            WHITESPACE@205..206 " "
+            PATH_TYPE@206..209
+              PATH@206..209
+                PATH_SEGMENT@206..209
+                  NAME_REF@206..209
+                    IDENT@206..209 "i32"
+          COMMA@209..210 ","
+          WHITESPACE@210..215 "\n    "
+          R_CURLY@215..216 "}"
+      WHITESPACE@216..221 "\n    "
+      STRUCT_DEF@221..251
+        STRUCT_KW@221..227 "struct"
+        WHITESPACE@227..228 " "
+        NAME@228..231
+          IDENT@228..231 "Bar"
+        TUPLE_FIELD_DEF_LIST@231..251
+          L_PAREN@231..232 "("
+          TUPLE_FIELD_DEF@232..249
+            ATTR@232..245
+              HASH@232..233 "#"
+              L_BRACKET@233..234 "["
+              PATH@234..244
+                PATH_SEGMENT@234..244
+                  NAME_REF@234..244
+                    IDENT@234..244 "deprecated"
+              R_BRACKET@244..245 "]"
+            WHITESPACE@245..246 " "
+            PATH_TYPE@246..249
+              PATH@246..249
+                PATH_SEGMENT@246..249
+                  NAME_REF@246..249
+                    IDENT@246..249 "i32"
+          R_PAREN@249..250 ")"
+          SEMI@250..251 ";"
+      WHITESPACE@251..256 "\n    "
+      ENUM_DEF@256..305
+        ENUM_KW@256..260 "enum"
+        WHITESPACE@260..261 " "
+        NAME@261..264
+          IDENT@261..264 "Baz"
+        WHITESPACE@264..265 " "
+        ENUM_VARIANT_LIST@265..305
+          L_CURLY@265..266 "{"
+          WHITESPACE@266..275 "\n        "
+          ENUM_VARIANT@275..298
+            ATTR@275..288
+              HASH@275..276 "#"
+              L_BRACKET@276..277 "["
+              PATH@277..287
+                PATH_SEGMENT@277..287
+                  NAME_REF@277..287
+                    IDENT@277..287 "deprecated"
+              R_BRACKET@287..288 "]"
+            WHITESPACE@288..297 "\n        "
+            NAME@297..298
+              IDENT@297..298 "A"
+          COMMA@298..299 ","
+          WHITESPACE@299..304 "\n    "
+          R_CURLY@304..305 "}"
+      WHITESPACE@305..310 "\n    "
+      IMPL@310..374
+        IMPL_KW@310..314 "impl"
+        WHITESPACE@314..315 " "
+        PATH_TYPE@315..318
+          PATH@315..318
+            PATH_SEGMENT@315..318
+              NAME_REF@315..318
+                IDENT@315..318 "Foo"
+        WHITESPACE@318..319 " "
+        ASSOCIATED_ITEM_LIST@319..374
+          L_CURLY@319..320 "{"
+          FUNCTION_DEF@320..368
+            WHITESPACE@320..329 "\n        "
+            ATTR@329..338
+              HASH@329..330 "#"
+              L_BRACKET@330..331 "["
+              PATH@331..337
+                PATH_SEGMENT@331..337
+                  NAME_REF@331..337
+                    IDENT@331..337 "inline"
+              R_BRACKET@337..338 "]"
+            WHITESPACE@338..347 "\n        "
+            FN_KW@347..349 "fn"
+            WHITESPACE@349..350 " "
+            NAME@350..353
+              IDENT@350..353 "baz"
+            PARAM_LIST@353..365
+              L_PAREN@353..354 "("
+              PARAM@354..364
+                BIND_PAT@354..358
+                  NAME@354..358
+                    SELF_KW@354..358 "self"
+                COLON@358..359 ":"
+                WHITESPACE@359..360 " "
+                PATH_TYPE@360..364
+                  PATH@360..364
+                    PATH_SEGMENT@360..364
+                      NAME_REF@360..364
+                        IDENT@360..364 "Self"
+              R_PAREN@364..365 ")"
+            WHITESPACE@365..366 " "
+            BLOCK_EXPR@366..368
+              L_CURLY@366..367 "{"
+              R_CURLY@367..368 "}"
+          WHITESPACE@368..373 "\n    "
+          R_CURLY@373..374 "}"
+      FUNCTION_DEF@374..451
+        WHITESPACE@374..379 "\n    "
+        ATTR@379..382
+          HASH@379..380 "#"
+          L_BRACKET@380..381 "["
+          R_BRACKET@381..382 "]"
+        WHITESPACE@382..395 "             "
+        COMMENT@395..431 "// error: expected an ..."
+        WHITESPACE@431..436 "\n    "
+        FN_KW@436..438 "fn"
+        WHITESPACE@438..439 " "
+        NAME@439..446
+          IDENT@439..446 "missing"
+        PARAM_LIST@446..448
+          L_PAREN@446..447 "("
+          R_PAREN@447..448 ")"
+        WHITESPACE@448..449 " "
+        BLOCK_EXPR@449..451
+          L_CURLY@449..450 "{"
+          R_CURLY@450..451 "}"
+      FUNCTION_DEF@451..520
+        WHITESPACE@451..456 "\n    "
+        ATTR@456..465
+          HASH@456..457 "#"
+          L_BRACKET@457..458 "["
+          PATH@458..461
+            PATH_SEGMENT@458..461
+              NAME_REF@458..461
+                IDENT@458..461 "foo"
+          WHITESPACE@461..462 " "
+          EQ@462..463 "="
+          WHITESPACE@463..464 " "
+          R_BRACKET@464..465 "]"
+        WHITESPACE@465..472 "       "
+        COMMENT@472..500 "// error: expected a  ..."
+        WHITESPACE@500..505 "\n    "
+        FN_KW@505..507 "fn"
+        WHITESPACE@507..508 " "
+        NAME@508..515
+          IDENT@508..515 "literal"
+        PARAM_LIST@515..517
+          L_PAREN@515..516 "("
+          R_PAREN@516..517 ")"
+        WHITESPACE@517..518 " "
+        BLOCK_EXPR@518..520
+          L_CURLY@518..519 "{"
+          R_CURLY@519..520 "}"
+      FUNCTION_DEF@520..586
+        WHITESPACE@520..525 "\n    "
+        ATTR@525..535
+          HASH@525..526 "#"
+          L_BRACKET@526..527 "["
+          PATH@527..530
+            PATH_SEGMENT@527..530
+              NAME_REF@527..530
+                IDENT@527..530 "foo"
+          TOKEN_TREE@530..534
+            L_PAREN@530..531 "("
+            IDENT@531..534 "bar"
+          R_BRACKET@534..535 "]"
+        WHITESPACE@535..541 "      "
+        COMMENT@541..563 "// error: expected `)`"
+        WHITESPACE@563..568 "\n    "
+        FN_KW@568..570 "fn"
+        WHITESPACE@570..571 " "
+        NAME@571..581
+          IDENT@571..581 "unbalanced"
+        PARAM_LIST@581..583
+          L_PAREN@581..582 "("
+          R_PAREN@582..583 ")"
+        WHITESPACE@583..584 " "
+        BLOCK_EXPR@584..586
+          L_CURLY@584..585 "{"
+          R_CURLY@585..586 "}"
+      WHITESPACE@586..591 "\n    "
+    error Offset(381): expected an attribute name
+    error Offset(463): expected a literal
+    error Offset(534): expected R_PAREN
+    "###);
+}
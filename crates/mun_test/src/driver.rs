@@ -61,10 +61,17 @@ impl CompileTestDriver {
 
     /// Constructs a new `CompileTestDriver` from a single Mun source.
     pub fn from_file(text: &str) -> Self {
+        Self::from_file_with_config(text, Config::default())
+    }
+
+    /// Constructs a new `CompileTestDriver` from a single Mun source that is compiled with the
+    /// specified `config`. The output directory of the `config` is replaced by a temporary
+    /// directory.
+    pub fn from_file_with_config(text: &str, config: Config) -> Self {
         let temp_dir = tempfile::TempDir::new().unwrap();
         let config = Config {
             out_dir: Some(temp_dir.path().to_path_buf()),
-            ..Config::default()
+            ..config
         };
         let input = PathOrInline::Inline {
             rel_path: RelativePathBuf::from("mod.mun"),
@@ -161,7 +168,18 @@ impl CompileAndRunTestDriver {
         text: &str,
         config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
     ) -> Result<Self, anyhow::Error> {
-        let driver = CompileTestDriver::from_file(text);
+        Self::with_compiler_config(text, Config::default(), config_fn)
+    }
+
+    /// Constructs a `CompileAndRunTestDriver` from a single Mun source file that is compiled with
+    /// the specified `compiler_config`, and a `config_fn` that allows modification of a
+    /// [`RuntimeBuilder`].
+    pub fn with_compiler_config(
+        text: &str,
+        compiler_config: Config,
+        config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
+    ) -> Result<Self, anyhow::Error> {
+        let driver = CompileTestDriver::from_file_with_config(text, compiler_config);
         let builder = Runtime::builder(driver.lib_path());
 
         // Safety: We compiled the library ourselves, therefor loading the munlib is safe.